@@ -8,7 +8,7 @@
 use axum::{routing, Router as AxumRouter};
 use telers::{
     enums::UpdateType,
-    event::{telegram::HandlerResult, EventReturn, ToServiceProvider as _},
+    event::{telegram::HandlerResult, FlowControl, ToServiceProvider as _},
     methods::CopyMessage,
     types::Message,
     Bot, Dispatcher, Router as TelersRouter,
@@ -25,7 +25,7 @@ async fn echo_handler(bot: Bot, message: Message) -> HandlerResult {
     ))
     .await?;
 
-    Ok(EventReturn::Finish)
+    Ok(FlowControl::Continue)
 }
 
 #[allow(clippy::unused_async)]
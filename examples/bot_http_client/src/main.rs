@@ -24,11 +24,11 @@
 //! [`Bot::with_client`]: telers::Bot#method.with_client
 
 use async_trait::async_trait;
-use std::borrow::Cow;
+use std::{borrow::Cow, time::Duration};
 use telers::{
     client::{session::ClientResponse, telegram, Session},
     enums::UpdateType,
-    event::{telegram::HandlerResult, EventReturn, ToServiceProvider as _},
+    event::{telegram::HandlerResult, FlowControl, ToServiceProvider as _},
     methods::{CopyMessage, TelegramMethod},
     types::Message,
     Bot, Dispatcher, Router,
@@ -60,7 +60,7 @@ impl Session for CustomClient {
         &self,
         _bot: &Bot<Client>,
         _method: &T,
-        _timeout: Option<f32>,
+        _timeout: Option<Duration>,
     ) -> Result<ClientResponse, anyhow::Error>
     where
         Client: Session,
@@ -82,7 +82,7 @@ async fn echo_handler(bot: Bot<impl Session>, message: Message) -> HandlerResult
     ))
     .await?;
 
-    Ok(EventReturn::Finish)
+    Ok(FlowControl::Continue)
 }
 
 #[tokio::main(flavor = "current_thread")]
@@ -0,0 +1,150 @@
+//! This example shows how to create a gatekeeper bot for a private group, which handles chat join requests.
+//! Join requests sent by the invite link with name "VIP" are approved immediately,
+//! other users have to solve a simple captcha in the private chat with the bot before their requests are approved.
+//!
+//! We use [`ChatJoinRequest::approve`] and [`ChatJoinRequest::decline`] shortcuts to create methods
+//! and [`InviteLink`] filter to check the invite link used by the user.
+//!
+//! The bot must be an administrator in the chat with the `can_invite_users` right
+//! and the chat must have an invite link with the "Request admin approval" option enabled.
+//!
+//! You can run this example by setting `BOT_TOKEN` and optional `RUST_LOG` environment variable and running:
+//! ```bash
+//! RUST_LOG={log_level} BOT_TOKEN={your_bot_token} cargo run --package chat_join_request_captcha
+//! ```
+
+use telers::{
+    enums::UpdateType,
+    event::{telegram::HandlerResult, FlowControl, ToServiceProvider as _},
+    filters::{InviteLink, Text},
+    methods::{
+        AnswerCallbackQuery, ApproveChatJoinRequest, DeclineChatJoinRequest, EditMessageText,
+        SendMessage,
+    },
+    types::{CallbackQuery, ChatJoinRequest, InlineKeyboardButton, InlineKeyboardMarkup},
+    Bot, Dispatcher, Router,
+};
+use tracing::{event, Level};
+use tracing_subscriber::{fmt, layer::SubscriberExt as _, util::SubscriberInitExt as _, EnvFilter};
+
+const CAPTCHA_PREFIX: &str = "captcha";
+
+async fn vip_join_request_handler(bot: Bot, join_request: ChatJoinRequest) -> HandlerResult {
+    bot.send(join_request.approve()).await?;
+
+    Ok(FlowControl::Continue)
+}
+
+async fn join_request_handler(bot: Bot, join_request: ChatJoinRequest) -> HandlerResult {
+    let chat_id = join_request.chat.id();
+
+    // Simple arithmetic captcha, the correct answer is always `first + second`
+    let first = join_request.date % 5 + 1;
+    let second = join_request.from.id % 5 + 1;
+    let answer = first + second;
+
+    let buttons = [answer - 1, answer, answer + 1].map(|variant| {
+        let result = if variant == answer { "ok" } else { "fail" };
+
+        InlineKeyboardButton::new(variant.to_string())
+            .callback_data(format!("{CAPTCHA_PREFIX}:{chat_id}:{result}"))
+    });
+
+    // The bot can send messages to the user for 24 hours until the join request is processed
+    bot.send(
+        SendMessage::new(
+            join_request.user_chat_id,
+            format!("To join the chat, please, solve the captcha: {first} + {second} = ?"),
+        )
+        .reply_markup(InlineKeyboardMarkup::new([buttons])),
+    )
+    .await?;
+
+    Ok(FlowControl::Continue)
+}
+
+async fn captcha_handler(bot: Bot, callback_query: CallbackQuery) -> HandlerResult {
+    // `unwrap` is safe here, because `Text` filter checks that data is set
+    let data = callback_query.data.as_deref().unwrap();
+
+    let mut parts = data.split(':').skip(1);
+    let (Some(Ok(chat_id)), Some(result)) = (parts.next().map(str::parse::<i64>), parts.next())
+    else {
+        bot.send(AnswerCallbackQuery::new(callback_query.id).text("Invalid captcha data"))
+            .await?;
+
+        return Ok(FlowControl::Continue);
+    };
+
+    let user_id = callback_query.from.id;
+
+    let text = if result == "ok" {
+        bot.send(ApproveChatJoinRequest::new(chat_id, user_id))
+            .await?;
+
+        "Correct! Your join request is approved."
+    } else {
+        bot.send(DeclineChatJoinRequest::new(chat_id, user_id))
+            .await?;
+
+        "Wrong answer! Your join request is declined."
+    };
+
+    bot.send(AnswerCallbackQuery::new(callback_query.id.as_ref()))
+        .await?;
+
+    if let (Some(chat_id), Some(message_id)) =
+        (callback_query.chat_id(), callback_query.message_id())
+    {
+        bot.send(
+            EditMessageText::new(text)
+                .chat_id(chat_id)
+                .message_id(message_id),
+        )
+        .await?;
+    }
+
+    Ok(FlowControl::Continue)
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_env("RUST_LOG"))
+        .init();
+
+    let Ok(bot_token) = std::env::var("BOT_TOKEN") else {
+        panic!("BOT_TOKEN env variable is not set!");
+    };
+
+    let bot = Bot::new(bot_token);
+
+    let mut router = Router::new("main");
+    // Handlers are checked in order of registration, so VIP handler should be registered first
+    router
+        .chat_join_request
+        .register(vip_join_request_handler)
+        .filter(InviteLink::name("VIP"));
+    router.chat_join_request.register(join_request_handler);
+    router
+        .callback_query
+        .register(captcha_handler)
+        .filter(Text::starts_with_single(CAPTCHA_PREFIX));
+
+    let dispatcher = Dispatcher::builder()
+        .main_router(router)
+        .bot(bot)
+        .allowed_updates([UpdateType::ChatJoinRequest, UpdateType::CallbackQuery])
+        .build();
+
+    match dispatcher
+        .to_service_provider_default()
+        .unwrap()
+        .run_polling()
+        .await
+    {
+        Ok(()) => event!(Level::INFO, "Bot stopped"),
+        Err(err) => event!(Level::ERROR, error = %err, "Bot stopped"),
+    }
+}
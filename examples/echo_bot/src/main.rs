@@ -7,7 +7,7 @@
 
 use telers::{
     enums::UpdateType,
-    event::{telegram::HandlerResult, EventReturn, ToServiceProvider as _},
+    event::{telegram::HandlerResult, FlowControl, ToServiceProvider as _},
     methods::CopyMessage,
     types::Message,
     Bot, Dispatcher, Router,
@@ -23,7 +23,7 @@ async fn echo_handler(bot: Bot, message: Message) -> HandlerResult {
     ))
     .await?;
 
-    Ok(EventReturn::Finish)
+    Ok(FlowControl::Continue)
 }
 
 #[tokio::main(flavor = "current_thread")]
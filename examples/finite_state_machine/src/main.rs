@@ -21,7 +21,7 @@ use std::borrow::Cow;
 use telers::{
     enums::ContentType as ContentTypeEnum,
     enums::UpdateType,
-    event::{telegram::HandlerResult, EventReturn, ToServiceProvider as _},
+    event::{telegram::HandlerResult, FlowControl, ToServiceProvider as _},
     filters::{Command, ContentType, State as StateFilter},
     fsm::{Context as FSMContext, MemoryStorage, Storage, Strategy},
     methods::SendMessage,
@@ -85,7 +85,7 @@ async fn start_handler<S: Storage>(
     // because we set `State::Name` as state and this handler is registered for this state
     fsm.set_state(State::Name).await.map_err(Into::into)?;
 
-    Ok(EventReturn::Finish)
+    Ok(FlowControl::Continue)
 }
 
 async fn name_handler<S: Storage>(
@@ -112,7 +112,7 @@ async fn name_handler<S: Storage>(
     ))
     .await?;
 
-    Ok(EventReturn::Finish)
+    Ok(FlowControl::Continue)
 }
 
 async fn language_handler<S: Storage>(
@@ -154,7 +154,7 @@ async fn language_handler<S: Storage>(
         }
     };
 
-    Ok(EventReturn::Finish)
+    Ok(FlowControl::Continue)
 }
 
 #[tokio::main(flavor = "current_thread")]
@@ -14,9 +14,9 @@
 use async_trait::async_trait;
 use telers::{
     enums::UpdateType,
-    errors::{ConvertToTypeError, EventErrorKind},
-    event::{telegram::HandlerResult, EventReturn, ToServiceProvider as _},
-    extractors::FromEvent,
+    errors::EventErrorKind,
+    event::{telegram::HandlerResult, FlowControl, ToServiceProvider as _},
+    extractors::{ChatId, FromEvent},
     filters::Command,
     methods::SendMessage,
     middlewares::{outer::MiddlewareResponse, OuterMiddleware},
@@ -40,42 +40,20 @@ impl From<Update> for UpdateId {
     }
 }
 
-/// Implementing [`telers::extractors::FromEventAndContext`] by [`FromEvent`] macros to use struct in handlers.
-/// # Notes
-/// You can implement it manually, but it's more convenient to use macros to avoid boilerplate code.
-///
-/// You can specify custom error type by `[event(error = ...)]`, default it's `telers::errors::ConvertToTypeError`.
-/// The error type in macros should be the same as in the implementation of [`TryFrom`].
-///
-/// The trait also is implemented for `Option<T>`, `Result<T, E>` where `T: FromEventAndContext`,
-/// so we can use `Option<UpdateChatId>` in handlers (or `Result<UpdateChatId, ConvertToTypeError>`).
-#[derive(FromEvent)]
-#[event(try_from = Update)]
-struct UpdateChatId(i64);
-
-impl TryFrom<Update> for UpdateChatId {
-    type Error = ConvertToTypeError; // You can use your own error type here
-
-    fn try_from(update: Update) -> Result<Self, Self::Error> {
-        match update.chat_id() {
-            Some(chat_id) => Ok(Self(chat_id)),
-            None => Err(ConvertToTypeError::new("Update", "UpdateChatId")),
-        }
-    }
-}
-
 /// Handler that sends update id to chat from which we get the update.
 /// # Arguments
 /// * `bot` - Bot instance
 /// * `update_id` - Update id that we get from [`Update`] by extractor
-/// * `update_chat_id` - Update chat id that we get from [`Update`] by extractor
+/// * `chat_id` - Chat id that we get from [`Update`] by built-in [`ChatId`] extractor.
+///   The trait also is implemented for `Option<T>`, `Result<T, E>` where `T: FromEventAndContext`,
+///   so we can use `Option<ChatId>` in handlers (or `Result<ChatId, ExtractionError>`).
 async fn update_id_handler(
     bot: Bot,
     UpdateId(update_id): UpdateId,
-    update_chat_id: Option<UpdateChatId>,
+    chat_id: Option<ChatId>,
 ) -> HandlerResult {
-    match update_chat_id {
-        Some(UpdateChatId(chat_id)) => {
+    match chat_id {
+        Some(ChatId(chat_id)) => {
             bot.send(SendMessage::new(chat_id, format!("Update id: {update_id}")))
                 .await?;
         }
@@ -84,7 +62,7 @@ async fn update_id_handler(
         }
     }
 
-    Ok(EventReturn::Finish)
+    Ok(FlowControl::Continue)
 }
 
 /// # Warning
@@ -112,7 +90,7 @@ where
             .context
             .insert(self.key, Box::new(self.data.clone()));
 
-        Ok((request, EventReturn::default()))
+        Ok((request, FlowControl::default()))
     }
 }
 
@@ -128,7 +106,7 @@ async fn send_data_handler(bot: Bot, message: Message, data: Data) -> HandlerRes
     ))
     .await?;
 
-    Ok(EventReturn::Finish)
+    Ok(FlowControl::Continue)
 }
 
 #[tokio::main(flavor = "current_thread")]
@@ -5,26 +5,21 @@
 //! RUST_LOG={log_level} BOT_TOKEN={your_bot_token} cargo run --package input_file
 //! ```
 
-use bytes::BytesMut;
-use futures::{TryFutureExt as _, TryStreamExt as _};
 use telers::{
     enums::UpdateType,
     errors::HandlerError,
-    event::{simple, telegram, EventReturn, ToServiceProvider as _},
+    event::{simple, telegram, FlowControl, ToServiceProvider as _},
     methods::{SendMediaGroup, SendPhoto},
     router::Router,
     types::{InputFile, InputMediaPhoto, Message},
     Bot, Dispatcher,
 };
-use tokio_util::codec::{BytesCodec, FramedRead};
 use tracing::{event, Level};
 use tracing_subscriber::{fmt, layer::SubscriberExt as _, util::SubscriberInitExt as _, EnvFilter};
 
 const CAT_URL: &str = "https://http.cat/images/200.jpg";
 const CAT_FS_PATH: &str = "cat.jpg";
 
-const DEFAULT_CAPACITY: usize = 64 * 1024; // 64 KiB
-
 /// This handler will be called on bot startup.
 /// It will download file from URL and save it to the file system as `cat.jpg` for further usage in handlers.
 async fn on_startup() -> simple::HandlerResult {
@@ -97,15 +92,13 @@ async fn input_file_handler(bot: Bot, message: Message) -> telegram::HandlerResu
             HandlerError::new(err)
         })?);
 
-    // Using `InputFile::stream` to send file by stream
-    let cat_stream_input_file = InputFile::stream(Box::pin(
-        tokio::fs::File::open(CAT_FS_PATH)
-            .map_ok(|file| {
-                FramedRead::with_capacity(file, BytesCodec::new(), DEFAULT_CAPACITY)
-                    .map_ok(BytesMut::freeze)
-            })
-            .try_flatten_stream(),
-    ));
+    // Using `InputFile::read` to send file by stream from any `AsyncRead`
+    let cat_stream_input_file =
+        InputFile::read(tokio::fs::File::open(CAT_FS_PATH).await.map_err(|err| {
+            event!(Level::ERROR, error = %err, "Failed to open file");
+
+            HandlerError::new(err)
+        })?);
 
     let result_message = bot
         .send(SendMediaGroup::new(
@@ -134,7 +127,7 @@ async fn input_file_handler(bot: Bot, message: Message) -> telegram::HandlerResu
     )
     .await?;
 
-    Ok(EventReturn::Finish)
+    Ok(FlowControl::Continue)
 }
 
 #[tokio::main(flavor = "current_thread")]
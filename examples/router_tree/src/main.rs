@@ -17,7 +17,7 @@ use std::sync::{
 use telers::{
     enums::ChatType as ChatTypeEnum,
     errors::EventErrorKind,
-    event::{telegram::HandlerResult, EventReturn, ToServiceProvider as _},
+    event::{telegram::HandlerResult, FlowControl, ToServiceProvider as _},
     filters::{ChatType, Command},
     methods::{CopyMessage, SendMessage},
     middlewares::{outer::MiddlewareResponse, OuterMiddleware},
@@ -46,7 +46,7 @@ impl OuterMiddleware for IncomingEchoRouterUpdates {
             Box::new(self.counter.load(Ordering::SeqCst)),
         );
 
-        Ok((request, EventReturn::Finish))
+        Ok((request, FlowControl::Continue))
     }
 }
 
@@ -57,7 +57,7 @@ async fn start_private(bot: Bot, message: Message) -> HandlerResult {
     ))
     .await?;
 
-    Ok(EventReturn::Finish)
+    Ok(FlowControl::Continue)
 }
 
 async fn echo_handler(bot: Bot, message: Message) -> HandlerResult {
@@ -68,12 +68,17 @@ async fn echo_handler(bot: Bot, message: Message) -> HandlerResult {
     ))
     .await?;
 
-    Ok(EventReturn::Finish)
+    Ok(FlowControl::Continue)
 }
 
 async fn stats_echo_router(bot: Bot, message: Message, context: Arc<Context>) -> HandlerResult {
     let text = format!(
-        "Echo router updates stats\n\nIncoming updates: {}",
+        "Stats of the `{}` feature\n\nIncoming updates: {}",
+        context
+            .get("feature")
+            .unwrap()
+            .downcast_ref::<&str>()
+            .unwrap(),
         context
             .get("incoming_echo_router_updates_counter")
             .unwrap()
@@ -84,7 +89,7 @@ async fn stats_echo_router(bot: Bot, message: Message, context: Arc<Context>) ->
     bot.send(SendMessage::new(message.chat().id(), text))
         .await?;
 
-    Ok(EventReturn::Finish)
+    Ok(FlowControl::Continue)
 }
 
 #[tokio::main(flavor = "current_thread")]
@@ -118,6 +123,8 @@ async fn main() {
     main_router.include(private_router);
 
     let mut echo_router = Router::new("echo");
+    // Attach constant data, which is put to the context for every update passing through echo router and its sub routers
+    echo_router.context_data("feature", "echo");
     // Register stats middleware for echo router
     echo_router
         .update
@@ -18,7 +18,7 @@ use telers::{
     errors::EventErrorKind,
     event::{
         telegram::{HandlerRequest, HandlerResponse, HandlerResult},
-        EventReturn, ToServiceProvider as _,
+        FlowControl, ToServiceProvider as _,
     },
     methods::SendMessage,
     middlewares::{outer::MiddlewareResponse, InnerMiddleware, Next, OuterMiddleware},
@@ -44,7 +44,7 @@ impl OuterMiddleware for IncomingUpdates {
             Box::new(self.counter.load(Ordering::SeqCst)),
         );
 
-        Ok((request, EventReturn::Finish))
+        Ok((request, FlowControl::Continue))
     }
 }
 
@@ -94,7 +94,7 @@ async fn handler(bot: Bot, update: Update, context: Arc<Context>) -> HandlerResu
         bot.send(SendMessage::new(chat.id(), text)).await?;
     }
 
-    Ok(EventReturn::Finish)
+    Ok(FlowControl::Continue)
 }
 
 #[tokio::main(flavor = "current_thread")]
@@ -10,7 +10,7 @@ use std::future::Future;
 
 use telers::{
     enums::UpdateType,
-    event::{telegram::HandlerResult, EventReturn, ToServiceProvider as _},
+    event::{telegram::HandlerResult, FlowControl, ToServiceProvider as _},
     methods::SendMessage,
     types::{Message, Update},
     Bot, Context, Dispatcher, Filter, Router,
@@ -46,21 +46,21 @@ async fn uppercase_handler(bot: Bot, message: Message) -> HandlerResult {
     bot.send(SendMessage::new(message.chat().id(), "Uppercase message!"))
         .await?;
 
-    Ok(EventReturn::Finish)
+    Ok(FlowControl::Continue)
 }
 
 async fn lowercase_handler(bot: Bot, message: Message) -> HandlerResult {
     bot.send(SendMessage::new(message.chat().id(), "Lowercase message!"))
         .await?;
 
-    Ok(EventReturn::Finish)
+    Ok(FlowControl::Continue)
 }
 
 async fn any_case_handler(bot: Bot, message: Message) -> HandlerResult {
     bot.send(&SendMessage::new(message.chat().id(), "Any case message!"))
         .await?;
 
-    Ok(EventReturn::Finish)
+    Ok(FlowControl::Continue)
 }
 
 #[tokio::main(flavor = "current_thread")]
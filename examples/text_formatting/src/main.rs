@@ -8,7 +8,7 @@
 
 use telers::{
     enums::{ParseMode, UpdateType},
-    event::{telegram::HandlerResult, EventReturn, ToServiceProvider as _},
+    event::{telegram::HandlerResult, FlowControl, ToServiceProvider as _},
     methods::SendMessage,
     types::Message,
     utils::text::{html_text_link, Builder as TextBuilder, Formatter as _, HTMLFormatter},
@@ -53,7 +53,7 @@ async fn handler(bot: Bot, message: Message) -> HandlerResult {
     bot.send(SendMessage::new(message.chat().id(), text).parse_mode(ParseMode::HTML))
         .await?;
 
-    Ok(EventReturn::Finish)
+    Ok(FlowControl::Continue)
 }
 
 #[tokio::main(flavor = "current_thread")]
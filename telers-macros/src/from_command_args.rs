@@ -0,0 +1,49 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Fields, Item, ItemStruct};
+
+fn expand_struct(item: &ItemStruct) -> TokenStream {
+    let ident = &item.ident;
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+
+    let value = match &item.fields {
+        Fields::Named(fields) => {
+            let fields = fields.named.iter().map(|field| {
+                let ident = &field.ident;
+                let ty = &field.ty;
+
+                quote! { #ident: <#ty as ::telers::extractors::FromCommandArgs>::from_command_args(args)? }
+            });
+
+            quote! { Self { #(#fields,)* } }
+        }
+        Fields::Unnamed(fields) => {
+            let fields = fields.unnamed.iter().map(|field| {
+                let ty = &field.ty;
+
+                quote! { <#ty as ::telers::extractors::FromCommandArgs>::from_command_args(args)? }
+            });
+
+            quote! { Self(#(#fields,)*) }
+        }
+        Fields::Unit => quote! { Self },
+    };
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics ::telers::extractors::FromCommandArgs for #ident #ty_generics #where_clause {
+            fn from_command_args(
+                args: &mut ::telers::extractors::command_args::Args<'_>,
+            ) -> ::std::result::Result<Self, ::telers::extractors::command_args::Error> {
+                ::std::result::Result::Ok(#value)
+            }
+        }
+    }
+}
+
+pub(crate) fn expand(item: Item) -> Result<TokenStream, syn::Error> {
+    match item {
+        Item::Struct(item) => Ok(expand_struct(&item)),
+        _ => Err(syn::Error::new_spanned(item, "expected `struct`")),
+    }
+}
@@ -1,13 +1,14 @@
 use crate::{attrs_parsing::parse_attr, stream::trim_chars};
 
 use proc_macro2::TokenStream;
-use quote::{quote_spanned, ToTokens};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
 use syn::{
     parse::{Parse, ParseStream},
     parse_quote,
     punctuated::Punctuated,
-    Attribute, Ident, ImplGenerics, Item, ItemEnum, ItemStruct, LitStr, Token, Type, TypeGenerics,
-    WhereClause,
+    spanned::Spanned as _,
+    Attribute, Field, Fields, GenericArgument, Generics, Ident, ImplGenerics, Item, ItemEnum,
+    ItemStruct, LitStr, PathArguments, Token, Type, TypeGenerics, WhereClause,
 };
 
 mod keywords {
@@ -156,6 +157,95 @@ impl Parse for FromContextAttrs {
     }
 }
 
+/// Context attributes of the struct field
+/// # Fields
+/// * `key` - key of context (required)
+/// * `description` - description of field type in context (optional)
+/// # Examples
+/// ```not_rust
+/// struct Type {
+///     #[context(key = "a")]
+///     a: A,
+///     #[context(key = "b", description = "Optional value")]
+///     b: Option<B>,
+/// }
+/// ```
+/// # Notes
+/// If any unknown attribute is found, then we return error
+struct FieldAttrs {
+    key: LitStr,
+    description: Option<LitStr>,
+}
+
+/// Parse `#[context(...)]` attributes of the struct field
+/// # Examples
+/// ```not_rust
+/// #[context(key = "a", description = "...")]
+/// ```
+impl Parse for FieldAttrs {
+    fn parse(input: ParseStream) -> Result<Self, syn::Error> {
+        let mut key = None;
+        let mut description = None;
+
+        while !input.is_empty() {
+            let lookahead = input.lookahead1();
+
+            // If we found `,` token, then we need to skip it and continue parsing
+            if lookahead.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+
+                continue;
+            }
+
+            if lookahead.peek(keywords::key) {
+                let input_key: keywords::key = input.parse()?;
+                input.parse::<Token![=]>()?;
+
+                let value: LitStr = input.parse()?;
+
+                if key.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        input_key,
+                        "duplicate `key` attribute",
+                    ));
+                }
+
+                key = Some(value);
+
+                continue;
+            }
+
+            if lookahead.peek(keywords::description) {
+                let input_description: keywords::description = input.parse()?;
+                input.parse::<Token![=]>()?;
+
+                let value: LitStr = input.parse()?;
+
+                if description.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        input_description,
+                        "duplicate `description` attribute",
+                    ));
+                }
+
+                description = Some(value);
+
+                continue;
+            }
+
+            // If we found unknown attribute, then we need to return error
+            return Err(syn::Error::new(
+                input.span(),
+                "expected `key` or `description` attribute",
+            ));
+        }
+
+        let key = key.ok_or_else(|| syn::Error::new(input.span(), "missing `key` attribute"))?;
+
+        Ok(Self { key, description })
+    }
+}
+
 /// # Notes
 /// Currently, we support only default client type, but in future we will support custom client types
 enum Client {
@@ -292,6 +382,10 @@ fn impl_from_event_and_context(
                         ))),
                     }
                 }
+
+                fn context_keys() -> ::std::vec::Vec<&'static str> {
+                    ::std::vec![#key_str]
+                }
             }
         };
     };
@@ -333,6 +427,10 @@ fn impl_from_event_and_context(
                         ))),
                     }
                 }
+
+                fn context_keys() -> ::std::vec::Vec<&'static str> {
+                    ::std::vec![#key_str]
+                }
             }
         };
     };
@@ -371,6 +469,165 @@ fn impl_from_event_and_context(
                     ))),
                 }
             }
+
+            fn context_keys() -> ::std::vec::Vec<&'static str> {
+                ::std::vec![#key_str]
+            }
+        }
+    }
+}
+
+/// Get inner type of `Option<T>` type.
+/// # Notes
+/// We check only the last segment of the type path, so `Option`, `std::option::Option`, etc. are supported,
+/// but type aliases of `Option` aren't.
+fn option_inner_ty(ty: &Type) -> Option<&Type> {
+    let Type::Path(ty_path) = ty else {
+        return None;
+    };
+    let segment = ty_path.path.segments.last()?;
+
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match args.args.first() {
+            Some(GenericArgument::Type(inner)) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Implement `FromEventAndContext` trait for struct, each field of which is extracted from context by its own key.
+/// # Arguments
+/// * `ident` - struct for which we need to implement `FromEventAndContext` trait
+/// * `generics` - generics of the struct
+/// * `client` - client type
+/// * `fields` - fields of the struct with their context attributes. \
+///   If field type is `Option<T>`, then value of type `T` is extracted and the field is `None` if there is no value by the key.
+fn impl_from_event_and_context_fields(
+    ident: &Ident,
+    generics: &Generics,
+    client: &Client,
+    fields: &Fields,
+    fields_attrs: &[FieldAttrs],
+) -> TokenStream {
+    let client_impl_generic = client.impl_generic();
+    let client_ty_generic = client.ty_generic();
+
+    let mut impl_generics = generics.clone();
+    impl_generics
+        .params
+        .push(parse_quote! { #client_impl_generic });
+
+    let (impl_generics, _, _) = impl_generics.split_for_impl();
+    let (_, ty_generics, where_clause) = generics.split_for_impl();
+    let where_predicates = where_clause.map_or_else(Vec::new, |where_clause| {
+        where_clause.predicates.iter().collect()
+    });
+
+    let mut bounds = Vec::with_capacity(fields_attrs.len());
+    let mut values = Vec::with_capacity(fields_attrs.len());
+    let mut value_idents = Vec::with_capacity(fields_attrs.len());
+    let mut keys = Vec::with_capacity(fields_attrs.len());
+
+    for (index, (Field { ty, .. }, FieldAttrs { key, description })) in
+        fields.iter().zip(fields_attrs).enumerate()
+    {
+        // Be aware that `key` is `LitStr`, so we need to use `value` method to get `String` instead of using `to_string` method
+        let key = key.value();
+        let key_str = key.as_str();
+
+        let description = description.as_ref().map(LitStr::value);
+        let description_str = description.as_deref().unwrap_or("no description");
+
+        let value_ident = format_ident!("value_{}", index);
+
+        let (value_ty, value) = match option_inner_ty(ty) {
+            Some(inner_ty) => (
+                inner_ty,
+                quote_spanned! { ty.span() =>
+                    let #value_ident = match context.get(#key_str) {
+                        Some(value) => match value.downcast_ref::<#inner_ty>() {
+                            Some(value_ref) => Some(::std::clone::Clone::clone(value_ref)),
+                            None => return Err(Error::new(concat!(
+                                "Data in context by key `", #key_str, "` has wrong type expected `", stringify!(#inner_ty), "`. ",
+                                "You didn't forget to add type to context? ",
+                                "Type description: ", #description_str,
+                            ))),
+                        },
+                        None => None,
+                    };
+                },
+            ),
+            None => (
+                ty,
+                quote_spanned! { ty.span() =>
+                    let #value_ident = {
+                        let Some(value) = context.get(#key_str) else {
+                            return Err(Error::new(concat!(
+                                "No found data in context by key `", #key_str, "`. ",
+                                "You didn't forget to add type to context? ",
+                                "Type description: ", #description_str,
+                            )));
+                        };
+
+                        match value.downcast_ref::<#ty>() {
+                            Some(value_ref) => ::std::clone::Clone::clone(value_ref),
+                            None => return Err(Error::new(concat!(
+                                "Data in context by key `", #key_str, "` has wrong type expected `", stringify!(#ty), "`. ",
+                                "You didn't forget to add type to context? ",
+                                "Type description: ", #description_str,
+                            ))),
+                        }
+                    };
+                },
+            ),
+        };
+
+        bounds.push(quote! { #value_ty: ::std::clone::Clone + 'static });
+        keys.push(key);
+        values.push(value);
+        value_idents.push(value_ident);
+    }
+
+    let construct = match fields {
+        Fields::Named(_) => {
+            let field_idents = fields.iter().map(|field| &field.ident);
+
+            quote! { Self { #(#field_idents: #value_idents,)* } }
+        }
+        Fields::Unnamed(_) => quote! { Self(#(#value_idents,)*) },
+        Fields::Unit => quote! { Self },
+    };
+
+    quote_spanned! { ident.span() =>
+        #[automatically_derived]
+        impl #impl_generics ::telers::extractors::FromEventAndContext<#client_ty_generic> for #ident #ty_generics
+        where
+            #(#where_predicates,)*
+            #(#bounds,)*
+        {
+            type Error = ::telers::errors::ExtractionError;
+
+            #[inline]
+            fn extract(
+                bot: ::std::sync::Arc<::telers::client::Bot<#client_ty_generic>>,
+                update: ::std::sync::Arc<::telers::types::Update>,
+                context: ::std::sync::Arc<::telers::context::Context>,
+            ) -> Result<Self, Self::Error> {
+                use ::telers::errors::ExtractionError as Error;
+
+                #(#values)*
+
+                Ok(#construct)
+            }
+
+            fn context_keys() -> ::std::vec::Vec<&'static str> {
+                ::std::vec![#(#keys,)*]
+            }
         }
     }
 }
@@ -380,6 +637,7 @@ fn expand_struct(
         attrs,
         ident,
         generics,
+        fields,
         ..
     }: &ItemStruct,
 ) -> Result<TokenStream, syn::Error> {
@@ -395,6 +653,31 @@ fn expand_struct(
 
     let context_attrs = match parse_attr("context", attrs) {
         Ok(Some(attrs)) => attrs,
+        // If struct doesn't have `#[context(...)]` attribute, but its fields have, then each field is extracted by its own key
+        Ok(None) if fields.iter().any(|field| has_context_attr(&field.attrs)) => {
+            let fields_attrs = fields
+                .iter()
+                .map(|field| match parse_attr::<FieldAttrs>("context", &field.attrs) {
+                    Ok(Some(attrs)) => Ok(attrs),
+                    Ok(None) => Err(syn::Error::new_spanned(
+                        field,
+                        "missing `#[context(...)]` attribute, all fields should be extracted from context",
+                    )),
+                    Err(err) => Err(syn::Error::new_spanned(
+                        field,
+                        format!("failed to parse `#[context(...)]` attributes: {err}"),
+                    )),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            return Ok(impl_from_event_and_context_fields(
+                ident,
+                generics,
+                &client,
+                fields,
+                &fields_attrs,
+            ));
+        }
         Ok(None) => {
             return Err(syn::Error::new_spanned(
                 ident,
@@ -409,6 +692,13 @@ fn expand_struct(
         }
     };
 
+    if let Some(field) = fields.iter().find(|field| has_context_attr(&field.attrs)) {
+        return Err(syn::Error::new_spanned(
+            field,
+            "`#[context(...)]` attribute of the field can't be used with `#[context(...)]` attribute of the struct",
+        ));
+    }
+
     let (ident_impl_generics, ident_ty_generics, ident_where_clause) = generics.split_for_impl();
 
     Ok(impl_from_event_and_context(
@@ -421,6 +711,10 @@ fn expand_struct(
     ))
 }
 
+fn has_context_attr(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| attr.path().is_ident("context"))
+}
+
 fn expand_enum(
     ItemEnum {
         attrs,
@@ -1,13 +1,13 @@
 use crate::{attrs_parsing::parse_attr, stream::trim_chars};
 
 use proc_macro2::TokenStream;
-use quote::{quote_spanned, ToTokens};
+use quote::{quote, quote_spanned, ToTokens};
 use syn::{
     parse::{Parse, ParseStream},
     parse_quote,
     punctuated::Punctuated,
-    Attribute, Ident, ImplGenerics, Item, ItemEnum, ItemStruct, LitStr, Path, Token, Type,
-    TypeGenerics, WhereClause,
+    Attribute, Fields, Generics, Ident, ImplGenerics, Item, ItemEnum, ItemStruct, LitStr, Path,
+    Token, Type, TypeGenerics, WhereClause,
 };
 
 mod keywords {
@@ -15,13 +15,16 @@ mod keywords {
     syn::custom_keyword!(try_from);
     syn::custom_keyword!(description);
     syn::custom_keyword!(error);
+    syn::custom_keyword!(path);
 }
 
 /// # Notes
-/// Currently, we support only `Update` type
+/// Currently, we support only `Update` type and reference to it
 #[derive(Debug)]
 enum TypeKind {
     Update,
+    /// Reference to `Update`, so the event is converted without cloning the whole update
+    UpdateRef,
 }
 
 /// Parse attribute value in `#[event(from = ...)]` or `#[event(try_from = ...)]` attributes
@@ -32,9 +35,20 @@ enum TypeKind {
 ///
 /// #[event(try_from = Update)]
 /// struct AnotherType;
+///
+/// #[event(try_from = &Update)]
+/// struct AnotherTypeByRef;
 /// ```
 impl Parse for TypeKind {
     fn parse(input: ParseStream) -> Result<Self, syn::Error> {
+        if input.peek(Token![&]) {
+            input.parse::<Token![&]>()?;
+
+            return match input.parse()? {
+                Self::Update | Self::UpdateRef => Ok(Self::UpdateRef),
+            };
+        }
+
         let lookahead = input.lookahead1();
 
         // Check if we found `Path` type
@@ -68,6 +82,53 @@ impl Parse for TypeKind {
     }
 }
 
+/// Segment of the path in `#[event(path = ...)]` attribute
+/// # Examples
+/// ```not_rust
+/// #[event(from = Update, path = from().id)]
+///                               ^^^^^^ method call
+///                                      ^^ field access
+/// ```
+struct PathSegment {
+    ident: Ident,
+    is_call: bool,
+}
+
+impl Parse for PathSegment {
+    fn parse(input: ParseStream) -> Result<Self, syn::Error> {
+        let ident = input.parse()?;
+
+        let is_call = input.peek(syn::token::Paren);
+        if is_call {
+            let content;
+            syn::parenthesized!(content in input);
+
+            if !content.is_empty() {
+                return Err(syn::Error::new(
+                    content.span(),
+                    "methods with arguments aren't supported in `path` attribute",
+                ));
+            }
+        }
+
+        Ok(Self { ident, is_call })
+    }
+}
+
+impl ToTokens for PathSegment {
+    /// Method call returns a value, and field access returns a reference to the field,
+    /// so the value isn't moved out of the reference
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ident = &self.ident;
+
+        if self.is_call {
+            tokens.extend(quote! { value.#ident() });
+        } else {
+            tokens.extend(quote! { &value.#ident });
+        }
+    }
+}
+
 #[derive(Debug)]
 enum ConvertKind {
     From(TypeKind),
@@ -82,6 +143,8 @@ enum ConvertKind {
 /// If it's empty, then we use `ConvertToTypeError` type as error type. \
 /// If it's not empty, then we use this type as error type.
 /// * `description` - description of type (optional)
+/// * `path` - path of method calls and field accesses to the value in the update (optional). \
+///   If it's not empty, then we implement `TryFrom<&Update>` for the single-field struct too.
 /// # Examples
 /// ```not_rust
 /// #[event(from = Update)]
@@ -89,15 +152,21 @@ enum ConvertKind {
 ///
 /// #[event(try_from = Update)]
 /// struct AnotherType;
+///
+/// #[event(from = Update, path = chat().id())]
+/// struct ChatId(i64);
 /// ```
 /// # Notes
 /// If any unknown attribute is found, then we return error
 ///
 /// If `try_from` is empty and `error` is not empty, then we return error
+///
+/// If `path` is not empty and `error` is not empty, then we return error
 struct FromEventAttrs {
     convert_kind: ConvertKind,
     error: Option<ExtractionError>,
     _description: Option<LitStr>,
+    path: Option<Punctuated<PathSegment, Token![.]>>,
 }
 
 /// Parse `#[event(...)]` attributes
@@ -111,6 +180,7 @@ impl Parse for FromEventAttrs {
         let mut convert_kind = None;
         let mut error = None;
         let mut description = None;
+        let mut path = None;
 
         while !input.is_empty() {
             let lookahead = input.lookahead1();
@@ -216,10 +286,29 @@ impl Parse for FromEventAttrs {
                 continue;
             }
 
+            if lookahead.peek(keywords::path) {
+                let input_path: keywords::path = input.parse()?;
+                input.parse::<Token![=]>()?;
+
+                let value = Punctuated::<PathSegment, Token![.]>::parse_separated_nonempty(input)?;
+
+                if path.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        input_path,
+                        "duplicate `path` attribute",
+                    ));
+                }
+
+                path = Some(value);
+
+                // If we found `path` attribute, then we need to skip it and continue parsing
+                continue;
+            }
+
             // If we found unknown attribute, then we need to return error
             return Err(syn::Error::new(
                 input.span(),
-                "expected `from`, `try_from` or `description` attribute",
+                "expected `from`, `try_from`, `error`, `path` or `description` attribute",
             ));
         }
 
@@ -227,6 +316,23 @@ impl Parse for FromEventAttrs {
             syn::Error::new(input.span(), "missing `from` or `try_from` attribute")
         })?;
 
+        if path.is_some() {
+            if error.is_some() {
+                return Err(syn::Error::new(
+                    input.span(),
+                    "you can't use `error` attribute with `path` attribute",
+                ));
+            }
+
+            // Value by the path can be absent, so the conversion is always fallible
+            return Ok(Self {
+                convert_kind: ConvertKind::TryFrom(TypeKind::UpdateRef),
+                error: Some(ExtractionError::default()),
+                _description: description,
+                path,
+            });
+        }
+
         if let ConvertKind::From(_) = convert_kind {
             // We don't need to check `error` attribute if `from` attribute is not empty
             if error.is_some() {
@@ -246,6 +352,7 @@ impl Parse for FromEventAttrs {
             convert_kind,
             error,
             _description: description,
+            path,
         })
     }
 }
@@ -437,7 +544,117 @@ fn impl_from_event_and_context(
                 }
             }
         }
+        ConvertKind::From(TypeKind::UpdateRef) => {
+            quote_spanned! { ident.span() =>
+                #[automatically_derived]
+                impl <#impl_generics_punctuated> ::telers::extractors::FromEventAndContext<#client_ty_generic> for #ident #ty_generics_punctuated
+                where
+                    #where_clause_punctuated
+                    for<'__a> &'__a ::telers::types::Update: ::std::convert::Into<Self>
+                {
+                    type Error = ::std::convert::Infallible;
+
+                    #[inline]
+                    fn extract(
+                        bot: ::std::sync::Arc<::telers::client::Bot<#client_ty_generic>>,
+                        update: ::std::sync::Arc<::telers::types::Update>,
+                        context: ::std::sync::Arc<::telers::context::Context>,
+                    ) -> Result<Self, Self::Error> {
+                        Ok((&*update).into())
+                    }
+                }
+            }
+        }
+        ConvertKind::TryFrom(TypeKind::UpdateRef) => {
+            let error = event_attrs
+                .error
+                .as_ref()
+                .expect("error is empty in `try_from`, but it should be filled automatically");
+            let error_ty = error.ty_generic().clone();
+
+            quote_spanned! { ident.span() =>
+                #[automatically_derived]
+                impl <#impl_generics_punctuated> ::telers::extractors::FromEventAndContext<#client_ty_generic> for #ident #ty_generics_punctuated
+                where
+                    #where_clause_punctuated
+                    for<'__a> &'__a ::telers::types::Update: ::std::convert::TryInto<Self>
+                {
+                    type Error = #error_ty;
+
+                    #[inline]
+                    fn extract(
+                        bot: ::std::sync::Arc<::telers::client::Bot<#client_ty_generic>>,
+                        update: ::std::sync::Arc<::telers::types::Update>,
+                        context: ::std::sync::Arc<::telers::context::Context>,
+                    ) -> Result<Self, Self::Error> {
+                        ::std::convert::TryFrom::try_from(&*update)
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Implement `TryFrom<&Update>` trait for the single-field struct, which value is taken by the path of method calls and field accesses.
+/// # Arguments
+/// * `ident` - struct for which we need to implement `TryFrom<&Update>` trait
+/// * `generics` - generics of the struct
+/// * `fields` - fields of the struct, there should be only one field
+/// * `path` - path of method calls to the value in the update. \
+///   Each segment can return a value or `Option` of it, if any of them returns `None`, then the conversion fails.
+///   The value is converted to owned and then into field type with `Into` trait.
+fn impl_try_from_update_path(
+    ident: &Ident,
+    generics: &Generics,
+    fields: &Fields,
+    path: &Punctuated<PathSegment, Token![.]>,
+) -> Result<TokenStream, syn::Error> {
+    if fields.len() != 1 {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "`path` attribute can be used only with single-field struct",
+        ));
     }
+
+    let construct = match fields {
+        Fields::Named(fields) => {
+            let field_ident = &fields.named[0].ident;
+
+            quote! { Self { #field_ident: ::std::convert::Into::into(value) } }
+        }
+        _ => quote! { Self(::std::convert::Into::into(value)) },
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let segments = path.iter();
+
+    Ok(quote_spanned! { ident.span() =>
+        #[automatically_derived]
+        impl #impl_generics ::std::convert::TryFrom<&::telers::types::Update> for #ident #ty_generics #where_clause {
+            type Error = ::telers::errors::ConvertToTypeError;
+
+            fn try_from(update: &::telers::types::Update) -> Result<Self, Self::Error> {
+                #[allow(unused_imports)]
+                use ::telers::extractors::event_path::{OptionStep as _, Step, ValueStep as _};
+
+                let value = (|| {
+                    let value = update;
+                    #(
+                        let value = Step(#segments).into_option()?;
+                    )*
+                    Some(value.to_owned())
+                })();
+
+                match value {
+                    Some(value) => Ok(#construct),
+                    None => Err(::telers::errors::ConvertToTypeError::new(
+                        "Update",
+                        stringify!(#ident),
+                    )),
+                }
+            }
+        }
+    })
 }
 
 fn expand_struct(
@@ -445,6 +662,7 @@ fn expand_struct(
         attrs,
         ident,
         generics,
+        fields,
         ..
     }: &ItemStruct,
 ) -> Result<TokenStream, syn::Error> {
@@ -458,7 +676,7 @@ fn expand_struct(
         }
     };
 
-    let event_attrs = match parse_attr("event", attrs) {
+    let event_attrs: FromEventAttrs = match parse_attr("event", attrs) {
         Ok(Some(attrs)) => attrs,
         Ok(None) => {
             return Err(syn::Error::new_spanned(
@@ -476,14 +694,26 @@ fn expand_struct(
 
     let (ident_impl_generics, ident_ty_generics, ident_where_clause) = generics.split_for_impl();
 
-    Ok(impl_from_event_and_context(
+    let from_event_and_context = impl_from_event_and_context(
         ident,
         &ident_impl_generics,
         &ident_ty_generics,
         ident_where_clause,
         &client,
         &event_attrs,
-    ))
+    );
+
+    match event_attrs.path {
+        Some(ref path) => {
+            let try_from = impl_try_from_update_path(ident, generics, fields, path)?;
+
+            Ok(quote! {
+                #try_from
+                #from_event_and_context
+            })
+        }
+        None => Ok(from_event_and_context),
+    }
 }
 
 fn expand_enum(
@@ -504,7 +734,7 @@ fn expand_enum(
         }
     };
 
-    let event_attrs = match parse_attr("event", attrs) {
+    let event_attrs: FromEventAttrs = match parse_attr("event", attrs) {
         Ok(Some(attrs)) => attrs,
         Ok(None) => {
             return Err(syn::Error::new_spanned(
@@ -520,6 +750,13 @@ fn expand_enum(
         }
     };
 
+    if event_attrs.path.is_some() {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "`path` attribute can be used only with single-field struct",
+        ));
+    }
+
     let (ident_impl_generics, ident_ty_generics, ident_where_clause) = generics.split_for_impl();
 
     Ok(impl_from_event_and_context(
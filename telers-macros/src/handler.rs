@@ -0,0 +1,315 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_quote,
+    spanned::Spanned as _,
+    FnArg, ItemFn, LitStr, Pat, PatIdent, PatType, ReturnType, Token, Type,
+};
+
+mod keywords {
+    syn::custom_keyword!(client);
+    syn::custom_keyword!(name);
+    syn::custom_keyword!(description);
+    syn::custom_keyword!(command);
+}
+
+/// Max number of handler arguments, for which `Handler` trait is implemented
+const MAX_ARGS: usize = 20;
+
+/// All handler attributes
+/// # Fields
+/// * `client` - client type, for which arguments are checked (optional). \
+///   If it's empty, then we use `DefaultSession` type as client type.
+/// * `name` - name of the handler (optional). \
+///   If it's empty, then we use the function name as handler name.
+/// * `description` - description of the handler commands in the bot command menu (optional)
+/// * `command` - commands, which are handled by the handler (optional, can be specified multiple times)
+/// # Examples
+/// ```not_rust
+/// #[handler(client = Reqwest, name = "start", description = "Start the bot", command = "start")]
+/// async fn handler(message: Message) -> HandlerResult {}
+/// ```
+/// # Notes
+/// If any unknown attribute is found, then we return error
+pub(crate) struct HandlerAttrs {
+    client: Option<Type>,
+    name: Option<LitStr>,
+    description: Option<LitStr>,
+    commands: Vec<LitStr>,
+}
+
+/// Parse `#[handler(...)]` attributes
+/// # Examples
+/// ```not_rust
+/// #[handler(client = Reqwest, command = "start", command = "help")]
+/// ```
+impl Parse for HandlerAttrs {
+    fn parse(input: ParseStream) -> Result<Self, syn::Error> {
+        let mut client = None;
+        let mut name = None;
+        let mut description = None;
+        let mut commands = vec![];
+
+        while !input.is_empty() {
+            let lookahead = input.lookahead1();
+
+            // If we found `,` token, then we need to skip it and continue parsing
+            if lookahead.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+
+                continue;
+            }
+
+            if lookahead.peek(keywords::client) {
+                let input_client: keywords::client = input.parse()?;
+                input.parse::<Token![=]>()?;
+
+                let value: Type = input.parse()?;
+
+                if client.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        input_client,
+                        "duplicate `client` attribute",
+                    ));
+                }
+
+                client = Some(value);
+
+                // If we found `client` attribute, then we need to skip it and continue parsing
+                continue;
+            }
+
+            if lookahead.peek(keywords::name) {
+                let input_name: keywords::name = input.parse()?;
+                input.parse::<Token![=]>()?;
+
+                let value: LitStr = input.parse()?;
+
+                if name.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        input_name,
+                        "duplicate `name` attribute",
+                    ));
+                }
+
+                name = Some(value);
+
+                // If we found `name` attribute, then we need to skip it and continue parsing
+                continue;
+            }
+
+            if lookahead.peek(keywords::description) {
+                let input_description: keywords::description = input.parse()?;
+                input.parse::<Token![=]>()?;
+
+                let value: LitStr = input.parse()?;
+
+                if description.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        input_description,
+                        "duplicate `description` attribute",
+                    ));
+                }
+
+                description = Some(value);
+
+                // If we found `description` attribute, then we need to skip it and continue parsing
+                continue;
+            }
+
+            if lookahead.peek(keywords::command) {
+                input.parse::<keywords::command>()?;
+                input.parse::<Token![=]>()?;
+
+                let value: LitStr = input.parse()?;
+
+                if value.value().is_empty() {
+                    return Err(syn::Error::new_spanned(value, "command can't be empty"));
+                }
+
+                // Command can be specified multiple times, so we don't check duplicates
+                commands.push(value);
+
+                continue;
+            }
+
+            return Err(lookahead.error());
+        }
+
+        Ok(Self {
+            client,
+            name,
+            description,
+            commands,
+        })
+    }
+}
+
+/// Wrap the handler and check its signature, so the errors point to the wrong parameter or return type
+/// instead of the place where the handler is registered.
+///
+/// The function body is moved to the inner function with the same signature,
+/// and the outer function converts its output into `HandlerResult` with `IntoHandlerResult`,
+/// so the handler can return `()`, `FlowControl` or `Result` with any error, which can be converted into `HandlerError`.
+///
+/// For each parameter we generate function with the bound `Param: FromEventAndContext<Client> + Send`,
+/// for the return type - `Output: IntoHandlerResult`, and for the future - `Future: Send`.
+/// These functions are never called, they are only used to check the bounds.
+///
+/// Metadata of the handler is generated as `METADATA` constant in the module with the same name as the handler.
+#[allow(clippy::too_many_lines)]
+pub(crate) fn expand(attrs: &HandlerAttrs, item: ItemFn) -> Result<TokenStream, syn::Error> {
+    let ItemFn {
+        attrs: fn_attrs,
+        vis,
+        sig,
+        block,
+    } = item;
+
+    let asyncness = match sig.asyncness {
+        Some(asyncness) => asyncness,
+        None => {
+            return Err(syn::Error::new_spanned(
+                sig.fn_token,
+                "handler must be `async fn`",
+            ))
+        }
+    };
+    if sig.inputs.len() > MAX_ARGS {
+        return Err(syn::Error::new_spanned(
+            &sig.inputs,
+            format!("handler can't have more than {MAX_ARGS} arguments"),
+        ));
+    }
+
+    let client = attrs.client.as_ref().map_or_else(
+        || quote! { ::telers::client::DefaultSession },
+        |client| quote! { #client },
+    );
+
+    let ident = &sig.ident;
+    let (impl_generics, _, where_clause) = sig.generics.split_for_impl();
+    let where_predicates = where_clause.map_or_else(Vec::new, |where_clause| {
+        where_clause.predicates.iter().collect()
+    });
+
+    let mut tys = Vec::with_capacity(sig.inputs.len());
+    let mut arg_idents = Vec::with_capacity(sig.inputs.len());
+    let mut param_checks = Vec::with_capacity(sig.inputs.len());
+
+    for (index, input) in sig.inputs.iter().enumerate() {
+        let ty = match input {
+            FnArg::Typed(pat_type) => &pat_type.ty,
+            FnArg::Receiver(receiver) => {
+                return Err(syn::Error::new_spanned(
+                    receiver,
+                    "handler can't have `self` parameter",
+                ))
+            }
+        };
+        if let Type::ImplTrait(_) = **ty {
+            return Err(syn::Error::new_spanned(
+                ty,
+                "handler parameter can't be `impl Trait`, use a concrete type",
+            ));
+        }
+
+        let check_ident = format_ident!("__check_{}_param_{}", ident, index);
+
+        param_checks.push(quote_spanned! { ty.span() =>
+            #[allow(warnings, clippy::all)]
+            fn #check_ident #impl_generics ()
+            where
+                #(#where_predicates,)*
+                #ty: ::telers::extractors::FromEventAndContext<#client> + ::core::marker::Send,
+            {}
+        });
+        tys.push(ty);
+        arg_idents.push(format_ident!("arg{}", index));
+    }
+
+    let output_check_ident = format_ident!("__check_{}_output", ident);
+    let output_ty: Type = match &sig.output {
+        ReturnType::Type(_, ty) => (**ty).clone(),
+        ReturnType::Default => parse_quote! { () },
+    };
+    let output_check = quote_spanned! { sig.output.span() =>
+        #[allow(warnings, clippy::all)]
+        fn #output_check_ident #impl_generics ()
+        where
+            #(#where_predicates,)*
+            #output_ty: ::telers::event::telegram::IntoHandlerResult,
+        {}
+    };
+
+    let future_check_ident = format_ident!("__check_{}_future", ident);
+    let future_check = quote_spanned! { asyncness.span =>
+        #[allow(warnings, clippy::all, unreachable_code)]
+        fn #future_check_ident #impl_generics () #where_clause {
+            fn __check_future_send<F>(_: F)
+            where
+                F: ::core::future::Future + ::core::marker::Send,
+            {}
+
+            __check_future_send(#ident(#({ let arg: #tys = ::core::panic!(); arg }),*));
+        }
+    };
+
+    // Outer function has the same signature, but its parameters are plain identifiers
+    // (parameters of the inner function can be patterns) and it returns `HandlerResult`
+    let mut outer_sig = sig.clone();
+    for (input, arg_ident) in outer_sig.inputs.iter_mut().zip(&arg_idents) {
+        if let FnArg::Typed(PatType { pat, .. }) = input {
+            **pat = Pat::Ident(PatIdent {
+                attrs: vec![],
+                by_ref: None,
+                mutability: None,
+                ident: arg_ident.clone(),
+                subpat: None,
+            });
+        }
+    }
+    outer_sig.output = parse_quote! { -> ::telers::event::telegram::HandlerResult };
+
+    let name = attrs
+        .name
+        .as_ref()
+        .map_or_else(|| ident.to_string(), LitStr::value);
+    let description = match &attrs.description {
+        Some(description) => quote! { ::core::option::Option::Some(#description) },
+        None => quote! { ::core::option::Option::None },
+    };
+    let commands = &attrs.commands;
+    let metadata_doc =
+        format!("Metadata of the `{ident}` handler, which is generated by `#[handler]` attribute.");
+
+    Ok(quote! {
+        #(#fn_attrs)*
+        #vis #outer_sig {
+            #sig #block
+
+            ::telers::event::telegram::IntoHandlerResult::into_handler_result(
+                #ident(#(#arg_idents),*).await,
+            )
+        }
+
+        #[doc = #metadata_doc]
+        #[allow(non_snake_case)]
+        #vis mod #ident {
+            /// Apply it to the handler with `HandlerObject::metadata`
+            pub const METADATA: ::telers::event::telegram::HandlerMetadata =
+                ::telers::event::telegram::HandlerMetadata {
+                    name: ::core::option::Option::Some(#name),
+                    description: #description,
+                    commands: &[#(#commands),*],
+                };
+        }
+
+        const _: () = {
+            #(#param_checks)*
+            #output_check
+            #future_check
+        };
+    })
+}
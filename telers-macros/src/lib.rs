@@ -10,8 +10,10 @@
 pub(crate) mod attrs_parsing;
 pub(crate) mod stream;
 
+mod from_command_args;
 mod from_context;
 mod from_event;
+mod handler;
 
 use proc_macro::TokenStream;
 use quote::{quote, ToTokens};
@@ -26,6 +28,13 @@ use syn::parse::Parse;
 /// * `#[context(description = "...")]` - the description of the type in context. \
 /// This attribute is used only for documentation purposes and perhaps for debugging.
 ///
+/// Struct fields can also be extracted from context by their own keys, if the struct doesn't have `#[context(...)]` attribute.
+/// In this case, each field should have the following attributes:
+/// * `#[context(key = "...")]` - the key by which the field will be extracted from context.
+/// * `#[context(description = "...")]` - the description of the field type in context.
+///
+/// If the field type is `Option<T>`, then value of type `T` is extracted and the field is `None` if there is no value by the key.
+///
 /// Check the examples below to see how to use this macro and what types of deriving are supported.
 ///
 /// ## Whole struct by key in context
@@ -152,6 +161,26 @@ use syn::parse::Parse;
 ///  }
 /// }
 /// ```
+///
+/// ## Struct with fields from different keys in context
+///
+/// This can be useful when you want to extract several values in one handler parameter.
+///
+/// ```rust
+/// use telers_macros::FromContext;
+///
+/// #[derive(FromContext)]
+/// struct Profile {
+///  #[context(key = "user_id")]
+///  user_id: i64,
+///  #[context(key = "language", description = "Language chosen by the user")]
+///  language: Option<String>, // it's `None` if there is no value by the key
+/// }
+///
+/// async fn handler(profile: Profile) {
+///  // ...
+/// }
+/// ```
 #[proc_macro_derive(FromContext, attributes(context))]
 pub fn derive_from_context(item: TokenStream) -> TokenStream {
     expand_with(item, from_context::expand)
@@ -169,8 +198,13 @@ pub fn derive_from_context(item: TokenStream) -> TokenStream {
 /// * `#[event(description = "...")]` - the description of the type. \s
 /// This attribute is used only for documentation purposes.
 ///
+/// * `#[event(path = ...)]` - the path of method calls and field accesses to the value in the update. \
+///   If it's specified, then `TryFrom<&Update>` is implemented for the single-field struct automatically.
+///
 /// "..." it can be either a type, or a type path to one of them:
 /// * `Update` - the main type of the crate, which contains all the information about the event.
+/// * `&Update` - reference to the update. The update is shared between handlers, so it's cloned for each conversion from `Update`,
+///   and conversion from `&Update` can clone only the needed part of it instead.
 ///
 /// Check examples below to see how to use this macro and what types of deriving are supported.
 ///
@@ -236,6 +270,55 @@ pub fn derive_from_context(item: TokenStream) -> TokenStream {
 ///  }
 /// }
 /// ```
+/// Conversion from the reference to the update, which clones only the needed part of the update:
+///
+/// ```rust
+/// use telers_macros::FromEvent;
+/// use telers::{types::{Update, UpdateKind}, errors::ConvertToTypeError};
+///
+/// #[derive(FromEvent)]
+/// #[event(try_from = &Update)]
+/// struct CallbackData(Box<str>);
+///
+/// impl TryFrom<&Update> for CallbackData {
+///  type Error = ConvertToTypeError;
+///
+///  fn try_from(update: &Update) -> Result<Self, Self::Error> {
+///   match &update.kind {
+///    UpdateKind::CallbackQuery(query) => query.data.clone().map(Self),
+///    _ => None,
+///   }
+///   .ok_or_else(|| ConvertToTypeError::new("Update", "CallbackData"))
+///  }
+/// }
+/// ```
+///
+/// ## Single-field struct with the value by the path in `Update`
+///
+/// Each segment of the path is a method call (`chat()`) or a field access (`id`),
+/// which can return a value or `Option` of it. If any of them returns `None`, then the extraction fails with `ConvertToTypeError`.
+/// The value is converted to owned and then into the field type with `Into` trait.
+///
+/// ```rust
+/// use telers_macros::FromEvent;
+/// use telers::types::Update;
+///
+/// #[derive(FromEvent)]
+/// #[event(from = Update, path = chat().id())]
+/// struct ChatId(i64);
+///
+/// #[derive(FromEvent)]
+/// #[event(from = Update, path = from().username)]
+/// struct Username(Box<str>);
+///
+/// #[derive(FromEvent)]
+/// #[event(from = Update, path = message_thread_id())]
+/// struct ThreadId(i64);
+///
+/// async fn handler(ChatId(chat_id): ChatId, username: Option<Username>) {
+///  // ...
+/// }
+/// ```
 /// # Notes
 /// This macros is used in the library to implement `FromEventAndContext` for types that impl `From` for `Update`,
 /// but you can use it for your own types.
@@ -244,6 +327,104 @@ pub fn derive_from_event(item: TokenStream) -> TokenStream {
     expand_with(item, from_event::expand)
 }
 
+/// Derive an implementation of `FromCommandArgs` for the given struct,
+/// so it can be used in `CommandArgs` extractor.
+///
+/// Fields are parsed from the command arguments in order of declaration,
+/// so each field type should implement `FromCommandArgs`.
+///
+/// ```rust
+/// use telers::extractors::{CommandArgs, FromCommandArgs};
+///
+/// #[derive(FromCommandArgs)]
+/// struct Price {
+///  coin: String,
+///  amount: u32,
+///  // Optional argument, if there are remaining arguments
+///  currency: Option<String>,
+/// }
+///
+/// async fn handler(CommandArgs(price): CommandArgs<Price>) {
+///  // ...
+/// }
+/// ```
+#[proc_macro_derive(FromCommandArgs)]
+pub fn derive_from_command_args(item: TokenStream) -> TokenStream {
+    expand_with(item, from_command_args::expand)
+}
+
+/// Wrap the handler and check its signature at compile time.
+///
+/// Without this attribute, if some handler parameter doesn't implement `FromEventAndContext`,
+/// the error points to the place where the handler is registered and says that the function doesn't implement `Handler`,
+/// so it's hard to find the reason. This attribute generates checks for each parameter, return type and future of the handler,
+/// so the error points to the wrong one.
+///
+/// The handler can return `()`, `FlowControl` or `Result` with them and any error, which can be converted into `HandlerError`
+/// (for example, `anyhow::Error`), so `?` operator can be used with `anyhow::Result`.
+/// The output is converted into `HandlerResult` by `IntoHandlerResult` trait, and `()` is converted into `FlowControl::Continue`.
+///
+/// The attribute also generates the module with the same name as the handler with `METADATA` constant,
+/// which can be applied to the handler with `HandlerObject::metadata` to set its name, description and commands,
+/// which are used by the router introspection and the bot command menu.
+///
+/// This macro supports the following attributes:
+/// * `#[handler(client = ...)]` - the client type, for which parameters are checked. \
+///   If it's empty, then we use `DefaultSession` type as client type.
+/// * `#[handler(name = "...")]` - the name of the handler. \
+///   If it's empty, then we use the function name as handler name.
+/// * `#[handler(description = "...")]` - the description of the handler commands in the bot command menu.
+/// * `#[handler(command = "...")]` - the command, which is handled by the handler. \
+///   Can be specified multiple times. `Command` filter with all the commands is added to the handler with the metadata.
+///
+/// ```rust
+/// use telers::{
+///     event::{telegram::HandlerResult, FlowControl},
+///     handler,
+///     types::Message,
+///     Bot, Router,
+/// };
+///
+/// #[handler]
+/// async fn echo(bot: Bot, message: Message) -> HandlerResult {
+///     // ...
+///     Ok(FlowControl::Continue)
+/// }
+///
+/// #[handler(description = "Show the price", command = "price")]
+/// async fn price(message: Message) -> anyhow::Result<()> {
+///     let price: u32 = message.text().unwrap_or_default().parse()?;
+///     // ...
+///     Ok(())
+/// }
+///
+/// let mut router = Router::new("main");
+/// router.message.register(echo);
+/// router.message.register(price).metadata(price::METADATA);
+/// ```
+///
+/// If the parameter can't be extracted, the error points to its type:
+///
+/// ```rust,compile_fail
+/// use telers::handler;
+///
+/// struct NotExtractor;
+///
+/// #[handler]
+/// async fn echo(_: NotExtractor) {
+/// //               ^^^^^^^^^^^^ the trait `FromEventAndContext` is not implemented for `NotExtractor`
+/// }
+/// ```
+/// # Notes
+/// Only free functions are supported
+#[proc_macro_attribute]
+pub fn handler(attr: TokenStream, item: TokenStream) -> TokenStream {
+    expand(
+        syn::parse::<handler::HandlerAttrs>(attr)
+            .and_then(|attrs| handler::expand(&attrs, syn::parse(item)?)),
+    )
+}
+
 fn expand_with<F, I, K>(input: TokenStream, f: F) -> TokenStream
 where
     F: FnOnce(I) -> syn::Result<K>,
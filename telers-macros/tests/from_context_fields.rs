@@ -0,0 +1,109 @@
+use std::{any::Any, sync::Arc};
+use telers::{
+    client::Reqwest,
+    extractors::FromEventAndContext,
+    types::{Message, Update, UpdateKind},
+    Bot, Context,
+};
+use telers_macros::FromContext;
+
+#[derive(FromContext)]
+struct Named {
+    #[context(key = "id")]
+    id: i64,
+    #[context(key = "name", description = "Name of the user")]
+    name: String,
+    #[context(key = "language")]
+    language: Option<String>,
+}
+
+#[derive(FromContext)]
+struct Unnamed(
+    #[context(key = "id")] i64,
+    #[context(key = "language")] Option<String>,
+);
+
+#[derive(FromContext)]
+struct Generic<T> {
+    #[context(key = "value")]
+    value: T,
+    #[context(key = "optional_value")]
+    optional_value: Option<T>,
+}
+
+fn extract<T: FromEventAndContext<Reqwest>>(context: Context) -> Result<T, T::Error> {
+    T::extract(
+        Arc::new(Bot::default()),
+        Arc::new(Update {
+            id: 0,
+            kind: UpdateKind::Message(Message::default()),
+        }),
+        Arc::new(context),
+    )
+}
+
+fn context(values: Vec<(&'static str, Box<dyn Any + Send + Sync>)>) -> Context {
+    values.into_iter().collect()
+}
+
+#[test]
+fn extract_named() {
+    let named = extract::<Named>(context(vec![
+        ("id", Box::new(1_i64)),
+        ("name", Box::new("name".to_owned())),
+        ("language", Box::new("en".to_owned())),
+    ]))
+    .unwrap();
+
+    assert_eq!(named.id, 1);
+    assert_eq!(named.name, "name");
+    assert_eq!(named.language.as_deref(), Some("en"));
+
+    // Optional field is allowed to be missing
+    let named = extract::<Named>(context(vec![
+        ("id", Box::new(1_i64)),
+        ("name", Box::new("name".to_owned())),
+    ]))
+    .unwrap();
+
+    assert_eq!(named.language, None);
+}
+
+#[test]
+fn extract_named_errors() {
+    // Required field is missing
+    assert!(extract::<Named>(context(vec![("id", Box::new(1_i64))])).is_err());
+    // Required field has wrong type
+    assert!(extract::<Named>(context(vec![
+        ("id", Box::new(1_i32)),
+        ("name", Box::new("name".to_owned())),
+    ]))
+    .is_err());
+    // Optional field has wrong type
+    assert!(extract::<Named>(context(vec![
+        ("id", Box::new(1_i64)),
+        ("name", Box::new("name".to_owned())),
+        ("language", Box::new(1_i64)),
+    ]))
+    .is_err());
+}
+
+#[test]
+fn extract_unnamed() {
+    let Unnamed(id, language) = extract(context(vec![("id", Box::new(1_i64))])).unwrap();
+
+    assert_eq!(id, 1);
+    assert_eq!(language, None);
+}
+
+#[test]
+fn extract_generic() {
+    let generic = extract::<Generic<i32>>(context(vec![
+        ("value", Box::new(1_i32)),
+        ("optional_value", Box::new(2_i32)),
+    ]))
+    .unwrap();
+
+    assert_eq!(generic.value, 1);
+    assert_eq!(generic.optional_value, Some(2));
+}
@@ -0,0 +1,66 @@
+use telers::{
+    errors::ConvertToTypeError,
+    types::{Message, MessageText, Update, UpdateKind, User},
+};
+use telers_macros::FromEvent;
+
+#[derive(FromEvent)]
+#[event(from = Update, path = chat().id())]
+struct ChatId(i64);
+
+#[derive(FromEvent)]
+#[event(from = Update, path = from().id)]
+struct UserId {
+    id: i64,
+}
+
+#[derive(FromEvent)]
+#[event(try_from = &Update, path = from().username)]
+struct Username(Box<str>);
+
+#[derive(FromEvent)]
+#[event(from = Update, path = text())]
+struct Text(String);
+
+#[derive(FromEvent)]
+#[event(from = Update, path = message_thread_id())]
+struct ThreadId(i64);
+
+fn update(message: Message) -> Update {
+    Update {
+        id: 0,
+        kind: UpdateKind::Message(message),
+    }
+}
+
+#[test]
+fn convert() {
+    let update = update(Message::Text(Box::new(MessageText {
+        text: "text".into(),
+        thread_id: Some(2),
+        from: Some(User {
+            id: 1,
+            username: Some("username".into()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    })));
+
+    assert_eq!(ChatId::try_from(&update).unwrap().0, 0);
+    assert_eq!(UserId::try_from(&update).unwrap().id, 1);
+    assert_eq!(&*Username::try_from(&update).unwrap().0, "username");
+    assert_eq!(Text::try_from(&update).unwrap().0, "text");
+    assert_eq!(ThreadId::try_from(&update).unwrap().0, 2);
+}
+
+#[test]
+fn convert_missing() {
+    let update = update(Message::default());
+
+    assert!(UserId::try_from(&update).is_err());
+    assert!(Username::try_from(&update).is_err());
+    assert!(matches!(
+        ThreadId::try_from(&update),
+        Err(ConvertToTypeError { .. })
+    ));
+}
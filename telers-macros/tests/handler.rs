@@ -0,0 +1,141 @@
+use telers::{
+    client::Reqwest,
+    event::{
+        telegram::{HandlerMetadata, HandlerResult},
+        FlowControl,
+    },
+    extractors::FromEventAndContext,
+    types::{Message, Update},
+    Bot, Context, Router,
+};
+use telers_macros::{handler, FromContext};
+
+#[handler]
+async fn no_args() -> HandlerResult {
+    Ok(FlowControl::Continue)
+}
+
+#[handler]
+async fn single_arg(_: Message) -> HandlerResult {
+    Ok(FlowControl::Continue)
+}
+
+#[handler]
+async fn multi_args(
+    _bot: Bot,
+    _update: Update,
+    _message: Option<Message>,
+    _context: std::sync::Arc<Context>,
+) -> HandlerResult {
+    Ok(FlowControl::Continue)
+}
+
+#[handler(client = Reqwest)]
+async fn explicit_client(_: Bot<Reqwest>) -> HandlerResult {
+    Ok(FlowControl::Continue)
+}
+
+#[handler(client = Client)]
+async fn generic_client<Client>(_: Bot<Client>) -> HandlerResult
+where
+    Client: Clone + Send + Sync + 'static,
+{
+    Ok(FlowControl::Continue)
+}
+
+#[handler]
+async fn generic_arg<T>(_: T) -> HandlerResult
+where
+    T: FromEventAndContext + Send,
+{
+    Ok(FlowControl::Continue)
+}
+
+#[handler]
+async fn no_output(_: Message) {}
+
+#[handler]
+async fn event_return_output() -> FlowControl {
+    FlowControl::SkipHandler
+}
+
+#[handler]
+async fn anyhow_output(_: Message) -> anyhow::Result<()> {
+    let _: i32 = "1".parse()?;
+    Ok(())
+}
+
+#[handler]
+async fn pattern_args(Extracted(value): Extracted) -> HandlerResult {
+    assert_eq!(value, 1);
+    Ok(FlowControl::Continue)
+}
+
+#[handler(
+    name = "start_handler",
+    description = "Start the bot",
+    command = "start",
+    command = "help"
+)]
+async fn start() -> HandlerResult {
+    Ok(FlowControl::Continue)
+}
+
+#[derive(FromContext, Clone)]
+#[context(key = "extracted")]
+struct Extracted(i64);
+
+#[test]
+fn handlers_are_registered() {
+    let mut router = Router::<Reqwest>::new("main");
+    router.message.register(no_args);
+    router.message.register(single_arg);
+    router.message.register(multi_args);
+    router.message.register(explicit_client);
+    router.message.register(generic_client::<Reqwest>);
+    router.message.register(generic_arg::<Message>);
+    router.message.register(no_output);
+    router.message.register(event_return_output);
+    router.message.register(anyhow_output);
+    router.message.register(pattern_args);
+}
+
+#[test]
+fn metadata() {
+    assert_eq!(
+        no_args::METADATA,
+        HandlerMetadata {
+            name: Some("no_args"),
+            description: None,
+            commands: &[],
+        }
+    );
+    assert_eq!(
+        start::METADATA,
+        HandlerMetadata {
+            name: Some("start_handler"),
+            description: Some("Start the bot"),
+            commands: &["start", "help"],
+        }
+    );
+
+    let mut router = Router::<Reqwest>::new("main");
+    let handler = router.message.register(start).metadata(start::METADATA);
+
+    assert_eq!(handler.handler_name(), Some("start_handler"));
+    assert_eq!(handler.command_description(), Some("Start the bot"));
+    assert_eq!(handler.menu_commands().len(), 2);
+}
+
+#[tokio::test]
+async fn output_conversion() {
+    assert!(matches!(
+        no_output(Message::default()).await,
+        Ok(FlowControl::Continue)
+    ));
+    assert!(matches!(
+        event_return_output().await,
+        Ok(FlowControl::SkipHandler)
+    ));
+    assert!(anyhow_output(Message::default()).await.is_ok());
+}
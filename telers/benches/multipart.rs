@@ -0,0 +1,115 @@
+//! Benchmarks of file uploads with the default [`Reqwest`] session.
+//!
+//! Requests are sent to the local HTTP server, which reads the whole body and responds with a message,
+//! so the benchmarks measure building of multipart forms and streaming of files without network latency.
+
+use bytes::Bytes;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use http_body_util::{BodyExt as _, Full};
+use hyper::{body::Incoming, server::conn::http1, service::service_fn, Request, Response};
+use hyper_util::rt::TokioIo;
+use std::{borrow::Cow, convert::Infallible, io::Write as _, net::SocketAddr};
+use telers::{
+    client::{
+        telegram::{APIServer, BareFilesPathWrapper},
+        Reqwest,
+    },
+    methods::SendDocument,
+    types::InputFile,
+    Bot,
+};
+use tokio::{
+    net::TcpListener,
+    runtime::{Builder, Runtime},
+};
+
+const TOKEN: &str = "1234567890:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA";
+const RESPONSE: &str = r#"{
+    "ok": true,
+    "result": {
+        "message_id": 1,
+        "date": 0,
+        "chat": {"id": 1, "type": "private", "first_name": "test"},
+        "document": {"file_id": "id", "file_unique_id": "id"}
+    }
+}"#;
+const SIZES: [usize; 3] = [64 * 1024, 1024 * 1024, 16 * 1024 * 1024];
+
+fn runtime() -> Runtime {
+    Builder::new_current_thread().enable_all().build().unwrap()
+}
+
+async fn run_server() -> SocketAddr {
+    let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0)))
+        .await
+        .unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+
+            let service = service_fn(|request: Request<Incoming>| async move {
+                let _ = request.into_body().collect().await;
+
+                Ok::<_, Infallible>(Response::new(Full::new(Bytes::from_static(
+                    RESPONSE.as_bytes(),
+                ))))
+            });
+
+            tokio::spawn(http1::Builder::new().serve_connection(TokioIo::new(stream), service));
+        }
+    });
+
+    addr
+}
+
+fn bot(addr: SocketAddr) -> Bot {
+    let api = APIServer::new(
+        &format!("http://{addr}/bot{{token}}/{{method_name}}"),
+        &format!("http://{addr}/file/bot{{token}}/{{path}}"),
+        false,
+        BareFilesPathWrapper,
+    );
+
+    Bot::with_client(TOKEN, Reqwest::default().with_api_server(Cow::Owned(api)))
+}
+
+fn upload(c: &mut Criterion) {
+    let runtime = runtime();
+    let bot = bot(runtime.block_on(run_server()));
+
+    let mut group = c.benchmark_group("upload");
+
+    for size in SIZES {
+        let content = Bytes::from(vec![0_u8; size]);
+
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(&content).unwrap();
+
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_with_input(
+            BenchmarkId::new("buffered", size),
+            &content,
+            |b, content| {
+                b.to_async(&runtime).iter(|| async {
+                    bot.send(SendDocument::new(1, InputFile::buffered(content.clone())))
+                        .await
+                        .unwrap()
+                });
+            },
+        );
+        group.bench_with_input(BenchmarkId::new("fs", size), file.path(), |b, path| {
+            b.to_async(&runtime).iter(|| async {
+                bot.send(SendDocument::new(1, InputFile::fs(path)))
+                    .await
+                    .unwrap()
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, upload);
+criterion_main!(benches);
@@ -0,0 +1,142 @@
+//! Benchmarks of update routing.
+//!
+//! Updates are fed to the dispatcher with the default middlewares,
+//! so the benchmarks measure propagation of the update through routers, observers, filters and extractors
+//! without network requests.
+//!
+//! Run with `cargo bench -p telers --bench routing`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::sync::Arc;
+use telers::{
+    client::Reqwest,
+    context::Context,
+    event::{service::ToServiceProvider as _, FlowControl},
+    filters::Command,
+    types::{Message, Update},
+    Bot, Dispatcher, Router,
+};
+use tokio::runtime::{Builder, Runtime};
+
+const TOKEN: &str = "1234567890:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA";
+const COMMANDS: [&str; 16] = [
+    "cmd0", "cmd1", "cmd2", "cmd3", "cmd4", "cmd5", "cmd6", "cmd7", "cmd8", "cmd9", "cmd10",
+    "cmd11", "cmd12", "cmd13", "cmd14", "cmd15",
+];
+const UPDATE: &str = r#"{
+    "update_id": 10000,
+    "message": {
+        "message_id": 1365,
+        "from": {"id": 1111111, "is_bot": false, "first_name": "Test", "username": "test_user"},
+        "chat": {"id": 1111111, "type": "private", "first_name": "Test"},
+        "date": 1441645532,
+        "text": "/cmd15 payload"
+    }
+}"#;
+
+fn runtime() -> Runtime {
+    Builder::new_current_thread().enable_all().build().unwrap()
+}
+
+/// Creates the router with the handlers for the commands, so only the last handler passes the filters
+fn router(handlers: usize) -> Router<Reqwest> {
+    let mut router = Router::new("main");
+
+    for command in &COMMANDS[COMMANDS.len() - handlers..] {
+        router
+            .message
+            .register(|_: Message| async { Ok(FlowControl::Continue) })
+            .filter(Command::one(*command));
+    }
+
+    router
+}
+
+/// Filter, which reads the values, which are put to the context by the dispatcher
+fn context_filter(
+    _bot: &Bot<Reqwest>,
+    _update: &Update,
+    context: &Context,
+) -> std::future::Ready<bool> {
+    std::future::ready(context.contains_key("request_id") && context.get("span").is_some())
+}
+
+/// Creates the router with the handlers, each of which has the filters reading the context,
+/// so only the last handler passes the command filter
+fn context_router(filters: usize) -> Router<Reqwest> {
+    let mut router = Router::new("main");
+
+    for command in COMMANDS {
+        let handler = router
+            .message
+            .register(|_: Message| async { Ok(FlowControl::Continue) });
+
+        for _ in 0..filters {
+            handler.filter(context_filter);
+        }
+        handler.filter(Command::one(command));
+    }
+
+    router
+}
+
+fn routing(c: &mut Criterion) {
+    let runtime = runtime();
+    let bot = Arc::new(Bot::new(TOKEN));
+    let update = Arc::new(serde_json::from_str::<Update>(UPDATE).unwrap());
+
+    let mut group = c.benchmark_group("routing");
+
+    for handlers in [1, 4, 16] {
+        let dispatcher = Dispatcher::builder()
+            .main_router(router(handlers))
+            .build()
+            .to_service_provider_default()
+            .unwrap();
+
+        group.bench_with_input(
+            BenchmarkId::new("handlers", handlers),
+            &dispatcher,
+            |b, dispatcher| {
+                b.to_async(&runtime).iter(|| {
+                    Arc::clone(dispatcher).feed_update(Arc::clone(&bot), Arc::clone(&update))
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn context(c: &mut Criterion) {
+    let runtime = runtime();
+    let bot = Arc::new(Bot::new(TOKEN));
+    let update = Arc::new(serde_json::from_str::<Update>(UPDATE).unwrap());
+
+    let mut group = c.benchmark_group("context");
+
+    group.bench_function("new", |b| b.iter(Context::new));
+
+    for filters in [1, 4, 16] {
+        let dispatcher = Dispatcher::builder()
+            .main_router(context_router(filters))
+            .build()
+            .to_service_provider_default()
+            .unwrap();
+
+        group.bench_with_input(
+            BenchmarkId::new("filters", filters),
+            &dispatcher,
+            |b, dispatcher| {
+                b.to_async(&runtime).iter(|| {
+                    Arc::clone(dispatcher).feed_update(Arc::clone(&bot), Arc::clone(&update))
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, routing, context);
+criterion_main!(benches);
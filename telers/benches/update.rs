@@ -0,0 +1,119 @@
+//! Benchmarks of update deserialization.
+//!
+//! Updates are deserialized from the JSON payloads in the same way as they come from `getUpdates` and webhooks,
+//! so the benchmarks measure throughput of the [`Update`] and [`Message`](telers::types::Message) deserializers.
+//!
+//! Run with `cargo bench -p telers --bench update`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use telers::types::Update;
+
+const TEXT_MESSAGE: &str = r#"{
+    "update_id": 10000,
+    "message": {
+        "message_id": 1365,
+        "from": {"id": 1111111, "is_bot": false, "first_name": "Test", "last_name": "User", "username": "test_user", "language_code": "en"},
+        "chat": {"id": 1111111, "type": "private", "first_name": "Test", "last_name": "User", "username": "test_user"},
+        "date": 1441645532,
+        "text": "/start payload"
+    }
+}"#;
+const PHOTO_MESSAGE: &str = r#"{
+    "update_id": 10001,
+    "message": {
+        "message_id": 1366,
+        "from": {"id": 1111111, "is_bot": false, "first_name": "Test", "username": "test_user"},
+        "chat": {"id": -1001234567890, "type": "supergroup", "title": "Test group", "is_forum": true},
+        "message_thread_id": 3,
+        "is_topic_message": true,
+        "date": 1441645532,
+        "photo": [
+            {"file_id": "AgACAgIAAxkBAAIBH2Vx", "file_unique_id": "AQADwdIxGz", "file_size": 1234, "width": 90, "height": 90},
+            {"file_id": "AgACAgIAAxkBAAIBH2Vy", "file_unique_id": "AQADwdIxG3", "file_size": 12345, "width": 320, "height": 320},
+            {"file_id": "AgACAgIAAxkBAAIBH2Vz", "file_unique_id": "AQADwdIxG4", "file_size": 123456, "width": 800, "height": 800}
+        ],
+        "caption": "Look at this https://example.com @test_user",
+        "caption_entities": [
+            {"type": "url", "offset": 13, "length": 19},
+            {"type": "mention", "offset": 33, "length": 10}
+        ]
+    }
+}"#;
+const CALLBACK_QUERY: &str = r#"{
+    "update_id": 10002,
+    "callback_query": {
+        "id": "4382bfdwdsb323b2d9",
+        "from": {"id": 1111111, "is_bot": false, "first_name": "Test", "username": "test_user"},
+        "message": {
+            "message_id": 1367,
+            "from": {"id": 2222222, "is_bot": true, "first_name": "Bot", "username": "test_bot"},
+            "chat": {"id": 1111111, "type": "private", "first_name": "Test"},
+            "date": 1441645532,
+            "text": "Choose an option",
+            "reply_markup": {"inline_keyboard": [[{"text": "Yes", "callback_data": "yes"}, {"text": "No", "callback_data": "no"}]]}
+        },
+        "chat_instance": "-1234567890",
+        "data": "yes"
+    }
+}"#;
+const INLINE_QUERY: &str = r#"{
+    "update_id": 10003,
+    "inline_query": {
+        "id": "134567890097",
+        "from": {"id": 1111111, "is_bot": false, "first_name": "Test", "username": "test_user"},
+        "query": "inline query",
+        "offset": ""
+    }
+}"#;
+
+fn updates() -> [(&'static str, &'static str); 4] {
+    [
+        ("text_message", TEXT_MESSAGE),
+        ("photo_message", PHOTO_MESSAGE),
+        ("callback_query", CALLBACK_QUERY),
+        ("inline_query", INLINE_QUERY),
+    ]
+}
+
+fn deserialize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("deserialize");
+
+    for (name, content) in updates() {
+        group.throughput(Throughput::Bytes(content.len() as u64));
+        group.bench_with_input(BenchmarkId::new("str", name), content, |b, content| {
+            b.iter(|| serde_json::from_str::<Update>(content).unwrap());
+        });
+        group.bench_with_input(
+            BenchmarkId::new("slice", name),
+            content.as_bytes(),
+            |b, content| {
+                b.iter(|| serde_json::from_slice::<Update>(content).unwrap());
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn deserialize_batch(c: &mut Criterion) {
+    let content = format!(
+        "[{}]",
+        updates()
+            .iter()
+            .map(|(_, content)| *content)
+            .cycle()
+            .take(100)
+            .collect::<Vec<_>>()
+            .join(",")
+    );
+
+    let mut group = c.benchmark_group("deserialize_batch");
+    group.throughput(Throughput::Bytes(content.len() as u64));
+    group.bench_function("100", |b| {
+        b.iter(|| serde_json::from_str::<Box<[Update]>>(&content).unwrap());
+    });
+    group.finish();
+}
+
+criterion_group!(benches, deserialize, deserialize_batch);
+criterion_main!(benches);
@@ -0,0 +1,24 @@
+//! Fuzz target of [`Chat`] deserialization.
+//!
+//! `Chat` is tagged by the `type` field, so the target checks that mutated chats are either rejected
+//! or deserialized into the variant with accessible fields.
+//!
+//! Run with `cargo +nightly fuzz run chat corpus/chat seeds/chat` from the `telers/fuzz` directory.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use telers::types::Chat;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(chat) = serde_json::from_slice::<Chat>(data) else {
+        return;
+    };
+
+    let _ = chat.id();
+    let _ = chat.username();
+    let _ = chat.title();
+    let _ = chat.first_name();
+    let _ = chat.last_name();
+    let _ = chat.is_forum();
+});
@@ -0,0 +1,31 @@
+//! Fuzz target of [`Message`] deserialization.
+//!
+//! `Message` is an untagged enum, so the target checks that the content type of the message can always be determined
+//! and that accessors of the deserialized message never panic.
+//!
+//! Run with `cargo +nightly fuzz run message corpus/message seeds/message` from the `telers/fuzz` directory.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use telers::{enums::ContentType, types::Message};
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(message) = serde_json::from_slice::<Message>(data) else {
+        return;
+    };
+
+    let _ = ContentType::from(&message);
+    let _ = message.text_or_caption();
+    let _ = message.from_id();
+    let _ = message.sender_chat_id();
+    let _ = message.chat().id();
+    let _ = message.thread_id();
+    let _ = message.largest_photo();
+    let _ = message.smallest_photo_by_file_size();
+    let _ = message.paid_media_star_count();
+
+    if let Some(reply_to_message) = message.reply_to_message() {
+        let _ = ContentType::from(reply_to_message);
+    }
+});
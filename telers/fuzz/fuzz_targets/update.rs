@@ -0,0 +1,31 @@
+//! Fuzz target of [`Update`] deserialization.
+//!
+//! Each input is deserialized as the update from `getUpdates` or webhook.
+//! Errors are expected for invalid JSON, but deserialization and accessors of the deserialized update must never panic.
+//!
+//! Run with `cargo +nightly fuzz run update corpus/update seeds/update` from the `telers/fuzz` directory.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use telers::{enums::UpdateType, types::Update};
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(update) = serde_json::from_slice::<Update>(data) else {
+        return;
+    };
+
+    let _ = UpdateType::from(&update);
+    let _ = update.text_or_caption();
+    let _ = update.from_id();
+    let _ = update.chat_id();
+    let _ = update.sender_chat_id();
+    let _ = update.message_thread_id();
+    let _ = update.date();
+
+    if let Some(chat) = update.chat() {
+        let _ = chat.username();
+        let _ = chat.title();
+        let _ = chat.is_forum();
+    }
+});
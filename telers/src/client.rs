@@ -3,14 +3,36 @@
 //!
 //! Components are:
 //! - [`bot`] module with the main entry point for the library
+//! - [`defaults`] module with default properties of the bot, which are applied to all requests
 //! - [`session`] module with components for sending requests
+//! - [`outbox`] module with durable queue for outgoing requests (with `fsm` feature)
+//! - [`scheduler`] module with components for delayed sending of requests (with `fsm` and `tokio-runtime` features)
 //! - [`telegram`] module with configuration of the Telegram Bot API
+//! - [`webhook`] module with the manager, which keeps the bot webhook in sync with the desired configuration
 //!
 //! Check each submodule for more information.
 
 pub mod bot;
+pub mod bulk;
+pub mod defaults;
+#[cfg(feature = "fsm")]
+pub mod outbox;
+#[cfg(feature = "fsm")]
+mod persisted;
+#[cfg(all(feature = "fsm", feature = "tokio-runtime"))]
+pub mod scheduler;
 pub mod session;
 pub mod telegram;
+pub mod webhook;
 
-pub use bot::Bot;
-pub use session::{Reqwest, Session};
+pub use bot::{Bot, Builder as BotBuilder};
+pub use bulk::BulkResult;
+pub use defaults::Defaults;
+#[cfg(feature = "fsm")]
+pub use outbox::Outbox;
+#[cfg(all(feature = "fsm", feature = "tokio-runtime"))]
+pub use scheduler::Scheduler;
+#[cfg(feature = "reqwest")]
+pub use session::Reqwest;
+pub use session::{DefaultSession, Session};
+pub use webhook::{SecretTokens, WebhookManager};
@@ -7,10 +7,11 @@
 //!
 //! # Notes
 //!
-//! This structure is cheap to clone, because it contains only [`String`], [`i64`] fields and a client.
+//! This structure is cheap to clone, because it contains only [`String`], [`i64`] fields, cached bot information in [`Arc`] and a client.
 //! Default client is [`Reqwest`], which also is cheap to clone.
 //!
-//! You can use custom client by using [`Bot::with_client`] method.
+//! You can use custom client by using [`Bot::with_client`] method or [`Bot::builder`],
+//! which also allows to wrap the client into [session layers](crate::client::session::layer) (logging, retries, etc.).
 //!
 //! # Examples
 //! ```rust
@@ -24,35 +25,84 @@
 //! }
 //! ```
 //!
-//! You also can use [`Bot::send_with_timeout`] method to send requests with timeout:
+//! You also can use [`Bot::send_with_timeout`] method or [`TelegramMethod::with_timeout`] to send requests with timeout:
 //!
 //! ```rust
-//! use telers::{Bot, methods::SendMessage};
+//! use telers::{Bot, methods::{SendMessage, TelegramMethod as _}};
+//! use std::time::Duration;
 //!
 //! async fn call_method(bot: Bot) {
 //!     let chat_id = 1;
 //!     let text = "Hello, world!";
-//!     let timeout = 10.0; // 10 seconds
+//!     let timeout = Duration::from_secs(10);
 //!
 //!     let _ = bot.send_with_timeout(SendMessage::new(chat_id, text), timeout).await;
+//!     // or
+//!     let _ = bot.send(SendMessage::new(chat_id, text).with_timeout(timeout)).await;
 //! }
 //! ```
 //!
+//! Default timeout for all requests of the bot can be set with [`Builder::request_timeout`] method.
+//! Long polling [`GetUpdates`] requests use timeout, which is derived from the polling timeout, so they aren't cancelled too early.
+//!
+//! Requests can be sent later in the background task with [`Bot::send_later`] and [`Bot::send_after`] methods
+//! (see [`scheduler module`](crate::client::scheduler) for more information).
+//!
+//! Bot information (the result of [`GetMe`] method) is often required in handlers, for example, to check the bot username in commands.
+//! You can use [`Bot::get_me`] method, which sends [`GetMe`] request only once and caches the result,
+//! and [`Bot::refresh_me`] method to update cached information on demand.
+//! Cached information can be received without sending requests with [`Bot::me`] method or [`Me`] extractor in handlers.
+//! Dispatcher loads bot information automatically when polling is started.
+//!
+//! In multi-bot setups, bots can be tagged with [`Bot::with_tag`] or [`Builder::tag`] methods,
+//! so routers can be bound to specific bots with [`ForBot`] filter and shared handler code can branch on
+//! [`BotId`] and [`BotTag`] extractors.
+//!
 //! More production examples can be found in [`examples`] directory.
 //!
 //! [`examples`]: https://github.com/Desiders/telers/tree/dev-1.x/examples
 //! [`methods`]: crate::methods
+//! [`ForBot`]: crate::filters::ForBot
+//! [`BotId`]: crate::extractors::BotId
+//! [`BotTag`]: crate::extractors::BotTag
+//! [`GetMe`]: crate::methods::GetMe
+//! [`GetUpdates`]: crate::methods::GetUpdates
+//! [`Me`]: crate::extractors::Me
 
-use super::{session::base::Session, Reqwest};
+#[cfg(all(feature = "fsm", feature = "tokio-runtime"))]
+use super::scheduler::{self, Handle};
+use super::{
+    bulk::BulkResult,
+    defaults::Defaults,
+    session::{
+        base::{ResultStream, Session},
+        layer::SessionLayer,
+        DefaultSession,
+    },
+};
+#[cfg(feature = "reqwest")]
+use super::{session::reqwest::ClientOptions, Reqwest};
 
-use crate::{errors::SessionErrorKind, methods::TelegramMethod, utils::token};
+use crate::{
+    errors::SessionErrorKind,
+    methods::{CopyMessages, ForwardMessages, GetMe, SendMessage, TelegramMethod},
+    types::{LinkPreviewOptions, Message, User},
+    utils::token,
+};
 
-use std::fmt::{self, Debug, Display, Formatter};
+#[cfg(all(feature = "fsm", feature = "tokio-runtime"))]
+use std::time::SystemTime;
+use std::{
+    fmt::{self, Debug, Display, Formatter},
+    sync::{Arc, RwLock},
+    time::Duration,
+};
 use tracing::instrument;
 
 /// Represents a bot with its token and ID, also contains client for sending requests to Telegram API.
 /// # Notes
-/// This structure is cheap to clone, because it contains only [`String`], [`i64`] fields and a client.
+/// This structure is cheap to clone, because it contains only [`String`], [`i64`] fields, cached bot information in [`Arc`] and a client.
+/// Clones share the same cached bot information.
 ///
 /// Default client is [`Reqwest`], which also is cheap to clone.
 ///
@@ -60,17 +110,26 @@ use tracing::instrument;
 ///
 /// Check [module docs](crate::client::bot) for examples.
 #[derive(Clone, Default)]
-pub struct Bot<Client: ?Sized = Reqwest> {
+pub struct Bot<Client: ?Sized = DefaultSession> {
     /// Bot token, which is used to receive updates and send requests to the Telegram API
     pub token: String,
     /// Bot token, which is used in `Debug` implementation for privacy
     pub hidden_token: String,
     /// Bot id, extracted from the token
     pub bot_id: i64,
+    /// Cached bot information, which is shared between clones of the bot
+    me: Arc<RwLock<Option<Arc<User>>>>,
+    /// Default properties, which are applied to all requests, shared between clones of the bot
+    defaults: Arc<Defaults>,
+    /// Default timeout of the requests, which is used if the method doesn't require a specific timeout
+    request_timeout: Option<Duration>,
+    /// Tag of the bot, which is used to distinguish bots in multi-bot setups
+    tag: Option<Arc<str>>,
     /// Client for sending requests to Telegram API
     client: Client,
 }
 
+#[cfg(feature = "reqwest")]
 impl Bot<Reqwest> {
     /// # Panics
     /// Panics if the token is invalid
@@ -96,9 +155,244 @@ impl<Client> Bot<Client> {
             token,
             hidden_token,
             bot_id,
+            me: Arc::default(),
+            defaults: Arc::default(),
+            request_timeout: None,
+            tag: None,
             client,
         }
     }
+
+    /// Set default properties, which are applied to all requests.
+    /// Check [`defaults module`](crate::client::defaults) for more information.
+    #[must_use]
+    pub fn with_defaults(self, defaults: Defaults) -> Self {
+        Self {
+            defaults: Arc::new(defaults),
+            ..self
+        }
+    }
+
+    /// Set default timeout of the requests, which is used instead of the client timeout.
+    /// Check [`Builder::request_timeout`] for more information.
+    #[must_use]
+    pub fn with_request_timeout(self, request_timeout: Option<Duration>) -> Self {
+        Self {
+            request_timeout,
+            ..self
+        }
+    }
+
+    /// Set tag of the bot, which is used to distinguish bots in multi-bot setups,
+    /// for example, to bind routers to the bot with [`ForBot`] filter or to branch handler code with [`BotTag`] extractor.
+    ///
+    /// [`ForBot`]: crate::filters::ForBot
+    /// [`BotTag`]: crate::extractors::BotTag
+    #[must_use]
+    pub fn with_tag(self, tag: impl Into<Arc<str>>) -> Self {
+        Self {
+            tag: Some(tag.into()),
+            ..self
+        }
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl Bot<Reqwest> {
+    /// Creates a new [`Builder`] for the bot with the token and default [`Reqwest`] client
+    #[must_use]
+    pub fn builder(token: impl Into<String>) -> Builder<Reqwest> {
+        Builder::new(token)
+    }
+}
+
+impl<Client: ?Sized> Bot<Client> {
+    /// Gets cached bot information without sending requests
+    /// # Returns
+    /// `None` if bot information isn't loaded yet by [`Bot::get_me`] or [`Bot::refresh_me`] methods and isn't set by [`Bot::set_me`] method
+    #[must_use]
+    pub fn me(&self) -> Option<Arc<User>> {
+        self.me.read().unwrap().clone()
+    }
+
+    /// Sets cached bot information.
+    /// Usually you don't need to use this method, because bot information is loaded by [`Bot::get_me`] and [`Bot::refresh_me`] methods,
+    /// but it can be useful for testing.
+    pub fn set_me(&self, user: impl Into<Arc<User>>) {
+        *self.me.write().unwrap() = Some(user.into());
+    }
+
+    /// Gets default properties, which are applied to all requests
+    #[must_use]
+    pub fn defaults(&self) -> &Defaults {
+        &self.defaults
+    }
+
+    /// Gets default timeout of the requests
+    /// # Returns
+    /// `None` if the client timeout is used
+    #[must_use]
+    pub const fn request_timeout(&self) -> Option<Duration> {
+        self.request_timeout
+    }
+
+    /// Gets tag of the bot
+    /// # Returns
+    /// `None` if the tag isn't set by [`Bot::with_tag`] or [`Builder::tag`] methods
+    #[must_use]
+    pub fn tag(&self) -> Option<&str> {
+        self.tag.as_deref()
+    }
+}
+
+/// Builder for [`Bot`], which allows to set the client and wrap it into [session layers](crate::client::session::layer).
+/// Layers are applied in the order they are added, so the last added layer is the outermost one.
+#[derive(Debug, Clone)]
+pub struct Builder<Client = DefaultSession> {
+    token: String,
+    defaults: Defaults,
+    request_timeout: Option<Duration>,
+    tag: Option<Arc<str>>,
+    client: Client,
+}
+
+#[cfg(feature = "reqwest")]
+impl Builder<Reqwest> {
+    #[must_use]
+    pub fn new(token: impl Into<String>) -> Self {
+        Self {
+            token: token.into(),
+            defaults: Defaults::default(),
+            request_timeout: None,
+            tag: None,
+            client: Reqwest::default(),
+        }
+    }
+}
+
+impl<Client> Builder<Client> {
+    /// Set the client for sending requests to Telegram API
+    /// # Notes
+    /// It replaces the current client with all previously added layers
+    #[must_use]
+    pub fn client<NewClient>(self, client: NewClient) -> Builder<NewClient> {
+        Builder {
+            token: self.token,
+            defaults: self.defaults,
+            request_timeout: self.request_timeout,
+            tag: self.tag,
+            client,
+        }
+    }
+
+    /// Set [`Reqwest`] client, built from the options
+    /// # Notes
+    /// It replaces the current client with all previously added layers,
+    /// so call it before [`Builder::layer`] method
+    /// # Panics
+    /// If the client cannot be built from the options. Use [`Reqwest::with_options`] to handle the error.
+    #[cfg(feature = "reqwest")]
+    #[must_use]
+    pub fn client_options(self, options: ClientOptions) -> Builder<Reqwest> {
+        self.client(
+            Reqwest::with_options(&options).expect("Cannot build the client from the options"),
+        )
+    }
+
+    /// Wrap the current client into the layer
+    #[must_use]
+    pub fn layer<L>(self, layer: L) -> Builder<L::Session>
+    where
+        L: SessionLayer<Client>,
+    {
+        Builder {
+            token: self.token,
+            defaults: self.defaults,
+            request_timeout: self.request_timeout,
+            tag: self.tag,
+            client: layer.layer(self.client),
+        }
+    }
+
+    /// Set default properties, which are applied to all requests.
+    /// Check [`defaults module`](crate::client::defaults) for more information.
+    #[must_use]
+    pub fn defaults(self, val: Defaults) -> Self {
+        Self {
+            defaults: val,
+            ..self
+        }
+    }
+
+    /// Set default timeout of the requests, which is used instead of the client timeout.
+    /// Timeout of the method, which is set by [`TelegramMethod::with_timeout`] or required by the method itself
+    /// (for example, long polling [`GetUpdates`](crate::methods::GetUpdates)), and timeout passed to [`Bot::send_with_timeout`] override it.
+    #[must_use]
+    pub fn request_timeout(self, val: Duration) -> Self {
+        Self {
+            request_timeout: Some(val),
+            ..self
+        }
+    }
+
+    /// Set tag of the bot. Check [`Bot::with_tag`] for more information.
+    #[must_use]
+    pub fn tag(self, val: impl Into<Arc<str>>) -> Self {
+        Self {
+            tag: Some(val.into()),
+            ..self
+        }
+    }
+
+    /// Set default mode for parsing entities in the text and captions of the messages
+    #[must_use]
+    pub fn default_parse_mode(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            defaults: self.defaults.parse_mode(val),
+            ..self
+        }
+    }
+
+    /// Send the messages silently by default
+    #[must_use]
+    pub fn default_disable_notification(self, val: bool) -> Self {
+        Self {
+            defaults: self.defaults.disable_notification(val),
+            ..self
+        }
+    }
+
+    /// Protect the contents of the sent messages from forwarding and saving by default
+    #[must_use]
+    pub fn default_protect_content(self, val: bool) -> Self {
+        Self {
+            defaults: self.defaults.protect_content(val),
+            ..self
+        }
+    }
+
+    /// Set default link preview generation options for the messages
+    #[must_use]
+    pub fn default_link_preview_options(self, val: LinkPreviewOptions) -> Self {
+        Self {
+            defaults: self.defaults.link_preview_options(val),
+            ..self
+        }
+    }
+
+    /// # Panics
+    /// Panics if the token is invalid
+    #[must_use]
+    pub fn build(self) -> Bot<Client> {
+        let bot = Bot::with_client(self.token, self.client)
+            .with_defaults(self.defaults)
+            .with_request_timeout(self.request_timeout);
+
+        match self.tag {
+            Some(tag) => bot.with_tag(tag),
+            None => bot,
+        }
+    }
 }
 
 impl<Client> Debug for Bot<Client> {
@@ -106,6 +400,7 @@ impl<Client> Debug for Bot<Client> {
         f.debug_struct("Bot")
             .field("token", &self.hidden_token)
             .field("bot_id", &self.bot_id)
+            .field("tag", &self.tag)
             .finish_non_exhaustive()
     }
 }
@@ -130,7 +425,8 @@ impl<Client: Session> Bot<Client> {
     /// - If the response cannot be parsed
     /// - If the response represents an Telegram API error
     /// # Notes
-    /// This method uses default timeout for requests, which is 30 seconds.
+    /// This method uses timeout of the method (check [`TelegramMethod::request_timeout`]) if it's set,
+    /// otherwise default timeout of the bot (check [`Builder::request_timeout`]) or the client.
     /// If you want to use custom timeout, use [`Bot::send_with_timeout`] method.
     #[instrument(skip(self, method))]
     pub async fn send<T, TRef>(&self, method: TRef) -> Result<T::Return, SessionErrorKind>
@@ -139,11 +435,41 @@ impl<Client: Session> Bot<Client> {
         T::Method: Send + Sync,
         TRef: AsRef<T>,
     {
+        let method = method.as_ref();
+        let method_with_defaults = self.method_with_defaults(method);
+
         self.client
-            .make_request_and_get_result(self, method.as_ref(), None)
+            .make_request_and_get_result(
+                self,
+                method_with_defaults.as_ref().unwrap_or(method),
+                method.request_timeout().or(self.request_timeout),
+            )
             .await
     }
 
+    /// Gets bot information.
+    /// Sends [`GetMe`] request only if bot information isn't cached yet, otherwise returns cached information.
+    /// # Errors
+    /// If bot information isn't cached and the request to Telegram API is unsuccessful
+    pub async fn get_me(&self) -> Result<Arc<User>, SessionErrorKind> {
+        match self.me() {
+            Some(user) => Ok(user),
+            None => self.refresh_me().await,
+        }
+    }
+
+    /// Sends [`GetMe`] request and updates cached bot information
+    /// # Errors
+    /// If the request to Telegram API is unsuccessful
+    #[instrument(skip(self))]
+    pub async fn refresh_me(&self) -> Result<Arc<User>, SessionErrorKind> {
+        let user = Arc::new(self.send(GetMe::new()).await?);
+
+        self.set_me(Arc::clone(&user));
+
+        Ok(user)
+    }
+
     /// Use this method to send requests to Telegram API with timeout
     /// # Arguments
     /// * `method` - Telegram API method
@@ -158,15 +484,151 @@ impl<Client: Session> Bot<Client> {
     pub async fn send_with_timeout<T, TRef>(
         &self,
         method: TRef,
-        request_timeout: f32,
+        request_timeout: Duration,
     ) -> Result<T::Return, SessionErrorKind>
     where
         T: TelegramMethod + Send + Sync,
         T::Method: Send + Sync,
         TRef: AsRef<T>,
     {
+        let method = method.as_ref();
+        let method_with_defaults = self.method_with_defaults(method);
+
+        self.client
+            .make_request_and_get_result(
+                self,
+                method_with_defaults.as_ref().unwrap_or(method),
+                Some(request_timeout),
+            )
+            .await
+    }
+
+    /// Use this method to send requests to Telegram API, which return an array,
+    /// and receive elements of the array one by one, while the response content is being received.
+    /// It's useful for responses with many big elements, for example, `getUpdates` response with 100 updates.
+    /// # Arguments
+    /// * `method` - Telegram API method
+    /// # Returns
+    /// Stream of raw JSON of the elements, which are deserialized by the caller
+    /// # Errors
+    /// If the request cannot be send.
+    /// Stream returns an error, if the response content cannot be received or parsed,
+    /// or the response represents an Telegram API error.
+    /// # Notes
+    /// The content is received in chunks only if the client supports it (check [`Session::send_request_streamed`]),
+    /// otherwise the elements are decoded after receiving the full content.
+    #[instrument(skip(self, method))]
+    pub async fn send_streamed<T, TRef>(
+        &self,
+        method: TRef,
+    ) -> Result<ResultStream<'_>, SessionErrorKind>
+    where
+        T: TelegramMethod + Send + Sync,
+        T::Method: Send + Sync,
+        TRef: AsRef<T>,
+    {
+        let method = method.as_ref();
+        let method_with_defaults = self.method_with_defaults(method);
+
         self.client
-            .make_request_and_get_result(self, method.as_ref(), Some(request_timeout))
+            .make_request_streamed(
+                self,
+                method_with_defaults.as_ref().unwrap_or(method),
+                method.request_timeout().or(self.request_timeout),
+            )
             .await
     }
+
+    /// Applies default properties to the method, if they are set
+    fn method_with_defaults<T: TelegramMethod>(&self, method: &T) -> Option<T> {
+        if self.defaults.is_empty() {
+            None
+        } else {
+            method.with_defaults(&self.defaults)
+        }
+    }
+
+    /// Use this method to send text messages, which can be longer than the Telegram limit.
+    /// The text is split into several messages by [`SendMessage::chunked`], which are sent one by one.
+    /// # Returns
+    /// All sent messages in the order of the text
+    /// # Errors
+    /// If any of the messages can't be sent. Messages, which are sent before the error, aren't deleted.
+    #[instrument(skip(self, method))]
+    pub async fn send_long_text(
+        &self,
+        method: SendMessage,
+    ) -> Result<Vec<Message>, SessionErrorKind> {
+        let methods = method.chunked();
+        let mut messages = Vec::with_capacity(methods.len());
+
+        for method in methods {
+            messages.push(self.send(method).await?);
+        }
+
+        Ok(messages)
+    }
+
+    /// Use this method to copy any number of messages.
+    /// Message identifiers are split into chunks by [`CopyMessages::chunked`], which are sent one by one.
+    /// # Returns
+    /// Identifiers of the sent messages and chunks, which can't be sent, check [`BulkResult`] for more information
+    #[instrument(skip(self, method))]
+    pub async fn copy_messages_chunked(&self, method: CopyMessages) -> BulkResult {
+        let mut result = BulkResult::default();
+
+        for method in method.chunked() {
+            result.push(&method.message_ids, self.send(&method).await);
+        }
+
+        result
+    }
+
+    /// Use this method to forward any number of messages.
+    /// Message identifiers are split into chunks by [`ForwardMessages::chunked`], which are sent one by one.
+    /// # Returns
+    /// Identifiers of the sent messages and chunks, which can't be sent, check [`BulkResult`] for more information
+    #[instrument(skip(self, method))]
+    pub async fn forward_messages_chunked(&self, method: ForwardMessages) -> BulkResult {
+        let mut result = BulkResult::default();
+
+        for method in method.chunked() {
+            result.push(&method.message_ids, self.send(&method).await);
+        }
+
+        result
+    }
+
+    /// Sends request to Telegram API at the specified time in the background task.
+    /// Request is stored only in memory, use [`Scheduler`] if you need to persist it.
+    /// Check [`scheduler module`](super::scheduler) for more information.
+    /// # Returns
+    /// [`Handle`], which can be used to cancel the request or to wait for its result
+    /// # Notes
+    /// If the time is already passed, the request is sent immediately
+    ///
+    /// [`Scheduler`]: super::scheduler::Scheduler
+    #[cfg(all(feature = "fsm", feature = "tokio-runtime"))]
+    pub fn send_later<T>(&self, method: T, at: SystemTime) -> Handle<T::Return>
+    where
+        Client: Session + Clone + 'static,
+        T: TelegramMethod + AsRef<T> + Send + Sync + 'static,
+        T::Method: Send + Sync,
+        T::Return: Send + 'static,
+    {
+        scheduler::spawn(self.clone(), method, at)
+    }
+
+    /// Sends request to Telegram API after the delay in the background task.
+    /// Check [`Bot::send_later`] for more information.
+    #[cfg(all(feature = "fsm", feature = "tokio-runtime"))]
+    pub fn send_after<T>(&self, method: T, delay: Duration) -> Handle<T::Return>
+    where
+        Client: Session + Clone + 'static,
+        T: TelegramMethod + AsRef<T> + Send + Sync + 'static,
+        T::Method: Send + Sync,
+        T::Return: Send + 'static,
+    {
+        self.send_later(method, SystemTime::now() + delay)
+    }
 }
@@ -0,0 +1,113 @@
+//! This module contains [`BulkResult`], which is returned by [`Bot::copy_messages_chunked`] and [`Bot::forward_messages_chunked`] methods.
+//!
+//! These methods split the message identifiers into chunks with at most [`MAX_MESSAGE_IDS`] identifiers
+//! and send them one by one, so any number of messages can be moved with one call (for example, by archive and mirror bots).
+//! If some chunk can't be sent, the next chunks are still sent and the failed chunk is saved in [`BulkResult::failed`],
+//! so you can retry only the failed messages.
+//!
+//! [`Bot::copy_messages_chunked`]: crate::client::Bot#method.copy_messages_chunked
+//! [`Bot::forward_messages_chunked`]: crate::client::Bot#method.forward_messages_chunked
+//! [`MAX_MESSAGE_IDS`]: crate::methods::copy_messages::MAX_MESSAGE_IDS
+
+use crate::{errors::SessionErrorKind, types::MessageId};
+
+/// Chunk of the message identifiers, which can't be sent
+#[derive(Debug)]
+pub struct FailedChunk {
+    /// Identifiers of the original messages of the chunk
+    pub message_ids: Box<[i64]>,
+    pub error: SessionErrorKind,
+}
+
+/// Result of the bulk copy or forward of the messages.
+/// Check the [module](self) documentation for more information.
+#[derive(Debug, Default)]
+pub struct BulkResult {
+    /// Identifiers of the sent messages in the order of the original messages.
+    /// Messages, which can't be found or copied, are skipped by Telegram, so the number of identifiers can be less than requested.
+    pub message_ids: Vec<MessageId>,
+    /// Chunks, which can't be sent, in the order of the original messages
+    pub failed: Vec<FailedChunk>,
+}
+
+impl BulkResult {
+    /// Check if all chunks are sent
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    /// Get identifiers of the original messages, which can't be sent
+    pub fn failed_message_ids(&self) -> impl Iterator<Item = i64> + '_ {
+        self.failed
+            .iter()
+            .flat_map(|chunk| chunk.message_ids.iter().copied())
+    }
+
+    /// Convert to the result, which is an error if any chunk can't be sent
+    /// # Errors
+    /// Error of the first failed chunk
+    pub fn into_result(self) -> Result<Vec<MessageId>, SessionErrorKind> {
+        match self.failed.into_iter().next() {
+            Some(chunk) => Err(chunk.error),
+            None => Ok(self.message_ids),
+        }
+    }
+
+    pub(super) fn push(
+        &mut self,
+        message_ids: &[i64],
+        result: Result<Vec<MessageId>, SessionErrorKind>,
+    ) {
+        match result {
+            Ok(sent) => self.message_ids.extend(sent),
+            Err(error) => self.failed.push(FailedChunk {
+                message_ids: message_ids.into(),
+                error,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        errors::TelegramErrorKind,
+        methods::{copy_messages::MAX_MESSAGE_IDS, CopyMessages, ForwardMessages},
+    };
+
+    #[test]
+    fn test_chunked() {
+        let methods = CopyMessages::new(1, 2, (1..=250).rev().chain([5, 5])).chunked();
+        assert_eq!(methods.len(), 3);
+        assert_eq!(methods[0].message_ids.len(), MAX_MESSAGE_IDS);
+        assert_eq!(methods[0].message_ids[0], 1);
+        assert_eq!(methods[2].message_ids, (201..=250).collect::<Vec<_>>());
+
+        let methods = ForwardMessages::new(1, 2, [3, 1, 2]).chunked();
+        assert_eq!(methods.len(), 1);
+        assert_eq!(methods[0].message_ids, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_push() {
+        let mut result = BulkResult::default();
+        result.push(&[1, 2], Ok(vec![MessageId { id: 10 }]));
+        result.push(
+            &[3, 4],
+            Err(SessionErrorKind::Telegram(TelegramErrorKind::BadRequest {
+                message: "Bad Request: message to copy not found".into(),
+            })),
+        );
+        result.push(&[5], Ok(vec![MessageId { id: 11 }]));
+
+        assert!(!result.is_ok());
+        assert_eq!(result.failed_message_ids().collect::<Vec<_>>(), [3, 4]);
+        assert_eq!(
+            result.message_ids,
+            [MessageId { id: 10 }, MessageId { id: 11 }]
+        );
+        assert!(result.into_result().is_err());
+    }
+}
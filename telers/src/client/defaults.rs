@@ -0,0 +1,207 @@
+//! This module contains [`Defaults`], which are default properties of the bot, applied to all requests,
+//! so you don't need to set the same parse mode, notification or link preview options in each method.
+//!
+//! Defaults are set by [`BotBuilder`] methods and applied by [`Bot::send`] and [`Bot::send_with_timeout`] methods
+//! to methods, which have the corresponding fields, only if the fields aren't set in the method.
+//! Values, which are set in the method, always override the defaults.
+//!
+//! The default parse mode isn't applied to methods with entities, because they are used instead of the parse mode.
+//! To send a plain text without the default parse mode, set empty entities with `entities(vec![])`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use telers::{enums::ParseMode, methods::SendMessage, Bot};
+//!
+//! let bot = Bot::builder("1234567890:ABCDEFGHIJKLMNOPQRSTUVWXYZ012345678")
+//!     .default_parse_mode(ParseMode::HTML)
+//!     .default_protect_content(true)
+//!     .build();
+//!
+//! // Parse mode is `HTML` and content is protected
+//! let method = SendMessage::new(1, "<b>Hello!</b>");
+//! // Parse mode is `MarkdownV2` and content is protected
+//! let method = SendMessage::new(1, "*Hello\\!*").parse_mode(ParseMode::MarkdownV2);
+//! ```
+//!
+//! [`BotBuilder`]: crate::client::BotBuilder
+//! [`Bot::send`]: crate::client::Bot#method.send
+//! [`Bot::send_with_timeout`]: crate::client::Bot#method.send_with_timeout
+
+use crate::types::LinkPreviewOptions;
+
+/// Default properties of the bot, which are applied to all requests.
+/// Check the [module](self) documentation for more information.
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq)]
+pub struct Defaults {
+    /// Default mode for parsing entities in the text and captions of the messages
+    pub parse_mode: Option<Box<str>>,
+    /// Sends the messages silently by default
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent messages from forwarding and saving by default
+    pub protect_content: Option<bool>,
+    /// Default link preview generation options for the messages
+    pub link_preview_options: Option<LinkPreviewOptions>,
+}
+
+impl Defaults {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn parse_mode(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            parse_mode: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn disable_notification(self, val: bool) -> Self {
+        Self {
+            disable_notification: Some(val),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn protect_content(self, val: bool) -> Self {
+        Self {
+            protect_content: Some(val),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn link_preview_options(self, val: LinkPreviewOptions) -> Self {
+        Self {
+            link_preview_options: Some(val),
+            ..self
+        }
+    }
+
+    /// Check if no defaults are set, so methods can be sent as is
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.parse_mode.is_none()
+            && self.disable_notification.is_none()
+            && self.protect_content.is_none()
+            && self.link_preview_options.is_none()
+    }
+
+    /// Set the default parse mode to the field, if the field and the entities aren't set
+    /// # Returns
+    /// `true` if the field is changed
+    pub fn fill_parse_mode<T>(
+        &self,
+        parse_mode: &mut Option<String>,
+        entities: Option<&T>,
+    ) -> bool {
+        if parse_mode.is_some() || entities.is_some() {
+            return false;
+        }
+
+        *parse_mode = self.parse_mode.as_deref().map(ToOwned::to_owned);
+        parse_mode.is_some()
+    }
+
+    /// Set the default value to the field, if it isn't set
+    /// # Returns
+    /// `true` if the field is changed
+    pub fn fill_disable_notification(&self, disable_notification: &mut Option<bool>) -> bool {
+        fill(disable_notification, self.disable_notification.as_ref())
+    }
+
+    /// Set the default value to the field, if it isn't set
+    /// # Returns
+    /// `true` if the field is changed
+    pub fn fill_protect_content(&self, protect_content: &mut Option<bool>) -> bool {
+        fill(protect_content, self.protect_content.as_ref())
+    }
+
+    /// Set the default value to the field, if it isn't set
+    /// # Returns
+    /// `true` if the field is changed
+    pub fn fill_link_preview_options(
+        &self,
+        link_preview_options: &mut Option<LinkPreviewOptions>,
+    ) -> bool {
+        fill(link_preview_options, self.link_preview_options.as_ref())
+    }
+}
+
+fn fill<T: Clone>(field: &mut Option<T>, default: Option<&T>) -> bool {
+    if field.is_some() {
+        return false;
+    }
+
+    *field = default.cloned();
+    field.is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{Bot, Reqwest},
+        enums::ParseMode,
+        methods::{ForwardMessage, GetMe, SendMessage, SendPhoto, TelegramMethod as _},
+        types::{InputFile, MessageEntity},
+    };
+
+    #[test]
+    fn test_with_defaults() {
+        let defaults = Defaults::new()
+            .parse_mode(ParseMode::HTML)
+            .disable_notification(true);
+
+        let method = SendMessage::new(1, "text")
+            .with_defaults(&defaults)
+            .unwrap();
+        assert_eq!(method.parse_mode.as_deref(), Some("HTML"));
+        assert_eq!(method.disable_notification, Some(true));
+        assert!(method.protect_content.is_none());
+
+        // Values of the method override the defaults
+        let method = SendMessage::new(1, "text")
+            .parse_mode(ParseMode::MarkdownV2)
+            .disable_notification(false);
+        assert!(method.with_defaults(&defaults).is_none());
+
+        // Parse mode isn't applied to the method with entities
+        let method = SendMessage::new(1, "text")
+            .entities([] as [MessageEntity; 0])
+            .with_defaults(&defaults)
+            .unwrap();
+        assert!(method.parse_mode.is_none());
+
+        let method = SendPhoto::new(1, InputFile::id("id"))
+            .with_defaults(&defaults)
+            .unwrap();
+        assert_eq!(method.parse_mode.as_deref(), Some("HTML"));
+
+        let method = ForwardMessage::new(1, 2, 3)
+            .with_defaults(&defaults)
+            .unwrap();
+        assert_eq!(method.disable_notification, Some(true));
+
+        assert!(GetMe::new().with_defaults(&defaults).is_none());
+        assert!(SendMessage::new(1, "text")
+            .with_defaults(&Defaults::new())
+            .is_none());
+    }
+
+    #[test]
+    fn test_builder() {
+        let bot = Bot::builder("1234567890:ABCDEFGHIJKLMNOPQRSTUVWXYZ012345678")
+            .default_parse_mode(ParseMode::HTML)
+            .default_protect_content(true)
+            .build();
+
+        assert_eq!(bot.defaults().parse_mode.as_deref(), Some("HTML"));
+        assert_eq!(bot.defaults().protect_content, Some(true));
+        assert!(Bot::<Reqwest>::default().defaults().is_empty());
+    }
+}
@@ -0,0 +1,456 @@
+//! This module contains [`Outbox`], which is a durable queue for outgoing requests to the Telegram Bot API,
+//! so broadcasts and critical notifications survive process crashes.
+//!
+//! Requests are persisted in the FSM [`Storage`] (see [`storage module`](crate::fsm::storage)) with [`Outbox::push`] method
+//! and sent with [`Outbox::flush`] or [`Outbox::run`] methods.
+//! Request is removed from the storage (acknowledged) only after it's sent successfully
+//! or it can't be sent anymore:
+//! - Request is failed with a temporary error (network errors, server errors, flood limits, etc.) [`Outbox::max_attempts`] times
+//! - Request is failed with a permanent error (bad request, bot is blocked by the user, etc.)
+//!
+//! Requests are stored as JSON by [`StorageKey`] with bot id, zero chat and user ids and [`DESTINY`] destiny,
+//! so requests with files, which are uploaded with `multipart/form-data`, can't be persisted.
+//!
+//! # Notes
+//! Request can be sent more than once, if the process is crashed after sending the request but before its acknowledgement.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use telers::{client::Outbox, fsm::MemoryStorage, methods::SendMessage, Bot};
+//!
+//! async fn broadcast(bot: Bot, outbox: Outbox<MemoryStorage>, chat_ids: Vec<i64>) {
+//!     for chat_id in chat_ids {
+//!         outbox
+//!             .push(&bot, &SendMessage::new(chat_id, "News"))
+//!             .await
+//!             .unwrap();
+//!     }
+//!
+//!     // Usually it's spawned once on startup, so requests, which are pushed before the crash, are sent as well
+//!     tokio::spawn(async move { outbox.run(&bot).await });
+//! }
+//! ```
+
+use super::{
+    persisted::{self, Request as PersistedRequest},
+    session::base::Session,
+    Bot,
+};
+
+use crate::{
+    errors::{SessionErrorKind, TelegramErrorKind},
+    fsm::{storage::Error as StorageError, Storage, StorageKey},
+    methods::TelegramMethod,
+    runtime::{self, Runtime},
+};
+
+use futures::future;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    fmt::{self, Debug, Formatter},
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+use thiserror;
+use tokio::sync::{Mutex, Notify};
+use tracing::{event, Level};
+use uuid::Uuid;
+
+/// Destiny of the storage key, by which outgoing requests are stored
+pub const DESTINY: &str = "outbox";
+
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 5;
+pub const DEFAULT_RETRY_DELAY: Duration = Duration::from_secs(5);
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Request with files can't be persisted")]
+    Files,
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+    #[error(transparent)]
+    Parse(#[from] serde_json::Error),
+}
+
+/// Outgoing request, which is stored in the storage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    request: PersistedRequest,
+    /// Time, when the request is pushed, in milliseconds since the Unix epoch
+    created_at: u64,
+    /// Number of failed attempts to send the request
+    attempts: u32,
+}
+
+/// Checks that the request can be sent successfully later
+fn is_temporary(err: &SessionErrorKind) -> bool {
+    matches!(
+        err,
+        SessionErrorKind::Client(_)
+            | SessionErrorKind::Telegram(
+                TelegramErrorKind::NetworkError { .. }
+                    | TelegramErrorKind::RetryAfter { .. }
+                    | TelegramErrorKind::ServerError { .. }
+                    | TelegramErrorKind::RestartingTelegram { .. }
+            )
+    )
+}
+
+/// Result of [`Outbox::flush`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Flushed {
+    /// Number of successfully sent requests
+    pub sent: usize,
+    /// Number of requests, which are failed with a temporary error and will be retried
+    pub retried: usize,
+    /// Number of requests, which are failed and removed from the outbox
+    pub dropped: usize,
+}
+
+/// Durable queue for outgoing requests.
+/// Check [module docs](crate::client::outbox) for more information.
+#[derive(Clone)]
+pub struct Outbox<S> {
+    storage: S,
+    /// Lock for modifying entries in the storage, because the storage doesn't support removing a single value
+    lock: Arc<Mutex<()>>,
+    /// Lock for flushing, so the same request isn't sent concurrently
+    flush_lock: Arc<Mutex<()>>,
+    notify: Arc<Notify>,
+    max_attempts: u32,
+    retry_delay: Duration,
+    runtime: Arc<dyn Runtime>,
+}
+
+impl<S> Outbox<S>
+where
+    S: Storage + Send + Sync + 'static,
+{
+    #[must_use]
+    pub fn new(storage: S) -> Self {
+        Self {
+            storage,
+            lock: Arc::default(),
+            flush_lock: Arc::default(),
+            notify: Arc::default(),
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            retry_delay: DEFAULT_RETRY_DELAY,
+            runtime: runtime::default(),
+        }
+    }
+
+    /// Max number of attempts to send the request, which is failed with a temporary error
+    /// # Default
+    /// [`DEFAULT_MAX_ATTEMPTS`]
+    #[must_use]
+    pub fn max_attempts(self, val: u32) -> Self {
+        Self {
+            max_attempts: val,
+            ..self
+        }
+    }
+
+    /// Delay between retries of the requests, which are failed with a temporary error, in [`Outbox::run`] method
+    /// # Default
+    /// [`DEFAULT_RETRY_DELAY`]
+    #[must_use]
+    pub fn retry_delay(self, val: Duration) -> Self {
+        Self {
+            retry_delay: val,
+            ..self
+        }
+    }
+
+    /// Runtime, which is used to wait between retries in [`Outbox::run`] method.
+    /// Check [`runtime`] module documentation for more information.
+    /// # Default
+    /// [`runtime::Tokio`] with `tokio-runtime` feature
+    #[must_use]
+    pub fn runtime(self, val: impl Runtime) -> Self {
+        Self {
+            runtime: Arc::new(val),
+            ..self
+        }
+    }
+
+    fn key(bot_id: i64) -> StorageKey {
+        StorageKey::new(bot_id, 0, 0, None).destiny(DESTINY)
+    }
+
+    async fn entries(&self, bot_id: i64) -> Result<HashMap<Box<str>, Entry>, StorageError> {
+        let _guard = self.lock.lock().await;
+
+        self.storage
+            .get_data(&Self::key(bot_id))
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Replaces the entry in the storage or removes it, if `entry` is `None`
+    async fn set_entry(
+        &self,
+        bot_id: i64,
+        id: &str,
+        entry: Option<Entry>,
+    ) -> Result<(), StorageError> {
+        let key = Self::key(bot_id);
+        let _guard = self.lock.lock().await;
+
+        let mut entries: HashMap<Box<str>, Entry> =
+            self.storage.get_data(&key).await.map_err(Into::into)?;
+
+        match entry {
+            Some(entry) => {
+                entries.insert(id.into(), entry);
+            }
+            None => {
+                entries.remove(id);
+            }
+        }
+
+        self.storage
+            .set_data(
+                &key,
+                entries
+                    .into_iter()
+                    .map(|(id, entry)| (id.into_string(), entry))
+                    .collect(),
+            )
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Persists the request in the storage, so it's sent by [`Outbox::flush`] or [`Outbox::run`] methods
+    /// # Returns
+    /// Unique id of the request
+    /// # Errors
+    /// - If the request contains files, which are uploaded with `multipart/form-data`
+    /// - If the request cannot be serialized
+    /// - If the request cannot be saved in the storage
+    pub async fn push<Client, T>(&self, bot: &Bot<Client>, method: &T) -> Result<Box<str>, Error>
+    where
+        T: TelegramMethod,
+    {
+        let id: Box<str> = Uuid::new_v4().to_string().into();
+        let entry = Entry {
+            request: PersistedRequest::new(bot, method)?.ok_or(Error::Files)?,
+            created_at: persisted::timestamp(SystemTime::now()),
+            attempts: 0,
+        };
+
+        {
+            let _guard = self.lock.lock().await;
+
+            self.storage
+                .set_value(&Self::key(bot.bot_id), id.clone().into_string(), &entry)
+                .await
+                .map_err(Into::into)?;
+        }
+
+        self.notify.notify_one();
+
+        Ok(id)
+    }
+
+    /// Gets number of the requests, which aren't sent yet
+    /// # Errors
+    /// If the requests cannot be loaded from the storage
+    pub async fn pending(&self, bot_id: i64) -> Result<usize, Error> {
+        Ok(self.entries(bot_id).await?.len())
+    }
+
+    /// Sends all pending requests of the bot in the order they are pushed
+    /// # Errors
+    /// If the requests cannot be loaded from or saved in the storage
+    pub async fn flush<Client>(&self, bot: &Bot<Client>) -> Result<Flushed, Error>
+    where
+        Client: Session,
+    {
+        let _guard = self.flush_lock.lock().await;
+
+        let mut entries = self
+            .entries(bot.bot_id)
+            .await?
+            .into_iter()
+            .collect::<Vec<_>>();
+        entries.sort_by(|(left_id, left), (right_id, right)| {
+            (left.created_at, left_id).cmp(&(right.created_at, right_id))
+        });
+
+        let mut flushed = Flushed::default();
+
+        for (id, mut entry) in entries {
+            let err = match entry.request.send::<_, Value>(bot).await {
+                Ok(_) => {
+                    self.set_entry(bot.bot_id, &id, None).await?;
+
+                    flushed.sent += 1;
+                    continue;
+                }
+                Err(err) => err,
+            };
+
+            entry.attempts += 1;
+
+            if is_temporary(&err) && entry.attempts < self.max_attempts {
+                event!(
+                    Level::WARN,
+                    error = %err,
+                    %id,
+                    method_name = entry.request.method_name(),
+                    attempts = entry.attempts,
+                    "Failed to send request from the outbox, it will be retried",
+                );
+
+                let is_flood_limit = matches!(
+                    err,
+                    SessionErrorKind::Telegram(TelegramErrorKind::RetryAfter { .. })
+                );
+
+                self.set_entry(bot.bot_id, &id, Some(entry)).await?;
+
+                flushed.retried += 1;
+
+                // Other requests will hit the limit as well, so we stop flushing until the next retry
+                if is_flood_limit {
+                    break;
+                }
+            } else {
+                event!(
+                    Level::ERROR,
+                    error = %err,
+                    %id,
+                    method_name = entry.request.method_name(),
+                    attempts = entry.attempts,
+                    "Failed to send request from the outbox, it's dropped",
+                );
+
+                self.set_entry(bot.bot_id, &id, None).await?;
+
+                flushed.dropped += 1;
+            }
+        }
+
+        Ok(flushed)
+    }
+
+    /// Sends pending requests of the bot and waits for new ones.
+    /// Requests, which are failed with a temporary error, are retried after [`Outbox::retry_delay`].
+    /// # Notes
+    /// This method runs forever, so you need to spawn it in a separate task
+    /// # Errors
+    /// If the requests cannot be loaded from or saved in the storage
+    pub async fn run<Client>(&self, bot: &Bot<Client>) -> Result<(), Error>
+    where
+        Client: Session,
+    {
+        loop {
+            let flushed = self.flush(bot).await?;
+
+            if flushed.retried == 0 {
+                self.notify.notified().await;
+            } else {
+                future::select(
+                    Box::pin(self.notify.notified()),
+                    self.runtime.sleep(self.retry_delay),
+                )
+                .await;
+            }
+        }
+    }
+}
+
+impl<S> Debug for Outbox<S>
+where
+    S: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Outbox")
+            .field("storage", &self.storage)
+            .field("max_attempts", &self.max_attempts)
+            .field("retry_delay", &self.retry_delay)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_temporary() {
+        assert!(is_temporary(&SessionErrorKind::Telegram(
+            TelegramErrorKind::ServerError {
+                message: "Bad Gateway".into()
+            }
+        )));
+        assert!(!is_temporary(&SessionErrorKind::Telegram(
+            TelegramErrorKind::Forbidden {
+                message: "bot was blocked by the user".into()
+            }
+        )));
+    }
+
+    #[cfg(feature = "memory-storage")]
+    #[tokio::test]
+    async fn test_flush() {
+        use crate::{
+            client::{
+                telegram::{APIServer, BareFilesPathWrapper},
+                Reqwest,
+            },
+            fsm::MemoryStorage,
+            methods::{SendMessage, SendPhoto},
+            types::InputFile,
+        };
+
+        use std::borrow::Cow;
+
+        // Nothing listens on this port, so requests are failed with a temporary client error
+        let bot = Bot::with_client(
+            "1234567890:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+            Reqwest::default().with_api_server(Cow::Owned(APIServer::new(
+                "http://127.0.0.1:1/bot{token}/{method_name}",
+                "http://127.0.0.1:1/file/bot{token}/{path}",
+                false,
+                BareFilesPathWrapper,
+            ))),
+        );
+        let outbox = Outbox::new(MemoryStorage::default()).max_attempts(2);
+
+        outbox
+            .push(&bot, &SendMessage::new(1, "text"))
+            .await
+            .unwrap();
+        assert!(matches!(
+            outbox
+                .push(&bot, &SendPhoto::new(1, InputFile::buffered(vec![1])))
+                .await,
+            Err(Error::Files)
+        ));
+        assert_eq!(outbox.pending(bot.bot_id).await.unwrap(), 1);
+
+        assert_eq!(
+            outbox.flush(&bot).await.unwrap(),
+            Flushed {
+                sent: 0,
+                retried: 1,
+                dropped: 0
+            }
+        );
+        assert_eq!(outbox.pending(bot.bot_id).await.unwrap(), 1);
+
+        assert_eq!(
+            outbox.flush(&bot).await.unwrap(),
+            Flushed {
+                sent: 0,
+                retried: 0,
+                dropped: 1
+            }
+        );
+        assert_eq!(outbox.pending(bot.bot_id).await.unwrap(), 0);
+    }
+}
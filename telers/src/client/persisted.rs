@@ -0,0 +1,116 @@
+//! This module contains [`Request`], which represents a request to Telegram API in a serializable form,
+//! so it can be persisted in the storage and sent later (see [`scheduler`] and [`outbox`] modules).
+//!
+//! [`scheduler`]: super::scheduler
+//! [`outbox`]: super::outbox
+
+use super::{session::base::Session, Bot};
+
+use crate::{
+    errors::SessionErrorKind,
+    methods::{Request as MethodRequest, TelegramMethod},
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Converts the time to milliseconds since the Unix epoch
+pub(super) fn timestamp(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).map_or(0, |time| {
+        u64::try_from(time.as_millis()).unwrap_or(u64::MAX)
+    })
+}
+
+/// Converts milliseconds since the Unix epoch to the time
+#[cfg(feature = "tokio-runtime")]
+pub(super) fn from_timestamp(timestamp: u64) -> SystemTime {
+    UNIX_EPOCH + std::time::Duration::from_millis(timestamp)
+}
+
+/// Request with raw JSON params, which is built from the persisted [`Request`]
+struct Raw<'a> {
+    method_name: &'a str,
+    params: Value,
+}
+
+impl TelegramMethod for Raw<'_> {
+    type Method = Value;
+    type Return = Value;
+
+    fn build_request<Client>(&self, _bot: &Bot<Client>) -> MethodRequest<'_, Self::Method> {
+        MethodRequest::new(self.method_name, &self.params, None)
+    }
+}
+
+impl<'a> AsRef<Raw<'a>> for Raw<'a> {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+/// Request to Telegram API in a serializable form
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) struct Request {
+    method_name: Box<str>,
+    /// Params of the request in JSON format.
+    /// It's stored as a string, because some storages use non self-describing formats.
+    params: Box<str>,
+}
+
+impl Request {
+    /// # Returns
+    /// `None` if the request contains files, which are uploaded with `multipart/form-data`
+    /// # Errors
+    /// If the params of the request cannot be serialized
+    pub(super) fn new<Client, T>(
+        bot: &Bot<Client>,
+        method: &T,
+    ) -> Result<Option<Self>, serde_json::Error>
+    where
+        T: TelegramMethod,
+    {
+        let method_with_defaults = method.with_defaults(bot.defaults());
+        let request = method_with_defaults
+            .as_ref()
+            .unwrap_or(method)
+            .build_request(bot);
+
+        if request.files.map_or(false, |files| !files.is_empty()) {
+            return Ok(None);
+        }
+
+        Ok(Some(Self {
+            method_name: request.method_name.into(),
+            params: serde_json::to_string(request.data)?.into(),
+        }))
+    }
+
+    pub(super) fn method_name(&self) -> &str {
+        &self.method_name
+    }
+
+    #[cfg(test)]
+    pub(super) fn params(&self) -> &str {
+        &self.params
+    }
+
+    /// Sends the request and parses its result
+    /// # Errors
+    /// - If the params of the request cannot be parsed
+    /// - If the request is unsuccessful
+    pub(super) async fn send<Client, R>(&self, bot: &Bot<Client>) -> Result<R, SessionErrorKind>
+    where
+        Client: Session,
+        R: DeserializeOwned,
+    {
+        let result = bot
+            .send::<Raw, _>(Raw {
+                method_name: &self.method_name,
+                params: serde_json::from_str(&self.params)?,
+            })
+            .await?;
+
+        Ok(serde_json::from_value(result)?)
+    }
+}
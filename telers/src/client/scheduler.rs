@@ -0,0 +1,462 @@
+//! This module contains components for delayed sending of requests to the Telegram Bot API,
+//! so reminder-like flows don't require a hand-rolled job queue.
+//!
+//! [`Bot::send_later`] and [`Bot::send_after`] methods send the request in a background task at the specified time
+//! and return [`Handle`], which can be used to cancel the request or to wait for its result.
+//! These requests are stored only in memory, so they are lost when the process is restarted.
+//!
+//! [`Scheduler`] persists delayed requests in the FSM [`Storage`] (see [`storage module`](crate::fsm::storage)),
+//! so they can be restored with [`Scheduler::restore`] method after the restart.
+//! Requests are stored as JSON by [`StorageKey`] with bot id, zero chat and user ids and [`DESTINY`] destiny,
+//! so requests with files, which are uploaded with `multipart/form-data`, can't be persisted.
+//!
+//! # Notes
+//! Requests, which time is already passed, are sent immediately.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use telers::{client::Scheduler, fsm::MemoryStorage, methods::SendMessage, Bot};
+//!
+//! async fn remind(bot: Bot, scheduler: Scheduler<MemoryStorage>) {
+//!     // Request is stored in memory only
+//!     let handle = bot.send_after(SendMessage::new(1, "Reminder"), Duration::from_secs(60));
+//!     handle.cancel();
+//!
+//!     // Request is persisted in the storage
+//!     let handle = scheduler
+//!         .send_after(&bot, &SendMessage::new(1, "Reminder"), Duration::from_secs(60))
+//!         .await
+//!         .unwrap();
+//!     scheduler.cancel(bot.bot_id, handle.id()).await.unwrap();
+//!
+//!     // Restore persisted requests after the restart
+//!     let handles = scheduler.restore(&bot).await.unwrap();
+//! }
+//! ```
+
+use super::{
+    persisted::{self, Request as PersistedRequest},
+    session::base::Session,
+    Bot,
+};
+
+use crate::{
+    errors::SessionErrorKind,
+    fsm::{storage::Error as StorageError, Storage, StorageKey},
+    methods::TelegramMethod,
+};
+
+use dashmap::DashMap;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    panic,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+use thiserror;
+use tokio::{
+    sync::{oneshot, Mutex as AsyncMutex},
+    task::{AbortHandle, JoinHandle},
+};
+use tracing::{event, Level};
+use uuid::Uuid;
+
+/// Destiny of the storage key, by which delayed requests are stored
+pub const DESTINY: &str = "scheduler";
+
+fn delay_until(at: SystemTime) -> Duration {
+    at.duration_since(SystemTime::now()).unwrap_or_default()
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Request is cancelled")]
+    Cancelled,
+    #[error("Request with files can't be persisted")]
+    Files,
+    #[error(transparent)]
+    Session(#[from] SessionErrorKind),
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+    #[error(transparent)]
+    Parse(#[from] serde_json::Error),
+}
+
+/// Handle of the delayed request
+#[derive(Debug)]
+pub struct Handle<T> {
+    id: Box<str>,
+    at: SystemTime,
+    task: JoinHandle<Result<T, SessionErrorKind>>,
+}
+
+impl<T> Handle<T> {
+    /// Gets unique id of the request
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Gets time, when the request is sent
+    #[must_use]
+    pub const fn at(&self) -> SystemTime {
+        self.at
+    }
+
+    /// Cancels the request, if it isn't sent yet.
+    /// # Notes
+    /// This method doesn't remove persisted requests from the storage, use [`Scheduler::cancel`] for it
+    pub fn cancel(&self) {
+        self.task.abort();
+    }
+
+    /// Checks that the request is sent or cancelled
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+
+    /// Waits for the result of the request
+    /// # Errors
+    /// - If the request is cancelled
+    /// - If the request is unsuccessful
+    pub async fn wait(self) -> Result<T, Error> {
+        match self.task.await {
+            Ok(result) => result.map_err(Into::into),
+            Err(err) if err.is_cancelled() => Err(Error::Cancelled),
+            Err(err) => panic::resume_unwind(err.into_panic()),
+        }
+    }
+}
+
+/// Spawns a background task, which sends the request at the specified time
+pub(super) fn spawn<Client, T>(bot: Bot<Client>, method: T, at: SystemTime) -> Handle<T::Return>
+where
+    Client: Session + Clone + 'static,
+    T: TelegramMethod + AsRef<T> + Send + Sync + 'static,
+    T::Method: Send + Sync,
+    T::Return: Send + 'static,
+{
+    let task = tokio::spawn(async move {
+        tokio::time::sleep(delay_until(at)).await;
+
+        bot.send::<T, _>(&method).await
+    });
+
+    Handle {
+        id: Uuid::new_v4().to_string().into(),
+        at,
+        task,
+    }
+}
+
+/// Delayed request, which is stored in the storage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Job {
+    request: PersistedRequest,
+    /// Time, when the request is sent, in milliseconds since the Unix epoch
+    at: u64,
+}
+
+/// Scheduler of delayed requests, which are persisted in the storage.
+/// Check [module docs](crate::client::scheduler) for more information.
+#[derive(Debug, Clone)]
+pub struct Scheduler<S> {
+    storage: S,
+    tasks: Arc<DashMap<Box<str>, AbortHandle>>,
+    /// Lock for modifying jobs in the storage, because the storage doesn't support removing a single value
+    lock: Arc<AsyncMutex<()>>,
+}
+
+impl<S> Scheduler<S>
+where
+    S: Storage + Send + Sync + 'static,
+{
+    #[must_use]
+    pub fn new(storage: S) -> Self {
+        Self {
+            storage,
+            tasks: Arc::default(),
+            lock: Arc::default(),
+        }
+    }
+
+    fn key(bot_id: i64) -> StorageKey {
+        StorageKey::new(bot_id, 0, 0, None).destiny(DESTINY)
+    }
+
+    async fn remove_job(&self, bot_id: i64, id: &str) -> Result<bool, StorageError> {
+        let key = Self::key(bot_id);
+        let _guard = self.lock.lock().await;
+
+        let mut jobs: HashMap<Box<str>, Job> =
+            self.storage.get_data(&key).await.map_err(Into::into)?;
+
+        if jobs.remove(id).is_none() {
+            return Ok(false);
+        }
+
+        self.storage
+            .set_data(
+                &key,
+                jobs.into_iter()
+                    .map(|(id, job)| (id.into_string(), job))
+                    .collect(),
+            )
+            .await
+            .map_err(Into::into)?;
+
+        Ok(true)
+    }
+
+    fn spawn<Client, R>(&self, bot: &Bot<Client>, id: Box<str>, job: Job) -> Handle<R>
+    where
+        Client: Session + Clone + 'static,
+        R: DeserializeOwned + Send + 'static,
+    {
+        let at = persisted::from_timestamp(job.at);
+        let (registered_sender, registered_receiver) = oneshot::channel::<()>();
+        let task = tokio::spawn({
+            let scheduler = self.clone();
+            let bot = bot.clone();
+            let id = id.clone();
+
+            async move {
+                // Wait until the task is registered, so it isn't removed before the insertion and the stale entry isn't left
+                let _ = registered_receiver.await;
+
+                tokio::time::sleep(delay_until(at)).await;
+
+                let result = job.request.send(&bot).await;
+
+                scheduler.tasks.remove(&id);
+
+                // Request is removed even if it's unsuccessful, because it can't be sent again in most cases
+                if let Err(err) = scheduler.remove_job(bot.bot_id, &id).await {
+                    event!(Level::ERROR, error = %err, %id, "Failed to remove sent request from the storage");
+                }
+
+                result
+            }
+        });
+
+        self.tasks.insert(id.clone(), task.abort_handle());
+        let _ = registered_sender.send(());
+
+        Handle { id, at, task }
+    }
+
+    /// Persists the request in the storage and sends it at the specified time
+    /// # Errors
+    /// - If the request contains files, which are uploaded with `multipart/form-data`
+    /// - If the request cannot be serialized
+    /// - If the request cannot be saved in the storage
+    pub async fn send_later<Client, T>(
+        &self,
+        bot: &Bot<Client>,
+        method: &T,
+        at: SystemTime,
+    ) -> Result<Handle<T::Return>, Error>
+    where
+        Client: Session + Clone + 'static,
+        T: TelegramMethod,
+        T::Return: Send + 'static,
+    {
+        let id: Box<str> = Uuid::new_v4().to_string().into();
+        let job = Job {
+            request: PersistedRequest::new(bot, method)?.ok_or(Error::Files)?,
+            at: persisted::timestamp(at),
+        };
+
+        {
+            let _guard = self.lock.lock().await;
+
+            self.storage
+                .set_value(&Self::key(bot.bot_id), id.clone().into_string(), &job)
+                .await
+                .map_err(Into::into)?;
+        }
+
+        Ok(self.spawn(bot, id, job))
+    }
+
+    /// Persists the request in the storage and sends it after the delay.
+    /// Check [`Scheduler::send_later`] for more information.
+    /// # Errors
+    /// - If the request contains files, which are uploaded with `multipart/form-data`
+    /// - If the request cannot be serialized
+    /// - If the request cannot be saved in the storage
+    pub async fn send_after<Client, T>(
+        &self,
+        bot: &Bot<Client>,
+        method: &T,
+        delay: Duration,
+    ) -> Result<Handle<T::Return>, Error>
+    where
+        Client: Session + Clone + 'static,
+        T: TelegramMethod,
+        T::Return: Send + 'static,
+    {
+        self.send_later(bot, method, SystemTime::now() + delay)
+            .await
+    }
+
+    /// Restores persisted requests of the bot from the storage, for example, after the restart.
+    /// Requests, which are already scheduled by this scheduler, are skipped.
+    /// # Returns
+    /// Handles of the restored requests with raw JSON results
+    /// # Errors
+    /// If the requests cannot be loaded from the storage
+    pub async fn restore<Client>(&self, bot: &Bot<Client>) -> Result<Box<[Handle<Value>]>, Error>
+    where
+        Client: Session + Clone + 'static,
+    {
+        let jobs: HashMap<Box<str>, Job> = {
+            let _guard = self.lock.lock().await;
+
+            self.storage
+                .get_data(&Self::key(bot.bot_id))
+                .await
+                .map_err(Into::into)?
+        };
+
+        Ok(jobs
+            .into_iter()
+            .filter(|(id, _)| !self.tasks.contains_key(id))
+            .map(|(id, job)| self.spawn(bot, id, job))
+            .collect())
+    }
+
+    /// Cancels the request and removes it from the storage
+    /// # Returns
+    /// `true` if the request is found in the storage
+    /// # Errors
+    /// If the request cannot be removed from the storage
+    pub async fn cancel(&self, bot_id: i64, id: &str) -> Result<bool, Error> {
+        if let Some((_, task)) = self.tasks.remove(id) {
+            task.abort();
+        }
+
+        self.remove_job(bot_id, id).await.map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{session::base::ClientResponse, telegram::APIServer},
+        methods::SendMessage,
+    };
+
+    use async_trait::async_trait;
+
+    #[derive(Clone)]
+    struct Responder {
+        api: APIServer,
+    }
+
+    #[async_trait]
+    impl Session for Responder {
+        fn api(&self) -> &APIServer {
+            &self.api
+        }
+
+        async fn send_request<Client, T>(
+            &self,
+            _bot: &Bot<Client>,
+            _method: &T,
+            _timeout: Option<Duration>,
+        ) -> Result<ClientResponse, anyhow::Error>
+        where
+            Client: Session,
+            T: TelegramMethod + Send + Sync,
+            T::Method: Send + Sync,
+        {
+            Ok(ClientResponse::new(
+                200,
+                r#"{"ok": false, "error_code": 400, "description": "Bad Request"}"#,
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_later() {
+        let bot = Bot::new("1234567890:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA");
+
+        let handle = bot.send_after(SendMessage::new(1, "text"), Duration::from_secs(60));
+        assert!(!handle.is_finished());
+
+        handle.cancel();
+        assert!(matches!(handle.wait().await, Err(Error::Cancelled)));
+    }
+
+    #[cfg(feature = "memory-storage")]
+    #[tokio::test]
+    async fn test_scheduler() {
+        use crate::fsm::MemoryStorage;
+
+        let bot = Bot::new("1234567890:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA");
+        let storage = MemoryStorage::default();
+        let scheduler = Scheduler::new(storage.clone());
+        let key = StorageKey::new(bot.bot_id, 0, 0, None).destiny(DESTINY);
+
+        let handle = scheduler
+            .send_after(&bot, &SendMessage::new(1, "text"), Duration::from_secs(60))
+            .await
+            .unwrap();
+
+        let job: Job = storage
+            .get_value(&key, handle.id().to_owned())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(job.request.method_name(), "sendMessage");
+        assert_eq!(
+            serde_json::from_str::<Value>(job.request.params()).unwrap()["text"],
+            "text"
+        );
+
+        // Already scheduled requests aren't restored twice
+        assert!(scheduler.restore(&bot).await.unwrap().is_empty());
+
+        // Another scheduler with the same storage restores persisted requests
+        let restored = Scheduler::new(storage.clone()).restore(&bot).await.unwrap();
+        assert_eq!(restored.len(), 1);
+        assert_eq!(restored[0].id(), handle.id());
+        restored[0].cancel();
+
+        assert!(scheduler.cancel(bot.bot_id, handle.id()).await.unwrap());
+        assert!(!scheduler.cancel(bot.bot_id, handle.id()).await.unwrap());
+        assert!(matches!(handle.wait().await, Err(Error::Cancelled)));
+        assert!(storage.get_data::<Job>(&key).await.unwrap().is_empty());
+    }
+
+    #[cfg(feature = "memory-storage")]
+    #[tokio::test]
+    async fn test_completed_task_is_removed() {
+        use crate::fsm::MemoryStorage;
+
+        let bot = Bot::with_client(
+            "1234567890:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+            Responder {
+                api: APIServer::default(),
+            },
+        );
+        let scheduler = Scheduler::new(MemoryStorage::default());
+
+        for _ in 0..10 {
+            let handle = scheduler
+                .send_later(&bot, &SendMessage::new(1, "text"), SystemTime::UNIX_EPOCH)
+                .await
+                .unwrap();
+            let _ = handle.wait().await;
+        }
+
+        assert!(scheduler.tasks.is_empty());
+    }
+}
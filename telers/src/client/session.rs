@@ -2,12 +2,36 @@
 //!
 //! Components are:
 //! - [`base`] module with basic types and traits for sending requests
-//! - [`reqwest`] module with reqwest client implementation
+//! - [`reqwest`] module with reqwest client implementation (with `reqwest` feature, enabled by default)
+//! - [`layer`] module with [`SessionLayer`] trait for composing session decorators
+//! - [`logging`] module with session decorator, which logs requests and responses
+//! - [`idempotency`] module with session decorator, which suppresses duplicate requests (with `fsm` feature)
+//! - [`streaming`] module with decoder of the response content, which is being received
 //!
 //! Check each submodule for more information.
 
 pub mod base;
+#[cfg(feature = "fsm")]
+pub mod idempotency;
+pub mod layer;
+pub mod logging;
+#[cfg(feature = "reqwest")]
 pub mod reqwest;
+pub mod streaming;
 
-pub use self::reqwest::Reqwest;
-pub use base::{ClientResponse, Session, StatusCode};
+#[cfg(feature = "reqwest")]
+pub use self::reqwest::{ClientOptions, Reqwest};
+pub use base::{ClientResponse, Session, StatusCode, StreamedClientResponse, Unset};
+#[cfg(feature = "fsm")]
+pub use idempotency::IdempotentSession;
+
+/// Default client type parameter of [`Bot`](crate::client::Bot) and other components, which send requests:
+/// [`Reqwest`] with `reqwest` feature and [`Unset`] otherwise
+#[cfg(feature = "reqwest")]
+pub type DefaultSession = Reqwest;
+/// Default client type parameter of [`Bot`](crate::client::Bot) and other components, which send requests:
+/// `Reqwest` with `reqwest` feature and [`Unset`] otherwise
+#[cfg(not(feature = "reqwest"))]
+pub type DefaultSession = Unset;
+pub use layer::SessionLayer;
+pub use logging::LoggingSession;
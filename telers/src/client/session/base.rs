@@ -1,10 +1,12 @@
 //! This module contains [`Session`] trait that needs to be implemented for sending requests to Telegram Bot API.
 //!
 //! Supported implementations:
-//! - [`Reqwest`] - uses reqwest client. Check [module docs](crate::client::session::reqwest) for more information.
+//! - [`Reqwest`] - uses reqwest client (with `reqwest` feature). Check [module docs](crate::client::session::reqwest) for more information.
 //!
 //! [`Reqwest`]: crate::client::session::reqwest::Reqwest
 
+use super::streaming::ResultDecoder;
+
 use crate::{
     client::{telegram::APIServer, Bot},
     errors::{SessionErrorKind, TelegramErrorKind},
@@ -12,16 +14,20 @@ use crate::{
 };
 
 use async_trait::async_trait;
-use serde::de::DeserializeOwned;
+use bytes::Bytes;
+use futures::stream::{self, BoxStream, StreamExt as _};
+use serde::de::{DeserializeOwned, IgnoredAny};
 use std::{
-    fmt::{self, Display, Formatter},
+    fmt::{self, Debug, Display, Formatter},
     ops::RangeInclusive,
+    time::Duration,
 };
-use tracing::{event, instrument, Level, Span};
+use tracing::{event, field, instrument, Level, Span};
 
-pub const DEFAULT_TIMEOUT: f32 = 60.0;
+/// Default total timeout of the request, which is used by the client if timeout isn't passed to the request
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct StatusCode(u16);
 
 impl StatusCode {
@@ -66,10 +72,15 @@ impl From<u16> for StatusCode {
     }
 }
 
+/// This object represents a raw response of the Telegram Bot API server, which is returned by [`Session::send_request`]
 #[derive(Debug)]
 pub struct ClientResponse {
     pub status_code: StatusCode,
     pub content: Box<str>,
+    /// Value of the `Retry-After` header, if the server asks to wait before the next request
+    pub retry_after: Option<Duration>,
+    /// Time between sending the request and receiving the response content
+    pub elapsed: Option<Duration>,
 }
 
 impl ClientResponse {
@@ -78,10 +89,117 @@ impl ClientResponse {
         Self {
             status_code: status_code.into(),
             content: content.into(),
+            retry_after: None,
+            elapsed: None,
+        }
+    }
+
+    #[must_use]
+    pub fn retry_after(self, val: Duration) -> Self {
+        Self {
+            retry_after: Some(val),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn retry_after_option(self, val: Option<Duration>) -> Self {
+        Self {
+            retry_after: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn elapsed(self, val: Duration) -> Self {
+        Self {
+            elapsed: Some(val),
+            ..self
+        }
+    }
+
+    /// Parse value of the `Retry-After` header
+    /// # Notes
+    /// Only delay in seconds is supported, because Telegram doesn't use HTTP dates in this header
+    #[must_use]
+    pub fn parse_retry_after(header: &str) -> Option<Duration> {
+        header.trim().parse().ok().map(Duration::from_secs)
+    }
+
+    /// Convert the error to [`TelegramErrorKind::RetryAfter`], if the server responds with `429 Too Many Requests` status code
+    /// and `Retry-After` header, but without `retry_after` parameter in the response body (for example, the response is from a proxy)
+    #[must_use]
+    pub fn with_retry_after(&self, err: TelegramErrorKind) -> TelegramErrorKind {
+        match (err, self.retry_after) {
+            (err, Some(retry_after))
+                if self.status_code == 429 && !matches!(err, TelegramErrorKind::RetryAfter { .. }) =>
+            {
+                TelegramErrorKind::RetryAfter {
+                    url: "https://core.telegram.org/bots/faq#my-bot-is-hitting-limits-how-do-i-avoid-this",
+                    message: err.to_string().into(),
+                    retry_after: retry_after.as_secs().try_into().unwrap_or(i64::MAX),
+                }
+            }
+            (err, _) => err,
         }
     }
 }
 
+/// Stream of chunks of the response content
+pub type ContentStream = BoxStream<'static, Result<Bytes, anyhow::Error>>;
+
+/// Stream of raw JSON of elements of the `result` array, which is returned by [`Session::make_request_streamed`]
+pub type ResultStream<'a> = BoxStream<'a, Result<Box<[u8]>, SessionErrorKind>>;
+
+/// This object represents a raw response of the Telegram Bot API server with the content, which is being received,
+/// which is returned by [`Session::send_request_streamed`]
+pub struct StreamedClientResponse {
+    pub status_code: StatusCode,
+    pub content: ContentStream,
+    /// Value of the `Retry-After` header, if the server asks to wait before the next request
+    pub retry_after: Option<Duration>,
+}
+
+impl StreamedClientResponse {
+    #[must_use]
+    pub fn new(status_code: impl Into<StatusCode>, content: ContentStream) -> Self {
+        Self {
+            status_code: status_code.into(),
+            content,
+            retry_after: None,
+        }
+    }
+
+    #[must_use]
+    pub fn retry_after_option(self, val: Option<Duration>) -> Self {
+        Self {
+            retry_after: val,
+            ..self
+        }
+    }
+}
+
+impl Debug for StreamedClientResponse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamedClientResponse")
+            .field("status_code", &self.status_code)
+            .field("retry_after", &self.retry_after)
+            .finish_non_exhaustive()
+    }
+}
+
+impl From<ClientResponse> for StreamedClientResponse {
+    fn from(response: ClientResponse) -> Self {
+        let content = Bytes::from(String::from(response.content));
+
+        Self::new(
+            response.status_code,
+            stream::once(async { Ok(content) }).boxed(),
+        )
+        .retry_after_option(response.retry_after)
+    }
+}
+
 #[async_trait]
 pub trait Session: Send + Sync {
     /// Get configuration of Telegram Bot API server endpoints and local mode
@@ -101,7 +219,7 @@ pub trait Session: Send + Sync {
         &self,
         bot: &Bot<Client>,
         method: &T,
-        timeout: Option<f32>,
+        timeout: Option<Duration>,
     ) -> Result<ClientResponse, anyhow::Error>
     where
         Client: Session,
@@ -219,12 +337,15 @@ pub trait Session: Send + Sync {
     /// - If the request cannot be send or decoded
     /// - If the response cannot be parsed
     /// - If the response represents an telegram api error
-    #[instrument(skip(self, bot, method, timeout), fields(bot_id))]
+    #[instrument(
+        skip(self, bot, method, timeout),
+        fields(bot_id, status_code, elapsed, retry_after)
+    )]
     async fn make_request<Client, T>(
         &self,
         bot: &Bot<Client>,
         method: &T,
-        timeout: Option<f32>,
+        timeout: Option<Duration>,
     ) -> Result<Response<T::Return>, SessionErrorKind>
     where
         Client: Session,
@@ -246,6 +367,14 @@ pub trait Session: Send + Sync {
                 err
             })?;
 
+        Span::current().record("status_code", response.status_code.as_u16());
+        if let Some(elapsed) = response.elapsed {
+            Span::current().record("elapsed", field::debug(elapsed));
+        }
+        if let Some(retry_after) = response.retry_after {
+            Span::current().record("retry_after", retry_after.as_secs());
+        }
+
         event!(
             Level::TRACE,
             content = response.content,
@@ -264,13 +393,15 @@ pub trait Session: Send + Sync {
                         "Cannot parse response content",
                     );
 
-                    err
+                    method.build_response_error(response.content.as_ref(), err)
                 })?;
 
         event!(Level::TRACE, "Response parsed successfully",);
 
         self.check_response(&telegram_response, &response.status_code)
             .map_err(|err| {
+                let err = response.with_retry_after(err);
+
                 event!(
                     Level::ERROR,
                     error = %err,
@@ -297,7 +428,7 @@ pub trait Session: Send + Sync {
         &self,
         bot: &Bot<Client>,
         method: &T,
-        timeout: Option<f32>,
+        timeout: Option<Duration>,
     ) -> Result<T::Return, SessionErrorKind>
     where
         Client: Session,
@@ -310,12 +441,129 @@ pub trait Session: Send + Sync {
         Ok(response.result.unwrap())
     }
 
+    /// Makes a request to Telegram API and returns the response content as a stream of chunks, while it's being received.
+    /// Default implementation waits for the full content with [`Session::send_request`],
+    /// so implement this method, if the client supports streaming of the response content.
+    /// # Arguments
+    /// * `bot` - Bot instance for building request, it is mainly used for getting bot token
+    /// * `method` - Telegram method for building request
+    /// * `timeout` - Request timeout.
+    /// If `None`, then client timeout will be used, which is [`DEFAULT_TIMEOUT`] by default.
+    /// # Errors
+    /// If the request cannot be send
+    async fn send_request_streamed<Client, T>(
+        &self,
+        bot: &Bot<Client>,
+        method: &T,
+        timeout: Option<Duration>,
+    ) -> Result<StreamedClientResponse, anyhow::Error>
+    where
+        Client: Session,
+        T: TelegramMethod + Send + Sync,
+        T::Method: Send + Sync,
+    {
+        self.send_request(bot, method, timeout)
+            .await
+            .map(Into::into)
+    }
+
+    /// Makes a request to Telegram API, which returns an array, and decodes elements of the array one by one,
+    /// while the response content is being received (see [`ResultDecoder`]).
+    /// Elements are returned as raw JSON, so they are deserialized by the caller.
+    /// # Arguments
+    /// * `bot` - Bot instance for building and sending request, it is mainly used for getting bot token
+    /// * `method` - Telegram method for building and sending request
+    /// * `timeout` - Request timeout.
+    /// If `None`, then client timeout will be used, which is [`DEFAULT_TIMEOUT`] by default.
+    /// # Errors
+    /// If the request cannot be send.
+    /// Stream returns an error, if the response content cannot be received or parsed,
+    /// or the response represents an telegram api error.
+    #[instrument(skip(self, bot, method, timeout), fields(bot_id, status_code))]
+    async fn make_request_streamed<'a, Client, T>(
+        &'a self,
+        bot: &Bot<Client>,
+        method: &T,
+        timeout: Option<Duration>,
+    ) -> Result<ResultStream<'a>, SessionErrorKind>
+    where
+        Client: Session,
+        T: TelegramMethod + Send + Sync,
+        T::Method: Send + Sync,
+    {
+        Span::current().record("bot_id", bot.bot_id);
+
+        let StreamedClientResponse {
+            status_code,
+            content,
+            retry_after,
+        } = self
+            .send_request_streamed(bot, method, timeout)
+            .await
+            .map_err(|err| {
+                event!(
+                    Level::ERROR,
+                    error = %err,
+                    "Cannot send request to Telegram API",
+                );
+
+                err
+            })?;
+
+        Span::current().record("status_code", status_code.as_u16());
+
+        let state = (content, ResultDecoder::new());
+
+        Ok(
+            stream::try_unfold(state, move |(mut content, mut decoder)| async move {
+                loop {
+                    if let Some(element) = decoder.next_element() {
+                        return Ok(Some((element, (content, decoder))));
+                    }
+
+                    if let Some(chunk) = content.next().await {
+                        decoder.push(&chunk?);
+                        continue;
+                    }
+
+                    // Content without the `result` array is parsed as the usual response to get the error
+                    if let Some(content) = decoder.finish()? {
+                        let response = serde_json::from_slice::<Response<IgnoredAny>>(&content)?;
+
+                        self.check_response(&response, &status_code)
+                            .map_err(|err| {
+                                let err = ClientResponse::new(status_code.as_u16(), "")
+                                    .retry_after_option(retry_after)
+                                    .with_retry_after(err);
+
+                                event!(
+                                    Level::ERROR,
+                                    error = %err,
+                                    "Response represents an telegram api error",
+                                );
+
+                                err
+                            })?;
+                    }
+
+                    return Ok(None);
+                }
+            })
+            .boxed(),
+        )
+    }
+
     /// Close client session. Default implementation does nothing.
     async fn close(&self) -> Result<(), anyhow::Error> {
         Ok(())
     }
 }
 
+/// Placeholder of the client, which is used as the default client type parameter if `reqwest` feature is disabled.
+/// It can't be created and doesn't implement [`Session`], so specify your client type explicitly, for example, `Bot<MyClient>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unset {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -323,6 +571,46 @@ mod tests {
 
     use serde_json::json;
 
+    #[test]
+    fn test_with_retry_after() {
+        assert_eq!(
+            ClientResponse::parse_retry_after(" 15"),
+            Some(Duration::from_secs(15))
+        );
+        assert_eq!(
+            ClientResponse::parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+            None
+        );
+
+        let response =
+            ClientResponse::new(429, "Too Many Requests").retry_after(Duration::from_secs(5));
+        let err = response.with_retry_after(anyhow::Error::msg("Too Many Requests").into());
+        assert!(matches!(
+            err,
+            TelegramErrorKind::RetryAfter { retry_after: 5, .. }
+        ));
+
+        // Parameter from the response body has priority over the header
+        let err = response.with_retry_after(TelegramErrorKind::RetryAfter {
+            url: "",
+            message: "Too Many Requests".into(),
+            retry_after: 10,
+        });
+        assert!(matches!(
+            err,
+            TelegramErrorKind::RetryAfter {
+                retry_after: 10,
+                ..
+            }
+        ));
+
+        let response = ClientResponse::new(400, "Bad Request").retry_after(Duration::from_secs(5));
+        let err = response.with_retry_after(TelegramErrorKind::BadRequest {
+            message: "Bad Request".into(),
+        });
+        assert!(matches!(err, TelegramErrorKind::BadRequest { .. }));
+    }
+
     #[test]
     fn build_response() {
         let method = SendMessage::new(810646651, "Hello, abc!");
@@ -0,0 +1,542 @@
+//! This module contains [`IdempotentSession`] struct, which is a decorator for any [`Session`] implementation
+//! and suppresses accidental duplicate sends.
+//!
+//! Retries after timeouts, webhook redeliveries and double-processing of the same update can send the same message twice.
+//! To prevent it, the decorator serializes the method name with its params (chat, content, keyboard, etc.)
+//! and stores successful responses in the FSM [`Storage`] for the configured window (by default [`DEFAULT_WINDOW`]).
+//! If the same request is sent again within the window, it isn't sent to the Telegram Bot API
+//! and the stored response is returned instead, so the caller gets the same result as for the first request.
+//!
+//! Only methods from the list (by default [`DEFAULT_METHODS`]) are checked, other methods are sent as is.
+//! Requests with files, which are uploaded with `multipart/form-data`, are always sent as is.
+//!
+//! Responses are stored by [`StorageKey`] with bot id, chat id and [`DESTINY`] destiny
+//! (zero chat id and `{DESTINY}:{username}` destiny, if the chat is specified by username), so the storage can be shared with FSM. Expired responses of the chat are removed, when a new response of the chat is stored.
+//!
+//! # Notes
+//! - Requests are compared by the serialized method name and params, so stored responses are matched exactly
+//!   and can be shared between processes and versions of the library
+//! - Duplicates, which are sent concurrently, aren't suppressed, because the response isn't stored yet
+//! - Storage errors don't fail requests, they are only logged, so the decorator never makes sending less reliable
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use telers::{
+//!     client::{session::IdempotentSession, Reqwest},
+//!     fsm::MemoryStorage,
+//!     Bot,
+//! };
+//!
+//! let session =
+//!     IdempotentSession::new(Reqwest::default(), MemoryStorage::new()).window(Duration::from_secs(30));
+//! let bot = Bot::with_client("1234567890:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA", session);
+//! ```
+//!
+//! [`Storage`]: crate::fsm::Storage
+
+use super::base::{ClientResponse, Session, StatusCode, StreamedClientResponse};
+
+use crate::{
+    client::{persisted, telegram::APIServer, Bot},
+    errors::TelegramErrorKind,
+    fsm::{storage::Error as StorageError, Storage, StorageKey},
+    methods::{Response, TelegramMethod},
+};
+
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+use tokio::sync::Mutex;
+use tracing::{event, Level};
+
+/// Destiny of the storage key, by which responses are stored
+pub const DESTINY: &str = "idempotency";
+
+/// Default window, within which duplicate requests are suppressed
+pub const DEFAULT_WINDOW: Duration = Duration::from_secs(60);
+
+/// Methods, which are checked for duplicates by default
+pub const DEFAULT_METHODS: &[&str] = &[
+    "sendMessage",
+    "sendPhoto",
+    "sendAudio",
+    "sendDocument",
+    "sendVideo",
+    "sendAnimation",
+    "sendVoice",
+    "sendVideoNote",
+    "sendMediaGroup",
+    "sendLocation",
+    "sendVenue",
+    "sendContact",
+    "sendPoll",
+    "sendDice",
+    "sendSticker",
+    "sendInvoice",
+    "sendGame",
+    "forwardMessage",
+    "forwardMessages",
+    "copyMessage",
+    "copyMessages",
+];
+
+/// Successful response, which is stored in the storage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    status_code: u16,
+    content: Box<str>,
+    /// Time, when the entry is expired, in milliseconds since the Unix epoch
+    expires_at: u64,
+}
+
+/// Request, which is checked for duplicates
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Fingerprint {
+    key: StorageKey,
+    /// Method name with the serialized params, by which the response is stored
+    request: String,
+}
+
+/// Decorator for [`Session`], which suppresses duplicate requests within the window.
+/// Check [module docs](crate::client::session::idempotency) for more information.
+#[derive(Debug, Clone)]
+pub struct IdempotentSession<S, St> {
+    session: S,
+    storage: St,
+    window: Duration,
+    methods: Box<[Box<str>]>,
+    /// Lock for modifying entries in the storage, because the storage doesn't support removing a single value
+    lock: Arc<Mutex<()>>,
+}
+
+impl<S, St> IdempotentSession<S, St> {
+    /// Creates a new decorator for the session.
+    /// By default, [`DEFAULT_METHODS`] are checked for duplicates within [`DEFAULT_WINDOW`].
+    #[must_use]
+    pub fn new(session: S, storage: St) -> Self {
+        Self {
+            session,
+            storage,
+            window: DEFAULT_WINDOW,
+            methods: DEFAULT_METHODS
+                .iter()
+                .map(|method| (*method).into())
+                .collect(),
+            lock: Arc::default(),
+        }
+    }
+
+    /// Window, within which duplicate requests are suppressed
+    #[must_use]
+    pub fn window(self, val: Duration) -> Self {
+        Self {
+            window: val,
+            ..self
+        }
+    }
+
+    /// Add the method, which is checked for duplicates
+    #[must_use]
+    pub fn method(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            methods: self
+                .methods
+                .into_vec()
+                .into_iter()
+                .chain(Some(val.into()))
+                .collect(),
+            ..self
+        }
+    }
+
+    /// Replace the methods, which are checked for duplicates
+    #[must_use]
+    pub fn methods<T, I>(self, val: I) -> Self
+    where
+        T: Into<Box<str>>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            methods: val.into_iter().map(Into::into).collect(),
+            ..self
+        }
+    }
+
+    /// Gets the inner session
+    #[must_use]
+    pub const fn inner(&self) -> &S {
+        &self.session
+    }
+
+    /// Unwraps the decorator and returns the inner session
+    #[must_use]
+    pub fn into_inner(self) -> S {
+        self.session
+    }
+
+    /// # Returns
+    /// `None` if the method isn't checked for duplicates or the request contains files
+    fn fingerprint<Client, T>(&self, bot: &Bot<Client>, method: &T) -> Option<Fingerprint>
+    where
+        T: TelegramMethod,
+    {
+        let request = method.build_request(bot);
+
+        if !self
+            .methods
+            .iter()
+            .any(|name| **name == *request.method_name)
+            || request.files.map_or(false, |files| !files.is_empty())
+        {
+            return None;
+        }
+
+        let params = serde_json::to_value(request.data).ok()?;
+        let (chat_id, destiny): (i64, Cow<'static, str>) = match params.get("chat_id") {
+            Some(Value::String(username)) => (0, format!("{DESTINY}:{username}").into()),
+            chat_id => (chat_id.and_then(Value::as_i64).unwrap_or(0), DESTINY.into()),
+        };
+
+        Some(Fingerprint {
+            key: StorageKey::new(bot.bot_id, chat_id, 0, None).destiny(destiny),
+            request: format!("{}:{params}", request.method_name),
+        })
+    }
+}
+
+impl<S, St> IdempotentSession<S, St>
+where
+    St: Storage,
+{
+    /// Gets stored response of the request, if it isn't expired
+    async fn get(&self, fingerprint: &Fingerprint) -> Result<Option<ClientResponse>, StorageError> {
+        let entry: Option<Entry> = self
+            .storage
+            .get_value(&fingerprint.key, fingerprint.request.clone())
+            .await
+            .map_err(Into::into)?;
+
+        Ok(entry
+            .filter(|entry| entry.expires_at > persisted::timestamp(SystemTime::now()))
+            .map(|entry| ClientResponse::new(entry.status_code, entry.content)))
+    }
+
+    /// Stores response of the request and removes expired responses of the same chat
+    async fn set(
+        &self,
+        fingerprint: &Fingerprint,
+        response: &ClientResponse,
+    ) -> Result<(), StorageError> {
+        let _lock = self.lock.lock().await;
+
+        let now = SystemTime::now();
+        let timestamp = persisted::timestamp(now);
+
+        let mut entries: HashMap<String, Entry> = self
+            .storage
+            .get_data::<Entry>(&fingerprint.key)
+            .await
+            .map_err(Into::into)?
+            .into_iter()
+            .filter(|(_, entry)| entry.expires_at > timestamp)
+            .map(|(request, entry)| (request.into_string(), entry))
+            .collect();
+        entries.insert(
+            fingerprint.request.clone(),
+            Entry {
+                status_code: response.status_code.as_u16(),
+                content: response.content.clone(),
+                expires_at: persisted::timestamp(now + self.window),
+            },
+        );
+
+        self.storage
+            .set_data(&fingerprint.key, entries)
+            .await
+            .map_err(Into::into)
+    }
+}
+
+#[async_trait]
+impl<S, St> Session for IdempotentSession<S, St>
+where
+    S: Session,
+    St: Storage + Send + Sync,
+{
+    fn api(&self) -> &APIServer {
+        self.session.api()
+    }
+
+    async fn send_request<Client, T>(
+        &self,
+        bot: &Bot<Client>,
+        method: &T,
+        timeout: Option<Duration>,
+    ) -> Result<ClientResponse, anyhow::Error>
+    where
+        Client: Session,
+        T: TelegramMethod + Send + Sync,
+        T::Method: Send + Sync,
+    {
+        let Some(fingerprint) = self.fingerprint(bot, method) else {
+            return self.session.send_request(bot, method, timeout).await;
+        };
+
+        match self.get(&fingerprint).await {
+            Ok(Some(response)) => {
+                event!(
+                    Level::DEBUG,
+                    bot_id = bot.bot_id,
+                    "Duplicate request is suppressed, stored response is returned",
+                );
+
+                return Ok(response);
+            }
+            Ok(None) => {}
+            Err(err) => {
+                event!(
+                    Level::WARN,
+                    error = %err,
+                    "Cannot get stored response, request is sent without idempotency check",
+                );
+            }
+        }
+
+        let response = self.session.send_request(bot, method, timeout).await?;
+
+        if response.status_code.is_success() {
+            if let Err(err) = self.set(&fingerprint, &response).await {
+                event!(Level::WARN, error = %err, "Cannot store response");
+            }
+        }
+
+        Ok(response)
+    }
+
+    async fn send_request_streamed<Client, T>(
+        &self,
+        bot: &Bot<Client>,
+        method: &T,
+        timeout: Option<Duration>,
+    ) -> Result<StreamedClientResponse, anyhow::Error>
+    where
+        Client: Session,
+        T: TelegramMethod + Send + Sync,
+        T::Method: Send + Sync,
+    {
+        // Response is stored only after the full content is received,
+        // so requests, which are checked for duplicates, aren't streamed
+        if self.fingerprint(bot, method).is_some() {
+            return self
+                .send_request(bot, method, timeout)
+                .await
+                .map(Into::into);
+        }
+
+        self.session
+            .send_request_streamed(bot, method, timeout)
+            .await
+    }
+
+    fn check_response(
+        &self,
+        response: &Response<impl DeserializeOwned>,
+        status_code: &StatusCode,
+    ) -> Result<(), TelegramErrorKind> {
+        self.session.check_response(response, status_code)
+    }
+
+    async fn close(&self) -> Result<(), anyhow::Error> {
+        self.session.close().await
+    }
+}
+
+#[cfg(all(test, feature = "memory-storage"))]
+mod tests {
+    use super::*;
+    use crate::{
+        fsm::MemoryStorage,
+        methods::{GetMe, GetUpdates, SendMessage},
+    };
+
+    use bytes::Bytes;
+    use futures::{stream, StreamExt as _, TryStreamExt as _};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Session, which responds differently to buffered and streamed requests,
+    /// so it's possible to check which one is called by the decorator
+    #[derive(Default)]
+    struct Streamer {
+        api: APIServer,
+    }
+
+    #[async_trait]
+    impl Session for Streamer {
+        fn api(&self) -> &APIServer {
+            &self.api
+        }
+
+        async fn send_request<Client, T>(
+            &self,
+            _bot: &Bot<Client>,
+            _method: &T,
+            _timeout: Option<Duration>,
+        ) -> Result<ClientResponse, anyhow::Error>
+        where
+            Client: Session,
+            T: TelegramMethod + Send + Sync,
+            T::Method: Send + Sync,
+        {
+            Ok(ClientResponse::new(200, "buffered"))
+        }
+
+        async fn send_request_streamed<Client, T>(
+            &self,
+            _bot: &Bot<Client>,
+            _method: &T,
+            _timeout: Option<Duration>,
+        ) -> Result<StreamedClientResponse, anyhow::Error>
+        where
+            Client: Session,
+            T: TelegramMethod + Send + Sync,
+            T::Method: Send + Sync,
+        {
+            Ok(StreamedClientResponse::new(
+                200,
+                stream::iter([
+                    Ok(Bytes::from_static(b"stream")),
+                    Ok(Bytes::from_static(b"ed")),
+                ])
+                .boxed(),
+            ))
+        }
+    }
+
+    async fn content(response: StreamedClientResponse) -> Vec<u8> {
+        response
+            .content
+            .try_fold(vec![], |mut content, bytes| async move {
+                content.extend_from_slice(&bytes);
+                Ok(content)
+            })
+            .await
+            .unwrap()
+    }
+
+    #[derive(Default)]
+    struct Counter {
+        api: APIServer,
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl Session for Counter {
+        fn api(&self) -> &APIServer {
+            &self.api
+        }
+
+        async fn send_request<Client, T>(
+            &self,
+            _bot: &Bot<Client>,
+            _method: &T,
+            _timeout: Option<Duration>,
+        ) -> Result<ClientResponse, anyhow::Error>
+        where
+            Client: Session,
+            T: TelegramMethod + Send + Sync,
+            T::Method: Send + Sync,
+        {
+            let calls = self.calls.fetch_add(1, Ordering::SeqCst);
+
+            Ok(ClientResponse::new(200, calls.to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_duplicates_suppressed() {
+        let bot = Bot::with_client(
+            "1234567890:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+            Counter::default(),
+        );
+        let session = IdempotentSession::new(Counter::default(), MemoryStorage::new());
+
+        let send = |method| {
+            let session = &session;
+            let bot = &bot;
+
+            async move {
+                session
+                    .send_request(bot, &method, None)
+                    .await
+                    .unwrap()
+                    .content
+            }
+        };
+
+        assert_eq!(&*send(SendMessage::new(1, "text")).await, "0");
+        assert_eq!(&*send(SendMessage::new(1, "text")).await, "0");
+        assert_eq!(&*send(SendMessage::new(1, "other")).await, "1");
+        assert_eq!(&*send(SendMessage::new(2, "text")).await, "2");
+        // Chats, which are specified by username, aren't merged
+        assert_eq!(&*send(SendMessage::new("@first", "text")).await, "3");
+        assert_eq!(&*send(SendMessage::new("@second", "text")).await, "4");
+        assert_eq!(&*send(SendMessage::new("@first", "text")).await, "3");
+        assert_eq!(session.inner().calls.load(Ordering::SeqCst), 5);
+
+        // Methods, which aren't in the list, are always sent
+        for _ in 0..2 {
+            session
+                .send_request(&bot, &GetMe::new(), None)
+                .await
+                .unwrap();
+        }
+        assert_eq!(session.inner().calls.load(Ordering::SeqCst), 7);
+    }
+
+    #[tokio::test]
+    async fn test_window_expired() {
+        let bot = Bot::with_client(
+            "1234567890:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+            Counter::default(),
+        );
+        let session =
+            IdempotentSession::new(Counter::default(), MemoryStorage::new()).window(Duration::ZERO);
+        let method = SendMessage::new(1, "text");
+
+        session.send_request(&bot, &method, None).await.unwrap();
+        session.send_request(&bot, &method, None).await.unwrap();
+
+        assert_eq!(session.inner().calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_send_request_streamed() {
+        let bot = Bot::with_client(
+            "1234567890:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+            Streamer::default(),
+        );
+        let session = IdempotentSession::new(Streamer::default(), MemoryStorage::new());
+
+        // Methods, which aren't checked for duplicates, are streamed by the inner session
+        let response = session
+            .send_request_streamed(&bot, &GetUpdates::new(), None)
+            .await
+            .unwrap();
+        assert_eq!(content(response).await, b"streamed");
+
+        // Methods, which are checked for duplicates, are sent buffered to store the response
+        let response = session
+            .send_request_streamed(&bot, &SendMessage::new(1, "text"), None)
+            .await
+            .unwrap();
+        assert_eq!(content(response).await, b"buffered");
+    }
+}
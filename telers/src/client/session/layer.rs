@@ -0,0 +1,288 @@
+//! This module contains [`SessionLayer`] trait, which is used to wrap a [`Session`] into another one (decorator).
+//!
+//! Layers allow to add independent behaviour to the client, such as logging, retries, rate limiting or metrics,
+//! without hard-coding all of it into one session implementation.
+//! Each layer wraps the inner session and delegates requests to it, so layers can be composed in any order.
+//!
+//! Layers are applied with [`Builder::layer`] method: the first added layer is the innermost one,
+//! so the last added layer is called first.
+//!
+//! Supported layers:
+//! - [`Logging`] - wraps session into [`LoggingSession`]
+//! - [`Idempotency`] - wraps session into [`IdempotentSession`] (with `fsm` feature)
+//! - [`Identity`] - returns session as is
+//! - [`Stack`] - composition of two layers
+//! - Any function `Fn(S) -> Session`
+//!
+//! # Examples
+//!
+//! ```rust
+//! use telers::{
+//!     client::session::{layer, Reqwest},
+//!     Bot,
+//! };
+//!
+//! let bot = Bot::builder("1234567890:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA")
+//!     .client(Reqwest::default())
+//!     .layer(layer::Logging::new().log_payload(true))
+//!     .build();
+//! ```
+//!
+//! [`Builder::layer`]: crate::client::bot::Builder::layer
+
+use super::{base::Session, logging::LoggingSession};
+
+#[cfg(feature = "fsm")]
+use super::idempotency::{IdempotentSession, DEFAULT_METHODS, DEFAULT_WINDOW};
+#[cfg(feature = "fsm")]
+use crate::fsm::Storage;
+#[cfg(feature = "fsm")]
+use std::time::Duration;
+
+/// Implement this trait to wrap a session into another one
+pub trait SessionLayer<S> {
+    /// Session, which wraps the inner session
+    type Session: Session;
+
+    /// Wrap the inner session
+    #[must_use]
+    fn layer(&self, session: S) -> Self::Session;
+}
+
+/// To possible use function-like as layers
+impl<S, Func, Output> SessionLayer<S> for Func
+where
+    Func: Fn(S) -> Output,
+    Output: Session,
+{
+    type Session = Output;
+
+    fn layer(&self, session: S) -> Self::Session {
+        self(session)
+    }
+}
+
+/// Layer, which returns the session as is
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Identity;
+
+impl<S> SessionLayer<S> for Identity
+where
+    S: Session,
+{
+    type Session = S;
+
+    fn layer(&self, session: S) -> Self::Session {
+        session
+    }
+}
+
+/// Composition of two layers: `inner` layer is applied first, then `outer` layer
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Stack<Inner, Outer> {
+    inner: Inner,
+    outer: Outer,
+}
+
+impl<Inner, Outer> Stack<Inner, Outer> {
+    #[must_use]
+    pub const fn new(inner: Inner, outer: Outer) -> Self {
+        Self { inner, outer }
+    }
+}
+
+impl<S, Inner, Outer> SessionLayer<S> for Stack<Inner, Outer>
+where
+    Inner: SessionLayer<S>,
+    Outer: SessionLayer<Inner::Session>,
+{
+    type Session = Outer::Session;
+
+    fn layer(&self, session: S) -> Self::Session {
+        self.outer.layer(self.inner.layer(session))
+    }
+}
+
+/// Layer, which wraps the session into [`LoggingSession`].
+/// Check [`LoggingSession`] for the description of the options.
+#[derive(Debug, Clone)]
+pub struct Logging {
+    redact_token: bool,
+    redacted_fields: Box<[Box<str>]>,
+    log_payload: bool,
+    log_response: bool,
+}
+
+impl Logging {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            redact_token: true,
+            redacted_fields: Box::new([]),
+            log_payload: false,
+            log_response: false,
+        }
+    }
+
+    #[must_use]
+    pub fn redact_token(self, val: bool) -> Self {
+        Self {
+            redact_token: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn redact_field(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            redacted_fields: self
+                .redacted_fields
+                .into_vec()
+                .into_iter()
+                .chain(Some(val.into()))
+                .collect(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn log_payload(self, val: bool) -> Self {
+        Self {
+            log_payload: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn log_response(self, val: bool) -> Self {
+        Self {
+            log_response: val,
+            ..self
+        }
+    }
+}
+
+impl Default for Logging {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> SessionLayer<S> for Logging
+where
+    S: Session,
+{
+    type Session = LoggingSession<S>;
+
+    fn layer(&self, session: S) -> Self::Session {
+        LoggingSession::new(session)
+            .redact_token(self.redact_token)
+            .redact_fields(self.redacted_fields.iter().cloned())
+            .log_payload(self.log_payload)
+            .log_response(self.log_response)
+    }
+}
+
+/// Layer, which wraps the session into [`IdempotentSession`].
+/// Check [`IdempotentSession`] for the description of the options.
+#[cfg(feature = "fsm")]
+#[derive(Debug, Clone)]
+pub struct Idempotency<St> {
+    storage: St,
+    window: Duration,
+    methods: Box<[Box<str>]>,
+}
+
+#[cfg(feature = "fsm")]
+impl<St> Idempotency<St> {
+    #[must_use]
+    pub fn new(storage: St) -> Self {
+        Self {
+            storage,
+            window: DEFAULT_WINDOW,
+            methods: DEFAULT_METHODS
+                .iter()
+                .map(|method| (*method).into())
+                .collect(),
+        }
+    }
+
+    #[must_use]
+    pub fn window(self, val: Duration) -> Self {
+        Self {
+            window: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn method(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            methods: self
+                .methods
+                .into_vec()
+                .into_iter()
+                .chain(Some(val.into()))
+                .collect(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn methods<T, I>(self, val: I) -> Self
+    where
+        T: Into<Box<str>>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            methods: val.into_iter().map(Into::into).collect(),
+            ..self
+        }
+    }
+}
+
+#[cfg(feature = "fsm")]
+impl<S, St> SessionLayer<S> for Idempotency<St>
+where
+    S: Session,
+    St: Storage + Send + Sync,
+{
+    type Session = IdempotentSession<S, St>;
+
+    fn layer(&self, session: S) -> Self::Session {
+        IdempotentSession::new(session, self.storage.clone())
+            .window(self.window)
+            .methods(self.methods.iter().cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{Bot, Reqwest};
+
+    #[test]
+    fn test_stack() {
+        let layer = Stack::new(Logging::new(), Stack::new(Identity, Logging::new()));
+        let session: LoggingSession<LoggingSession<Reqwest>> = layer.layer(Reqwest::default());
+
+        assert_eq!(
+            session.inner().inner().api().api_url("token", "getMe"),
+            Reqwest::default().api().api_url("token", "getMe")
+        );
+
+        let session = (|session| LoggingSession::new(session)).layer(Reqwest::default());
+        let _: Reqwest = session.into_inner();
+    }
+
+    #[test]
+    fn test_bot_builder() {
+        let bot: Bot<LoggingSession<LoggingSession<Reqwest>>> =
+            Bot::builder("1234567890:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA")
+                .layer(Logging::new())
+                .layer(Logging::new().log_payload(true))
+                .build();
+
+        assert_eq!(bot.bot_id, 1_234_567_890);
+    }
+}
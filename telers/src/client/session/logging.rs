@@ -0,0 +1,490 @@
+//! This module contains [`LoggingSession`] struct, which is a decorator for any [`Session`] implementation
+//! and logs outgoing requests and incoming responses.
+//!
+//! Each request is logged with its method name, payload (if enabled) and files, which are sent with it.
+//! Each response is logged with its status code, content (if enabled), execution time and parsed Telegram API error.
+//!
+//! Sensitive data is redacted before logging:
+//! - Bot token is replaced with hidden token (see [`Bot::hidden_token`]) in payloads, responses and errors (it can be disabled by [`LoggingSession::redact_token`])
+//! - Values of redacted fields (by default [`DEFAULT_REDACTED_FIELDS`]) are replaced with [`REDACTED`] in payloads
+//! - File contents are never logged, only file ids and names
+//!
+//! # Examples
+//!
+//! ```rust
+//! use telers::{
+//!     client::{session::LoggingSession, Reqwest},
+//!     Bot,
+//! };
+//!
+//! let session = LoggingSession::new(Reqwest::default())
+//!     .log_payload(true)
+//!     .redact_field("password");
+//! let bot = Bot::with_client("1234567890:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA", session);
+//! ```
+
+use super::base::{ClientResponse, Session, StatusCode, StreamedClientResponse};
+
+use crate::{
+    client::{telegram::APIServer, Bot},
+    errors::TelegramErrorKind,
+    methods::{Response, TelegramMethod},
+    types::InputFile,
+};
+
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use std::time::{Duration, Instant};
+use tracing::{event, Level};
+
+/// Replacement for values of redacted fields
+pub const REDACTED: &str = "<redacted>";
+
+/// Fields, which values are redacted by default
+pub const DEFAULT_REDACTED_FIELDS: &[&str] = &["provider_token", "secret_token"];
+
+/// Decorator for [`Session`], which logs requests and responses with redaction of sensitive data.
+/// Check [module docs](crate::client::session::logging) for more information.
+#[derive(Debug, Clone)]
+pub struct LoggingSession<S> {
+    session: S,
+    redact_token: bool,
+    redacted_fields: Box<[Box<str>]>,
+    log_payload: bool,
+    log_response: bool,
+}
+
+impl<S> LoggingSession<S> {
+    /// Creates a new decorator for the session.
+    /// By default, the token and [`DEFAULT_REDACTED_FIELDS`] are redacted, payloads and response contents aren't logged.
+    #[must_use]
+    pub fn new(session: S) -> Self {
+        Self {
+            session,
+            redact_token: true,
+            redacted_fields: DEFAULT_REDACTED_FIELDS
+                .iter()
+                .map(|field| (*field).into())
+                .collect(),
+            log_payload: false,
+            log_response: false,
+        }
+    }
+
+    /// Replace bot token with hidden token in logs
+    #[must_use]
+    pub fn redact_token(self, val: bool) -> Self {
+        Self {
+            redact_token: val,
+            ..self
+        }
+    }
+
+    /// Add field, which value will be replaced with [`REDACTED`] in payloads
+    #[must_use]
+    pub fn redact_field(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            redacted_fields: self
+                .redacted_fields
+                .into_vec()
+                .into_iter()
+                .chain(Some(val.into()))
+                .collect(),
+            ..self
+        }
+    }
+
+    /// Add fields, which values will be replaced with [`REDACTED`] in payloads
+    #[must_use]
+    pub fn redact_fields<T, I>(self, val: I) -> Self
+    where
+        T: Into<Box<str>>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            redacted_fields: self
+                .redacted_fields
+                .into_vec()
+                .into_iter()
+                .chain(val.into_iter().map(Into::into))
+                .collect(),
+            ..self
+        }
+    }
+
+    /// Log payloads of requests
+    #[must_use]
+    pub fn log_payload(self, val: bool) -> Self {
+        Self {
+            log_payload: val,
+            ..self
+        }
+    }
+
+    /// Log contents of responses
+    #[must_use]
+    pub fn log_response(self, val: bool) -> Self {
+        Self {
+            log_response: val,
+            ..self
+        }
+    }
+
+    /// Gets the inner session
+    #[must_use]
+    pub const fn inner(&self) -> &S {
+        &self.session
+    }
+
+    /// Unwraps the decorator and returns the inner session
+    #[must_use]
+    pub fn into_inner(self) -> S {
+        self.session
+    }
+
+    fn redact_value(&self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map.iter_mut() {
+                    if self.redacted_fields.iter().any(|field| **field == **key) {
+                        *value = Value::String(REDACTED.to_owned());
+                    } else {
+                        self.redact_value(value);
+                    }
+                }
+            }
+            Value::Array(values) => {
+                for value in values {
+                    self.redact_value(value);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn redact_str<Client: ?Sized>(&self, bot: &Bot<Client>, val: &str) -> String {
+        if self.redact_token && !bot.token.is_empty() {
+            val.replace(&bot.token, &bot.hidden_token)
+        } else {
+            val.to_owned()
+        }
+    }
+
+    fn payload<Client: ?Sized>(
+        &self,
+        bot: &Bot<Client>,
+        data: &(impl Serialize + ?Sized),
+    ) -> String {
+        match serde_json::to_value(data) {
+            Ok(mut value) => {
+                self.redact_value(&mut value);
+                self.redact_str(bot, &value.to_string())
+            }
+            Err(err) => format!("<unserializable payload: {err}>"),
+        }
+    }
+}
+
+impl<S> LoggingSession<S> {
+    /// Logs the request before sending
+    /// # Returns
+    /// Name of the method of the request
+    fn log_request<'a, Client, T>(
+        &self,
+        bot: &Bot<Client>,
+        method: &'a T,
+        timeout: Option<Duration>,
+    ) -> &'a str
+    where
+        T: TelegramMethod,
+    {
+        let request = method.build_request(bot);
+
+        let payload = if self.log_payload {
+            Some(self.payload(bot, request.data))
+        } else {
+            None
+        };
+        let files = request.files.as_deref().map(|files| {
+            files
+                .iter()
+                .map(|file| describe_file(file))
+                .collect::<Vec<_>>()
+        });
+
+        event!(
+            Level::DEBUG,
+            bot_id = bot.bot_id,
+            method_name = request.method_name,
+            payload,
+            files = ?files,
+            timeout = ?timeout,
+            "Sending request",
+        );
+
+        request.method_name
+    }
+}
+
+fn describe_file(file: &InputFile<'_>) -> String {
+    match file {
+        InputFile::FS(file) => format!("{} ({:?})", file.str_to_file(), file.file_name()),
+        InputFile::Buffered(file) => format!(
+            "{} ({:?}, {} bytes)",
+            file.str_to_file(),
+            file.file_name(),
+            file.bytes().len()
+        ),
+        InputFile::Stream(file) => format!("{} ({:?})", file.str_to_file(), file.file_name()),
+        InputFile::Id(_) | InputFile::Url(_) => file.str_to_file().to_owned(),
+    }
+}
+
+#[async_trait]
+impl<S> Session for LoggingSession<S>
+where
+    S: Session,
+{
+    fn api(&self) -> &APIServer {
+        self.session.api()
+    }
+
+    async fn send_request<Client, T>(
+        &self,
+        bot: &Bot<Client>,
+        method: &T,
+        timeout: Option<Duration>,
+    ) -> Result<ClientResponse, anyhow::Error>
+    where
+        Client: Session,
+        T: TelegramMethod + Send + Sync,
+        T::Method: Send + Sync,
+    {
+        let method_name = self.log_request(bot, method, timeout);
+
+        let now = Instant::now();
+        let result = self.session.send_request(bot, method, timeout).await;
+        let elapsed = now.elapsed();
+
+        match result {
+            Ok(ref response) => {
+                let content = if self.log_response {
+                    Some(self.redact_str(bot, &response.content))
+                } else {
+                    None
+                };
+
+                event!(
+                    Level::DEBUG,
+                    bot_id = bot.bot_id,
+                    method_name,
+                    status_code = response.status_code.as_u16(),
+                    retry_after = ?response.retry_after,
+                    content,
+                    "Got response. Execution time: {elapsed:.2?}",
+                );
+            }
+            Err(ref err) => {
+                event!(
+                    Level::ERROR,
+                    bot_id = bot.bot_id,
+                    method_name,
+                    error = self.redact_str(bot, &err.to_string()),
+                    "Cannot send request. Execution time: {elapsed:.2?}",
+                );
+            }
+        }
+
+        result
+    }
+
+    async fn send_request_streamed<Client, T>(
+        &self,
+        bot: &Bot<Client>,
+        method: &T,
+        timeout: Option<Duration>,
+    ) -> Result<StreamedClientResponse, anyhow::Error>
+    where
+        Client: Session,
+        T: TelegramMethod + Send + Sync,
+        T::Method: Send + Sync,
+    {
+        let method_name = self.log_request(bot, method, timeout);
+
+        let now = Instant::now();
+        let result = self
+            .session
+            .send_request_streamed(bot, method, timeout)
+            .await;
+        let elapsed = now.elapsed();
+
+        // Content is still being received, so only the status is logged
+        match result {
+            Ok(ref response) => {
+                event!(
+                    Level::DEBUG,
+                    bot_id = bot.bot_id,
+                    method_name,
+                    status_code = response.status_code.as_u16(),
+                    retry_after = ?response.retry_after,
+                    "Got streamed response. Time to response: {elapsed:.2?}",
+                );
+            }
+            Err(ref err) => {
+                event!(
+                    Level::ERROR,
+                    bot_id = bot.bot_id,
+                    method_name,
+                    error = self.redact_str(bot, &err.to_string()),
+                    "Cannot send request. Execution time: {elapsed:.2?}",
+                );
+            }
+        }
+
+        result
+    }
+
+    fn check_response(
+        &self,
+        response: &Response<impl DeserializeOwned>,
+        status_code: &StatusCode,
+    ) -> Result<(), TelegramErrorKind> {
+        self.session
+            .check_response(response, status_code)
+            .map_err(|err| {
+                event!(
+                    Level::WARN,
+                    %status_code,
+                    error_code = response.error_code,
+                    error = %err,
+                    "Telegram API returned an error",
+                );
+
+                err
+            })
+    }
+
+    async fn close(&self) -> Result<(), anyhow::Error> {
+        self.session.close().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::Reqwest,
+        methods::{GetUpdates, SendInvoice},
+        types::LabeledPrice,
+    };
+
+    use bytes::Bytes;
+    use futures::{stream, StreamExt as _, TryStreamExt as _};
+
+    /// Session, which responds differently to buffered and streamed requests,
+    /// so it's possible to check which one is called by the decorator
+    #[derive(Default)]
+    struct Streamer {
+        api: APIServer,
+    }
+
+    #[async_trait]
+    impl Session for Streamer {
+        fn api(&self) -> &APIServer {
+            &self.api
+        }
+
+        async fn send_request<Client, T>(
+            &self,
+            _bot: &Bot<Client>,
+            _method: &T,
+            _timeout: Option<Duration>,
+        ) -> Result<ClientResponse, anyhow::Error>
+        where
+            Client: Session,
+            T: TelegramMethod + Send + Sync,
+            T::Method: Send + Sync,
+        {
+            Ok(ClientResponse::new(200, "buffered"))
+        }
+
+        async fn send_request_streamed<Client, T>(
+            &self,
+            _bot: &Bot<Client>,
+            _method: &T,
+            _timeout: Option<Duration>,
+        ) -> Result<StreamedClientResponse, anyhow::Error>
+        where
+            Client: Session,
+            T: TelegramMethod + Send + Sync,
+            T::Method: Send + Sync,
+        {
+            Ok(StreamedClientResponse::new(
+                200,
+                stream::iter([
+                    Ok(Bytes::from_static(b"stream")),
+                    Ok(Bytes::from_static(b"ed")),
+                ])
+                .boxed(),
+            ))
+        }
+    }
+
+    async fn content(response: StreamedClientResponse) -> Vec<u8> {
+        response
+            .content
+            .try_fold(vec![], |mut content, bytes| async move {
+                content.extend_from_slice(&bytes);
+                Ok(content)
+            })
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn test_payload_redaction() {
+        let token = "1234567890:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA";
+        let bot = Bot::with_client(token, Reqwest::default());
+        let session = LoggingSession::new(Reqwest::default()).redact_field("title");
+
+        let method = SendInvoice::new(
+            1,
+            "title",
+            token,
+            "payload",
+            "provider_token",
+            "RUB",
+            [LabeledPrice::new("label", 100)],
+        );
+        let payload = session.payload(&bot, method.build_request(&bot).data);
+        let value: Value = serde_json::from_str(&payload).unwrap();
+
+        assert!(!payload.contains(token));
+        assert_eq!(value["description"], bot.hidden_token);
+        assert_eq!(value["provider_token"], REDACTED);
+        assert_eq!(value["title"], REDACTED);
+        assert_eq!(value["payload"], "payload");
+
+        let session = session.redact_token(false);
+
+        assert!(session
+            .payload(&bot, method.build_request(&bot).data)
+            .contains(token));
+    }
+
+    #[tokio::test]
+    async fn test_send_request_streamed() {
+        let bot = Bot::with_client(
+            "1234567890:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+            Streamer::default(),
+        );
+        let session = LoggingSession::new(Streamer::default());
+
+        let response = session
+            .send_request_streamed(&bot, &GetUpdates::new(), None)
+            .await
+            .unwrap();
+        assert_eq!(content(response).await, b"streamed");
+    }
+}
@@ -8,10 +8,40 @@
 //! This structure is cheap to clone,
 //! because it contains only [`reqwest::Client`] field which is wrapped in [`Arc`] and [`APIServer`] wrapped in [`Cow`].
 //!
+//! Client can be configured with [`ClientOptions`] (proxy, timeouts, keep-alive, DNS overrides and TLS root certificates)
+//! by [`Reqwest::with_options`] method or [`Builder::client_options`] method.
+//! Custom root certificates are useful for local Bot API servers with self-signed certificates.
+//!
+//! SOCKS5 proxies are supported only with `socks-proxy` feature, HTTP(S) proxies are always supported.
+//! HTTP/2 is negotiated with the Telegram Bot API server only with `http2` feature,
+//! so multiple requests (for example, uploads of large files) can share one connection.
+//!
+//! Files are streamed to the server without buffering in memory:
+//! [`FSFile`] is read by chunks and sent with the known length, [`BufferedFile`] content isn't copied.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use telers::{client::session::reqwest::ClientOptions, Bot};
+//!
+//! let bot = Bot::builder("1234567890:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA")
+//!     .client_options(
+//!         ClientOptions::new()
+//!             .proxy("http://127.0.0.1:8080")
+//!             .connect_timeout(Duration::from_secs(5))
+//!             .tcp_keepalive(Duration::from_secs(60)),
+//!     )
+//!     .build();
+//! ```
+//!
 //! [`Arc`]: std::sync::Arc
 //! [`APIServer`]: crate::client::telegram::APIServer
+//! [`Builder::client_options`]: crate::client::bot::Builder::client_options
+//! [`FSFile`]: crate::types::InputFSFile
+//! [`BufferedFile`]: crate::types::InputBufferedFile
 
-use super::base::{ClientResponse, Session, DEFAULT_TIMEOUT};
+use super::base::{ClientResponse, Session, StreamedClientResponse, DEFAULT_TIMEOUT};
 
 use crate::{
     client::{telegram, Bot},
@@ -21,12 +51,18 @@ use crate::{
 };
 
 use async_trait::async_trait;
+use futures::{StreamExt as _, TryStreamExt as _};
 use reqwest::{
+    header::RETRY_AFTER,
     multipart::{Form, Part},
-    Body, Client, ClientBuilder,
+    Body, Certificate, Client, ClientBuilder, Proxy, Response,
 };
 use serde::Serialize;
-use std::{borrow::Cow, time::Duration};
+use std::{
+    borrow::Cow,
+    net::SocketAddr,
+    time::{Duration, Instant},
+};
 use tracing::{event, field, instrument, Level, Span};
 
 #[derive(Debug, Clone)]
@@ -44,6 +80,13 @@ impl Reqwest {
         }
     }
 
+    /// Creates a new session with the client built from the options
+    /// # Errors
+    /// If the proxy URL or root certificates are invalid, or TLS backend cannot be initialized
+    pub fn with_options(options: &ClientOptions) -> Result<Self, reqwest::Error> {
+        options.build().map(Self::new)
+    }
+
     #[must_use]
     pub fn with_api_server(self, api: impl Into<Cow<'static, telegram::APIServer>>) -> Self {
         Self {
@@ -52,6 +95,73 @@ impl Reqwest {
         }
     }
 
+    /// Sends a `POST` request with the method to the Telegram Bot API
+    /// # Errors
+    /// Returns an error if the form cannot be built or the request cannot be sent.
+    async fn post<Client, T>(
+        &self,
+        bot: &Bot<Client>,
+        method: &T,
+        timeout: Option<Duration>,
+    ) -> Result<Response, anyhow::Error>
+    where
+        T: TelegramMethod,
+    {
+        let request = method.build_request(bot);
+
+        Span::current()
+            .record("files", field::debug(&request.files))
+            .record("method_name", request.method_name);
+
+        let form = self
+            .build_form_data(request.data, request.files.as_deref())
+            .await
+            .map_err(|err| {
+                event!(
+                    Level::ERROR,
+                    error = %err,
+                    "Cannot build a form",
+                );
+
+                err
+            })?;
+
+        let url = self.api.api_url(&bot.token, request.method_name);
+
+        let response = if let Some(timeout) = timeout {
+            Span::current().record("timeout", timeout.as_secs_f32());
+
+            self.client
+                .post(url.as_ref())
+                .multipart(form)
+                .timeout(timeout)
+        } else {
+            self.client.post(url.as_ref()).multipart(form)
+        }
+        .send()
+        .await
+        .map_err(|err| {
+            event!(
+                Level::ERROR,
+                error = %err,
+                "Cannot send a request",
+            );
+
+            err
+        })?;
+
+        Ok(response)
+    }
+
+    /// Gets value of the `Retry-After` header of the response
+    fn retry_after(response: &Response) -> Option<Duration> {
+        response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|header| header.to_str().ok())
+            .and_then(ClientResponse::parse_retry_after)
+    }
+
     /// Builds a form data from the given data and files.
     /// # Notes
     /// This method uses [`MultipartSerializer`] to serialize the data in custom format that Telegram Bot API accepts.
@@ -80,27 +190,31 @@ impl Reqwest {
                 InputFile::FS(file) => {
                     let id = file.id().to_string();
                     let file_name = file.file_name();
+                    // Known length allows to send the part without chunked encoding
+                    let length = tokio::fs::metadata(file.path())
+                        .await
+                        .ok()
+                        .map(|metadata| metadata.len());
                     let stream = file.clone().stream();
 
                     let body = Body::wrap_stream(stream);
-                    let part = if let Some(file_name) = file_name {
-                        Part::stream(body).file_name(file_name.to_owned())
-                    } else {
-                        Part::stream(body).file_name(id.clone())
-                    };
+                    let part = match length {
+                        Some(length) => Part::stream_with_length(body, length),
+                        None => Part::stream(body),
+                    }
+                    .file_name(file_name.map_or_else(|| id.clone(), ToOwned::to_owned));
 
                     form = form.part(id, part);
                 }
                 InputFile::Buffered(file) => {
                     let id = file.id().to_string();
                     let file_name = file.file_name();
-                    let bytes = file.bytes();
+                    // `Bytes` is reference counted, so the content isn't copied
+                    let bytes = file.bytes().clone();
+                    let length = bytes.len() as u64;
 
-                    let part = if let Some(file_name) = file_name {
-                        Part::bytes(bytes.to_vec()).file_name(file_name.to_string())
-                    } else {
-                        Part::bytes(bytes.to_vec()).file_name(id.clone())
-                    };
+                    let part = Part::stream_with_length(bytes, length)
+                        .file_name(file_name.map_or_else(|| id.clone(), ToOwned::to_owned));
 
                     form = form.part(id, part);
                 }
@@ -115,11 +229,8 @@ impl Reqwest {
                     let file_name = file.file_name();
 
                     let body = Body::wrap_stream(stream);
-                    let part = if let Some(file_name) = file_name {
-                        Part::stream(body).file_name(file_name.to_owned())
-                    } else {
-                        Part::stream(body).file_name(id.clone())
-                    };
+                    let part = Part::stream(body)
+                        .file_name(file_name.map_or_else(|| id.clone(), ToOwned::to_owned));
 
                     form = form.part(id, part);
                 }
@@ -131,18 +242,193 @@ impl Reqwest {
     }
 }
 
+/// Options for building [`reqwest::Client`] of the [`Reqwest`] session
+#[derive(Debug, Clone)]
+pub struct ClientOptions {
+    proxy: Option<Box<str>>,
+    timeout: Duration,
+    connect_timeout: Option<Duration>,
+    pool_idle_timeout: Option<Duration>,
+    tcp_keepalive: Option<Duration>,
+    resolve: Vec<(Box<str>, SocketAddr)>,
+    root_certificates: Vec<Box<[u8]>>,
+    accept_invalid_certs: bool,
+    http2_prior_knowledge: bool,
+    http2_adaptive_window: bool,
+}
+
+impl ClientOptions {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            proxy: None,
+            timeout: DEFAULT_TIMEOUT,
+            connect_timeout: None,
+            pool_idle_timeout: None,
+            tcp_keepalive: None,
+            resolve: vec![],
+            root_certificates: vec![],
+            accept_invalid_certs: false,
+            http2_prior_knowledge: false,
+            http2_adaptive_window: false,
+        }
+    }
+
+    /// Proxy URL for all requests, for example `http://127.0.0.1:8080` or `socks5://127.0.0.1:1080`
+    /// # Notes
+    /// SOCKS5 proxies are supported only with `socks-proxy` feature
+    #[must_use]
+    pub fn proxy(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            proxy: Some(val.into()),
+            ..self
+        }
+    }
+
+    /// Total timeout of the request, which is used if timeout isn't passed to the request.
+    /// By default, it's [`DEFAULT_TIMEOUT`].
+    #[must_use]
+    pub fn timeout(self, val: Duration) -> Self {
+        Self {
+            timeout: val,
+            ..self
+        }
+    }
+
+    /// Timeout for the connect phase of the request
+    #[must_use]
+    pub fn connect_timeout(self, val: Duration) -> Self {
+        Self {
+            connect_timeout: Some(val),
+            ..self
+        }
+    }
+
+    /// Timeout for idle sockets being kept-alive in the pool
+    #[must_use]
+    pub fn pool_idle_timeout(self, val: Duration) -> Self {
+        Self {
+            pool_idle_timeout: Some(val),
+            ..self
+        }
+    }
+
+    /// Interval of TCP keep-alive probes
+    #[must_use]
+    pub fn tcp_keepalive(self, val: Duration) -> Self {
+        Self {
+            tcp_keepalive: Some(val),
+            ..self
+        }
+    }
+
+    /// Override DNS resolution of the domain to the address.
+    /// Useful for local Bot API servers, which aren't available via DNS.
+    #[must_use]
+    pub fn resolve(self, domain: impl Into<Box<str>>, addr: SocketAddr) -> Self {
+        Self {
+            resolve: self
+                .resolve
+                .into_iter()
+                .chain(Some((domain.into(), addr)))
+                .collect(),
+            ..self
+        }
+    }
+
+    /// Add trusted root certificate in PEM format.
+    /// Useful for local Bot API servers with self-signed certificates.
+    #[must_use]
+    pub fn root_certificate(self, pem: impl Into<Box<[u8]>>) -> Self {
+        Self {
+            root_certificates: self
+                .root_certificates
+                .into_iter()
+                .chain(Some(pem.into()))
+                .collect(),
+            ..self
+        }
+    }
+
+    /// Accept invalid TLS certificates.
+    /// # Warning
+    /// It's dangerous, because it disables certificate validation. Prefer to use [`ClientOptions::root_certificate`] instead.
+    #[must_use]
+    pub fn danger_accept_invalid_certs(self, val: bool) -> Self {
+        Self {
+            accept_invalid_certs: val,
+            ..self
+        }
+    }
+
+    /// Use HTTP/2 without negotiation.
+    /// Useful for local Bot API servers, which support HTTP/2 over plain TCP.
+    /// # Notes
+    /// HTTP/2 over TLS is negotiated automatically with `http2` feature
+    #[must_use]
+    pub fn http2_prior_knowledge(self, val: bool) -> Self {
+        Self {
+            http2_prior_knowledge: val,
+            ..self
+        }
+    }
+
+    /// Use adaptive flow control of HTTP/2 connections, which improves throughput of large uploads
+    #[must_use]
+    pub fn http2_adaptive_window(self, val: bool) -> Self {
+        Self {
+            http2_adaptive_window: val,
+            ..self
+        }
+    }
+
+    /// Build [`reqwest::Client`] with the options
+    /// # Errors
+    /// If the proxy URL or root certificates are invalid, or TLS backend cannot be initialized
+    pub fn build(&self) -> Result<Client, reqwest::Error> {
+        let mut builder = ClientBuilder::new()
+            .timeout(self.timeout)
+            .danger_accept_invalid_certs(self.accept_invalid_certs)
+            .http2_adaptive_window(self.http2_adaptive_window);
+
+        if self.http2_prior_knowledge {
+            builder = builder.http2_prior_knowledge();
+        }
+        if let Some(ref proxy) = self.proxy {
+            builder = builder.proxy(Proxy::all(proxy.as_ref())?);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(pool_idle_timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(pool_idle_timeout);
+        }
+        if let Some(tcp_keepalive) = self.tcp_keepalive {
+            builder = builder.tcp_keepalive(tcp_keepalive);
+        }
+        for (domain, addr) in &self.resolve {
+            builder = builder.resolve(domain, *addr);
+        }
+        for pem in &self.root_certificates {
+            builder = builder.add_root_certificate(Certificate::from_pem(pem)?);
+        }
+
+        builder.build()
+    }
+}
+
+impl Default for ClientOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Default for Reqwest {
     /// # Panics
     /// This method panics if the client cannot be created
     #[must_use]
     fn default() -> Self {
-        Self {
-            client: ClientBuilder::new()
-                .timeout(Duration::from_secs_f32(DEFAULT_TIMEOUT))
-                .build()
-                .unwrap(),
-            api: Cow::Borrowed(&telegram::PRODUCTION),
-        }
+        Self::new(ClientOptions::new().build().unwrap())
     }
 }
 
@@ -169,57 +455,18 @@ impl Session for Reqwest {
         &self,
         bot: &Bot<Client>,
         method: &T,
-        timeout: Option<f32>,
+        timeout: Option<Duration>,
     ) -> Result<ClientResponse, anyhow::Error>
     where
         Client: Session,
         T: TelegramMethod + Send + Sync,
         T::Method: Send + Sync,
     {
-        let request = method.build_request(bot);
-
-        Span::current()
-            .record("files", field::debug(&request.files))
-            .record("method_name", request.method_name);
-
-        let form = self
-            .build_form_data(request.data, request.files.as_deref())
-            .await
-            .map_err(|err| {
-                event!(
-                    Level::ERROR,
-                    error = %err,
-                    "Cannot build a form",
-                );
-
-                err
-            })?;
-
-        let url = self.api.api_url(&bot.token, request.method_name);
-
-        let response = if let Some(timeout) = timeout {
-            Span::current().record("timeout", timeout);
-
-            self.client
-                .post(url.as_ref())
-                .multipart(form)
-                .timeout(Duration::from_secs_f32(timeout))
-        } else {
-            self.client.post(url.as_ref()).multipart(form)
-        }
-        .send()
-        .await
-        .map_err(|err| {
-            event!(
-                Level::ERROR,
-                error = %err,
-                "Cannot send a request",
-            );
-
-            err
-        })?;
+        let now = Instant::now();
+        let response = self.post(bot, method, timeout).await?;
 
         let status_code = response.status().as_u16();
+        let retry_after = Self::retry_after(&response);
 
         let content = response.text().await.map_err(|err| {
             event!(
@@ -232,6 +479,56 @@ impl Session for Reqwest {
             err
         })?;
 
-        Ok(ClientResponse::new(status_code, content))
+        Ok(ClientResponse::new(status_code, content)
+            .retry_after_option(retry_after)
+            .elapsed(now.elapsed()))
+    }
+
+    /// Sends a request to the Telegram Bot API and returns a response with the content as a stream of chunks,
+    /// so the content is processed while it's being received.
+    /// # Errors
+    /// Returns an error if the request cannot be sent.
+    #[instrument(skip(self, bot, method, timeout), fields(files, method_name, timeout))]
+    async fn send_request_streamed<Client, T>(
+        &self,
+        bot: &Bot<Client>,
+        method: &T,
+        timeout: Option<Duration>,
+    ) -> Result<StreamedClientResponse, anyhow::Error>
+    where
+        Client: Session,
+        T: TelegramMethod + Send + Sync,
+        T::Method: Send + Sync,
+    {
+        let response = self.post(bot, method, timeout).await?;
+
+        let status_code = response.status().as_u16();
+        let retry_after = Self::retry_after(&response);
+
+        let content = response.bytes_stream().map_err(Into::into).boxed();
+
+        Ok(StreamedClientResponse::new(status_code, content).retry_after_option(retry_after))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_client_options() {
+        let options = ClientOptions::new()
+            .proxy("http://127.0.0.1:8080")
+            .timeout(Duration::from_secs(10))
+            .connect_timeout(Duration::from_secs(5))
+            .pool_idle_timeout(Duration::from_secs(90))
+            .tcp_keepalive(Duration::from_secs(60))
+            .resolve(
+                "api.telegram.local",
+                SocketAddr::from(([127, 0, 0, 1], 8081)),
+            );
+
+        assert!(Reqwest::with_options(&options).is_ok());
+        assert!(Reqwest::with_options(&options.proxy("not a url")).is_err());
     }
 }
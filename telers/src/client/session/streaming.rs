@@ -0,0 +1,203 @@
+//! This module contains [`ResultDecoder`], which decodes elements of the `result` array of the Telegram Bot API response
+//! one by one, while the response content is being received.
+//!
+//! Responses with many big elements (for example, `getUpdates` response with 100 updates) are decoded and processed
+//! element by element instead of waiting for the full content and deserializing the full array,
+//! so the first element is processed earlier and the full content isn't kept in memory.
+//!
+//! The decoder only splits the content into raw JSON of the elements, so elements are deserialized by the caller.
+//! Elements of the array must be objects or arrays, which is true for all methods, which return arrays.
+//! Content of the responses without the `result` array (for example, error responses) is kept as is,
+//! so it can be parsed as the usual response.
+//!
+//! Check [`Session::make_request_streamed`] for the usage with the client.
+//!
+//! [`Session::make_request_streamed`]: super::Session::make_request_streamed
+
+use std::collections::VecDeque;
+
+/// Key of the array, which elements are decoded
+const RESULT_KEY: &[u8] = b"result";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// The `result` array isn't found yet
+    #[default]
+    Before,
+    /// Inside the `result` array
+    Inside,
+    /// The `result` array is ended
+    After,
+}
+
+/// Incremental decoder of elements of the `result` array.
+/// Check the [module](self) documentation for more information.
+#[derive(Debug, Default)]
+pub struct ResultDecoder {
+    /// Received content, which isn't decoded yet
+    buffer: Vec<u8>,
+    /// Position in the buffer, up to which the content is scanned
+    position: usize,
+    /// Depth of the current JSON value: `1` is the response object, `2` is the `result` array
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    /// Start of the last string of the response object
+    string_start: usize,
+    /// Whether the last string of the response object is the `result` key
+    result_key: bool,
+    /// Start of the current element in the buffer
+    element_start: Option<usize>,
+    state: State,
+    /// Decoded elements, which aren't taken yet
+    elements: VecDeque<Box<[u8]>>,
+}
+
+impl ResultDecoder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pushes the next chunk of the response content and decodes elements, which are fully received
+    pub fn push(&mut self, chunk: &[u8]) {
+        if self.state == State::After {
+            return;
+        }
+
+        self.buffer.extend_from_slice(chunk);
+
+        while self.position < self.buffer.len() {
+            let byte = self.buffer[self.position];
+
+            if self.in_string {
+                if self.escaped {
+                    self.escaped = false;
+                } else if byte == b'\\' {
+                    self.escaped = true;
+                } else if byte == b'"' {
+                    self.in_string = false;
+
+                    if self.depth == 1 {
+                        self.result_key =
+                            &self.buffer[self.string_start..self.position] == RESULT_KEY;
+                    }
+                }
+            } else {
+                match byte {
+                    b'"' => {
+                        self.in_string = true;
+                        self.string_start = self.position + 1;
+                    }
+                    b'{' | b'[' => {
+                        match (self.state, self.depth) {
+                            (State::Before, 1) if byte == b'[' && self.result_key => {
+                                self.state = State::Inside;
+                            }
+                            (State::Inside, 2) => self.element_start = Some(self.position),
+                            _ => {}
+                        }
+
+                        self.depth += 1;
+                    }
+                    b'}' | b']' => {
+                        self.depth = self.depth.saturating_sub(1);
+
+                        match (self.state, self.depth) {
+                            (State::Inside, 2) => {
+                                if let Some(start) = self.element_start.take() {
+                                    self.elements
+                                        .push_back(self.buffer[start..=self.position].into());
+                                }
+                            }
+                            (State::Inside, 1) => self.state = State::After,
+                            _ => {}
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            self.position += 1;
+        }
+
+        // Content before the `result` array is kept to parse error responses, other decoded content isn't needed
+        if self.state != State::Before {
+            let start = self.element_start.unwrap_or(self.position);
+
+            self.buffer.drain(..start);
+            self.position -= start;
+            self.element_start = self.element_start.map(|_| 0);
+        }
+    }
+
+    /// Takes the next decoded element
+    /// # Returns
+    /// Raw JSON of the element or `None` if there are no decoded elements yet
+    #[must_use]
+    pub fn next_element(&mut self) -> Option<Box<[u8]>> {
+        self.elements.pop_front()
+    }
+
+    /// Finishes decoding, when the full content is received
+    /// # Returns
+    /// Full content of the response, if the `result` array isn't found in it, for example, the response is an error,
+    /// otherwise `None`
+    /// # Errors
+    /// If the content is ended inside the `result` array
+    pub fn finish(&mut self) -> Result<Option<Vec<u8>>, anyhow::Error> {
+        match self.state {
+            State::Before => Ok(Some(std::mem::take(&mut self.buffer))),
+            State::Inside => Err(anyhow::Error::msg(
+                "Response content is ended inside the result array",
+            )),
+            State::After => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        let content = br#"{"ok":true,"result":[{"update_id":1,"message":{"text":"}]\"{["}},
+            {"update_id":2,"callback_query":{"data":"result"}}]}"#;
+
+        // Elements are decoded regardless of how the content is split into chunks
+        for chunk_size in [1, 7, content.len()] {
+            let mut decoder = ResultDecoder::new();
+            let mut elements = vec![];
+
+            for chunk in content.chunks(chunk_size) {
+                decoder.push(chunk);
+                elements.extend(std::iter::from_fn(|| decoder.next_element()));
+            }
+
+            assert_eq!(elements.len(), 2);
+            assert_eq!(
+                serde_json::from_slice::<serde_json::Value>(&elements[0]).unwrap()["message"]
+                    ["text"],
+                "}]\"{["
+            );
+            assert_eq!(
+                &*elements[1],
+                br#"{"update_id":2,"callback_query":{"data":"result"}}"#
+            );
+            assert!(decoder.buffer.is_empty());
+            assert_eq!(decoder.finish().unwrap(), None);
+        }
+
+        let content = br#"{"ok":false,"error_code":409,"description":"result"}"#;
+        let mut decoder = ResultDecoder::new();
+        decoder.push(content);
+        assert_eq!(decoder.next_element(), None);
+        assert_eq!(decoder.finish().unwrap().as_deref(), Some(&content[..]));
+
+        let mut decoder = ResultDecoder::new();
+        decoder.push(br#"{"ok":true,"result":[{"update_id":1}"#);
+        assert!(decoder.next_element().is_some());
+        assert!(decoder.finish().is_err());
+    }
+}
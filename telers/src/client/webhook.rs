@@ -0,0 +1,627 @@
+//! This module contains [`WebhookManager`], which keeps the webhook of the bot in sync with the desired configuration,
+//! and [`SecretTokens`], which are used to check webhook requests.
+//!
+//! At startup call [`WebhookManager::reconcile`]: it gets the current webhook with `getWebhookInfo` request,
+//! compares URL, certificate, IP address, max connections and allowed updates with the desired ones
+//! and sends `setWebhook` request only if something differs (see [`Mismatch`]).
+//! Telegram doesn't return the secret token of the webhook, so it can't be compared.
+//! By default the webhook is always set with the current secret token, so it's in sync after restart.
+//! If the secret token is persistent, use [`WebhookManager::trust_secret_token`] to skip the request when other fields match.
+//!
+//! Secret token can be rotated without downtime with [`WebhookManager::rotate_secret`]:
+//! the previous token is still valid during the grace period, so requests, which are sent by Telegram before the webhook is updated, aren't rejected.
+//! If several bots share the same [`SecretTokens`], rotate tokens once with [`SecretTokens::rotate`] and apply them with [`WebhookManager::apply`] for each bot.
+//!
+//! If the lifecycle bus is set by [`WebhookManager::lifecycle_bus`], [`LifecycleEvent::WebhookSet`] is emitted after each `setWebhook` request.
+//!
+//! Number of updates awaiting delivery is updated after each `getWebhookInfo` request
+//! and can be got with [`WebhookManager::pending_update_count`], for example, to export it as a metric.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use std::time::Duration;
+//! use telers::client::webhook::{SecretTokens, WebhookManager};
+//!
+//! let manager = WebhookManager::new("https://example.com/webhook", SecretTokens::new("secret"))
+//!     .allowed_updates(["message", "callback_query"]);
+//!
+//! let reconciliation = manager.reconcile(&bot).await?;
+//! println!("Webhook mismatches: {:?}", reconciliation.mismatches);
+//!
+//! // Later, for example, by schedule
+//! manager.rotate_secret(&bot, "new_secret", Duration::from_secs(60)).await?;
+//! ```
+//!
+//! [`LifecycleEvent::WebhookSet`]: crate::event::LifecycleEvent::WebhookSet
+
+use super::{Bot, Session};
+
+use crate::{
+    errors::SessionErrorKind,
+    methods::{GetWebhookInfo, SetWebhook},
+    types::{InputFile, WebhookInfo},
+};
+
+#[cfg(feature = "dispatcher")]
+use crate::event::{LifecycleBus, LifecycleEvent};
+
+use std::{
+    collections::HashSet,
+    sync::{
+        atomic::{AtomicI64, Ordering},
+        Arc, RwLock,
+    },
+    time::{Duration, Instant},
+};
+use tracing::{event, instrument, Level};
+
+#[derive(Debug)]
+struct Tokens {
+    current: Box<str>,
+    /// Previous token and time until it's valid
+    previous: Option<(Box<str>, Instant)>,
+}
+
+/// Secret tokens of the webhook, which are checked in every webhook request.
+/// Clones share the same tokens, so the rotated token is visible to the webhook server immediately.
+#[derive(Clone)]
+pub struct SecretTokens {
+    inner: Arc<RwLock<Tokens>>,
+}
+
+impl SecretTokens {
+    /// # Arguments
+    /// * `current` - Secret token, 1-256 characters, only characters `A-Z`, `a-z`, `0-9`, `_` and `-` are allowed
+    #[must_use]
+    pub fn new(current: impl Into<Box<str>>) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(Tokens {
+                current: current.into(),
+                previous: None,
+            })),
+        }
+    }
+
+    /// Create secret tokens with the random current token
+    #[must_use]
+    pub fn random() -> Self {
+        Self::new(uuid::Uuid::new_v4().simple().to_string())
+    }
+
+    /// Get the current token, which is set to the webhook
+    #[must_use]
+    pub fn current(&self) -> Box<str> {
+        self.inner.read().unwrap().current.clone()
+    }
+
+    /// Check if the token is the current one or the previous one, which is still in the grace period
+    #[must_use]
+    pub fn is_valid(&self, token: &str) -> bool {
+        let tokens = self.inner.read().unwrap();
+
+        // Both tokens are always compared, so the valid one can't be guessed by timing
+        let is_current = constant_time_eq(&tokens.current, token);
+        let is_previous = tokens
+            .previous
+            .as_ref()
+            .map_or(false, |(previous, valid_until)| {
+                constant_time_eq(previous, token) && Instant::now() < *valid_until
+            });
+
+        is_current | is_previous
+    }
+
+    /// Replace the current token with the new one.
+    /// The replaced token is still valid during the grace period.
+    /// # Notes
+    /// This method doesn't update the webhook, use [`WebhookManager::apply`] after it
+    pub fn rotate(&self, new: impl Into<Box<str>>, grace_period: Duration) {
+        let mut tokens = self.inner.write().unwrap();
+
+        let previous = std::mem::replace(&mut tokens.current, new.into());
+        tokens.previous = Some((previous, Instant::now() + grace_period));
+    }
+}
+
+/// Compare the strings in constant time, so the secret token can't be guessed by timing
+fn constant_time_eq(left: &str, right: &str) -> bool {
+    left.len() == right.len()
+        && left
+            .bytes()
+            .zip(right.bytes())
+            .fold(0, |diff, (left, right)| diff | (left ^ right))
+            == 0
+}
+
+impl std::fmt::Debug for SecretTokens {
+    /// Tokens are hidden, so they aren't leaked to logs
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecretTokens").finish_non_exhaustive()
+    }
+}
+
+/// Field of the webhook, which differs from the desired configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mismatch {
+    Url,
+    Certificate,
+    IpAddress,
+    MaxConnections,
+    AllowedUpdates,
+    /// Secret token can't be checked, so it's considered mismatched unless [`WebhookManager::trust_secret_token`] is used
+    SecretToken,
+}
+
+/// Result of [`WebhookManager::reconcile`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reconciliation {
+    /// Webhook info before the reconciliation
+    pub info: WebhookInfo,
+    /// Fields, which differ from the desired configuration. If it's empty, webhook isn't updated
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl Reconciliation {
+    /// Check if the webhook is updated by `setWebhook` request
+    #[must_use]
+    pub fn is_updated(&self) -> bool {
+        !self.mismatches.is_empty()
+    }
+}
+
+/// Manager of the bot webhook.
+/// Check the [module](self) documentation for more information.
+#[derive(Debug, Clone)]
+pub struct WebhookManager {
+    url: Box<str>,
+    secret_tokens: SecretTokens,
+    certificate: Option<InputFile<'static>>,
+    ip_address: Option<Box<str>>,
+    max_connections: Option<i64>,
+    allowed_updates: Option<Box<[Box<str>]>>,
+    drop_pending_updates: bool,
+    trust_secret_token: bool,
+    pending_update_count: Arc<AtomicI64>,
+    #[cfg(feature = "dispatcher")]
+    lifecycle: Option<LifecycleBus>,
+}
+
+impl WebhookManager {
+    /// # Arguments
+    /// * `url` - HTTPS URL to send updates to
+    /// * `secret_tokens` - Secret tokens, which are set to the webhook and checked by the webhook server
+    #[must_use]
+    pub fn new(url: impl Into<Box<str>>, secret_tokens: SecretTokens) -> Self {
+        Self {
+            url: url.into(),
+            secret_tokens,
+            certificate: None,
+            ip_address: None,
+            max_connections: None,
+            allowed_updates: None,
+            drop_pending_updates: false,
+            trust_secret_token: false,
+            pending_update_count: Arc::default(),
+            #[cfg(feature = "dispatcher")]
+            lifecycle: None,
+        }
+    }
+
+    /// Public key certificate, which is uploaded to Telegram, if the certificate is self-signed
+    #[must_use]
+    pub fn certificate(self, val: impl Into<InputFile<'static>>) -> Self {
+        Self {
+            certificate: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn ip_address(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            ip_address: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn max_connections(self, val: i64) -> Self {
+        Self {
+            max_connections: Some(val),
+            ..self
+        }
+    }
+
+    /// Update types, which the bot is subscribed to.
+    /// If it isn't set, allowed updates of the webhook aren't checked and changed
+    #[must_use]
+    pub fn allowed_updates<T, I>(self, val: I) -> Self
+    where
+        T: Into<Box<str>>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            allowed_updates: Some(val.into_iter().map(Into::into).collect()),
+            ..self
+        }
+    }
+
+    /// Drop pending updates, when the webhook is set
+    #[must_use]
+    pub fn drop_pending_updates(self, val: bool) -> Self {
+        Self {
+            drop_pending_updates: val,
+            ..self
+        }
+    }
+
+    /// Consider the secret token as set, so the webhook isn't updated if other fields match.
+    /// Use it only if the secret token is persistent, otherwise the webhook server rejects requests with the old token.
+    /// # Default
+    /// `false`
+    #[must_use]
+    pub fn trust_secret_token(self, val: bool) -> Self {
+        Self {
+            trust_secret_token: val,
+            ..self
+        }
+    }
+
+    /// Bus to emit [`LifecycleEvent::WebhookSet`] to, when the webhook is set
+    #[cfg(feature = "dispatcher")]
+    #[must_use]
+    pub fn lifecycle_bus(self, val: LifecycleBus) -> Self {
+        Self {
+            lifecycle: Some(val),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    #[must_use]
+    pub const fn secret_tokens(&self) -> &SecretTokens {
+        &self.secret_tokens
+    }
+
+    /// Get number of updates awaiting delivery, which is received by the last `getWebhookInfo` request
+    #[must_use]
+    pub fn pending_update_count(&self) -> i64 {
+        self.pending_update_count.load(Ordering::Relaxed)
+    }
+
+    /// Build `setWebhook` request with the desired configuration and the current secret token
+    #[must_use]
+    pub fn set_webhook_method(&self) -> SetWebhook<'static> {
+        let method = SetWebhook::new(&*self.url)
+            .certificate_option(self.certificate.clone())
+            .ip_address_option(self.ip_address.as_deref())
+            .max_connections_option(self.max_connections)
+            .drop_pending_updates(self.drop_pending_updates)
+            .secret_token(&*self.secret_tokens.current());
+
+        match &self.allowed_updates {
+            Some(allowed_updates) => {
+                method.allowed_updates(allowed_updates.iter().map(AsRef::<str>::as_ref))
+            }
+            None => method,
+        }
+    }
+
+    /// Compare the webhook with the desired configuration
+    #[must_use]
+    pub fn mismatches(&self, info: &WebhookInfo) -> Vec<Mismatch> {
+        let mut mismatches = vec![];
+
+        if *info.url != *self.url {
+            mismatches.push(Mismatch::Url);
+        }
+        if info.has_custom_certificate != self.certificate.is_some() {
+            mismatches.push(Mismatch::Certificate);
+        }
+        if self.ip_address.is_some() && info.ip_address != self.ip_address {
+            mismatches.push(Mismatch::IpAddress);
+        }
+        if self.max_connections.is_some() && info.max_connections != self.max_connections {
+            mismatches.push(Mismatch::MaxConnections);
+        }
+        if let Some(allowed_updates) = &self.allowed_updates {
+            // Empty list and missing list both mean default update types
+            let current = info
+                .allowed_updates
+                .iter()
+                .flat_map(|val| val.iter())
+                .collect::<HashSet<_>>();
+            let desired = allowed_updates.iter().collect::<HashSet<_>>();
+
+            if current != desired {
+                mismatches.push(Mismatch::AllowedUpdates);
+            }
+        }
+        if !self.trust_secret_token {
+            mismatches.push(Mismatch::SecretToken);
+        }
+
+        mismatches
+    }
+
+    /// Get the current webhook and update [`WebhookManager::pending_update_count`]
+    /// # Errors
+    /// If the request to Telegram API is unsuccessful
+    #[instrument(skip(self, bot))]
+    pub async fn refresh<Client: Session>(
+        &self,
+        bot: &Bot<Client>,
+    ) -> Result<WebhookInfo, SessionErrorKind> {
+        let info = bot.send(GetWebhookInfo::new()).await?;
+
+        self.pending_update_count
+            .store(info.pending_update_count, Ordering::Relaxed);
+
+        if let Some(message) = &info.last_error_message {
+            event!(
+                Level::WARN,
+                last_error_date = info.last_error_date,
+                "Last error of the webhook: {message}",
+            );
+        }
+        event!(
+            Level::DEBUG,
+            pending_update_count = info.pending_update_count,
+            "Webhook info is received",
+        );
+
+        Ok(info)
+    }
+
+    /// Set the webhook with the desired configuration and the current secret token
+    /// # Errors
+    /// If the request to Telegram API is unsuccessful
+    #[instrument(skip(self, bot))]
+    pub async fn apply<Client: Session>(&self, bot: &Bot<Client>) -> Result<(), SessionErrorKind> {
+        self.set_webhook(bot, self.set_webhook_method()).await
+    }
+
+    async fn set_webhook<Client: Session>(
+        &self,
+        bot: &Bot<Client>,
+        method: SetWebhook<'static>,
+    ) -> Result<(), SessionErrorKind> {
+        bot.send(method).await?;
+
+        event!(Level::INFO, bot = %bot, url = %self.url, "Webhook is set for bot");
+
+        #[cfg(feature = "dispatcher")]
+        if let Some(lifecycle) = &self.lifecycle {
+            lifecycle.emit(LifecycleEvent::WebhookSet {
+                bot_id: bot.bot_id,
+                url: self.url.clone(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Get the current webhook and set it, if it differs from the desired configuration
+    /// # Errors
+    /// If the request to Telegram API is unsuccessful
+    #[instrument(skip(self, bot))]
+    pub async fn reconcile<Client: Session>(
+        &self,
+        bot: &Bot<Client>,
+    ) -> Result<Reconciliation, SessionErrorKind> {
+        let info = self.refresh(bot).await?;
+        let mismatches = self.mismatches(&info);
+
+        if mismatches.is_empty() {
+            event!(Level::DEBUG, "Webhook is up to date");
+        } else {
+            event!(
+                Level::DEBUG,
+                ?mismatches,
+                "Webhook differs from the desired configuration"
+            );
+
+            self.apply(bot).await?;
+        }
+
+        Ok(Reconciliation { info, mismatches })
+    }
+
+    /// Set the webhook with the new secret token and rotate it.
+    /// The previous token is still valid during the grace period.
+    /// # Errors
+    /// If the request to Telegram API is unsuccessful. In this case the token isn't rotated
+    #[instrument(skip(self, bot, new))]
+    pub async fn rotate_secret<Client: Session>(
+        &self,
+        bot: &Bot<Client>,
+        new: impl Into<Box<str>>,
+        grace_period: Duration,
+    ) -> Result<(), SessionErrorKind> {
+        let new = new.into();
+
+        // The token is rotated only after the webhook is set,
+        // so the webhook server doesn't reject requests with the token, which is still set to the webhook
+        self.set_webhook(bot, self.set_webhook_method().secret_token(&*new))
+            .await?;
+        self.secret_tokens.rotate(new, grace_period);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{session::base::ClientResponse, telegram::APIServer},
+        methods::TelegramMethod,
+    };
+
+    use async_trait::async_trait;
+
+    struct Responder {
+        api: APIServer,
+        content: &'static str,
+    }
+
+    #[async_trait]
+    impl Session for Responder {
+        fn api(&self) -> &APIServer {
+            &self.api
+        }
+
+        async fn send_request<Client, T>(
+            &self,
+            _bot: &Bot<Client>,
+            _method: &T,
+            _timeout: Option<Duration>,
+        ) -> Result<ClientResponse, anyhow::Error>
+        where
+            Client: Session,
+            T: TelegramMethod + Send + Sync,
+            T::Method: Send + Sync,
+        {
+            Ok(ClientResponse::new(200, self.content))
+        }
+    }
+
+    #[test]
+    fn test_secret_tokens() {
+        let tokens = SecretTokens::new("first");
+        assert!(tokens.is_valid("first"));
+        assert!(!tokens.is_valid("second"));
+
+        tokens.clone().rotate("second", Duration::from_secs(60));
+        assert_eq!(&*tokens.current(), "second");
+        assert!(tokens.is_valid("first"));
+        assert!(tokens.is_valid("second"));
+
+        tokens.rotate("third", Duration::ZERO);
+        assert!(!tokens.is_valid("first"));
+        assert!(!tokens.is_valid("second"));
+        assert!(tokens.is_valid("third"));
+        assert!(!tokens.is_valid("thir"));
+        assert!(!tokens.is_valid("third_"));
+        assert!(!tokens.is_valid(""));
+    }
+
+    #[test]
+    fn test_mismatches() {
+        let manager = WebhookManager::new("https://example.com/1", SecretTokens::new("secret"))
+            .allowed_updates(["message", "callback_query"])
+            .max_connections(10)
+            .trust_secret_token(true);
+
+        let info = WebhookInfo {
+            url: "https://example.com/1".into(),
+            max_connections: Some(10),
+            allowed_updates: Some(["callback_query".into(), "message".into()].into()),
+            ..Default::default()
+        };
+        assert!(manager.mismatches(&info).is_empty());
+
+        let info = WebhookInfo {
+            url: "".into(),
+            has_custom_certificate: true,
+            max_connections: Some(40),
+            ..Default::default()
+        };
+        assert_eq!(
+            manager.mismatches(&info),
+            [
+                Mismatch::Url,
+                Mismatch::Certificate,
+                Mismatch::MaxConnections,
+                Mismatch::AllowedUpdates,
+            ]
+        );
+
+        let manager = manager.trust_secret_token(false);
+        assert_eq!(
+            manager.mismatches(&WebhookInfo {
+                url: "https://example.com/1".into(),
+                max_connections: Some(10),
+                allowed_updates: Some(["message".into(), "callback_query".into()].into()),
+                ..Default::default()
+            }),
+            [Mismatch::SecretToken]
+        );
+    }
+
+    #[test]
+    fn test_set_webhook_method() {
+        let manager = WebhookManager::new("https://example.com/1", SecretTokens::new("first"))
+            .allowed_updates(["message"]);
+        manager
+            .secret_tokens()
+            .rotate("second", Duration::from_secs(60));
+
+        let method = manager.set_webhook_method();
+        assert_eq!(method.url, "https://example.com/1");
+        assert_eq!(method.secret_token.as_deref(), Some("second"));
+        assert_eq!(method.allowed_updates, Some(vec!["message".to_owned()]));
+    }
+
+    #[tokio::test]
+    async fn test_rotate_secret() {
+        let manager = WebhookManager::new("https://example.com/1", SecretTokens::new("first"));
+        let bot = |content| {
+            Bot::with_client(
+                "1234567890:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+                Responder {
+                    api: APIServer::default(),
+                    content,
+                },
+            )
+        };
+
+        // Webhook isn't set, so the token isn't rotated
+        let failed = bot(r#"{"ok": false, "error_code": 400, "description": "Bad Request"}"#);
+        manager
+            .rotate_secret(&failed, "second", Duration::from_secs(60))
+            .await
+            .unwrap_err();
+        assert_eq!(&*manager.secret_tokens().current(), "first");
+        assert!(!manager.secret_tokens().is_valid("second"));
+
+        let succeeded = bot(r#"{"ok": true, "result": true}"#);
+        manager
+            .rotate_secret(&succeeded, "second", Duration::from_secs(60))
+            .await
+            .unwrap();
+        assert_eq!(&*manager.secret_tokens().current(), "second");
+        assert!(manager.secret_tokens().is_valid("first"));
+    }
+
+    #[cfg(feature = "dispatcher")]
+    #[tokio::test]
+    async fn test_webhook_set_emitted() {
+        let bus = LifecycleBus::default();
+        let mut receiver = bus.subscribe();
+        let manager = WebhookManager::new("https://example.com/1", SecretTokens::new("first"))
+            .lifecycle_bus(bus);
+        let bot = Bot::with_client(
+            "1234567890:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+            Responder {
+                api: APIServer::default(),
+                content: r#"{"ok": true, "result": true}"#,
+            },
+        );
+
+        manager.apply(&bot).await.unwrap();
+
+        match receiver.try_recv().unwrap() {
+            LifecycleEvent::WebhookSet { bot_id, url } => {
+                assert_eq!(bot_id, bot.bot_id);
+                assert_eq!(&*url, "https://example.com/1");
+            }
+            event => panic!("Unexpected event: {event:?}"),
+        }
+    }
+}
@@ -21,6 +21,15 @@
 //! so you don't need to pass context as parameter of handler and extract data from context manually.
 //! Check [`extractors module`] documentation for more information (**recommended**).
 //!
+//! Dispatcher opens a tracing span per update with standard fields (`bot_id`, `update_id`, `update_type`, `chat_id`, `user_id` and `request_id`)
+//! and puts [`UpdateSpan`] and [`RequestId`] to the context.
+//! Handlers, middlewares and filters are called inside this span, so logs and API calls inside them inherit it.
+//! If you spawn tasks in handlers, use [`UpdateSpan`] to instrument them, so you can correlate their logs with the update.
+//! If [`RequestId`] is already in the context (for example, it's passed from the webhook request header), it isn't replaced.
+//!
+//! Selected values of the context and the update can be captured to a serializable [`Job`] to offload heavy work to an external queue,
+//! check [`snapshot module`] documentation for more information.
+//!
 //! [`Dispatcher`]: crate::Dispatcher
 //! [`OuterMiddleware`]: crate::middlewares::OuterMiddleware
 //! [`InnerMiddleware`]: crate::middlewares::InnerMiddleware
@@ -34,11 +43,175 @@
 //! [`inner middleware module`]: crate::middlewares::inner
 //! [`filter module`]: crate::filters
 //! [`extractors module`]: crate::extractors
+//! [`Job`]: snapshot::Job
+//! [`snapshot module`]: snapshot
+
+pub mod snapshot;
+
+use crate::extractors::FromContext;
 
 use dashmap::DashMap;
-use std::any::Any;
+use serde_json::Value;
+use std::{
+    any::Any,
+    fmt::Display,
+    ops::{Deref, DerefMut},
+    sync::Arc,
+};
+use tracing::Span;
+
+/// Number of shards of the context map.
+/// Context is created for each update and is rarely accessed concurrently,
+/// so a few shards are enough and the context is created faster than with the default number of shards (depends on CPU count).
+const SHARD_AMOUNT: usize = 4;
+
+/// Hasher of the context keys.
+/// Keys are short static strings, which are chosen by the code instead of users,
+/// so the hasher doesn't need to be resistant to HashDoS attacks, unlike the default hasher.
+type KeyHasher = rustc_hash::FxBuildHasher;
+
+/// Map of the context values by their keys
+pub type ContextMap = DashMap<&'static str, Box<dyn Any + Send + Sync>, KeyHasher>;
+
+/// Data, which is transmitted between processing-units when propagating an event.
+/// It derefs to [`ContextMap`], so use map methods (`insert`, `get`, `remove`, etc.) to work with the data.
+/// Check the [module](self) documentation for more information.
+#[derive(Debug)]
+pub struct Context(ContextMap);
+
+impl Context {
+    #[must_use]
+    pub fn new() -> Self {
+        Self(DashMap::with_hasher_and_shard_amount(
+            KeyHasher::default(),
+            SHARD_AMOUNT,
+        ))
+    }
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deref for Context {
+    type Target = ContextMap;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Context {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<ContextMap> for Context {
+    fn from(map: ContextMap) -> Self {
+        Self(map)
+    }
+}
 
-pub type Context = DashMap<&'static str, Box<dyn Any + Send + Sync>>;
+impl FromIterator<(&'static str, Box<dyn Any + Send + Sync>)> for Context {
+    fn from_iter<I: IntoIterator<Item = (&'static str, Box<dyn Any + Send + Sync>)>>(
+        iter: I,
+    ) -> Self {
+        let context = Self::new();
+        for (key, value) in iter {
+            context.insert(key, value);
+        }
+        context
+    }
+}
+
+impl IntoIterator for Context {
+    type Item = (&'static str, Box<dyn Any + Send + Sync>);
+    type IntoIter = <ContextMap as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// Generator of request IDs, which are used to correlate logs of the update processing across services
+pub type RequestIdGenerator = Arc<dyn Fn() -> RequestId + Send + Sync>;
+
+/// Unique ID of the update processing
+#[derive(Debug, Clone, Hash, PartialEq, Eq, FromContext)]
+#[context(
+    key = "request_id",
+    description = "Unique ID of the update processing. This type is available if the update is fed by the dispatcher."
+)]
+pub struct RequestId(pub Box<str>);
+
+impl RequestId {
+    #[must_use]
+    pub fn new(val: impl Into<Box<str>>) -> Self {
+        Self(val.into())
+    }
+
+    /// Generates a new random request ID (UUID v4)
+    #[must_use]
+    pub fn generate() -> Self {
+        Self(uuid::Uuid::new_v4().simple().to_string().into())
+    }
+
+    /// Gets default generator, which uses [`RequestId::generate`]
+    #[must_use]
+    pub fn default_generator() -> RequestIdGenerator {
+        Arc::new(Self::generate)
+    }
+}
+
+impl Display for RequestId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Tracing span of the update processing.
+/// Use it to instrument tasks spawned in handlers, so their logs are correlated with the update.
+/// # Examples
+/// ```rust
+/// use telers::{context::UpdateSpan, event::{telegram::HandlerResult, FlowControl}};
+/// use tracing::{event, Instrument as _, Level};
+///
+/// async fn handler(span: UpdateSpan) -> HandlerResult {
+///     tokio::spawn(
+///         async {
+///             event!(Level::INFO, "This log has the update span fields");
+///         }
+///         .instrument(span.0),
+///     );
+///
+///     Ok(FlowControl::Continue)
+/// }
+/// ```
+#[derive(Debug, Clone, FromContext)]
+#[context(
+    key = "span",
+    description = "Tracing span of the update processing. This type is available if the update is fed by the dispatcher."
+)]
+pub struct UpdateSpan(pub Span);
+
+/// Raw JSON of the update, as it's received from Telegram.
+/// It's used to capture the update to a [`Job`](snapshot::Job), because [`Update`](crate::types::Update) can't be serialized back.
+#[derive(Debug, Clone, PartialEq, FromContext)]
+#[context(
+    key = "raw_update",
+    description = "Raw JSON of the update. This type is available if the update is fed by the dispatcher with enabled `raw_updates` option."
+)]
+pub struct RawUpdate(pub Arc<Value>);
+
+impl RawUpdate {
+    #[must_use]
+    pub fn new(val: Value) -> Self {
+        Self(Arc::new(val))
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,264 @@
+//! This module contains [`Snapshot`] and [`Job`], which are used to offload heavy work from handlers to external queues
+//! (for example, RabbitMQ or Redis) and process it in workers.
+//!
+//! [`Job`] is a serializable snapshot of the update and selected values of the context.
+//! [`Snapshot`] describes which context keys are captured and how their values are serialized and deserialized back,
+//! so the same snapshot must be used by the handler, which captures the job, and by the worker, which restores it.
+//! Values of the context are stored as `Box<dyn Any>`, so their types must be registered with [`Snapshot::key`].
+//!
+//! [`Update`] can't be serialized back, so its raw JSON is captured from [`RawUpdate`],
+//! which is put to the context by the dispatcher with enabled [`Builder::raw_updates`] option.
+//!
+//! After restoring, the update and the context can be passed to extractors, so the worker uses the same types as handlers:
+//!
+//! ```ignore
+//! let snapshot = Snapshot::new().key::<Settings>("settings").key::<Locale>("locale");
+//!
+//! // Handler
+//! async fn handler(context: Arc<Context>, queue: Queue) -> HandlerResult {
+//!     let job = snapshot.capture(&context)?;
+//!     queue.push(serde_json::to_vec(&job)?).await?;
+//!
+//!     Ok(FlowControl::Continue)
+//! }
+//!
+//! // Worker
+//! let job: Job = serde_json::from_slice(&queue.pop().await?)?;
+//! let (update, context) = snapshot.restore(job)?;
+//! let (update, context) = (Arc::new(update), Arc::new(context));
+//!
+//! let message = Message::extract(Arc::clone(&bot), Arc::clone(&update), Arc::clone(&context))?;
+//! let settings = Settings::extract(bot, update, context)?;
+//! ```
+//!
+//! [`Update`]: crate::types::Update
+//! [`Builder::raw_updates`]: crate::dispatcher::Builder#method.raw_updates
+
+use super::{Context, RawUpdate};
+
+use crate::types::Update;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::{any::Any, collections::HashMap};
+use tracing::{event, Level};
+
+type Encode = fn(&(dyn Any + Send + Sync)) -> Option<Result<Value, serde_json::Error>>;
+type Decode = fn(Value) -> Result<Box<dyn Any + Send + Sync>, serde_json::Error>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Raw update isn't in the context. Enable `raw_updates` option of the dispatcher")]
+    RawUpdateNotFound,
+    #[error("Value of the context key `{0}` has another type than the registered one")]
+    UnexpectedType(&'static str),
+    #[error(transparent)]
+    Parse(#[from] serde_json::Error),
+}
+
+/// Serializable snapshot of the update and selected values of the context.
+/// Check the [module](self) documentation for more information.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Job {
+    /// Raw JSON of the update
+    pub update: Value,
+    /// Captured values of the context by their keys
+    pub context: HashMap<Box<str>, Value>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Codec {
+    key: &'static str,
+    encode: Encode,
+    decode: Decode,
+}
+
+fn encode<T>(value: &(dyn Any + Send + Sync)) -> Option<Result<Value, serde_json::Error>>
+where
+    T: Serialize + 'static,
+{
+    value.downcast_ref::<T>().map(serde_json::to_value)
+}
+
+fn decode<T>(value: Value) -> Result<Box<dyn Any + Send + Sync>, serde_json::Error>
+where
+    T: DeserializeOwned + Send + Sync + 'static,
+{
+    serde_json::from_value::<T>(value).map(|value| Box::new(value) as _)
+}
+
+/// Description of the context keys, which are captured to [`Job`].
+/// Check the [module](self) documentation for more information.
+#[derive(Debug, Default, Clone)]
+pub struct Snapshot {
+    codecs: Vec<Codec>,
+}
+
+impl Snapshot {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the context key with the type of its value.
+    /// If the key is already registered, its type is replaced.
+    #[must_use]
+    pub fn key<T>(self, key: &'static str) -> Self
+    where
+        T: Serialize + DeserializeOwned + Send + Sync + 'static,
+    {
+        let codec = Codec {
+            key,
+            encode: encode::<T>,
+            decode: decode::<T>,
+        };
+
+        Self {
+            codecs: self
+                .codecs
+                .into_iter()
+                .filter(|codec| codec.key != key)
+                .chain(Some(codec))
+                .collect(),
+        }
+    }
+
+    /// Captures the update and the registered values of the context to the job.
+    /// Keys, which aren't in the context, are skipped.
+    /// # Errors
+    /// - If [`RawUpdate`] isn't in the context
+    /// - If the value of the registered key has another type
+    /// - If the value can't be serialized
+    pub fn capture(&self, context: &Context) -> Result<Job, Error> {
+        let update = context
+            .get("raw_update")
+            .and_then(|value| {
+                value
+                    .downcast_ref::<RawUpdate>()
+                    .map(|raw| (*raw.0).clone())
+            })
+            .ok_or(Error::RawUpdateNotFound)?;
+
+        let mut values = HashMap::with_capacity(self.codecs.len());
+        for Codec { key, encode, .. } in &self.codecs {
+            let Some(value) = context.get(key) else {
+                continue;
+            };
+
+            let value = encode(value.as_ref()).ok_or(Error::UnexpectedType(key))??;
+            values.insert((*key).into(), value);
+        }
+
+        Ok(Job {
+            update,
+            context: values,
+        })
+    }
+
+    /// Restores the update and the context from the job.
+    /// Context contains [`RawUpdate`] and the registered values, so the job can be captured again.
+    /// Values of the unregistered keys are skipped.
+    /// # Errors
+    /// If the update or the values can't be deserialized
+    pub fn restore(&self, job: Job) -> Result<(Update, Context), Error> {
+        let Job {
+            update: raw,
+            context: mut values,
+        } = job;
+
+        let update = Update::deserialize(&raw)?;
+
+        let context = Context::new();
+        context.insert("raw_update", Box::new(RawUpdate::new(raw)));
+
+        for Codec { key, decode, .. } in &self.codecs {
+            if let Some(value) = values.remove(*key) {
+                context.insert(key, decode(value)?);
+            }
+        }
+
+        for key in values.keys() {
+            event!(Level::WARN, key = %key, "Context key of the job isn't registered, it's skipped");
+        }
+
+        Ok((update, context))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{Bot, Reqwest},
+        extractors::FromEventAndContext as _,
+        types::Message,
+        FromContext,
+    };
+
+    use serde_json::json;
+    use std::sync::Arc;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromContext)]
+    #[context(key = "settings")]
+    struct Settings {
+        lang: Box<str>,
+    }
+
+    #[test]
+    fn test_capture_restore() {
+        let snapshot = Snapshot::new()
+            .key::<u8>("settings")
+            .key::<Settings>("settings")
+            .key::<i64>("limit");
+
+        let context = Context::new();
+        assert!(matches!(
+            snapshot.capture(&context),
+            Err(Error::RawUpdateNotFound)
+        ));
+
+        context.insert(
+            "raw_update",
+            Box::new(RawUpdate::new(json!({
+                "update_id": 1,
+                "message": {
+                    "message_id": 2,
+                    "date": 0,
+                    "chat": {"id": 3, "type": "private", "first_name": "test"},
+                    "text": "test"
+                }
+            }))),
+        );
+        context.insert("settings", Box::new(Settings { lang: "en".into() }));
+        context.insert("other", Box::new(1_u8));
+
+        let job = snapshot.capture(&context).unwrap();
+        assert_eq!(job.context.len(), 1);
+
+        // Job survives serialization
+        let job: Job = serde_json::from_str(&serde_json::to_string(&job).unwrap()).unwrap();
+
+        let (update, context) = snapshot.restore(job).unwrap();
+        let (bot, update, context) = (
+            Arc::new(Bot::<Reqwest>::default()),
+            Arc::new(update),
+            Arc::new(context),
+        );
+
+        let message =
+            Message::extract(Arc::clone(&bot), Arc::clone(&update), Arc::clone(&context)).unwrap();
+        assert_eq!(message.id(), 2);
+        assert_eq!(
+            Settings::extract(bot, update, Arc::clone(&context)).unwrap(),
+            Settings { lang: "en".into() }
+        );
+        assert!(context.get("other").is_none());
+        assert!(snapshot.capture(&context).is_ok());
+
+        context.insert("limit", Box::new(1_u8));
+        assert!(matches!(
+            snapshot.capture(&context),
+            Err(Error::UnexpectedType("limit"))
+        ));
+    }
+}
@@ -24,6 +24,17 @@
 //! See [`UpdateType`] for a complete list of available update types.
 //! By default, all update types except [`ChatMember`] are enabled.
 //!
+//! Allowed updates are checked against update types, which are handled by the main router, when the dispatcher is converted to the service.
+//! Policy of the check can be changed with [`Builder::allowed_updates_policy`] method (see [`allowed_updates module`] for more information).
+//!
+//! Configuration of the main router is validated when the dispatcher is converted to the service as well
+//! (overlapping command handlers, handlers using FSM without `FSMContext` middleware, etc.),
+//! and all found errors are returned at once as [`InitError::Config`] (see [`ConfigError`] for the list of the checks).
+//!
+//! Pending updates, which are received while the bot was offline, can be dropped when polling is started with [`Builder::drop_pending_updates`] method.
+//! Update filters, which are executed for each update before routing, can be added with [`Builder::update_filter`] method,
+//! for example, [`max_update_age`] filter drops updates older than N seconds after downtime.
+//!
 //! Dispatcher supports startup and shutdown events.
 //! You can register handlers for these observers (startup and shutdown) in the main router and handle them (see [`router module`]).
 //! When you call long polling with [`Dispatcher::run_polling`] method, it will emit main router startup event
@@ -31,6 +42,39 @@
 //! Also, you can emit these events manually with [`Dispatcher::emit_startup`] and [`Dispatcher::emit_shutdown`] methods.
 //! See [`Dispatcher::run_polling_without_startup_and_shutdown`] method if you don't need emitting these events.
 //!
+//! Besides router observers, dispatcher emits typed lifecycle events (startup, shutdown, polling started/stopped, polling errors, etc.)
+//! to the [`LifecycleBus`], which can be subscribed from middlewares, background tasks and any other code.
+//! You can pass own bus with [`Builder::lifecycle_bus`] method or get the bus with [`Dispatcher::lifecycle`] method
+//! (see [`lifecycle module`] for more information).
+//!
+//! Routing of each update can be traced with [`Builder::trace_routing`] method to debug why the update matched no handler
+//! (see [`RoutingReport`] for more information).
+//! Durations of the update processing stages (outer middlewares, filters, extraction, handler and inner middlewares)
+//! can be traced with [`Builder::trace_timings`] method to find where the latency goes (see [`Timings`] for more information).
+//!
+//! Raw JSON of each update can be kept in the context with [`Builder::raw_updates`] method
+//! to capture the update to a serializable job and process it outside the handler (see [`snapshot module`] for more information).
+//!
+//! Updates of the `getUpdates` response are decoded and pushed to the queue one by one, while the response is being received,
+//! so big batches of updates don't wait for the full response (see [`Builder::stream_updates`] method).
+//!
+//! Received updates wait to be processed in a bounded queue with configurable capacity and overflow policy
+//! (see [`Builder::queue_capacity`], [`Builder::overflow_policy`] and [`Builder::concurrency_limit`] methods and [`queue module`]).
+//!
+//! Dispatcher collects health status of the polling processes (last update time, polling lag, queue depth, last API error),
+//! which you can get with [`Dispatcher::health`] method.
+//! If `health-server` feature is enabled, you can also run a tiny HTTP health endpoint with [`Dispatcher::run_health_server`] method
+//! (see [`health module`] for more information).
+//! Handlers can get the health status, number of fed updates, uptime and [`ServiceHandle`] with [`Introspection`] argument,
+//! which is put to the context of each update (see [`introspection module`] for more information).
+//!
+//! Handlers can run fire-and-forget work with [`TaskManager`] argument, which tracks the tasks and waits for them on shutdown
+//! (see [`tasks module`] for more information).
+//!
+//! If `webhook-server` feature is enabled, you can receive updates with the standalone webhook server with TLS instead of polling
+//! with [`Dispatcher::run_webhook`] method (see [`webhook module`] for more information).
+//! Many bots, which are resolved on each request, can be hosted by the same server with [`Dispatcher::run_webhook_with_resolver`] method.
+//!
 //! Use [`Dispatcher::feed_update`] and [`Dispatcher::feed_update_with_context`] methods for feeding updates to the dispatcher manually.
 //! These methods are useful for testing or if you want to use your own update source.
 //! Second method allows you to pass [`Context`] with own data, which will be used in the handlers, middlewares, etc. (see [`context module`] for more information).
@@ -38,13 +82,39 @@
 //! Check out the examples directory for usage examples.
 //!
 //! [`Router`]: crate::router::Router
+//! [`ConfigError`]: crate::errors::ConfigError
 //! [`UpdateType`]: crate::enums::UpdateType
 //! [`ChatMember`]: crate::enums::UpdateType::ChatMember
 //! [`router module`]: crate::router
 //! [`context module`]: crate::context
+//! [`lifecycle module`]: crate::event::lifecycle
+//! [`health module`]: health
+//! [`introspection module`]: introspection
+//! [`tasks module`]: tasks
+//! [`webhook module`]: webhook
+//! [`Dispatcher::run_webhook`]: Service#method.run_webhook
+//! [`Dispatcher::run_webhook_with_resolver`]: Service#method.run_webhook_with_resolver
+//! [`allowed_updates module`]: allowed_updates
+//! [`Builder::allowed_updates_policy`]: Builder#method.allowed_updates_policy
+//! [`queue module`]: queue
+//! [`Builder::queue_capacity`]: Builder#method.queue_capacity
+//! [`Builder::overflow_policy`]: Builder#method.overflow_policy
+//! [`Builder::concurrency_limit`]: Builder#method.concurrency_limit
+//! [`Builder::trace_routing`]: Builder#method.trace_routing
+//! [`Builder::trace_timings`]: Builder#method.trace_timings
+//! [`Builder::raw_updates`]: Builder#method.raw_updates
+//! [`Builder::stream_updates`]: Builder#method.stream_updates
+//! [`snapshot module`]: crate::context::snapshot
+//! [`Dispatcher::health`]: Service#method.health
+//! [`Dispatcher::run_health_server`]: Service#method.run_health_server
+//! [`LifecycleBus`]: crate::event::LifecycleBus
+//! [`Builder::lifecycle_bus`]: Builder#method.lifecycle_bus
+//! [`Dispatcher::lifecycle`]: Service#method.lifecycle
 //! [`Dispatcher::new`]: Dispatcher#method.new
 //! [`Builder::polling_timeout`]: Builder#method.polling_timeout
 //! [`Builder::backoff`]: Builder#method.backoff
+//! [`Builder::drop_pending_updates`]: Builder#method.drop_pending_updates
+//! [`Builder::update_filter`]: Builder#method.update_filter
 //! [`Dispatcher::run_polling`]: Service#method.run_polling
 //! [`Dispatcher::emit_startup`]: Service#method.emit_startup
 //! [`Dispatcher::emit_shutdown`]: Service#method.emit_shutdown
@@ -52,41 +122,146 @@
 //! [`Dispatcher::feed_update`]: Service#method.feed_update
 //! [`Dispatcher::feed_update_with_context`]: Service#method.feed_update_with_context
 
-use super::router::{PropagateEvent, Request, Response};
+pub mod allowed_updates;
+pub mod health;
+pub mod introspection;
+pub mod queue;
+pub mod tasks;
+#[cfg(feature = "webhook-server")]
+pub mod webhook;
+
+pub use allowed_updates::AllowedUpdatesPolicy;
+pub use health::{BotHealth, Health};
+pub use introspection::Introspection;
+pub use queue::OverflowPolicy;
+pub use tasks::TaskManager;
 
+use super::router::{PropagateEvent, Request, Response, RoutingReport, ServiceHandle, Timings};
+
+use self::queue::Queue;
+
+#[cfg(feature = "fsm")]
+use crate::fsm::{conversation::Registry as ConversationRegistry, Strategy};
 use crate::{
     client::{Bot, Session},
-    context::Context,
+    context::{Context, RawUpdate, RequestId, RequestIdGenerator, UpdateSpan},
     enums::UpdateType,
     errors::EventErrorKind,
+    errors::{ConfigError, ConfigErrors, SessionErrorKind, UpdateParseError},
     event::{
+        bases::PropagateEventResult,
+        lifecycle::{Bus as LifecycleBus, Event as LifecycleEvent},
         service::{ServiceProvider, ToServiceProvider},
         simple::HandlerResult as SimpleHandlerResult,
     },
-    methods::GetUpdates,
+    methods::{GetUpdates, Request as MethodRequest, Response as MethodResponse, TelegramMethod},
+    runtime::{self, Runtime},
     types::Update,
 };
 
 use backoff::{backoff::Backoff, exponential::ExponentialBackoff, SystemClock};
-use std::sync::Arc;
+use futures::TryStreamExt as _;
+use std::{
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 use thiserror;
-use tokio::sync::mpsc::{channel as mspc_channel, error::SendError, Sender};
+use tokio::sync::Semaphore;
 use tracing::{event, field, instrument, Level, Span};
 
 const GET_UPDATES_SIZE: i64 = 100;
-const CHANNEL_UPDATES_SIZE: usize = 100;
 
 pub const DEFAULT_POLLING_TIMEOUT: i64 = 30;
+pub const DEFAULT_QUEUE_CAPACITY: usize = 100;
+
+/// Received update with its raw JSON, if [`Builder::raw_updates`] is enabled
+type Received = (Update, Option<RawUpdate>);
+/// Received update with the bot, which received it, in the queue of updates
+type Queued<Client> = (Arc<Bot<Client>>, Received);
+
+/// [`GetUpdates`] method, which returns updates with their raw JSON (see [`Builder::raw_updates`])
+struct GetRawUpdates<'a>(&'a GetUpdates);
+
+impl<'a> TelegramMethod for GetRawUpdates<'a> {
+    type Method = GetUpdates;
+    type Return = Vec<(Update, serde_json::Value)>;
+
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> MethodRequest<'_, Self::Method> {
+        self.0.build_request(bot)
+    }
+
+    fn request_timeout(&self) -> Option<Duration> {
+        self.0.request_timeout()
+    }
+
+    fn build_response(
+        &self,
+        content: &str,
+    ) -> Result<MethodResponse<Self::Return>, serde_json::Error> {
+        let response = self.0.build_response(content)?;
+        let raw: MethodResponse<Vec<serde_json::Value>> = serde_json::from_str(content)?;
+
+        Ok(MethodResponse {
+            ok: response.ok,
+            result: response
+                .result
+                .zip(raw.result)
+                .map(|(updates, raw)| updates.into_iter().zip(raw).collect()),
+            description: response.description,
+            error_code: response.error_code,
+            parameters: response.parameters,
+        })
+    }
+
+    fn build_response_error(&self, content: &str, err: serde_json::Error) -> SessionErrorKind {
+        self.0.build_response_error(content, err)
+    }
+}
+
+impl<'a> AsRef<GetRawUpdates<'a>> for GetRawUpdates<'a> {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+/// Filter, which is executed for each update before routing (see [`Builder::update_filter`])
+pub type UpdateFilter = Arc<dyn Fn(&Update) -> bool + Send + Sync>;
+
+/// Creates update filter, which drops updates older than `max_age`, for example, received after downtime.
+/// Updates without date (inline queries, callback queries, etc.) aren't dropped.
+pub fn max_update_age(max_age: Duration) -> impl Fn(&Update) -> bool + Send + Sync + 'static {
+    move |update| {
+        let Some(date) = update.date() else {
+            return true;
+        };
+        let Ok(now) = SystemTime::now().duration_since(UNIX_EPOCH) else {
+            return true;
+        };
+
+        now.as_secs()
+            .saturating_sub(u64::try_from(date).unwrap_or_default())
+            <= max_age.as_secs()
+    }
+}
 
+/// Error, which can occur when the dispatcher is converted to the service
 #[derive(Debug, thiserror::Error)]
-enum ListenerError<T> {
+pub enum InitError<E> {
+    /// Main router can't be converted to the service
+    #[error("Main router can't be converted to the service: {0:?}")]
+    MainRouter(E),
+    /// Configuration of the dispatcher is invalid, check [`ConfigError`] for the list of the checks
     #[error(transparent)]
-    SendError(#[from] SendError<T>),
+    Config(#[from] ConfigErrors),
 }
 
 #[derive(Debug, thiserror::Error)]
 enum PollingError {
     #[error("Polling was aborted by signal")]
+    #[cfg_attr(
+        not(all(feature = "tokio-runtime", any(unix, windows))),
+        allow(dead_code)
+    )]
     Aborted,
 }
 
@@ -97,6 +272,24 @@ pub struct Dispatcher<Client, Propagator, BackoffType = ExponentialBackoff<Syste
     polling_timeout: Option<i64>,
     backoff: BackoffType,
     allowed_updates: Box<[UpdateType]>,
+    allowed_updates_policy: AllowedUpdatesPolicy,
+    lifecycle: LifecycleBus,
+    register_commands: bool,
+    request_id_generator: RequestIdGenerator,
+    #[cfg(feature = "fsm")]
+    conversation_strategy: Strategy,
+    drop_pending_updates: bool,
+    update_filters: Box<[UpdateFilter]>,
+    queue_capacity: usize,
+    overflow_policy: OverflowPolicy,
+    concurrency_limit: Option<usize>,
+    trace_routing: bool,
+    trace_timings: bool,
+    raw_updates: bool,
+    stream_updates: bool,
+    task_limit: Option<usize>,
+    task_shutdown_timeout: Duration,
+    runtime: Arc<dyn Runtime>,
 }
 
 impl<Client, Propagator, BackoffType> Dispatcher<Client, Propagator, BackoffType> {
@@ -140,6 +333,24 @@ impl<Client, Propagator, BackoffType> Dispatcher<Client, Propagator, BackoffType
             polling_timeout,
             backoff,
             allowed_updates: allowed_updates.into_iter().collect(),
+            allowed_updates_policy: AllowedUpdatesPolicy::default(),
+            lifecycle: LifecycleBus::default(),
+            register_commands: false,
+            request_id_generator: RequestId::default_generator(),
+            #[cfg(feature = "fsm")]
+            conversation_strategy: Strategy::default(),
+            drop_pending_updates: false,
+            update_filters: Box::new([]),
+            queue_capacity: DEFAULT_QUEUE_CAPACITY,
+            overflow_policy: OverflowPolicy::default(),
+            concurrency_limit: None,
+            trace_routing: false,
+            trace_timings: false,
+            raw_updates: false,
+            stream_updates: true,
+            task_limit: None,
+            task_shutdown_timeout: tasks::DEFAULT_SHUTDOWN_TIMEOUT,
+            runtime: runtime::default(),
         }
     }
 }
@@ -154,6 +365,24 @@ where
     }
 }
 
+#[cfg(feature = "config")]
+impl<Propagator> Dispatcher<crate::client::Reqwest, Propagator>
+where
+    Propagator: Default,
+{
+    /// Creates a new dispatcher builder with the bot, built from the configuration.
+    /// Check [`config module`](crate::config) for more information.
+    /// # Errors
+    /// If the client of the bot cannot be built
+    /// # Panics
+    /// If the token is invalid
+    pub fn from_config(
+        config: &crate::config::BotConfig,
+    ) -> Result<Builder<crate::client::Reqwest, Propagator>, crate::config::Error> {
+        config.bot().map(|bot| Builder::default().bot(bot))
+    }
+}
+
 impl<Client, Propagator, BackoffType> Dispatcher<Client, Propagator, BackoffType>
 where
     Propagator: Default,
@@ -170,6 +399,24 @@ pub struct Builder<Client, Propagator, BackoffType = ExponentialBackoff<SystemCl
     polling_timeout: Option<i64>,
     backoff: BackoffType,
     allowed_updates: Vec<UpdateType>,
+    allowed_updates_policy: AllowedUpdatesPolicy,
+    lifecycle: LifecycleBus,
+    register_commands: bool,
+    request_id_generator: RequestIdGenerator,
+    #[cfg(feature = "fsm")]
+    conversation_strategy: Strategy,
+    drop_pending_updates: bool,
+    update_filters: Box<[UpdateFilter]>,
+    queue_capacity: usize,
+    overflow_policy: OverflowPolicy,
+    concurrency_limit: Option<usize>,
+    trace_routing: bool,
+    trace_timings: bool,
+    raw_updates: bool,
+    stream_updates: bool,
+    task_limit: Option<usize>,
+    task_shutdown_timeout: Duration,
+    runtime: Arc<dyn Runtime>,
 }
 
 impl<Client, Propagator> Default for Builder<Client, Propagator>
@@ -185,6 +432,24 @@ where
             polling_timeout: Some(DEFAULT_POLLING_TIMEOUT),
             backoff: ExponentialBackoff::default(),
             allowed_updates: vec![],
+            allowed_updates_policy: AllowedUpdatesPolicy::default(),
+            lifecycle: LifecycleBus::default(),
+            register_commands: false,
+            request_id_generator: RequestId::default_generator(),
+            #[cfg(feature = "fsm")]
+            conversation_strategy: Strategy::default(),
+            drop_pending_updates: false,
+            update_filters: Box::new([]),
+            queue_capacity: DEFAULT_QUEUE_CAPACITY,
+            overflow_policy: OverflowPolicy::default(),
+            concurrency_limit: None,
+            trace_routing: false,
+            trace_timings: false,
+            raw_updates: false,
+            stream_updates: true,
+            task_limit: None,
+            task_shutdown_timeout: tasks::DEFAULT_SHUTDOWN_TIMEOUT,
+            runtime: runtime::default(),
         }
     }
 }
@@ -201,6 +466,24 @@ where
             polling_timeout: Some(DEFAULT_POLLING_TIMEOUT),
             backoff,
             allowed_updates: vec![],
+            allowed_updates_policy: AllowedUpdatesPolicy::default(),
+            lifecycle: LifecycleBus::default(),
+            register_commands: false,
+            request_id_generator: RequestId::default_generator(),
+            #[cfg(feature = "fsm")]
+            conversation_strategy: Strategy::default(),
+            drop_pending_updates: false,
+            update_filters: Box::new([]),
+            queue_capacity: DEFAULT_QUEUE_CAPACITY,
+            overflow_policy: OverflowPolicy::default(),
+            concurrency_limit: None,
+            trace_routing: false,
+            trace_timings: false,
+            raw_updates: false,
+            stream_updates: true,
+            task_limit: None,
+            task_shutdown_timeout: tasks::DEFAULT_SHUTDOWN_TIMEOUT,
+            runtime: runtime::default(),
         }
     }
 }
@@ -311,6 +594,229 @@ impl<Client, Propagator, BackoffType> Builder<Client, Propagator, BackoffType> {
         }
     }
 
+    /// Policy, which is used when allowed updates don't contain update types, which are handled by the main router.
+    /// Check [`allowed_updates module`](allowed_updates) for more information.
+    /// # Default
+    /// [`AllowedUpdatesPolicy::Warn`]
+    #[must_use]
+    pub fn allowed_updates_policy(self, val: AllowedUpdatesPolicy) -> Self {
+        Self {
+            allowed_updates_policy: val,
+            ..self
+        }
+    }
+
+    /// Bus for lifecycle events of the dispatcher.
+    /// Use it if you want to subscribe to lifecycle events before the dispatcher is built
+    /// or share the same bus between multiple dispatchers.
+    /// # Default
+    /// New bus with [`DEFAULT_CAPACITY`](crate::event::lifecycle::DEFAULT_CAPACITY)
+    #[must_use]
+    pub fn lifecycle_bus(self, val: LifecycleBus) -> Self {
+        Self {
+            lifecycle: val,
+            ..self
+        }
+    }
+
+    /// Register bot commands from the main router handlers with description in the bot command menu
+    /// (see [`Router::resolve_bot_commands`]), when polling is started.
+    /// Commands are registered with [`SetMyCommands`](crate::methods::SetMyCommands) method for each scope and language.
+    /// # Default
+    /// `false`, because it sends requests to the Telegram API and overrides commands set manually
+    ///
+    /// [`Router::resolve_bot_commands`]: crate::router::Router#method.resolve_bot_commands
+    #[must_use]
+    pub fn register_commands(self, val: bool) -> Self {
+        Self {
+            register_commands: val,
+            ..self
+        }
+    }
+
+    /// Generator of request IDs, which are put to the context and the update span for each update
+    /// (see [`context module`](crate::context) for more information).
+    /// # Default
+    /// [`RequestId::generate`], which generates random UUID v4
+    #[must_use]
+    pub fn request_id_generator(self, val: impl Fn() -> RequestId + Send + Sync + 'static) -> Self {
+        Self {
+            request_id_generator: Arc::new(val),
+            ..self
+        }
+    }
+
+    /// Strategy of the [`Conversation`](crate::fsm::Conversation) registry,
+    /// which is used to match updates to waiting conversations (see [`conversation module`](crate::fsm::conversation) for more information).
+    /// # Default
+    /// [`Strategy::UserInChat`]
+    #[cfg(feature = "fsm")]
+    #[must_use]
+    pub fn conversation_strategy(self, val: Strategy) -> Self {
+        Self {
+            conversation_strategy: val,
+            ..self
+        }
+    }
+
+    /// Drop updates, which are received while the bot was offline, when polling is started
+    /// # Default
+    /// `false`
+    #[must_use]
+    pub fn drop_pending_updates(self, val: bool) -> Self {
+        Self {
+            drop_pending_updates: val,
+            ..self
+        }
+    }
+
+    /// Filter, which is executed for each update before routing.
+    /// If the filter returns `false`, the update is dropped and isn't propagated to the main router.
+    /// For example, use [`max_update_age`] to drop updates older than N seconds after downtime.
+    /// # Notes
+    /// You can add multiple filters, update is propagated only if all filters return `true`
+    #[must_use]
+    pub fn update_filter(self, val: impl Fn(&Update) -> bool + Send + Sync + 'static) -> Self {
+        Self {
+            update_filters: self
+                .update_filters
+                .into_vec()
+                .into_iter()
+                .chain(Some(Arc::new(val) as UpdateFilter))
+                .collect(),
+            ..self
+        }
+    }
+
+    /// Capacity of the queue of received updates, which wait to be processed (see [`queue module`](queue) for more information)
+    /// # Default
+    /// [`DEFAULT_QUEUE_CAPACITY`]
+    /// # Notes
+    /// The capacity must be greater than zero, otherwise [`ConfigError::ZeroLimit`] is returned when the dispatcher is converted to the service
+    #[must_use]
+    pub fn queue_capacity(self, val: usize) -> Self {
+        Self {
+            queue_capacity: val,
+            ..self
+        }
+    }
+
+    /// Policy, which is used when the queue of received updates is full
+    /// # Default
+    /// [`OverflowPolicy::Block`]
+    #[must_use]
+    pub fn overflow_policy(self, val: OverflowPolicy) -> Self {
+        Self {
+            overflow_policy: val,
+            ..self
+        }
+    }
+
+    /// Max number of updates, which are processed concurrently by all bots.
+    /// If the limit is reached, received updates wait in the queue.
+    /// # Default
+    /// Unlimited
+    /// # Notes
+    /// The limit must be greater than zero, otherwise [`ConfigError::ZeroLimit`] is returned when the dispatcher is converted to the service
+    #[must_use]
+    pub fn concurrency_limit(self, val: usize) -> Self {
+        Self {
+            concurrency_limit: Some(val),
+            ..self
+        }
+    }
+
+    /// Trace routing of each update: which routers, observers and handlers were tried and which filter rejected the update.
+    /// [`RoutingReport`] is put to the context and unhandled updates are logged with the report.
+    /// It's useful to debug "my handler never fires" issues, but it slows down the routing, so don't use it in production.
+    /// # Default
+    /// `false`
+    #[must_use]
+    pub fn trace_routing(self, val: bool) -> Self {
+        Self {
+            trace_routing: val,
+            ..self
+        }
+    }
+
+    /// Trace durations of the update processing stages: outer middlewares, filters, extraction, handler and inner middlewares.
+    /// [`Timings`] is put to the context and the breakdown of each processed update is logged.
+    /// It's useful to find where the latency goes, but it slows down the processing a bit.
+    /// # Default
+    /// `false`
+    #[must_use]
+    pub fn trace_timings(self, val: bool) -> Self {
+        Self {
+            trace_timings: val,
+            ..self
+        }
+    }
+
+    /// Keep raw JSON of each update and put it to the context as [`RawUpdate`],
+    /// so the update can be captured to a [`Job`] with [`Snapshot`] and processed outside the handler.
+    /// It slows down the receiving of updates, because each update is parsed twice, so enable it only if you need it.
+    /// # Default
+    /// `false`
+    ///
+    /// [`Job`]: crate::context::snapshot::Job
+    /// [`Snapshot`]: crate::context::snapshot::Snapshot
+    #[must_use]
+    pub fn raw_updates(self, val: bool) -> Self {
+        Self {
+            raw_updates: val,
+            ..self
+        }
+    }
+
+    /// Decode updates of the `getUpdates` response one by one and push them to the queue, while the response is being received,
+    /// instead of waiting for the full response and deserializing all updates at once.
+    /// It reduces latency of the first updates and peak memory for big batches of updates (check [`Bot::send_streamed`]).
+    /// # Default
+    /// `true`
+    #[must_use]
+    pub fn stream_updates(self, val: bool) -> Self {
+        Self {
+            stream_updates: val,
+            ..self
+        }
+    }
+
+    /// Maximum number of concurrently running background tasks of [`TaskManager`], other tasks wait for a free slot
+    /// # Default
+    /// Unlimited
+    /// # Notes
+    /// The limit must be greater than zero, otherwise [`ConfigError::ZeroLimit`] is returned when the dispatcher is converted to the service
+    #[must_use]
+    pub fn task_limit(self, val: usize) -> Self {
+        Self {
+            task_limit: Some(val),
+            ..self
+        }
+    }
+
+    /// Time to wait for background tasks of [`TaskManager`] on shutdown before shutdown observers are called
+    /// # Default
+    /// [`tasks::DEFAULT_SHUTDOWN_TIMEOUT`]
+    #[must_use]
+    pub fn task_shutdown_timeout(self, val: Duration) -> Self {
+        Self {
+            task_shutdown_timeout: val,
+            ..self
+        }
+    }
+
+    /// Runtime, which is used to spawn polling processes and update handlers and to sleep between retries.
+    /// Check [`runtime`] module documentation for more information.
+    /// # Default
+    /// [`runtime::Tokio`] with `tokio-runtime` feature
+    #[must_use]
+    pub fn runtime(self, val: impl Runtime) -> Self {
+        Self {
+            runtime: Arc::new(val),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn build(self) -> Dispatcher<Client, Propagator, BackoffType> {
         Dispatcher {
@@ -319,43 +825,199 @@ impl<Client, Propagator, BackoffType> Builder<Client, Propagator, BackoffType> {
             polling_timeout: self.polling_timeout,
             backoff: self.backoff,
             allowed_updates: self.allowed_updates.into_iter().collect(),
+            allowed_updates_policy: self.allowed_updates_policy,
+            lifecycle: self.lifecycle,
+            register_commands: self.register_commands,
+            request_id_generator: self.request_id_generator,
+            #[cfg(feature = "fsm")]
+            conversation_strategy: self.conversation_strategy,
+            drop_pending_updates: self.drop_pending_updates,
+            update_filters: self.update_filters,
+            queue_capacity: self.queue_capacity,
+            overflow_policy: self.overflow_policy,
+            concurrency_limit: self.concurrency_limit,
+            trace_routing: self.trace_routing,
+            trace_timings: self.trace_timings,
+            raw_updates: self.raw_updates,
+            stream_updates: self.stream_updates,
+            task_limit: self.task_limit,
+            task_shutdown_timeout: self.task_shutdown_timeout,
+            runtime: self.runtime,
         }
     }
 }
 
 /// This converts all dependencies to [`ServiceProvider`] and creates [`Arc<Service>`]
 /// that contains converted [`ServiceProvider`]s.
-impl<Client, BackoffType, PropagatorService, Propagator, Cfg, InitError> ToServiceProvider
+///
+/// Configuration of the dispatcher is validated on conversion and all found errors are returned at once
+/// (see [`ConfigError`] for the list of the checks).
+impl<Client, BackoffType, PropagatorService, Propagator, Cfg, PropagatorInitError> ToServiceProvider
     for Dispatcher<Client, Propagator, BackoffType>
 where
     Client: Send + Sync + 'static,
-    Propagator:
-        ToServiceProvider<Config = Cfg, ServiceProvider = PropagatorService, InitError = InitError>,
+    Propagator: ToServiceProvider<
+        Config = Cfg,
+        ServiceProvider = PropagatorService,
+        InitError = PropagatorInitError,
+    >,
+    PropagatorService: PropagateEvent<Client>,
 {
     type Config = Cfg;
     type ServiceProvider = Arc<Service<Client, PropagatorService, BackoffType>>;
-    type InitError = InitError;
+    type InitError = InitError<PropagatorInitError>;
 
+    /// # Errors
+    /// - If the main router can't be converted to the service
+    /// - If configuration of the dispatcher is invalid
     fn to_service_provider(
         self,
         config: Self::Config,
     ) -> Result<Self::ServiceProvider, Self::InitError> {
+        let main_router = self
+            .main_router
+            .to_service_provider(config)
+            .map_err(InitError::MainRouter)?;
+
+        let mut config_errors = main_router
+            .config_errors()
+            .iter()
+            // Updates can be fed manually without bots (for example, in tests), so the main router without handlers is allowed
+            .filter(|err| !self.bots.is_empty() || **err != ConfigError::NoHandlers)
+            .cloned()
+            .collect::<Vec<_>>();
+        let allowed_updates = match allowed_updates::check(
+            self.allowed_updates,
+            main_router.used_update_types(),
+            self.allowed_updates_policy,
+        ) {
+            Ok(allowed_updates) => allowed_updates,
+            Err(err) => {
+                config_errors.push(err);
+                Box::new([])
+            }
+        };
+        config_errors.extend(
+            [
+                ("queue_capacity", Some(self.queue_capacity)),
+                ("concurrency_limit", self.concurrency_limit),
+                ("task_limit", self.task_limit),
+            ]
+            .into_iter()
+            .filter(|(_, limit)| *limit == Some(0))
+            .map(|(option, _)| ConfigError::ZeroLimit { option }),
+        );
+        if !config_errors.is_empty() {
+            return Err(ConfigErrors::new(config_errors).into());
+        }
+
+        let health = Arc::<health::State>::default();
+        let introspection = Introspection::new(
+            self.bots.iter().map(|bot| bot.bot_id),
+            Arc::clone(&health),
+            main_router.handle(),
+        );
+
         Ok(Arc::new(Service {
-            main_router: self.main_router.to_service_provider(config)?,
+            main_router,
             bots: self.bots,
             polling_timeout: self.polling_timeout,
             backoff: self.backoff,
-            allowed_updates: self.allowed_updates,
+            allowed_updates,
+            lifecycle: self.lifecycle,
+            health,
+            introspection,
+            register_commands: self.register_commands,
+            request_id_generator: self.request_id_generator,
+            #[cfg(feature = "fsm")]
+            conversations: Arc::new(ConversationRegistry::with_runtime(
+                self.conversation_strategy,
+                Arc::clone(&self.runtime),
+            )),
+            drop_pending_updates: self.drop_pending_updates,
+            update_filters: self.update_filters,
+            queue_capacity: self.queue_capacity,
+            overflow_policy: self.overflow_policy,
+            concurrency_limit: self
+                .concurrency_limit
+                .map(|limit| Arc::new(Semaphore::new(limit))),
+            trace_routing: self.trace_routing,
+            trace_timings: self.trace_timings,
+            raw_updates: self.raw_updates,
+            stream_updates: self.stream_updates,
+            tasks: TaskManager::new(Arc::clone(&self.runtime), self.task_limit),
+            task_shutdown_timeout: self.task_shutdown_timeout,
+            runtime: self.runtime,
         }))
     }
 }
 
+/// Waits for exit signal: SIGINT or SIGTERM on unix and CTRL+C or CTRL+BREAK on windows
+/// # Panics
+/// If failed to register exit signal handlers
+#[cfg(all(feature = "tokio-runtime", any(unix, windows)))]
+async fn wait_exit_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        let mut sigint =
+            signal(SignalKind::interrupt()).expect("Failed to register SIGINT handler");
+        let mut sigterm =
+            signal(SignalKind::terminate()).expect("Failed to register SIGTERM handler");
+
+        tokio::select! {
+            _ = sigint.recv() => {
+                event!(Level::WARN, "SIGINT signal received");
+            },
+            _ = sigterm.recv() => {
+                event!(Level::WARN, "SIGTERM signal received");
+            },
+        }
+    }
+    #[cfg(windows)]
+    {
+        use tokio::signal::windows::{ctrl_break, ctrl_c};
+
+        let mut ctrl_c = ctrl_c().expect("Failed to register CTRL+C handler");
+        let mut ctrl_break = ctrl_break().expect("Failed to register CTRL+BREAK handler");
+
+        tokio::select! {
+            _ = ctrl_c.recv() => {
+                event!(Level::WARN, "CTRL+C signal received");
+            },
+            _ = ctrl_break.recv() => {
+                event!(Level::WARN,  "CTRL+BREAK signal received");
+            },
+        }
+    }
+}
+
 pub struct Service<Client, PropagatorService, BackoffType> {
     main_router: PropagatorService,
     bots: Box<[Bot<Client>]>,
     polling_timeout: Option<i64>,
     backoff: BackoffType,
     allowed_updates: Box<[UpdateType]>,
+    lifecycle: LifecycleBus,
+    health: Arc<health::State>,
+    introspection: Introspection,
+    register_commands: bool,
+    request_id_generator: RequestIdGenerator,
+    #[cfg(feature = "fsm")]
+    conversations: Arc<ConversationRegistry>,
+    drop_pending_updates: bool,
+    update_filters: Box<[UpdateFilter]>,
+    queue_capacity: usize,
+    overflow_policy: OverflowPolicy,
+    concurrency_limit: Option<Arc<Semaphore>>,
+    trace_routing: bool,
+    trace_timings: bool,
+    raw_updates: bool,
+    stream_updates: bool,
+    tasks: TaskManager,
+    task_shutdown_timeout: Duration,
+    runtime: Arc<dyn Runtime>,
 }
 
 impl<Client, PropagatorService, BackoffType> ServiceProvider
@@ -364,6 +1026,108 @@ impl<Client, PropagatorService, BackoffType> ServiceProvider
 }
 
 impl<Client, PropagatorService, BackoffType> Service<Client, PropagatorService, BackoffType> {
+    /// Gets bus for lifecycle events of the dispatcher.
+    /// Use [`LifecycleBus::subscribe`] to receive them.
+    #[must_use]
+    pub const fn lifecycle(&self) -> &LifecycleBus {
+        &self.lifecycle
+    }
+
+    /// Gets update types, which are received by polling.
+    /// They are checked against update types, which are handled by the main router, according to [`AllowedUpdatesPolicy`].
+    /// # Notes
+    /// Pass them to `setWebhook` request when switching to webhook, so the same update types are received.
+    /// Empty list means that all update types except [`UpdateType::ChatMember`], [`UpdateType::MessageReaction`] and [`UpdateType::MessageReactionCount`] are received.
+    #[must_use]
+    pub const fn allowed_updates(&self) -> &[UpdateType] {
+        &self.allowed_updates
+    }
+
+    /// Gets registry of conversations, which wait for updates
+    #[cfg(feature = "fsm")]
+    #[must_use]
+    pub const fn conversations(&self) -> &Arc<ConversationRegistry> {
+        &self.conversations
+    }
+
+    /// Gets health status of the polling processes for all bots of the dispatcher
+    #[must_use]
+    pub fn health(&self) -> Health {
+        Health {
+            bots: self
+                .bots
+                .iter()
+                .map(|bot| self.health.bot_health(bot.bot_id))
+                .collect(),
+        }
+    }
+
+    /// Gets manager of background tasks, which is put to the context of each update
+    #[must_use]
+    pub const fn tasks(&self) -> &TaskManager {
+        &self.tasks
+    }
+
+    /// Gets introspection of the dispatcher, which is put to the context of each update.
+    /// Check [`Introspection`] for more information.
+    #[must_use]
+    pub const fn introspection(&self) -> &Introspection {
+        &self.introspection
+    }
+
+    /// Runs a tiny HTTP server, which responds to any request with [`Health`] in JSON format.
+    /// Status code of the response is `200 OK` if all bots are healthy and `503 Service Unavailable` otherwise
+    /// (see [`Health::is_healthy`] for more information).
+    /// # Arguments
+    /// * `addr` - Address to bind the server to, for example, `0.0.0.0:8080`
+    /// * `max_polling_lag` -
+    /// Maximum time since the last successful `getUpdates` response, after which the bot is considered unhealthy.
+    /// Usually it should be greater than the polling timeout.
+    /// # Notes
+    /// This method runs forever, so you need to spawn it in a separate task
+    /// # Errors
+    /// If failed to bind the server to the address
+    #[cfg(feature = "health-server")]
+    #[instrument(skip(self, addr))]
+    pub async fn run_health_server(
+        self: Arc<Self>,
+        addr: impl tokio::net::ToSocketAddrs,
+        max_polling_lag: std::time::Duration,
+    ) -> std::io::Result<()> {
+        let listener = tokio::net::TcpListener::bind(addr).await?;
+
+        event!(Level::INFO, addr = ?listener.local_addr(), "Health server is started");
+
+        loop {
+            let (stream, peer_addr) = match listener.accept().await {
+                Ok(val) => val,
+                Err(err) => {
+                    event!(Level::ERROR, error = %err, "Failed to accept health request");
+
+                    continue;
+                }
+            };
+
+            let health = self.health();
+
+            self.runtime.spawn(Box::pin(async move {
+                if let Err(err) = health::server::respond(stream, health, max_polling_lag).await {
+                    event!(Level::ERROR, error = %err, %peer_addr, "Failed to respond to health request");
+                }
+            }));
+        }
+    }
+
+    /// Gets handle to enable and disable named handlers of the main router and its sub routers at runtime.
+    /// Check [`ServiceHandle`] for more information.
+    #[must_use]
+    pub fn handle(&self) -> ServiceHandle
+    where
+        PropagatorService: PropagateEvent<Client>,
+    {
+        self.main_router.handle()
+    }
+
     /// Main entry point for incoming updates.
     /// This method will propagate update to the main router.
     #[instrument(skip(self, bot, update))]
@@ -382,9 +1146,15 @@ impl<Client, PropagatorService, BackoffType> Service<Client, PropagatorService,
 
     /// Main entry point for incoming updates with user context.
     /// This method will propagate update to the main router.
+    /// # Notes
+    /// Update is processed inside the tracing span with standard fields,
+    /// [`UpdateSpan`], [`RequestId`] and [`Introspection`] are put to the context (see [`context module`](crate::context) for more information).
+    /// If the update doesn't pass [update filters](Builder::update_filter), it's dropped and isn't propagated to the main router.
+    /// If the update is waited by a [`Conversation`](crate::fsm::Conversation), it's passed to the conversation and isn't propagated to the main router,
+    /// but it's reported as handled, because the waiting handler handles it.
     #[instrument(
         skip(self, bot, update, context),
-        fields(bot_id, update_id, update_type)
+        fields(bot_id, update_id, update_type, chat_id, user_id, request_id)
     )]
     pub async fn feed_update_with_context(
         self: Arc<Self>,
@@ -398,29 +1168,133 @@ impl<Client, PropagatorService, BackoffType> Service<Client, PropagatorService,
     {
         let update_type = UpdateType::from(update.as_ref());
 
-        Span::current()
-            .record("bot_id", bot.bot_id)
+        let span = Span::current();
+        span.record("bot_id", bot.bot_id)
             .record("update_id", update.id)
             .record("update_type", field::debug(&update_type));
 
-        self.main_router
+        if let Some(chat_id) = update.chat_id() {
+            span.record("chat_id", chat_id);
+        }
+        if let Some(user_id) = update.from_id() {
+            span.record("user_id", user_id);
+        }
+
+        // Request ID can be already set by the user (for example, from the webhook request header)
+        let request_id = context
+            .entry("request_id")
+            .or_insert_with(|| Box::new((self.request_id_generator)()))
+            .downcast_ref::<RequestId>()
+            .cloned();
+        if let Some(request_id) = request_id {
+            span.record("request_id", field::display(&request_id));
+        }
+
+        context.insert("span", Box::new(UpdateSpan(span)));
+        context.insert(
+            "dispatcher_introspection",
+            Box::new(self.introspection.clone()),
+        );
+        self.introspection.update_fed();
+        context.insert("task_manager", Box::new(self.tasks.clone()));
+        #[cfg(feature = "fsm")]
+        context.insert(
+            "conversation_registry",
+            Box::new(Arc::clone(&self.conversations)),
+        );
+
+        if !self.update_filters.iter().all(|filter| filter(&update)) {
+            event!(Level::DEBUG, "Update is dropped by the update filter");
+
+            return Ok(Response {
+                request: Request::new(bot, update, context),
+                propagate_result: PropagateEventResult::Rejected,
+            });
+        }
+
+        // Update is consumed by the conversation, which waits for it inside another handler
+        #[cfg(feature = "fsm")]
+        if self.conversations.dispatch(bot.bot_id, &update) {
+            event!(Level::TRACE, "Update is passed to the waiting conversation");
+
+            // The waiting handler handles the update, so it's reported as handled
+            return Ok(Response {
+                request: Request::new(Arc::clone(&bot), Arc::clone(&update), Arc::clone(&context)),
+                propagate_result: PropagateEventResult::Handled(
+                    crate::event::telegram::HandlerResponse {
+                        request: crate::event::telegram::HandlerRequest::new(bot, update, context),
+                        handler_result: Ok(crate::event::bases::FlowControl::Continue),
+                    },
+                ),
+            });
+        }
+
+        if !self.trace_routing && !self.trace_timings {
+            return self
+                .main_router
+                .propagate_event(update_type, Request::new(bot, update, context))
+                .await;
+        }
+
+        let report = self.trace_routing.then(|| {
+            let report = RoutingReport::new();
+            context.insert("routing_report", Box::new(report.clone()));
+            report
+        });
+        let timings = self.trace_timings.then(|| {
+            let timings = Timings::new();
+            context.insert("timings", Box::new(timings.clone()));
+            timings
+        });
+
+        let response = self
+            .main_router
             .propagate_event(update_type, Request::new(bot, update, context))
-            .await
+            .await?;
+
+        if let Some(report) = report {
+            if let PropagateEventResult::Unhandled = response.propagate_result {
+                event!(Level::DEBUG, report = %report, "Update isn't handled");
+            }
+        }
+        if let Some(timings) = timings {
+            event!(
+                Level::DEBUG,
+                timings = %timings,
+                total = ?timings.total(),
+                "Update is processed",
+            );
+        }
+
+        Ok(response)
     }
 
     /// Start listening updates for the bot.
-    /// [`Update`] is sent to the [`Sender`] channel.
-    /// # Errors
-    /// If sender channel is disconnected
-    #[instrument(skip(bot, polling_timeout, allowed_updates, update_sender, backoff))]
+    /// [`Update`] is pushed to the queue according to the overflow policy.
+    #[instrument(skip(
+        bot,
+        polling_timeout,
+        allowed_updates,
+        queue,
+        backoff,
+        lifecycle,
+        health,
+        runtime
+    ))]
+    #[allow(clippy::too_many_arguments)]
     async fn listen_updates(
         bot: Arc<Bot<Client>>,
         polling_timeout: Option<i64>,
         allowed_updates: Box<[UpdateType]>,
-        update_sender: Sender<Update>,
+        drop_pending_updates: bool,
+        raw_updates: bool,
+        stream_updates: bool,
+        queue: Arc<Queue<Queued<Client>>>,
         mut backoff: BackoffType,
-    ) -> Result<(), ListenerError<Update>>
-    where
+        lifecycle: LifecycleBus,
+        health: Arc<health::State>,
+        runtime: Arc<dyn Runtime>,
+    ) where
         Client: Session,
         BackoffType: Backoff,
     {
@@ -431,8 +1305,32 @@ impl<Client, PropagatorService, BackoffType> Service<Client, PropagatorService,
             .timeout_option(polling_timeout)
             .allowed_updates(allowed_updates.iter().map(AsRef::as_ref));
 
-        // Flag for handling connection errors.
-        // If it's `true`, we will use backoff algorithm to next backoff.
+        if drop_pending_updates {
+            // Negative offset returns only the last update and confirms all previous ones,
+            // so we confirm the last update with the next request
+            match bot
+                .send(&GetUpdates::new().offset(-1).limit(1).timeout(0))
+                .await
+            {
+                Ok(updates) => {
+                    if let Some(Update { id, .. }) = updates.last() {
+                        event!(
+                            Level::DEBUG,
+                            last_update_id = id,
+                            "Pending updates are dropped"
+                        );
+
+                        method.offset = Some(id + 1);
+                    }
+                }
+                Err(err) => {
+                    event!(Level::ERROR, %err, "Failed to drop pending updates");
+                }
+            }
+        }
+
+        // Flag for handling connection errors.
+        // If it's `true`, we will use backoff algorithm to next backoff.
         // If it's `false`, we will use default backoff algorithm.
         let mut failed = false;
 
@@ -442,66 +1340,232 @@ impl<Client, PropagatorService, BackoffType> Service<Client, PropagatorService,
                 "Send `getUpdates` request to the Telegram server",
             );
 
-            let updates = match bot.send(&method).await {
-                Ok(updates) => {
-                    // Get last update id to set offset or skip updates if it's empty
-                    let Some(Update { id, .. }) = updates.last() else {
+            let received = if stream_updates {
+                Self::receive_updates_streamed(&bot, &mut method, raw_updates, &queue, &health)
+                    .await
+            } else {
+                Self::receive_updates(&bot, &mut method, raw_updates, &queue, &health).await
+            };
+
+            match received {
+                Ok(updates_len) => {
+                    health.poll_succeeded(bot.bot_id, updates_len);
+
+                    // If we successfully connected to the server, we will reset backoff config.
+                    // It's checked before the empty response, because the connection is restored even if there are no updates
+                    if failed {
+                        event!(Level::INFO, "Connection established successfully");
+
+                        backoff.reset();
+
+                        lifecycle.emit(LifecycleEvent::ConnectionRestored { bot_id: bot.bot_id });
+
+                        // Reset failed flag, because we successfully connected to the server and don't need to use backoff algorithm
+                        failed = false;
+                    }
+
+                    if updates_len == 0 {
                         event!(Level::TRACE, "No updates received");
 
                         continue;
-                    };
+                    }
 
                     event!(
                         Level::TRACE,
-                        updates_len = updates.len(),
-                        last_update_id = id,
+                        updates_len,
+                        next_offset = method.offset,
                         "Received updates from the Telegram server",
                     );
-
-                    // The `getUpdates` method returns the earliest 100 unconfirmed updates.
-                    // To confirm an update, use the offset parameter when calling `getUpdates`.
-                    // All updates with `update_id` less than or equal to `offset` will be marked.
-                    // as confirmed on the server and will no longer be returned.
-                    // So we need to set offset to the last update `id` + 1
-                    // `unwrap` is safe here, because we checked that updates isn't empty
-                    method.offset = Some(id + 1);
-
-                    updates
                 }
                 Err(err) => {
                     event!(Level::ERROR, %err, "Failed to fetch updates");
 
+                    health.poll_failed(bot.bot_id, &err);
+
                     // If we failed to fetch updates, we will sleep for a while and try again
                     failed = true;
 
-                    if let Some(duration) = backoff.next_backoff() {
+                    let retry_in = backoff.next_backoff();
+
+                    lifecycle.emit(LifecycleEvent::PollingError {
+                        bot_id: bot.bot_id,
+                        error: Arc::new(err),
+                        retry_in,
+                    });
+
+                    if let Some(duration) = retry_in {
                         event!(
                             Level::WARN,
                             "Sleep for {duration:?} seconds and try again..."
                         );
 
-                        tokio::time::sleep(duration).await;
+                        runtime.sleep(duration).await;
                     }
                     continue;
                 }
+            }
+        }
+    }
+
+    /// Sends [`GetUpdates`] request and pushes received updates to the queue after receiving the full response.
+    /// Offset of the method is moved to the next update.
+    /// # Returns
+    /// Number of received updates
+    /// # Errors
+    /// If the request is unsuccessful
+    async fn receive_updates(
+        bot: &Arc<Bot<Client>>,
+        method: &mut GetUpdates,
+        raw_updates: bool,
+        queue: &Queue<Queued<Client>>,
+        health: &health::State,
+    ) -> Result<usize, SessionErrorKind>
+    where
+        Client: Session,
+    {
+        let updates = if raw_updates {
+            bot.send(&GetRawUpdates(method))
+                .await?
+                .into_iter()
+                .map(|(update, raw)| (update, Some(RawUpdate::new(raw))))
+                .collect::<Vec<_>>()
+        } else {
+            bot.send(&*method)
+                .await?
+                .into_iter()
+                .map(|update| (update, None))
+                .collect::<Vec<_>>()
+        };
+
+        // The `getUpdates` method returns the earliest 100 unconfirmed updates.
+        // To confirm an update, use the offset parameter when calling `getUpdates`.
+        // All updates with `update_id` less than or equal to `offset` will be marked.
+        // as confirmed on the server and will no longer be returned.
+        // So we need to set offset to the last update `id` + 1
+        if let Some((Update { id, .. }, _)) = updates.last() {
+            method.offset = Some(id + 1);
+        }
+
+        let updates_len = updates.len();
+        for update in updates {
+            Self::push_update((Arc::clone(bot), update), queue, health).await;
+        }
+
+        Ok(updates_len)
+    }
+
+    /// Sends [`GetUpdates`] request and pushes updates to the queue one by one, while the response is being received
+    /// (see [`Builder::stream_updates`]).
+    /// Offset of the method is moved to the next update after each pushed update,
+    /// so updates, which are pushed before an error, aren't received again.
+    /// # Returns
+    /// Number of received updates
+    /// # Errors
+    /// If the request is unsuccessful or an update can't be parsed
+    async fn receive_updates_streamed(
+        bot: &Arc<Bot<Client>>,
+        method: &mut GetUpdates,
+        raw_updates: bool,
+        queue: &Queue<Queued<Client>>,
+        health: &health::State,
+    ) -> Result<usize, SessionErrorKind>
+    where
+        Client: Session,
+    {
+        let mut updates = bot.send_streamed(&*method).await?;
+        let mut updates_len = 0;
+
+        while let Some(content) = updates.try_next().await? {
+            let update = serde_json::from_slice::<Update>(&content).map_err(|err| {
+                serde_json::from_slice(&content)
+                    .ok()
+                    .and_then(|update| UpdateParseError::from_update(&update))
+                    .map_or(SessionErrorKind::Parse(err), SessionErrorKind::UpdateParse)
+            })?;
+            let raw = if raw_updates {
+                Some(RawUpdate::new(serde_json::from_slice(&content)?))
+            } else {
+                None
             };
 
-            for update in updates {
-                event!(Level::TRACE, "Send update to the listener",);
+            // Offset is moved after each update (see `receive_updates` for more information)
+            method.offset = Some(update.id + 1);
+            updates_len += 1;
 
-                // `Box` is used to avoid stack overflow, because `Update` is a big struct
-                update_sender.send(update).await?;
-            }
+            Self::push_update((Arc::clone(bot), (update, raw)), queue, health).await;
+        }
 
-            // If we successfully connected to the server, we will reset backoff config
-            if failed {
-                event!(Level::INFO, "Connection established successfully");
+        Ok(updates_len)
+    }
 
-                backoff.reset();
+    /// Pushes the update to the queue according to the overflow policy
+    pub(super) async fn push_update(
+        update: Queued<Client>,
+        queue: &Queue<Queued<Client>>,
+        health: &health::State,
+    ) {
+        event!(Level::TRACE, "Send update to the listener");
 
-                // Reset failed flag, because we successfully connected to the server and don't need to use backoff algorithm
-                failed = false;
-            }
+        // Increment queue depth before pushing, because the receiver can dequeue the update before we increment it
+        health.update_enqueued(update.0.bot_id);
+
+        if let Some((bot, (Update { id, .. }, _))) = queue.push(update).await {
+            event!(
+                Level::WARN,
+                bot_id = bot.bot_id,
+                update_id = id,
+                "Update queue is full, update is dropped"
+            );
+
+            health.update_dequeued(bot.bot_id);
+            health.update_dropped(bot.bot_id);
+        }
+    }
+
+    /// Pops updates from the queue and propagates them to the main router in background tasks.
+    /// Number of the tasks is limited by [`Builder::concurrency_limit`], so updates wait in the queue if all workers are busy.
+    pub(super) async fn process_queue(self: Arc<Self>, queue: Arc<Queue<Queued<Client>>>)
+    where
+        Client: Send + Sync + 'static,
+        PropagatorService: PropagateEvent<Client> + 'static,
+        BackoffType: Send + Sync + 'static,
+    {
+        loop {
+            // Permit is acquired before dequeuing, so updates wait in the queue if all workers are busy
+            let permit = match self.concurrency_limit {
+                Some(ref semaphore) => Some(
+                    Arc::clone(semaphore)
+                        .acquire_owned()
+                        .await
+                        .expect("Semaphore is never closed"),
+                ),
+                None => None,
+            };
+
+            let (bot, (update, raw_update)) = queue.pop().await;
+
+            self.health.update_dequeued(bot.bot_id);
+
+            event!(
+                Level::TRACE,
+                update_id = update.id,
+                "Received update from the listener"
+            );
+
+            let dispatcher = Arc::clone(&self);
+
+            self.runtime.spawn(Box::pin(async move {
+                let context = Context::default();
+                if let Some(raw_update) = raw_update {
+                    context.insert("raw_update", Box::new(raw_update));
+                }
+
+                let _ = dispatcher
+                    .feed_update_with_context(bot, Arc::new(update), Arc::new(context))
+                    .await;
+
+                drop(permit);
+            }));
         }
     }
 
@@ -519,85 +1583,93 @@ impl<Client, PropagatorService, BackoffType> Service<Client, PropagatorService,
     {
         let bot = Arc::new(bot);
 
-        let (sender_update, mut receiver_update) = mspc_channel(CHANNEL_UPDATES_SIZE);
+        // Load bot information once, so handlers can get it without sending requests (see `Bot::me` and `Me` extractor)
+        match bot.refresh_me().await {
+            Ok(user) => {
+                event!(Level::DEBUG, username = ?user.username, "Bot information is loaded");
+
+                self.lifecycle.emit(LifecycleEvent::BotInfoLoaded {
+                    bot_id: bot.bot_id,
+                    user: (*user).clone(),
+                });
+            }
+            Err(err) => {
+                event!(Level::ERROR, error = %err, "Failed to load bot information");
+            }
+        }
+
+        if self.register_commands {
+            self.register_bot_commands(&bot).await;
+        }
 
-        let listen_updates_handle = tokio::spawn(Self::listen_updates(
+        let queue = Arc::new(Queue::new(self.queue_capacity, self.overflow_policy));
+
+        let listen_updates_handle = self.runtime.spawn_with_handle(Self::listen_updates(
             Arc::clone(&bot),
             self.polling_timeout,
             self.allowed_updates.clone(),
-            sender_update,
+            self.drop_pending_updates,
+            self.raw_updates,
+            self.stream_updates,
+            Arc::clone(&queue),
             self.backoff.clone(),
+            self.lifecycle.clone(),
+            Arc::clone(&self.health),
+            Arc::clone(&self.runtime),
         ));
 
-        let receiver_updates_handle = tokio::spawn(async move {
-            while let Some(update) = receiver_update.recv().await {
-                event!(
-                    Level::TRACE,
-                    update_id = update.id,
-                    "Received update from the listener"
-                );
-
-                let dispatcher = Arc::clone(&self);
-                let bot = Arc::clone(&bot);
+        self.health.polling_started(bot.bot_id);
 
-                tokio::spawn(dispatcher.feed_update(bot, Arc::new(update)));
-            }
-        });
+        self.lifecycle
+            .emit(LifecycleEvent::PollingStarted { bot_id: bot.bot_id });
 
-        #[cfg(unix)]
-        {
-            use tokio::signal::unix::{signal, SignalKind};
+        #[cfg(all(feature = "tokio-runtime", any(unix, windows)))]
+        let (bot_id, lifecycle, health) =
+            (bot.bot_id, self.lifecycle.clone(), Arc::clone(&self.health));
 
-            let mut sigint =
-                signal(SignalKind::interrupt()).expect("Failed to register SIGINT handler");
-            let mut sigterm =
-                signal(SignalKind::terminate()).expect("Failed to register SIGTERM handler");
+        let runtime = Arc::clone(&self.runtime);
+        let receiver_updates_handle = runtime.spawn_with_handle(self.process_queue(queue));
 
-            tokio::select! {
-                _ = sigint.recv() => {
-                    event!(Level::WARN, "SIGINT signal received");
-                },
-                _ = sigterm.recv() => {
-                    event!(Level::WARN, "SIGTERM signal received");
-                },
-            }
-        }
-        #[cfg(windows)]
+        #[cfg(all(feature = "tokio-runtime", any(unix, windows)))]
         {
-            use tokio::signal::windows::{ctrl_break, ctrl_c};
-
-            let mut ctrl_c = ctrl_c().expect("Failed to register CTRL+C handler");
-            let mut ctrl_break = ctrl_break().expect("Failed to register CTRL+BREAK handler");
+            wait_exit_signal().await;
 
-            tokio::select! {
-                _ = ctrl_c.recv() => {
-                    event!(Level::WARN, "CTRL+C signal received");
-                },
-                _ = ctrl_break.recv() => {
-                    event!(Level::WARN,  "CTRL+BREAK signal received");
-                },
-            }
-        }
+            // Dropping the handles cancels the tasks
+            drop(listen_updates_handle);
+            drop(receiver_updates_handle);
 
-        #[cfg(any(unix, windows))]
-        {
-            listen_updates_handle.abort();
-            receiver_updates_handle.abort();
+            health.polling_stopped(bot_id);
+            lifecycle.emit(LifecycleEvent::PollingStopped { bot_id });
 
             PollingError::Aborted
         }
-        #[cfg(not(any(unix, windows)))]
+        #[cfg(not(all(feature = "tokio-runtime", any(unix, windows))))]
         {
             event!(
                 Level::WARN,
-                "Exit signals of this platform are not supported, \
+                "Exit signals of this platform or runtime are not supported, \
                 so polling process will never stop by signal and shutdown events will never be emitted.",
             );
 
             listen_updates_handle.await;
             receiver_updates_handle.await;
 
-            unimplemented!("Exit signals of this platform are not supported");
+            unimplemented!("Exit signals of this platform or runtime are not supported");
+        }
+    }
+
+    /// Registers bot commands from the main router in the bot command menu.
+    /// Errors are logged and don't stop polling.
+    #[instrument(skip(self, bot))]
+    async fn register_bot_commands(&self, bot: &Bot<Client>)
+    where
+        Client: Session,
+        PropagatorService: PropagateEvent<Client>,
+    {
+        for method in self.main_router.bot_commands().set_my_commands() {
+            if let Err(err) = bot.send(&method).await {
+                event!(Level::ERROR, error = %err, scope = ?method.scope, "Failed to register bot commands");
+            }
         }
     }
 
@@ -617,7 +1689,7 @@ impl<Client, PropagatorService, BackoffType> Service<Client, PropagatorService,
     {
         event!(Level::TRACE, "Start emit startup observers");
 
-        if let Err(err) = self.main_router.emit_startup().await {
+        if let Err(err) = self.emit_startup().await {
             event!(Level::ERROR, error = %err, "Error while emit startup");
 
             return Err(err.into());
@@ -659,13 +1731,11 @@ impl<Client, PropagatorService, BackoffType> Service<Client, PropagatorService,
 
             event!(Level::INFO, bot = %bot, "Polling is started for bot");
 
-            handles.push(tokio::spawn(dispatcher.polling(bot)));
+            handles.push(self.runtime.spawn_with_handle(dispatcher.polling(bot)));
         }
 
         for handle in handles {
-            if let Err(err) = handle.await {
-                event!(Level::ERROR, error = %err);
-            }
+            handle.await;
         }
 
         if bots_len == 1 {
@@ -675,11 +1745,92 @@ impl<Client, PropagatorService, BackoffType> Service<Client, PropagatorService,
         }
     }
 
+    /// Standalone webhook server runner for multiple bots and emit startup and shutdown observers.
+    /// Webhooks of the bots are set when the server is started, and the server runs until exit signal is received
+    /// (see [`webhook module`](webhook) for more information).
+    /// # Errors
+    /// - If any startup observer returns error
+    /// - If any shutdown observer returns error
+    /// - If the webhook URL or the certificate is invalid
+    /// - If failed to set webhook or bind the server to the address
+    /// # Panics
+    /// If failed to register exit signal handlers
+    #[cfg(feature = "webhook-server")]
+    #[instrument(skip(self, config))]
+    pub async fn run_webhook(self: Arc<Self>, config: webhook::Config) -> Result<(), webhook::Error>
+    where
+        Client: Session + Clone + 'static,
+        PropagatorService: PropagateEvent<Client> + 'static,
+        BackoffType: Send + Sync + 'static,
+    {
+        event!(Level::TRACE, "Start emit startup observers");
+
+        if let Err(err) = self.emit_startup().await {
+            event!(Level::ERROR, error = %err, "Error while emit startup");
+
+            return Err(EventErrorKind::from(err).into());
+        }
+
+        webhook::run(Arc::clone(&self), config, None).await?;
+
+        event!(Level::TRACE, "Start emit shutdown observers");
+
+        self.emit_shutdown().await.map_err(|err| {
+            event!(Level::ERROR, error = %err, "Error while emit shutdown");
+
+            EventErrorKind::from(err).into()
+        })
+    }
+
+    /// Standalone webhook server runner for multiple bots, which are the bots of the dispatcher and the bots resolved by the resolver,
+    /// and emit startup and shutdown observers.
+    /// Only webhooks of the dispatcher bots are set when the server is started
+    /// (see [`webhook module`](webhook) for more information).
+    /// # Errors
+    /// - If any startup observer returns error
+    /// - If any shutdown observer returns error
+    /// - If the webhook URL or the certificate is invalid
+    /// - If failed to set webhook or bind the server to the address
+    /// # Panics
+    /// If failed to register exit signal handlers
+    #[cfg(feature = "webhook-server")]
+    #[instrument(skip(self, config, resolver))]
+    pub async fn run_webhook_with_resolver<R>(
+        self: Arc<Self>,
+        config: webhook::Config,
+        resolver: R,
+    ) -> Result<(), webhook::Error>
+    where
+        Client: Session + Clone + 'static,
+        PropagatorService: PropagateEvent<Client> + 'static,
+        BackoffType: Send + Sync + 'static,
+        R: webhook::TokenResolver<Client> + 'static,
+    {
+        event!(Level::TRACE, "Start emit startup observers");
+
+        if let Err(err) = self.emit_startup().await {
+            event!(Level::ERROR, error = %err, "Error while emit startup");
+
+            return Err(EventErrorKind::from(err).into());
+        }
+
+        webhook::run(Arc::clone(&self), config, Some(Arc::new(resolver))).await?;
+
+        event!(Level::TRACE, "Start emit shutdown observers");
+
+        self.emit_shutdown().await.map_err(|err| {
+            event!(Level::ERROR, error = %err, "Error while emit shutdown");
+
+            EventErrorKind::from(err).into()
+        })
+    }
+
     /// Emit startup events.
     /// Use this method if you want to emit startup events manually
     /// # Notes
     /// This method is called automatically in `run_polling` method,
-    /// but not in `run_polling_without_startup_and_shutdown` method
+    /// but not in `run_polling_without_startup_and_shutdown` method.
+    /// [`LifecycleEvent::Startup`] is emitted to the lifecycle bus only if all startup observers are successful
     /// # Errors
     /// If any startup observer returns error
     #[instrument(skip(self))]
@@ -687,14 +1838,21 @@ impl<Client, PropagatorService, BackoffType> Service<Client, PropagatorService,
     where
         PropagatorService: PropagateEvent<Client>,
     {
-        self.main_router.emit_startup().await
+        self.main_router.emit_startup().await?;
+
+        self.lifecycle.emit(LifecycleEvent::Startup);
+
+        Ok(())
     }
 
     /// Emit shutdown events.
     /// Use this method if you want to emit shutdown events manually
     /// # Notes
     /// This method is called automatically in `run_polling` method,
-    /// but not in `run_polling_without_startup_and_shutdown` method
+    /// but not in `run_polling_without_startup_and_shutdown` method.
+    /// Before shutdown observers are called, [`TaskManager`] is closed and its background tasks are waited
+    /// (see [`Builder::task_shutdown_timeout`]).
+    /// [`LifecycleEvent::Shutdown`] is emitted to the lifecycle bus only if all shutdown observers are successful
     /// # Errors
     /// If any shutdown observer returns error
     #[instrument(skip(self))]
@@ -702,7 +1860,13 @@ impl<Client, PropagatorService, BackoffType> Service<Client, PropagatorService,
     where
         PropagatorService: PropagateEvent<Client>,
     {
-        self.main_router.emit_shutdown().await
+        self.tasks.shutdown(self.task_shutdown_timeout).await;
+
+        self.main_router.emit_shutdown().await?;
+
+        self.lifecycle.emit(LifecycleEvent::Shutdown);
+
+        Ok(())
     }
 }
 
@@ -710,11 +1874,14 @@ impl<Client, PropagatorService, BackoffType> Service<Client, PropagatorService,
 mod tests {
     use super::*;
     use crate::{
-        client::Reqwest,
-        event::bases::{EventReturn, PropagateEventResult},
+        client::{session::ClientResponse, telegram::APIServer, Reqwest},
+        errors::{HandlerError, TelegramErrorKind},
+        event::bases::{FlowControl, PropagateEventResult},
+        filters::Command,
         router::Router,
     };
 
+    use async_trait::async_trait;
     use tokio;
 
     #[tokio::test]
@@ -743,7 +1910,7 @@ mod tests {
         let mut router = Router::new("main");
         router
             .message
-            .register(|| async { Ok(EventReturn::Finish) });
+            .register(|| async { Ok(FlowControl::Continue) });
 
         let dispatcher = Dispatcher::builder()
             .main_router(router)
@@ -763,6 +1930,217 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_tasks() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let bot = Arc::new(Bot::<Reqwest>::default());
+        let completed = Arc::new(AtomicBool::new(false));
+
+        let mut router = Router::new("main");
+        router.message.register({
+            let completed = Arc::clone(&completed);
+
+            move |tasks: TaskManager| {
+                let completed = Arc::clone(&completed);
+
+                async move {
+                    tasks
+                        .spawn(async move {
+                            tokio::time::sleep(Duration::from_millis(10)).await;
+                            completed.store(true, Ordering::SeqCst);
+                        })
+                        .map_err(HandlerError::new)?;
+
+                    Ok(FlowControl::Continue)
+                }
+            }
+        });
+
+        let dispatcher = Dispatcher::builder()
+            .main_router(router)
+            .task_limit(1)
+            .build()
+            .to_service_provider_default()
+            .unwrap();
+
+        Arc::clone(&dispatcher)
+            .feed_update(bot, Arc::new(Update::default()))
+            .await
+            .unwrap();
+        assert_eq!(dispatcher.tasks().active(), 1);
+
+        dispatcher.emit_shutdown().await.unwrap();
+        assert!(completed.load(Ordering::SeqCst));
+        assert!(dispatcher.tasks().is_closed());
+    }
+
+    #[tokio::test]
+    async fn test_update_filter() {
+        use crate::types::{Message, MessageText, UpdateKind};
+
+        let bot = Arc::new(Bot::<Reqwest>::default());
+        let update = |date| {
+            Arc::new(Update {
+                kind: UpdateKind::Message(Message::Text(Box::new(MessageText {
+                    date,
+                    ..Default::default()
+                }))),
+                ..Default::default()
+            })
+        };
+        let now = i64::try_from(
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+        )
+        .unwrap();
+
+        let mut router = Router::new("main");
+        router
+            .message
+            .register(|| async { Ok(FlowControl::Continue) });
+
+        let dispatcher = Dispatcher::builder()
+            .main_router(router)
+            .update_filter(max_update_age(Duration::from_secs(60)))
+            .build()
+            .to_service_provider_default()
+            .unwrap();
+
+        let response = Arc::clone(&dispatcher)
+            .feed_update(Arc::clone(&bot), update(now - 120))
+            .await
+            .unwrap();
+
+        // Event should be dropped, because it's too old
+        match response.propagate_result {
+            PropagateEventResult::Rejected => {}
+            _ => panic!("Unexpected result"),
+        }
+
+        let response = dispatcher.feed_update(bot, update(now)).await.unwrap();
+
+        match response.propagate_result {
+            PropagateEventResult::Handled(_) => {}
+            _ => panic!("Unexpected result"),
+        }
+    }
+
+    #[cfg(feature = "fsm")]
+    #[tokio::test]
+    async fn test_feed_update_conversation() {
+        use crate::types::{Message, MessageText, UpdateKind, User};
+
+        let bot = Arc::new(Bot::<Reqwest>::default());
+        let update = Arc::new(Update {
+            id: 1,
+            kind: UpdateKind::Message(Message::Text(Box::new(MessageText {
+                from: Some(User {
+                    id: 1,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }))),
+        });
+
+        let dispatcher = Dispatcher::builder()
+            .main_router(Router::new("main"))
+            .build()
+            .to_service_provider_default()
+            .unwrap();
+
+        let registry = dispatcher.conversations();
+        let receiver = registry.register(registry.key(bot.bot_id, &update).unwrap(), |_| true);
+
+        let response = Arc::clone(&dispatcher)
+            .feed_update(bot, Arc::clone(&update))
+            .await
+            .unwrap();
+
+        // Update is handled by the waiting conversation
+        match response.propagate_result {
+            PropagateEventResult::Handled(_) => {}
+            _ => panic!("Unexpected result"),
+        }
+        assert!(Arc::ptr_eq(&receiver.await.unwrap(), &update));
+    }
+
+    #[tokio::test]
+    async fn test_feed_update_request_id_and_span() {
+        let bot = Arc::new(Bot::<Reqwest>::default());
+        let update = Arc::new(Update::default());
+
+        let dispatcher = Dispatcher::builder()
+            .main_router(Router::new("main"))
+            .request_id_generator(|| RequestId::new("generated"))
+            .build()
+            .to_service_provider_default()
+            .unwrap();
+
+        let context = Arc::new(Context::default());
+        Arc::clone(&dispatcher)
+            .feed_update_with_context(Arc::clone(&bot), Arc::clone(&update), Arc::clone(&context))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            context
+                .get("request_id")
+                .unwrap()
+                .downcast_ref::<RequestId>()
+                .unwrap(),
+            &RequestId::new("generated")
+        );
+        assert!(context.get("span").unwrap().is::<UpdateSpan>());
+
+        // Request ID passed by the user isn't replaced
+        let context = Arc::new(Context::default());
+        context.insert("request_id", Box::new(RequestId::new("custom")));
+
+        dispatcher
+            .feed_update_with_context(bot, update, Arc::clone(&context))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            context
+                .get("request_id")
+                .unwrap()
+                .downcast_ref::<RequestId>()
+                .unwrap(),
+            &RequestId::new("custom")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_lifecycle_startup_and_shutdown() {
+        let bus = LifecycleBus::default();
+        let mut receiver = bus.subscribe();
+
+        let dispatcher = Dispatcher::<Reqwest, _>::builder()
+            .main_router(Router::new("main"))
+            .lifecycle_bus(bus)
+            .build()
+            .to_service_provider_default()
+            .unwrap();
+
+        assert_eq!(dispatcher.lifecycle().subscribers_count(), 1);
+
+        dispatcher.emit_startup().await.unwrap();
+        dispatcher.emit_shutdown().await.unwrap();
+
+        match receiver.recv().await.unwrap() {
+            LifecycleEvent::Startup => {}
+            _ => panic!("Unexpected event"),
+        }
+        match receiver.recv().await.unwrap() {
+            LifecycleEvent::Shutdown => {}
+            _ => panic!("Unexpected event"),
+        }
+    }
+
     #[test]
     fn test_builder() {
         let bot = Bot::<Reqwest>::default();
@@ -780,4 +2158,212 @@ mod tests {
         assert_eq!(dispatcher.polling_timeout, Some(123));
         assert_eq!(dispatcher.allowed_updates.len(), 3);
     }
+
+    #[test]
+    fn test_config_validation() {
+        let bot = Bot::<Reqwest>::default();
+
+        // Main router without handlers is allowed without bots
+        assert!(Dispatcher::builder()
+            .main_router(Router::<Reqwest>::new("main"))
+            .build()
+            .to_service_provider_default()
+            .is_ok());
+
+        let mut router = Router::new("main");
+        router
+            .message
+            .register(|| async { Ok(FlowControl::Continue) })
+            .filter(Command::one("start"));
+        router
+            .message
+            .register(|| async { Ok(FlowControl::Continue) })
+            .filter(Command::one("start"));
+        router
+            .chat_member
+            .register(|| async { Ok(FlowControl::Continue) });
+
+        let Err(InitError::Config(errors)) = Dispatcher::builder()
+            .main_router(router)
+            .bot(bot.clone())
+            .allowed_updates_policy(AllowedUpdatesPolicy::Strict)
+            .build()
+            .to_service_provider_default()
+        else {
+            panic!("Configuration should be invalid");
+        };
+
+        assert!(matches!(
+            errors.errors(),
+            [
+                ConfigError::OverlappingCommands { .. },
+                ConfigError::AllowedUpdates { .. },
+            ]
+        ));
+
+        let Err(InitError::Config(errors)) = Dispatcher::builder()
+            .main_router(Router::new("main"))
+            .bot(bot)
+            .build()
+            .to_service_provider_default()
+        else {
+            panic!("Configuration should be invalid");
+        };
+
+        assert_eq!(errors.errors(), [ConfigError::NoHandlers]);
+
+        let Err(InitError::Config(errors)) = Dispatcher::builder()
+            .main_router(Router::<Reqwest>::new("main"))
+            .queue_capacity(0)
+            .concurrency_limit(0)
+            .task_limit(0)
+            .build()
+            .to_service_provider_default()
+        else {
+            panic!("Configuration should be invalid");
+        };
+
+        assert_eq!(
+            errors.errors(),
+            [
+                ConfigError::ZeroLimit {
+                    option: "queue_capacity"
+                },
+                ConfigError::ZeroLimit {
+                    option: "concurrency_limit"
+                },
+                ConfigError::ZeroLimit {
+                    option: "task_limit"
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_raw_updates() {
+        let method = GetUpdates::new();
+        let response = GetRawUpdates(&method)
+            .build_response(r#"{"ok": true, "result": [{"update_id": 1, "poll_answer": {"poll_id": "1", "option_ids": [0], "new_field": 1}}]}"#)
+            .unwrap();
+
+        let (update, raw) = &response.result.unwrap()[0];
+        assert_eq!(update.id, 1);
+        assert_eq!(raw["poll_answer"]["new_field"], 1);
+
+        let response = GetRawUpdates(&method)
+            .build_response(r#"{"ok": false, "error_code": 409, "description": "Conflict"}"#)
+            .unwrap();
+        assert!(!response.ok);
+        assert!(response.result.is_none());
+    }
+
+    /// Session, which returns the same response to all requests
+    struct Responder {
+        api: APIServer,
+        status_code: u16,
+        content: &'static str,
+    }
+
+    impl Responder {
+        fn new(status_code: u16, content: &'static str) -> Self {
+            Self {
+                api: APIServer::default(),
+                status_code,
+                content,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Session for Responder {
+        fn api(&self) -> &APIServer {
+            &self.api
+        }
+
+        async fn send_request<Client, T>(
+            &self,
+            _bot: &Bot<Client>,
+            _method: &T,
+            _timeout: Option<Duration>,
+        ) -> Result<ClientResponse, anyhow::Error>
+        where
+            Client: Session,
+            T: TelegramMethod + Send + Sync,
+            T::Method: Send + Sync,
+        {
+            Ok(ClientResponse::new(self.status_code, self.content))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_receive_updates_streamed() {
+        type TestService = Service<Responder, (), ()>;
+
+        let queue = Queue::new(DEFAULT_QUEUE_CAPACITY, OverflowPolicy::default());
+        let health = health::State::default();
+
+        let bot = Arc::new(Bot::with_client(
+            "1234567890:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+            Responder::new(
+                200,
+                r#"{"ok": true, "result": [{"update_id": 1, "poll_answer": {"poll_id": "1", "option_ids": [0], "new_field": 1}}, {"update_id": 2, "poll_answer": {"poll_id": "1", "option_ids": [1]}}]}"#,
+            ),
+        ));
+        let mut method = GetUpdates::new();
+
+        for stream_updates in [true, false] {
+            method.offset = None;
+
+            let updates_len = if stream_updates {
+                TestService::receive_updates_streamed(&bot, &mut method, true, &queue, &health)
+                    .await
+            } else {
+                TestService::receive_updates(&bot, &mut method, true, &queue, &health).await
+            }
+            .unwrap();
+
+            assert_eq!(updates_len, 2);
+            assert_eq!(method.offset, Some(3));
+
+            let (_, (update, raw)) = queue.pop().await;
+            assert_eq!(update.id, 1);
+            assert_eq!(raw.unwrap().0["poll_answer"]["new_field"], 1);
+            let (_, (update, _)) = queue.pop().await;
+            assert_eq!(update.id, 2);
+        }
+
+        // Updates before the broken one are pushed and confirmed
+        let bot = Arc::new(Bot::with_client(
+            "1234567890:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+            Responder::new(
+                200,
+                r#"{"ok": true, "result": [{"update_id": 1, "poll_answer": {"poll_id": "1", "option_ids": [0]}}, {"update_id": 2, "message": {"message_id": "broken"}}]}"#,
+            ),
+        ));
+        method.offset = None;
+
+        let err = TestService::receive_updates_streamed(&bot, &mut method, false, &queue, &health)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SessionErrorKind::UpdateParse(err) if err.update_id == Some(2)));
+        assert_eq!(method.offset, Some(2));
+        let (_, (update, _)) = queue.pop().await;
+        assert_eq!(update.id, 1);
+
+        let bot = Arc::new(Bot::with_client(
+            "1234567890:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+            Responder::new(
+                409,
+                r#"{"ok": false, "error_code": 409, "description": "Conflict"}"#,
+            ),
+        ));
+
+        let err = TestService::receive_updates_streamed(&bot, &mut method, false, &queue, &health)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            SessionErrorKind::Telegram(TelegramErrorKind::ConflictError { .. })
+        ));
+    }
 }
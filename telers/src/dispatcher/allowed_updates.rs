@@ -0,0 +1,178 @@
+//! This module contains [`AllowedUpdatesPolicy`], which is used when configured allowed updates of the dispatcher
+//! (see [`Builder::allowed_updates`]) don't contain update types, which are handled by the main router
+//! (see [`Router::resolve_used_update_types`]).
+//!
+//! Telegram doesn't send updates of types, which aren't allowed, so handlers of these types are never called.
+//! The check is done when the dispatcher is converted to the service with [`ToServiceProvider::to_service_provider`],
+//! so misconfiguration is found on startup instead of silently unreceived updates.
+//!
+//! If allowed updates aren't configured, Telegram sends all update types except [`UpdateType::ChatMember`],
+//! [`UpdateType::MessageReaction`] and [`UpdateType::MessageReactionCount`] (see [`UpdateType::is_received_by_default`]),
+//! so handlers of these types are checked as well.
+//!
+//! [`Builder::allowed_updates`]: crate::dispatcher::Builder#method.allowed_updates
+//! [`Router::resolve_used_update_types`]: crate::router::Router#method.resolve_used_update_types
+//! [`ToServiceProvider::to_service_provider`]: crate::event::service::ToServiceProvider::to_service_provider
+
+use crate::{enums::UpdateType, errors::ConfigError};
+
+use std::collections::HashSet;
+use tracing::{event, Level};
+
+/// Policy, which is used when configured allowed updates don't contain update types, which are handled by the main router
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AllowedUpdatesPolicy {
+    /// Log a warning with missing update types and use configured allowed updates as is
+    #[default]
+    Warn,
+    /// Add missing update types to the allowed updates and log it
+    Sync,
+    /// Return [`ConfigError::AllowedUpdates`] error with missing update types, so the misconfiguration is found on startup
+    Strict,
+    /// Don't check allowed updates
+    Ignore,
+}
+
+/// Get update types, which are handled by the main router, but aren't received with the allowed updates
+#[must_use]
+pub(super) fn missing(
+    allowed_updates: &[UpdateType],
+    used_update_types: &HashSet<UpdateType>,
+) -> Vec<UpdateType> {
+    let mut missing = used_update_types
+        .iter()
+        .copied()
+        .filter(|update_type| {
+            if allowed_updates.is_empty() {
+                !update_type.is_received_by_default()
+            } else {
+                !allowed_updates.contains(update_type)
+            }
+        })
+        .collect::<Vec<_>>();
+    // Sort for stable output, because the set is unordered
+    missing.sort_by_key(|update_type| update_type.as_ref().to_owned());
+    missing
+}
+
+/// Check the allowed updates according to the policy
+/// # Arguments
+/// * `used_update_types` - Update types, which are handled by the main router, or `None` if they are unknown
+/// # Returns
+/// Allowed updates, which should be used to receive updates
+/// # Errors
+/// If the policy is [`AllowedUpdatesPolicy::Strict`] and some used update types aren't allowed
+pub(super) fn check(
+    allowed_updates: Box<[UpdateType]>,
+    used_update_types: Option<&HashSet<UpdateType>>,
+    policy: AllowedUpdatesPolicy,
+) -> Result<Box<[UpdateType]>, ConfigError> {
+    let Some(used_update_types) = used_update_types else {
+        return Ok(allowed_updates);
+    };
+    if policy == AllowedUpdatesPolicy::Ignore {
+        return Ok(allowed_updates);
+    }
+
+    let missing = missing(&allowed_updates, used_update_types);
+    if missing.is_empty() {
+        return Ok(allowed_updates);
+    }
+
+    match policy {
+        AllowedUpdatesPolicy::Warn => {
+            event!(
+                Level::WARN,
+                ?missing,
+                ?allowed_updates,
+                "Main router handles update types, which aren't allowed, so these handlers are never called. \
+                 Add them to allowed updates or use `AllowedUpdatesPolicy::Sync`",
+            );
+
+            Ok(allowed_updates)
+        }
+        AllowedUpdatesPolicy::Sync => {
+            let allowed_updates = if allowed_updates.is_empty() {
+                UpdateType::all()
+                    .into_iter()
+                    .filter(UpdateType::is_received_by_default)
+                    .chain(missing.iter().copied())
+                    .collect()
+            } else {
+                allowed_updates
+                    .iter()
+                    .copied()
+                    .chain(missing.iter().copied())
+                    .collect::<Box<_>>()
+            };
+
+            event!(
+                Level::INFO,
+                ?missing,
+                ?allowed_updates,
+                "Update types, which are handled by the main router, are added to allowed updates",
+            );
+
+            Ok(allowed_updates)
+        }
+        AllowedUpdatesPolicy::Strict => Err(ConfigError::AllowedUpdates {
+            missing: missing.into(),
+            allowed: allowed_updates,
+        }),
+        AllowedUpdatesPolicy::Ignore => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing() {
+        let used = HashSet::from([UpdateType::Message, UpdateType::ChatMember]);
+
+        assert_eq!(missing(&[], &used), [UpdateType::ChatMember]);
+        assert_eq!(
+            missing(&[UpdateType::CallbackQuery], &used),
+            [UpdateType::ChatMember, UpdateType::Message]
+        );
+        assert!(missing(&[UpdateType::Message, UpdateType::ChatMember], &used).is_empty());
+    }
+
+    #[test]
+    fn test_check() {
+        let used = HashSet::from([UpdateType::Message, UpdateType::ChatMember]);
+        let allowed: Box<[UpdateType]> = Box::new([UpdateType::Message]);
+
+        assert_eq!(
+            &*check(allowed.clone(), Some(&used), AllowedUpdatesPolicy::Warn).unwrap(),
+            [UpdateType::Message]
+        );
+        assert_eq!(
+            &*check(allowed.clone(), Some(&used), AllowedUpdatesPolicy::Sync).unwrap(),
+            [UpdateType::Message, UpdateType::ChatMember]
+        );
+        assert_eq!(
+            &*check(allowed.clone(), None, AllowedUpdatesPolicy::Strict).unwrap(),
+            [UpdateType::Message]
+        );
+
+        let synced = check(Box::new([]), Some(&used), AllowedUpdatesPolicy::Sync).unwrap();
+        assert!(synced.contains(&UpdateType::ChatMember));
+        assert!(synced.contains(&UpdateType::CallbackQuery));
+        assert!(!synced.contains(&UpdateType::MessageReaction));
+    }
+
+    #[test]
+    fn test_check_strict() {
+        let used = HashSet::from([UpdateType::ChatMember]);
+
+        assert_eq!(
+            check(Box::new([]), Some(&used), AllowedUpdatesPolicy::Strict),
+            Err(ConfigError::AllowedUpdates {
+                missing: Box::new([UpdateType::ChatMember]),
+                allowed: Box::new([]),
+            })
+        );
+    }
+}
@@ -0,0 +1,311 @@
+//! This module contains [`Health`] structure, which represents the current status of the dispatcher polling processes.
+//!
+//! Health status is collected by the dispatcher automatically during polling and can be received with [`Dispatcher::health`] method.
+//! It contains information for each bot: is polling running, when the last update was received,
+//! polling lag (time since the last successful `getUpdates` response), number of updates in the queue,
+//! number of dropped updates and the last API error.
+//!
+//! If `health-server` feature is enabled, you can also run a tiny HTTP server with [`Dispatcher::run_health_server`] method,
+//! which responds to any request with the health status in JSON format,
+//! so orchestrators (Docker, Kubernetes, etc.) can probe bot liveness.
+//!
+//! [`Dispatcher::health`]: crate::dispatcher::Service#method.health
+//! [`Dispatcher::run_health_server`]: crate::dispatcher::Service#method.run_health_server
+
+use dashmap::DashMap;
+use serde::{Serialize, Serializer};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Health status of the polling process of the bot
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BotHealth {
+    pub bot_id: i64,
+    /// `true` if polling process is running for the bot
+    pub is_polling: bool,
+    /// Time when the last update was received
+    #[serde(serialize_with = "serialize_time")]
+    pub last_update_at: Option<SystemTime>,
+    /// Time when the last successful `getUpdates` response was received
+    #[serde(serialize_with = "serialize_time")]
+    pub last_poll_at: Option<SystemTime>,
+    /// Time since the last successful `getUpdates` response (or since polling is started, if there were no successful responses).
+    /// Long polling responds at least once per polling timeout, so big lag means that polling is stuck or the server is unavailable.
+    #[serde(serialize_with = "serialize_duration")]
+    pub polling_lag: Option<Duration>,
+    /// Number of received updates, which wait to be propagated to the main router
+    pub queue_depth: usize,
+    /// Number of updates, which are dropped, because the queue was full (see [`OverflowPolicy`])
+    ///
+    /// [`OverflowPolicy`]: crate::dispatcher::OverflowPolicy
+    pub dropped_updates: u64,
+    /// The last error of the `getUpdates` request
+    pub last_error: Option<Box<str>>,
+    /// Time when the last error of the `getUpdates` request was occurred
+    #[serde(serialize_with = "serialize_time")]
+    pub last_error_at: Option<SystemTime>,
+}
+
+impl BotHealth {
+    /// Creates a new [`BotHealth`] for the bot, whose polling process isn't started
+    #[must_use]
+    pub const fn new(bot_id: i64) -> Self {
+        Self {
+            bot_id,
+            is_polling: false,
+            last_update_at: None,
+            last_poll_at: None,
+            polling_lag: None,
+            queue_depth: 0,
+            dropped_updates: 0,
+            last_error: None,
+            last_error_at: None,
+        }
+    }
+
+    /// Checks that polling process is running and its lag isn't greater than `max_polling_lag`
+    #[must_use]
+    pub fn is_healthy(&self, max_polling_lag: Duration) -> bool {
+        self.is_polling
+            && self
+                .polling_lag
+                .map_or(true, |polling_lag| polling_lag <= max_polling_lag)
+    }
+}
+
+/// Health status of the dispatcher
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Health {
+    /// Health status for each bot of the dispatcher
+    pub bots: Box<[BotHealth]>,
+}
+
+impl Health {
+    /// Checks that all bots are healthy
+    /// # Notes
+    /// See [`BotHealth::is_healthy`] for more information
+    #[must_use]
+    pub fn is_healthy(&self, max_polling_lag: Duration) -> bool {
+        !self.bots.is_empty() && self.bots.iter().all(|bot| bot.is_healthy(max_polling_lag))
+    }
+}
+
+#[derive(Debug, Default)]
+struct BotState {
+    polling_started_at: Option<SystemTime>,
+    last_update_at: Option<SystemTime>,
+    last_poll_at: Option<SystemTime>,
+    last_error: Option<Box<str>>,
+    last_error_at: Option<SystemTime>,
+    dropped_updates: u64,
+}
+
+/// Collector of the health status, which is updated by the polling processes
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    bots: DashMap<i64, (Mutex<BotState>, AtomicUsize)>,
+}
+
+impl State {
+    fn update(&self, bot_id: i64, f: impl FnOnce(&mut BotState)) {
+        let entry = self.bots.entry(bot_id).or_default();
+        let mut state = entry.0.lock().unwrap();
+
+        f(&mut state);
+    }
+
+    pub(super) fn polling_started(&self, bot_id: i64) {
+        self.update(bot_id, |state| {
+            state.polling_started_at = Some(SystemTime::now());
+        });
+    }
+
+    #[cfg_attr(
+        not(all(feature = "tokio-runtime", any(unix, windows))),
+        allow(dead_code)
+    )]
+    pub(super) fn polling_stopped(&self, bot_id: i64) {
+        self.update(bot_id, |state| state.polling_started_at = None);
+    }
+
+    pub(super) fn poll_succeeded(&self, bot_id: i64, updates_len: usize) {
+        let now = SystemTime::now();
+
+        self.update(bot_id, |state| {
+            state.last_poll_at = Some(now);
+
+            if updates_len > 0 {
+                state.last_update_at = Some(now);
+            }
+        });
+    }
+
+    pub(super) fn poll_failed(&self, bot_id: i64, error: impl ToString) {
+        self.update(bot_id, |state| {
+            state.last_error = Some(error.to_string().into());
+            state.last_error_at = Some(SystemTime::now());
+        });
+    }
+
+    pub(super) fn update_enqueued(&self, bot_id: i64) {
+        self.bots
+            .entry(bot_id)
+            .or_default()
+            .1
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(super) fn update_dequeued(&self, bot_id: i64) {
+        if let Some(entry) = self.bots.get(&bot_id) {
+            // Saturating decrement, the value can't be less than zero
+            let _ = entry
+                .1
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |val| {
+                    val.checked_sub(1)
+                });
+        }
+    }
+
+    pub(super) fn update_dropped(&self, bot_id: i64) {
+        self.update(bot_id, |state| state.dropped_updates += 1);
+    }
+
+    pub(super) fn bot_health(&self, bot_id: i64) -> BotHealth {
+        let Some(entry) = self.bots.get(&bot_id) else {
+            return BotHealth::new(bot_id);
+        };
+
+        let state = entry.0.lock().unwrap();
+        let now = SystemTime::now();
+
+        BotHealth {
+            bot_id,
+            is_polling: state.polling_started_at.is_some(),
+            last_update_at: state.last_update_at,
+            last_poll_at: state.last_poll_at,
+            polling_lag: state.polling_started_at.map(|polling_started_at| {
+                let since = state
+                    .last_poll_at
+                    .map_or(polling_started_at, |last_poll_at| {
+                        last_poll_at.max(polling_started_at)
+                    });
+
+                now.duration_since(since).unwrap_or_default()
+            }),
+            queue_depth: entry.1.load(Ordering::Relaxed),
+            dropped_updates: state.dropped_updates,
+            last_error: state.last_error.clone(),
+            last_error_at: state.last_error_at,
+        }
+    }
+}
+
+#[allow(clippy::ref_option)]
+fn serialize_time<S>(time: &Option<SystemTime>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    time.map(|time| {
+        time.duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs()
+    })
+    .serialize(serializer)
+}
+
+#[allow(clippy::ref_option)]
+fn serialize_duration<S>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    duration
+        .map(|duration| duration.as_secs_f64())
+        .serialize(serializer)
+}
+
+#[cfg(feature = "health-server")]
+pub(super) mod server {
+    use super::Health;
+
+    use std::{io, time::Duration};
+    use tokio::{
+        io::{AsyncReadExt as _, AsyncWriteExt as _},
+        net::TcpStream,
+    };
+
+    /// Reads HTTP request (it's ignored, because server responds the same to any request) and writes health status as response
+    pub(in crate::dispatcher) async fn respond(
+        mut stream: TcpStream,
+        health: Health,
+        max_polling_lag: Duration,
+    ) -> io::Result<()> {
+        let mut buf = [0; 1024];
+        let _ = stream.read(&mut buf).await?;
+
+        let status = if health.is_healthy(max_polling_lag) {
+            "200 OK"
+        } else {
+            "503 Service Unavailable"
+        };
+        let body = serde_json::to_string(&health).map_err(io::Error::from)?;
+
+        let response = format!(
+            "HTTP/1.1 {status}\r\n\
+            Content-Type: application/json\r\n\
+            Content-Length: {len}\r\n\
+            Connection: close\r\n\r\n\
+            {body}",
+            len = body.len(),
+        );
+
+        stream.write_all(response.as_bytes()).await?;
+        stream.shutdown().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state() {
+        let state = State::default();
+
+        let health = state.bot_health(1);
+        assert!(!health.is_polling);
+        assert!(!health.is_healthy(Duration::from_secs(60)));
+
+        state.polling_started(1);
+        state.update_enqueued(1);
+        state.update_enqueued(1);
+        state.update_dequeued(1);
+        state.poll_succeeded(1, 2);
+        state.poll_failed(1, "error");
+
+        let health = state.bot_health(1);
+        assert!(health.is_polling);
+        assert!(health.is_healthy(Duration::from_secs(60)));
+        assert_eq!(health.queue_depth, 1);
+        assert!(health.last_update_at.is_some());
+        assert_eq!(health.last_error.as_deref(), Some("error"));
+
+        state.update_dequeued(1);
+        state.update_dequeued(1);
+        assert_eq!(state.bot_health(1).queue_depth, 0);
+
+        state.update_dropped(1);
+        assert_eq!(state.bot_health(1).dropped_updates, 1);
+
+        state.polling_stopped(1);
+
+        let health = Health {
+            bots: Box::new([state.bot_health(1)]),
+        };
+        assert!(!health.is_healthy(Duration::from_secs(60)));
+    }
+}
@@ -0,0 +1,96 @@
+//! This module contains [`Introspection`], which gives handlers, middlewares and filters access to the dispatcher state:
+//! health status of the polling processes, number of fed updates, uptime and [`ServiceHandle`] to enable and disable handlers at runtime.
+//!
+//! Dispatcher puts [`Introspection`] to the context of each update, so it can be used as handler argument:
+//! ```ignore
+//! async fn stats(bot: Bot, message: Message, introspection: Introspection) -> HandlerResult {
+//!     let text = format!(
+//!         "Uptime: {:?}, updates: {}",
+//!         introspection.uptime(),
+//!         introspection.updates(),
+//!     );
+//!
+//!     bot.send(SendMessage::new(message.chat().id(), text)).await?;
+//!
+//!     Ok(FlowControl::Continue)
+//! }
+//! ```
+
+use super::health::{Health, State as HealthState};
+
+use crate::{router::ServiceHandle, FromContext};
+
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::{Duration, SystemTime},
+};
+
+/// Introspection of the dispatcher.
+/// Check the [module](self) documentation for more information.
+#[derive(Debug, Clone, FromContext)]
+#[context(
+    key = "dispatcher_introspection",
+    description = "Introspection of the dispatcher. This type is available if the update is fed by the dispatcher."
+)]
+pub struct Introspection {
+    bot_ids: Arc<[i64]>,
+    health: Arc<HealthState>,
+    handle: ServiceHandle,
+    updates: Arc<AtomicU64>,
+    created_at: SystemTime,
+}
+
+impl Introspection {
+    pub(super) fn new(
+        bot_ids: impl IntoIterator<Item = i64>,
+        health: Arc<HealthState>,
+        handle: ServiceHandle,
+    ) -> Self {
+        Self {
+            bot_ids: bot_ids.into_iter().collect(),
+            health,
+            handle,
+            updates: Arc::default(),
+            created_at: SystemTime::now(),
+        }
+    }
+
+    pub(super) fn update_fed(&self) {
+        self.updates.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Gets health status of the polling processes for all bots of the dispatcher
+    #[must_use]
+    pub fn health(&self) -> Health {
+        Health {
+            bots: self
+                .bot_ids
+                .iter()
+                .map(|bot_id| self.health.bot_health(*bot_id))
+                .collect(),
+        }
+    }
+
+    /// Gets handle to enable and disable named handlers at runtime
+    #[must_use]
+    pub const fn handle(&self) -> &ServiceHandle {
+        &self.handle
+    }
+
+    /// Gets number of updates, which are fed to the dispatcher
+    #[must_use]
+    pub fn updates(&self) -> u64 {
+        self.updates.load(Ordering::Relaxed)
+    }
+
+    /// Gets time since the dispatcher service is created
+    #[must_use]
+    pub fn uptime(&self) -> Duration {
+        SystemTime::now()
+            .duration_since(self.created_at)
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,138 @@
+//! This module contains a bounded queue of updates between the polling process (or the webhook server) and the processing workers,
+//! and [`OverflowPolicy`], which is used when the queue is full.
+//!
+//! Queue is filled when all processing workers are busy (see [`Builder::concurrency_limit`]),
+//! so bursts of updates don't cause unbounded memory growth.
+//! Current number of updates in the queue and number of dropped updates can be received with [`Dispatcher::health`] method.
+//!
+//! [`Builder::concurrency_limit`]: crate::dispatcher::Builder#method.concurrency_limit
+//! [`Dispatcher::health`]: crate::dispatcher::Service#method.health
+
+use std::{collections::VecDeque, sync::Mutex};
+use tokio::sync::Notify;
+
+/// Policy, which is used when the queue is full
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Wait until there is a free space in the queue, so the polling process stops receiving new updates
+    #[default]
+    Block,
+    /// Drop the oldest update in the queue and push the new one
+    DropOldest,
+    /// Drop the new update
+    DropNew,
+}
+
+/// Bounded queue with a single consumer
+#[derive(Debug)]
+pub(crate) struct Queue<T> {
+    items: Mutex<VecDeque<T>>,
+    capacity: usize,
+    policy: OverflowPolicy,
+    item_pushed: Notify,
+    item_popped: Notify,
+}
+
+impl<T> Queue<T> {
+    /// # Panics
+    /// If the capacity is zero
+    pub(super) fn new(capacity: usize, policy: OverflowPolicy) -> Self {
+        assert!(capacity > 0, "Queue capacity must be greater than zero");
+
+        Self {
+            items: Mutex::new(VecDeque::with_capacity(capacity)),
+            capacity,
+            policy,
+            item_pushed: Notify::new(),
+            item_popped: Notify::new(),
+        }
+    }
+
+    /// Pushes the item to the queue according to the overflow policy
+    /// # Returns
+    /// The dropped item, if the queue is full and the policy isn't [`OverflowPolicy::Block`]
+    pub(super) async fn push(&self, item: T) -> Option<T> {
+        loop {
+            {
+                let mut items = self.items.lock().unwrap();
+
+                if items.len() < self.capacity {
+                    items.push_back(item);
+                    drop(items);
+
+                    self.item_pushed.notify_one();
+                    return None;
+                }
+
+                match self.policy {
+                    OverflowPolicy::Block => {}
+                    OverflowPolicy::DropOldest => {
+                        let dropped = items.pop_front();
+                        items.push_back(item);
+                        drop(items);
+
+                        self.item_pushed.notify_one();
+                        return dropped;
+                    }
+                    OverflowPolicy::DropNew => return Some(item),
+                }
+            }
+
+            // `notify_one` stores a permit if there is no waiter, so the notification isn't lost
+            self.item_popped.notified().await;
+        }
+    }
+
+    /// Pops the item from the queue or waits until it's pushed
+    pub(super) async fn pop(&self) -> T {
+        loop {
+            if let Some(item) = self.items.lock().unwrap().pop_front() {
+                self.item_popped.notify_one();
+                return item;
+            }
+
+            self.item_pushed.notified().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{sync::Arc, time::Duration};
+
+    #[tokio::test]
+    async fn test_overflow_policy() {
+        let queue = Queue::new(2, OverflowPolicy::DropNew);
+        assert_eq!(queue.push(1).await, None);
+        assert_eq!(queue.push(2).await, None);
+        assert_eq!(queue.push(3).await, Some(3));
+        assert_eq!(queue.pop().await, 1);
+        assert_eq!(queue.pop().await, 2);
+
+        let queue = Queue::new(2, OverflowPolicy::DropOldest);
+        assert_eq!(queue.push(1).await, None);
+        assert_eq!(queue.push(2).await, None);
+        assert_eq!(queue.push(3).await, Some(1));
+        assert_eq!(queue.pop().await, 2);
+        assert_eq!(queue.pop().await, 3);
+
+        let queue = Arc::new(Queue::new(1, OverflowPolicy::Block));
+        assert_eq!(queue.push(1).await, None);
+
+        let push = tokio::spawn({
+            let queue = Arc::clone(&queue);
+
+            async move { queue.push(2).await }
+        });
+
+        // Producer is blocked, because the queue is full
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert!(!push.is_finished());
+
+        assert_eq!(queue.pop().await, 1);
+        assert_eq!(push.await.unwrap(), None);
+        assert_eq!(queue.pop().await, 2);
+    }
+}
@@ -0,0 +1,224 @@
+//! This module contains [`TaskManager`], which runs background tasks of handlers (fire-and-forget work),
+//! tracks them and waits for them during the dispatcher shutdown, so the tasks aren't orphaned.
+//!
+//! Dispatcher puts [`TaskManager`] to the context of each update, so it can be used as handler argument:
+//! ```ignore
+//! async fn handler(bot: Bot, message: Message, tasks: TaskManager) -> HandlerResult {
+//!     tasks.spawn(async move {
+//!         if let Err(err) = generate_report(&bot, message.chat().id()).await {
+//!             event!(Level::ERROR, error = %err, "Failed to generate report");
+//!         }
+//!     })
+//!     .map_err(HandlerError::new)?;
+//!
+//!     Ok(FlowControl::Continue)
+//! }
+//! ```
+//!
+//! Number of concurrently running tasks can be limited with [`Builder::task_limit`], other tasks wait for a free slot.
+//! When the dispatcher emits shutdown, the manager is closed, so new tasks aren't spawned,
+//! and running tasks are waited for [`Builder::task_shutdown_timeout`] before shutdown observers are called.
+//!
+//! [`Builder::task_limit`]: super::Builder#method.task_limit
+//! [`Builder::task_shutdown_timeout`]: super::Builder#method.task_shutdown_timeout
+
+use crate::{runtime::Runtime, FromContext};
+
+use futures::{
+    future::{self, Either},
+    Future,
+};
+use std::{
+    fmt::{self, Debug, Formatter},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::sync::{Notify, Semaphore};
+use tracing::{event, Level};
+
+pub const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, thiserror::Error)]
+#[error("Task manager is closed, because the dispatcher is shutting down")]
+pub struct ClosedError;
+
+struct Inner {
+    runtime: Arc<dyn Runtime>,
+    limit: Option<Arc<Semaphore>>,
+    active: AtomicUsize,
+    closed: AtomicBool,
+    finished: Notify,
+}
+
+/// Decrements number of the active tasks, when the task is completed, cancelled or panicked
+struct Active(Arc<Inner>);
+
+impl Drop for Active {
+    fn drop(&mut self) {
+        if self.0.active.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.0.finished.notify_waiters();
+        }
+    }
+}
+
+/// Manager of background tasks of handlers.
+/// Check the [module](self) documentation for more information.
+#[derive(Clone, FromContext)]
+#[context(
+    key = "task_manager",
+    description = "Manager of background tasks. This type is available if the update is fed by the dispatcher."
+)]
+pub struct TaskManager {
+    inner: Arc<Inner>,
+}
+
+impl TaskManager {
+    /// Creates a new task manager, which spawns tasks with the runtime.
+    /// If `limit` is set, only `limit` tasks run concurrently and other tasks wait for a free slot.
+    /// # Panics
+    /// If `limit` is zero, because no task could run
+    #[must_use]
+    pub fn new(runtime: Arc<dyn Runtime>, limit: Option<usize>) -> Self {
+        assert!(limit != Some(0), "Task limit must be greater than zero");
+
+        Self {
+            inner: Arc::new(Inner {
+                runtime,
+                limit: limit.map(|limit| Arc::new(Semaphore::new(limit))),
+                active: AtomicUsize::new(0),
+                closed: AtomicBool::new(false),
+                finished: Notify::new(),
+            }),
+        }
+    }
+
+    /// Spawns the future to run in the background.
+    /// The task is tracked until it's completed, so the dispatcher waits for it on shutdown.
+    /// # Errors
+    /// If the manager is closed, because the dispatcher is shutting down
+    pub fn spawn<F>(&self, future: F) -> Result<(), ClosedError>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        if self.is_closed() {
+            return Err(ClosedError);
+        }
+
+        self.inner.active.fetch_add(1, Ordering::AcqRel);
+
+        let active = Active(Arc::clone(&self.inner));
+        let limit = self.inner.limit.clone();
+
+        self.inner.runtime.spawn(Box::pin(async move {
+            let _active = active;
+            let _permit = match limit {
+                Some(semaphore) => Some(
+                    semaphore
+                        .acquire_owned()
+                        .await
+                        .expect("Semaphore is never closed"),
+                ),
+                None => None,
+            };
+
+            future.await;
+        }));
+
+        Ok(())
+    }
+
+    /// Gets number of the tasks, which are running or waiting for a free slot
+    #[must_use]
+    pub fn active(&self) -> usize {
+        self.inner.active.load(Ordering::Acquire)
+    }
+
+    /// Checks that the manager is closed and doesn't spawn new tasks
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.inner.closed.load(Ordering::Acquire)
+    }
+
+    /// Closes the manager, so new tasks aren't spawned, and waits for the active tasks
+    /// # Returns
+    /// `true` if all tasks are completed and `false` if the timeout is elapsed
+    pub async fn shutdown(&self, timeout: Duration) -> bool {
+        self.inner.closed.store(true, Ordering::Release);
+
+        let wait = async {
+            loop {
+                // Notification is received by the future from the moment it's created,
+                // so the last task can't be completed between the check and the waiting
+                let finished = self.inner.finished.notified();
+
+                if self.active() == 0 {
+                    break;
+                }
+
+                finished.await;
+            }
+        };
+
+        match future::select(Box::pin(wait), self.inner.runtime.sleep(timeout)).await {
+            Either::Left(((), _)) => true,
+            Either::Right(((), _)) => {
+                event!(
+                    Level::WARN,
+                    active = self.active(),
+                    "Background tasks aren't completed before the shutdown timeout",
+                );
+
+                false
+            }
+        }
+    }
+}
+
+impl Debug for TaskManager {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TaskManager")
+            .field("active", &self.active())
+            .field("closed", &self.is_closed())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(test, feature = "tokio-runtime"))]
+mod tests {
+    use super::*;
+    use crate::runtime;
+
+    use tokio::sync::oneshot;
+
+    #[tokio::test]
+    async fn test_spawn_and_shutdown() {
+        let tasks = TaskManager::new(runtime::default(), Some(1));
+
+        let (sender, receiver) = oneshot::channel();
+        tasks
+            .spawn(async move {
+                receiver.await.unwrap();
+            })
+            .unwrap();
+        tasks.spawn(async {}).unwrap();
+        assert_eq!(tasks.active(), 2);
+
+        // The first task waits for the signal, and the second one waits for a free slot
+        assert!(!tasks.shutdown(Duration::from_millis(10)).await);
+        assert!(tasks.is_closed());
+        assert!(tasks.spawn(async {}).is_err());
+
+        sender.send(()).unwrap();
+        assert!(tasks.shutdown(Duration::from_secs(1)).await);
+        assert_eq!(tasks.active(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Task limit must be greater than zero")]
+    fn test_zero_limit() {
+        let _ = TaskManager::new(runtime::default(), Some(0));
+    }
+}
@@ -0,0 +1,951 @@
+//! This module contains standalone webhook server, which receives updates from Telegram without a reverse proxy.
+//! The server is run with [`Dispatcher::run_webhook`] method and configured with [`Config`].
+//!
+//! Telegram sends updates only to HTTPS URLs, so the server serves TLS with the [`Certificate`],
+//! which can be loaded from PEM files or generated with [`Certificate::self_signed`].
+//! Self-signed certificate is uploaded to Telegram with `setWebhook` request, so Telegram trusts it.
+//! If the server is behind a reverse proxy, which terminates TLS, don't set the certificate and the server will serve plain HTTP.
+//!
+//! Each bot of the dispatcher receives updates on its own path: `{url}/{bot_id}`,
+//! and each request is checked by the secret token (`X-Telegram-Bot-Api-Secret-Token` header).
+//! If the secret token isn't set, it's generated randomly.
+//!
+//! Bots, which aren't known when the server is started (for example, on bot-hosting platforms, where users add own bots),
+//! can be resolved on each request by the bot id from the path with [`TokenResolver`],
+//! which is passed to [`Dispatcher::run_webhook_with_resolver`] method.
+//! Use [`BotRegistry`] to add and remove bots while the server is running or implement [`TokenResolver`] to load them from a database.
+//! The resolved bot is used to handle the update, so it's available in the context like the bots of the dispatcher.
+//! Webhooks of the resolved bots aren't set by the server, use [`Config::manager`] to set them.
+//!
+//! Webhooks of the bots are reconciled with the configuration by [`WebhookManager`] when the server is started,
+//! and [`LifecycleEvent::WebhookSet`](crate::event::LifecycleEvent::WebhookSet) is emitted to the lifecycle bus of the dispatcher for each updated webhook.
+//! Pass own [`SecretTokens`] with [`Config::secret_tokens`] to rotate the secret token without restart of the server
+//! (see [`webhook module`](crate::client::webhook) of the client for more information).
+//!
+//! Updates are pushed to the queue of the dispatcher and processed in background tasks like in polling,
+//! so Telegram receives the response immediately and doesn't resend the update because of the long processing.
+//! The queue is limited by [`Builder::queue_capacity`], [`Builder::overflow_policy`] and [`Builder::concurrency_limit`],
+//! so if the queue is full and the policy is [`OverflowPolicy::Block`], the response is delayed and Telegram slows down the delivery.
+//!
+//! Body of the request is limited by [`Config::max_body_size`], TLS handshake by [`Config::tls_handshake_timeout`]
+//! and reading of the request headers by [`Config::header_read_timeout`],
+//! so the public endpoint can't be exhausted by large or slow requests.
+//!
+//! The server is stopped on exit signals (SIGINT and SIGTERM on unix, CTRL+C and CTRL+BREAK on windows) like polling.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use telers::dispatcher::webhook::{Certificate, Config};
+//!
+//! let config = Config::new("https://1.2.3.4:8443/webhook", ([0, 0, 0, 0], 8443))
+//!     .self_signed_certificate(Certificate::self_signed("1.2.3.4")?);
+//!
+//! dispatcher.run_webhook(config).await?;
+//! ```
+//!
+//! [`Dispatcher::run_webhook`]: super::Service#method.run_webhook
+//! [`Dispatcher::run_webhook_with_resolver`]: super::Service#method.run_webhook_with_resolver
+//! [`Builder::queue_capacity`]: super::Builder#method.queue_capacity
+//! [`Builder::overflow_policy`]: super::Builder#method.overflow_policy
+//! [`Builder::concurrency_limit`]: super::Builder#method.concurrency_limit
+//! [`OverflowPolicy::Block`]: super::OverflowPolicy::Block
+
+use super::{queue::Queue, wait_exit_signal, Queued, Service};
+
+use crate::{
+    client::{Bot, SecretTokens, Session, WebhookManager},
+    context::RawUpdate,
+    errors::{EventErrorKind, SessionErrorKind},
+    router::PropagateEvent,
+    types::{InputFile, Update},
+};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use dashmap::DashMap;
+use http_body_util::{BodyExt as _, Full, LengthLimitError, Limited};
+use hyper::{
+    body::Body, header::HeaderValue, server::conn::http1, service::service_fn, Method, StatusCode,
+    Uri,
+};
+use hyper_util::rt::{TokioIo, TokioTimer};
+use serde::Deserialize as _;
+use serde_json::Value;
+use std::{
+    collections::HashMap, convert::Infallible, io, net::SocketAddr, sync::Arc, time::Duration,
+};
+use thiserror;
+use tokio::{
+    io::{AsyncRead, AsyncWrite},
+    net::TcpListener,
+};
+use tokio_rustls::{
+    rustls::{self, ServerConfig},
+    TlsAcceptor,
+};
+use tracing::{event, instrument, Level};
+
+/// Header with the secret token, which is sent by Telegram in every webhook request
+pub const SECRET_TOKEN_HEADER: &str = "X-Telegram-Bot-Api-Secret-Token";
+
+/// Default max size of the request body, which is enough for any update
+pub const DEFAULT_MAX_BODY_SIZE: usize = 4 * 1024 * 1024;
+/// Default timeout of the TLS handshake
+pub const DEFAULT_TLS_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+/// Default timeout of reading the request headers
+pub const DEFAULT_HEADER_READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Invalid webhook URL `{0}`")]
+    InvalidUrl(Box<str>),
+    #[error("Failed to generate certificate: {0}")]
+    Certificate(#[from] rcgen::Error),
+    #[error("Private key isn't found in the PEM")]
+    NoPrivateKey,
+    #[error(transparent)]
+    Tls(#[from] rustls::Error),
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("Failed to set webhook for bot with id {bot_id}: {error}")]
+    SetWebhook {
+        bot_id: i64,
+        error: SessionErrorKind,
+    },
+    #[error(transparent)]
+    Event(#[from] EventErrorKind),
+}
+
+/// TLS certificate chain and private key of the webhook server in PEM format
+#[derive(Clone, PartialEq, Eq)]
+pub struct Certificate {
+    cert_pem: Box<str>,
+    key_pem: Box<str>,
+}
+
+impl Certificate {
+    #[must_use]
+    pub fn from_pem(cert_pem: impl Into<Box<str>>, key_pem: impl Into<Box<str>>) -> Self {
+        Self {
+            cert_pem: cert_pem.into(),
+            key_pem: key_pem.into(),
+        }
+    }
+
+    /// Generate self-signed certificate for the domain or IP address of the webhook URL.
+    /// Common name and subject alternative name of the certificate are set to the host, as Telegram requires.
+    /// # Errors
+    /// If the host is invalid or failed to generate the key pair
+    pub fn self_signed(host: impl Into<String>) -> Result<Self, Error> {
+        let host = host.into();
+
+        let mut params = rcgen::CertificateParams::new([host.clone()])?;
+        params
+            .distinguished_name
+            .push(rcgen::DnType::CommonName, host);
+
+        let key_pair = rcgen::KeyPair::generate()?;
+        let cert = params.self_signed(&key_pair)?;
+
+        Ok(Self::from_pem(cert.pem(), key_pair.serialize_pem()))
+    }
+
+    #[must_use]
+    pub fn cert_pem(&self) -> &str {
+        &self.cert_pem
+    }
+
+    #[must_use]
+    pub fn key_pem(&self) -> &str {
+        &self.key_pem
+    }
+
+    /// Create TLS acceptor of the server with the certificate
+    /// # Errors
+    /// If the certificate or the private key is invalid
+    pub fn tls_acceptor(&self) -> Result<TlsAcceptor, Error> {
+        let certs =
+            rustls_pemfile::certs(&mut self.cert_pem.as_bytes()).collect::<Result<Vec<_>, _>>()?;
+        let key = rustls_pemfile::private_key(&mut self.key_pem.as_bytes())?
+            .ok_or(Error::NoPrivateKey)?;
+
+        let config =
+            ServerConfig::builder_with_provider(Arc::new(rustls::crypto::ring::default_provider()))
+                .with_safe_default_protocol_versions()?
+                .with_no_client_auth()
+                .with_single_cert(certs, key)?;
+
+        Ok(TlsAcceptor::from(Arc::new(config)))
+    }
+}
+
+impl std::fmt::Debug for Certificate {
+    /// Private key is hidden, so it isn't leaked to logs
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Certificate")
+            .field("cert_pem", &self.cert_pem)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Configuration of the webhook server.
+/// Check the [module](self) documentation for more information.
+#[derive(Debug, Clone)]
+pub struct Config {
+    url: Box<str>,
+    addr: SocketAddr,
+    certificate: Option<Certificate>,
+    upload_certificate: bool,
+    secret_tokens: SecretTokens,
+    set_webhook: bool,
+    trust_secret_token: bool,
+    drop_pending_updates: bool,
+    max_connections: Option<i64>,
+    ip_address: Option<Box<str>>,
+    max_body_size: usize,
+    tls_handshake_timeout: Duration,
+    header_read_timeout: Duration,
+}
+
+impl Config {
+    /// # Arguments
+    /// * `url` - Public HTTPS URL of the server, for example, `https://example.com:8443/webhook`, updates of each bot are received on `{url}/{bot_id}`
+    /// * `addr` - Address to bind the server to, for example, `0.0.0.0:8443`
+    #[must_use]
+    pub fn new(url: impl Into<Box<str>>, addr: impl Into<SocketAddr>) -> Self {
+        Self {
+            url: url.into(),
+            addr: addr.into(),
+            certificate: None,
+            upload_certificate: false,
+            secret_tokens: SecretTokens::random(),
+            set_webhook: true,
+            trust_secret_token: false,
+            drop_pending_updates: false,
+            max_connections: None,
+            ip_address: None,
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+            tls_handshake_timeout: DEFAULT_TLS_HANDSHAKE_TIMEOUT,
+            header_read_timeout: DEFAULT_HEADER_READ_TIMEOUT,
+        }
+    }
+
+    /// Certificate signed by a trusted certificate authority, which isn't uploaded to Telegram
+    #[must_use]
+    pub fn certificate(self, val: Certificate) -> Self {
+        Self {
+            certificate: Some(val),
+            upload_certificate: false,
+            ..self
+        }
+    }
+
+    /// Self-signed certificate, which is uploaded to Telegram with `setWebhook` request
+    #[must_use]
+    pub fn self_signed_certificate(self, val: Certificate) -> Self {
+        Self {
+            certificate: Some(val),
+            upload_certificate: true,
+            ..self
+        }
+    }
+
+    /// Secret token, which is checked in every webhook request, 1-256 characters.
+    /// Only characters `A-Z`, `a-z`, `0-9`, `_` and `-` are allowed.
+    /// # Default
+    /// Random token
+    #[must_use]
+    pub fn secret_token(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            secret_tokens: SecretTokens::new(val),
+            ..self
+        }
+    }
+
+    /// Secret tokens, which are shared with the caller, so they can be rotated while the server is running
+    #[must_use]
+    pub fn secret_tokens(self, val: SecretTokens) -> Self {
+        Self {
+            secret_tokens: val,
+            ..self
+        }
+    }
+
+    /// Reconcile webhook of each bot with the configuration when the server is started.
+    /// Disable it if the webhook is set manually, but then the secret token must be set explicitly.
+    /// # Default
+    /// `true`
+    #[must_use]
+    pub fn set_webhook(self, val: bool) -> Self {
+        Self {
+            set_webhook: val,
+            ..self
+        }
+    }
+
+    /// Don't set the webhook, if it matches the configuration, because the secret token is persistent
+    /// (see [`WebhookManager::trust_secret_token`] for more information)
+    #[must_use]
+    pub fn trust_secret_token(self, val: bool) -> Self {
+        Self {
+            trust_secret_token: val,
+            ..self
+        }
+    }
+
+    /// Drop updates, which are received while the bot was offline, when the webhook is set
+    #[must_use]
+    pub fn drop_pending_updates(self, val: bool) -> Self {
+        Self {
+            drop_pending_updates: val,
+            ..self
+        }
+    }
+
+    /// The maximum allowed number of simultaneous HTTPS connections to the webhook for update delivery, 1-100
+    #[must_use]
+    pub fn max_connections(self, val: i64) -> Self {
+        Self {
+            max_connections: Some(val),
+            ..self
+        }
+    }
+
+    /// The fixed IP address which will be used to send webhook requests instead of the IP address resolved through DNS
+    #[must_use]
+    pub fn ip_address(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            ip_address: Some(val.into()),
+            ..self
+        }
+    }
+
+    /// Max size of the request body in bytes, requests with larger body are rejected with `413 Payload Too Large` status
+    /// # Default
+    /// [`DEFAULT_MAX_BODY_SIZE`]
+    #[must_use]
+    pub fn max_body_size(self, val: usize) -> Self {
+        Self {
+            max_body_size: val,
+            ..self
+        }
+    }
+
+    /// Timeout of the TLS handshake, connections, which don't finish the handshake in time, are closed
+    /// # Default
+    /// [`DEFAULT_TLS_HANDSHAKE_TIMEOUT`]
+    #[must_use]
+    pub fn tls_handshake_timeout(self, val: Duration) -> Self {
+        Self {
+            tls_handshake_timeout: val,
+            ..self
+        }
+    }
+
+    /// Timeout of reading the request headers, connections, which don't send the headers in time, are closed.
+    /// It's applied to plain HTTP connections and to connections after the TLS handshake.
+    /// # Default
+    /// [`DEFAULT_HEADER_READ_TIMEOUT`]
+    #[must_use]
+    pub fn header_read_timeout(self, val: Duration) -> Self {
+        Self {
+            header_read_timeout: val,
+            ..self
+        }
+    }
+
+    /// Get webhook URL of the bot
+    #[must_use]
+    pub fn webhook_url(&self, bot_id: i64) -> String {
+        format!("{}/{bot_id}", self.url.trim_end_matches('/'))
+    }
+
+    /// Create webhook manager of the bot with the configuration
+    #[must_use]
+    pub fn manager<T, I>(&self, bot_id: i64, allowed_updates: I) -> WebhookManager
+    where
+        T: Into<Box<str>>,
+        I: IntoIterator<Item = T>,
+    {
+        let mut manager = WebhookManager::new(self.webhook_url(bot_id), self.secret_tokens.clone())
+            .allowed_updates(allowed_updates)
+            .drop_pending_updates(self.drop_pending_updates)
+            .trust_secret_token(self.trust_secret_token);
+
+        if let Some(certificate) = self
+            .certificate
+            .as_ref()
+            .filter(|_| self.upload_certificate)
+        {
+            manager = manager.certificate(InputFile::buffered_with_name(
+                Bytes::copy_from_slice(certificate.cert_pem.as_bytes()),
+                "certificate.pem",
+            ));
+        }
+        if let Some(ip_address) = &self.ip_address {
+            manager = manager.ip_address(ip_address.clone());
+        }
+        if let Some(max_connections) = self.max_connections {
+            manager = manager.max_connections(max_connections);
+        }
+
+        manager
+    }
+}
+
+/// Resolver of the bots, which aren't bots of the dispatcher, by their ids from the webhook path.
+/// Check the [module](self) documentation for more information.
+#[async_trait]
+pub trait TokenResolver<Client>: Send + Sync {
+    /// Resolve the bot by its id
+    /// # Returns
+    /// `None` if the bot isn't found, then the request is rejected with `404 Not Found` status.
+    /// It's called only for requests with the valid secret token.
+    async fn resolve(&self, bot_id: i64) -> Option<Arc<Bot<Client>>>;
+}
+
+/// Registry of the bots, which can be changed while the server is running.
+/// Clones of the registry share the same bots.
+#[derive(Debug)]
+pub struct BotRegistry<Client> {
+    bots: Arc<DashMap<i64, Arc<Bot<Client>>>>,
+}
+
+impl<Client> BotRegistry<Client> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            bots: Arc::default(),
+        }
+    }
+
+    /// Add the bot to the registry. If the bot with the same id is already added, it's replaced
+    pub fn insert(&self, bot: impl Into<Arc<Bot<Client>>>) {
+        let bot = bot.into();
+
+        self.bots.insert(bot.bot_id, bot);
+    }
+
+    /// Remove the bot from the registry
+    /// # Returns
+    /// Removed bot, if it was added
+    pub fn remove(&self, bot_id: i64) -> Option<Arc<Bot<Client>>> {
+        self.bots.remove(&bot_id).map(|(_, bot)| bot)
+    }
+
+    /// Get the bot by its id
+    #[must_use]
+    pub fn get(&self, bot_id: i64) -> Option<Arc<Bot<Client>>> {
+        self.bots.get(&bot_id).map(|bot| Arc::clone(&bot))
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.bots.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.bots.is_empty()
+    }
+}
+
+impl<Client> Default for BotRegistry<Client> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Client> Clone for BotRegistry<Client> {
+    fn clone(&self) -> Self {
+        Self {
+            bots: Arc::clone(&self.bots),
+        }
+    }
+}
+
+#[async_trait]
+impl<Client> TokenResolver<Client> for BotRegistry<Client>
+where
+    Client: Send + Sync,
+{
+    async fn resolve(&self, bot_id: i64) -> Option<Arc<Bot<Client>>> {
+        self.get(bot_id)
+    }
+}
+
+/// Bots of the server by their webhook paths, the secret token and the max size of the request body
+struct Routes<Client> {
+    base_path: Box<str>,
+    secret_tokens: SecretTokens,
+    bots: HashMap<i64, Arc<Bot<Client>>>,
+    resolver: Option<Arc<dyn TokenResolver<Client>>>,
+    max_body_size: usize,
+}
+
+impl<Client> Routes<Client> {
+    fn bot_id(&self, path: &str) -> Option<i64> {
+        path.strip_prefix(&*self.base_path)?
+            .strip_prefix('/')?
+            .trim_end_matches('/')
+            .parse()
+            .ok()
+    }
+
+    /// Get the bot of the dispatcher by the path or resolve it with the resolver
+    async fn resolve(&self, path: &str) -> Option<Arc<Bot<Client>>> {
+        let bot_id = self.bot_id(path)?;
+
+        if let Some(bot) = self.bots.get(&bot_id) {
+            return Some(Arc::clone(bot));
+        }
+
+        self.resolver.as_ref()?.resolve(bot_id).await
+    }
+
+    fn is_authorized(&self, secret_token: Option<&HeaderValue>) -> bool {
+        secret_token
+            .and_then(|val| val.to_str().ok())
+            .map_or(false, |val| self.secret_tokens.is_valid(val))
+    }
+}
+
+fn response(status: StatusCode) -> hyper::Response<Full<Bytes>> {
+    let mut response = hyper::Response::new(Full::default());
+    *response.status_mut() = status;
+    response
+}
+
+async fn handle<Client, PropagatorService, BackoffType, B>(
+    dispatcher: Arc<Service<Client, PropagatorService, BackoffType>>,
+    routes: Arc<Routes<Client>>,
+    queue: Arc<Queue<Queued<Client>>>,
+    request: hyper::Request<B>,
+) -> Result<hyper::Response<Full<Bytes>>, Infallible>
+where
+    Client: Session + 'static,
+    PropagatorService: PropagateEvent<Client> + 'static,
+    BackoffType: Send + Sync + 'static,
+    B: Body,
+    B::Error: std::error::Error + Send + Sync + 'static,
+{
+    if request.method() != Method::POST {
+        return Ok(response(StatusCode::METHOD_NOT_ALLOWED));
+    }
+
+    // Secret token is checked before resolving the bot, so unauthorized requests don't trigger the resolver
+    // and can't find out which bots exist
+    if !routes.is_authorized(request.headers().get(SECRET_TOKEN_HEADER)) {
+        event!(Level::WARN, "Webhook request with invalid secret token");
+
+        return Ok(response(StatusCode::UNAUTHORIZED));
+    }
+
+    let Some(bot) = routes.resolve(request.uri().path()).await else {
+        return Ok(response(StatusCode::NOT_FOUND));
+    };
+
+    let body = match Limited::new(request.into_body(), routes.max_body_size)
+        .collect()
+        .await
+    {
+        Ok(body) => body.to_bytes(),
+        Err(err) if err.is::<LengthLimitError>() => {
+            event!(Level::WARN, "Webhook request body is too large");
+
+            return Ok(response(StatusCode::PAYLOAD_TOO_LARGE));
+        }
+        Err(err) => {
+            event!(Level::ERROR, error = %err, "Failed to read webhook request");
+
+            return Ok(response(StatusCode::BAD_REQUEST));
+        }
+    };
+
+    let parsed = if dispatcher.raw_updates {
+        serde_json::from_slice::<Value>(&body).and_then(|raw| {
+            Update::deserialize(&raw).map(|update| (update, Some(RawUpdate::new(raw))))
+        })
+    } else {
+        serde_json::from_slice::<Update>(&body).map(|update| (update, None))
+    };
+
+    let (update, raw_update) = match parsed {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            event!(Level::ERROR, error = %err, "Failed to parse update of webhook request");
+
+            return Ok(response(StatusCode::BAD_REQUEST));
+        }
+    };
+
+    // Response is delayed, if the queue is full and the overflow policy blocks,
+    // so Telegram slows down the delivery instead of unbounded growth of the processing tasks
+    Service::<Client, PropagatorService, BackoffType>::push_update(
+        (bot, (update, raw_update)),
+        &queue,
+        &dispatcher.health,
+    )
+    .await;
+
+    Ok(response(StatusCode::OK))
+}
+
+async fn serve_connection<Client, PropagatorService, BackoffType, IO>(
+    dispatcher: Arc<Service<Client, PropagatorService, BackoffType>>,
+    routes: Arc<Routes<Client>>,
+    queue: Arc<Queue<Queued<Client>>>,
+    io: IO,
+    header_read_timeout: Duration,
+) -> hyper::Result<()>
+where
+    Client: Session + 'static,
+    PropagatorService: PropagateEvent<Client> + 'static,
+    BackoffType: Send + Sync + 'static,
+    IO: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    let service = service_fn(move |request| {
+        handle(
+            Arc::clone(&dispatcher),
+            Arc::clone(&routes),
+            Arc::clone(&queue),
+            request,
+        )
+    });
+
+    http1::Builder::new()
+        .timer(TokioTimer::new())
+        .header_read_timeout(header_read_timeout)
+        .serve_connection(TokioIo::new(io), service)
+        .await
+}
+
+/// Sets webhooks of the bots and serves webhook requests until exit signal is received
+#[instrument(skip(dispatcher, config, resolver))]
+pub(super) async fn run<Client, PropagatorService, BackoffType>(
+    dispatcher: Arc<Service<Client, PropagatorService, BackoffType>>,
+    config: Config,
+    resolver: Option<Arc<dyn TokenResolver<Client>>>,
+) -> Result<(), Error>
+where
+    Client: Session + Clone + 'static,
+    PropagatorService: PropagateEvent<Client> + 'static,
+    BackoffType: Send + Sync + 'static,
+{
+    let base_path = config
+        .url
+        .parse::<Uri>()
+        .ok()
+        .filter(|uri| uri.host().is_some())
+        .ok_or_else(|| Error::InvalidUrl(config.url.clone()))?
+        .path()
+        .trim_end_matches('/')
+        .into();
+    let tls_acceptor = config
+        .certificate
+        .as_ref()
+        .map(Certificate::tls_acceptor)
+        .transpose()?;
+
+    let mut bots = HashMap::with_capacity(dispatcher.bots.len());
+    for bot in dispatcher.bots.iter() {
+        if config.set_webhook {
+            config
+                .manager(
+                    bot.bot_id,
+                    dispatcher.allowed_updates.iter().map(AsRef::<str>::as_ref),
+                )
+                .lifecycle_bus(dispatcher.lifecycle.clone())
+                .reconcile(bot)
+                .await
+                .map_err(|error| Error::SetWebhook {
+                    bot_id: bot.bot_id,
+                    error,
+                })?;
+        }
+
+        bots.insert(bot.bot_id, Arc::new(bot.clone()));
+    }
+
+    let routes = Arc::new(Routes {
+        base_path,
+        secret_tokens: config.secret_tokens.clone(),
+        bots,
+        resolver,
+        max_body_size: config.max_body_size,
+    });
+
+    let queue = Arc::new(Queue::new(
+        dispatcher.queue_capacity,
+        dispatcher.overflow_policy,
+    ));
+    // Dropping the handle cancels the task
+    let _process_queue_handle = dispatcher
+        .runtime
+        .spawn_with_handle(Arc::clone(&dispatcher).process_queue(Arc::clone(&queue)));
+
+    let listener = TcpListener::bind(config.addr).await?;
+
+    event!(
+        Level::INFO,
+        addr = ?listener.local_addr(),
+        tls = tls_acceptor.is_some(),
+        "Webhook server is started",
+    );
+
+    let accept = async {
+        loop {
+            let (stream, peer_addr) = match listener.accept().await {
+                Ok(val) => val,
+                Err(err) => {
+                    event!(Level::ERROR, error = %err, "Failed to accept webhook request");
+
+                    continue;
+                }
+            };
+
+            let dispatcher = Arc::clone(&dispatcher);
+            let routes = Arc::clone(&routes);
+            let queue = Arc::clone(&queue);
+            let tls_acceptor = tls_acceptor.clone();
+            let tls_handshake_timeout = config.tls_handshake_timeout;
+            let header_read_timeout = config.header_read_timeout;
+
+            tokio::spawn(async move {
+                let result = match tls_acceptor {
+                    Some(tls_acceptor) => {
+                        match tokio::time::timeout(
+                            tls_handshake_timeout,
+                            tls_acceptor.accept(stream),
+                        )
+                        .await
+                        {
+                            Ok(Ok(stream)) => {
+                                serve_connection(
+                                    dispatcher,
+                                    routes,
+                                    queue,
+                                    stream,
+                                    header_read_timeout,
+                                )
+                                .await
+                            }
+                            Ok(Err(err)) => {
+                                event!(Level::DEBUG, error = %err, %peer_addr, "TLS handshake failed");
+
+                                return;
+                            }
+                            Err(_) => {
+                                event!(Level::DEBUG, %peer_addr, "TLS handshake timed out");
+
+                                return;
+                            }
+                        }
+                    }
+                    None => {
+                        serve_connection(dispatcher, routes, queue, stream, header_read_timeout)
+                            .await
+                    }
+                };
+
+                if let Err(err) = result {
+                    event!(Level::ERROR, error = %err, %peer_addr, "Failed to serve webhook connection");
+                }
+            });
+        }
+    };
+
+    tokio::select! {
+        () = accept => {},
+        () = wait_exit_signal() => {},
+    }
+
+    event!(Level::WARN, "Webhook server is stopped");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Reqwest;
+
+    #[test]
+    fn test_webhook_url() {
+        let config = Config::new("https://example.com/webhook/", ([0, 0, 0, 0], 8443));
+        assert_eq!(config.webhook_url(1), "https://example.com/webhook/1");
+
+        let method = config
+            .secret_token("token")
+            .manager(1, ["message"])
+            .set_webhook_method();
+        assert_eq!(method.url, "https://example.com/webhook/1");
+        assert_eq!(method.secret_token.as_deref(), Some("token"));
+        assert!(method.certificate.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_routes() {
+        let routes = Routes {
+            base_path: "/webhook".into(),
+            secret_tokens: SecretTokens::new("token"),
+            bots: HashMap::from([(1, Arc::new(Bot::<Reqwest>::default()))]),
+            resolver: None,
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+        };
+
+        assert!(routes.resolve("/webhook/1").await.is_some());
+        assert!(routes.resolve("/webhook/1/").await.is_some());
+        assert!(routes.resolve("/webhook/2").await.is_none());
+        assert!(routes.resolve("/webhook1").await.is_none());
+        assert!(routes.resolve("/").await.is_none());
+
+        assert!(routes.is_authorized(Some(&HeaderValue::from_static("token"))));
+        assert!(!routes.is_authorized(Some(&HeaderValue::from_static("other"))));
+        assert!(!routes.is_authorized(None));
+    }
+
+    #[tokio::test]
+    async fn test_resolver() {
+        let registry = BotRegistry::<Reqwest>::new();
+        let routes = Routes {
+            base_path: "/webhook".into(),
+            secret_tokens: SecretTokens::new("token"),
+            bots: HashMap::from([(1, Arc::new(Bot::<Reqwest>::default()))]),
+            resolver: Some(Arc::new(registry.clone())),
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+        };
+
+        assert!(routes.resolve("/webhook/1").await.is_some());
+        assert!(routes.resolve("/webhook/1234567890").await.is_none());
+
+        registry.insert(Bot::new("1234567890:ABCDEFGHIJKLMNOPQRSTUVWXYZ012345678"));
+        let bot = routes.resolve("/webhook/1234567890/").await.unwrap();
+        assert_eq!(bot.bot_id, 1_234_567_890);
+        // Resolved bots aren't routes of the dispatcher bots
+        assert!(!routes.bots.contains_key(&1_234_567_890));
+
+        assert!(registry.remove(1_234_567_890).is_some());
+        assert!(routes.resolve("/webhook/1234567890").await.is_none());
+        assert!(routes.resolve("/webhook/other").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_handle() {
+        use crate::{dispatcher::Dispatcher, event::ToServiceProvider as _, router::Router};
+
+        let dispatcher = Arc::new(
+            Dispatcher::builder()
+                .main_router(Router::<Reqwest>::new("main"))
+                .build()
+                .to_service_provider_default()
+                .unwrap(),
+        );
+        let routes = Arc::new(Routes {
+            base_path: "/webhook".into(),
+            secret_tokens: SecretTokens::new("token"),
+            bots: HashMap::from([(1, Arc::new(Bot::<Reqwest>::default()))]),
+            resolver: None,
+            max_body_size: 128,
+        });
+        let queue = Arc::new(Queue::new(10, super::super::OverflowPolicy::default()));
+
+        let request = |poll_id: &str| {
+            let body = format!(
+                r#"{{"update_id": 1, "poll_answer": {{"poll_id": "{poll_id}", "option_ids": [0]}}}}"#
+            );
+            hyper::Request::builder()
+                .method(Method::POST)
+                .uri("/webhook/1")
+                .header(SECRET_TOKEN_HEADER, "token")
+                .body(Full::new(Bytes::from(body)))
+                .unwrap()
+        };
+        let handle = |request| {
+            handle(
+                Arc::clone(&dispatcher),
+                Arc::clone(&routes),
+                Arc::clone(&queue),
+                request,
+            )
+        };
+
+        let response = handle(request("1")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        // Update is pushed to the queue of the dispatcher instead of processing it right away
+        let (bot, (update, _)) = queue.pop().await;
+        assert!(Arc::ptr_eq(&bot, &routes.bots[&1]));
+        assert_eq!(update.id, 1);
+
+        // Unauthorized requests are rejected before the bot is resolved, so unknown bots aren't revealed
+        for (uri, token) in [("/webhook/1", "other"), ("/webhook/2", "other")] {
+            let mut request = request("1");
+            *request.uri_mut() = uri.parse().unwrap();
+            request
+                .headers_mut()
+                .insert(SECRET_TOKEN_HEADER, HeaderValue::from_static(token));
+
+            let response = handle(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+
+        let mut unknown_bot_request = request("1");
+        *unknown_bot_request.uri_mut() = "/webhook/2".parse().unwrap();
+        let response = handle(unknown_bot_request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let response = handle(request(&"1".repeat(128))).await.unwrap();
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[tokio::test]
+    async fn test_header_read_timeout() {
+        use crate::{dispatcher::Dispatcher, event::ToServiceProvider as _, router::Router};
+
+        let dispatcher = Dispatcher::builder()
+            .main_router(Router::<Reqwest>::new("main"))
+            .build()
+            .to_service_provider_default()
+            .unwrap();
+        let routes = Arc::new(Routes {
+            base_path: "/webhook".into(),
+            secret_tokens: SecretTokens::new("token"),
+            bots: HashMap::new(),
+            resolver: None,
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+        });
+        let queue = Arc::new(Queue::new(10, super::super::OverflowPolicy::default()));
+
+        // Client sends only a part of the headers and holds the connection
+        let (mut client, server) = tokio::io::duplex(64);
+        tokio::io::AsyncWriteExt::write_all(&mut client, b"POST /webhook/1 HTTP/1.1\r\n")
+            .await
+            .unwrap();
+
+        let result = tokio::time::timeout(
+            Duration::from_secs(5),
+            serve_connection(dispatcher, routes, queue, server, Duration::from_millis(50)),
+        )
+        .await;
+        assert!(
+            result.is_ok(),
+            "Connection should be closed by the header read timeout"
+        );
+    }
+
+    #[test]
+    fn test_self_signed() {
+        let certificate = Certificate::self_signed("127.0.0.1").unwrap();
+        assert!(certificate
+            .cert_pem()
+            .starts_with("-----BEGIN CERTIFICATE-----"));
+        assert!(certificate.tls_acceptor().is_ok());
+        assert!(!format!("{certificate:?}").contains("PRIVATE KEY"));
+
+        let config = Config::new("https://127.0.0.1:8443", ([0, 0, 0, 0], 8443))
+            .self_signed_certificate(certificate);
+        let method = config.manager(1, ["message"]).set_webhook_method();
+        assert!(method.certificate.is_some());
+    }
+}
@@ -1,6 +1,6 @@
 use strum_macros::{AsRefStr, Display, EnumString, IntoStaticStr};
 
-use crate::types::Chat;
+use crate::types::{Chat, ChatFullInfo};
 
 /// This enum represents all possible types of the chat
 /// # Documentation
@@ -57,3 +57,14 @@ impl<'a> From<&'a Chat> for ChatType {
         }
     }
 }
+
+impl<'a> From<&'a ChatFullInfo> for ChatType {
+    fn from(chat: &'a ChatFullInfo) -> Self {
+        match chat {
+            ChatFullInfo::Private(_) => ChatType::Private,
+            ChatFullInfo::Group(_) => ChatType::Group,
+            ChatFullInfo::Supergroup(_) => ChatType::Supergroup,
+            ChatFullInfo::Channel(_) => ChatType::Channel,
+        }
+    }
+}
@@ -17,6 +17,8 @@ pub enum ContentType {
     Document,
     #[strum(serialize = "photo")]
     Photo,
+    #[strum(serialize = "paid_media")]
+    PaidMedia,
     #[strum(serialize = "sticker")]
     Sticker,
     #[strum(serialize = "story")]
@@ -115,13 +117,14 @@ pub enum ContentType {
 
 impl ContentType {
     #[must_use]
-    pub const fn all() -> [ContentType; 52] {
+    pub const fn all() -> [ContentType; 53] {
         [
             ContentType::Text,
             ContentType::Animation,
             ContentType::Audio,
             ContentType::Document,
             ContentType::Photo,
+            ContentType::PaidMedia,
             ContentType::Sticker,
             ContentType::Story,
             ContentType::Video,
@@ -171,6 +174,22 @@ impl ContentType {
             ContentType::WebAppData,
         ]
     }
+
+    /// Content types of messages with media files
+    #[must_use]
+    pub const fn media() -> [ContentType; 9] {
+        [
+            ContentType::Animation,
+            ContentType::Audio,
+            ContentType::Document,
+            ContentType::Photo,
+            ContentType::PaidMedia,
+            ContentType::Sticker,
+            ContentType::Video,
+            ContentType::VideoNote,
+            ContentType::Voice,
+        ]
+    }
 }
 
 impl From<ContentType> for Box<str> {
@@ -199,6 +218,7 @@ impl From<&Message> for ContentType {
             Message::Audio(_) => ContentType::Audio,
             Message::Document(_) => ContentType::Document,
             Message::Photo(_) => ContentType::Photo,
+            Message::PaidMedia(_) => ContentType::PaidMedia,
             Message::Sticker(_) => ContentType::Sticker,
             Message::Story(_) => ContentType::Story,
             Message::Video(_) => ContentType::Video,
@@ -0,0 +1,121 @@
+use crate::types::{MessageEntity, MessageEntityKind};
+
+use strum_macros::{AsRefStr, Display, EnumString, IntoStaticStr};
+
+/// This enum represents all possible types of the message entity
+/// # Documentation
+/// <https://core.telegram.org/bots/api#messageentity>
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash, EnumString, AsRefStr, IntoStaticStr)]
+pub enum MessageEntityType {
+    #[strum(serialize = "mention")]
+    Mention,
+    #[strum(serialize = "hashtag")]
+    Hashtag,
+    #[strum(serialize = "cashtag")]
+    Cashtag,
+    #[strum(serialize = "bot_command")]
+    BotCommand,
+    #[strum(serialize = "url")]
+    Url,
+    #[strum(serialize = "email")]
+    Email,
+    #[strum(serialize = "phone_number")]
+    PhoneNumber,
+    #[strum(serialize = "bold")]
+    Bold,
+    #[strum(serialize = "italic")]
+    Italic,
+    #[strum(serialize = "underline")]
+    Underline,
+    #[strum(serialize = "strikethrough")]
+    Strikethrough,
+    #[strum(serialize = "spoiler")]
+    Spoiler,
+    #[strum(serialize = "blockquote")]
+    Blockquote,
+    #[strum(serialize = "code")]
+    Code,
+    #[strum(serialize = "pre")]
+    Pre,
+    #[strum(serialize = "text_link")]
+    TextLink,
+    #[strum(serialize = "text_mention")]
+    TextMention,
+    #[strum(serialize = "custom_emoji")]
+    CustomEmoji,
+}
+
+impl MessageEntityType {
+    #[must_use]
+    pub const fn all() -> [MessageEntityType; 18] {
+        [
+            MessageEntityType::Mention,
+            MessageEntityType::Hashtag,
+            MessageEntityType::Cashtag,
+            MessageEntityType::BotCommand,
+            MessageEntityType::Url,
+            MessageEntityType::Email,
+            MessageEntityType::PhoneNumber,
+            MessageEntityType::Bold,
+            MessageEntityType::Italic,
+            MessageEntityType::Underline,
+            MessageEntityType::Strikethrough,
+            MessageEntityType::Spoiler,
+            MessageEntityType::Blockquote,
+            MessageEntityType::Code,
+            MessageEntityType::Pre,
+            MessageEntityType::TextLink,
+            MessageEntityType::TextMention,
+            MessageEntityType::CustomEmoji,
+        ]
+    }
+}
+
+impl From<MessageEntityType> for Box<str> {
+    fn from(entity_type: MessageEntityType) -> Self {
+        Into::<&'static str>::into(entity_type).into()
+    }
+}
+
+impl From<MessageEntityType> for String {
+    fn from(entity_type: MessageEntityType) -> Self {
+        entity_type.as_ref().to_owned()
+    }
+}
+
+impl<'a> PartialEq<&'a str> for MessageEntityType {
+    fn eq(&self, other: &&'a str) -> bool {
+        self.as_ref() == *other
+    }
+}
+
+impl From<&MessageEntityKind> for MessageEntityType {
+    fn from(kind: &MessageEntityKind) -> Self {
+        match kind {
+            MessageEntityKind::Mention => MessageEntityType::Mention,
+            MessageEntityKind::Hashtag => MessageEntityType::Hashtag,
+            MessageEntityKind::Cashtag => MessageEntityType::Cashtag,
+            MessageEntityKind::BotCommand => MessageEntityType::BotCommand,
+            MessageEntityKind::Url => MessageEntityType::Url,
+            MessageEntityKind::Email => MessageEntityType::Email,
+            MessageEntityKind::PhoneNumber => MessageEntityType::PhoneNumber,
+            MessageEntityKind::Bold => MessageEntityType::Bold,
+            MessageEntityKind::Italic => MessageEntityType::Italic,
+            MessageEntityKind::Underline => MessageEntityType::Underline,
+            MessageEntityKind::Strikethrough => MessageEntityType::Strikethrough,
+            MessageEntityKind::Spoiler => MessageEntityType::Spoiler,
+            MessageEntityKind::Blockquote => MessageEntityType::Blockquote,
+            MessageEntityKind::Code => MessageEntityType::Code,
+            MessageEntityKind::Pre(_) => MessageEntityType::Pre,
+            MessageEntityKind::TextLink(_) => MessageEntityType::TextLink,
+            MessageEntityKind::TextMention(_) => MessageEntityType::TextMention,
+            MessageEntityKind::CustomEmoji(_) => MessageEntityType::CustomEmoji,
+        }
+    }
+}
+
+impl From<&MessageEntity> for MessageEntityType {
+    fn from(entity: &MessageEntity) -> Self {
+        Self::from(&entity.kind)
+    }
+}
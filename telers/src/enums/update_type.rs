@@ -69,6 +69,17 @@ impl UpdateType {
             UpdateType::RemovedChatBoost,
         ]
     }
+
+    /// Check if updates of the type are received, when allowed updates aren't specified.
+    /// Telegram doesn't send [`UpdateType::ChatMember`], [`UpdateType::MessageReaction`] and [`UpdateType::MessageReactionCount`]
+    /// updates, unless they are explicitly allowed.
+    #[must_use]
+    pub const fn is_received_by_default(&self) -> bool {
+        !matches!(
+            self,
+            UpdateType::ChatMember | UpdateType::MessageReaction | UpdateType::MessageReactionCount
+        )
+    }
 }
 
 impl<'a> From<&'a UpdateKind> for UpdateType {
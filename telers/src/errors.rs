@@ -6,22 +6,42 @@
 //! - [`SessionErrorKind`]
 //! - [`TelegramErrorKind`]
 //! - [`ConvertToTypeError`]
+//! - [`UpdateParseError`]
+//! - [`ConfigError`] and [`ConfigErrors`]
 //! Check the documentation for each error to see what it means.
 
 #![allow(clippy::module_name_repetitions)]
 
+#[cfg(feature = "dispatcher")]
+pub mod config;
 pub mod convert;
+#[cfg(feature = "dispatcher")]
 pub mod event;
+#[cfg(feature = "dispatcher")]
 pub mod extractor;
+#[cfg(feature = "dispatcher")]
 pub mod handler;
+#[cfg(feature = "dispatcher")]
 pub mod middleware;
+#[cfg(feature = "client")]
 pub mod session;
 pub mod telegram;
+#[cfg(feature = "client")]
+pub mod update_parse;
 
+#[cfg(feature = "dispatcher")]
+pub use config::{Error as ConfigError, Errors as ConfigErrors};
 pub use convert::ConvertToType as ConvertToTypeError;
+#[cfg(feature = "dispatcher")]
 pub use event::ErrorKind as EventErrorKind;
+#[cfg(feature = "dispatcher")]
 pub use extractor::Error as ExtractionError;
+#[cfg(feature = "dispatcher")]
 pub use handler::Error as HandlerError;
+#[cfg(feature = "dispatcher")]
 pub use middleware::Error as MiddlewareError;
+#[cfg(feature = "client")]
 pub use session::ErrorKind as SessionErrorKind;
 pub use telegram::ErrorKind as TelegramErrorKind;
+#[cfg(feature = "client")]
+pub use update_parse::Error as UpdateParseError;
@@ -0,0 +1,120 @@
+//! This module contains errors of the dispatcher configuration, which are found when the dispatcher is converted to the service.
+//! For example, if the handlers use FSM, but [`FSMContext`] middleware isn't registered for them, you got [`Error::MissingFSMContext`].
+//!
+//! All found errors are collected to [`Errors`], so the misconfiguration can be fixed at once instead of one by one.
+//!
+//! [`FSMContext`]: crate::middlewares::outer::FSMContext
+
+use crate::enums::UpdateType;
+
+use std::{
+    fmt::{self, Display, Formatter},
+    slice,
+};
+
+/// Error of the dispatcher configuration
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// Bots are configured, but the main router doesn't have any handlers, so all received updates are ignored
+    #[error("Main router and its sub routers don't have any handlers, so all received updates are ignored")]
+    NoHandlers,
+    /// Handlers in the same observer handle the same command and don't have other filters,
+    /// so only the first of them is ever called
+    #[error(
+        "Handlers {handlers:?} of `{observer}` observer in `{router}` router handle the same command `{command}` \
+         and don't have other filters, so only the first of them is called"
+    )]
+    OverlappingCommands {
+        router: &'static str,
+        observer: &'static str,
+        command: Box<str>,
+        handlers: Box<[Box<str>]>,
+    },
+    /// Handler uses FSM (state filter or FSM extractors), but [`FSMContext`] middleware isn't registered
+    /// for its observer in the router or its parent routers, so the state is never set
+    ///
+    /// [`FSMContext`]: crate::middlewares::outer::FSMContext
+    #[error(
+        "Handler `{handler}` of `{observer}` observer in `{router}` router uses FSM, \
+         but `FSMContext` middleware isn't registered for the observer or `update` observer in the router or its parent routers"
+    )]
+    MissingFSMContext {
+        router: &'static str,
+        observer: &'static str,
+        handler: Box<str>,
+    },
+    /// Main router handles update types, which aren't allowed, so these handlers are never called.
+    /// It's error only with [`AllowedUpdatesPolicy::Strict`] policy.
+    ///
+    /// [`AllowedUpdatesPolicy::Strict`]: crate::dispatcher::AllowedUpdatesPolicy::Strict
+    #[error("Main router handles update types {missing:?}, which aren't allowed ({allowed:?}), so these handlers are never called")]
+    AllowedUpdates {
+        missing: Box<[UpdateType]>,
+        allowed: Box<[UpdateType]>,
+    },
+    /// Option of the dispatcher (for example, `queue_capacity`) is set to zero, so no update or task could be processed
+    #[error("Option `{option}` of the dispatcher is zero, but it must be greater than zero")]
+    ZeroLimit { option: &'static str },
+}
+
+/// All errors of the dispatcher configuration
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub struct Errors(Box<[Error]>);
+
+impl Errors {
+    #[must_use]
+    pub fn new(errors: impl IntoIterator<Item = Error>) -> Self {
+        Self(errors.into_iter().collect())
+    }
+
+    #[must_use]
+    pub const fn errors(&self) -> &[Error] {
+        &self.0
+    }
+}
+
+impl Display for Errors {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Dispatcher is misconfigured:")?;
+
+        for error in &*self.0 {
+            write!(f, "\n - {error}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> IntoIterator for &'a Errors {
+    type Item = &'a Error;
+    type IntoIter = slice::Iter<'a, Error>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let errors = Errors::new([
+            Error::NoHandlers,
+            Error::MissingFSMContext {
+                router: "main",
+                observer: "message",
+                handler: "handler".into(),
+            },
+        ]);
+
+        assert_eq!(
+            errors.to_string(),
+            "Dispatcher is misconfigured:\n \
+             - Main router and its sub routers don't have any handlers, so all received updates are ignored\n \
+             - Handler `handler` of `message` observer in `main` router uses FSM, \
+             but `FSMContext` middleware isn't registered for the observer or `update` observer in the router or its parent routers"
+        );
+    }
+}
@@ -8,6 +8,7 @@
 //! [`CallbackQuery`]: crate::types::CallbackQuery
 //! [`FromEventAndContext`]: crate::extractors::FromEventAndContext
 
+#[cfg(feature = "dispatcher")]
 use super::ExtractionError;
 
 /// This error can occur when type be converted to the desired type.
@@ -33,6 +34,7 @@ impl ConvertToType {
     }
 }
 
+#[cfg(feature = "dispatcher")]
 impl From<ConvertToType> for ExtractionError {
     fn from(err: ConvertToType) -> Self {
         Self::new(err.to_string())
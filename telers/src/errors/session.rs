@@ -5,7 +5,7 @@
 //!
 //! Possible Telegram Bot API errors are described in enum [`TelegramErrorKind`], check it out.
 
-use super::TelegramErrorKind;
+use super::{TelegramErrorKind, UpdateParseError};
 
 use anyhow;
 use thiserror;
@@ -22,7 +22,21 @@ pub enum ErrorKind {
     /// Error while parsing JSON
     #[error(transparent)]
     Parse(#[from] serde_json::Error),
+    /// Error while parsing updates from `getUpdates` response.
+    /// It contains the update id, path to the field and raw JSON of the update, which can't be parsed.
+    #[error(transparent)]
+    UpdateParse(#[from] UpdateParseError),
     /// Error by Telegram API
     #[error(transparent)]
     Telegram(#[from] TelegramErrorKind),
 }
+
+impl ErrorKind {
+    /// Check if the error is returned, because the new content and the reply markup of the edited message
+    /// are exactly the same as the current ones.
+    /// Check [`TelegramErrorKind::is_message_not_modified`] for more information.
+    #[must_use]
+    pub fn is_message_not_modified(&self) -> bool {
+        matches!(self, Self::Telegram(err) if err.is_message_not_modified())
+    }
+}
@@ -58,3 +58,12 @@ pub enum ErrorKind {
     #[error(transparent)]
     UnknownError(#[from] anyhow::Error),
 }
+
+impl ErrorKind {
+    /// Check if the error is returned, because the new content and the reply markup of the edited message
+    /// are exactly the same as the current ones
+    #[must_use]
+    pub fn is_message_not_modified(&self) -> bool {
+        matches!(self, Self::BadRequest { message } if message.contains("message is not modified"))
+    }
+}
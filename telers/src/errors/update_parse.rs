@@ -0,0 +1,135 @@
+//! This module contains the [`Error`] struct, which is returned when the updates from `getUpdates` response can't be parsed.
+//!
+//! Parse errors of [`serde_json`] contain only line and column of the response content,
+//! which isn't enough to understand what went wrong with the update, especially if the response contains many updates.
+//! This error contains `update_id` of the update, which can't be parsed, path to the field, which can't be parsed,
+//! and raw JSON snippet of the update, so the failure can be diagnosed from logs alone.
+
+use crate::{methods::Response, types::Update};
+
+use serde_json::Value;
+use thiserror;
+
+/// Max length of the raw JSON snippet of the update in bytes
+const SNIPPET_MAX_LEN: usize = 1024;
+
+/// Error while parsing updates from `getUpdates` response.
+/// Check the [module](self) documentation for more information.
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "Cannot parse update {} at `{path}`: {source}. Raw JSON: {snippet}",
+    update_id.map_or_else(|| "<unknown>".to_owned(), |id| id.to_string())
+)]
+pub struct Error {
+    /// Identifier of the update, which can't be parsed, if it's found
+    pub update_id: Option<i64>,
+    /// Path to the field of the update, which can't be parsed, for example `message`.
+    /// Path to the field inside the message is added to the source error message.
+    pub path: Box<str>,
+    /// Raw JSON of the update, which can't be parsed. It's truncated, if it's too long.
+    pub snippet: Box<str>,
+    #[source]
+    pub source: serde_json::Error,
+}
+
+impl Error {
+    /// Find the update, which can't be parsed, in the `getUpdates` response content
+    /// # Returns
+    /// `None` if the content isn't a valid response with updates array,
+    /// so the error isn't related to any update
+    #[must_use]
+    pub fn from_response(content: &str) -> Option<Self> {
+        let response = serde_json::from_str::<Response<Box<[Value]>>>(content).ok()?;
+
+        response.result?.iter().find_map(Self::from_update)
+    }
+
+    /// Check the raw JSON of the update, which can't be parsed
+    /// # Returns
+    /// `None` if the update can be parsed, so the error isn't related to it
+    #[must_use]
+    pub fn from_update(update: &Value) -> Option<Self> {
+        let err = serde_path_to_error::deserialize::<_, Update>(update).err()?;
+
+        Some(Self {
+            update_id: update.get("update_id").and_then(Value::as_i64),
+            path: err.path().to_string().into(),
+            snippet: snippet(&update.to_string()).into(),
+            source: err.into_inner(),
+        })
+    }
+}
+
+/// Truncate the content to [`SNIPPET_MAX_LEN`] bytes on a char boundary
+fn snippet(content: &str) -> String {
+    if content.len() <= SNIPPET_MAX_LEN {
+        return content.to_owned();
+    }
+
+    let mut end = SNIPPET_MAX_LEN;
+    while !content.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    format!("{}...", &content[..end])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_response() {
+        let content = r#"{
+            "ok": true,
+            "result": [
+                {
+                    "update_id": 1,
+                    "message": {
+                        "message_id": 1,
+                        "chat": {"id": 1, "type": "private", "first_name": "Test"},
+                        "date": 1,
+                        "text": "ok"
+                    }
+                },
+                {
+                    "update_id": 2,
+                    "message": {
+                        "message_id": 2,
+                        "chat": {"id": 1, "type": "private", "first_name": "Test"},
+                        "date": 1,
+                        "text": "broken",
+                        "external_reply": {"origin": {"type": "unknown_origin", "date": 1}}
+                    }
+                }
+            ]
+        }"#;
+
+        let err = Error::from_response(content).unwrap();
+
+        assert_eq!(err.update_id, Some(2));
+        assert_eq!(&*err.path, "message");
+        assert!(err.snippet.contains("unknown_origin"));
+        assert!(err
+            .source
+            .to_string()
+            .contains("as `Text` at `external_reply`"));
+
+        let err = err.to_string();
+        assert!(err.starts_with("Cannot parse update 2 at `message`"));
+
+        assert!(Error::from_response("not json").is_none());
+        assert!(Error::from_response(r#"{"ok": true, "result": []}"#).is_none());
+    }
+
+    #[test]
+    fn test_snippet() {
+        assert_eq!(snippet("short"), "short");
+
+        let content = "ы".repeat(SNIPPET_MAX_LEN);
+        let snippet = snippet(&content);
+
+        assert!(snippet.ends_with("..."));
+        assert!(snippet.len() <= SNIPPET_MAX_LEN + 3);
+    }
+}
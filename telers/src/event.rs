@@ -1,9 +1,11 @@
 #![allow(clippy::module_name_repetitions)]
 
 pub mod bases;
+pub mod lifecycle;
 pub mod service;
 pub mod simple;
 pub mod telegram;
 
-pub use bases::{cancel_event, finish_event, skip_event, EventReturn};
+pub use bases::{cancel_event, finish_event, skip_event, FlowControl};
+pub use lifecycle::{Bus as LifecycleBus, Event as LifecycleEvent};
 pub use service::ToServiceProvider;
@@ -4,44 +4,67 @@ use crate::errors::HandlerError;
 
 use std::fmt::{self, Debug, Display, Formatter};
 
-/// Response, which can be returned from handlers, filters and middlewares by user.
-/// This indicates how [`crate::dispatcher::Dispatcher`] should process response.
-/// # Notes
-/// In some cases, some values may represent the same result
-#[derive(Debug, Default, Clone)]
-pub enum EventReturn {
-    Skip,
-    Cancel,
+/// Control flow of the event propagation, which is returned from handlers and middlewares by user.
+/// This indicates how [`crate::dispatcher::Dispatcher`] should continue to process the event.
+///
+/// Each variant has the same meaning for handlers, inner and outer middlewares,
+/// so the propagation stops exactly at the level, which is specified by the variant:
+/// - [`FlowControl::Continue`] - continue processing as usual;
+/// - [`FlowControl::SkipHandler`] - skip the current handler (or the current outer middleware);
+/// - [`FlowControl::StopObserver`] - stop the current observer of the router, but try its sub routers;
+/// - [`FlowControl::StopRouter`] - stop the current router and its sub routers, but try the next routers;
+/// - [`FlowControl::StopPropagation`] - stop the propagation at all.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlowControl {
+    /// Continue processing as usual.
+    /// For outer middlewares, the updated request is passed to the next middleware (or the observer).
+    /// For handlers (and inner middlewares), the event is considered as processed and its propagation is stopped.
     #[default]
-    Finish,
+    Continue,
+    /// Skip the current handler and pass the event to the next handler in order of priority
+    /// (or to the sub routers if there are no more handlers).
+    /// Can be used to process the same update by several handlers.
+    /// For outer middlewares, changes of the request by the middleware are discarded and the next middleware is called.
+    SkipHandler,
+    /// Stop the current observer: the rest handlers of the observer aren't tried,
+    /// and the event is passed to the sub routers of the current router.
+    /// For outer middlewares of `update` observer, the observer of the event in the current router is skipped.
+    StopObserver,
+    /// Stop the current router: the rest handlers of the router and its sub routers aren't tried,
+    /// and the event is passed to the next router of the parent router.
+    StopRouter,
+    /// Stop the event propagation at all, the event is considered as unprocessed
+    StopPropagation,
 }
 
-impl Display for EventReturn {
+impl Display for FlowControl {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Skip => write!(f, "skip"),
-            Self::Cancel => write!(f, "cancel"),
-            Self::Finish => write!(f, "finish"),
+            Self::Continue => write!(f, "continue"),
+            Self::SkipHandler => write!(f, "skip handler"),
+            Self::StopObserver => write!(f, "stop observer"),
+            Self::StopRouter => write!(f, "stop router"),
+            Self::StopPropagation => write!(f, "stop propagation"),
         }
     }
 }
 
-/// Shortcut for [`Ok(EventReturn::Skip)`]
+/// Shortcut for [`Ok(FlowControl::SkipHandler)`]
 #[allow(clippy::missing_errors_doc)]
-pub fn skip_event() -> Result<EventReturn, HandlerError> {
-    Ok(EventReturn::Skip)
+pub fn skip_event() -> Result<FlowControl, HandlerError> {
+    Ok(FlowControl::SkipHandler)
 }
 
-/// Shortcut for [`Ok(EventReturn::Cancel)`]
+/// Shortcut for [`Ok(FlowControl::StopRouter)`]
 #[allow(clippy::missing_errors_doc)]
-pub fn cancel_event() -> Result<EventReturn, HandlerError> {
-    Ok(EventReturn::Cancel)
+pub fn cancel_event() -> Result<FlowControl, HandlerError> {
+    Ok(FlowControl::StopRouter)
 }
 
-/// Shortcut for [`Ok(EventReturn::Finish)`]
+/// Shortcut for [`Ok(FlowControl::Continue)`]
 #[allow(clippy::missing_errors_doc)]
-pub fn finish_event() -> Result<EventReturn, HandlerError> {
-    Ok(EventReturn::Finish)
+pub fn finish_event() -> Result<FlowControl, HandlerError> {
+    Ok(FlowControl::Continue)
 }
 
 /// Response, which can be returned from routers and observers by program.
@@ -0,0 +1,145 @@
+//! This module contains [`Bus`] for lifecycle events of the bot and [`Event`] enum that represents them.
+//!
+//! Unlike [`startup`] and [`shutdown`] observers of the router, lifecycle events aren't related to the router tree
+//! and can be received by any part of the code: middlewares, background tasks, health reporters, etc.
+//! Lifecycle events are typed and contain information about the event, for example,
+//! [`Event::PollingError`] contains the error and the time after which the next request will be sent.
+//!
+//! [`Bus`] is cheap to clone, so you can create it once, pass it to [`DispatcherBuilder::lifecycle_bus`]
+//! and clone it for all subscribers.
+//! Each subscriber gets its own [`Receiver`] by [`Bus::subscribe`] method and receives all events emitted after subscription.
+//!
+//! # Examples
+//! ```rust
+//! use telers::event::{LifecycleBus, LifecycleEvent};
+//!
+//! async fn alerting(bus: LifecycleBus) {
+//!     let mut receiver = bus.subscribe();
+//!
+//!     while let Ok(event) = receiver.recv().await {
+//!         if let LifecycleEvent::PollingError { bot_id, error, retry_in } = event {
+//!             eprintln!("Polling error for bot {bot_id}: {error}, retry in {retry_in:?}");
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! [`startup`]: crate::router::Router#structfield.startup
+//! [`shutdown`]: crate::router::Router#structfield.shutdown
+//! [`DispatcherBuilder::lifecycle_bus`]: crate::dispatcher::Builder#method.lifecycle_bus
+
+use crate::{errors::SessionErrorKind, types::User};
+
+use std::{sync::Arc, time::Duration};
+use tokio::sync::broadcast::{self, Receiver, Sender};
+use tracing::{event, Level};
+
+/// Default capacity of the [`Bus`] channel.
+/// If a subscriber doesn't receive events fast enough, the oldest events will be dropped for it.
+pub const DEFAULT_CAPACITY: usize = 64;
+
+/// Lifecycle events of the bot
+#[derive(Debug, Clone)]
+pub enum Event {
+    /// Startup observers of the main router are emitted successfully
+    Startup,
+    /// Shutdown observers of the main router are emitted successfully
+    Shutdown,
+    /// Polling process is started for the bot
+    PollingStarted { bot_id: i64 },
+    /// Polling process is stopped for the bot
+    PollingStopped { bot_id: i64 },
+    /// Failed to fetch updates for the bot
+    PollingError {
+        bot_id: i64,
+        error: Arc<SessionErrorKind>,
+        /// Time after which the next request will be sent.
+        /// If `None`, the backoff is exhausted and the request will be sent immediately.
+        retry_in: Option<Duration>,
+    },
+    /// Connection to the Telegram Bot API server is established again after [`Event::PollingError`]
+    ConnectionRestored { bot_id: i64 },
+    /// Webhook is set for the bot by [`WebhookManager`](crate::client::WebhookManager) with the lifecycle bus
+    WebhookSet { bot_id: i64, url: Box<str> },
+    /// Bot information is loaded by [`GetMe`](crate::methods::GetMe) method
+    BotInfoLoaded { bot_id: i64, user: User },
+}
+
+/// Bus for lifecycle events.
+/// # Notes
+/// This structure is cheap to clone, because it contains only [`Sender`] of the broadcast channel.
+///
+/// Check [module docs](crate::event::lifecycle) for more information.
+#[derive(Debug, Clone)]
+pub struct Bus {
+    sender: Sender<Event>,
+}
+
+impl Bus {
+    /// Creates a new [`Bus`]
+    /// # Arguments
+    /// * `capacity` - Maximum number of events that can be stored for a subscriber, which doesn't receive them
+    /// # Panics
+    /// If `capacity` is zero
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+
+        Self { sender }
+    }
+
+    /// Subscribes to lifecycle events.
+    /// Subscriber receives all events emitted after subscription.
+    #[must_use]
+    pub fn subscribe(&self) -> Receiver<Event> {
+        self.sender.subscribe()
+    }
+
+    /// Emits lifecycle event to all subscribers
+    /// # Returns
+    /// Number of subscribers, which the event was sent to
+    pub fn emit(&self, lifecycle_event: Event) -> usize {
+        event!(Level::TRACE, event = ?lifecycle_event, "Emit lifecycle event");
+
+        // Error is returned only if there are no subscribers, so we can ignore it
+        self.sender.send(lifecycle_event).unwrap_or(0)
+    }
+
+    /// Gets number of subscribers
+    #[must_use]
+    pub fn subscribers_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+}
+
+impl Default for Bus {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_emit() {
+        let bus = Bus::default();
+
+        // There are no subscribers, so the event will be dropped
+        assert_eq!(bus.emit(Event::Startup), 0);
+
+        let mut receiver = bus.subscribe();
+        let mut receiver2 = bus.clone().subscribe();
+
+        assert_eq!(bus.subscribers_count(), 2);
+        assert_eq!(bus.emit(Event::PollingStarted { bot_id: 1 }), 2);
+
+        for receiver in [&mut receiver, &mut receiver2] {
+            match receiver.recv().await.unwrap() {
+                Event::PollingStarted { bot_id: 1 } => {}
+                _ => panic!("Unexpected event"),
+            }
+        }
+    }
+}
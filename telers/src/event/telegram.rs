@@ -1,8 +1,10 @@
+pub mod extraction_error_handler;
 pub mod handler;
 pub mod observer;
 
+pub use extraction_error_handler::{ExtractionErrorHandler, ExtractionFailure};
 pub use handler::{
-    handler_service, BoxedHandlerService, Handler, Request as HandlerRequest,
-    Response as HandlerResponse, Result as HandlerResult,
+    handler_service, BoxedHandlerService, Handler, IntoHandlerResult, Metadata as HandlerMetadata,
+    Request as HandlerRequest, Response as HandlerResponse, Result as HandlerResult,
 };
 pub use observer::Observer;
@@ -0,0 +1,151 @@
+//! This module contains [`ExtractionErrorHandler`], which is used to customize the response,
+//! when arguments of the handler can't be extracted (see [`FromEventAndContext`]).
+//!
+//! By default, the extraction error is returned from the observer as [`EventErrorKind::Extraction`] and logged
+//! with the type of the handler arguments.
+//! Register the error handler in the observer with [`Observer::extraction_error_handler`] method
+//! to answer the user (for example, answer the callback query with "something went wrong"),
+//! or to log the error with additional information.
+//!
+//! The error handler receives [`ExtractionFailure`] with the error, the type of the handler arguments and the handler name.
+//! If it returns [`FlowControl`], it replaces the handler result, otherwise the error is propagated as before.
+//!
+//! ```ignore
+//! router.callback_query.extraction_error_handler(
+//!     |request: HandlerRequest, failure: ExtractionFailure| async move {
+//!         let UpdateKind::CallbackQuery(query) = request.update.kind() else {
+//!             return Err(failure.error);
+//!         };
+//!
+//!         match request
+//!             .bot
+//!             .send(AnswerCallbackQuery::new(&query.id).text("Something went wrong"))
+//!             .await
+//!         {
+//!             Ok(_) => Ok(FlowControl::Continue),
+//!             Err(_) => Err(failure.error),
+//!         }
+//!     },
+//! );
+//! ```
+//!
+//! [`FromEventAndContext`]: crate::extractors::FromEventAndContext
+//! [`EventErrorKind::Extraction`]: crate::errors::EventErrorKind::Extraction
+//! [`Observer::extraction_error_handler`]: super::Observer#method.extraction_error_handler
+
+use super::handler::{BoxedHandlerService, Request, Response};
+
+use crate::{
+    client::DefaultSession,
+    errors::ExtractionError,
+    event::{
+        service::{BoxFuture, Service},
+        FlowControl,
+    },
+};
+
+use async_trait::async_trait;
+use std::{future::Future, sync::Arc};
+
+/// Information about the failed extraction of the handler arguments
+#[derive(Debug)]
+pub struct ExtractionFailure {
+    pub error: ExtractionError,
+    /// Type of the handler arguments
+    pub args: &'static str,
+    /// Name of the handler, if it's set
+    pub handler_name: Option<Box<str>>,
+}
+
+/// Implement this trait to customize the response, when arguments of the handler can't be extracted.
+/// Check the [module](self) documentation for more information.
+#[async_trait]
+pub trait ExtractionErrorHandler<Client = DefaultSession>: Send + Sync {
+    /// Handle the failed extraction
+    /// # Returns
+    /// [`FlowControl`], which replaces the handler result
+    /// # Errors
+    /// Extraction error, if it should be propagated as before
+    async fn handle(
+        &self,
+        request: Request<Client>,
+        failure: ExtractionFailure,
+    ) -> Result<FlowControl, ExtractionError>;
+}
+
+#[async_trait]
+impl<Client, T: ?Sized> ExtractionErrorHandler<Client> for Arc<T>
+where
+    Client: Send + Sync + 'static,
+    T: ExtractionErrorHandler<Client>,
+{
+    async fn handle(
+        &self,
+        request: Request<Client>,
+        failure: ExtractionFailure,
+    ) -> Result<FlowControl, ExtractionError> {
+        T::handle(self, request, failure).await
+    }
+}
+
+/// To possible use function-like as extraction error handlers
+#[async_trait]
+impl<Client, Func, Fut> ExtractionErrorHandler<Client> for Func
+where
+    Client: Send + Sync + 'static,
+    Func: Fn(Request<Client>, ExtractionFailure) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<FlowControl, ExtractionError>> + Send,
+{
+    async fn handle(
+        &self,
+        request: Request<Client>,
+        failure: ExtractionFailure,
+    ) -> Result<FlowControl, ExtractionError> {
+        self(request, failure).await
+    }
+}
+
+/// Handler service, which calls the extraction error handler, when arguments of the inner service can't be extracted
+pub(super) struct HandlerService<Client> {
+    pub(super) inner: Arc<BoxedHandlerService<Client>>,
+    pub(super) error_handler: Arc<dyn ExtractionErrorHandler<Client>>,
+    pub(super) args: &'static str,
+    pub(super) handler_name: Option<Box<str>>,
+}
+
+impl<Client> Service<Request<Client>> for HandlerService<Client>
+where
+    Client: Send + Sync + 'static,
+{
+    type Response = Response<Client>;
+    type Error = ExtractionError;
+    type Future = BoxFuture<Result<Self::Response, Self::Error>>;
+
+    fn call(&self, request: Request<Client>) -> Self::Future {
+        let future = self.inner.call(request.clone());
+        let error_handler = Arc::clone(&self.error_handler);
+        let args = self.args;
+        let handler_name = self.handler_name.clone();
+
+        Box::pin(async move {
+            let error = match future.await {
+                Err(error) => error,
+                result => return result,
+            };
+
+            let failure = ExtractionFailure {
+                error,
+                args,
+                handler_name,
+            };
+
+            error_handler
+                .handle(request.clone(), failure)
+                .await
+                .map(|event_return| Response {
+                    request,
+                    handler_result: Ok(event_return),
+                })
+        })
+    }
+}
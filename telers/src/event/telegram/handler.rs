@@ -1,10 +1,11 @@
 use crate::{
-    client::Reqwest,
+    client::{DefaultSession, Session},
     event::{
         service::{
-            factory, fn_service, BoxFuture, BoxService, BoxServiceFactory, Service, ServiceFactory,
+            factory, fn_service, service, BoxFuture, BoxService, BoxServiceFactory, Service,
+            ServiceFactory,
         },
-        EventReturn,
+        FlowControl,
     },
 };
 
@@ -13,15 +14,24 @@ use crate::{
     context::Context,
     errors::{ExtractionError, HandlerError},
     extractors::FromEventAndContext,
-    filters::Filter,
-    types::Update,
+    filters::{Command, Filter},
+    router::{HandlerDescription, Stage, Timings},
+    types::{BotCommandScope, Update},
+};
+
+use super::extraction_error_handler::{
+    ExtractionErrorHandler, HandlerService as ErrorHandlingService,
 };
 
 use std::{
+    any::{type_name, Any},
     fmt::{self, Debug, Formatter},
     future::Future,
     result::Result as StdResult,
-    sync::Arc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
 };
 use tracing::{event, instrument, Level};
 
@@ -30,7 +40,7 @@ pub type BoxedHandlerService<Client> =
 pub type BoxedHandlerServiceFactory<Client> =
     BoxServiceFactory<(), Request<Client>, Response<Client>, ExtractionError, ()>;
 
-pub struct Request<Client = Reqwest> {
+pub struct Request<Client = DefaultSession> {
     pub bot: Arc<Bot<Client>>,
     pub update: Arc<Update>,
     pub context: Arc<Context>,
@@ -75,9 +85,65 @@ impl<Client> Clone for Request<Client> {
     }
 }
 
-pub type Result = StdResult<EventReturn, HandlerError>;
+pub type Result = StdResult<FlowControl, HandlerError>;
+
+/// Conversion of the handler output into [`Result`].
+/// It's used by the [`handler`] attribute, so handlers can return `()`, [`FlowControl`]
+/// or `Result` with any error, which can be converted into [`HandlerError`] (for example, [`anyhow::Error`]),
+/// and use `?` operator with it.
+///
+/// [`handler`]: crate::handler
+pub trait IntoHandlerResult {
+    /// Converts the handler output into [`Result`]
+    /// # Errors
+    /// If the handler output is an error
+    fn into_handler_result(self) -> Result;
+}
+
+/// Handler without a return value finishes the event
+impl IntoHandlerResult for () {
+    #[inline]
+    fn into_handler_result(self) -> Result {
+        Ok(FlowControl::Continue)
+    }
+}
+
+impl IntoHandlerResult for FlowControl {
+    #[inline]
+    fn into_handler_result(self) -> Result {
+        Ok(self)
+    }
+}
+
+impl<T, E> IntoHandlerResult for StdResult<T, E>
+where
+    T: IntoHandlerResult,
+    E: Into<HandlerError>,
+{
+    #[inline]
+    fn into_handler_result(self) -> Result {
+        match self {
+            Ok(val) => val.into_handler_result(),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+/// Metadata of the handler, which is generated by the [`handler`] attribute.
+/// Apply it to the handler with [`HandlerObject::metadata`].
+///
+/// [`handler`]: crate::handler
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Metadata {
+    /// Name of the handler, which is used for introspection
+    pub name: Option<&'static str>,
+    /// Description of the handler commands in the bot command menu
+    pub description: Option<&'static str>,
+    /// Commands, which are handled by the handler
+    pub commands: &'static [&'static str],
+}
 
-pub struct Response<Client = Reqwest> {
+pub struct Response<Client = DefaultSession> {
     pub request: Request<Client>,
     pub handler_result: Result,
 }
@@ -102,7 +168,33 @@ pub trait Handler<Args> {
 pub struct HandlerObject<Client> {
     service: BoxedHandlerServiceFactory<Client>,
 
+    /// Name of the handler, which is used for introspection (see [`Router::describe`])
+    ///
+    /// [`Router::describe`]: crate::router::Router#method.describe
+    name: Option<Box<str>>,
+    /// Type of the handler arguments, which is used for debugging of extraction errors
+    args: &'static str,
+    /// Keys of the context, which are read by the handler arguments
+    args_context_keys: Box<[&'static str]>,
+    /// Switch to enable and disable the handler at runtime (see [`ServiceHandle`])
+    ///
+    /// [`ServiceHandle`]: crate::router::ServiceHandle
+    enabled: Arc<AtomicBool>,
+    /// Priority of the handler in the observer, handlers with higher priority are checked first
+    priority: i32,
+
     pub filters: Vec<Arc<dyn Filter<Client>>>,
+
+    /// Commands from [`Command`] filters, which can be shown in the bot command menu
+    menu_commands: Vec<Box<str>>,
+    /// Number of [`Command`] filters, which are added by [`HandlerObject::filter`]
+    command_filters: usize,
+    /// Description of the handler commands in the bot command menu
+    description: Option<Box<str>>,
+    /// Scopes of users for which the handler commands are shown in the bot command menu
+    command_scopes: Vec<BotCommandScope>,
+    /// Languages for which the handler commands are shown in the bot command menu
+    command_language_codes: Vec<Box<str>>,
 }
 
 impl<Client> HandlerObject<Client>
@@ -119,7 +211,17 @@ where
     {
         Self {
             service: handler_service(handler),
+            name: None,
+            args: type_name::<Args>(),
+            args_context_keys: Args::context_keys().into(),
+            enabled: Arc::new(AtomicBool::new(true)),
+            priority: 0,
             filters: vec![],
+            menu_commands: vec![],
+            command_filters: 0,
+            description: None,
+            command_scopes: vec![],
+            command_language_codes: vec![],
         }
     }
 }
@@ -129,6 +231,7 @@ impl<Client> HandlerObject<Client> {
     where
         T: Filter<Client> + 'static,
     {
+        self.collect_menu_commands(&val);
         self.filters.push(Arc::new(val));
         self
     }
@@ -138,10 +241,173 @@ impl<Client> HandlerObject<Client> {
         T: Filter<Client> + 'static,
         I: IntoIterator<Item = T>,
     {
-        self.filters
-            .extend(val.into_iter().map(|val| Arc::new(val) as _));
+        for val in val {
+            self.filter(val);
+        }
+        self
+    }
+
+    /// Name of the handler, which is used for introspection (see [`Router::describe`]).
+    /// It's useful for debugging, documentation generation and admin commands that list available handlers.
+    ///
+    /// [`Router::describe`]: crate::router::Router#method.describe
+    pub fn name(&mut self, val: impl Into<Box<str>>) -> &mut Self {
+        self.name = Some(val.into());
+        self
+    }
+
+    /// Whether the handler is enabled initially. By default, it's enabled.
+    /// Disabled handler is skipped as if its filters don't pass.
+    /// # Notes
+    /// Named handlers can be enabled and disabled at runtime with [`ServiceHandle`].
+    ///
+    /// [`ServiceHandle`]: crate::router::ServiceHandle
+    pub fn enabled(&mut self, val: bool) -> &mut Self {
+        self.enabled.store(val, Ordering::Relaxed);
+        self
+    }
+
+    /// Priority of the handler in the observer. By default, it's `0`.
+    /// Handlers with higher priority are checked first, handlers with the same priority are checked in order of registration.
+    /// # Notes
+    /// Handler can return [`FlowControl::SkipHandler`] to pass the update to the next handler even after its filters are passed,
+    /// so several handlers can process the same update in order of their priorities.
+    ///
+    /// [`FlowControl::SkipHandler`]: crate::event::FlowControl::SkipHandler
+    pub fn priority(&mut self, val: i32) -> &mut Self {
+        self.priority = val;
+        self
+    }
+
+    /// Description of the handler commands in the bot command menu.
+    /// Commands are taken from [`Command`] filters of the handler.
+    /// If description is set, the commands will be registered in the menu by the dispatcher
+    /// (see [`DispatcherBuilder::register_commands`]).
+    /// # Notes
+    /// Only [`Command`] filters passed directly to [`HandlerObject::filter`] and [`HandlerObject::filters`] are used,
+    /// commands from combined filters (for example, with [`Filter::or`]) aren't taken.
+    ///
+    /// [`DispatcherBuilder::register_commands`]: crate::dispatcher::Builder#method.register_commands
+    pub fn description(&mut self, val: impl Into<Box<str>>) -> &mut Self {
+        self.description = Some(val.into());
+        self
+    }
+
+    /// Scope of users for which the handler commands are shown in the bot command menu.
+    /// # Notes
+    /// You can add multiple scopes by calling this method multiple times.
+    /// If no scopes are added, [`BotCommandScope::default`] is used.
+    pub fn command_scope(&mut self, val: impl Into<BotCommandScope>) -> &mut Self {
+        self.command_scopes.push(val.into());
+        self
+    }
+
+    /// A two-letter ISO 639-1 language code for which the handler commands are shown in the bot command menu.
+    /// # Notes
+    /// You can add multiple languages by calling this method multiple times.
+    /// If no languages are added, commands are shown for all users from the scope,
+    /// for whose language there are no dedicated commands.
+    pub fn command_language_code(&mut self, val: impl Into<Box<str>>) -> &mut Self {
+        self.command_language_codes.push(val.into());
+        self
+    }
+
+    /// Apply the metadata, which is generated by the [`handler`] attribute.
+    /// Name and description are set, if they are present,
+    /// and [`Command`] filter is added with the commands, if they are present,
+    /// so the commands are shown in the bot command menu too.
+    ///
+    /// [`handler`]: crate::handler
+    pub fn metadata(&mut self, val: Metadata) -> &mut Self
+    where
+        Client: Session,
+    {
+        if let Some(name) = val.name {
+            self.name(name);
+        }
+        if let Some(description) = val.description {
+            self.description(description);
+        }
+        if !val.commands.is_empty() {
+            self.filter(Command::many(val.commands.iter().copied()));
+        }
         self
     }
+
+    #[must_use]
+    pub fn handler_name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Type name of the handler arguments
+    #[must_use]
+    pub const fn handler_args(&self) -> &'static str {
+        self.args
+    }
+
+    /// Keys of the context, which are read by the handler arguments and filters
+    #[must_use]
+    pub fn context_keys(&self) -> Vec<&'static str> {
+        self.args_context_keys
+            .iter()
+            .copied()
+            .chain(self.filters.iter().flat_map(|filter| filter.context_keys()))
+            .collect()
+    }
+
+    /// Check if all filters of the handler are [`Command`] filters
+    #[must_use]
+    pub fn has_only_command_filters(&self) -> bool {
+        !self.filters.is_empty() && self.filters.len() == self.command_filters
+    }
+
+    #[must_use]
+    pub const fn handler_priority(&self) -> i32 {
+        self.priority
+    }
+
+    #[must_use]
+    pub fn describe(&self) -> HandlerDescription {
+        HandlerDescription {
+            name: self.name.clone(),
+            priority: self.priority,
+            filters: self.filters.iter().map(|filter| filter.name()).collect(),
+            commands: self.menu_commands.clone().into(),
+        }
+    }
+
+    #[must_use]
+    pub fn menu_commands(&self) -> &[Box<str>] {
+        &self.menu_commands
+    }
+
+    #[must_use]
+    pub fn command_description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    #[must_use]
+    pub fn command_scopes(&self) -> &[BotCommandScope] {
+        &self.command_scopes
+    }
+
+    #[must_use]
+    pub fn command_language_codes(&self) -> &[Box<str>] {
+        &self.command_language_codes
+    }
+
+    fn collect_menu_commands<T: 'static>(&mut self, filter: &T) {
+        if let Some(command) = (filter as &dyn Any).downcast_ref::<Command<'static>>() {
+            self.command_filters += 1;
+            self.menu_commands
+                .extend(command.menu_commands().into_iter().map(Into::into));
+        }
+    }
 }
 
 impl<Client> ServiceFactory<Request<Client>> for HandlerObject<Client> {
@@ -156,6 +422,9 @@ impl<Client> ServiceFactory<Request<Client>> for HandlerObject<Client> {
 
         Ok(HandlerObjectService {
             service: Arc::new(service),
+            args: self.args,
+            name: self.name.clone(),
+            enabled: Arc::clone(&self.enabled),
             filters: self.filters.clone().into(),
         })
     }
@@ -164,29 +433,77 @@ impl<Client> ServiceFactory<Request<Client>> for HandlerObject<Client> {
 #[allow(clippy::module_name_repetitions)]
 pub struct HandlerObjectService<Client> {
     pub(crate) service: Arc<BoxedHandlerService<Client>>,
+    args: &'static str,
+    pub(crate) name: Option<Box<str>>,
+    pub(crate) enabled: Arc<AtomicBool>,
     filters: Box<[Arc<dyn Filter<Client>>]>,
 }
 
+impl<Client> HandlerObjectService<Client>
+where
+    Client: Send + Sync + 'static,
+{
+    /// Wrap the handler service, so the extraction error handler is called,
+    /// when arguments of the handler can't be extracted
+    pub(super) fn with_extraction_error_handler(
+        self,
+        error_handler: Arc<dyn ExtractionErrorHandler<Client>>,
+    ) -> Self {
+        Self {
+            service: Arc::new(service::<_, Request<Client>>(ErrorHandlingService {
+                inner: self.service,
+                error_handler,
+                args: self.args,
+                handler_name: self.name.clone(),
+            })),
+            ..self
+        }
+    }
+}
+
 impl<Client> HandlerObjectService<Client>
 where
     Client: Sync,
 {
-    /// Check if the handler pass the filters.
+    /// Check if the handler is enabled and pass the filters.
     /// If the handler pass all them, it will be called.
     #[instrument(skip(self, request))]
     pub async fn check(&self, request: &Request<Client>) -> bool {
+        self.rejection(request).await.is_none()
+    }
+
+    /// Check if the handler is enabled and pass the filters
+    /// # Returns
+    /// Reason why the handler is rejected or `None` if the handler pass all filters
+    pub(crate) async fn rejection(&self, request: &Request<Client>) -> Option<Rejection> {
+        if !self.enabled.load(Ordering::Relaxed) {
+            event!(Level::TRACE, name = ?self.name, "Handler is disabled");
+
+            return Some(Rejection::Disabled);
+        }
+
         for filter in &*self.filters {
-            if !filter
-                .check(&request.bot, &request.update, &request.context)
-                .await
+            if !Timings::time_in(
+                &request.context,
+                Stage::Filters,
+                filter.check(&request.bot, &request.update, &request.context),
+            )
+            .await
             {
-                return false;
+                return Some(Rejection::Filter(filter.name()));
             }
         }
-        true
+        None
     }
 }
 
+/// Reason why the handler is rejected
+pub(crate) enum Rejection {
+    Disabled,
+    /// Filter with the name doesn't pass
+    Filter(&'static str),
+}
+
 impl<Client> Service<Request<Client>> for HandlerObjectService<Client> {
     type Response = Response<Client>;
     type Error = ExtractionError;
@@ -216,10 +533,18 @@ where
         let handler = handler.clone();
 
         async move {
-            match Args::extract(bot, update, context) {
+            match Timings::time_fn_in(&request.context, Stage::Extraction, || {
+                Args::extract(bot, update, context)
+            }) {
                 Ok(extracted_args) => Ok(Response {
+                    handler_result: Timings::time_in(
+                        &request.context,
+                        Stage::Handler,
+                        handler.call(extracted_args),
+                    )
+                    .await
+                    .into(),
                     request,
-                    handler_result: handler.call(extracted_args).await.into(),
                 }),
                 Err(extraction_err) => {
                     let extraction_err = extraction_err.into();
@@ -227,6 +552,7 @@ where
                     event!(
                         Level::ERROR,
                         error = %extraction_err,
+                        args = type_name::<Args>(),
                         bot = ?request.bot,
                         update = ?request.update,
                         context = ?request.context,
@@ -312,7 +638,7 @@ mod tests {
     use super::*;
     use crate::{
         client::Reqwest,
-        event::EventReturn,
+        event::FlowControl,
         filters::Command,
         types::{Message, UpdateKind},
     };
@@ -353,21 +679,66 @@ mod tests {
         let filter = Command::default();
 
         let mut handler_object =
-            HandlerObject::<Reqwest>::new(|| async { Ok(EventReturn::Finish) });
+            HandlerObject::<Reqwest>::new(|| async { Ok(FlowControl::Continue) });
         assert!(handler_object.filters.is_empty());
 
         handler_object.filter(filter.clone());
         assert_eq!(handler_object.filters.len(), 1);
 
         let mut handler_object =
-            HandlerObject::<Reqwest>::new(|| async { Ok(EventReturn::Finish) });
+            HandlerObject::<Reqwest>::new(|| async { Ok(FlowControl::Continue) });
         handler_object.filter(filter);
         assert_eq!(handler_object.filters.len(), 1);
     }
 
+    #[test]
+    fn test_handler_object_metadata() {
+        let mut handler_object =
+            HandlerObject::<Reqwest>::new(|| async { Ok(FlowControl::Continue) });
+        handler_object.metadata(Metadata::default());
+        assert!(handler_object.filters.is_empty());
+        assert_eq!(handler_object.handler_name(), None);
+
+        handler_object.metadata(Metadata {
+            name: Some("start"),
+            description: Some("Start the bot"),
+            commands: &["start", "help"],
+        });
+        assert_eq!(handler_object.filters.len(), 1);
+        assert_eq!(handler_object.handler_name(), Some("start"));
+        assert_eq!(handler_object.command_description(), Some("Start the bot"));
+        assert_eq!(
+            handler_object.menu_commands(),
+            [Box::from("start"), Box::from("help")]
+        );
+    }
+
+    #[test]
+    fn test_into_handler_result() {
+        assert!(matches!(
+            ().into_handler_result(),
+            Ok(FlowControl::Continue)
+        ));
+        assert!(matches!(
+            FlowControl::SkipHandler.into_handler_result(),
+            Ok(FlowControl::SkipHandler)
+        ));
+        assert!(matches!(
+            Ok::<_, HandlerError>(()).into_handler_result(),
+            Ok(FlowControl::Continue)
+        ));
+        assert!(matches!(
+            Ok::<_, anyhow::Error>(FlowControl::StopRouter).into_handler_result(),
+            Ok(FlowControl::StopRouter)
+        ));
+        assert!(Err::<(), _>(anyhow::anyhow!("test"))
+            .into_handler_result()
+            .is_err());
+    }
+
     #[tokio::test]
     async fn test_handler_object_service() {
-        let handler_object = HandlerObject::<Reqwest>::new(|| async { Ok(EventReturn::Finish) });
+        let handler_object = HandlerObject::<Reqwest>::new(|| async { Ok(FlowControl::Continue) });
         let handler_object_service = handler_object.new_service(()).unwrap();
 
         let request = Request::new(
@@ -381,7 +752,7 @@ mod tests {
         let response = handler_object_service.call(request).await.unwrap();
 
         match response.handler_result {
-            Ok(EventReturn::Finish) => {}
+            Ok(FlowControl::Continue) => {}
             _ => panic!("Unexpected result"),
         }
     }
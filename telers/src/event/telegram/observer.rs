@@ -4,11 +4,14 @@ use crate::{
     enums::TelegramObserverName,
     errors::EventErrorKind,
     event::{
-        bases::{EventReturn, PropagateEventResult},
+        bases::{FlowControl, PropagateEventResult},
         service::{Service as _, ServiceFactory as _, ServiceProvider, ToServiceProvider},
-        telegram::handler::{
-            Handler, HandlerObject, HandlerObjectService, Request as HandlerRequest,
-            Result as HandlerResult,
+        telegram::{
+            extraction_error_handler::ExtractionErrorHandler,
+            handler::{
+                Handler, HandlerObject, HandlerObjectService, Rejection, Request as HandlerRequest,
+                Result as HandlerResult,
+            },
         },
     },
     extractors::FromEventAndContext,
@@ -20,12 +23,14 @@ use crate::{
         },
         outer::{Manager as OuterMiddlewareManager, Middleware as OuterMiddleware},
     },
+    router::{ObserverDescription, RoutingReport, RoutingStep, Stage, Timings},
     types::Update,
 };
 
 use std::{
+    cmp::Reverse,
     fmt::{self, Debug, Formatter},
-    sync::Arc,
+    sync::{atomic::AtomicBool, Arc},
 };
 use tracing::{event, instrument, Level};
 
@@ -100,6 +105,7 @@ pub struct Observer<Client> {
 
     handlers: Vec<HandlerObject<Client>>,
     common: Box<HandlerObject<Client>>,
+    extraction_error_handler: Option<Arc<dyn ExtractionErrorHandler<Client>>>,
 
     pub inner_middlewares: InnerMiddlewareManager<Client>,
     pub outer_middlewares: OuterMiddlewareManager<Client>,
@@ -121,16 +127,27 @@ impl<Client> Observer<Client> {
                     unreachable!("This handler never will be used");
                 }) as Result<_, _>
             })),
+            extraction_error_handler: None,
             inner_middlewares: InnerMiddlewareManager::<Client>::default(),
             outer_middlewares: OuterMiddlewareManager::<Client>::default(),
         }
     }
 
+    /// Get handlers in order of registration
     #[must_use]
     pub fn handlers(&self) -> &[HandlerObject<Client>] {
         &self.handlers
     }
 
+    /// Get handlers in order of checking: by priority from higher to lower and in order of registration for the same priority
+    #[must_use]
+    pub fn handlers_by_priority(&self) -> Vec<&HandlerObject<Client>> {
+        let mut handlers = self.handlers.iter().collect::<Vec<_>>();
+        // Sort is stable, so handlers with the same priority keep order of registration
+        handlers.sort_by_key(|handler| Reverse(handler.handler_priority()));
+        handlers
+    }
+
     #[allow(clippy::missing_panics_doc)]
     pub fn register<H, Args>(&mut self, handler: H) -> &mut HandlerObject<Client>
     where
@@ -159,6 +176,30 @@ impl<Client> Observer<Client> {
         self.register(handler)
     }
 
+    /// Register handler, which is called when arguments of any handler in the observer can't be extracted.
+    /// Check [`ExtractionErrorHandler`] for more information.
+    pub fn extraction_error_handler<T>(&mut self, val: T) -> &mut Self
+    where
+        T: ExtractionErrorHandler<Client> + 'static,
+    {
+        self.extraction_error_handler = Some(Arc::new(val));
+        self
+    }
+
+    /// Describe the observer with its filters and handlers for introspection
+    #[must_use]
+    pub fn describe(&self) -> ObserverDescription {
+        ObserverDescription {
+            name: self.event_name.into(),
+            filters: self.common.describe().filters,
+            handlers: self
+                .handlers_by_priority()
+                .into_iter()
+                .map(HandlerObject::describe)
+                .collect(),
+        }
+    }
+
     /// Register filter for all handlers in the observer
     pub fn filter<T>(&mut self, val: T) -> &mut Self
     where
@@ -204,7 +245,10 @@ impl<Client> AsRef<Observer<Client>> for Observer<Client> {
     }
 }
 
-impl<Client> ToServiceProvider for Observer<Client> {
+impl<Client> ToServiceProvider for Observer<Client>
+where
+    Client: Send + Sync + 'static,
+{
     type Config = ();
     type ServiceProvider = Service<Client>;
     type InitError = ();
@@ -216,9 +260,18 @@ impl<Client> ToServiceProvider for Observer<Client> {
         Ok(Service {
             event_name: self.event_name,
             handlers: self
-                .handlers
-                .iter()
-                .map(|handler| handler.new_service(config))
+                .handlers_by_priority()
+                .into_iter()
+                .map(|handler| {
+                    handler
+                        .new_service(config)
+                        .map(|service| match self.extraction_error_handler {
+                            Some(ref error_handler) => {
+                                service.with_extraction_error_handler(Arc::clone(error_handler))
+                            }
+                            None => service,
+                        })
+                })
                 .collect::<Result<_, _>>()?,
             common: self.common.new_service(config)?,
             inner_middlewares: self.inner_middlewares.middlewares.into(),
@@ -240,8 +293,21 @@ pub struct Service<Client> {
 impl<Client> ServiceProvider for Service<Client> {}
 
 impl<Client> Service<Client> {
-    /// Propagate event to handlers and stops propagation on first match.
+    /// Get switches of the named handlers to enable and disable them at runtime
+    pub(crate) fn handler_switches(&self) -> impl Iterator<Item = (&str, Arc<AtomicBool>)> {
+        self.handlers.iter().filter_map(|handler| {
+            handler
+                .name
+                .as_deref()
+                .map(|name| (name, Arc::clone(&handler.enabled)))
+        })
+    }
+
+    /// Propagate event to handlers in order of their priorities and stops propagation on first match.
     /// Handler will be called when all its filters is pass.
+    /// If the handler returns [`FlowControl::SkipHandler`], the event is propagated to the next handlers.
+    /// If the handler returns [`FlowControl::StopObserver`], the rest handlers are skipped and the response is unhandled.
+    /// Other flows are returned in the handled response, so the router can stop the propagation by them.
     /// # Errors
     /// - If any handler returns error. Probably it's error to extract args to the handler.
     #[instrument(skip(self, request))]
@@ -254,10 +320,22 @@ impl<Client> Service<Client> {
     {
         let handler_request: HandlerRequest<Client> = request.clone().into();
 
+        if !self.handlers.is_empty() {
+            RoutingReport::record_in(&request.context, || RoutingStep::Observer {
+                name: self.event_name.into(),
+            });
+        }
+
         // Check observer filters
-        if !self.common.check(&handler_request).await {
+        if let Some(rejection) = self.common.rejection(&handler_request).await {
             event!(Level::TRACE, "Request are not pass observer filters");
 
+            if let Rejection::Filter(filter) = rejection {
+                RoutingReport::record_in(&request.context, || {
+                    RoutingStep::ObserverFilterRejected { filter }
+                });
+            }
+
             return Ok(Response {
                 request,
                 propagate_result: PropagateEventResult::Rejected,
@@ -266,10 +344,24 @@ impl<Client> Service<Client> {
 
         // Check handlers filters
         for handler in &*self.handlers {
-            if !handler.check(&handler_request).await {
+            if let Some(rejection) = handler.rejection(&handler_request).await {
+                RoutingReport::record_in(&request.context, || match rejection {
+                    Rejection::Disabled => RoutingStep::HandlerDisabled {
+                        handler: handler.name.clone(),
+                    },
+                    Rejection::Filter(filter) => RoutingStep::HandlerFilterRejected {
+                        handler: handler.name.clone(),
+                        filter,
+                    },
+                });
+
                 continue;
             }
 
+            RoutingReport::record_in(&request.context, || RoutingStep::HandlerCalled {
+                handler: handler.name.clone(),
+            });
+
             event!(Level::TRACE, "Request are pass handler filters");
 
             let response = match self.inner_middlewares.split_first() {
@@ -278,7 +370,13 @@ impl<Client> Service<Client> {
                         Arc::clone(&handler.service),
                         middlewares.to_vec().into_boxed_slice(), // we use it instead of `into` because some versions of rustc can't infer type
                     ));
-                    middleware.call(handler_request.clone(), next).await
+                    Timings::time_excluding_in(
+                        &request.context,
+                        Stage::InnerMiddlewares,
+                        &[Stage::Extraction, Stage::Handler],
+                        middleware.call(handler_request.clone(), next),
+                    )
+                    .await
                 }
                 None => handler
                     .call(handler_request.clone())
@@ -288,23 +386,29 @@ impl<Client> Service<Client> {
 
             return match response.handler_result {
                 // If the handler or middleware returns skip, then we should skip it
-                Ok(EventReturn::Skip) => {
+                Ok(FlowControl::SkipHandler) => {
                     event!(Level::TRACE, "Handler returns skip");
 
                     continue;
                 }
-                // If the handler or middleware returns cancel, then we should stop propagation
-                Ok(EventReturn::Cancel) => {
-                    event!(Level::TRACE, "Handler returns cancel");
+                // If the handler or middleware returns stop observer, then we should skip the rest handlers
+                // and let the router propagate the event further
+                Ok(FlowControl::StopObserver) => {
+                    event!(Level::TRACE, "Handler returns stop observer");
 
                     Ok(Response {
                         request,
-                        propagate_result: PropagateEventResult::Rejected,
+                        propagate_result: PropagateEventResult::Unhandled,
                     })
                 }
-                // If the handler or middleware returns finish, then we should stop propagation and return a response
-                Ok(EventReturn::Finish) => {
-                    event!(Level::TRACE, "Handler returns finish");
+                // If the handler or middleware returns continue, then we should stop propagation and return a response.
+                // If it returns stop router or stop propagation, then the router stops propagation by the response.
+                Ok(
+                    flow @ (FlowControl::Continue
+                    | FlowControl::StopRouter
+                    | FlowControl::StopPropagation),
+                ) => {
+                    event!(Level::TRACE, %flow, "Handler returns");
 
                     Ok(Response {
                         request,
@@ -372,11 +476,11 @@ mod tests {
         let mut observer = Observer::default();
         // Register common filter, which handlers can't pass
         observer.filter(Command::one("start"));
-        observer.register(|| async { Ok(EventReturn::Finish) });
+        observer.register(|| async { Ok(FlowControl::Continue) });
         observer.register(|| async {
             unreachable!("It's shouldn't trigger because the first handler handles the event");
 
-            Ok(EventReturn::Finish)
+            Ok(FlowControl::Continue)
         });
 
         let observer_service = observer.to_service_provider_default().unwrap();
@@ -421,7 +525,7 @@ mod tests {
         observer.register(|| async {
             unreachable!("It's shouldn't trigger because the first handler handles the event");
 
-            Ok(EventReturn::Finish)
+            Ok(FlowControl::Continue)
         });
 
         let observer_service = observer.to_service_provider_default().unwrap();
@@ -445,8 +549,8 @@ mod tests {
     #[tokio::test]
     async fn test_observer_event_return() {
         let mut observer = Observer::default();
-        observer.register(|| async { Ok(EventReturn::Skip) });
-        observer.register(|| async { Ok(EventReturn::Finish) });
+        observer.register(|| async { Ok(FlowControl::SkipHandler) });
+        observer.register(|| async { Ok(FlowControl::Continue) });
 
         let observer_service = observer.to_service_provider_default().unwrap();
 
@@ -457,27 +561,134 @@ mod tests {
         );
         let response = observer_service.trigger(request.clone()).await.unwrap();
 
-        // First handler returns `EventReturn::Skip`, so second handler should be called
+        // First handler returns `FlowControl::SkipHandler`, so second handler should be called
         match response.propagate_result {
             PropagateEventResult::Handled(response) => match response.handler_result {
-                Ok(EventReturn::Finish) => {}
+                Ok(FlowControl::Continue) => {}
                 _ => panic!("Unexpected result"),
             },
             _ => panic!("Unexpected result"),
         }
 
         let mut observer = Observer::default();
-        observer.register(|| async { Ok(EventReturn::Skip) });
-        observer.register(|| async { Ok(EventReturn::Cancel) });
+        observer.register(|| async { Ok(FlowControl::SkipHandler) });
+        observer.register(|| async { Ok(FlowControl::StopObserver) });
+        observer.register(|| async { Ok(FlowControl::Continue) });
+
+        let observer_service = observer.to_service_provider_default().unwrap();
+
+        let response = observer_service.trigger(request.clone()).await.unwrap();
+
+        // Second handler returns `FlowControl::StopObserver`, so the third handler shouldn't be called
+        // and response should be `PropagateEventResult::Unhandled`
+        match response.propagate_result {
+            PropagateEventResult::Unhandled => {}
+            _ => panic!("Unexpected result"),
+        }
+
+        let mut observer = Observer::default();
+        observer.register(|| async { Ok(FlowControl::StopRouter) });
+        observer.register(|| async { Ok(FlowControl::Continue) });
 
         let observer_service = observer.to_service_provider_default().unwrap();
 
         let response = observer_service.trigger(request).await.unwrap();
 
-        // First handler returns `EventReturn::Skip`, so second handler should be called and it returns `EventReturn::Cancel`,
-        // so response should be `PropagateEventResult::Rejected`
+        // Handler returns `FlowControl::StopRouter`, so the router should stop propagation by the handled response
         match response.propagate_result {
-            PropagateEventResult::Rejected => {}
+            PropagateEventResult::Handled(response) => match response.handler_result {
+                Ok(FlowControl::StopRouter) => {}
+                _ => panic!("Unexpected result"),
+            },
+            _ => panic!("Unexpected result"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_observer_handler_priority() {
+        use std::sync::Mutex;
+
+        let calls = Arc::new(Mutex::new(vec![]));
+
+        let mut observer = Observer::default();
+        for (name, priority) in [("low", -1), ("default", 0), ("high", 10), ("default_2", 0)] {
+            let calls = calls.clone();
+            observer
+                .register(move || {
+                    let calls = calls.clone();
+                    async move {
+                        calls.lock().unwrap().push(name);
+                        Ok(FlowControl::SkipHandler)
+                    }
+                })
+                .priority(priority);
+        }
+
+        let observer_service = observer.to_service_provider_default().unwrap();
+
+        let request = Request::new(
+            Arc::new(Bot::<Reqwest>::default()),
+            Arc::new(Update::default()),
+            Arc::new(Context::default()),
+        );
+        let response = observer_service.trigger(request).await.unwrap();
+
+        // All handlers return `FlowControl::SkipHandler`, so all of them should be called in order of priority
+        match response.propagate_result {
+            PropagateEventResult::Unhandled => {}
+            _ => panic!("Unexpected result"),
+        }
+        assert_eq!(
+            *calls.lock().unwrap(),
+            ["high", "default", "default_2", "low"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_extraction_error_handler() {
+        use crate::{errors::ExtractionError, event::telegram::ExtractionFailure, extractors::Me};
+
+        let request = Request::new(
+            Arc::new(Bot::<Reqwest>::default()),
+            Arc::new(Update::default()),
+            Arc::new(Context::default()),
+        );
+
+        // Bot information isn't loaded, so `Me` can't be extracted
+        let mut observer = Observer::default();
+        observer
+            .register(|_: Me| async { Ok(FlowControl::Continue) })
+            .name("me");
+
+        let observer_service = observer.to_service_provider_default().unwrap();
+        assert!(matches!(
+            observer_service.trigger(request.clone()).await,
+            Err(EventErrorKind::Extraction(_))
+        ));
+
+        let mut observer = Observer::default();
+        observer
+            .register(|_: Me| async { Ok(FlowControl::Continue) })
+            .name("me");
+        observer.extraction_error_handler(
+            |_: HandlerRequest<Reqwest>, failure: ExtractionFailure| async move {
+                assert!(failure.args.ends_with("Me,)"));
+                assert_eq!(failure.handler_name.as_deref(), Some("me"));
+
+                Ok::<_, ExtractionError>(FlowControl::Continue)
+            },
+        );
+
+        let observer_service = observer.to_service_provider_default().unwrap();
+        match observer_service
+            .trigger(request)
+            .await
+            .unwrap()
+            .propagate_result
+        {
+            PropagateEventResult::Handled(response) => {
+                assert!(matches!(response.handler_result, Ok(FlowControl::Continue)));
+            }
             _ => panic!("Unexpected result"),
         }
     }
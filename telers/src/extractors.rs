@@ -6,6 +6,15 @@
 //! The trait also is implemented for `Option<T>`, `Result<T, E>` where `T: FromEventAndContext`,
 //! so you can don't implement it for your types if you want to use them as optional or result arguments.
 //!
+//! Bot information, which is cached by [`Bot::get_me`] method, can be extracted with [`Me`] extractor.
+//! ID and tag of the bot, which received the update, can be extracted with [`BotId`] and [`BotTag`] extractors.
+//! The most common identifiers of the update can be extracted with [`ChatId`], [`UserId`], [`MessageId`] and [`ThreadId`] extractors,
+//! check [`types`] module for more details.
+//! Callback query with the accessible message, which originated it, can be extracted with [`CallbackMessage`] extractor.
+//! Locale of the user can be extracted with [`Locale`] extractor.
+//!
+//! Arguments of the command can be parsed with [`CommandArgs`] extractor, check [`command_args`] module for more details.
+//!
 //! # Implementing trait
 //!
 //! Ways to implement [`FromEventAndContext`] for your own types:
@@ -161,6 +170,29 @@
 //! }
 //! ```
 //!
+//! The update is shared between middlewares, filters and handlers by [`Arc`], so conversion from [`Update`] clones the whole update.
+//! To clone only the needed part of the update, use `#[event(try_from = &Update)]` (or `#[event(from = &Update)]`)
+//! and implement the conversion from the reference, as all event types of the library do:
+//!
+//! ```rust
+//! use telers::{types::{Update, UpdateKind}, extractors::FromEvent, errors::ConvertToTypeError};
+//!
+//! #[derive(FromEvent)]
+//! #[event(try_from = &Update)]
+//! struct InlineQueryText(Box<str>);
+//!
+//! impl TryFrom<&Update> for InlineQueryText {
+//!  type Error = ConvertToTypeError;
+//!
+//!  fn try_from(update: &Update) -> Result<Self, Self::Error> {
+//!   match &update.kind {
+//!    UpdateKind::InlineQuery(query) => Ok(Self(query.query.clone())),
+//!    _ => Err(ConvertToTypeError::new("Update", "InlineQueryText")),
+//!   }
+//!  }
+//! }
+//! ```
+//!
 //! ## Implementing with [`FromContext`] macro
 //!
 //! Simple example with extracting struct by key from [`Context`]:
@@ -242,19 +274,26 @@
 //!
 //! This code similar to the previous one, but more useful in cases when `from` type is a foreign type.
 
-pub use crate::{FromContext, FromEvent};
+pub mod command_args;
+#[doc(hidden)]
+pub mod event_path;
+pub mod types;
+
+pub use crate::{FromCommandArgs, FromContext, FromEvent};
+pub use command_args::{CommandArgs, FromCommandArgs};
+pub use types::{CallbackMessage, ChatId, MessageId, ThreadId, UserId};
 
 use crate::{
-    client::{Bot, Reqwest},
+    client::{Bot, DefaultSession},
     context::Context,
     errors::ExtractionError,
-    types::Update,
+    types::{Update, User},
 };
 
-use std::{convert::Infallible, sync::Arc};
+use std::{convert::Infallible, ops::Deref, sync::Arc};
 
 /// Trait for extracting data from [`Update`] and [`Context`] to handlers arguments
-pub trait FromEventAndContext<Client = Reqwest>: Sized {
+pub trait FromEventAndContext<Client = DefaultSession>: Sized {
     type Error: Into<ExtractionError>;
 
     /// Extracts data from [`Update`], [`Context`] and [`Bot`] to handler argument
@@ -270,6 +309,16 @@ pub trait FromEventAndContext<Client = Reqwest>: Sized {
         update: Arc<Update>,
         context: Arc<Context>,
     ) -> Result<Self, Self::Error>;
+
+    /// Keys of the context, which the extractor reads, for example, `fsm_context` for [`FSMContext`].
+    /// It's used for validation of the router configuration (see [`Router::validate`]).
+    ///
+    /// [`FSMContext`]: crate::fsm::Context
+    /// [`Router::validate`]: crate::router::Router#method.validate
+    #[must_use]
+    fn context_keys() -> Vec<&'static str> {
+        vec![]
+    }
 }
 
 /// To be able to use [`Option`] as handler argument
@@ -288,6 +337,10 @@ impl<Client, T: FromEventAndContext<Client>> FromEventAndContext<Client> for Opt
             Err(_) => Ok(None),
         }
     }
+
+    fn context_keys() -> Vec<&'static str> {
+        T::context_keys()
+    }
 }
 
 /// To be able to use [`Result`] as handler argument
@@ -308,6 +361,10 @@ where
     ) -> Result<Self, Self::Error> {
         Ok(T::extract(bot, update, context).map_err(Into::into))
     }
+
+    fn context_keys() -> Vec<&'static str> {
+        T::context_keys()
+    }
 }
 
 /// To be able to use handler without arguments
@@ -351,6 +408,154 @@ impl<Client> FromEventAndContext<Client> for Arc<Bot<Client>> {
     }
 }
 
+/// Cached bot information, which is received by [`GetMe`] method.
+/// # Notes
+/// Extraction is unsuccessful if bot information isn't loaded yet (see [`Bot::me`] for more information).
+/// Dispatcher loads bot information automatically when polling is started,
+/// in other cases (for example, when you feed updates manually) you need to load it by [`Bot::get_me`] method.
+///
+/// [`GetMe`]: crate::methods::GetMe
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Me(pub Arc<User>);
+
+impl Deref for Me {
+    type Target = User;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<Client> FromEventAndContext<Client> for Me {
+    type Error = ExtractionError;
+
+    #[inline]
+    fn extract(
+        bot: Arc<Bot<Client>>,
+        _update: Arc<Update>,
+        _context: Arc<Context>,
+    ) -> Result<Self, Self::Error> {
+        bot.me().map(Me).ok_or_else(|| {
+            ExtractionError::new(
+                "Bot information isn't loaded. Use `Bot::get_me` method to load it",
+            )
+        })
+    }
+}
+
+/// Unique identifier of the bot, which received the update.
+/// It's useful in multi-bot setups to branch shared handler code on the bot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BotId(pub i64);
+
+impl Deref for BotId {
+    type Target = i64;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<Client> FromEventAndContext<Client> for BotId {
+    type Error = Infallible;
+
+    #[inline]
+    fn extract(
+        bot: Arc<Bot<Client>>,
+        _update: Arc<Update>,
+        _context: Arc<Context>,
+    ) -> Result<Self, Self::Error> {
+        Ok(BotId(bot.bot_id))
+    }
+}
+
+/// Tag of the bot, which received the update (see [`Bot::with_tag`]).
+/// It's useful in multi-bot setups to branch shared handler code on the bot.
+/// # Notes
+/// Extraction is unsuccessful if the bot isn't tagged.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BotTag(pub Arc<str>);
+
+impl Deref for BotTag {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<Client> FromEventAndContext<Client> for BotTag {
+    type Error = ExtractionError;
+
+    #[inline]
+    fn extract(
+        bot: Arc<Bot<Client>>,
+        _update: Arc<Update>,
+        _context: Arc<Context>,
+    ) -> Result<Self, Self::Error> {
+        bot.tag().map(|tag| BotTag(tag.into())).ok_or_else(|| {
+            ExtractionError::new("Bot isn't tagged. Use `Bot::with_tag` method to tag it")
+        })
+    }
+}
+
+/// Locale of the user of the update.
+/// Locale resolved by [`LocaleResolver`] middleware (with fallbacks and overrides stored in FSM) is preferred,
+/// otherwise the language code of the user is used as is.
+/// # Notes
+/// Extraction is unsuccessful if the locale isn't resolved and the update doesn't contain the language code of the user.
+///
+/// [`LocaleResolver`]: crate::middlewares::outer::LocaleResolver
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Locale(pub Box<str>);
+
+impl Locale {
+    #[must_use]
+    pub fn new(val: impl Into<Box<str>>) -> Self {
+        Self(val.into())
+    }
+
+    /// Gets the locale resolved by [`LocaleResolver`] middleware from the context by `locale` key
+    /// or the language code of the user of the update
+    ///
+    /// [`LocaleResolver`]: crate::middlewares::outer::LocaleResolver
+    #[must_use]
+    pub fn get(update: &Update, context: &Context) -> Option<Self> {
+        context
+            .get("locale")
+            .and_then(|locale| locale.downcast_ref::<Self>().cloned())
+            .or_else(|| {
+                update
+                    .from()
+                    .and_then(|user| user.language_code.as_deref())
+                    .map(Self::new)
+            })
+    }
+}
+
+impl Deref for Locale {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<Client> FromEventAndContext<Client> for Locale {
+    type Error = ExtractionError;
+
+    #[inline]
+    fn extract(
+        _bot: Arc<Bot<Client>>,
+        update: Arc<Update>,
+        context: Arc<Context>,
+    ) -> Result<Self, Self::Error> {
+        Self::get(&update, &context).ok_or_else(|| {
+            ExtractionError::new("Locale isn't resolved and the user language code is empty")
+        })
+    }
+}
+
 impl<Client> FromEventAndContext<Client> for Update {
     type Error = Infallible;
 
@@ -405,6 +610,12 @@ mod factory_from_event_and_context {
             fn extract(bot: Arc<Bot<Client>>, update: Arc<Update>, context: Arc<Context>) -> Result<Self, Self::Error> {
                 Ok(($($param::extract(Arc::clone(&bot), Arc::clone(&update), Arc::clone(&context)).map_err(Into::into)?,)*))
             }
+
+            fn context_keys() -> Vec<&'static str> {
+                let mut keys = vec![];
+                $(keys.extend($param::context_keys());)*
+                keys
+            }
         }
     });
 
@@ -454,6 +665,7 @@ mod factory_from_event_and_context {
 mod tests {
     use super::*;
     use crate::{
+        client::Reqwest,
         errors::ConvertToTypeError,
         types::{
             CallbackQuery, ChatBoostRemoved, ChatBoostUpdated, ChatJoinRequest, ChatMemberUpdated,
@@ -466,7 +678,7 @@ mod tests {
             MessageGiveawayWinners, MessageGroupChatCreated, MessageInvoice, MessageLeftChatMember,
             MessageLocation, MessageMessageAutoDeleteTimerChanged, MessageMigrateFromChat,
             MessageMigrateToChat, MessageNewChatMembers, MessageNewChatPhoto, MessageNewChatTitle,
-            MessagePassportData, MessagePhoto, MessagePinned, MessagePoll,
+            MessagePaidMedia, MessagePassportData, MessagePhoto, MessagePinned, MessagePoll,
             MessageProximityAlertTriggered, MessageReactionCountUpdated, MessageReactionUpdated,
             MessageSticker, MessageStory, MessageSuccessfulPayment, MessageSupergroupChatCreated,
             MessageText, MessageUsersShared, MessageVenue, MessageVideo, MessageVideoChatEnded,
@@ -520,6 +732,92 @@ mod tests {
             FromEventAndContext::extract(bot.clone(), update.clone(), context.clone()).unwrap();
     }
 
+    #[test]
+    fn test_event_extract_by_ref() {
+        let bot = Arc::new(Bot::<Reqwest>::default());
+        let update = Arc::new(
+            serde_json::from_str::<Update>(
+                r#"{"update_id": 1, "message": {"message_id": 1, "date": 1, "chat": {"id": 1, "type": "private", "first_name": "Test"}, "text": "test"}}"#,
+            )
+            .unwrap(),
+        );
+        let context = Arc::new(Context::default());
+
+        let (message, text, photo): (Message, MessageText, Option<MessagePhoto>) =
+            FromEventAndContext::extract(bot.clone(), update.clone(), context.clone()).unwrap();
+
+        assert_eq!(message.text(), Some("test"));
+        assert_eq!(&*text.text, "test");
+        assert!(photo.is_none());
+        assert!(CallbackQuery::extract(bot, update, context).is_err());
+    }
+
+    #[test]
+    fn test_bot_id_and_tag_extract() {
+        let bot = Bot::<Reqwest>::new("1234567890:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA");
+        let update = Arc::new(Update::default());
+        let context = Arc::new(Context::default());
+
+        assert_eq!(
+            BotId::extract(Arc::new(bot.clone()), update.clone(), context.clone()).unwrap(),
+            BotId(1_234_567_890)
+        );
+        assert!(BotTag::extract(Arc::new(bot.clone()), update.clone(), context.clone()).is_err());
+        assert_eq!(
+            &*BotTag::extract(Arc::new(bot.with_tag("support")), update, context).unwrap(),
+            "support"
+        );
+    }
+
+    #[test]
+    fn test_me_extract() {
+        let bot = Arc::new(Bot::<Reqwest>::default());
+        let update = Arc::new(Update::default());
+        let context = Arc::new(Context::default());
+
+        assert!(Me::extract(bot.clone(), update.clone(), context.clone()).is_err());
+
+        bot.set_me(User {
+            id: 1,
+            username: Some("test_bot".into()),
+            ..Default::default()
+        });
+
+        let me = Me::extract(bot, update, context).unwrap();
+        assert_eq!(me.id, 1);
+        assert_eq!(me.username.as_deref(), Some("test_bot"));
+    }
+
+    #[test]
+    fn test_locale_extract() {
+        let bot = Arc::new(Bot::<Reqwest>::default());
+        let context = Arc::new(Context::default());
+        let update = Arc::new(Update {
+            id: 0,
+            kind: UpdateKind::Message(Message::Text(Box::new(MessageText {
+                from: Some(User {
+                    language_code: Some("en".into()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }))),
+        });
+
+        assert!(
+            Locale::extract(bot.clone(), Arc::new(Update::default()), context.clone()).is_err()
+        );
+        assert_eq!(
+            Locale::extract(bot.clone(), update.clone(), context.clone()).unwrap(),
+            Locale::new("en")
+        );
+
+        context.insert("locale", Box::new(Locale::new("uk")));
+        assert_eq!(
+            Locale::extract(bot, update, context).unwrap(),
+            Locale::new("uk")
+        );
+    }
+
     #[allow(unreachable_code)]
     fn _check_bounds<Client, T: FromEventAndContext<Client>>() {
         unimplemented!("This function is only used for checking bounds");
@@ -528,6 +826,8 @@ mod tests {
 
         _check_bounds::<_, Bot>();
         _check_bounds::<_, Arc<Bot>>();
+        _check_bounds::<Client, Me>();
+        _check_bounds::<Client, Locale>();
         _check_bounds::<Client, Update>();
         _check_bounds::<Client, Arc<Update>>();
         _check_bounds::<Client, Arc<Context>>();
@@ -565,6 +865,7 @@ mod tests {
         _check_bounds::<Client, MessageNewChatTitle>();
         _check_bounds::<Client, MessagePassportData>();
         _check_bounds::<Client, MessagePhoto>();
+        _check_bounds::<Client, MessagePaidMedia>();
         _check_bounds::<Client, MessagePinned>();
         _check_bounds::<Client, MessagePoll>();
         _check_bounds::<Client, MessageProximityAlertTriggered>();
@@ -653,6 +954,7 @@ mod tests {
         _check_bounds::<Client, Option<MessageNewChatTitle>>();
         _check_bounds::<Client, Option<MessagePassportData>>();
         _check_bounds::<Client, Option<MessagePhoto>>();
+        _check_bounds::<Client, Option<MessagePaidMedia>>();
         _check_bounds::<Client, Option<MessagePinned>>();
         _check_bounds::<Client, Option<MessagePoll>>();
         _check_bounds::<Client, Option<MessageProximityAlertTriggered>>();
@@ -741,6 +1043,7 @@ mod tests {
         _check_bounds::<Client, Result<MessageNewChatTitle, ConvertToTypeError>>();
         _check_bounds::<Client, Result<MessagePassportData, ConvertToTypeError>>();
         _check_bounds::<Client, Result<MessagePhoto, ConvertToTypeError>>();
+        _check_bounds::<Client, Result<MessagePaidMedia, ConvertToTypeError>>();
         _check_bounds::<Client, Result<MessagePinned, ConvertToTypeError>>();
         _check_bounds::<Client, Result<MessagePoll, ConvertToTypeError>>();
         _check_bounds::<Client, Result<MessageProximityAlertTriggered, ConvertToTypeError>>();
@@ -0,0 +1,404 @@
+//! This module contains [`CommandArgs`] extractor, which parses arguments of the command to the handler argument,
+//! so `/price BTC 3` can be consumed as `CommandArgs<(String, u32)>` instead of splitting [`CommandObject::args`] manually.
+//!
+//! Arguments are parsed with [`FromCommandArgs`] trait, which is implemented for:
+//! * Primitive types, [`String`] and [`Box<str>`] (parsed with [`FromStr`]);
+//! * Tuples with up to 12 elements, each of which implements [`FromCommandArgs`];
+//! * `Option<T>` to parse an optional argument, if there are remaining arguments;
+//! * `Vec<T>` to parse all remaining arguments.
+//!
+//! For structs you can use [`FromCommandArgs`](crate::FromCommandArgs) derive macro, which parses fields in order of declaration.
+//! For your own types, which implement [`FromStr`], implement the trait with [`Args::parse_next`] method
+//! or wrap them in [`Arg`].
+//!
+//! Extraction is unsuccessful, if the [`Command`] filter isn't used,
+//! if there are missing or invalid arguments, or if there are unexpected arguments after parsed ones.
+//! In the last cases the parse error is put to the context by `command_args_error` key,
+//! so [`CommandArgsReply`] middleware can reply to the message with the error.
+//!
+//! ```ignore
+//! async fn price(bot: Bot, message: Message, CommandArgs((coin, amount)): CommandArgs<(String, u32)>) -> HandlerResult {
+//!     ...
+//! }
+//!
+//! router
+//!     .message
+//!     .register(price)
+//!     .filter(Command::one("price"));
+//! router.message.inner_middlewares.register(CommandArgsReply::default());
+//! ```
+//!
+//! [`Command`]: crate::filters::Command
+//! [`CommandArgsReply`]: crate::middlewares::inner::CommandArgsReply
+
+use super::FromEventAndContext;
+
+use crate::{
+    client::Bot, context::Context, errors::ExtractionError, filters::CommandObject, types::Update,
+};
+
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+    str::FromStr,
+    sync::Arc,
+};
+
+/// Error of parsing command arguments.
+/// Positions of the arguments start from 1.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    #[error("Missing argument at position {position}")]
+    Missing { position: usize },
+    #[error("Invalid argument `{value}` at position {position}: {reason}")]
+    Invalid {
+        position: usize,
+        value: Box<str>,
+        reason: Box<str>,
+    },
+    #[error("Too many arguments: expected {expected}, found {found}")]
+    TooMany { expected: usize, found: usize },
+}
+
+/// Cursor over the command arguments, which is used by [`FromCommandArgs`] implementations.
+/// Empty arguments (for example, from several spaces in a row) are skipped.
+#[derive(Debug, Clone)]
+pub struct Args<'a> {
+    args: Box<[&'a str]>,
+    position: usize,
+}
+
+impl<'a> Args<'a> {
+    #[must_use]
+    pub fn new(args: &'a [Box<str>]) -> Self {
+        Self {
+            args: args
+                .iter()
+                .map(AsRef::as_ref)
+                .filter(|arg: &&str| !arg.is_empty())
+                .collect(),
+            position: 0,
+        }
+    }
+
+    /// Parse the next argument with [`FromStr`]
+    /// # Errors
+    /// If there is no next argument or it can't be parsed
+    pub fn parse_next<T>(&mut self) -> Result<T, Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let Some(value) = self.args.get(self.position) else {
+            return Err(Error::Missing {
+                position: self.position + 1,
+            });
+        };
+
+        let result = value.parse().map_err(|err: T::Err| Error::Invalid {
+            position: self.position + 1,
+            value: (*value).into(),
+            reason: err.to_string().into(),
+        });
+
+        self.position += 1;
+        result
+    }
+
+    /// Get the remaining arguments without parsing and consume them
+    pub fn rest(&mut self) -> &[&'a str] {
+        let rest = &self.args[self.position..];
+        self.position = self.args.len();
+        rest
+    }
+
+    /// Check if there are no remaining arguments
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.position >= self.args.len()
+    }
+
+    /// Check that all arguments are consumed
+    /// # Errors
+    /// If there are remaining arguments
+    pub fn finish(&self) -> Result<(), Error> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::TooMany {
+                expected: self.position,
+                found: self.args.len(),
+            })
+        }
+    }
+}
+
+/// Trait for parsing command arguments.
+/// Check the [module](self) documentation for more information.
+pub trait FromCommandArgs: Sized {
+    /// Parse the value from the next arguments
+    /// # Errors
+    /// If the arguments are missing or invalid
+    fn from_command_args(args: &mut Args<'_>) -> Result<Self, Error>;
+}
+
+macro_rules! impl_from_str {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl FromCommandArgs for $ty {
+                fn from_command_args(args: &mut Args<'_>) -> Result<Self, Error> {
+                    args.parse_next()
+                }
+            }
+        )+
+    };
+}
+
+impl_from_str!(
+    String, bool, char, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64,
+);
+
+impl FromCommandArgs for Box<str> {
+    fn from_command_args(args: &mut Args<'_>) -> Result<Self, Error> {
+        args.parse_next::<String>().map(Into::into)
+    }
+}
+
+/// Wrapper to parse any type, which implements [`FromStr`], as command argument
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Arg<T>(pub T);
+
+impl<T> FromCommandArgs for Arg<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    fn from_command_args(args: &mut Args<'_>) -> Result<Self, Error> {
+        args.parse_next().map(Self)
+    }
+}
+
+impl<T: FromCommandArgs> FromCommandArgs for Option<T> {
+    fn from_command_args(args: &mut Args<'_>) -> Result<Self, Error> {
+        if args.is_empty() {
+            Ok(None)
+        } else {
+            T::from_command_args(args).map(Some)
+        }
+    }
+}
+
+impl<T: FromCommandArgs> FromCommandArgs for Vec<T> {
+    fn from_command_args(args: &mut Args<'_>) -> Result<Self, Error> {
+        let mut values = vec![];
+
+        while !args.is_empty() {
+            values.push(T::from_command_args(args)?);
+        }
+
+        Ok(values)
+    }
+}
+
+#[allow(non_snake_case)]
+mod factory_tuples {
+    //! This module is used to implement [`FromCommandArgs`] for tuples, each element of which implements it
+
+    use super::{Args, Error, FromCommandArgs};
+
+    macro_rules! factory ({ $($param:ident)* } => {
+        impl<$($param: FromCommandArgs,)*> FromCommandArgs for ($($param,)*) {
+            #[inline]
+            fn from_command_args(args: &mut Args<'_>) -> Result<Self, Error> {
+                Ok(($($param::from_command_args(args)?,)*))
+            }
+        }
+    });
+
+    factory! { A }
+    factory! { A B }
+    factory! { A B C }
+    factory! { A B C D }
+    factory! { A B C D E }
+    factory! { A B C D E F }
+    factory! { A B C D E F G }
+    factory! { A B C D E F G H }
+    factory! { A B C D E F G H I }
+    factory! { A B C D E F G H I J }
+    factory! { A B C D E F G H I J K }
+    factory! { A B C D E F G H I J K L }
+}
+
+/// Extractor of the parsed command arguments.
+/// Check the [module](self) documentation for more information.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CommandArgs<T>(pub T);
+
+impl<T> CommandArgs<T>
+where
+    T: FromCommandArgs,
+{
+    /// Parse all arguments of the command
+    /// # Errors
+    /// If the arguments are missing or invalid, or if there are unexpected arguments after parsed ones
+    pub fn parse(command: &CommandObject) -> Result<Self, Error> {
+        let mut args = Args::new(&command.args);
+        let value = T::from_command_args(&mut args)?;
+        args.finish()?;
+
+        Ok(Self(value))
+    }
+}
+
+impl<T> Deref for CommandArgs<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for CommandArgs<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<Client, T> FromEventAndContext<Client> for CommandArgs<T>
+where
+    T: FromCommandArgs,
+{
+    type Error = ExtractionError;
+
+    fn extract(
+        bot: Arc<Bot<Client>>,
+        update: Arc<Update>,
+        context: Arc<Context>,
+    ) -> Result<Self, Self::Error> {
+        let command = <CommandObject as FromEventAndContext<Client>>::extract(
+            bot,
+            update,
+            Arc::clone(&context),
+        )?;
+
+        Self::parse(&command).map_err(|err| {
+            let extraction_err = ExtractionError::new(format!("Invalid command arguments: {err}"));
+
+            context.insert("command_args_error", Box::new(err));
+
+            extraction_err
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Reqwest;
+
+    fn parse<T: FromCommandArgs>(text: &str) -> Result<T, Error> {
+        CommandArgs::parse(&CommandObject::extract(text).unwrap()).map(|CommandArgs(value)| value)
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse::<(String, u32)>("/price BTC 3"),
+            Ok(("BTC".to_owned(), 3))
+        );
+        assert_eq!(
+            parse::<(String, u32)>("/price  BTC   3 "),
+            Ok(("BTC".to_owned(), 3))
+        );
+        assert_eq!(
+            parse::<(String, Option<u32>)>("/price BTC").unwrap().1,
+            None
+        );
+        assert_eq!(
+            parse::<(String, Vec<u32>)>("/price BTC 1 2 3").unwrap().1,
+            [1, 2, 3]
+        );
+        assert_eq!(
+            parse::<(Arg<std::net::Ipv4Addr>,)>("/ping 127.0.0.1")
+                .unwrap()
+                .0
+                 .0,
+            std::net::Ipv4Addr::LOCALHOST
+        );
+
+        assert_eq!(
+            parse::<(String, u32)>("/price BTC"),
+            Err(Error::Missing { position: 2 })
+        );
+        assert_eq!(
+            parse::<(String, u32)>("/price BTC 1 2"),
+            Err(Error::TooMany {
+                expected: 2,
+                found: 3
+            })
+        );
+        assert!(matches!(
+            parse::<(String, u32)>("/price BTC three"),
+            Err(Error::Invalid { position: 2, value, .. }) if &*value == "three"
+        ));
+    }
+
+    #[test]
+    fn test_derive() {
+        #[derive(Debug, PartialEq, crate::FromCommandArgs)]
+        struct Price {
+            coin: String,
+            amount: u32,
+            currency: Option<String>,
+        }
+
+        #[derive(Debug, PartialEq, crate::FromCommandArgs)]
+        struct Ban(i64, Vec<String>);
+
+        assert_eq!(
+            parse::<Price>("/price BTC 3"),
+            Ok(Price {
+                coin: "BTC".to_owned(),
+                amount: 3,
+                currency: None,
+            })
+        );
+        assert_eq!(
+            parse::<Ban>("/ban 1 spam flood"),
+            Ok(Ban(1, vec!["spam".to_owned(), "flood".to_owned()]))
+        );
+    }
+
+    #[test]
+    fn test_extract() {
+        let bot = Arc::new(Bot::<Reqwest>::default());
+        let update = Arc::new(Update::default());
+        let context = Arc::new(Context::default());
+
+        // Command filter isn't used
+        assert!(CommandArgs::<(String,)>::extract(
+            Arc::clone(&bot),
+            Arc::clone(&update),
+            Arc::clone(&context)
+        )
+        .is_err());
+
+        context.insert(
+            "command",
+            Box::new(CommandObject::extract("/price BTC three").unwrap()),
+        );
+
+        assert!(CommandArgs::<(String, u32)>::extract(
+            Arc::clone(&bot),
+            Arc::clone(&update),
+            Arc::clone(&context)
+        )
+        .is_err());
+        assert!(context.get("command_args_error").unwrap().is::<Error>());
+
+        let CommandArgs((coin, amount)) =
+            CommandArgs::<(String, String)>::extract(bot, update, context).unwrap();
+        assert_eq!(coin, "BTC");
+        assert_eq!(amount, "three");
+    }
+}
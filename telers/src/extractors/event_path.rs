@@ -0,0 +1,68 @@
+//! This module contains helpers for the code, which is generated by [`FromEvent`] derive with `path` attribute.
+//! Each segment of the path is a method call or a field access, which can return a value or [`Option`] of it,
+//! so the helpers unify both cases to [`Option`] and the path is followed while values are present.
+//!
+//! The module isn't a part of the public API and can be changed without notice.
+//!
+//! [`FromEvent`]: crate::FromEvent
+
+/// Wrapper of the path segment value
+pub struct Step<T>(pub T);
+
+/// Conversion of the path segment value, which is [`Option`]
+pub trait OptionStep {
+    type Value;
+
+    fn into_option(self) -> Option<Self::Value>;
+}
+
+impl<T> OptionStep for Step<Option<T>> {
+    type Value = T;
+
+    #[inline]
+    fn into_option(self) -> Option<Self::Value> {
+        self.0
+    }
+}
+
+impl<'a, T> OptionStep for Step<&'a Option<T>> {
+    type Value = &'a T;
+
+    #[inline]
+    fn into_option(self) -> Option<Self::Value> {
+        self.0.as_ref()
+    }
+}
+
+/// Conversion of the path segment value, which isn't [`Option`].
+/// It's implemented for the reference to [`Step`], so [`OptionStep`] has a priority in the method resolution.
+pub trait ValueStep {
+    type Value;
+
+    fn into_option(self) -> Option<Self::Value>;
+}
+
+impl<T: Clone> ValueStep for &Step<T> {
+    type Value = T;
+
+    #[inline]
+    fn into_option(self) -> Option<Self::Value> {
+        Some(self.0.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OptionStep as _, Step, ValueStep as _};
+
+    #[test]
+    fn test_into_option() {
+        assert_eq!(Step(1).into_option(), Some(1));
+        assert_eq!(Step("text").into_option(), Some("text"));
+        assert_eq!(Step(Some(1)).into_option(), Some(1));
+        assert_eq!(Step(None::<i64>).into_option(), None);
+        assert_eq!(Step(&Some(1)).into_option(), Some(&1));
+        assert_eq!(Step(&None::<i64>).into_option(), None);
+        assert_eq!(Step(&1).into_option(), Some(&1));
+    }
+}
@@ -0,0 +1,267 @@
+//! This module contains ready-made extractors for the most common values of the update (identifiers, callback query with its message),
+//! so you don't need to write your own wrappers with [`FromEvent`] macro for them.
+//!
+//! Extraction is unsuccessful if the update doesn't contain the value,
+//! so use `Option<T>` if the handler can be called for the updates without it:
+//!
+//! ```ignore
+//! async fn handler(ChatId(chat_id): ChatId, thread_id: Option<ThreadId>) -> HandlerResult {
+//!  ...
+//! }
+//! ```
+//!
+//! [`FromEvent`]: crate::FromEvent
+
+use super::FromEventAndContext;
+
+use crate::{
+    client::Bot,
+    context::Context,
+    errors::ExtractionError,
+    types::{CallbackQuery, Message, Update, UpdateKind},
+};
+
+use std::{ops::Deref, sync::Arc};
+
+/// Unique identifier of the chat, in which the update was sent.
+/// Use it to send answers to the same chat.
+/// # Notes
+/// Extraction is unsuccessful if the update isn't related to a chat (for example, inline query).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChatId(pub i64);
+
+impl Deref for ChatId {
+    type Target = i64;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<Client> FromEventAndContext<Client> for ChatId {
+    type Error = ExtractionError;
+
+    #[inline]
+    fn extract(
+        _bot: Arc<Bot<Client>>,
+        update: Arc<Update>,
+        _context: Arc<Context>,
+    ) -> Result<Self, Self::Error> {
+        update
+            .chat_id()
+            .map(ChatId)
+            .ok_or_else(|| ExtractionError::new("Update isn't related to a chat"))
+    }
+}
+
+/// Unique identifier of the user, who sent the update.
+/// # Notes
+/// Extraction is unsuccessful if the update doesn't contain the user
+/// (for example, message is sent on behalf of the channel or the chat).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UserId(pub i64);
+
+impl Deref for UserId {
+    type Target = i64;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<Client> FromEventAndContext<Client> for UserId {
+    type Error = ExtractionError;
+
+    #[inline]
+    fn extract(
+        _bot: Arc<Bot<Client>>,
+        update: Arc<Update>,
+        _context: Arc<Context>,
+    ) -> Result<Self, Self::Error> {
+        update
+            .from_id()
+            .map(UserId)
+            .ok_or_else(|| ExtractionError::new("Update doesn't contain a user"))
+    }
+}
+
+/// Unique identifier of the message inside the chat, to which the update is related.
+/// Use it to reply to, edit or delete the message.
+/// # Notes
+/// Extraction is unsuccessful if the update isn't related to a message
+/// (for example, inline query or callback query from the inline message).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MessageId(pub i64);
+
+impl Deref for MessageId {
+    type Target = i64;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<Client> FromEventAndContext<Client> for MessageId {
+    type Error = ExtractionError;
+
+    #[inline]
+    fn extract(
+        _bot: Arc<Bot<Client>>,
+        update: Arc<Update>,
+        _context: Arc<Context>,
+    ) -> Result<Self, Self::Error> {
+        update
+            .message_id()
+            .map(MessageId)
+            .ok_or_else(|| ExtractionError::new("Update isn't related to a message"))
+    }
+}
+
+/// Unique identifier of the message thread (topic) of the forum, to which the message of the update belongs.
+/// Use it to send answers to the same topic.
+/// # Notes
+/// Extraction is unsuccessful if the update isn't related to a message in the message thread.
+/// Use `Option<ThreadId>` if the update can be sent outside of the message thread.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ThreadId(pub i64);
+
+impl Deref for ThreadId {
+    type Target = i64;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<Client> FromEventAndContext<Client> for ThreadId {
+    type Error = ExtractionError;
+
+    #[inline]
+    fn extract(
+        _bot: Arc<Bot<Client>>,
+        update: Arc<Update>,
+        _context: Arc<Context>,
+    ) -> Result<Self, Self::Error> {
+        update
+            .message_thread_id()
+            .map(ThreadId)
+            .ok_or_else(|| ExtractionError::new("Update isn't related to a message thread"))
+    }
+}
+
+/// Callback query and the message with the callback button, which originated the query.
+/// Use it instead of [`CallbackQuery`] to get the message without handling [`MaybeInaccessibleMessage`] manually.
+/// # Notes
+/// Extraction is unsuccessful if the update isn't a callback query
+/// or the message is inaccessible or absent (for example, the button was attached to the message sent in inline mode).
+///
+/// [`MaybeInaccessibleMessage`]: crate::types::MaybeInaccessibleMessage
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallbackMessage(pub CallbackQuery, pub Message);
+
+impl<Client> FromEventAndContext<Client> for CallbackMessage {
+    type Error = ExtractionError;
+
+    #[inline]
+    fn extract(
+        _bot: Arc<Bot<Client>>,
+        update: Arc<Update>,
+        _context: Arc<Context>,
+    ) -> Result<Self, Self::Error> {
+        let UpdateKind::CallbackQuery(callback_query) = update.kind() else {
+            return Err(ExtractionError::new("Update isn't a callback query"));
+        };
+
+        match &callback_query.message {
+            Some(message) => match message.as_message() {
+                Some(message) => Ok(CallbackMessage(callback_query.clone(), message.clone())),
+                None => Err(ExtractionError::new(
+                    "Message of the callback query is inaccessible",
+                )),
+            },
+            None => Err(ExtractionError::new(
+                "Callback query doesn't contain a message",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::Reqwest,
+        types::{Chat, InaccessibleMessage, InlineQuery, MessageText, User},
+    };
+
+    fn extract<T: FromEventAndContext<Reqwest>>(update: Update) -> Result<T, T::Error> {
+        T::extract(
+            Arc::new(Bot::default()),
+            Arc::new(update),
+            Arc::new(Context::default()),
+        )
+    }
+
+    #[test]
+    fn test_extract() {
+        let update = Update {
+            id: 0,
+            kind: UpdateKind::Message(Message::Text(Box::new(MessageText {
+                id: 3,
+                thread_id: Some(4),
+                from: Some(User {
+                    id: 2,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }))),
+        };
+
+        assert_eq!(extract::<ChatId>(update.clone()).unwrap(), ChatId(0));
+        assert_eq!(extract::<UserId>(update.clone()).unwrap(), UserId(2));
+        assert_eq!(extract::<MessageId>(update.clone()).unwrap(), MessageId(3));
+        assert_eq!(extract::<ThreadId>(update).unwrap(), ThreadId(4));
+    }
+
+    #[test]
+    fn test_extract_missing() {
+        let update = Update {
+            id: 0,
+            kind: UpdateKind::InlineQuery(InlineQuery::default()),
+        };
+
+        assert!(extract::<ChatId>(update.clone()).is_err());
+        assert!(extract::<MessageId>(update.clone()).is_err());
+        assert!(extract::<ThreadId>(update.clone()).is_err());
+        assert!(extract::<Option<ThreadId>>(update).unwrap().is_none());
+        assert!(extract::<UserId>(Update::default()).is_err());
+    }
+
+    #[test]
+    fn test_callback_message_extract() {
+        let update = |message| Update {
+            id: 0,
+            kind: UpdateKind::CallbackQuery(CallbackQuery {
+                message,
+                ..Default::default()
+            }),
+        };
+
+        let CallbackMessage(callback_query, message) =
+            extract(update(Some(Message::default().into()))).unwrap();
+        assert!(callback_query.message.is_some());
+        assert_eq!(message, Message::default());
+
+        assert!(extract::<CallbackMessage>(update(Some(
+            InaccessibleMessage {
+                chat: Chat::default(),
+                id: 1,
+                date: 0,
+            }
+            .into()
+        )))
+        .is_err());
+        assert!(extract::<CallbackMessage>(update(None)).is_err());
+        assert!(extract::<CallbackMessage>(Update::default()).is_err());
+    }
+}
@@ -10,6 +10,10 @@
 //! Each filter has a method [`Filter::invert`], [`Filter::and`] and [`Filter::or`] to create [`Invert`], [`And`] and [`Or`] filters respectively.
 //!
 //! Ready-made implementations:
+//! * [`ChatId`] and [`Username`]:
+//! Filters for whitelisting specific chats and channels by their IDs and usernames.
+//! [`ChatId`] creates with `one`, `in_` or `not_in` methods.
+//! [`Username`] checks the username of the chat with glob patterns (`*` and `?` wildcards) and creates with `matches`, `matches_any` or `matches_none` methods.
 //! * [`ChatType`]:
 //! Filter for checking the type of chat.
 //! Usually used with [`ChatTypeEnum`] (or its string representation) to check the type of chat.
@@ -23,7 +27,41 @@
 //! * [`ContentType`]:
 //! Filter for checking the type of the message content.
 //! Usually used with [`ContentTypeEnum`] (or its string representation) to check the type of content.
+//! Creates with `one`, `many`, `any_media` or `all_except` methods and can be combined with `content_type`, `union` and `except` methods.
+//! * [`Entity`]:
+//! Filter for checking the entities of the message text or caption, for example, mentions, hashtags, cashtags or URLs.
+//! Usually used with [`MessageEntityType`] (or its string representation) to check the type of entity and optionally its value.
+//! Creates with `one`, `many`, `mention_of_bot`, `mention`, `hashtag`, `cashtag` or `url` methods.
+//! * [`ForBot`]:
+//! Filter for checking the bot, which received the update, by its ID or tag, to bind routers and handlers to specific bots in multi-bot setups.
+//! Creates with `id`, `ids`, `tag` or `tags` methods.
+//! * [`HasCaption`], [`HasLink`], [`IsForwarded`], [`IsAutomaticForward`], [`IsTopicMessage`] and [`ViaBot`]:
+//! Filters for checking common properties of the message, for example, if the message has a caption or a link, is forwarded or sent to a forum topic.
+//! [`ViaBot`] filter can be created with `any`, `id` or `ids` methods to check the inline bot through which the message was sent.
+//! * [`InviteLink`]:
+//! Filter for checking the chat invite link, which was used by the user to send the join request or to join the chat.
+//! This filter checks if the invite link name or creator ID is equal to one of the specified.
+//! You can create a filter with `new` method with transferring all necessary data at once, or use [`InviteLinkBuilder`] to create a filter step by step.
+//! Instead of [`InviteLinkBuilder`] you can use [`InviteLink`] `name`, `names`, `creator_id` or `creator_ids` methods.
+//! * [`InvoicePayload`] and [`TypedInvoicePayload`]:
+//! Filters for checking the invoice payload of the successful payment message, shipping query or pre-checkout query.
+//! [`InvoicePayload`] checks if the payload is equal to one of the specified or starts with one of the specified prefixes
+//! and creates with `one`, `many`, `prefix` or `prefixes` methods.
+//! [`TypedInvoicePayload`] checks if the payload can be deserialized from JSON to the type and matches the predicate,
+//! so the deserialized payload can be extracted with [`Payload`] extractor.
+//! * [`LanguageCode`]:
+//! Filter for checking the language of the user.
+//! This filter checks if the locale resolved by [`LocaleResolver`] middleware or the language code of the user matches one of the specified.
 //! Creates with `one` or `many` methods.
+//! * [`Location`]:
+//! Filter for checking if the location of the message (or the venue) is within the radius of the specified point.
+//! Creates with `within` method, distances are computed with [`LocationType::distance`] method.
+//! * [`MemberStatus`]:
+//! Filter for checking the status of the user in the chat, for example, if the user is an administrator.
+//! Statuses are received by `getChatMember` requests and cached with [`CachedCheck`] to cut repeated requests in hot groups.
+//! Creates with `one`, `many` or `admin` methods.
+//! * [`IsPremium`], [`IsBot`], [`SenderIsChannel`] and [`IsAnonymousAdmin`]:
+//! Filters for checking the sender of the update, for example, if the user has Telegram Premium or the message is sent on behalf of a channel or the group.
 //! * [`State`]:
 //! Filter for checking the state of the user/chat/etc.
 //! Filter accepts [`StateType`] that represents a state type for verification,
@@ -38,6 +76,7 @@
 //! If you want to check the text with `contains`, `starts_with` or `ends_with` methods that accept only [`Cow`],
 //! you can create a filter with `contains_single`, `contains`, `starts_with_single`, `starts_with`, `ends_with_single`, or `ends_with` methods,
 //! or use [`TextBuilder`] to create a filter step by step.
+//! By default the text is checked, use [`TextSource`] to check the caption of the message or the text or caption instead.
 //! * [`User`]:
 //! Filter for checking the user.
 //! This filter checks if the user username, first name, last name, language code or ID is equal to one of the specified.
@@ -47,32 +86,61 @@
 //! This filter checks user data step by step using the logical operator `or`,
 //! so if at least one check is successful, the filter will return the value `true`.
 //!
+//! Results of expensive checks, for example, Telegram API requests, can be memoized in your own filters with [`CachedCheck`]
+//! (see [`cached module`] for more information).
+//!
 //! [`Cow`]: std::borrow::Cow
 //! [`Regex`]: regex::Regex
 //! [`ChatTypeEnum`]: crate::enums::ChatType
+//! [`LocaleResolver`]: crate::middlewares::outer::LocaleResolver
+//! [`LocationType::distance`]: crate::types::Location::distance
 //! [`ContentTypeEnum`]: crate::enums::ContentType
+//! [`MessageEntityType`]: crate::enums::MessageEntityType
 //! [`BotCommand`]: crate::types::BotCommand
 //! [`Regex`]: regex::Regex
 //! [`context`]: crate::context::Context
 //! [`command pattern type`]: command::PatternType
 //! [`text pattern type`]: text::PatternType
 //! [`bot`]: crate::client::Bot
+//! [`cached module`]: cached
 //! [`update`]: crate::types::Update
 
 pub mod base;
+pub mod cached;
+pub mod chat;
 pub mod chat_type;
 pub mod command;
 pub mod content_type;
+pub mod entity;
+pub mod for_bot;
+pub mod invite_link;
+pub mod invoice_payload;
+pub mod language_code;
+pub mod location;
 pub mod logical;
+pub mod member_status;
+pub mod message;
+pub mod sender;
 pub mod state;
 pub mod text;
 pub mod user;
 
 pub use base::Filter;
+pub use cached::CachedCheck;
+pub use chat::{ChatId, Username};
 pub use chat_type::ChatType;
 pub use command::{Builder as CommandBuilder, Command, CommandObject};
 pub use content_type::ContentType;
+pub use entity::Entity;
+pub use for_bot::ForBot;
+pub use invite_link::{Builder as InviteLinkBuilder, InviteLink};
+pub use invoice_payload::{InvoicePayload, Payload, TypedInvoicePayload};
+pub use language_code::LanguageCode;
+pub use location::Location;
 pub use logical::{And, Invert, Or};
+pub use member_status::MemberStatus;
+pub use message::{HasCaption, HasLink, IsAutomaticForward, IsForwarded, IsTopicMessage, ViaBot};
+pub use sender::{IsAnonymousAdmin, IsBot, IsPremium, SenderIsChannel};
 pub use state::{State, StateType};
-pub use text::{Builder as TextBuilder, Text};
+pub use text::{Builder as TextBuilder, Source as TextSource, Text};
 pub use user::{Builder as UserBuilder, User};
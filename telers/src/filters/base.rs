@@ -1,13 +1,13 @@
 use super::{And, Invert, Or};
 
 use crate::{
-    client::{Bot, Reqwest},
+    client::{Bot, DefaultSession},
     context::Context,
     types::Update,
 };
 
 use async_trait::async_trait;
-use std::{future::Future, sync::Arc};
+use std::{any::type_name, future::Future, sync::Arc};
 
 /// Filters are used to filter updates before processing handlers and inner middlewares.
 /// You can use filters to check if the update meets the necessary conditions,
@@ -16,12 +16,29 @@ use std::{future::Future, sync::Arc};
 /// Check out the examples to see how to create your own filters and check ready-made implementations of filters
 /// to avoid writing your own filters which are already implemented.
 #[async_trait]
-pub trait Filter<Client = Reqwest>: Send + Sync {
+pub trait Filter<Client = DefaultSession>: Send + Sync {
     /// Check if the filter passes
     /// # Returns
     /// `true` if the filter passes, otherwise `false`
     async fn check(&self, bot: &Bot<Client>, update: &Update, context: &Context) -> bool;
 
+    /// Name of the filter, which is used for introspection (see [`Router::describe`]).
+    /// By default, it's the type name of the filter.
+    ///
+    /// [`Router::describe`]: crate::router::Router#method.describe
+    fn name(&self) -> &'static str {
+        type_name::<Self>()
+    }
+
+    /// Keys of the context, which the filter reads, for example, `fsm_state` for [`State`] filter.
+    /// It's used for validation of the router configuration (see [`Router::validate`]).
+    ///
+    /// [`State`]: crate::filters::State
+    /// [`Router::validate`]: crate::router::Router#method.validate
+    fn context_keys(&self) -> Vec<&'static str> {
+        vec![]
+    }
+
     /// Invert result of the filter
     /// # Notes
     /// This method is used to create [`Invert`] filter
@@ -62,6 +79,14 @@ where
     async fn check(&self, bot: &Bot<Client>, update: &Update, context: &Context) -> bool {
         T::check(self, bot, update, context).await
     }
+
+    fn name(&self) -> &'static str {
+        T::name(self)
+    }
+
+    fn context_keys(&self) -> Vec<&'static str> {
+        T::context_keys(self)
+    }
 }
 
 /// To possible use function-like as filters
@@ -0,0 +1,237 @@
+//! This module contains [`CachedCheck`], which memoizes results of expensive async checks with TTL and maximum size,
+//! for example, results of `getChatMember` requests in filters, so hot groups don't cause repeated Telegram API calls.
+//!
+//! It's used by built-in filters (see [`MemberStatus`]) and can be used by your own filters:
+//! ```ignore
+//! struct IsSubscribed {
+//!     cache: CachedCheck<i64, bool>,
+//! }
+//!
+//! #[async_trait]
+//! impl<Client> Filter<Client> for IsSubscribed
+//! where
+//!     Client: Sync,
+//! {
+//!     async fn check(&self, bot: &Bot<Client>, update: &Update, context: &Context) -> bool {
+//!         let Some(user_id) = update.from_id() else {
+//!             return false;
+//!         };
+//!
+//!         self.cache
+//!             .get_or_try_insert_with(user_id, || is_subscribed(user_id))
+//!             .await
+//!             .unwrap_or(false)
+//!     }
+//! }
+//! ```
+//!
+//! Cache is cloned cheaply and clones share the entries, so one cache can be used by many filters.
+//! Concurrent checks of the same missing key aren't merged, so the check can be called several times until the first result is cached.
+//!
+//! [`MemberStatus`]: super::MemberStatus
+
+use std::{
+    collections::HashMap,
+    fmt::{self, Debug, Formatter},
+    future::Future,
+    hash::Hash,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+pub const DEFAULT_TTL: Duration = Duration::from_secs(60);
+pub const DEFAULT_MAX_SIZE: usize = 10_000;
+
+/// Memoized results of async checks with TTL and maximum size.
+/// Check the [module](self) documentation for more information.
+pub struct CachedCheck<K, V> {
+    entries: Arc<Mutex<HashMap<K, (V, Instant)>>>,
+    ttl: Duration,
+    max_size: usize,
+}
+
+impl<K, V> CachedCheck<K, V>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+{
+    /// Creates a new cache, which keeps results for `ttl` and stores at most `max_size` results.
+    /// If the cache is full, expired results are removed, and then the results, which expire first
+    #[must_use]
+    pub fn new(ttl: Duration, max_size: usize) -> Self {
+        Self {
+            entries: Arc::default(),
+            ttl,
+            max_size,
+        }
+    }
+
+    /// Gets the cached result, if it isn't expired
+    #[must_use]
+    pub fn get(&self, key: &K) -> Option<V> {
+        let mut entries = self.entries.lock().unwrap();
+
+        match entries.get(key) {
+            Some((value, expires_at)) if *expires_at > Instant::now() => Some(value.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Caches the result
+    pub fn insert(&self, key: K, value: V) {
+        if self.max_size == 0 {
+            return;
+        }
+
+        let now = Instant::now();
+        let mut entries = self.entries.lock().unwrap();
+
+        if entries.len() >= self.max_size && !entries.contains_key(&key) {
+            entries.retain(|_, (_, expires_at)| *expires_at > now);
+        }
+        if entries.len() >= self.max_size && !entries.contains_key(&key) {
+            // Linear search is fine, because eviction of not expired results happens only if the cache is too small
+            if let Some(oldest) = entries
+                .iter()
+                .min_by_key(|(_, (_, expires_at))| *expires_at)
+                .map(|(key, _)| key.clone())
+            {
+                entries.remove(&oldest);
+            }
+        }
+
+        entries.insert(key, (value, now + self.ttl));
+    }
+
+    /// Gets the cached result or calls the check and caches its result.
+    /// Errors of the check aren't cached, so the check is called again next time.
+    /// # Errors
+    /// If the check returns an error
+    pub async fn get_or_try_insert_with<F, Fut, E>(&self, key: K, check: F) -> Result<V, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<V, E>>,
+    {
+        if let Some(value) = self.get(&key) {
+            return Ok(value);
+        }
+
+        let value = check().await?;
+        self.insert(key, value.clone());
+
+        Ok(value)
+    }
+
+    /// Removes the cached result, for example, when the result is known to be changed
+    pub fn invalidate(&self, key: &K) {
+        self.entries.lock().unwrap().remove(key);
+    }
+
+    /// Removes all cached results
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    /// Gets number of the cached results, including expired ones, which aren't removed yet
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<K, V> Default for CachedCheck<K, V>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+{
+    fn default() -> Self {
+        Self::new(DEFAULT_TTL, DEFAULT_MAX_SIZE)
+    }
+}
+
+impl<K, V> Clone for CachedCheck<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: Arc::clone(&self.entries),
+            ttl: self.ttl,
+            max_size: self.max_size,
+        }
+    }
+}
+
+impl<K, V> Debug for CachedCheck<K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedCheck")
+            .field("len", &self.entries.lock().unwrap().len())
+            .field("ttl", &self.ttl)
+            .field("max_size", &self.max_size)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::Infallible;
+
+    #[tokio::test]
+    async fn test_get_or_try_insert_with() {
+        let cache = CachedCheck::new(Duration::from_secs(60), 2);
+
+        assert_eq!(
+            cache
+                .get_or_try_insert_with(1, || async { Ok::<_, Infallible>(true) })
+                .await,
+            Ok(true)
+        );
+        // Cached result is returned without calling the check
+        assert_eq!(
+            cache
+                .get_or_try_insert_with(1, || async { Ok::<_, Infallible>(false) })
+                .await,
+            Ok(true)
+        );
+        // Errors aren't cached
+        assert_eq!(
+            cache
+                .get_or_try_insert_with(2, || async { Err("error") })
+                .await,
+            Err("error")
+        );
+        assert_eq!(cache.get(&2), None);
+
+        cache.insert(2, false);
+        cache.insert(3, false);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&1), None);
+
+        cache.invalidate(&2);
+        assert_eq!(cache.get(&2), None);
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_ttl() {
+        let cache = CachedCheck::new(Duration::ZERO, 10);
+
+        cache.insert(1, true);
+        assert_eq!(cache.get(&1), None);
+        assert!(cache.is_empty());
+
+        let cache = CachedCheck::new(Duration::from_secs(60), 0);
+
+        cache.insert(1, true);
+        assert_eq!(cache.get(&1), None);
+    }
+}
@@ -0,0 +1,215 @@
+//! This module contains filters for whitelisting (or blacklisting with [`Filter::invert`]) specific chats and channels
+//! by their IDs and usernames.
+//!
+//! [`Filter::invert`]: super::Filter::invert
+
+use super::base::Filter;
+
+use crate::{client::Bot, context::Context, types::Update};
+
+use async_trait::async_trait;
+
+/// Filter for checking the ID of the chat
+#[derive(Debug, Clone)]
+pub struct ChatId {
+    chat_ids: Box<[i64]>,
+    exclude: bool,
+}
+
+impl ChatId {
+    /// Creates a new [`ChatId`] filter with one allowed chat ID
+    #[must_use]
+    pub fn one(chat_id: i64) -> Self {
+        Self::in_([chat_id])
+    }
+
+    /// Creates a new [`ChatId`] filter with many allowed chat IDs
+    #[must_use]
+    pub fn in_(chat_ids: impl IntoIterator<Item = i64>) -> Self {
+        Self {
+            chat_ids: chat_ids.into_iter().collect(),
+            exclude: false,
+        }
+    }
+
+    /// Creates a new [`ChatId`] filter with many disallowed chat IDs.
+    /// Updates without chat aren't passed by this filter.
+    #[must_use]
+    pub fn not_in(chat_ids: impl IntoIterator<Item = i64>) -> Self {
+        Self {
+            exclude: true,
+            ..Self::in_(chat_ids)
+        }
+    }
+}
+
+impl ChatId {
+    #[must_use]
+    pub fn validate_chat_id(&self, chat_id: i64) -> bool {
+        self.chat_ids.contains(&chat_id) != self.exclude
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for ChatId {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        update
+            .chat_id()
+            .map_or(false, |chat_id| self.validate_chat_id(chat_id))
+    }
+}
+
+/// Filter for checking the username of the chat with glob patterns.
+/// # Notes
+/// Patterns support `*` (any number of characters) and `?` (exactly one character) wildcards,
+/// for example, `admin_*` matches `admin_chat` and `admin_`.
+/// Usernames are compared case-insensitively and the `@` prefix of the patterns is optional.
+/// Chats without username aren't passed by this filter.
+#[derive(Debug, Clone)]
+pub struct Username {
+    patterns: Box<[Box<str>]>,
+    exclude: bool,
+}
+
+impl Username {
+    /// Creates a new [`Username`] filter with one allowed pattern
+    #[must_use]
+    pub fn matches(pattern: impl Into<Box<str>>) -> Self {
+        Self::matches_any([pattern])
+    }
+
+    /// Creates a new [`Username`] filter with many allowed patterns
+    #[must_use]
+    pub fn matches_any<T, I>(patterns: I) -> Self
+    where
+        T: Into<Box<str>>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            patterns: patterns
+                .into_iter()
+                .map(|pattern| {
+                    let pattern = pattern.into();
+                    pattern
+                        .strip_prefix('@')
+                        .unwrap_or(&pattern)
+                        .to_lowercase()
+                        .into()
+                })
+                .collect(),
+            exclude: false,
+        }
+    }
+
+    /// Creates a new [`Username`] filter with many disallowed patterns
+    #[must_use]
+    pub fn matches_none<T, I>(patterns: I) -> Self
+    where
+        T: Into<Box<str>>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            exclude: true,
+            ..Self::matches_any(patterns)
+        }
+    }
+}
+
+/// Checks if the text matches the glob pattern with `*` and `?` wildcards
+fn glob_match(pattern: &[char], text: &[char]) -> bool {
+    let (mut pattern_index, mut text_index) = (0, 0);
+    // Position of the last `*` in the pattern and the text position it's matched to
+    let mut backtrack = None;
+
+    while text_index < text.len() {
+        match pattern.get(pattern_index) {
+            Some('*') => {
+                backtrack = Some((pattern_index, text_index));
+                pattern_index += 1;
+            }
+            Some(ch) if *ch == '?' || *ch == text[text_index] => {
+                pattern_index += 1;
+                text_index += 1;
+            }
+            _ => match backtrack {
+                Some((star_index, star_text_index)) => {
+                    backtrack = Some((star_index, star_text_index + 1));
+                    pattern_index = star_index + 1;
+                    text_index = star_text_index + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[pattern_index..].iter().all(|ch| *ch == '*')
+}
+
+impl Username {
+    #[must_use]
+    pub fn validate_username(&self, username: &str) -> bool {
+        let username = username
+            .strip_prefix('@')
+            .unwrap_or(username)
+            .to_lowercase()
+            .chars()
+            .collect::<Vec<_>>();
+
+        self.patterns
+            .iter()
+            .any(|pattern| glob_match(&pattern.chars().collect::<Vec<_>>(), &username))
+            != self.exclude
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for Username {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        update
+            .chat()
+            .and_then(|chat| chat.username())
+            .map_or(false, |username| self.validate_username(username))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chat_id() {
+        let filter = ChatId::in_([1, 2]);
+
+        assert!(filter.validate_chat_id(1));
+        assert!(!filter.validate_chat_id(3));
+
+        let filter = ChatId::not_in([1, 2]);
+
+        assert!(!filter.validate_chat_id(1));
+        assert!(filter.validate_chat_id(3));
+    }
+
+    #[test]
+    fn test_username() {
+        let filter = Username::matches_any(["@admin_*", "telers_?ot"]);
+
+        assert!(filter.validate_username("admin_chat"));
+        assert!(filter.validate_username("Admin_"));
+        assert!(filter.validate_username("@ADMIN_chat"));
+        assert!(filter.validate_username("telers_bot"));
+        assert!(!filter.validate_username("telers_boot"));
+        assert!(!filter.validate_username("admin"));
+        assert!(!filter.validate_username("chat_admin_chat"));
+
+        let filter = Username::matches("*news*");
+
+        assert!(filter.validate_username("news"));
+        assert!(filter.validate_username("daily_news_channel"));
+        assert!(!filter.validate_username("new_channel"));
+
+        let filter = Username::matches_none(["*spam*"]);
+
+        assert!(filter.validate_username("channel"));
+        assert!(!filter.validate_username("spam_channel"));
+    }
+}
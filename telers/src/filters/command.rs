@@ -5,7 +5,6 @@ use crate::{
     context::Context,
     errors::SessionErrorKind,
     extractors::FromContext,
-    methods::GetMe,
     types::{BotCommand, Update, UpdateKind},
 };
 
@@ -14,6 +13,9 @@ use regex::Regex;
 use std::{borrow::Cow, iter::once};
 use tracing::{event, instrument, Level};
 
+/// Default command prefix
+pub const DEFAULT_PREFIX: char = '/';
+
 /// Represents a command pattern type for verification
 /// # Variants
 /// * [`PatternType::Text(Cow<str>)`] - A command pattern with text
@@ -62,12 +64,16 @@ impl From<Regex> for PatternType<'_> {
 /// # Notes
 /// You can use parsed command using [`CommandObject`] struct in handler arguments,
 /// or get it from [`Context`] by `command` key.
+///
+/// Commands with mention of other bot (for example, `/start@other_bot`) are rejected, if `ignore_mention` flag isn't set.
+/// Mention is compared with the bot username, which is cached by [`Bot::get_me`] method,
+/// so `getMe` request is sent only once.
 #[derive(Debug, Clone)]
 pub struct Command<'a> {
     /// List of commands ([`Cow`], [`BotCommand`] or compiled [`Regex`] patterns)
     commands: Box<[PatternType<'a>]>,
-    /// Command prefix
-    prefix: char,
+    /// List of allowed command prefixes
+    prefixes: Box<[char]>,
     /// Ignore case sensitive
     ignore_case: bool,
     /// Ignore bot mention
@@ -78,9 +84,11 @@ impl<'a> Command<'a> {
     /// Creates a new [`Command`] filter
     /// # Arguments
     /// * `commands` - List of commands (texts, [`BotCommand`] or compiled [`Regex`] patterns)
-    /// * `prefix` - Command prefix
+    /// * `prefix` - Command prefix. Use [`Command::prefixes`] to allow multiple prefixes
     /// * `ignore_case` - Ignore other command case
     /// * `ignore_mention` - Ignore bot mention
+    /// # Panics
+    /// If `ignore_case` is `true` and the regex pattern can't be compiled with `(?i)` flag
     #[must_use]
     #[instrument(skip(commands))]
     pub fn new<CommandType, Commands>(
@@ -107,7 +115,10 @@ impl<'a> Command<'a> {
                             event!(Level::WARN, "Ignore mention flag doesn't work with regexes");
                         }
 
-                        PatternType::Regex(regex)
+                        PatternType::Regex(
+                            Regex::new(&format!("(?i){}", regex.as_str()))
+                                .expect("Failed to compile regex with ignore case flag"),
+                        )
                     }
                 })
                 .collect()
@@ -131,12 +142,29 @@ impl<'a> Command<'a> {
 
         Self {
             commands,
-            prefix,
+            prefixes: Box::new([prefix]),
             ignore_case,
             ignore_mention,
         }
     }
 
+    /// Sets list of allowed command prefixes, for example, `/`, `!` or `.`
+    /// # Notes
+    /// If the list is empty, [`DEFAULT_PREFIX`] is used
+    #[must_use]
+    pub fn prefixes(self, val: impl IntoIterator<Item = char>) -> Self {
+        let prefixes: Box<[char]> = val.into_iter().collect();
+
+        Self {
+            prefixes: if prefixes.is_empty() {
+                Box::new([DEFAULT_PREFIX])
+            } else {
+                prefixes
+            },
+            ..self
+        }
+    }
+
     /// Creates a new [`Command`] filter with pass command
     /// # Notes
     /// - This method is just a shortcut to create a filter using the builder
@@ -181,6 +209,22 @@ impl<'a> Command<'a> {
         Self::builder().commands(commands).prefix(prefix).build()
     }
 
+    /// Creates a new [`Command`] filter with pass commands and list of allowed prefixes
+    /// # Notes
+    /// - This method is just a shortcut to create a filter using the builder
+    /// - By default, the prefix is `/`, so you can use [`Command::many`] instead. Use this method if you want to allow multiple prefixes, for example, `/`, `!` and `.`.
+    #[must_use]
+    pub fn many_with_prefixes<T, I>(commands: I, prefixes: impl IntoIterator<Item = char>) -> Self
+    where
+        T: Into<PatternType<'a>>,
+        I: IntoIterator<Item = T>,
+    {
+        Self::builder()
+            .commands(commands)
+            .prefixes(prefixes)
+            .build()
+    }
+
     #[must_use]
     pub fn builder() -> Builder<'a> {
         Builder::new()
@@ -192,7 +236,7 @@ impl Default for Command<'_> {
     fn default() -> Self {
         Self {
             commands: Box::new([]),
-            prefix: '/',
+            prefixes: Box::new([DEFAULT_PREFIX]),
             ignore_case: false,
             ignore_mention: false,
         }
@@ -202,7 +246,7 @@ impl Default for Command<'_> {
 #[derive(Debug, Clone)]
 pub struct Builder<'a> {
     commands: Vec<PatternType<'a>>,
-    prefix: char,
+    prefixes: Vec<char>,
     ignore_case: bool,
     ignore_mention: bool,
 }
@@ -237,10 +281,24 @@ impl<'a> Builder<'a> {
         }
     }
 
+    /// Adds allowed command prefix
+    /// # Notes
+    /// If no prefixes are added, [`DEFAULT_PREFIX`] is used
     #[must_use]
     pub fn prefix(self, val: char) -> Self {
         Self {
-            prefix: val,
+            prefixes: self.prefixes.into_iter().chain(once(val)).collect(),
+            ..self
+        }
+    }
+
+    /// Adds allowed command prefixes
+    /// # Notes
+    /// If no prefixes are added, [`DEFAULT_PREFIX`] is used
+    #[must_use]
+    pub fn prefixes(self, val: impl IntoIterator<Item = char>) -> Self {
+        Self {
+            prefixes: self.prefixes.into_iter().chain(val).collect(),
             ..self
         }
     }
@@ -265,10 +323,11 @@ impl<'a> Builder<'a> {
     pub fn build(self) -> Command<'a> {
         Command::new(
             self.commands,
-            self.prefix,
+            DEFAULT_PREFIX,
             self.ignore_case,
             self.ignore_mention,
         )
+        .prefixes(self.prefixes)
     }
 }
 
@@ -277,7 +336,7 @@ impl Default for Builder<'_> {
     fn default() -> Self {
         Self {
             commands: vec![],
-            prefix: '/',
+            prefixes: vec![],
             ignore_case: false,
             ignore_mention: false,
         }
@@ -285,14 +344,36 @@ impl Default for Builder<'_> {
 }
 
 impl Command<'_> {
+    /// Gets text commands, which can be shown in the bot command menu.
+    /// # Notes
+    /// Telegram command menu sends commands with `/` prefix,
+    /// so commands are returned only if the prefix is allowed.
+    /// Regex patterns are skipped, because they can't be shown in the menu.
+    #[must_use]
+    pub fn menu_commands(&self) -> Vec<&str> {
+        if !self.prefixes.contains(&DEFAULT_PREFIX) {
+            return vec![];
+        }
+
+        self.commands
+            .iter()
+            .filter_map(|pattern| match pattern {
+                PatternType::Text(command) => Some(command.as_ref()),
+                _ => None,
+            })
+            .collect()
+    }
+
     #[must_use]
     pub fn validate_prefix(&self, command: &CommandObject) -> bool {
-        command.prefix == self.prefix
+        self.prefixes.contains(&command.prefix)
     }
 
+    /// Checks that the command mention is the bot username (case-insensitive)
+    /// # Notes
+    /// Bot username is received by [`Bot::get_me`] method, which sends `getMe` request only if bot information isn't cached yet
     /// # Errors
     /// If error occurred in the process of sending request to the Telegram API or parsing response
-    #[allow(clippy::missing_panics_doc)]
     pub async fn validate_mention(
         &self,
         command: &CommandObject,
@@ -301,9 +382,10 @@ impl Command<'_> {
         if self.ignore_mention {
             Ok(true)
         } else if let Some(ref mention) = command.mention {
-            bot.send(GetMe {}).await.map(|user| {
-                // `unwrap` is safe here, because bot always has username
-                user.username.unwrap().eq(mention.as_ref())
+            bot.get_me().await.map(|user| {
+                user.username
+                    .as_deref()
+                    .map_or(false, |username| username.eq_ignore_ascii_case(mention))
             })
         } else {
             Ok(true)
@@ -620,5 +702,92 @@ mod tests {
         assert!(!command.validate_command(&command_obj));
     }
 
-    // TODO: Add tests for `validate_mention` method
+    #[test]
+    fn test_validate_prefixes() {
+        let command = Command::builder()
+            .prefixes(['/', '!'])
+            .prefix('.')
+            .command("start")
+            .build();
+
+        for text in ["/start", "!start", ".start"] {
+            let command_obj = CommandObject::extract(text).unwrap();
+            assert!(command.validate_prefix(&command_obj));
+        }
+
+        let command_obj = CommandObject::extract("?start").unwrap();
+        assert!(!command.validate_prefix(&command_obj));
+
+        // Default prefix is used, if no prefixes are added
+        let command = Command::one("start");
+
+        let command_obj = CommandObject::extract("/start").unwrap();
+        assert!(command.validate_prefix(&command_obj));
+
+        let command_obj = CommandObject::extract("!start").unwrap();
+        assert!(!command.validate_prefix(&command_obj));
+
+        let command = Command::new(["start"], '!', false, false);
+
+        let command_obj = CommandObject::extract("!start").unwrap();
+        assert!(command.validate_prefix(&command_obj));
+
+        let command_obj = CommandObject::extract("/start").unwrap();
+        assert!(!command.validate_prefix(&command_obj));
+
+        let command = command.prefixes(['/', '.']);
+
+        for (text, expected) in [("/start", true), (".start", true), ("!start", false)] {
+            let command_obj = CommandObject::extract(text).unwrap();
+            assert_eq!(command.validate_prefix(&command_obj), expected);
+        }
+    }
+
+    #[test]
+    fn test_validate_command_regex_ignore_case() {
+        let command = Command::builder()
+            .command(Regex::new("^st(art|op)$").unwrap())
+            .ignore_case(true)
+            .build();
+
+        let command_obj = CommandObject::extract("/START").unwrap();
+        assert!(command.validate_command(&command_obj));
+
+        let command_obj = CommandObject::extract("/restart").unwrap();
+        assert!(!command.validate_command(&command_obj));
+    }
+
+    #[tokio::test]
+    async fn test_validate_mention() {
+        use crate::{client::Reqwest, types::User};
+
+        let bot = Bot::<Reqwest>::default();
+        // Set bot information, so `getMe` request isn't sent
+        bot.set_me(User {
+            username: Some("test_bot".into()),
+            ..Default::default()
+        });
+
+        let command = Command::one("start");
+
+        let command_obj = CommandObject::extract("/start").unwrap();
+        assert!(command.validate_mention(&command_obj, &bot).await.unwrap());
+
+        let command_obj = CommandObject::extract("/start@test_bot").unwrap();
+        assert!(command.validate_mention(&command_obj, &bot).await.unwrap());
+
+        let command_obj = CommandObject::extract("/start@Test_Bot").unwrap();
+        assert!(command.validate_mention(&command_obj, &bot).await.unwrap());
+
+        let command_obj = CommandObject::extract("/start@other_bot").unwrap();
+        assert!(!command.validate_mention(&command_obj, &bot).await.unwrap());
+
+        let command = Command::builder()
+            .command("start")
+            .ignore_mention(true)
+            .build();
+
+        let command_obj = CommandObject::extract("/start@other_bot").unwrap();
+        assert!(command.validate_mention(&command_obj, &bot).await.unwrap());
+    }
 }
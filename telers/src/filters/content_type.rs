@@ -39,6 +39,84 @@ impl ContentType {
             content_types: content_types.into_iter().map(Into::into).collect(),
         }
     }
+
+    /// Creates a new [`ContentType`] filter with all media content types allowed,
+    /// see [`ContentTypeEnum::media`] for the list of them
+    #[must_use]
+    pub fn any_media() -> Self {
+        Self::many(ContentTypeEnum::media())
+    }
+
+    /// Creates a new [`ContentType`] filter with all content types allowed except the specified
+    /// # Notes
+    /// You can use [`ContentTypeEnum`] or its string representation
+    #[must_use]
+    pub fn all_except<T, I>(content_types: I) -> Self
+    where
+        T: Into<ContentTypeEnum>,
+        I: IntoIterator<Item = T>,
+    {
+        let excluded = content_types
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<_>>();
+
+        Self::many(
+            ContentTypeEnum::all()
+                .into_iter()
+                .filter(|content_type| !excluded.contains(content_type)),
+        )
+    }
+
+    /// Allows one more content type
+    #[must_use]
+    pub fn content_type(self, val: impl Into<ContentTypeEnum>) -> Self {
+        Self {
+            content_types: self
+                .content_types
+                .into_vec()
+                .into_iter()
+                .chain(Some(val.into()))
+                .collect(),
+        }
+    }
+
+    /// Allows content types of both filters
+    #[must_use]
+    pub fn union(self, other: Self) -> Self {
+        Self {
+            content_types: self
+                .content_types
+                .into_vec()
+                .into_iter()
+                .chain(other.content_types.into_vec())
+                .collect(),
+        }
+    }
+
+    /// Disallows the specified content types
+    /// # Notes
+    /// You can use [`ContentTypeEnum`] or its string representation
+    #[must_use]
+    pub fn except<T, I>(self, content_types: I) -> Self
+    where
+        T: Into<ContentTypeEnum>,
+        I: IntoIterator<Item = T>,
+    {
+        let excluded = content_types
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<_>>();
+
+        Self {
+            content_types: self
+                .content_types
+                .into_vec()
+                .into_iter()
+                .filter(|content_type| !excluded.contains(content_type))
+                .collect(),
+        }
+    }
 }
 
 impl ContentType {
@@ -74,4 +152,30 @@ mod tests {
         assert!(filter.validate_content_type(ContentTypeEnum::Photo));
         assert!(!filter.validate_content_type(ContentTypeEnum::Audio));
     }
+
+    #[test]
+    fn test_content_type_composition() {
+        let filter = ContentType::any_media();
+
+        assert!(filter.validate_content_type(ContentTypeEnum::Photo));
+        assert!(filter.validate_content_type(ContentTypeEnum::Voice));
+        assert!(!filter.validate_content_type(ContentTypeEnum::Text));
+
+        let filter = filter
+            .except([ContentTypeEnum::Sticker])
+            .union(ContentType::one(ContentTypeEnum::Text))
+            .content_type(ContentTypeEnum::Location);
+
+        assert!(filter.validate_content_type(ContentTypeEnum::Photo));
+        assert!(filter.validate_content_type(ContentTypeEnum::Text));
+        assert!(filter.validate_content_type(ContentTypeEnum::Location));
+        assert!(!filter.validate_content_type(ContentTypeEnum::Sticker));
+
+        let filter = ContentType::all_except([ContentTypeEnum::Text, ContentTypeEnum::Sticker]);
+
+        assert!(filter.validate_content_type(ContentTypeEnum::Photo));
+        assert!(filter.validate_content_type(ContentTypeEnum::NewChatMembers));
+        assert!(!filter.validate_content_type(ContentTypeEnum::Text));
+        assert!(!filter.validate_content_type(ContentTypeEnum::Sticker));
+    }
 }
@@ -0,0 +1,247 @@
+use super::{base::Filter, message::message};
+
+use crate::{
+    client::Bot,
+    context::Context,
+    enums::MessageEntityType,
+    types::{MessageEntity, MessageEntityKind, TextMentionMessageEntity, Update},
+};
+
+use async_trait::async_trait;
+
+/// Filter for checking the entities of the message text or caption, for example, mentions, hashtags, cashtags or URLs.
+/// This filter checks if the message has at least one entity of the specified types,
+/// which value is equal to one of the specified values (if they are specified).
+/// # Notes
+/// Values are compared case-insensitively, prefixes of mentions, hashtags and cashtags (`@`, `#` and `$`) are optional,
+/// so `Entity::hashtag("news")` matches `#news` and `#News`.
+#[derive(Debug, Clone)]
+pub struct Entity {
+    entity_types: Box<[MessageEntityType]>,
+    values: Box<[Box<str>]>,
+    mention_of_bot: bool,
+}
+
+impl Entity {
+    /// Creates a new [`Entity`] filter with one allowed entity type
+    /// # Notes
+    /// You can use [`MessageEntityType`] or its string representation
+    #[must_use]
+    pub fn one(entity_type: impl Into<MessageEntityType>) -> Self {
+        Self::many([entity_type])
+    }
+
+    /// Creates a new [`Entity`] filter with many allowed entity types
+    /// # Notes
+    /// You can use [`MessageEntityType`] or its string representation
+    #[must_use]
+    pub fn many<T, I>(entity_types: I) -> Self
+    where
+        T: Into<MessageEntityType>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            entity_types: entity_types.into_iter().map(Into::into).collect(),
+            values: Box::new([]),
+            mention_of_bot: false,
+        }
+    }
+
+    /// Creates a new [`Entity`] filter, which checks if the bot is mentioned by username or by text mention
+    /// # Notes
+    /// Bot information must be loaded to check mentions by username (see [`Bot::me`] for more information)
+    #[must_use]
+    pub fn mention_of_bot() -> Self {
+        Self {
+            mention_of_bot: true,
+            ..Self::many([MessageEntityType::Mention, MessageEntityType::TextMention])
+        }
+    }
+
+    /// Creates a new [`Entity`] filter with the username, which must be mentioned
+    #[must_use]
+    pub fn mention(username: impl Into<Box<str>>) -> Self {
+        Self::one(MessageEntityType::Mention).value(username)
+    }
+
+    /// Creates a new [`Entity`] filter with the hashtag, which must be in the message
+    #[must_use]
+    pub fn hashtag(val: impl Into<Box<str>>) -> Self {
+        Self::one(MessageEntityType::Hashtag).value(val)
+    }
+
+    /// Creates a new [`Entity`] filter with the cashtag, which must be in the message
+    #[must_use]
+    pub fn cashtag(val: impl Into<Box<str>>) -> Self {
+        Self::one(MessageEntityType::Cashtag).value(val)
+    }
+
+    /// Creates a new [`Entity`] filter, which checks if the message has URL or text link
+    #[must_use]
+    pub fn url() -> Self {
+        Self::many([MessageEntityType::Url, MessageEntityType::TextLink])
+    }
+
+    /// Adds the value, which the entity can be equal to
+    #[must_use]
+    pub fn value(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            values: self
+                .values
+                .into_vec()
+                .into_iter()
+                .chain(Some(val.into()))
+                .collect(),
+            ..self
+        }
+    }
+
+    /// Adds the values, which the entity can be equal to
+    #[must_use]
+    pub fn values<T, I>(self, val: I) -> Self
+    where
+        T: Into<Box<str>>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            values: self
+                .values
+                .into_vec()
+                .into_iter()
+                .chain(val.into_iter().map(Into::into))
+                .collect(),
+            ..self
+        }
+    }
+}
+
+/// Strips the prefix of mentions, hashtags and cashtags
+fn strip_prefix(value: &str) -> &str {
+    value.strip_prefix(['@', '#', '$']).unwrap_or(value)
+}
+
+impl Entity {
+    /// Checks if the entity matches the filter
+    /// # Arguments
+    /// * `entity` - Entity of the message
+    /// * `text` - Text or caption of the message, which the entity belongs to
+    /// * `bot_id` - ID of the bot, which is used to check text mentions of the bot
+    /// * `bot_username` - Username of the bot, which is used to check mentions of the bot
+    #[must_use]
+    pub fn validate_entity(
+        &self,
+        entity: &MessageEntity,
+        text: &str,
+        bot_id: i64,
+        bot_username: Option<&str>,
+    ) -> bool {
+        if !self.entity_types.contains(&MessageEntityType::from(entity)) {
+            return false;
+        }
+
+        if self.mention_of_bot {
+            return match entity.kind {
+                MessageEntityKind::TextMention(TextMentionMessageEntity { ref user }) => {
+                    user.id == bot_id
+                }
+                _ => match (entity.extract_from(text), bot_username) {
+                    (Some(value), Some(username)) => {
+                        strip_prefix(value).eq_ignore_ascii_case(username)
+                    }
+                    _ => false,
+                },
+            };
+        }
+
+        if self.values.is_empty() {
+            return true;
+        }
+
+        let value = match entity.kind {
+            MessageEntityKind::TextLink(ref text_link) => Some(text_link.url.as_ref()),
+            _ => entity.extract_from(text),
+        };
+
+        value.map_or(false, |value| {
+            self.values
+                .iter()
+                .any(|allowed| strip_prefix(allowed).eq_ignore_ascii_case(strip_prefix(value)))
+        })
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for Entity
+where
+    Client: Send + Sync,
+{
+    async fn check(&self, bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        let Some(message) = message(update) else {
+            return false;
+        };
+        let (Some(entities), Some(text)) = (message.entities(), message.text_or_caption()) else {
+            return false;
+        };
+
+        let me = bot.me();
+        let bot_username = me.as_ref().and_then(|me| me.username.as_deref());
+
+        entities
+            .iter()
+            .any(|entity| self.validate_entity(entity, text, bot.bot_id, bot_username))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::User;
+
+    #[test]
+    fn test_entity() {
+        let text = "Hi @Telers_bot #News $BTC https://example.com";
+        let mention = MessageEntity::new_mention(3, 11);
+        let hashtag = MessageEntity::new_hashtag(15, 5);
+        let cashtag = MessageEntity::new_cashtag(21, 4);
+        let url = MessageEntity::new_url(26, 19);
+
+        let filter = Entity::mention_of_bot();
+
+        assert!(filter.validate_entity(&mention, text, 1, Some("telers_bot")));
+        assert!(!filter.validate_entity(&mention, text, 1, Some("other_bot")));
+        assert!(!filter.validate_entity(&mention, text, 1, None));
+        assert!(filter.validate_entity(
+            &MessageEntity::new_text_mention(
+                0,
+                2,
+                User {
+                    id: 1,
+                    ..Default::default()
+                }
+            ),
+            text,
+            1,
+            None
+        ));
+
+        assert!(Entity::hashtag("news").validate_entity(&hashtag, text, 1, None));
+        assert!(Entity::hashtag("#NEWS").validate_entity(&hashtag, text, 1, None));
+        assert!(!Entity::hashtag("sport").validate_entity(&hashtag, text, 1, None));
+        assert!(!Entity::hashtag("news").validate_entity(&mention, text, 1, None));
+
+        assert!(Entity::cashtag("btc").validate_entity(&cashtag, text, 1, None));
+        assert!(Entity::mention("@telers_bot").validate_entity(&mention, text, 1, None));
+
+        assert!(Entity::url().validate_entity(&url, text, 1, None));
+        assert!(Entity::url()
+            .value("https://example.com")
+            .validate_entity(&url, text, 1, None));
+        assert!(Entity::url().value("https://example.com").validate_entity(
+            &MessageEntity::new_text_link(0, 2, "https://example.com"),
+            text,
+            1,
+            None
+        ));
+        assert!(!Entity::one(MessageEntityType::Email).validate_entity(&url, text, 1, None));
+    }
+}
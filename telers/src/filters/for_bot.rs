@@ -0,0 +1,93 @@
+use super::base::Filter;
+
+use crate::{client::Bot, context::Context, types::Update};
+
+use async_trait::async_trait;
+
+/// Filter for checking the bot, which received the update, by its ID or tag (see [`Bot::with_tag`]).
+/// It's useful in multi-bot setups to bind routers and handlers to specific bots.
+#[derive(Debug, Default, Clone)]
+pub struct ForBot {
+    ids: Box<[i64]>,
+    tags: Box<[Box<str>]>,
+}
+
+impl ForBot {
+    /// Creates a new [`ForBot`] filter with one allowed bot ID
+    #[must_use]
+    pub fn id(id: i64) -> Self {
+        Self {
+            ids: Box::new([id]),
+            tags: Box::new([]),
+        }
+    }
+
+    /// Creates a new [`ForBot`] filter with many allowed bot IDs
+    #[must_use]
+    pub fn ids(ids: impl IntoIterator<Item = i64>) -> Self {
+        Self {
+            ids: ids.into_iter().collect(),
+            tags: Box::new([]),
+        }
+    }
+
+    /// Creates a new [`ForBot`] filter with one allowed bot tag
+    #[must_use]
+    pub fn tag(tag: impl Into<Box<str>>) -> Self {
+        Self {
+            ids: Box::new([]),
+            tags: Box::new([tag.into()]),
+        }
+    }
+
+    /// Creates a new [`ForBot`] filter with many allowed bot tags
+    #[must_use]
+    pub fn tags<T, I>(tags: I) -> Self
+    where
+        T: Into<Box<str>>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            ids: Box::new([]),
+            tags: tags.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl ForBot {
+    #[must_use]
+    pub fn validate<Client>(&self, bot: &Bot<Client>) -> bool {
+        self.ids.contains(&bot.bot_id)
+            || bot.tag().map_or(false, |tag| {
+                self.tags.iter().any(|allowed| **allowed == *tag)
+            })
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for ForBot
+where
+    Client: Sync,
+{
+    async fn check(&self, bot: &Bot<Client>, _update: &Update, _context: &Context) -> bool {
+        self.validate(bot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Reqwest;
+
+    #[test]
+    fn test_for_bot() {
+        let bot = Bot::<Reqwest>::new("1234567890:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA");
+        let tagged_bot = bot.clone().with_tag("support");
+
+        assert!(ForBot::id(1_234_567_890).validate(&bot));
+        assert!(!ForBot::ids([1, 2]).validate(&bot));
+        assert!(!ForBot::tag("support").validate(&bot));
+        assert!(ForBot::tag("support").validate(&tagged_bot));
+        assert!(!ForBot::tags(["sales", "news"]).validate(&tagged_bot));
+    }
+}
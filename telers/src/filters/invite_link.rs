@@ -0,0 +1,244 @@
+use super::base::Filter;
+
+use crate::{
+    client::Bot,
+    context::Context,
+    types::{ChatInviteLink, ChatJoinRequest, ChatMemberUpdated, Update, UpdateKind},
+};
+
+use async_trait::async_trait;
+use std::borrow::Cow;
+
+/// Filter for checking the chat invite link, which was used by the user to send the join request or to join the chat.
+/// This filter checks if the invite link name or creator ID is equal to one of the specified.
+/// # Notes
+/// This filter checks invite link data step by step using the logical operator `or`,
+/// so if at least one check is successful, the filter will return the value `true`.
+///
+/// Filter works with [`ChatJoinRequest`] and [`ChatMemberUpdated`] updates,
+/// for other updates it always returns `false`.
+#[derive(Debug, Clone)]
+pub struct InviteLink<'a> {
+    /// List of invite link names
+    names: Box<[Cow<'a, str>]>,
+    /// List of user IDs of the invite link creators
+    creator_ids: Box<[i64]>,
+}
+
+impl<'a> InviteLink<'a> {
+    /// Creates a new [`InviteLink`] filter
+    /// # Arguments
+    /// * `names` - List of invite link names
+    /// * `creator_ids` - List of user IDs of the invite link creators
+    /// # Notes
+    /// This filter checks invite link data step by step using the logical operator `or`,
+    /// so if at least one check is successful, the filter will return the value `true`.
+    pub fn new<T, I1, I2>(names: I1, creator_ids: I2) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+        I1: IntoIterator<Item = T>,
+        I2: IntoIterator<Item = i64>,
+    {
+        Self {
+            names: names.into_iter().map(Into::into).collect(),
+            creator_ids: creator_ids.into_iter().collect(),
+        }
+    }
+
+    /// Creates a new [`InviteLink`] filter with a single invite link name
+    /// # Notes
+    /// This method is just a shortcut to create a filter using the builder
+    #[must_use]
+    pub fn name(val: impl Into<Cow<'a, str>>) -> Self {
+        Self::builder().name(val).build()
+    }
+
+    /// Creates a new [`InviteLink`] filter with a list of invite link names
+    /// # Notes
+    /// This method is just a shortcut to create a filter using the builder
+    #[must_use]
+    pub fn names<T, I>(val: I) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+        I: IntoIterator<Item = T>,
+    {
+        Self::builder().names(val).build()
+    }
+
+    /// Creates a new [`InviteLink`] filter with a single user ID of the invite link creator
+    /// # Notes
+    /// This method is just a shortcut to create a filter using the builder
+    #[must_use]
+    pub fn creator_id(val: i64) -> Self {
+        Self::builder().creator_id(val).build()
+    }
+
+    /// Creates a new [`InviteLink`] filter with a list of user IDs of the invite link creators
+    /// # Notes
+    /// This method is just a shortcut to create a filter using the builder
+    #[must_use]
+    pub fn creator_ids(val: impl IntoIterator<Item = i64>) -> Self {
+        Self::builder().creator_ids(val).build()
+    }
+
+    #[must_use]
+    pub fn builder() -> Builder<'a> {
+        Builder::default()
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Builder<'a> {
+    names: Vec<Cow<'a, str>>,
+    creator_ids: Vec<i64>,
+}
+
+impl<'a> Builder<'a> {
+    #[must_use]
+    pub fn name(self, val: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            names: self.names.into_iter().chain(Some(val.into())).collect(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn names<T, I>(self, val: I) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            names: self
+                .names
+                .into_iter()
+                .chain(val.into_iter().map(Into::into))
+                .collect(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn creator_id(self, val: i64) -> Self {
+        Self {
+            creator_ids: self.creator_ids.into_iter().chain(Some(val)).collect(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn creator_ids(self, val: impl IntoIterator<Item = i64>) -> Self {
+        Self {
+            creator_ids: self.creator_ids.into_iter().chain(val).collect(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn build(self) -> InviteLink<'a> {
+        InviteLink::new(self.names, self.creator_ids)
+    }
+}
+
+impl InviteLink<'_> {
+    #[must_use]
+    pub fn validate_name(&self, name: &str) -> bool {
+        self.names
+            .iter()
+            .any(|allowed_name| allowed_name.as_ref() == name)
+    }
+
+    #[must_use]
+    pub fn validate_creator_id(&self, creator_id: i64) -> bool {
+        self.creator_ids.contains(&creator_id)
+    }
+
+    #[must_use]
+    pub fn validate(&self, invite_link: &ChatInviteLink) -> bool {
+        invite_link
+            .name
+            .as_deref()
+            .map_or(false, |name| self.validate_name(name))
+            || self.validate_creator_id(invite_link.creator.id)
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for InviteLink<'_> {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        let invite_link = match update.kind() {
+            UpdateKind::ChatJoinRequest(ChatJoinRequest { invite_link, .. })
+            | UpdateKind::ChatMember(ChatMemberUpdated { invite_link, .. })
+            | UpdateKind::MyChatMember(ChatMemberUpdated { invite_link, .. }) => invite_link,
+            _ => return false,
+        };
+
+        match invite_link {
+            Some(invite_link) => self.validate(invite_link),
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::User;
+
+    #[test]
+    fn test_validate_name() {
+        let filter = InviteLink::name("test");
+
+        assert!(filter.validate_name("test"));
+        assert!(!filter.validate_name("test2"));
+
+        let filter = InviteLink::names(["test", "test2"]);
+
+        assert!(filter.validate_name("test"));
+        assert!(filter.validate_name("test2"));
+    }
+
+    #[test]
+    fn test_validate_creator_id() {
+        let filter = InviteLink::creator_id(1);
+
+        assert!(filter.validate_creator_id(1));
+        assert!(!filter.validate_creator_id(2));
+
+        let filter = InviteLink::creator_ids([1, 2]);
+
+        assert!(filter.validate_creator_id(1));
+        assert!(filter.validate_creator_id(2));
+    }
+
+    #[test]
+    fn test_validate() {
+        let invite_link = ChatInviteLink {
+            invite_link: "https://t.me/+test".into(),
+            creator: User {
+                id: 1,
+                ..Default::default()
+            },
+            creates_join_request: true,
+            is_primary: false,
+            is_revoked: false,
+            name: Some("test".into()),
+            expire_date: None,
+            member_limit: None,
+            pending_join_request_count: None,
+        };
+
+        assert!(InviteLink::name("test").validate(&invite_link));
+        assert!(InviteLink::creator_id(1).validate(&invite_link));
+        assert!(InviteLink::builder()
+            .name("test2")
+            .creator_id(1)
+            .build()
+            .validate(&invite_link));
+        assert!(!InviteLink::builder()
+            .name("test2")
+            .creator_id(2)
+            .build()
+            .validate(&invite_link));
+    }
+}
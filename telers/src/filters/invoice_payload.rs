@@ -0,0 +1,321 @@
+//! This module contains filters for routing payment flows by the invoice payload
+//! of successful payment messages, shipping queries and pre-checkout queries.
+//!
+//! [`InvoicePayload`] checks the payload as a string, for example, by the product prefix (`premium:` for `premium:1month`).
+//! [`TypedInvoicePayload`] checks if the payload can be deserialized from JSON to the type and optionally checks the deserialized value,
+//! so the value can be extracted to the handler argument with [`Payload`] extractor.
+//!
+//! ```rust,ignore
+//! #[derive(Deserialize)]
+//! struct Order {
+//!     product: String,
+//!     quantity: u32,
+//! }
+//!
+//! async fn pre_checkout(bot: Bot, query: PreCheckoutQuery, Payload(order): Payload<Order>) -> HandlerResult {
+//!     ...
+//! }
+//!
+//! router
+//!     .pre_checkout_query
+//!     .register(pre_checkout)
+//!     .filter(TypedInvoicePayload::<Order>::new().matches(|order| order.product == "coffee"));
+//! router
+//!     .message
+//!     .register(premium_paid)
+//!     .filter(InvoicePayload::prefix("premium:"));
+//! ```
+
+use super::base::Filter;
+
+use crate::{
+    client::Bot, context::Context, errors::ExtractionError, extractors::FromEventAndContext,
+    types::Update,
+};
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use std::{
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+    sync::Arc,
+};
+
+/// Filter for checking the invoice payload.
+/// This filter checks if the payload is equal to one of the specified payloads or starts with one of the specified prefixes.
+#[derive(Debug, Default, Clone)]
+pub struct InvoicePayload {
+    payloads: Box<[Box<str>]>,
+    prefixes: Box<[Box<str>]>,
+}
+
+impl InvoicePayload {
+    /// Creates a new [`InvoicePayload`] filter with the payload that must be equal to the invoice payload
+    #[must_use]
+    pub fn one(val: impl Into<Box<str>>) -> Self {
+        Self::default().payload(val)
+    }
+
+    /// Creates a new [`InvoicePayload`] filter with the payloads, one of which must be equal to the invoice payload
+    #[must_use]
+    pub fn many<T, I>(val: I) -> Self
+    where
+        T: Into<Box<str>>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            payloads: val.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new [`InvoicePayload`] filter with the prefix that must be at the beginning of the invoice payload
+    #[must_use]
+    pub fn prefix(val: impl Into<Box<str>>) -> Self {
+        Self::default().with_prefix(val)
+    }
+
+    /// Creates a new [`InvoicePayload`] filter with the prefixes, one of which must be at the beginning of the invoice payload
+    #[must_use]
+    pub fn prefixes<T, I>(val: I) -> Self
+    where
+        T: Into<Box<str>>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            prefixes: val.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Adds the payload that can be equal to the invoice payload
+    #[must_use]
+    pub fn payload(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            payloads: self
+                .payloads
+                .into_vec()
+                .into_iter()
+                .chain(Some(val.into()))
+                .collect(),
+            ..self
+        }
+    }
+
+    /// Adds the prefix that can be at the beginning of the invoice payload
+    #[must_use]
+    pub fn with_prefix(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            prefixes: self
+                .prefixes
+                .into_vec()
+                .into_iter()
+                .chain(Some(val.into()))
+                .collect(),
+            ..self
+        }
+    }
+}
+
+impl InvoicePayload {
+    #[must_use]
+    pub fn validate_payload(&self, payload: &str) -> bool {
+        self.payloads
+            .iter()
+            .any(|allowed| allowed.as_ref() == payload)
+            || self
+                .prefixes
+                .iter()
+                .any(|prefix| payload.starts_with(prefix.as_ref()))
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for InvoicePayload {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        update
+            .invoice_payload()
+            .map_or(false, |payload| self.validate_payload(payload))
+    }
+}
+
+type Predicate<T> = Arc<dyn Fn(&T) -> bool + Send + Sync>;
+
+/// Filter for checking if the invoice payload can be deserialized from JSON to the type
+/// and the deserialized value matches the predicate, if it's specified
+pub struct TypedInvoicePayload<T> {
+    predicate: Option<Predicate<T>>,
+    _payload: PhantomData<fn() -> T>,
+}
+
+impl<T> TypedInvoicePayload<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            predicate: None,
+            _payload: PhantomData,
+        }
+    }
+
+    /// Sets the predicate, which the deserialized payload must match
+    #[must_use]
+    pub fn matches<F>(self, predicate: F) -> Self
+    where
+        F: Fn(&T) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            predicate: Some(Arc::new(predicate)),
+            ..self
+        }
+    }
+}
+
+impl<T> TypedInvoicePayload<T>
+where
+    T: DeserializeOwned,
+{
+    #[must_use]
+    pub fn validate_payload(&self, payload: &str) -> bool {
+        match serde_json::from_str::<T>(payload) {
+            Ok(payload) => self
+                .predicate
+                .as_ref()
+                .map_or(true, |predicate| predicate(&payload)),
+            Err(_) => false,
+        }
+    }
+}
+
+impl<T> Default for TypedInvoicePayload<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for TypedInvoicePayload<T> {
+    fn clone(&self) -> Self {
+        Self {
+            predicate: self.predicate.clone(),
+            _payload: PhantomData,
+        }
+    }
+}
+
+impl<T> Debug for TypedInvoicePayload<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypedInvoicePayload")
+            .field("payload", &std::any::type_name::<T>())
+            .field("has_predicate", &self.predicate.is_some())
+            .finish()
+    }
+}
+
+#[async_trait]
+impl<Client, T> Filter<Client> for TypedInvoicePayload<T>
+where
+    T: DeserializeOwned,
+{
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        update
+            .invoice_payload()
+            .map_or(false, |payload| self.validate_payload(payload))
+    }
+}
+
+/// Invoice payload of the update deserialized from JSON.
+/// # Notes
+/// Extraction is unsuccessful if the update doesn't contain the invoice payload or it can't be deserialized to the type.
+/// Use [`TypedInvoicePayload`] filter to skip such updates before the handler is called.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Payload<T>(pub T);
+
+impl<Client, T> FromEventAndContext<Client> for Payload<T>
+where
+    T: DeserializeOwned,
+{
+    type Error = ExtractionError;
+
+    fn extract(
+        _bot: Arc<Bot<Client>>,
+        update: Arc<Update>,
+        _context: Arc<Context>,
+    ) -> Result<Self, Self::Error> {
+        let payload = update
+            .invoice_payload()
+            .ok_or_else(|| ExtractionError::new("Update doesn't contain invoice payload"))?;
+
+        serde_json::from_str(payload).map(Payload).map_err(|err| {
+            ExtractionError::new(format!("Failed to deserialize invoice payload: {err}"))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::Reqwest,
+        types::{PreCheckoutQuery, UpdateKind},
+    };
+
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Order {
+        product: String,
+        quantity: u32,
+    }
+
+    #[test]
+    fn test_invoice_payload() {
+        let filter = InvoicePayload::prefix("premium:").payload("donation");
+
+        assert!(filter.validate_payload("premium:1month"));
+        assert!(filter.validate_payload("donation"));
+        assert!(!filter.validate_payload("donation:1"));
+        assert!(!filter.validate_payload("premium"));
+    }
+
+    #[test]
+    fn test_typed_invoice_payload() {
+        let filter = TypedInvoicePayload::<Order>::new();
+
+        assert!(filter.validate_payload(r#"{"product": "coffee", "quantity": 2}"#));
+        assert!(!filter.validate_payload("coffee"));
+
+        let filter = filter.matches(|order| order.product == "tea");
+
+        assert!(filter.validate_payload(r#"{"product": "tea", "quantity": 1}"#));
+        assert!(!filter.validate_payload(r#"{"product": "coffee", "quantity": 2}"#));
+    }
+
+    #[test]
+    fn test_payload_extract() {
+        let bot = Arc::new(Bot::<Reqwest>::default());
+        let context = Arc::new(Context::default());
+        let update = Arc::new(Update {
+            id: 0,
+            kind: UpdateKind::PreCheckoutQuery(PreCheckoutQuery {
+                id: "1".into(),
+                from: crate::types::User::default(),
+                currency: "XTR".into(),
+                total_amount: 1,
+                invoice_payload: r#"{"product": "coffee", "quantity": 2}"#.into(),
+                shipping_option_id: None,
+                order_info: None,
+            }),
+        });
+
+        let Payload(order) =
+            Payload::<Order>::extract(bot.clone(), update, context.clone()).unwrap();
+        assert_eq!(
+            order,
+            Order {
+                product: "coffee".to_owned(),
+                quantity: 2
+            }
+        );
+
+        assert!(Payload::<Order>::extract(bot, Arc::new(Update::default()), context).is_err());
+    }
+}
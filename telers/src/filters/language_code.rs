@@ -0,0 +1,82 @@
+use super::base::Filter;
+
+use crate::{client::Bot, context::Context, extractors::Locale, types::Update};
+
+use async_trait::async_trait;
+
+/// Filter for checking the language of the user.
+/// Locale resolved by [`LocaleResolver`] middleware is preferred, otherwise the language code of the user is checked.
+/// # Notes
+/// Language codes are compared case-insensitively and the language without the region matches all its regions,
+/// so `en` matches `en`, `en-US` and `en-GB`, but `en-US` matches only `en-US`.
+///
+/// [`LocaleResolver`]: crate::middlewares::outer::LocaleResolver
+#[derive(Debug, Clone)]
+pub struct LanguageCode {
+    language_codes: Box<[Box<str>]>,
+}
+
+impl LanguageCode {
+    /// Creates a new [`LanguageCode`] filter with one allowed language code
+    #[must_use]
+    pub fn one(language_code: impl Into<Box<str>>) -> Self {
+        Self {
+            language_codes: [language_code.into()].into(),
+        }
+    }
+
+    /// Creates a new [`LanguageCode`] filter with many allowed language codes
+    #[must_use]
+    pub fn many<T, I>(language_codes: I) -> Self
+    where
+        T: Into<Box<str>>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            language_codes: language_codes.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl LanguageCode {
+    #[must_use]
+    pub fn validate_language_code(&self, language_code: &str) -> bool {
+        self.language_codes.iter().any(|allowed_language_code| {
+            let Some(rest) = language_code.get(..allowed_language_code.len()) else {
+                return false;
+            };
+
+            rest.eq_ignore_ascii_case(allowed_language_code)
+                && matches!(
+                    language_code.as_bytes().get(allowed_language_code.len()),
+                    None | Some(b'-' | b'_')
+                )
+        })
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for LanguageCode {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, context: &Context) -> bool {
+        Locale::get(update, context).map_or(false, |locale| self.validate_language_code(&locale))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_language_code() {
+        let filter = LanguageCode::many(["ru", "uk", "en-US"]);
+
+        assert!(filter.validate_language_code("ru"));
+        assert!(filter.validate_language_code("UK"));
+        assert!(filter.validate_language_code("ru-RU"));
+        assert!(filter.validate_language_code("en-us"));
+        assert!(!filter.validate_language_code("en"));
+        assert!(!filter.validate_language_code("en-GB"));
+        assert!(!filter.validate_language_code("rus"));
+        assert!(!filter.validate_language_code("r"));
+    }
+}
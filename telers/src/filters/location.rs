@@ -0,0 +1,70 @@
+use super::{base::Filter, message::message};
+
+use crate::{
+    client::Bot,
+    context::Context,
+    types::{Location as LocationType, Update},
+};
+
+use async_trait::async_trait;
+
+/// Filter for checking if the location of the message is within the radius of the specified point.
+/// # Notes
+/// Location of the venue is checked too.
+/// Live location updates are received as edited messages, so register the filter for `edited_message` observer to check them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Location {
+    latitude: f64,
+    longitude: f64,
+    radius: f64,
+}
+
+impl Location {
+    /// Creates a new [`Location`] filter with the center point and the radius in meters
+    #[must_use]
+    pub const fn within(latitude: f64, longitude: f64, radius: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+            radius,
+        }
+    }
+}
+
+impl Location {
+    /// Checks if the location is within the radius.
+    /// Horizontal accuracy of the location isn't taken into account.
+    #[must_use]
+    pub fn validate_location(&self, location: &LocationType) -> bool {
+        location.distance_to(self.latitude, self.longitude) <= self.radius
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for Location {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        let Some(message) = message(update) else {
+            return false;
+        };
+
+        message
+            .location()
+            .or_else(|| message.venue().map(|venue| &venue.location))
+            .map_or(false, |location| self.validate_location(location))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location() {
+        let filter = Location::within(55.7558, 37.6173, 1_000.0);
+
+        assert!(filter.validate_location(&LocationType::new(55.7558, 37.6173)));
+        assert!(filter.validate_location(&LocationType::new(55.76, 37.62)));
+        assert!(!filter.validate_location(&LocationType::new(55.8, 37.6173)));
+        assert!(!filter.validate_location(&LocationType::new(59.9343, 30.3351)));
+    }
+}
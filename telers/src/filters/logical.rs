@@ -57,7 +57,23 @@ macro_rules! impl_methods {
 impl_methods!(Or, or);
 impl_methods!(And, and);
 
+impl<Client> And<Client> {
+    fn inner_filters(&self) -> &[Arc<dyn Filter<Client>>] {
+        &self.filters
+    }
+}
+
+impl<Client> Or<Client> {
+    fn inner_filters(&self) -> &[Arc<dyn Filter<Client>>] {
+        &self.filters
+    }
+}
+
 impl<Client> Invert<Client> {
+    fn inner_filters(&self) -> &[Arc<dyn Filter<Client>>] {
+        std::slice::from_ref(&self.filter)
+    }
+
     pub fn new(filter: impl Filter<Client> + 'static) -> Self {
         Self {
             filter: Arc::new(filter),
@@ -115,6 +131,13 @@ macro_rules! impl_filter {
             async fn check(&self, bot: &Bot<Client>, update: &Update, context: &Context) -> bool {
                 self.validate(bot, update, context).await
             }
+
+            fn context_keys(&self) -> Vec<&'static str> {
+                self.inner_filters()
+                    .iter()
+                    .flat_map(|filter| filter.context_keys())
+                    .collect()
+            }
         }
     };
 }
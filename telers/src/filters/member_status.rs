@@ -0,0 +1,121 @@
+use super::{base::Filter, cached::CachedCheck};
+
+use crate::{
+    client::{Bot, Session},
+    context::Context,
+    enums::ChatMemberStatus,
+    methods::GetChatMember,
+    types::Update,
+};
+
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+/// Key of the cached status: bot ID, chat ID and user ID
+pub type MemberKey = (i64, i64, i64);
+
+/// Filter for checking the status of the user of the update in the chat of the update, for example, if the user is an administrator.
+/// Status is received by `getChatMember` request and cached with [`CachedCheck`],
+/// so repeated updates of the same user don't cause repeated requests.
+/// Updates without user or chat and failed requests don't pass the filter.
+#[derive(Debug, Clone)]
+pub struct MemberStatus {
+    statuses: Box<[ChatMemberStatus]>,
+    cache: CachedCheck<MemberKey, ChatMemberStatus>,
+}
+
+impl MemberStatus {
+    /// Creates a new [`MemberStatus`] filter with one allowed status
+    #[must_use]
+    pub fn one(status: ChatMemberStatus) -> Self {
+        Self::many([status])
+    }
+
+    /// Creates a new [`MemberStatus`] filter with many allowed statuses
+    #[must_use]
+    pub fn many(statuses: impl IntoIterator<Item = ChatMemberStatus>) -> Self {
+        Self {
+            statuses: statuses.into_iter().collect(),
+            cache: CachedCheck::default(),
+        }
+    }
+
+    /// Creates a new [`MemberStatus`] filter, which allows the owner and administrators of the chat
+    #[must_use]
+    pub fn admin() -> Self {
+        Self::many([ChatMemberStatus::Creator, ChatMemberStatus::Administrator])
+    }
+
+    /// Set the cache of the statuses, for example, to share it between filters or to change its TTL and maximum size
+    #[must_use]
+    pub fn cache(self, val: CachedCheck<MemberKey, ChatMemberStatus>) -> Self {
+        Self { cache: val, ..self }
+    }
+
+    #[must_use]
+    pub fn validate(&self, status: ChatMemberStatus) -> bool {
+        self.statuses.contains(&status)
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for MemberStatus
+where
+    Client: Session,
+{
+    async fn check(&self, bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        let (Some(chat_id), Some(user_id)) = (update.chat_id(), update.from_id()) else {
+            return false;
+        };
+
+        let status = self
+            .cache
+            .get_or_try_insert_with((bot.bot_id, chat_id, user_id), || async {
+                bot.send(GetChatMember::new(chat_id, user_id))
+                    .await
+                    .map(|member| ChatMemberStatus::from(&member))
+            })
+            .await;
+
+        match status {
+            Ok(status) => self.validate(status),
+            Err(err) => {
+                event!(Level::ERROR, error = %err, "Failed to get chat member status");
+
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Reqwest;
+
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_member_status() {
+        let bot = Bot::<Reqwest>::default();
+        let cache = CachedCheck::new(Duration::from_secs(60), 10);
+        let filter = MemberStatus::admin().cache(cache.clone());
+
+        assert!(filter.validate(ChatMemberStatus::Creator));
+        assert!(!filter.validate(ChatMemberStatus::Member));
+
+        // Cached status is used without sending the request
+        let update: Update = serde_json::from_str(
+            r#"{"update_id": 1, "message": {"message_id": 1, "date": 0, "chat": {"id": -100, "type": "supergroup", "title": "test"}, "from": {"id": 1, "is_bot": false, "first_name": "test"}, "text": "test"}}"#,
+        )
+        .unwrap();
+        cache.insert((bot.bot_id, -100, 1), ChatMemberStatus::Administrator);
+        assert!(filter.check(&bot, &update, &Context::new()).await);
+        assert!(
+            !MemberStatus::one(ChatMemberStatus::Left)
+                .cache(cache)
+                .check(&bot, &update, &Context::new())
+                .await
+        );
+    }
+}
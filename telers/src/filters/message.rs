@@ -0,0 +1,218 @@
+//! This module contains filters for checking common properties of the message,
+//! so you don't need to write closure filters for them.
+//!
+//! All filters check messages of `message`, `edited_message`, `channel_post` and `edited_channel_post` updates
+//! and return `false` for other updates.
+
+use super::base::Filter;
+
+use crate::{
+    client::Bot,
+    context::Context,
+    types::{Message, MessageEntityKind, Update, UpdateKind},
+};
+
+use async_trait::async_trait;
+
+/// Gets the message of `message`, `edited_message`, `channel_post` and `edited_channel_post` updates
+pub(crate) fn message(update: &Update) -> Option<&Message> {
+    match update.kind() {
+        UpdateKind::Message(message)
+        | UpdateKind::EditedMessage(message)
+        | UpdateKind::ChannelPost(message)
+        | UpdateKind::EditedChannelPost(message) => Some(message),
+        _ => None,
+    }
+}
+
+/// Filter for checking if the message has a caption
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HasCaption;
+
+impl HasCaption {
+    #[must_use]
+    pub fn validate(&self, message: &Message) -> bool {
+        message.caption().is_some()
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for HasCaption {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        message(update).map_or(false, |message| self.validate(message))
+    }
+}
+
+/// Filter for checking if the text or caption of the message has a link (URL or text link entity)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HasLink;
+
+impl HasLink {
+    #[must_use]
+    pub fn validate(&self, message: &Message) -> bool {
+        message.entities().map_or(false, |entities| {
+            entities.iter().any(|entity| {
+                matches!(
+                    entity.kind,
+                    MessageEntityKind::Url | MessageEntityKind::TextLink(_)
+                )
+            })
+        })
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for HasLink {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        message(update).map_or(false, |message| self.validate(message))
+    }
+}
+
+/// Filter for checking if the message was sent via inline bot.
+/// If bot IDs are specified, the message must be sent via one of them.
+#[derive(Debug, Default, Clone)]
+pub struct ViaBot {
+    bot_ids: Box<[i64]>,
+}
+
+impl ViaBot {
+    /// Creates a new [`ViaBot`] filter, which passes messages sent via any bot
+    #[must_use]
+    pub fn any() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`ViaBot`] filter, which passes messages sent via the bot with the specified ID
+    #[must_use]
+    pub fn id(val: i64) -> Self {
+        Self {
+            bot_ids: [val].into(),
+        }
+    }
+
+    /// Creates a new [`ViaBot`] filter, which passes messages sent via one of the bots with the specified IDs
+    #[must_use]
+    pub fn ids(val: impl IntoIterator<Item = i64>) -> Self {
+        Self {
+            bot_ids: val.into_iter().collect(),
+        }
+    }
+}
+
+impl ViaBot {
+    #[must_use]
+    pub fn validate(&self, message: &Message) -> bool {
+        match message.via_bot() {
+            Some(bot) => self.bot_ids.is_empty() || self.bot_ids.contains(&bot.id),
+            None => false,
+        }
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for ViaBot {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        message(update).map_or(false, |message| self.validate(message))
+    }
+}
+
+/// Filter for checking if the message is forwarded
+#[derive(Debug, Default, Clone, Copy)]
+pub struct IsForwarded;
+
+impl IsForwarded {
+    #[must_use]
+    pub fn validate(&self, message: &Message) -> bool {
+        message.forward_origin().is_some()
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for IsForwarded {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        message(update).map_or(false, |message| self.validate(message))
+    }
+}
+
+/// Filter for checking if the message is a channel post that was automatically forwarded to the connected discussion group
+#[derive(Debug, Default, Clone, Copy)]
+pub struct IsAutomaticForward;
+
+impl IsAutomaticForward {
+    #[must_use]
+    pub fn validate(&self, message: &Message) -> bool {
+        message.is_automatic_forward().unwrap_or(false)
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for IsAutomaticForward {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        message(update).map_or(false, |message| self.validate(message))
+    }
+}
+
+/// Filter for checking if the message is sent to a forum topic
+#[derive(Debug, Default, Clone, Copy)]
+pub struct IsTopicMessage;
+
+impl IsTopicMessage {
+    #[must_use]
+    pub fn validate(&self, message: &Message) -> bool {
+        message.is_topic_message().unwrap_or(false)
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for IsTopicMessage {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        message(update).map_or(false, |message| self.validate(message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{MessageEntity, MessageOriginHiddenUser, MessageText, User};
+
+    #[test]
+    fn test_message_filters() {
+        let text = Message::Text(Box::new(MessageText {
+            text: "https://example.com".into(),
+            entities: Some([MessageEntity::new_url(0, 19)].into()),
+            is_topic_message: Some(true),
+            via_bot: Some(User {
+                id: 1,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }));
+
+        assert!(HasLink.validate(&text));
+        assert!(!HasCaption.validate(&text));
+        assert!(IsTopicMessage.validate(&text));
+        assert!(!IsForwarded.validate(&text));
+        assert!(!IsAutomaticForward.validate(&text));
+        assert!(ViaBot::any().validate(&text));
+        assert!(ViaBot::ids([1, 2]).validate(&text));
+        assert!(!ViaBot::id(2).validate(&text));
+
+        let forwarded = Message::Text(Box::new(MessageText {
+            forward_origin: Some(
+                MessageOriginHiddenUser {
+                    date: 0,
+                    sender_user_name: "user".into(),
+                }
+                .into(),
+            ),
+            is_automatic_forward: Some(true),
+            ..Default::default()
+        }));
+
+        assert!(!HasLink.validate(&forwarded));
+        assert!(IsForwarded.validate(&forwarded));
+        assert!(IsAutomaticForward.validate(&forwarded));
+        assert!(!IsTopicMessage.validate(&forwarded));
+        assert!(!ViaBot::any().validate(&forwarded));
+    }
+}
@@ -0,0 +1,157 @@
+//! This module contains filters for checking the sender of the update,
+//! so you don't need to write closure filters for them.
+
+use super::base::Filter;
+
+use crate::{
+    client::Bot,
+    context::Context,
+    types::{Chat, Update},
+};
+
+use async_trait::async_trait;
+
+/// Filter for checking if the user of the update has Telegram Premium
+#[derive(Debug, Default, Clone, Copy)]
+pub struct IsPremium;
+
+impl IsPremium {
+    #[must_use]
+    pub fn validate(&self, update: &Update) -> bool {
+        update
+            .from()
+            .map_or(false, |user| user.is_premium.unwrap_or(false))
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for IsPremium {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        self.validate(update)
+    }
+}
+
+/// Filter for checking if the user of the update is a bot
+#[derive(Debug, Default, Clone, Copy)]
+pub struct IsBot;
+
+impl IsBot {
+    #[must_use]
+    pub fn validate(&self, update: &Update) -> bool {
+        update.from().map_or(false, |user| user.is_bot)
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for IsBot {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        self.validate(update)
+    }
+}
+
+/// Filter for checking if the update is sent on behalf of a channel,
+/// for example, channel posts, messages sent by users on behalf of their channels
+/// and posts automatically forwarded from the linked channel to the discussion group
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SenderIsChannel;
+
+impl SenderIsChannel {
+    #[must_use]
+    pub fn validate(&self, update: &Update) -> bool {
+        matches!(update.sender_chat(), Some(Chat::Channel(_)))
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for SenderIsChannel {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        self.validate(update)
+    }
+}
+
+/// Filter for checking if the update is sent by an anonymous administrator of the group on behalf of the group
+#[derive(Debug, Default, Clone, Copy)]
+pub struct IsAnonymousAdmin;
+
+impl IsAnonymousAdmin {
+    #[must_use]
+    pub fn validate(&self, update: &Update) -> bool {
+        match (update.sender_chat(), update.chat()) {
+            (Some(sender_chat), Some(chat)) => {
+                matches!(sender_chat, Chat::Group(_) | Chat::Supergroup(_))
+                    && sender_chat.id() == chat.id()
+            }
+            _ => false,
+        }
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for IsAnonymousAdmin {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        self.validate(update)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ChatChannel, ChatSupergroup, Message, MessageText, UpdateKind, User};
+
+    fn update(message: MessageText) -> Update {
+        Update {
+            id: 0,
+            kind: UpdateKind::Message(Message::Text(Box::new(message))),
+        }
+    }
+
+    #[test]
+    fn test_sender_filters() {
+        let group = Chat::Supergroup(ChatSupergroup {
+            id: -100,
+            title: "Group".into(),
+            username: None,
+            is_forum: None,
+        });
+        let channel = Chat::Channel(ChatChannel {
+            id: -200,
+            title: "Channel".into(),
+            username: None,
+        });
+
+        let from_user = update(MessageText {
+            from: Some(User {
+                is_bot: true,
+                is_premium: Some(true),
+                ..Default::default()
+            }),
+            chat: group.clone(),
+            ..Default::default()
+        });
+
+        assert!(IsPremium.validate(&from_user));
+        assert!(IsBot.validate(&from_user));
+        assert!(!SenderIsChannel.validate(&from_user));
+        assert!(!IsAnonymousAdmin.validate(&from_user));
+
+        let from_channel = update(MessageText {
+            chat: group.clone(),
+            sender_chat: Some(channel),
+            ..Default::default()
+        });
+
+        assert!(!IsPremium.validate(&from_channel));
+        assert!(!IsBot.validate(&from_channel));
+        assert!(SenderIsChannel.validate(&from_channel));
+        assert!(!IsAnonymousAdmin.validate(&from_channel));
+
+        let from_admin = update(MessageText {
+            chat: group.clone(),
+            sender_chat: Some(group),
+            ..Default::default()
+        });
+
+        assert!(!SenderIsChannel.validate(&from_admin));
+        assert!(IsAnonymousAdmin.validate(&from_admin));
+    }
+}
@@ -181,6 +181,10 @@ where
             None => self.check(None),
         }
     }
+
+    fn context_keys(&self) -> Vec<&'static str> {
+        vec!["fsm_state"]
+    }
 }
 
 #[cfg(test)]
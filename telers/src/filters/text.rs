@@ -37,9 +37,37 @@ impl From<Regex> for PatternType<'_> {
     }
 }
 
+/// Represents a source of the text for verification
+/// # Variants
+/// * [`Source::Text`]:
+/// The text of the message, the text of the inline query, the data of the callback query, etc. (default)
+/// * [`Source::Caption`]:
+/// The caption of the message, including the message of the callback query
+/// * [`Source::TextOrCaption`]:
+/// The text if it exists, otherwise the caption
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    #[default]
+    Text,
+    Caption,
+    TextOrCaption,
+}
+
+impl Source {
+    #[must_use]
+    pub const fn get<'a>(&self, update: &'a Update) -> Option<&'a str> {
+        match self {
+            Source::Text => update.text(),
+            Source::Caption => update.caption(),
+            Source::TextOrCaption => update.text_or_caption(),
+        }
+    }
+}
+
 /// This filter checks if the text matches the specified pattern
 /// # Notes
 /// Gets the text from the update, that is, the text of the message, the text of the inline query, the data of the callback query, etc.
+/// Use [`Text::source`] or [`Builder::source`] to check the caption of the message instead or the text or caption.
 #[derive(Debug, Default, Clone)]
 pub struct Text<'a> {
     /// List of texts or compiled [`Regex`] patterns that must be equal to the text
@@ -52,6 +80,8 @@ pub struct Text<'a> {
     ends_with: Box<[Cow<'a, str>]>,
     /// Ignore case sensitive
     ignore_case: bool,
+    /// Source of the text for verification
+    source: Source,
 }
 
 impl<'a> Text<'a> {
@@ -110,6 +140,7 @@ impl<'a> Text<'a> {
                     .map(|val| val.into().to_lowercase().into())
                     .collect(),
                 ignore_case,
+                source: Source::default(),
             }
         } else {
             Self {
@@ -118,6 +149,7 @@ impl<'a> Text<'a> {
                 starts_with: starts_with.into_iter().map(Into::into).collect(),
                 ends_with: ends_with.into_iter().map(Into::into).collect(),
                 ignore_case,
+                source: Source::default(),
             }
         }
     }
@@ -193,6 +225,15 @@ impl<'a> Text<'a> {
     pub fn builder() -> Builder<'a> {
         Builder::default()
     }
+
+    /// Sets the source of the text for verification
+    #[must_use]
+    pub fn source(self, val: Source) -> Self {
+        Self {
+            source: val,
+            ..self
+        }
+    }
 }
 
 #[derive(Debug, Default, Clone)]
@@ -202,6 +243,7 @@ pub struct Builder<'a> {
     starts_with: Vec<Cow<'a, str>>,
     ends_with: Vec<Cow<'a, str>>,
     ignore_case: bool,
+    source: Source,
 }
 
 impl<'a> Builder<'a> {
@@ -313,6 +355,14 @@ impl<'a> Builder<'a> {
         }
     }
 
+    #[must_use]
+    pub fn source(self, val: Source) -> Self {
+        Self {
+            source: val,
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn build(self) -> Text<'a> {
         Text::new(
@@ -322,6 +372,7 @@ impl<'a> Builder<'a> {
             self.ends_with,
             self.ignore_case,
         )
+        .source(self.source)
     }
 }
 
@@ -386,7 +437,9 @@ impl<'a> Text<'a> {
 #[async_trait]
 impl<Client> Filter<Client> for Text<'_> {
     async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
-        update.text().map_or(false, |text| self.validate_text(text))
+        self.source
+            .get(update)
+            .map_or(false, |text| self.validate_text(text))
     }
 }
 
@@ -538,4 +591,30 @@ mod tests {
         assert!(!text.validate_ends_with("FOOB"));
         assert!(text.validate_ends_with("OOBAR"));
     }
+
+    #[test]
+    fn text_source() {
+        use crate::types::{Message, MessageText, UpdateKind};
+
+        let update = Update {
+            id: 0,
+            kind: UpdateKind::Message(Message::Text(Box::new(MessageText {
+                text: "Hello".into(),
+                ..Default::default()
+            }))),
+        };
+
+        assert_eq!(Source::Text.get(&update), Some("Hello"));
+        assert_eq!(Source::Caption.get(&update), None);
+        assert_eq!(Source::TextOrCaption.get(&update), Some("Hello"));
+
+        let text = Text::builder()
+            .text("hello")
+            .ignore_case(true)
+            .source(Source::TextOrCaption)
+            .build();
+
+        assert_eq!(text.source, Source::TextOrCaption);
+        assert!(text.validate_text(Source::TextOrCaption.get(&update).unwrap()));
+    }
 }
@@ -25,6 +25,13 @@
 //! so we create [`StorageKey`] in the [`FSMContext middleware`] and pass it to the [`Context`]
 //! that wrap the [`StorageKey`] and [`Storage`] to provide more convenient API to work with the storage.
 //!
+//! Besides state and data of the FSM, you can store persistent data of the chat and the user, which is independent of the FSM state,
+//! with [`ChatData`] and [`UserData`] (see [`data module`] for more information).
+//! Current state can be extracted in handlers as the user type with [`CurrentState`] (see [`state module`] for more information).
+//! To export or delete all stored states and data of the user (for example, on GDPR requests), check the [`privacy module`].
+//! To wait for the next update of the user inside a single handler, check the [`conversation module`].
+//! Unlike states, waiting conversations are kept only in memory and aren't saved to the storage.
+//!
 //! You can check example of using FSM in the [`examples/finite_state_machine`].
 //!
 //! [`UserInChat`]: Strategy::UserInChat
@@ -32,13 +39,24 @@
 //! [`GlobalUser`]: Strategy::GlobalUser
 //! [`UserInThread`]: Strategy::UserInThread
 //! [`storage module`]: storage
+//! [`data module`]: data
+//! [`privacy module`]: privacy
+//! [`conversation module`]: conversation
+//! [`state module`]: state
 //! [`FSMContext middleware`]: crate::middlewares::outer::fsm_context::FSMContext
 
 pub mod context;
+pub mod conversation;
+pub mod data;
+pub mod privacy;
+pub mod state;
 pub mod storage;
 pub mod strategy;
 
 pub use context::Context;
+pub use conversation::Conversation;
+pub use data::{ChatData, UserData};
+pub use state::CurrentState;
 pub use storage::{Storage, StorageKey};
 pub use strategy::Strategy;
 
@@ -21,6 +21,12 @@ impl<S> Context<S> {
     pub fn new(storage: S, key: StorageKey) -> Self {
         Self { storage, key }
     }
+
+    /// Gets storage key of the context
+    #[must_use]
+    pub const fn key(&self) -> &StorageKey {
+        &self.key
+    }
 }
 
 impl<S> Clone for Context<S>
@@ -0,0 +1,464 @@
+//! This module contains [`Conversation`], which allows to wait for the next update of the user inside a single handler,
+//! so questionnaire-like flows can be written as linear code without splitting logic into many stateful handlers.
+//! Waiting conversations are kept in memory of the process and don't use the FSM [`Storage`] (see notes below).
+//!
+//! Updates are matched to waiting conversations by [`StorageKey`], which is built with the same [`Strategy`] as FSM context,
+//! so by default the conversation waits for updates of the same user in the same chat.
+//! When the update is received and matches a waiting conversation, it's passed to the conversation and isn't propagated to routers.
+//!
+//! [`Registry`] of waiting conversations is created by the dispatcher and put to the context by `conversation_registry` key,
+//! so you can use [`Conversation`] as an extractor in handlers.
+//! Strategy of the registry can be changed with [`DispatcherBuilder::conversation_strategy`] method.
+//!
+//! # Notes
+//! Dispatcher processes each update in a separate task, so handler, which waits for the next update, doesn't block other updates.
+//! If you feed updates manually, you need to process them concurrently as well.
+//!
+//! Only the key of the conversation is shared with FSM, waiting state itself isn't saved to the FSM storage:
+//! waiting conversations are lost on restart (handlers waiting for updates are dropped with the process)
+//! and aren't shared between several instances of the bot, even if the storage is shared.
+//! Use FSM states instead of conversations for flows, which must survive restarts or run on several instances.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use telers::{
+//!     enums::ContentType,
+//!     event::{telegram::HandlerResult, FlowControl},
+//!     fsm::Conversation,
+//!     methods::SendMessage,
+//!     types::Message,
+//!     Bot,
+//! };
+//!
+//! async fn handler(bot: Bot, message: Message, conversation: Conversation) -> HandlerResult {
+//!     let chat_id = message.chat().id();
+//!
+//!     bot.send(SendMessage::new(chat_id, "What is your name?")).await?;
+//!
+//!     let answer = conversation
+//!         .wait_for(ContentType::Text, Duration::from_secs(60))
+//!         .await?;
+//!
+//!     bot.send(SendMessage::new(
+//!         chat_id,
+//!         format!("Hello, {}!", answer.text().unwrap()),
+//!     ))
+//!     .await?;
+//!
+//!     Ok(FlowControl::Continue)
+//! }
+//! ```
+//!
+//! [`Storage`]: super::storage::Storage
+//! [`DispatcherBuilder::conversation_strategy`]: crate::dispatcher::Builder#method.conversation_strategy
+
+use super::{storage::StorageKey, strategy::Strategy};
+
+use crate::{
+    client::Bot,
+    context::Context,
+    enums::ContentType,
+    errors::{ExtractionError, HandlerError},
+    extractors::FromEventAndContext,
+    runtime::{self, Runtime},
+    types::{CallbackQuery, Message, Update, UpdateKind},
+};
+
+use dashmap::DashMap;
+use futures::future::{self, Either};
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use thiserror;
+use tokio::sync::oneshot;
+
+type Filter = Box<dyn Fn(&Update) -> bool + Send + Sync>;
+
+struct Waiter {
+    id: u64,
+    filter: Filter,
+    sender: oneshot::Sender<Arc<Update>>,
+}
+
+/// In-memory registry of conversations, which wait for updates.
+/// Waiting conversations aren't persisted, so they're lost on restart.
+pub struct Registry {
+    strategy: Strategy,
+    waiters: DashMap<StorageKey, Vec<Waiter>>,
+    next_id: AtomicU64,
+    runtime: Arc<dyn Runtime>,
+}
+
+impl Registry {
+    /// Creates a new registry, which uses the default runtime for timeouts of conversations
+    #[must_use]
+    pub fn new(strategy: Strategy) -> Self {
+        Self::with_runtime(strategy, runtime::default())
+    }
+
+    /// Creates a new registry, which uses the runtime for timeouts of conversations
+    #[must_use]
+    pub fn with_runtime(strategy: Strategy, runtime: Arc<dyn Runtime>) -> Self {
+        Self {
+            strategy,
+            waiters: DashMap::default(),
+            next_id: AtomicU64::default(),
+            runtime,
+        }
+    }
+
+    /// Builds the key of the conversation for the update
+    /// # Returns
+    /// `None` if the update doesn't contain the user
+    #[must_use]
+    pub fn key(&self, bot_id: i64, update: &Update) -> Option<StorageKey> {
+        let user_id = update.from_id()?;
+        let id_pair = self.strategy.apply(
+            update.chat_id().unwrap_or(user_id),
+            user_id,
+            update.message_thread_id(),
+        );
+
+        Some(StorageKey::new(
+            bot_id,
+            id_pair.chat_id,
+            id_pair.user_id,
+            id_pair.message_thread_id,
+        ))
+    }
+
+    /// Registers a new waiter for the key
+    /// # Returns
+    /// Receiver of the first update, which matches the key and the filter
+    pub fn register(
+        &self,
+        key: StorageKey,
+        filter: impl Fn(&Update) -> bool + Send + Sync + 'static,
+    ) -> oneshot::Receiver<Arc<Update>> {
+        self.register_waiter(key, filter).1
+    }
+
+    fn register_waiter(
+        &self,
+        key: StorageKey,
+        filter: impl Fn(&Update) -> bool + Send + Sync + 'static,
+    ) -> (u64, oneshot::Receiver<Arc<Update>>) {
+        let (sender, receiver) = oneshot::channel();
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+
+        self.waiters.entry(key).or_default().push(Waiter {
+            id,
+            filter: Box::new(filter),
+            sender,
+        });
+
+        (id, receiver)
+    }
+
+    /// Removes the waiter, if it isn't removed by [`Registry::dispatch`] yet
+    fn unregister(&self, key: &StorageKey, id: u64) {
+        if let Some(mut waiters) = self.waiters.get_mut(key) {
+            waiters.retain(|waiter| waiter.id != id);
+        }
+
+        self.waiters.remove_if(key, |_, waiters| waiters.is_empty());
+    }
+
+    /// Checks that there is a conversation, which waits for updates with the key
+    #[must_use]
+    pub fn is_waiting(&self, key: &StorageKey) -> bool {
+        self.waiters.get(key).map_or(false, |waiters| {
+            waiters.iter().any(|waiter| !waiter.sender.is_closed())
+        })
+    }
+
+    /// Passes the update to the first waiting conversation, which matches the update
+    /// # Returns
+    /// `true` if the update is consumed by a conversation, so it shouldn't be propagated further
+    pub fn dispatch(&self, bot_id: i64, update: &Arc<Update>) -> bool {
+        let Some(key) = self.key(bot_id, update) else {
+            return false;
+        };
+        let Some(mut waiters) = self.waiters.get_mut(&key) else {
+            return false;
+        };
+
+        // Conversations, which are timed out, aren't waiting anymore
+        waiters.retain(|waiter| !waiter.sender.is_closed());
+
+        let mut consumed = false;
+        while let Some(index) = waiters.iter().position(|waiter| (waiter.filter)(update)) {
+            if waiters
+                .remove(index)
+                .sender
+                .send(Arc::clone(update))
+                .is_ok()
+            {
+                consumed = true;
+                break;
+            }
+        }
+
+        let is_empty = waiters.is_empty();
+        drop(waiters);
+
+        if is_empty {
+            self.waiters
+                .remove_if(&key, |_, waiters| waiters.is_empty());
+        }
+
+        consumed
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self::new(Strategy::default())
+    }
+}
+
+/// Removes the waiter from the registry on drop, so it isn't left in the registry,
+/// if the conversation is timed out or the waiting future is dropped
+struct WaiterGuard<'a> {
+    registry: &'a Registry,
+    key: &'a StorageKey,
+    id: u64,
+}
+
+impl Drop for WaiterGuard<'_> {
+    fn drop(&mut self) {
+        self.registry.unregister(self.key, self.id);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    #[error("Conversation is timed out")]
+    Timeout,
+    #[error("Conversation registry is closed")]
+    Closed,
+}
+
+/// To possible to use `?` operator for conversation errors in handlers
+impl From<Error> for HandlerError {
+    fn from(err: Error) -> Self {
+        Self::new(err)
+    }
+}
+
+/// Conversation with the user of the current update.
+/// Check [module docs](crate::fsm::conversation) for more information.
+#[derive(Clone)]
+pub struct Conversation {
+    registry: Arc<Registry>,
+    key: StorageKey,
+}
+
+impl Conversation {
+    #[must_use]
+    pub fn new(registry: Arc<Registry>, key: StorageKey) -> Self {
+        Self { registry, key }
+    }
+
+    #[must_use]
+    pub const fn key(&self) -> &StorageKey {
+        &self.key
+    }
+
+    /// Waits for the next update of the conversation, which matches the filter
+    /// # Errors
+    /// - If the timeout is expired
+    /// - If the registry is dropped
+    pub async fn wait(
+        &self,
+        filter: impl Fn(&Update) -> bool + Send + Sync + 'static,
+        timeout: Duration,
+    ) -> Result<Arc<Update>, Error> {
+        let (id, receiver) = self.registry.register_waiter(self.key.clone(), filter);
+        let _guard = WaiterGuard {
+            registry: &self.registry,
+            key: &self.key,
+            id,
+        };
+
+        match future::select(receiver, self.registry.runtime.sleep(timeout)).await {
+            Either::Left((Ok(update), _)) => Ok(update),
+            Either::Left((Err(_), _)) => Err(Error::Closed),
+            Either::Right(((), _)) => Err(Error::Timeout),
+        }
+    }
+
+    /// Waits for the next message of the conversation
+    /// # Errors
+    /// - If the timeout is expired
+    /// - If the registry is dropped
+    pub async fn wait_for_message(&self, timeout: Duration) -> Result<Message, Error> {
+        let update = self
+            .wait(
+                |update| matches!(update.kind(), UpdateKind::Message(_)),
+                timeout,
+            )
+            .await?;
+
+        match update.kind() {
+            UpdateKind::Message(message) => Ok(message.clone()),
+            _ => unreachable!("Filter passes only messages"),
+        }
+    }
+
+    /// Waits for the next message of the conversation with the content type
+    /// # Errors
+    /// - If the timeout is expired
+    /// - If the registry is dropped
+    pub async fn wait_for(
+        &self,
+        content_type: ContentType,
+        timeout: Duration,
+    ) -> Result<Message, Error> {
+        let update = self
+            .wait(
+                move |update| match update.kind() {
+                    UpdateKind::Message(message) => ContentType::from(message) == content_type,
+                    _ => false,
+                },
+                timeout,
+            )
+            .await?;
+
+        match update.kind() {
+            UpdateKind::Message(message) => Ok(message.clone()),
+            _ => unreachable!("Filter passes only messages"),
+        }
+    }
+
+    /// Waits for the next callback query of the conversation
+    /// # Errors
+    /// - If the timeout is expired
+    /// - If the registry is dropped
+    pub async fn wait_for_callback_query(&self, timeout: Duration) -> Result<CallbackQuery, Error> {
+        let update = self
+            .wait(
+                |update| matches!(update.kind(), UpdateKind::CallbackQuery(_)),
+                timeout,
+            )
+            .await?;
+
+        match update.kind() {
+            UpdateKind::CallbackQuery(callback_query) => Ok(callback_query.clone()),
+            _ => unreachable!("Filter passes only callback queries"),
+        }
+    }
+}
+
+impl<Client> FromEventAndContext<Client> for Conversation {
+    type Error = ExtractionError;
+
+    fn extract(
+        bot: Arc<Bot<Client>>,
+        update: Arc<Update>,
+        context: Arc<Context>,
+    ) -> Result<Self, Self::Error> {
+        let registry = context
+            .get("conversation_registry")
+            .and_then(|registry| registry.downcast_ref::<Arc<Registry>>().cloned())
+            .ok_or_else(|| {
+                ExtractionError::new(
+                    "Conversation registry isn't found in the context by `conversation_registry` key",
+                )
+            })?;
+        let key = registry
+            .key(bot.bot_id, &update)
+            .ok_or_else(|| ExtractionError::new("Update doesn't contain the user"))?;
+
+        Ok(Self::new(registry, key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Chat, ChatPrivate, MessageText, User};
+
+    fn update(id: i64, text: &str) -> Arc<Update> {
+        Arc::new(Update {
+            id,
+            kind: UpdateKind::Message(Message::Text(Box::new(MessageText {
+                chat: Chat::Private(ChatPrivate {
+                    id: 1,
+                    ..Default::default()
+                }),
+                from: Some(User {
+                    id: 1,
+                    ..Default::default()
+                }),
+                text: text.into(),
+                ..Default::default()
+            }))),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_wait_for() {
+        let registry = Arc::new(Registry::default());
+        let conversation = Conversation::new(
+            Arc::clone(&registry),
+            registry.key(0, &update(0, "start")).unwrap(),
+        );
+
+        assert!(!registry.dispatch(0, &update(1, "not waited")));
+
+        let handle = tokio::spawn({
+            let conversation = conversation.clone();
+
+            async move {
+                conversation
+                    .wait_for(ContentType::Text, Duration::from_secs(10))
+                    .await
+            }
+        });
+
+        while !registry.is_waiting(conversation.key()) {
+            tokio::task::yield_now().await;
+        }
+
+        assert!(registry.dispatch(0, &update(2, "answer")));
+        assert_eq!(handle.await.unwrap().unwrap().text(), Some("answer"));
+        assert!(!registry.is_waiting(conversation.key()));
+    }
+
+    #[tokio::test]
+    async fn test_timeout() {
+        let registry = Arc::new(Registry::default());
+        let conversation = Conversation::new(
+            Arc::clone(&registry),
+            registry.key(0, &update(0, "start")).unwrap(),
+        );
+
+        assert_eq!(
+            conversation
+                .wait_for_message(Duration::from_millis(1))
+                .await
+                .unwrap_err(),
+            Error::Timeout
+        );
+        assert!(!registry.dispatch(0, &update(1, "late answer")));
+        assert!(registry.waiters.is_empty());
+
+        // Waiter is removed, even if the waiting future is dropped
+        let key = conversation.key().clone();
+        tokio::time::timeout(
+            Duration::from_millis(1),
+            conversation.wait_for_message(Duration::from_secs(10)),
+        )
+        .await
+        .unwrap_err();
+        assert!(!registry.is_waiting(&key));
+        assert!(registry.waiters.is_empty());
+    }
+}
@@ -0,0 +1,270 @@
+//! This module contains [`ChatData`] and [`UserData`], which are simple persistent per-chat and per-user stores
+//! backed by the FSM [`Storage`], but independent of the FSM state.
+//!
+//! Values are stored by their [`Data::KEY`], so each type of data (settings, statistics, etc.) is stored separately.
+//! Data of the chat is shared by all users of the chat and data of the user is shared by all chats of the user.
+//! Data isn't removed when FSM state is finished with [`Context::finish`](super::Context::finish),
+//! because it's stored by own keys with [`CHAT_DATA_DESTINY`] and [`USER_DATA_DESTINY`] destinies.
+//!
+//! [`ChatData`] and [`UserData`] are added to the context by [`FSMContext`] middleware,
+//! so you can get them in handlers as extractors.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use serde::{Deserialize, Serialize};
+//! use telers::{fsm::{data::Data, ChatData, MemoryStorage}, event::{simple::HandlerResult, FlowControl}};
+//!
+//! #[derive(Default, Serialize, Deserialize)]
+//! struct Settings {
+//!     language: Option<String>,
+//! }
+//!
+//! impl Data for Settings {
+//!     const KEY: &'static str = "settings";
+//! }
+//!
+//! async fn handler(chat_data: ChatData<MemoryStorage>) -> HandlerResult {
+//!     let settings = chat_data.get_or_default::<Settings>().await?;
+//!
+//!     chat_data
+//!         .update(|settings: &mut Settings| settings.language = Some("en".to_owned()))
+//!         .await?;
+//!
+//!     Ok(FlowControl::Continue)
+//! }
+//! ```
+//!
+//! [`FSMContext`]: crate::middlewares::outer::FSMContext
+
+use super::{Storage, StorageKey};
+
+use crate::extractors::FromContext;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Destiny of the storage keys of the chat data
+pub const CHAT_DATA_DESTINY: &str = "chat_data";
+/// Destiny of the storage keys of the user data
+pub const USER_DATA_DESTINY: &str = "user_data";
+
+/// Type of the data, which is stored in [`ChatData`] or [`UserData`]
+pub trait Data: Serialize + DeserializeOwned + Send {
+    /// Key of the data in the storage. It must be stable between restarts and unique for each type of data
+    const KEY: &'static str;
+}
+
+/// Store of the data, which is shared by methods of [`ChatData`] and [`UserData`]
+#[derive(Debug, Clone)]
+struct Store<S> {
+    storage: S,
+    key: StorageKey,
+}
+
+impl<S> Store<S>
+where
+    S: Storage,
+{
+    async fn get<T: Data>(&self) -> Result<Option<T>, S::Error> {
+        self.storage.get_value(&self.key, T::KEY).await
+    }
+
+    async fn set<T: Data>(&self, value: T) -> Result<(), S::Error> {
+        self.storage.set_value(&self.key, T::KEY, value).await
+    }
+
+    async fn update<T, F>(&self, f: F) -> Result<T, S::Error>
+    where
+        T: Data + Default + Clone,
+        F: FnOnce(&mut T) + Send,
+    {
+        let mut value = self.get::<T>().await?.unwrap_or_default();
+        f(&mut value);
+
+        self.set(value.clone()).await?;
+
+        Ok(value)
+    }
+
+    async fn clear(&self) -> Result<(), S::Error> {
+        self.storage.remove_data(&self.key).await
+    }
+}
+
+macro_rules! data_methods {
+    ($name:ident) => {
+        impl<S> $name<S>
+        where
+            S: Storage,
+        {
+            /// Get the data
+            /// # Errors
+            /// If storage error occurs, when get the data
+            /// # Returns
+            /// Data, if data isn't set, then `None` will be return
+            pub async fn get<T: Data>(&self) -> Result<Option<T>, S::Error> {
+                self.store.get().await
+            }
+
+            /// Get the data or the default value, if data isn't set
+            /// # Errors
+            /// If storage error occurs, when get the data
+            pub async fn get_or_default<T: Data + Default>(&self) -> Result<T, S::Error> {
+                Ok(self.store.get().await?.unwrap_or_default())
+            }
+
+            /// Set the data
+            /// # Errors
+            /// If storage error occurs, when set the data
+            pub async fn set<T: Data>(&self, value: T) -> Result<(), S::Error> {
+                self.store.set(value).await
+            }
+
+            /// Update the data with the function. If data isn't set, the default value is updated
+            /// # Errors
+            /// If storage error occurs, when get or set the data
+            /// # Returns
+            /// Updated data
+            /// # Notes
+            /// Update isn't atomic, so concurrent updates of the same data can overwrite each other
+            pub async fn update<T, F>(&self, f: F) -> Result<T, S::Error>
+            where
+                T: Data + Default + Clone,
+                F: FnOnce(&mut T) + Send,
+            {
+                self.store.update(f).await
+            }
+
+            /// Remove all data
+            /// # Errors
+            /// If storage error occurs, when remove the data
+            pub async fn clear(&self) -> Result<(), S::Error> {
+                self.store.clear().await
+            }
+        }
+    };
+}
+
+/// Persistent data of the chat, which is independent of the FSM state.
+/// Check the [module](self) documentation for more information.
+#[derive(Debug, Clone, FromContext)]
+#[context(
+    key = "fsm_chat_data",
+    description = "Persistent data of the chat. \
+    This context is available only if `FSMContext` middleware is used and `chat_id` in context is not empty."
+)]
+pub struct ChatData<S> {
+    store: Store<S>,
+}
+
+impl<S> ChatData<S> {
+    #[must_use]
+    pub fn new(storage: S, bot_id: i64, chat_id: i64) -> Self {
+        Self {
+            store: Store {
+                storage,
+                key: StorageKey::new(bot_id, chat_id, 0, None).destiny(CHAT_DATA_DESTINY),
+            },
+        }
+    }
+}
+
+data_methods!(ChatData);
+
+/// Persistent data of the user, which is independent of the FSM state.
+/// Check the [module](self) documentation for more information.
+#[derive(Debug, Clone, FromContext)]
+#[context(
+    key = "fsm_user_data",
+    description = "Persistent data of the user. \
+    This context is available only if `FSMContext` middleware is used and `user_id` in context is not empty."
+)]
+pub struct UserData<S> {
+    store: Store<S>,
+}
+
+impl<S> UserData<S> {
+    #[must_use]
+    pub fn new(storage: S, bot_id: i64, user_id: i64) -> Self {
+        Self {
+            store: Store {
+                storage,
+                key: StorageKey::new(bot_id, 0, user_id, None).destiny(USER_DATA_DESTINY),
+            },
+        }
+    }
+}
+
+data_methods!(UserData);
+
+#[cfg(all(test, feature = "memory-storage"))]
+mod tests {
+    use super::*;
+    use crate::fsm::MemoryStorage;
+
+    use serde::Deserialize;
+
+    #[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    struct Settings {
+        language: Option<String>,
+    }
+
+    impl Data for Settings {
+        const KEY: &'static str = "settings";
+    }
+
+    #[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    struct Counter(i64);
+
+    impl Data for Counter {
+        const KEY: &'static str = "counter";
+    }
+
+    #[tokio::test]
+    async fn test_chat_data() {
+        let storage = MemoryStorage::new();
+        let chat_data = ChatData::new(storage.clone(), 1, 2);
+
+        assert_eq!(chat_data.get::<Settings>().await.unwrap(), None);
+
+        chat_data
+            .set(Settings {
+                language: Some("en".to_owned()),
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            chat_data
+                .get_or_default::<Settings>()
+                .await
+                .unwrap()
+                .language
+                .as_deref(),
+            Some("en")
+        );
+
+        let counter = chat_data
+            .update(|counter: &mut Counter| counter.0 += 1)
+            .await
+            .unwrap();
+        assert_eq!(counter, Counter(1));
+        // Different types of data are stored separately
+        assert!(chat_data.get::<Settings>().await.unwrap().is_some());
+
+        // Data of the user with the same id isn't shared with the chat
+        let user_data = UserData::new(storage.clone(), 1, 2);
+        assert_eq!(user_data.get::<Counter>().await.unwrap(), None);
+
+        // Data isn't affected by the FSM state
+        let key = StorageKey::new(1, 2, 2, None);
+        storage
+            .set_value(&key, "counter", Counter(10))
+            .await
+            .unwrap();
+        storage.remove_data(&key).await.unwrap();
+        assert_eq!(chat_data.get::<Counter>().await.unwrap(), Some(Counter(1)));
+
+        chat_data.clear().await.unwrap();
+        assert_eq!(chat_data.get::<Counter>().await.unwrap(), None);
+    }
+}
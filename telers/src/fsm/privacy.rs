@@ -0,0 +1,239 @@
+//! This module contains utilities to export and delete all stored states and data of the user,
+//! which simplify privacy-compliance features (for example, GDPR requests) in bots storing personal data.
+//!
+//! States and data of the user are stored by different keys depending on the [`Strategy`] and the destiny,
+//! so [`UserKeys`] describes all of them:
+//! - FSM states and data with [`Strategy::UserInChat`] and [`Strategy::UserInThread`] for each known chat and thread of the user
+//! - FSM states and data with [`Strategy::GlobalUser`] and with [`Strategy::Chat`] in the private chat with the user
+//! - [`UserData`] of the user and [`ChatData`] of the private chat with the user
+//!
+//! Storage doesn't know which keys it contains in general (for example, redis keys can't be parsed back to [`StorageKey`]),
+//! so chats and threads of the user must be passed to [`UserKeys`] to build [`UserKeys::keys`].
+//! If the storage can enumerate its keys (for example, [`Memory::keys`]), use [`UserKeys::matches`] to find all keys of the user instead.
+//!
+//! ```ignore
+//! let user_keys = UserKeys::new(bot.bot_id, user_id).chats(known_chat_ids);
+//!
+//! // Export everything for the user in JSON format
+//! let dump = privacy::export(&storage, user_keys.keys()).await?;
+//! let json = serde_json::to_string_pretty(&dump)?;
+//!
+//! // Delete everything for the user
+//! privacy::purge(&storage, user_keys.keys()).await?;
+//!
+//! // Storage, which can enumerate its keys
+//! let keys = memory.keys().await.into_iter().filter(|key| user_keys.matches(key));
+//! privacy::purge(&memory, keys).await?;
+//! ```
+//!
+//! [`Strategy`]: super::Strategy
+//! [`Strategy::UserInChat`]: super::Strategy::UserInChat
+//! [`Strategy::UserInThread`]: super::Strategy::UserInThread
+//! [`Strategy::GlobalUser`]: super::Strategy::GlobalUser
+//! [`Strategy::Chat`]: super::Strategy::Chat
+//! [`UserData`]: super::UserData
+//! [`ChatData`]: super::ChatData
+//! [`Memory::keys`]: super::storage::Memory#method.keys
+
+use super::{
+    data::{CHAT_DATA_DESTINY, USER_DATA_DESTINY},
+    storage::{base::DEFAULT_DESTINY, Dump, Error},
+    Storage, StorageKey,
+};
+
+/// Keys of all states and data of the user.
+/// Check the [module](self) documentation for more information.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserKeys {
+    bot_id: i64,
+    user_id: i64,
+    chat_ids: Vec<i64>,
+    threads: Vec<(i64, i64)>,
+    destinies: Vec<&'static str>,
+}
+
+impl UserKeys {
+    /// Creates keys of the user with the default destiny of FSM states and data
+    #[must_use]
+    pub fn new(bot_id: i64, user_id: i64) -> Self {
+        Self {
+            bot_id,
+            user_id,
+            chat_ids: vec![],
+            threads: vec![],
+            destinies: vec![DEFAULT_DESTINY],
+        }
+    }
+
+    /// Add the chat, where the user has states and data
+    #[must_use]
+    pub fn chat(self, val: i64) -> Self {
+        Self {
+            chat_ids: self.chat_ids.into_iter().chain(Some(val)).collect(),
+            ..self
+        }
+    }
+
+    /// Add the chats, where the user has states and data
+    #[must_use]
+    pub fn chats(self, val: impl IntoIterator<Item = i64>) -> Self {
+        Self {
+            chat_ids: self.chat_ids.into_iter().chain(val).collect(),
+            ..self
+        }
+    }
+
+    /// Add the thread of the chat, where the user has states and data with [`Strategy::UserInThread`] strategy
+    ///
+    /// [`Strategy::UserInThread`]: super::Strategy::UserInThread
+    #[must_use]
+    pub fn thread(self, chat_id: i64, message_thread_id: i64) -> Self {
+        Self {
+            threads: self
+                .threads
+                .into_iter()
+                .chain(Some((chat_id, message_thread_id)))
+                .collect(),
+            ..self
+        }
+    }
+
+    /// Add the destiny of FSM states and data, if the `FSMContext` middleware is used with non-default destiny
+    #[must_use]
+    pub fn destiny(self, val: &'static str) -> Self {
+        Self {
+            destinies: self.destinies.into_iter().chain(Some(val)).collect(),
+            ..self
+        }
+    }
+
+    /// Gets all keys of the user for the known chats, threads and destinies without duplicates
+    #[must_use]
+    pub fn keys(&self) -> Vec<StorageKey> {
+        let Self {
+            bot_id, user_id, ..
+        } = *self;
+
+        let mut keys = vec![];
+
+        for &destiny in &self.destinies {
+            // `GlobalUser` strategy and `Chat` strategy in the private chat have the same key
+            keys.push(StorageKey::new(bot_id, user_id, user_id, None).destiny(destiny));
+
+            for chat_id in &self.chat_ids {
+                keys.push(StorageKey::new(bot_id, *chat_id, user_id, None).destiny(destiny));
+            }
+            for (chat_id, message_thread_id) in &self.threads {
+                keys.push(
+                    StorageKey::new(bot_id, *chat_id, user_id, Some(*message_thread_id))
+                        .destiny(destiny),
+                );
+            }
+        }
+
+        keys.push(StorageKey::new(bot_id, 0, user_id, None).destiny(USER_DATA_DESTINY));
+        keys.push(StorageKey::new(bot_id, user_id, 0, None).destiny(CHAT_DATA_DESTINY));
+
+        let mut unique = Vec::with_capacity(keys.len());
+        for key in keys {
+            if !unique.contains(&key) {
+                unique.push(key);
+            }
+        }
+        unique
+    }
+
+    /// Checks that the key belongs to the user: it's the key of the user in any chat, thread and destiny
+    /// or the key of the private chat with the user.
+    /// It's used to find all keys of the user in the storages, which can enumerate their keys.
+    #[must_use]
+    pub fn matches(&self, key: &StorageKey) -> bool {
+        key.bot_id == self.bot_id && (key.user_id == self.user_id || key.chat_id == self.user_id)
+    }
+}
+
+/// Exports states and data of the keys, which can be serialized to JSON.
+/// Keys without states and data are skipped.
+/// # Errors
+/// If storage error occurs, when get states or data
+pub async fn export<S>(
+    storage: &S,
+    keys: impl IntoIterator<Item = StorageKey>,
+) -> Result<Dump, Error>
+where
+    S: Storage,
+{
+    Dump::collect(storage, keys).await
+}
+
+/// Removes states and data of the keys
+/// # Errors
+/// If storage error occurs, when remove states or data
+pub async fn purge<S>(storage: &S, keys: impl IntoIterator<Item = StorageKey>) -> Result<(), Error>
+where
+    S: Storage,
+{
+    for key in keys {
+        storage.remove_states(&key).await.map_err(Into::into)?;
+        storage.remove_data(&key).await.map_err(Into::into)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "memory-storage"))]
+mod tests {
+    use super::*;
+    use crate::fsm::{data::Data, ChatData, MemoryStorage, UserData};
+
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct Settings(i64);
+
+    impl Data for Settings {
+        const KEY: &'static str = "settings";
+    }
+
+    #[tokio::test]
+    async fn test_export_purge() {
+        let storage = MemoryStorage::new();
+        let user_keys = UserKeys::new(1, 10).chat(-100).thread(-100, 5);
+
+        let in_chat = StorageKey::new(1, -100, 10, None);
+        let in_thread = StorageKey::new(1, -100, 10, Some(5));
+        let other_user = StorageKey::new(1, -100, 20, None);
+        let other_bot = StorageKey::new(2, -100, 10, None);
+
+        for key in [&in_chat, &in_thread, &other_user, &other_bot] {
+            storage.set_state(key, "state").await.unwrap();
+        }
+        storage.set_value(&in_chat, "name", "Alice").await.unwrap();
+        UserData::new(storage.clone(), 1, 10)
+            .set(Settings(1))
+            .await
+            .unwrap();
+        ChatData::new(storage.clone(), 1, 10)
+            .set(Settings(2))
+            .await
+            .unwrap();
+
+        let dump = export(&storage, user_keys.keys()).await.unwrap();
+        assert_eq!(dump.entries.len(), 4);
+        assert!(serde_json::to_string(&dump).unwrap().contains("Alice"));
+
+        let found = storage
+            .keys()
+            .await
+            .into_iter()
+            .filter(|key| user_keys.matches(key))
+            .count();
+        assert_eq!(found, 4);
+
+        purge(&storage, user_keys.keys()).await.unwrap();
+
+        let mut keys = storage.keys().await;
+        keys.sort_by_key(|key| key.bot_id);
+        assert_eq!(keys, [other_user, other_bot]);
+    }
+}
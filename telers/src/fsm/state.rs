@@ -0,0 +1,156 @@
+//! This module contains [`CurrentState`] extractor, which converts the current state of the FSM into the user type,
+//! so the handlers can match on the state without comparing strings.
+//!
+//! The state is loaded from the storage by [`FSMContext`] middleware, so it should be registered.
+//! The user type should implement [`TryFrom<&str>`], for example:
+//!
+//! ```rust
+//! use telers::fsm::CurrentState;
+//!
+//! enum State {
+//!     Name,
+//!     Language,
+//! }
+//!
+//! impl TryFrom<&str> for State {
+//!     type Error = ();
+//!
+//!     fn try_from(value: &str) -> Result<Self, Self::Error> {
+//!         match value {
+//!             "name" => Ok(State::Name),
+//!             "language" => Ok(State::Language),
+//!             _ => Err(()),
+//!         }
+//!     }
+//! }
+//!
+//! async fn handler(CurrentState(state): CurrentState<State>) {
+//!     match state {
+//!         State::Name => { /* ... */ }
+//!         State::Language => { /* ... */ }
+//!     }
+//! }
+//! ```
+//!
+//! Use `Option<CurrentState<S>>` if the handler can be called without the state.
+//!
+//! [`FSMContext`]: crate::middlewares::outer::FSMContext
+
+use crate::{
+    client::Bot, context::Context, errors::ExtractionError, extractors::FromEventAndContext,
+    types::Update,
+};
+
+use std::{any::type_name, ops::Deref, sync::Arc};
+
+/// Current state of the FSM converted into the user type by [`TryFrom<&str>`]
+/// # Notes
+/// Extraction is unsuccessful if [`FSMContext`] middleware isn't registered, the state isn't set
+/// or the state can't be converted into the user type.
+///
+/// [`FSMContext`]: crate::middlewares::outer::FSMContext
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CurrentState<S>(pub S);
+
+impl<S> Deref for CurrentState<S> {
+    type Target = S;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<Client, S> FromEventAndContext<Client> for CurrentState<S>
+where
+    S: for<'a> TryFrom<&'a str>,
+{
+    type Error = ExtractionError;
+
+    fn extract(
+        _bot: Arc<Bot<Client>>,
+        _update: Arc<Update>,
+        context: Arc<Context>,
+    ) -> Result<Self, Self::Error> {
+        let Some(state) = context.get("fsm_state") else {
+            // State is stored by the middleware only if it's set, so we check the context to give a clearer error
+            return Err(if context.contains_key("fsm_context") {
+                ExtractionError::new("State isn't set")
+            } else {
+                ExtractionError::new(
+                    "FSM context isn't found in the context by `fsm_context` key. \
+                    Check that `FSMContext` middleware is registered",
+                )
+            });
+        };
+        let state = state.downcast_ref::<Box<str>>().ok_or_else(|| {
+            ExtractionError::new("State in the context by `fsm_state` key isn't `Box<str>`")
+        })?;
+
+        S::try_from(state).map(CurrentState).map_err(|_| {
+            ExtractionError::new(format!(
+                "State `{state}` can't be converted into `{}`",
+                type_name::<S>(),
+            ))
+        })
+    }
+
+    fn context_keys() -> Vec<&'static str> {
+        vec!["fsm_state"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Reqwest;
+
+    #[derive(Debug, PartialEq)]
+    enum State {
+        Name,
+    }
+
+    impl TryFrom<&str> for State {
+        type Error = ();
+
+        fn try_from(value: &str) -> Result<Self, Self::Error> {
+            match value {
+                "name" => Ok(State::Name),
+                _ => Err(()),
+            }
+        }
+    }
+
+    fn extract(context: Context) -> Result<CurrentState<State>, ExtractionError> {
+        CurrentState::<State>::extract(
+            Arc::new(Bot::<Reqwest>::default()),
+            Arc::new(Update::default()),
+            Arc::new(context),
+        )
+    }
+
+    #[test]
+    fn test_extract() {
+        let context = Context::default();
+        context.insert("fsm_context", Box::new(()));
+        context.insert("fsm_state", Box::new(Box::<str>::from("name")));
+
+        assert_eq!(extract(context).unwrap(), CurrentState(State::Name));
+    }
+
+    #[test]
+    fn test_extract_errors() {
+        // Middleware isn't registered
+        assert!(extract(Context::default()).is_err());
+
+        // State isn't set
+        let context = Context::default();
+        context.insert("fsm_context", Box::new(()));
+        assert!(extract(context).is_err());
+
+        // State can't be converted
+        let context = Context::default();
+        context.insert("fsm_context", Box::new(()));
+        context.insert("fsm_state", Box::new(Box::<str>::from("unknown")));
+        assert!(extract(context).is_err());
+    }
+}
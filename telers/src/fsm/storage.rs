@@ -12,16 +12,29 @@
 //! Redis storage implementation.
 //! This is a thread-safe Redis storage implementation that persists data between restarts.
 //! This is the recommended storage implementation for production use.
+//!
+//! States and data can be migrated between storages with [`Dump`], which is a portable representation of them
+//! (see [`dump module`](dump) for more information).
+//!
+//! Custom storages can be checked for conformance with ready-made ones with [`test_suite!`] macro (feature: `test-utils`),
+//! see [`test_suite module`](test_suite) for more information.
 
 pub mod base;
+pub mod dump;
 #[cfg(feature = "memory-storage")]
 pub mod memory;
 #[cfg(feature = "redis-storage")]
 pub mod redis;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_suite;
 
 #[cfg(feature = "redis-storage")]
 pub use self::redis::{DefaultKeyBuilder, Redis};
+#[cfg(any(test, feature = "test-utils"))]
+#[doc(inline)]
+pub use crate::__storage_test_suite as test_suite;
 #[allow(clippy::module_name_repetitions)]
 pub use base::{Error, Storage, StorageKey};
+pub use dump::{Dump, DumpEntry};
 #[cfg(feature = "memory-storage")]
 pub use memory::Memory;
@@ -14,7 +14,9 @@ pub struct StorageKey {
     pub chat_id: i64,
     pub user_id: i64,
     pub message_thread_id: Option<i64>,
-    pub destiny: &'static str,
+    /// Destiny separates states and data of the same chat and user, for example, for several FSM contexts or storage users.
+    /// It's usually static, but it can be owned, for example, if the key is restored from the dump
+    pub destiny: Cow<'static, str>,
 }
 
 impl StorageKey {
@@ -25,13 +27,16 @@ impl StorageKey {
             chat_id,
             user_id,
             message_thread_id,
-            destiny: DEFAULT_DESTINY,
+            destiny: Cow::Borrowed(DEFAULT_DESTINY),
         }
     }
 
     #[must_use]
-    pub fn destiny(self, destiny: &'static str) -> Self {
-        Self { destiny, ..self }
+    pub fn destiny(self, destiny: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            destiny: destiny.into(),
+            ..self
+        }
     }
 }
 
@@ -0,0 +1,219 @@
+//! This module contains [`Dump`], which is a portable representation of states and data of the storage.
+//!
+//! Dump is used to migrate states and data between storages, for example, from [`Memory`] to [`Redis`],
+//! or between serialization formats of the same storage.
+//! Dump can be serialized to JSON (or any other self-describing format) and saved to the file,
+//! so states and data survive the migration, even if the storages aren't available at the same time.
+//!
+//! Storage doesn't know which keys it contains in general (for example, redis keys can't be parsed back to [`StorageKey`]),
+//! so keys are passed to [`Dump::collect`] explicitly. [`Memory::keys`] can be used to get all keys of the memory storage.
+//! Data values are read as [`serde_json::Value`], so storage must store them in a self-describing format.
+//!
+//! ```ignore
+//! let dump = Dump::collect(&memory, memory.keys().await).await?;
+//! std::fs::write("fsm.json", serde_json::to_vec(&dump)?)?;
+//!
+//! let dump: Dump = serde_json::from_slice(&std::fs::read("fsm.json")?)?;
+//! dump.restore(&redis).await?;
+//! ```
+//!
+//! [`Memory`]: super::Memory
+//! [`Memory::keys`]: super::Memory#method.keys
+//! [`Redis`]: super::Redis
+
+use super::{Error, Storage, StorageKey};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Version of the dump format, which is increased on incompatible changes
+pub const VERSION: u32 = 1;
+
+/// States and data of one storage key
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DumpEntry {
+    pub bot_id: i64,
+    pub chat_id: i64,
+    pub user_id: i64,
+    pub message_thread_id: Option<i64>,
+    pub destiny: Box<str>,
+    /// States stack, the last state is the current state
+    pub states: Box<[Box<str>]>,
+    pub data: HashMap<Box<str>, Value>,
+}
+
+impl DumpEntry {
+    /// Gets storage key of the entry
+    #[must_use]
+    pub fn key(&self) -> StorageKey {
+        StorageKey::new(
+            self.bot_id,
+            self.chat_id,
+            self.user_id,
+            self.message_thread_id,
+        )
+        .destiny(self.destiny.clone().into_string())
+    }
+}
+
+/// Portable representation of states and data of the storage.
+/// Check the [module](self) documentation for more information.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Dump {
+    pub version: u32,
+    pub entries: Vec<DumpEntry>,
+}
+
+impl Default for Dump {
+    fn default() -> Self {
+        Self {
+            version: VERSION,
+            entries: vec![],
+        }
+    }
+}
+
+impl Dump {
+    /// Collects states and data of the keys from the storage.
+    /// Keys without states and data are skipped.
+    /// # Errors
+    /// If storage error occurs, when get states or data, for example, data values can't be deserialized to [`Value`]
+    pub async fn collect<S>(
+        storage: &S,
+        keys: impl IntoIterator<Item = StorageKey>,
+    ) -> Result<Self, Error>
+    where
+        S: Storage,
+    {
+        let mut entries = vec![];
+
+        for key in keys {
+            let states = storage.get_states(&key).await.map_err(Into::into)?;
+            let data: HashMap<Box<str>, Value> =
+                storage.get_data(&key).await.map_err(Into::into)?;
+
+            if states.is_empty() && data.is_empty() {
+                continue;
+            }
+
+            entries.push(DumpEntry {
+                bot_id: key.bot_id,
+                chat_id: key.chat_id,
+                user_id: key.user_id,
+                message_thread_id: key.message_thread_id,
+                destiny: key.destiny.into(),
+                states,
+                data,
+            });
+        }
+
+        Ok(Self {
+            version: VERSION,
+            entries,
+        })
+    }
+
+    /// Restores states and data of the entries to the storage.
+    /// Existing states and data of the same keys are replaced.
+    /// # Errors
+    /// - If the dump version isn't supported
+    /// - If storage error occurs, when set states or data
+    pub async fn restore<S>(&self, storage: &S) -> Result<(), Error>
+    where
+        S: Storage,
+    {
+        if self.version != VERSION {
+            return Err(Error::new(
+                format!("Unsupported dump version: {}", self.version),
+                UnsupportedVersion,
+            ));
+        }
+
+        for entry in &self.entries {
+            let key = entry.key();
+
+            storage.remove_states(&key).await.map_err(Into::into)?;
+            for state in &*entry.states {
+                storage
+                    .set_state(&key, state.to_string())
+                    .await
+                    .map_err(Into::into)?;
+            }
+
+            storage
+                .set_data(
+                    &key,
+                    entry
+                        .data
+                        .iter()
+                        .map(|(value_key, value)| (value_key.to_string(), value))
+                        .collect::<HashMap<_, _>>(),
+                )
+                .await
+                .map_err(Into::into)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Unsupported dump version")]
+struct UnsupportedVersion;
+
+#[cfg(all(test, feature = "memory-storage"))]
+mod tests {
+    use super::*;
+    use crate::fsm::MemoryStorage;
+
+    #[tokio::test]
+    async fn test_dump_restore() {
+        let source = MemoryStorage::new();
+        let key = StorageKey::new(1, 2, 3, None);
+        let outbox_key = StorageKey::new(1, 0, 0, None).destiny("outbox");
+
+        source.set_state(&key, "first").await.unwrap();
+        source.set_state(&key, "second").await.unwrap();
+        source.set_value(&key, "name", "Alice").await.unwrap();
+        source.set_value(&key, "age", 30).await.unwrap();
+        source
+            .set_value(&outbox_key, "request", [1, 2])
+            .await
+            .unwrap();
+
+        let dump = Dump::collect(&source, source.keys().await).await.unwrap();
+        assert_eq!(dump.entries.len(), 2);
+
+        // Dump survives serialization
+        let dump: Dump = serde_json::from_str(&serde_json::to_string(&dump).unwrap()).unwrap();
+
+        let target = MemoryStorage::new();
+        target.set_state(&key, "stale").await.unwrap();
+        dump.restore(&target).await.unwrap();
+
+        assert_eq!(
+            &*target.get_states(&key).await.unwrap(),
+            [Box::from("first"), Box::from("second")]
+        );
+        assert_eq!(
+            target.get_value::<_, String>(&key, "name").await.unwrap(),
+            Some("Alice".to_owned())
+        );
+        assert_eq!(
+            target.get_value::<_, u8>(&key, "age").await.unwrap(),
+            Some(30)
+        );
+        assert_eq!(
+            target
+                .get_value::<_, Vec<u8>>(&outbox_key, "request")
+                .await
+                .unwrap(),
+            Some(vec![1, 2])
+        );
+
+        let mut dump = dump;
+        dump.version = 0;
+        assert!(dump.restore(&target).await.is_err());
+    }
+}
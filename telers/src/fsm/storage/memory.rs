@@ -10,6 +10,7 @@ use std::{
 use tokio::sync::Mutex;
 use tracing::{event, instrument, Level, Span};
 
+/// States and data of the key. Data values are serialized to JSON, so they can be exported to [`Dump`](super::Dump).
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 struct Record {
     states: Vec<Cow<'static, str>>,
@@ -36,6 +37,13 @@ impl Memory {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Gets all keys of the storage, for example, to collect [`Dump`] of the storage
+    ///
+    /// [`Dump`]: super::Dump
+    pub async fn keys(&self) -> Vec<StorageKey> {
+        self.storage.lock().await.keys().cloned().collect()
+    }
 }
 
 #[async_trait]
@@ -130,8 +138,13 @@ impl Storage for Memory {
     async fn remove_states(&self, key: &StorageKey) -> Result<(), Self::Error> {
         match self.storage.lock().await.entry(key.clone()) {
             Entry::Occupied(mut entry) => {
-                // We can't use `clear` method, because we don't need save allocated capacity
-                entry.get_mut().states = vec![];
+                // Record without states and data is removed, so the storage doesn't keep ids of the users, who are purged
+                if entry.get().data.is_empty() {
+                    entry.remove();
+                } else {
+                    // We can't use `clear` method, because we don't need save allocated capacity
+                    entry.get_mut().states = vec![];
+                }
             }
             Entry::Vacant(_) => {}
         }
@@ -167,7 +180,7 @@ impl Storage for Memory {
                 for (value_key, value) in data {
                     new_data.insert(
                         value_key.into(),
-                        bincode::serialize(&value)
+                        serde_json::to_vec(&value)
                             .map_err(|err| {
                                 event!(Level::ERROR, "Failed to serialize value");
 
@@ -196,7 +209,7 @@ impl Storage for Memory {
                 for (value_key, value) in data {
                     new_data.insert(
                         value_key.into(),
-                        bincode::serialize(&value)
+                        serde_json::to_vec(&value)
                             .map_err(|err| {
                                 event!(Level::ERROR, "Failed to serialize value");
 
@@ -242,7 +255,7 @@ impl Storage for Memory {
             Entry::Occupied(mut entry) => {
                 entry.get_mut().data.insert(
                     value_key,
-                    bincode::serialize(&value)
+                    serde_json::to_vec(&value)
                         .map_err(|err| {
                             event!(Level::ERROR, "Failed to serialize value");
 
@@ -261,7 +274,7 @@ impl Storage for Memory {
                         let mut new_data = HashMap::with_capacity(1);
                         new_data.insert(
                             value_key,
-                            bincode::serialize(&value)
+                            serde_json::to_vec(&value)
                                 .map_err(|err| {
                                     event!(Level::ERROR, "Failed to serialize value");
 
@@ -303,7 +316,7 @@ impl Storage for Memory {
                 for (value_key, value) in entry_data {
                     data.insert(
                         value_key.as_ref().into(),
-                        bincode::deserialize(value).map_err(|err| {
+                        serde_json::from_slice(value).map_err(|err| {
                             event!(Level::ERROR, "Failed to deserialize value");
 
                             Error::new(
@@ -342,7 +355,7 @@ impl Storage for Memory {
 
         match self.storage.lock().await.entry(key.clone()) {
             Entry::Occupied(entry) => entry.get().data.get(&value_key).map_or(Ok(None), |value| {
-                Ok(Some(bincode::deserialize(value).map_err(|err| {
+                Ok(Some(serde_json::from_slice(value).map_err(|err| {
                     event!(Level::ERROR, "Failed to deserialize value");
 
                     Error::new(
@@ -361,8 +374,13 @@ impl Storage for Memory {
     async fn remove_data(&self, key: &StorageKey) -> Result<(), Self::Error> {
         match self.storage.lock().await.entry(key.clone()) {
             Entry::Occupied(mut entry) => {
-                // We can't use `clear` method, because we don't need save allocated capacity
-                entry.get_mut().data = HashMap::default();
+                // Record without states and data is removed, so the storage doesn't keep ids of the users, who are purged
+                if entry.get().states.is_empty() {
+                    entry.remove();
+                } else {
+                    // We can't use `clear` method, because we don't need save allocated capacity
+                    entry.get_mut().data = HashMap::default();
+                }
             }
             Entry::Vacant(_) => {}
         }
@@ -374,6 +392,10 @@ impl Storage for Memory {
 mod tests {
     use super::*;
 
+    mod test_suite {
+        crate::fsm::storage::test_suite!(super::Memory::new());
+    }
+
     #[tokio::test]
     async fn test_state() {
         let storage = Memory::default();
@@ -102,7 +102,7 @@ impl KeyBuilder for DefaultKeyBuilder {
 
         parts.push(self.prefix);
         if self.with_destiny {
-            parts.push(key.destiny);
+            parts.push(&key.destiny);
         }
         if self.with_bot_id {
             parts.push(&bot_id);
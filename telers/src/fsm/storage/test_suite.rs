@@ -0,0 +1,432 @@
+//! This module contains conformance checks for [`Storage`] implementations, so third-party storages can verify
+//! that they behave the same way as ready-made ones.
+//!
+//! Use [`test_suite!`] macro to generate a test for each check.
+//! The macro accepts an expression, which creates a storage, it's evaluated for each test, so each test gets a fresh storage:
+//! ```ignore
+//! #[cfg(test)]
+//! mod tests {
+//!     use super::MyStorage;
+//!
+//!     telers::fsm::storage::test_suite!(MyStorage::new("redis://localhost"));
+//! }
+//! ```
+//!
+//! Checks cover:
+//! - states stack: set, get, previous state and removing
+//! - data: set, get, values, overwriting, clearing and removing
+//! - key strategies: keys, which differ only by one part (bot, chat, user, thread or destiny), don't share states and data
+//! - large values: long strings and many data values
+//! - serializer edge cases: options, untagged and tagged enums, nested structs, unicode and integer bounds
+//! - concurrency: concurrent writes and reads of different keys
+//!
+//! Expiration of keys (TTL) isn't part of the [`Storage`] trait, so it's not checked.
+//! Checks use unique keys with the [`DESTINY`] destiny, so they can be run against a shared storage, for example, a real redis instance.
+//!
+//! [`test_suite!`]: crate::fsm::storage::test_suite!
+
+use super::{Error, Storage, StorageKey};
+
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, future::Future, sync::atomic::AtomicI64};
+
+/// Destiny of the keys, which are used by the checks
+pub const DESTINY: &str = "telers_test_suite";
+
+/// Counter for unique chat ids of the keys, so checks don't intersect when run concurrently against the same storage
+static CHAT_ID: AtomicI64 = AtomicI64::new(1);
+
+/// Creates a unique key for the check
+fn unique_key() -> StorageKey {
+    let chat_id = CHAT_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+
+    StorageKey::new(0, chat_id, -chat_id, None).destiny(DESTINY)
+}
+
+/// Unwraps the storage result with the converted error, because storage error isn't required to implement [`Debug`]
+#[track_caller]
+fn ok<T, E>(result: Result<T, E>) -> T
+where
+    E: Into<Error>,
+{
+    match result {
+        Ok(val) => val,
+        Err(err) => panic!("Storage error: {:?}", err.into()),
+    }
+}
+
+/// Runs the check in the current thread runtime
+/// # Panics
+/// If the runtime can't be created or the check fails
+pub fn run<S, F, Fut>(storage: S, check: F)
+where
+    F: FnOnce(S) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("Failed to create runtime")
+        .block_on(check(storage));
+}
+
+/// Checks states stack
+/// # Panics
+/// If the storage doesn't conform
+pub async fn states<S>(storage: S)
+where
+    S: Storage,
+{
+    let key = unique_key();
+
+    assert_eq!(ok(storage.get_state(&key).await), None);
+    assert!(ok(storage.get_states(&key).await).is_empty());
+
+    ok(storage.set_state(&key, "first").await);
+    ok(storage.set_state(&key, "second").await);
+    assert_eq!(ok(storage.get_state(&key).await).as_deref(), Some("second"));
+    assert_eq!(
+        &*ok(storage.get_states(&key).await),
+        [Box::from("first"), Box::from("second")]
+    );
+
+    ok(storage.set_previous_state(&key).await);
+    assert_eq!(ok(storage.get_state(&key).await).as_deref(), Some("first"));
+
+    ok(storage.set_previous_state(&key).await);
+    assert_eq!(ok(storage.get_state(&key).await), None);
+
+    // Previous state of the empty stack is no state
+    ok(storage.set_previous_state(&key).await);
+    assert_eq!(ok(storage.get_state(&key).await), None);
+
+    ok(storage.set_state(&key, "third").await);
+    ok(storage.remove_states(&key).await);
+    assert_eq!(ok(storage.get_state(&key).await), None);
+    assert!(ok(storage.get_states(&key).await).is_empty());
+}
+
+/// Checks data and its values
+/// # Panics
+/// If the storage doesn't conform
+pub async fn data<S>(storage: S)
+where
+    S: Storage,
+{
+    let key = unique_key();
+
+    assert!(ok(storage.get_data::<String>(&key).await).is_empty());
+    assert_eq!(ok(storage.get_value::<_, String>(&key, "name").await), None);
+
+    ok(storage
+        .set_data(&key, HashMap::from([("name", "Alice"), ("city", "Paris")]))
+        .await);
+    assert_eq!(
+        ok(storage.get_data::<String>(&key).await),
+        HashMap::from([
+            ("name".into(), "Alice".into()),
+            ("city".into(), "Paris".into())
+        ])
+    );
+
+    // Data is replaced, not merged
+    ok(storage
+        .set_data(&key, HashMap::from([("name", "Bob")]))
+        .await);
+    assert_eq!(ok(storage.get_value::<_, String>(&key, "city").await), None);
+
+    ok(storage.set_value(&key, "name", "Carol").await);
+    ok(storage.set_value(&key, "age", 30).await);
+    assert_eq!(
+        ok(storage.get_value::<_, String>(&key, "name").await).as_deref(),
+        Some("Carol")
+    );
+    assert_eq!(ok(storage.get_value::<_, u8>(&key, "age").await), Some(30));
+
+    // Empty data clears the data
+    ok(storage.set_data(&key, HashMap::<&str, ()>::new()).await);
+    assert!(ok(storage.get_data::<String>(&key).await).is_empty());
+
+    ok(storage.set_value(&key, "name", "Dave").await);
+    ok(storage.remove_data(&key).await);
+    assert!(ok(storage.get_data::<String>(&key).await).is_empty());
+
+    // States and data are independent
+    ok(storage.set_state(&key, "state").await);
+    ok(storage.set_value(&key, "name", "Eve").await);
+    ok(storage.remove_states(&key).await);
+    assert_eq!(
+        ok(storage.get_value::<_, String>(&key, "name").await).as_deref(),
+        Some("Eve")
+    );
+    ok(storage.set_state(&key, "state").await);
+    ok(storage.remove_data(&key).await);
+    assert_eq!(ok(storage.get_state(&key).await).as_deref(), Some("state"));
+}
+
+/// Checks that keys, which differ only by one part, don't share states and data
+/// # Panics
+/// If the storage doesn't conform
+pub async fn key_strategies<S>(storage: S)
+where
+    S: Storage,
+{
+    let key = unique_key();
+    let keys = [
+        StorageKey {
+            bot_id: key.bot_id + 1,
+            ..key.clone()
+        },
+        StorageKey {
+            chat_id: key.chat_id + 1_000_000,
+            ..key.clone()
+        },
+        StorageKey {
+            user_id: key.user_id - 1_000_000,
+            ..key.clone()
+        },
+        StorageKey {
+            message_thread_id: Some(1),
+            ..key.clone()
+        },
+        key.clone().destiny("telers_test_suite_other"),
+    ];
+
+    ok(storage.set_state(&key, "state").await);
+    ok(storage.set_value(&key, "value", 1).await);
+
+    for other in &keys {
+        assert_eq!(
+            ok(storage.get_state(other).await),
+            None,
+            "State is shared with {other:?}"
+        );
+        assert_eq!(
+            ok(storage.get_value::<_, i32>(other, "value").await),
+            None,
+            "Data is shared with {other:?}"
+        );
+    }
+
+    for (index, other) in keys.iter().enumerate() {
+        ok(storage.set_state(other, format!("state_{index}")).await);
+    }
+    assert_eq!(ok(storage.get_state(&key).await).as_deref(), Some("state"));
+
+    for other in &keys {
+        ok(storage.remove_states(other).await);
+    }
+    ok(storage.remove_states(&key).await);
+    ok(storage.remove_data(&key).await);
+}
+
+/// Checks long strings and many data values
+/// # Panics
+/// If the storage doesn't conform
+pub async fn large_values<S>(storage: S)
+where
+    S: Storage,
+{
+    let key = unique_key();
+
+    let long_state = "s".repeat(64 * 1024);
+    ok(storage.set_state(&key, long_state.clone()).await);
+    assert_eq!(
+        ok(storage.get_state(&key).await).as_deref(),
+        Some(long_state.as_str())
+    );
+
+    let long_value = "v".repeat(1024 * 1024);
+    ok(storage.set_value(&key, "long", &long_value).await);
+    assert_eq!(
+        ok(storage.get_value::<_, String>(&key, "long").await),
+        Some(long_value)
+    );
+
+    let many_values = (0..10_000)
+        .map(|index| (format!("key_{index}"), index))
+        .collect::<HashMap<_, _>>();
+    ok(storage.set_data(&key, many_values.clone()).await);
+    let data = ok(storage.get_data::<i32>(&key).await);
+    assert_eq!(data.len(), many_values.len());
+    assert_eq!(data.get("key_9999"), Some(&9999));
+
+    ok(storage.remove_states(&key).await);
+    ok(storage.remove_data(&key).await);
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+enum Untagged {
+    Number(i64),
+    Text(String),
+    Point { x: f64, y: f64 },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum Tagged {
+    Unit,
+    Newtype(u8),
+    Tuple(u8, String),
+    Struct { value: Option<Box<str>> },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Nested {
+    name: String,
+    optional: Option<u32>,
+    untagged: Vec<Untagged>,
+    tagged: Vec<Tagged>,
+    map: HashMap<String, Vec<Option<i64>>>,
+}
+
+/// Checks serializer edge cases
+/// # Panics
+/// If the storage doesn't conform
+pub async fn serializer_edge_cases<S>(storage: S)
+where
+    S: Storage,
+{
+    let key = unique_key();
+
+    ok(storage.set_value(&key, "none", None::<u32>).await);
+    ok(storage.set_value(&key, "some", Some(1_u32)).await);
+    // `None` value is stored, so it differs from missed value only by the type of the result
+    assert_eq!(
+        ok(storage.get_value::<_, Option<u32>>(&key, "none").await),
+        Some(None)
+    );
+    assert_eq!(
+        ok(storage.get_value::<_, Option<u32>>(&key, "some").await),
+        Some(Some(1))
+    );
+
+    let untagged = [
+        Untagged::Number(-1),
+        Untagged::Text("text".to_owned()),
+        Untagged::Point { x: 1.5, y: -2.25 },
+    ];
+    for (index, value) in untagged.iter().enumerate() {
+        let value_key = format!("untagged_{index}");
+
+        ok(storage.set_value(&key, value_key.clone(), value).await);
+        assert_eq!(
+            ok(storage.get_value::<_, Untagged>(&key, value_key).await).as_ref(),
+            Some(value)
+        );
+    }
+
+    let nested = Nested {
+        name: "Юникод ✓ 😀 \"quoted\" \n\t\\".to_owned(),
+        optional: None,
+        untagged: untagged.to_vec(),
+        tagged: vec![
+            Tagged::Unit,
+            Tagged::Newtype(1),
+            Tagged::Tuple(2, String::new()),
+            Tagged::Struct { value: None },
+            Tagged::Struct {
+                value: Some("value".into()),
+            },
+        ],
+        map: HashMap::from([("key".to_owned(), vec![Some(i64::MIN), None, Some(i64::MAX)])]),
+    };
+    ok(storage.set_value(&key, "nested", &nested).await);
+    assert_eq!(
+        ok(storage.get_value::<_, Nested>(&key, "nested").await),
+        Some(nested)
+    );
+
+    ok(storage
+        .set_value(&key, "bounds", (u64::MAX, i64::MIN))
+        .await);
+    assert_eq!(
+        ok(storage.get_value::<_, (u64, i64)>(&key, "bounds").await),
+        Some((u64::MAX, i64::MIN))
+    );
+
+    // Unicode in the value key and the state
+    ok(storage.set_value(&key, "ключ 🔑", "значение").await);
+    assert_eq!(
+        ok(storage.get_value::<_, String>(&key, "ключ 🔑").await).as_deref(),
+        Some("значение")
+    );
+    ok(storage.set_state(&key, "состояние:🙂").await);
+    assert_eq!(
+        ok(storage.get_state(&key).await).as_deref(),
+        Some("состояние:🙂")
+    );
+
+    // Value can't be deserialized to the wrong type
+    assert!(storage.get_value::<_, u32>(&key, "nested").await.is_err());
+
+    ok(storage.remove_states(&key).await);
+    ok(storage.remove_data(&key).await);
+}
+
+/// Checks concurrent writes and reads of different keys
+/// # Panics
+/// If the storage doesn't conform
+pub async fn concurrency<S>(storage: S)
+where
+    S: Storage + Sync,
+{
+    let keys = (0..64).map(|_| unique_key()).collect::<Vec<_>>();
+
+    futures::future::join_all(keys.iter().enumerate().map(|(index, key)| {
+        let storage = &storage;
+
+        async move {
+            for step in 0..8 {
+                ok(storage.set_state(key, format!("state_{step}")).await);
+                ok(storage.set_value(key, "index", index).await);
+                // Reads are interleaved with writes of other keys
+                assert_eq!(
+                    ok(storage.get_value::<_, usize>(key, "index").await),
+                    Some(index)
+                );
+            }
+        }
+    }))
+    .await;
+
+    for (index, key) in keys.iter().enumerate() {
+        assert_eq!(ok(storage.get_states(key).await).len(), 8);
+        assert_eq!(ok(storage.get_state(key).await).as_deref(), Some("state_7"));
+        assert_eq!(
+            ok(storage.get_value::<_, usize>(key, "index").await),
+            Some(index)
+        );
+
+        ok(storage.remove_states(key).await);
+        ok(storage.remove_data(key).await);
+    }
+}
+
+/// Generates a test for each conformance check of the storage.
+/// Check the [module](crate::fsm::storage::test_suite) documentation for more information.
+#[macro_export]
+macro_rules! __storage_test_suite {
+    ($storage:expr) => {
+        $crate::__storage_test_suite!(
+            $storage;
+            states,
+            data,
+            key_strategies,
+            large_values,
+            serializer_edge_cases,
+            concurrency
+        );
+    };
+    ($storage:expr; $($check:ident),+ $(,)?) => {
+        $(
+            #[test]
+            fn $check() {
+                $crate::fsm::storage::test_suite::run(
+                    $storage,
+                    $crate::fsm::storage::test_suite::$check,
+                );
+            }
+        )+
+    };
+}
@@ -2,26 +2,51 @@ extern crate self as telers;
 
 mod serializers;
 
+#[cfg(feature = "client")]
 pub mod client;
+#[cfg(feature = "config")]
+pub mod config;
+#[cfg(feature = "dispatcher")]
 pub mod context;
+#[cfg(feature = "dispatcher")]
 pub mod dispatcher;
 pub mod enums;
 pub mod errors;
+#[cfg(feature = "dispatcher")]
 pub mod event;
+#[cfg(feature = "dispatcher")]
 pub mod extractors;
+#[cfg(feature = "dispatcher")]
 pub mod filters;
+#[cfg(feature = "fsm")]
 pub mod fsm;
+#[cfg(feature = "client")]
 pub mod methods;
+#[cfg(feature = "dispatcher")]
 pub mod middlewares;
+#[cfg(feature = "dispatcher")]
+pub mod plugins;
+#[cfg(feature = "dispatcher")]
 pub mod router;
+#[cfg(feature = "dispatcher")]
+pub mod runtime;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test;
 pub mod types;
 pub mod utils;
 
-pub use telers_macros::{FromContext, FromEvent};
+#[cfg(feature = "dispatcher")]
+pub use telers_macros::{handler, FromCommandArgs, FromContext, FromEvent};
 
+#[cfg(feature = "client")]
 pub use client::Bot;
+#[cfg(feature = "dispatcher")]
 pub use context::Context;
+#[cfg(feature = "dispatcher")]
 pub use dispatcher::{Builder as DispatcherBuilder, Dispatcher};
+#[cfg(feature = "dispatcher")]
 pub use filters::Filter;
+#[cfg(feature = "fsm")]
 pub use fsm::Context as FSMContext;
+#[cfg(feature = "dispatcher")]
 pub use router::Router;
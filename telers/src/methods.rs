@@ -11,12 +11,12 @@
 //! These methods are useful when you have some optional parameters that you want to pass to the method from the types without boilerplate code.
 //!
 //! ```rust
-//! use telers::{Bot, methods::SendMessage, types::Message, event::{telegram::HandlerResult, EventReturn}};
+//! use telers::{Bot, methods::SendMessage, types::Message, event::{telegram::HandlerResult, FlowControl}};
 //!
 //! async fn handler(bot: Bot, message: Message) -> HandlerResult {
 //!     bot.send(SendMessage::new(message.chat().id(), "Hello world!").message_thread_id_option(message.thread_id())).await?;
 //!
-//!     Ok(EventReturn::Finish)
+//!     Ok(FlowControl::Continue)
 //! }
 //! ```
 //!
@@ -51,6 +51,7 @@ pub mod delete_messages;
 pub mod delete_my_commands;
 pub mod delete_sticker_from_set;
 pub mod delete_sticker_set;
+pub mod delete_webhook;
 pub mod edit_chat_invite_link;
 pub mod edit_forum_topic;
 pub mod edit_general_forum_topic;
@@ -81,6 +82,7 @@ pub mod get_sticker_set;
 pub mod get_updates;
 pub mod get_user_chat_boosts;
 pub mod get_user_profile_photos;
+pub mod get_webhook_info;
 pub mod hide_general_forum_topic;
 pub mod leave_chat;
 pub mod log_out;
@@ -101,6 +103,7 @@ pub mod send_invoice;
 pub mod send_location;
 pub mod send_media_group;
 pub mod send_message;
+pub mod send_paid_media;
 pub mod send_photo;
 pub mod send_poll;
 pub mod send_sticker;
@@ -129,6 +132,7 @@ pub mod set_sticker_mask_position;
 pub mod set_sticker_position_in_set;
 pub mod set_sticker_set_thumbnail;
 pub mod set_sticker_set_title;
+pub mod set_webhook;
 pub mod stop_message_live_location;
 pub mod stop_poll;
 pub mod unban_chat_member;
@@ -149,7 +153,7 @@ pub use answer_web_app_query::AnswerWebAppQuery;
 pub use approve_chat_join_request::ApproveChatJoinRequest;
 pub use ban_chat_member::BanChatMember;
 pub use ban_chat_sender_chat::BanChatSenderChat;
-pub use base::{Request, Response, TelegramMethod};
+pub use base::{Request, Response, TelegramMethod, WithTimeout};
 pub use close_forum_topic::CloseForumTopic;
 pub use close_general_forum_topic::CloseGeneralForumTopic;
 pub use copy_message::CopyMessage;
@@ -167,6 +171,7 @@ pub use delete_messages::DeleteMessages;
 pub use delete_my_commands::DeleteMyCommands;
 pub use delete_sticker_from_set::DeleteStickerFromSet;
 pub use delete_sticker_set::DeleteStickerSet;
+pub use delete_webhook::DeleteWebhook;
 pub use edit_chat_invite_link::EditChatInviteLink;
 pub use edit_forum_topic::EditForumTopic;
 pub use edit_general_forum_topic::EditGeneralForumTopic;
@@ -197,6 +202,7 @@ pub use get_sticker_set::GetStickerSet;
 pub use get_updates::GetUpdates;
 pub use get_user_chat_boosts::GetUserChatBoosts;
 pub use get_user_profile_photos::GetUserProfilePhotos;
+pub use get_webhook_info::GetWebhookInfo;
 pub use hide_general_forum_topic::HideGeneralForumTopic;
 pub use leave_chat::LeaveChat;
 pub use log_out::LogOut;
@@ -217,6 +223,7 @@ pub use send_invoice::SendInvoice;
 pub use send_location::SendLocation;
 pub use send_media_group::SendMediaGroup;
 pub use send_message::SendMessage;
+pub use send_paid_media::SendPaidMedia;
 pub use send_photo::SendPhoto;
 pub use send_poll::SendPoll;
 pub use send_sticker::SendSticker;
@@ -245,6 +252,7 @@ pub use set_sticker_mask_position::SetStickerMaskPosition;
 pub use set_sticker_position_in_set::SetStickerPositionInSet;
 pub use set_sticker_set_thumbnail::SetStickerSetThumbnail;
 pub use set_sticker_set_title::SetStickerSetTitle;
+pub use set_webhook::SetWebhook;
 pub use stop_message_live_location::StopMessageLiveLocation;
 pub use stop_poll::StopPoll;
 pub use unban_chat_member::UnbanChatMember;
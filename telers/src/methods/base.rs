@@ -1,9 +1,11 @@
 use crate::{
-    client::Bot,
-    types::{InputFile, InputMedia, InputSticker, ResponseParameters},
+    client::{Bot, Defaults},
+    errors::SessionErrorKind,
+    types::{InputFile, InputMedia, InputPaidMedia, InputSticker, ResponseParameters},
 };
 
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::time::Duration;
 
 /// This object represents a request to Telegram API
 pub struct Request<'a, T>
@@ -11,7 +13,7 @@ where
     T: Serialize + ?Sized,
 {
     /// Telegram API method name
-    pub method_name: &'static str,
+    pub method_name: &'a str,
     /// Telegram API method data
     pub data: &'a T,
     /// Files to send
@@ -23,11 +25,7 @@ where
     T: Serialize + ?Sized,
 {
     #[must_use]
-    pub fn new(
-        method_name: &'static str,
-        data: &'a T,
-        files: Option<Box<[&'a InputFile<'a>]>>,
-    ) -> Self {
+    pub fn new(method_name: &'a str, data: &'a T, files: Option<Box<[&'a InputFile<'a>]>>) -> Self {
         Self {
             method_name,
             data,
@@ -63,6 +61,42 @@ pub trait TelegramMethod {
     #[must_use]
     fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<Self::Method>;
 
+    /// This method is called before a request is sent to Telegram API.
+    /// It's need for applying the bot [`Defaults`] to the fields, which aren't set in the method.
+    /// # Returns
+    /// `None` if the method doesn't have fields with defaults or all of them are already set,
+    /// so the method is sent as is
+    #[must_use]
+    fn with_defaults(&self, _defaults: &Defaults) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// This method is called before a request is sent to Telegram API.
+    /// It's need for methods, which require a specific timeout, for example, long polling [`GetUpdates`].
+    /// # Returns
+    /// `None` if the bot or client timeout should be used
+    ///
+    /// [`GetUpdates`]: crate::methods::GetUpdates
+    #[must_use]
+    fn request_timeout(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Wrap the method to send it with the timeout, which overrides the bot and client timeouts
+    #[must_use]
+    fn with_timeout(self, timeout: Duration) -> WithTimeout<Self>
+    where
+        Self: Sized,
+    {
+        WithTimeout {
+            method: self,
+            timeout,
+        }
+    }
+
     /// This method is called when a response is received from Telegram API.
     /// It's need for parsing a response from Telegram API.
     /// # Errors
@@ -70,6 +104,58 @@ pub trait TelegramMethod {
     fn build_response(&self, content: &str) -> Result<Response<Self::Return>, serde_json::Error> {
         serde_json::from_str(content)
     }
+
+    /// This method is called when a response from Telegram API can't be parsed by [`TelegramMethod::build_response`].
+    /// It's need for adding context to the parse error, for example, to find the update, which can't be parsed.
+    #[must_use]
+    fn build_response_error(&self, _content: &str, err: serde_json::Error) -> SessionErrorKind {
+        SessionErrorKind::Parse(err)
+    }
+}
+
+/// Method, which is sent with the timeout.
+/// It's created by [`TelegramMethod::with_timeout`] method.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct WithTimeout<T> {
+    pub method: T,
+    pub timeout: Duration,
+}
+
+impl<T> TelegramMethod for WithTimeout<T>
+where
+    T: TelegramMethod,
+{
+    type Method = T::Method;
+    type Return = T::Return;
+
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<'_, Self::Method> {
+        self.method.build_request(bot)
+    }
+
+    fn with_defaults(&self, defaults: &Defaults) -> Option<Self> {
+        self.method.with_defaults(defaults).map(|method| Self {
+            method,
+            timeout: self.timeout,
+        })
+    }
+
+    fn request_timeout(&self) -> Option<Duration> {
+        Some(self.timeout)
+    }
+
+    fn build_response(&self, content: &str) -> Result<Response<Self::Return>, serde_json::Error> {
+        self.method.build_response(content)
+    }
+
+    fn build_response_error(&self, content: &str, err: serde_json::Error) -> SessionErrorKind {
+        self.method.build_response_error(content, err)
+    }
+}
+
+impl<T> AsRef<WithTimeout<T>> for WithTimeout<T> {
+    fn as_ref(&self) -> &Self {
+        self
+    }
 }
 
 pub(super) fn prepare_file<'a>(files: &mut Vec<&'a InputFile<'a>>, file: &'a InputFile<'a>) {
@@ -116,6 +202,32 @@ pub(super) fn prepare_input_media_group<'a>(
     }
 }
 
+pub(super) fn prepare_input_paid_media<'a>(
+    files: &mut Vec<&'a InputFile<'a>>,
+    input_paid_media: &'a InputPaidMedia<'a>,
+) {
+    match input_paid_media {
+        InputPaidMedia::Photo(inner) => {
+            prepare_file(files, &inner.media);
+        }
+        InputPaidMedia::Video(inner) => {
+            prepare_file(files, &inner.media);
+            if let Some(thumbnail) = &inner.thumbnail {
+                prepare_file(files, thumbnail);
+            }
+        }
+    }
+}
+
+pub(super) fn prepare_input_paid_media_group<'a>(
+    files: &mut Vec<&'a InputFile<'a>>,
+    input_paid_media_group: &'a [InputPaidMedia<'a>],
+) {
+    for input_paid_media in input_paid_media_group {
+        prepare_input_paid_media(files, input_paid_media);
+    }
+}
+
 pub(super) fn prepare_input_sticker<'a>(
     files: &mut Vec<&'a InputFile<'a>>,
     input_sticker: &'a InputSticker<'a>,
@@ -131,3 +243,34 @@ pub(super) fn prepare_input_stickers<'a>(
         prepare_input_sticker(files, input_sticker);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::methods::{get_updates::LONG_POLLING_TIMEOUT_MARGIN, GetUpdates, SendMessage};
+
+    #[test]
+    fn test_request_timeout() {
+        assert_eq!(SendMessage::new(1, "text").request_timeout(), None);
+        assert_eq!(
+            SendMessage::new(1, "text")
+                .with_timeout(Duration::from_secs(5))
+                .request_timeout(),
+            Some(Duration::from_secs(5))
+        );
+
+        // Long polling timeout is extended by the margin
+        assert_eq!(
+            GetUpdates::new().timeout(30).request_timeout(),
+            Some(Duration::from_secs(30) + LONG_POLLING_TIMEOUT_MARGIN)
+        );
+        assert_eq!(GetUpdates::new().timeout(0).request_timeout(), None);
+        assert_eq!(
+            GetUpdates::new()
+                .timeout(30)
+                .with_timeout(Duration::from_secs(5))
+                .request_timeout(),
+            Some(Duration::from_secs(5))
+        );
+    }
+}
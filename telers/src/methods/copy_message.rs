@@ -1,7 +1,7 @@
 use super::base::{Request, TelegramMethod};
 
 use crate::{
-    client::Bot,
+    client::{Bot, Defaults},
     types::{ChatIdKind, MessageEntity, MessageId, ReplyMarkup, ReplyParameters},
 };
 
@@ -253,6 +253,16 @@ impl TelegramMethod for CopyMessage {
     fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
         Request::new("copyMessage", self, None)
     }
+
+    fn with_defaults(&self, defaults: &Defaults) -> Option<Self> {
+        let mut method = self.clone();
+        let changed = defaults
+            .fill_parse_mode(&mut method.parse_mode, method.caption_entities.as_ref())
+            | defaults.fill_disable_notification(&mut method.disable_notification)
+            | defaults.fill_protect_content(&mut method.protect_content);
+
+        changed.then_some(method)
+    }
 }
 
 impl AsRef<CopyMessage> for CopyMessage {
@@ -1,13 +1,16 @@
 use super::base::{Request, TelegramMethod};
 
 use crate::{
-    client::Bot,
+    client::{Bot, Defaults},
     types::{ChatIdKind, MessageId},
 };
 
 use serde::Serialize;
 use serde_with::skip_serializing_none;
 
+/// Max number of message identifiers in one request of [`CopyMessages`] and [`ForwardMessages`](crate::methods::ForwardMessages) methods
+pub const MAX_MESSAGE_IDS: usize = 100;
+
 /// Use this method to copy messages of any kind. If some of the specified messages can't be found or copied, they are skipped. Service messages, giveaway messages, giveaway winners messages, and invoice messages can't be copied. A quiz [`poll`](crate::types::Poll) can be copied only if the value of the field `correct_option_id` is known to the bot. The method is analogous to the method [`ForwardMessages`](crate::methods::ForwardMessages), but the copied messages don't have a link to the original message. Album grouping is kept for copied messages.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#copymessages>
@@ -149,6 +152,36 @@ impl CopyMessages {
     }
 }
 
+impl CopyMessages {
+    /// Split the method into several methods with at most [`MAX_MESSAGE_IDS`] message identifiers in each one,
+    /// because Telegram doesn't allow to pass more identifiers in one request.
+    /// Identifiers are sorted and deduplicated, because they must be specified in a strictly increasing order,
+    /// so the messages are copied in the order of the original chat.
+    /// # Notes
+    /// Use [`Bot::copy_messages_chunked`] to send the chunks.
+    #[must_use]
+    pub fn chunked(self) -> Vec<Self> {
+        let mut message_ids = self.message_ids.clone();
+        message_ids.sort_unstable();
+        message_ids.dedup();
+
+        if message_ids.len() <= MAX_MESSAGE_IDS {
+            return vec![Self {
+                message_ids,
+                ..self
+            }];
+        }
+
+        message_ids
+            .chunks(MAX_MESSAGE_IDS)
+            .map(|message_ids| Self {
+                message_ids: message_ids.to_vec(),
+                ..self.clone()
+            })
+            .collect()
+    }
+}
+
 impl TelegramMethod for CopyMessages {
     type Method = Self;
     type Return = Vec<MessageId>;
@@ -156,6 +189,14 @@ impl TelegramMethod for CopyMessages {
     fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
         Request::new("copyMessages", self, None)
     }
+
+    fn with_defaults(&self, defaults: &Defaults) -> Option<Self> {
+        let mut method = self.clone();
+        let changed = defaults.fill_disable_notification(&mut method.disable_notification)
+            | defaults.fill_protect_content(&mut method.protect_content);
+
+        changed.then_some(method)
+    }
 }
 
 impl AsRef<CopyMessages> for CopyMessages {
@@ -19,7 +19,7 @@ pub struct CreateChatInviteLink {
     /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
     pub chat_id: ChatIdKind,
     /// Invite link name; 0-32 characters
-    pub invite_link: Option<String>,
+    pub name: Option<String>,
     /// Point in time (Unix timestamp) when the link will expire
     pub expire_date: Option<i64>,
     /// The maximum number of users that can be members of the chat simultaneously after joining the chat via this invite link; 1-99999
@@ -33,7 +33,7 @@ impl CreateChatInviteLink {
     pub fn new(chat_id: impl Into<ChatIdKind>) -> Self {
         Self {
             chat_id: chat_id.into(),
-            invite_link: None,
+            name: None,
             expire_date: None,
             member_limit: None,
             creates_join_request: None,
@@ -49,13 +49,19 @@ impl CreateChatInviteLink {
     }
 
     #[must_use]
-    pub fn invite_link(self, val: impl Into<String>) -> Self {
+    pub fn name(self, val: impl Into<String>) -> Self {
         Self {
-            invite_link: Some(val.into()),
+            name: Some(val.into()),
             ..self
         }
     }
 
+    #[must_use]
+    #[deprecated(note = "Use `name` instead, the field is the name of the invite link")]
+    pub fn invite_link(self, val: impl Into<String>) -> Self {
+        self.name(val)
+    }
+
     #[must_use]
     pub fn expire_date(self, val: i64) -> Self {
         Self {
@@ -83,13 +89,19 @@ impl CreateChatInviteLink {
 
 impl CreateChatInviteLink {
     #[must_use]
-    pub fn invite_link_option(self, val: Option<impl Into<String>>) -> Self {
+    pub fn name_option(self, val: Option<impl Into<String>>) -> Self {
         Self {
-            invite_link: val.map(Into::into),
+            name: val.map(Into::into),
             ..self
         }
     }
 
+    #[must_use]
+    #[deprecated(note = "Use `name_option` instead, the field is the name of the invite link")]
+    pub fn invite_link_option(self, val: Option<impl Into<String>>) -> Self {
+        self.name_option(val)
+    }
+
     #[must_use]
     pub fn expire_date_option(self, val: Option<i64>) -> Self {
         Self {
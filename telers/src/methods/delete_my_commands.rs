@@ -42,6 +42,24 @@ impl DeleteMyCommands {
     }
 }
 
+impl DeleteMyCommands {
+    #[must_use]
+    pub fn scope_option(self, val: Option<impl Into<BotCommandScope>>) -> Self {
+        Self {
+            scope: val.map(Into::into),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn language_code_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            language_code: val.map(Into::into),
+            ..self
+        }
+    }
+}
+
 impl TelegramMethod for DeleteMyCommands {
     type Method = Self;
     type Return = bool;
@@ -68,6 +68,14 @@ impl EditForumTopic {
 }
 
 impl EditForumTopic {
+    #[must_use]
+    pub fn name_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            name: val.map(Into::into),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn icon_custom_emoji_id_option(self, val: Option<impl Into<String>>) -> Self {
         Self {
@@ -1,7 +1,7 @@
 use super::base::{Request, TelegramMethod};
 
 use crate::{
-    client::Bot,
+    client::{Bot, Defaults},
     types::{ChatIdKind, InlineKeyboardMarkup, MessageEntity, MessageOrTrue},
 };
 
@@ -189,6 +189,14 @@ impl TelegramMethod for EditMessageCaption {
     fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
         Request::new("editMessageCaption", self, None)
     }
+
+    fn with_defaults(&self, defaults: &Defaults) -> Option<Self> {
+        let mut method = self.clone();
+
+        defaults
+            .fill_parse_mode(&mut method.parse_mode, method.caption_entities.as_ref())
+            .then_some(method)
+    }
 }
 
 impl AsRef<EditMessageCaption> for EditMessageCaption {
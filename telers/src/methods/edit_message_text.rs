@@ -1,7 +1,7 @@
 use super::base::{Request, TelegramMethod};
 
 use crate::{
-    client::Bot,
+    client::{Bot, Defaults},
     types::{ChatIdKind, InlineKeyboardMarkup, LinkPreviewOptions, MessageEntity, MessageOrTrue},
 };
 
@@ -191,6 +191,14 @@ impl TelegramMethod for EditMessageText {
     fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
         Request::new("editMessageText", self, None)
     }
+
+    fn with_defaults(&self, defaults: &Defaults) -> Option<Self> {
+        let mut method = self.clone();
+        let changed = defaults.fill_parse_mode(&mut method.parse_mode, method.entities.as_ref())
+            | defaults.fill_link_preview_options(&mut method.link_preview_options);
+
+        changed.then_some(method)
+    }
 }
 
 impl AsRef<EditMessageText> for EditMessageText {
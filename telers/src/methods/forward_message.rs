@@ -1,7 +1,7 @@
 use super::base::{Request, TelegramMethod};
 
 use crate::{
-    client::Bot,
+    client::{Bot, Defaults},
     types::{ChatIdKind, Message},
 };
 
@@ -129,6 +129,14 @@ impl TelegramMethod for ForwardMessage {
     fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
         Request::new("forwardMessage", self, None)
     }
+
+    fn with_defaults(&self, defaults: &Defaults) -> Option<Self> {
+        let mut method = self.clone();
+        let changed = defaults.fill_disable_notification(&mut method.disable_notification)
+            | defaults.fill_protect_content(&mut method.protect_content);
+
+        changed.then_some(method)
+    }
 }
 
 impl AsRef<ForwardMessage> for ForwardMessage {
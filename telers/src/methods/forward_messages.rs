@@ -1,7 +1,10 @@
-use super::base::{Request, TelegramMethod};
+use super::{
+    base::{Request, TelegramMethod},
+    copy_messages::MAX_MESSAGE_IDS,
+};
 
 use crate::{
-    client::Bot,
+    client::{Bot, Defaults},
     types::{ChatIdKind, MessageId},
 };
 
@@ -130,6 +133,36 @@ impl ForwardMessages {
     }
 }
 
+impl ForwardMessages {
+    /// Split the method into several methods with at most [`MAX_MESSAGE_IDS`] message identifiers in each one,
+    /// because Telegram doesn't allow to pass more identifiers in one request.
+    /// Identifiers are sorted and deduplicated, because they must be specified in a strictly increasing order,
+    /// so the messages are forwarded in the order of the original chat.
+    /// # Notes
+    /// Use [`Bot::forward_messages_chunked`] to send the chunks.
+    #[must_use]
+    pub fn chunked(self) -> Vec<Self> {
+        let mut message_ids = self.message_ids.clone();
+        message_ids.sort_unstable();
+        message_ids.dedup();
+
+        if message_ids.len() <= MAX_MESSAGE_IDS {
+            return vec![Self {
+                message_ids,
+                ..self
+            }];
+        }
+
+        message_ids
+            .chunks(MAX_MESSAGE_IDS)
+            .map(|message_ids| Self {
+                message_ids: message_ids.to_vec(),
+                ..self.clone()
+            })
+            .collect()
+    }
+}
+
 impl TelegramMethod for ForwardMessages {
     type Method = Self;
     type Return = Vec<MessageId>;
@@ -137,6 +170,14 @@ impl TelegramMethod for ForwardMessages {
     fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
         Request::new("forwardMessages", self, None)
     }
+
+    fn with_defaults(&self, defaults: &Defaults) -> Option<Self> {
+        let mut method = self.clone();
+        let changed = defaults.fill_disable_notification(&mut method.disable_notification)
+            | defaults.fill_protect_content(&mut method.protect_content);
+
+        changed.then_some(method)
+    }
 }
 
 impl AsRef<ForwardMessages> for ForwardMessages {
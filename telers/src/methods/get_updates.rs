@@ -1,9 +1,18 @@
 use super::{Request, TelegramMethod};
 
-use crate::{client::Bot, types::Update};
+use crate::{
+    client::Bot,
+    errors::{SessionErrorKind, UpdateParseError},
+    types::Update,
+};
 
 use serde::Serialize;
 use serde_with::skip_serializing_none;
+use std::time::Duration;
+
+/// Margin, which is added to the long polling timeout to get the request timeout,
+/// so the request isn't cancelled before Telegram responds
+pub const LONG_POLLING_TIMEOUT_MARGIN: Duration = Duration::from_secs(10);
 
 /// Use this method to receive incoming updates using long polling ([`wiki`](https://en.wikipedia.org/wiki/Push_technology#Long_polling)).
 /// # Documentation
@@ -119,6 +128,19 @@ impl TelegramMethod for GetUpdates {
     fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
         Request::new("getUpdates", self, None)
     }
+
+    /// Long polling request is waited by Telegram for `timeout` seconds,
+    /// so the request timeout is longer than it by [`LONG_POLLING_TIMEOUT_MARGIN`]
+    fn request_timeout(&self) -> Option<Duration> {
+        self.timeout.filter(|timeout| *timeout > 0).map(|timeout| {
+            Duration::from_secs(timeout.unsigned_abs()) + LONG_POLLING_TIMEOUT_MARGIN
+        })
+    }
+
+    fn build_response_error(&self, content: &str, err: serde_json::Error) -> SessionErrorKind {
+        UpdateParseError::from_response(content)
+            .map_or(SessionErrorKind::Parse(err), SessionErrorKind::UpdateParse)
+    }
 }
 
 impl AsRef<GetUpdates> for GetUpdates {
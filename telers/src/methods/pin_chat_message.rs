@@ -1,6 +1,9 @@
 use super::base::{Request, TelegramMethod};
 
-use crate::{client::Bot, types::ChatIdKind};
+use crate::{
+    client::{Bot, Defaults},
+    types::ChatIdKind,
+};
 
 use serde::Serialize;
 use serde_with::skip_serializing_none;
@@ -73,6 +76,14 @@ impl TelegramMethod for PinChatMessage {
     fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
         Request::new("pinChatMessage", self, None)
     }
+
+    fn with_defaults(&self, defaults: &Defaults) -> Option<Self> {
+        let mut method = self.clone();
+
+        defaults
+            .fill_disable_notification(&mut method.disable_notification)
+            .then_some(method)
+    }
 }
 
 impl AsRef<PinChatMessage> for PinChatMessage {
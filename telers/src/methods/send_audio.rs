@@ -1,7 +1,7 @@
 use super::base::{prepare_file, Request, TelegramMethod};
 
 use crate::{
-    client::Bot,
+    client::{Bot, Defaults},
     types::{ChatIdKind, InputFile, Message, MessageEntity, ReplyMarkup, ReplyParameters},
 };
 
@@ -321,6 +321,16 @@ impl<'a> TelegramMethod for SendAudio<'a> {
 
         Request::new("sendAudio", self, Some(files.into()))
     }
+
+    fn with_defaults(&self, defaults: &Defaults) -> Option<Self> {
+        let mut method = self.clone();
+        let changed = defaults
+            .fill_parse_mode(&mut method.parse_mode, method.caption_entities.as_ref())
+            | defaults.fill_disable_notification(&mut method.disable_notification)
+            | defaults.fill_protect_content(&mut method.protect_content);
+
+        changed.then_some(method)
+    }
 }
 
 impl<'a> AsRef<SendAudio<'a>> for SendAudio<'a> {
@@ -1,7 +1,7 @@
 use super::base::{prepare_input_media_group, Request, TelegramMethod};
 
 use crate::{
-    client::Bot,
+    client::{Bot, Defaults},
     types::{ChatIdKind, InputMedia, Message, ReplyParameters},
 };
 
@@ -156,6 +156,14 @@ impl<'a> TelegramMethod for SendMediaGroup<'a> {
 
         Request::new("sendMediaGroup", self, Some(files.into()))
     }
+
+    fn with_defaults(&self, defaults: &Defaults) -> Option<Self> {
+        let mut method = self.clone();
+        let changed = defaults.fill_disable_notification(&mut method.disable_notification)
+            | defaults.fill_protect_content(&mut method.protect_content);
+
+        changed.then_some(method)
+    }
 }
 
 impl<'a> AsRef<SendMediaGroup<'a>> for SendMediaGroup<'a> {
@@ -1,8 +1,10 @@
 use super::base::{Request, TelegramMethod};
 
 use crate::{
-    client::Bot,
+    client::{Bot, Defaults},
+    enums::ParseMode,
     types::{ChatIdKind, LinkPreviewOptions, Message, MessageEntity, ReplyMarkup, ReplyParameters},
+    utils::text::splitter::{self, MAX_TEXT_LEN},
 };
 
 use serde::Serialize;
@@ -222,6 +224,73 @@ impl SendMessage {
     }
 }
 
+impl SendMessage {
+    /// Create the methods to send the text, which can be longer than [`MAX_TEXT_LEN`] characters, as several messages.
+    /// Check [`SendMessage::chunked`] for more information.
+    #[must_use]
+    pub fn new_chunked(chat_id: impl Into<ChatIdKind>, text: impl Into<String>) -> Vec<Self> {
+        Self::new(chat_id, text).chunked()
+    }
+
+    /// Split the method into several methods, if the text is longer than [`MAX_TEXT_LEN`] characters after entities parsing.
+    /// Text is split at line breaks and whitespaces if it's possible, entities and markup are preserved in each chunk,
+    /// check [`splitter`] module for more information.
+    ///
+    /// Reply parameters are kept only in the first method and reply markup only in the last one,
+    /// so the reply and the keyboard are attached to the message group as a whole.
+    /// # Notes
+    /// Use [`Bot::send_long_text`] to send the chunks.
+    #[must_use]
+    pub fn chunked(self) -> Vec<Self> {
+        let texts = match self.parse_mode.as_deref() {
+            Some(parse_mode) if ParseMode::HTML == parse_mode => {
+                splitter::split_html(&self.text, MAX_TEXT_LEN)
+                    .into_iter()
+                    .map(|text| (text, None))
+                    .collect()
+            }
+            Some(parse_mode)
+                if ParseMode::Markdown == parse_mode || ParseMode::MarkdownV2 == parse_mode =>
+            {
+                splitter::split_markdown(&self.text, MAX_TEXT_LEN)
+                    .into_iter()
+                    .map(|text| (text, None))
+                    .collect()
+            }
+            _ => splitter::split_text(&self.text, self.entities.as_deref(), MAX_TEXT_LEN)
+                .into_iter()
+                .map(|chunk| (chunk.text, chunk.entities))
+                .collect::<Vec<_>>(),
+        };
+
+        if texts.len() <= 1 {
+            return vec![self];
+        }
+
+        let last_index = texts.len() - 1;
+
+        texts
+            .into_iter()
+            .enumerate()
+            .map(|(index, (text, entities))| Self {
+                text,
+                entities,
+                reply_parameters: if index == 0 {
+                    self.reply_parameters.clone()
+                } else {
+                    None
+                },
+                reply_markup: if index == last_index {
+                    self.reply_markup.clone()
+                } else {
+                    None
+                },
+                ..self.clone()
+            })
+            .collect()
+    }
+}
+
 impl TelegramMethod for SendMessage {
     type Method = Self;
     type Return = Message;
@@ -229,6 +298,16 @@ impl TelegramMethod for SendMessage {
     fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
         Request::new("sendMessage", self, None)
     }
+
+    fn with_defaults(&self, defaults: &Defaults) -> Option<Self> {
+        let mut method = self.clone();
+        let changed = defaults.fill_parse_mode(&mut method.parse_mode, method.entities.as_ref())
+            | defaults.fill_disable_notification(&mut method.disable_notification)
+            | defaults.fill_protect_content(&mut method.protect_content)
+            | defaults.fill_link_preview_options(&mut method.link_preview_options);
+
+        changed.then_some(method)
+    }
 }
 
 impl AsRef<SendMessage> for SendMessage {
@@ -1,7 +1,7 @@
 use super::base::{prepare_file, Request, TelegramMethod};
 
 use crate::{
-    client::Bot,
+    client::{Bot, Defaults},
     types::{ChatIdKind, InputFile, Message, ReplyMarkup, ReplyParameters},
 };
 
@@ -174,6 +174,14 @@ impl<'a> TelegramMethod for SendSticker<'a> {
 
         Request::new("sendSticker", self, Some(files.into()))
     }
+
+    fn with_defaults(&self, defaults: &Defaults) -> Option<Self> {
+        let mut method = self.clone();
+        let changed = defaults.fill_disable_notification(&mut method.disable_notification)
+            | defaults.fill_protect_content(&mut method.protect_content);
+
+        changed.then_some(method)
+    }
 }
 
 impl<'a> AsRef<SendSticker<'a>> for SendSticker<'a> {
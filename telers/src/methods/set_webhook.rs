@@ -0,0 +1,193 @@
+use super::base::{prepare_file, Request, TelegramMethod};
+
+use crate::{client::Bot, types::InputFile};
+
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// Use this method to specify a URL and receive incoming updates via an outgoing webhook. Whenever there is an update for the bot, we will send an HTTPS POST request to the specified URL, containing a JSON-serialized [`Update`](crate::types::Update). In case of an unsuccessful request, we will give up after a reasonable amount of attempts.
+/// If you'd like to make sure that the webhook was set by you, you can specify secret data in the parameter `secret_token`. If specified, the request will contain a header `X-Telegram-Bot-Api-Secret-Token` with the secret token as content.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#setwebhook>
+/// # Notes
+/// - You will not be able to receive updates using [`GetUpdates`](crate::methods::GetUpdates) for as long as an outgoing webhook is set up. \
+/// - To use a self-signed certificate, you need to upload your public key certificate using `certificate` parameter. \
+/// - Ports currently supported for webhooks: 443, 80, 88, 8443.
+/// # Returns
+/// Returns `true` on success
+#[skip_serializing_none]
+#[derive(Debug, Clone, Hash, PartialEq, Serialize)]
+pub struct SetWebhook<'a> {
+    /// HTTPS URL to send updates to. Use an empty string to remove webhook integration
+    pub url: String,
+    /// Upload your public key certificate so that the root certificate in use can be checked
+    pub certificate: Option<InputFile<'a>>,
+    /// The fixed IP address which will be used to send webhook requests instead of the IP address resolved through DNS
+    pub ip_address: Option<String>,
+    /// The maximum allowed number of simultaneous HTTPS connections to the webhook for update delivery, 1-100. Defaults to 40. Use lower values to limit the load on your bot's server, and higher values to increase your bot's throughput.
+    pub max_connections: Option<i64>,
+    /// A JSON-serialized list of the update types you want your bot to receive. For example, specify [`message`, `edited_channel_post`, `callback_query`] to only receive updates of these types. See [`crate::types::Update`] for a complete list of available update types. Specify an empty list to receive all update types except *chat_member* (default). If not specified, the previous setting will be used.
+    pub allowed_updates: Option<Vec<String>>,
+    /// Pass `true` to drop all pending updates
+    pub drop_pending_updates: Option<bool>,
+    /// A secret token to be sent in a header `X-Telegram-Bot-Api-Secret-Token` in every webhook request, 1-256 characters. Only characters `A-Z`, `a-z`, `0-9`, `_` and `-` are allowed. The header is useful to ensure that the request comes from a webhook set by you.
+    pub secret_token: Option<String>,
+}
+
+impl<'a> SetWebhook<'a> {
+    #[must_use]
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            certificate: None,
+            ip_address: None,
+            max_connections: None,
+            allowed_updates: None,
+            drop_pending_updates: None,
+            secret_token: None,
+        }
+    }
+
+    #[must_use]
+    pub fn url(self, val: impl Into<String>) -> Self {
+        Self {
+            url: val.into(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn certificate(self, val: impl Into<InputFile<'a>>) -> Self {
+        Self {
+            certificate: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn ip_address(self, val: impl Into<String>) -> Self {
+        Self {
+            ip_address: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn max_connections(self, val: i64) -> Self {
+        Self {
+            max_connections: Some(val),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn allowed_update(self, val: impl Into<String>) -> Self {
+        Self {
+            allowed_updates: Some(
+                self.allowed_updates
+                    .unwrap_or_default()
+                    .into_iter()
+                    .chain(Some(val.into()))
+                    .collect(),
+            ),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn allowed_updates<T, I>(self, val: I) -> Self
+    where
+        T: Into<String>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            allowed_updates: Some(
+                self.allowed_updates
+                    .unwrap_or_default()
+                    .into_iter()
+                    .chain(val.into_iter().map(Into::into))
+                    .collect(),
+            ),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn drop_pending_updates(self, val: bool) -> Self {
+        Self {
+            drop_pending_updates: Some(val),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn secret_token(self, val: impl Into<String>) -> Self {
+        Self {
+            secret_token: Some(val.into()),
+            ..self
+        }
+    }
+}
+
+impl<'a> SetWebhook<'a> {
+    #[must_use]
+    pub fn certificate_option(self, val: Option<impl Into<InputFile<'a>>>) -> Self {
+        Self {
+            certificate: val.map(Into::into),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn ip_address_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            ip_address: val.map(Into::into),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn max_connections_option(self, val: Option<i64>) -> Self {
+        Self {
+            max_connections: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn drop_pending_updates_option(self, val: Option<bool>) -> Self {
+        Self {
+            drop_pending_updates: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn secret_token_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            secret_token: val.map(Into::into),
+            ..self
+        }
+    }
+}
+
+impl<'a> TelegramMethod for SetWebhook<'a> {
+    type Method = Self;
+    type Return = bool;
+
+    fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<'_, Self::Method> {
+        let mut files = vec![];
+
+        if let Some(file) = &self.certificate {
+            prepare_file(&mut files, file);
+        }
+
+        Request::new("setWebhook", self, Some(files.into()))
+    }
+}
+
+impl<'a> AsRef<SetWebhook<'a>> for SetWebhook<'a> {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
@@ -5,13 +5,18 @@
 //! - [`inner module`]
 //! - [`outer module`]
 //!
+//! Ready-made middlewares for group moderation are in the [`antispam module`].
+//!
 //! [`inner module`]: inner
+//! [`antispam module`]: antispam
 //! [`outer module`]: outer
 
 #![allow(clippy::module_name_repetitions)]
 
+pub mod antispam;
 pub mod inner;
 pub mod outer;
 
-pub use inner::{Middleware as InnerMiddleware, Next};
+pub use antispam::AntiSpam;
+pub use inner::{ErrorReporter, Middleware as InnerMiddleware, Next, Reporter};
 pub use outer::Middleware as OuterMiddleware;
@@ -0,0 +1,454 @@
+//! This module contains [`AntiSpam`] outer middleware for group-moderation bots,
+//! which detects spam messages by the [`Rule`]s and handles them with the [`Action`]s.
+//!
+//! Available rules:
+//! - [`Rule::Flood`]: the user sends too many messages in the period;
+//! - [`Rule::Duplicate`]: the user sends the same text or caption too many times in the period (texts are compared by their hashes);
+//! - [`Rule::NewMemberLink`]: the user posts a link soon after joining the chat.
+//!
+//! Available actions:
+//! - [`Action::Delete`]: delete the spam message;
+//! - [`Action::Mute`]: restrict the user from sending messages with `restrictChatMember` request for the duration;
+//! - [`Action::ReportToAdmins`]: send the report to human administrators of the chat in private messages.
+//!
+//! Administrators receive the report only if they started the private chat with the bot.
+//! If the message violates any rule, the actions are executed, the [`Violation`] is added to context with `spam_violation` key
+//! and propagation of the message is cancelled, so handlers don't receive it.
+//! Errors of the actions are logged and don't affect the propagation.
+//! The bot must be an administrator of the chat with `can_delete_messages` and `can_restrict_members` rights to delete and mute.
+//!
+//! Register the middleware for `message` observer. Activity of the users is stored in memory.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use telers::{client::Reqwest, middlewares::antispam::{Action, AntiSpam, Rule}, Router};
+//!
+//! let mut router = Router::<Reqwest>::new("main");
+//! router.message.outer_middlewares.register(
+//!     AntiSpam::new()
+//!         .rule(Rule::Flood { limit: 20, period: Duration::from_secs(60) })
+//!         .rule(Rule::Duplicate { limit: 3, period: Duration::from_secs(300) })
+//!         .rule(Rule::NewMemberLink { period: Duration::from_secs(24 * 60 * 60) })
+//!         .action(Action::Delete)
+//!         .action(Action::Mute { duration: Duration::from_secs(60 * 60) }),
+//! );
+//! ```
+
+use super::outer::{Middleware, MiddlewareResponse};
+
+use crate::{
+    client::{Bot, Session},
+    errors::EventErrorKind,
+    event::FlowControl,
+    methods::{DeleteMessage, GetChatAdministrators, RestrictChatMember, SendMessage},
+    router::Request,
+    types::{message_entity::Kind as EntityKind, ChatMember, ChatPermissions, Message, UpdateKind},
+};
+
+use async_trait::async_trait;
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap, VecDeque},
+    fmt::{self, Display, Formatter},
+    hash::{Hash as _, Hasher as _},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+use tracing::{event, instrument, Level};
+
+/// Number of tracked users, after which inactive users are removed
+const CLEANUP_THRESHOLD: usize = 10_000;
+
+/// Rule of spam detection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    /// The user sends more than `limit` messages in the `period`
+    Flood { limit: usize, period: Duration },
+    /// The user sends the same text or caption more than `limit` times in the `period`
+    Duplicate { limit: usize, period: Duration },
+    /// The user posts a link in the `period` after joining the chat
+    NewMemberLink { period: Duration },
+}
+
+impl Rule {
+    const fn period(&self) -> Duration {
+        match self {
+            Self::Flood { period, .. }
+            | Self::Duplicate { period, .. }
+            | Self::NewMemberLink { period } => *period,
+        }
+    }
+}
+
+/// Action, which is executed when the message violates a rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Delete the message
+    Delete,
+    /// Restrict the user from sending messages for the duration
+    Mute { duration: Duration },
+    /// Send the report to human administrators of the chat in private messages
+    ReportToAdmins,
+}
+
+/// Violated rule of the message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    pub rule: Rule,
+    pub chat_id: i64,
+    pub user_id: i64,
+    pub message_id: i64,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let reason = match self.rule {
+            Rule::Flood { .. } => "flood",
+            Rule::Duplicate { .. } => "duplicate messages",
+            Rule::NewMemberLink { .. } => "link from a new member",
+        };
+
+        write!(
+            f,
+            "Spam ({reason}) from user {} in chat {}, message {}",
+            self.user_id, self.chat_id, self.message_id
+        )
+    }
+}
+
+/// Recent activity of the user in the chat
+#[derive(Debug, Default)]
+struct Activity {
+    /// Times of the messages with the hashes of their texts
+    messages: VecDeque<(Instant, Option<u64>)>,
+    joined_at: Option<Instant>,
+}
+
+impl Activity {
+    fn last_seen(&self) -> Option<Instant> {
+        self.messages
+            .back()
+            .map(|(time, _)| *time)
+            .max(self.joined_at)
+    }
+}
+
+/// Middleware for detecting and handling spam messages.
+/// Check the [module](self) documentation for more information.
+#[derive(Debug, Clone, Default)]
+pub struct AntiSpam {
+    rules: Vec<Rule>,
+    actions: Vec<Action>,
+    activities: Arc<Mutex<HashMap<(i64, i64), Activity>>>,
+}
+
+impl AntiSpam {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn rule(self, val: Rule) -> Self {
+        Self {
+            rules: self.rules.into_iter().chain(Some(val)).collect(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn rules(self, val: impl IntoIterator<Item = Rule>) -> Self {
+        Self {
+            rules: self.rules.into_iter().chain(val).collect(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn action(self, val: Action) -> Self {
+        Self {
+            actions: self.actions.into_iter().chain(Some(val)).collect(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn actions(self, val: impl IntoIterator<Item = Action>) -> Self {
+        Self {
+            actions: self.actions.into_iter().chain(val).collect(),
+            ..self
+        }
+    }
+
+    /// Maximum period of the rules, activity older than it isn't used
+    fn max_period(&self) -> Duration {
+        self.rules
+            .iter()
+            .map(Rule::period)
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Record the message to the activity of the user and check it by the rules
+    /// # Returns
+    /// The first violated rule
+    fn check(&self, message: &Message, now: Instant) -> Option<Violation> {
+        let chat_id = message.chat().id();
+        let max_period = self.max_period();
+
+        let mut activities = self.activities.lock().unwrap();
+
+        if activities.len() > CLEANUP_THRESHOLD {
+            activities.retain(|_, activity| {
+                activity
+                    .last_seen()
+                    .map_or(false, |time| now.duration_since(time) <= max_period)
+            });
+        }
+
+        if let Some(users) = message.new_chat_members() {
+            for user in users {
+                activities.entry((chat_id, user.id)).or_default().joined_at = Some(now);
+            }
+            return None;
+        }
+
+        let user_id = message.from_id()?;
+        let activity = activities.entry((chat_id, user_id)).or_default();
+
+        while activity
+            .messages
+            .front()
+            .map_or(false, |(time, _)| now.duration_since(*time) > max_period)
+        {
+            activity.messages.pop_front();
+        }
+
+        let hash = message.text_or_caption().map(|text| {
+            let mut hasher = DefaultHasher::new();
+            text.hash(&mut hasher);
+            hasher.finish()
+        });
+        activity.messages.push_back((now, hash));
+
+        let rule = self.rules.iter().find(|rule| match rule {
+            Rule::Flood { limit, period } => {
+                activity
+                    .messages
+                    .iter()
+                    .filter(|(time, _)| now.duration_since(*time) <= *period)
+                    .count()
+                    > *limit
+            }
+            Rule::Duplicate { limit, period } => {
+                hash.is_some()
+                    && activity
+                        .messages
+                        .iter()
+                        .filter(|(time, message_hash)| {
+                            now.duration_since(*time) <= *period && *message_hash == hash
+                        })
+                        .count()
+                        > *limit
+            }
+            Rule::NewMemberLink { period } => {
+                activity
+                    .joined_at
+                    .map_or(false, |joined_at| now.duration_since(joined_at) <= *period)
+                    && message.entities().map_or(false, |entities| {
+                        entities.iter().any(|entity| {
+                            matches!(entity.kind, EntityKind::Url | EntityKind::TextLink(_))
+                        })
+                    })
+            }
+        })?;
+
+        Some(Violation {
+            rule: *rule,
+            chat_id,
+            user_id,
+            message_id: message.id(),
+        })
+    }
+
+    async fn execute<Client>(&self, bot: &Bot<Client>, violation: &Violation)
+    where
+        Client: Session,
+    {
+        let Violation {
+            chat_id,
+            user_id,
+            message_id,
+            ..
+        } = *violation;
+
+        for action in &self.actions {
+            let result = match action {
+                Action::Delete => bot
+                    .send(DeleteMessage::new(chat_id, message_id))
+                    .await
+                    .map(drop),
+                Action::Mute { duration } => {
+                    let until_date = SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .unwrap_or_default()
+                        .saturating_add(*duration)
+                        .as_secs();
+
+                    bot.send(
+                        RestrictChatMember::new(
+                            chat_id,
+                            user_id,
+                            ChatPermissions::new().can_send_messages(false),
+                        )
+                        .until_date(i64::try_from(until_date).unwrap_or(i64::MAX)),
+                    )
+                    .await
+                    .map(drop)
+                }
+                Action::ReportToAdmins => match bot.send(GetChatAdministrators::new(chat_id)).await
+                {
+                    Ok(admins) => {
+                        for admin in admins.iter().map(ChatMember::user) {
+                            if admin.is_bot {
+                                continue;
+                            }
+
+                            if let Err(err) = bot
+                                .send(SendMessage::new(admin.id, violation.to_string()))
+                                .await
+                            {
+                                event!(Level::DEBUG, error = %err, admin_id = admin.id, "Failed to report spam to admin");
+                            }
+                        }
+                        Ok(())
+                    }
+                    Err(err) => Err(err),
+                },
+            };
+
+            if let Err(err) = result {
+                event!(Level::WARN, error = %err, ?action, "Failed to execute anti-spam action");
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<Client> Middleware<Client> for AntiSpam
+where
+    Client: Session + 'static,
+{
+    #[instrument(skip(self, request))]
+    async fn call(
+        &self,
+        request: Request<Client>,
+    ) -> Result<MiddlewareResponse<Client>, EventErrorKind> {
+        let UpdateKind::Message(message) = &request.update.kind else {
+            return Ok((request, FlowControl::default()));
+        };
+        let Some(violation) = self.check(message, Instant::now()) else {
+            return Ok((request, FlowControl::default()));
+        };
+
+        event!(Level::INFO, %violation, "Spam is detected");
+
+        self.execute(&request.bot, &violation).await;
+
+        request
+            .context
+            .insert("spam_violation", Box::new(violation));
+
+        Ok((request, FlowControl::StopPropagation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Chat, MessageEntity, MessageNewChatMembers, MessageText, User};
+
+    fn text(user_id: i64, text: &str) -> Message {
+        Message::Text(Box::new(MessageText {
+            from: Some(User {
+                id: user_id,
+                ..Default::default()
+            }),
+            text: text.into(),
+            ..Default::default()
+        }))
+    }
+
+    #[test]
+    fn test_flood_and_duplicate() {
+        let antispam = AntiSpam::new()
+            .rule(Rule::Duplicate {
+                limit: 1,
+                period: Duration::from_secs(60),
+            })
+            .rule(Rule::Flood {
+                limit: 3,
+                period: Duration::from_secs(10),
+            });
+        let now = Instant::now();
+
+        assert!(antispam.check(&text(1, "a"), now).is_none());
+        assert!(antispam.check(&text(1, "b"), now).is_none());
+        // Messages of other users are tracked separately
+        assert!(antispam.check(&text(2, "a"), now).is_none());
+
+        let violation = antispam.check(&text(1, "a"), now).unwrap();
+        assert!(matches!(violation.rule, Rule::Duplicate { .. }));
+        assert_eq!(violation.user_id, 1);
+
+        let violation = antispam.check(&text(1, "c"), now).unwrap();
+        assert!(matches!(violation.rule, Rule::Flood { .. }));
+
+        // Old messages aren't counted
+        let later = now + Duration::from_secs(120);
+        assert!(antispam.check(&text(1, "a"), later).is_none());
+    }
+
+    #[test]
+    fn test_new_member_link() {
+        let antispam = AntiSpam::new().rule(Rule::NewMemberLink {
+            period: Duration::from_secs(60),
+        });
+        let now = Instant::now();
+
+        let link = |user_id| {
+            let mut message = text(user_id, "https://example.com");
+            if let Message::Text(message) = &mut message {
+                message.entities = Some(Box::new([MessageEntity {
+                    kind: EntityKind::Url,
+                    offset: 0,
+                    length: 19,
+                }]));
+            }
+            message
+        };
+
+        // Links of the users, which aren't seen joining the chat, are allowed
+        assert!(antispam.check(&link(1), now).is_none());
+
+        let joined = Message::NewChatMembers(Box::new(MessageNewChatMembers {
+            id: 1,
+            from: None,
+            sender_chat: None,
+            date: 0,
+            chat: Chat::default(),
+            members: Box::new([User {
+                id: 2,
+                ..Default::default()
+            }]),
+        }));
+        assert!(antispam.check(&joined, now).is_none());
+
+        assert!(antispam.check(&text(2, "hello"), now).is_none());
+        assert!(antispam.check(&link(2), now).is_some());
+        assert!(antispam
+            .check(&link(2), now + Duration::from_secs(120))
+            .is_none());
+    }
+}
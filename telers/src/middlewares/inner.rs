@@ -16,9 +16,17 @@
 //! [`context`]: crate::context::Context
 
 pub mod base;
+pub mod chat_settings;
+pub mod command_args_reply;
+pub mod error_reporter;
 pub mod logging;
 pub mod manager;
 
 pub use base::{wrap_handler_and_middlewares_to_next, Middleware, Next};
+pub use chat_settings::{ChatSettings, Settings, SettingsRepository};
+pub use command_args_reply::CommandArgsReply;
+#[cfg(feature = "sentry")]
+pub use error_reporter::Sentry;
+pub use error_reporter::{ErrorReporter, Reporter};
 pub use logging::Logging;
 pub use manager::Manager;
@@ -1,5 +1,5 @@
 use crate::{
-    client::Reqwest,
+    client::DefaultSession,
     errors::EventErrorKind,
     event::{
         service::Service,
@@ -11,7 +11,7 @@ use async_trait::async_trait;
 use std::{future::Future, pin::Pin, sync::Arc};
 
 /// The middleware chain and the handler at the end
-pub type Next<Client = Reqwest> = Box<
+pub type Next<Client = DefaultSession> = Box<
     dyn Fn(
             HandlerRequest<Client>,
         )
@@ -31,7 +31,7 @@ pub type Next<Client = Reqwest> = Box<
 ///
 /// Implement this trait for your own middlewares
 #[async_trait]
-pub trait Middleware<Client = Reqwest>: Send + Sync {
+pub trait Middleware<Client = DefaultSession>: Send + Sync {
     /// Execute middleware
     /// # Arguments
     /// * `request` - Data for handler and middlewares
@@ -124,7 +124,7 @@ mod tests {
     use crate::{
         client::{Bot, Reqwest},
         context::Context,
-        event::{service::ServiceFactory as _, telegram::handler_service, EventReturn},
+        event::{service::ServiceFactory as _, telegram::handler_service, FlowControl},
         types::{Message, Update, UpdateKind},
     };
 
@@ -138,7 +138,7 @@ mod tests {
     #[tokio::test]
     async fn test_call() {
         let handler_service_factory =
-            handler_service(|| async { Ok(EventReturn::Finish) }).new_service(());
+            handler_service(|| async { Ok(FlowControl::Continue) }).new_service(());
         let handler_service = Arc::new(handler_service_factory.unwrap());
 
         let request = HandlerRequest::new(
@@ -158,7 +158,7 @@ mod tests {
         .unwrap();
 
         match response.handler_result {
-            Ok(EventReturn::Finish) => {}
+            Ok(FlowControl::Continue) => {}
             _ => panic!("Unexpected response"),
         }
     }
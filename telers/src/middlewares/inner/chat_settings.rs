@@ -0,0 +1,331 @@
+//! This module contains [`ChatSettings`] middleware, which loads user-defined settings of the chat before the handler
+//! and saves them after the handler, if they are changed, and [`Settings`] extractor to read and change them in handlers.
+//!
+//! Settings are loaded and saved by [`SettingsRepository`], which you implement for your database.
+//! Settings are loaded lazily: only if filters are passed and the handler is going to be called,
+//! so updates, which aren't handled, don't cause database requests.
+//! If the chat doesn't have settings yet, the default settings are used.
+//! Changes are tracked by [`Settings`], so settings are saved only if they are changed by the handler and the handler is successful.
+//!
+//! ```ignore
+//! #[derive(Debug, Default, Clone)]
+//! struct GroupSettings {
+//!     welcome: bool,
+//! }
+//!
+//! router.message.inner_middlewares.register(ChatSettings::<GroupSettings, _>::new(Postgres::new(pool)));
+//!
+//! async fn toggle_welcome(settings: Settings<GroupSettings>) -> HandlerResult {
+//!     settings.update(|settings| settings.welcome = !settings.welcome);
+//!
+//!     Ok(FlowControl::Continue)
+//! }
+//! ```
+//!
+//! Settings are put to the context by `chat_settings` key, so only one type of the settings can be used for the update.
+
+use super::base::{Middleware, Next};
+
+use crate::{
+    client::Bot,
+    context::Context,
+    errors::{EventErrorKind, ExtractionError, MiddlewareError},
+    event::telegram::{HandlerRequest, HandlerResponse},
+    extractors::FromEventAndContext,
+    types::Update,
+};
+
+use async_trait::async_trait;
+use std::{
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+};
+use tracing::{event, Level};
+
+/// Repository of the chat settings, which is implemented for your database
+#[async_trait]
+pub trait SettingsRepository<T>: Send + Sync {
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Loads settings of the chat of the bot
+    /// # Returns
+    /// `None` if the chat doesn't have settings yet
+    /// # Errors
+    /// If the settings can't be loaded
+    async fn load(&self, bot_id: i64, chat_id: i64) -> Result<Option<T>, Self::Error>;
+
+    /// Saves settings of the chat of the bot
+    /// # Errors
+    /// If the settings can't be saved
+    async fn save(&self, bot_id: i64, chat_id: i64, settings: T) -> Result<(), Self::Error>;
+}
+
+#[derive(Debug)]
+struct State<T> {
+    value: Mutex<T>,
+    changed: AtomicBool,
+}
+
+/// Settings of the chat with change tracking, which are loaded by [`ChatSettings`] middleware.
+/// Check the [module](self) documentation for more information.
+pub struct Settings<T> {
+    state: Arc<State<T>>,
+}
+
+impl<T> Settings<T> {
+    #[must_use]
+    pub fn new(value: T) -> Self {
+        Self {
+            state: Arc::new(State {
+                value: Mutex::new(value),
+                changed: AtomicBool::new(false),
+            }),
+        }
+    }
+
+    /// Gets copy of the settings
+    #[must_use]
+    pub fn get(&self) -> T
+    where
+        T: Clone,
+    {
+        self.state.value.lock().unwrap().clone()
+    }
+
+    /// Reads the settings without copying
+    pub fn read<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(&self.state.value.lock().unwrap())
+    }
+
+    /// Changes the settings, so they are saved after the handler
+    pub fn update<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        let result = f(&mut self.state.value.lock().unwrap());
+        self.state.changed.store(true, Ordering::Release);
+        result
+    }
+
+    /// Replaces the settings, so they are saved after the handler
+    pub fn set(&self, val: T) {
+        self.update(|value| *value = val);
+    }
+
+    /// Checks that the settings are changed
+    #[must_use]
+    pub fn is_changed(&self) -> bool {
+        self.state.changed.load(Ordering::Acquire)
+    }
+}
+
+impl<T> Clone for Settings<T> {
+    fn clone(&self) -> Self {
+        Self {
+            state: Arc::clone(&self.state),
+        }
+    }
+}
+
+impl<T: Debug> Debug for Settings<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Settings")
+            .field("value", &self.state.value)
+            .field("changed", &self.is_changed())
+            .finish()
+    }
+}
+
+impl<Client, T> FromEventAndContext<Client> for Settings<T>
+where
+    T: Send + Sync + 'static,
+{
+    type Error = ExtractionError;
+
+    fn extract(
+        _bot: Arc<Bot<Client>>,
+        _update: Arc<Update>,
+        context: Arc<Context>,
+    ) -> Result<Self, Self::Error> {
+        let settings = context.get("chat_settings").ok_or_else(|| {
+            ExtractionError::new(
+                "Chat settings aren't found in the context by `chat_settings` key. \
+                Probably you forgot to register `ChatSettings` middleware or the update doesn't have a chat",
+            )
+        })?;
+
+        settings
+            .downcast_ref::<Self>()
+            .cloned()
+            .ok_or_else(|| ExtractionError::new("Chat settings in the context have another type"))
+    }
+}
+
+/// Middleware for loading the chat settings before the handler and saving them after the handler.
+/// Check the [module](self) documentation for more information.
+/// # Notes
+/// Use it as inner middleware, so settings are loaded only for handled updates
+pub struct ChatSettings<T, R> {
+    repository: Arc<R>,
+    _settings: PhantomData<fn() -> T>,
+}
+
+impl<T, R> ChatSettings<T, R> {
+    #[must_use]
+    pub fn new(repository: R) -> Self {
+        Self {
+            repository: Arc::new(repository),
+            _settings: PhantomData,
+        }
+    }
+}
+
+impl<T, R> Clone for ChatSettings<T, R> {
+    fn clone(&self) -> Self {
+        Self {
+            repository: Arc::clone(&self.repository),
+            _settings: PhantomData,
+        }
+    }
+}
+
+impl<T, R> Debug for ChatSettings<T, R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChatSettings").finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl<Client, T, R> Middleware<Client> for ChatSettings<T, R>
+where
+    Client: Send + Sync + 'static,
+    T: Default + Clone + Send + Sync + 'static,
+    R: SettingsRepository<T> + 'static,
+{
+    async fn call(
+        &self,
+        request: HandlerRequest<Client>,
+        next: Next<Client>,
+    ) -> Result<HandlerResponse<Client>, EventErrorKind> {
+        let Some(chat_id) = request.update.chat_id() else {
+            return next(request).await;
+        };
+        let bot_id = request.bot.bot_id;
+
+        let settings = Settings::new(
+            self.repository
+                .load(bot_id, chat_id)
+                .await
+                .map_err(MiddlewareError::new)?
+                .unwrap_or_default(),
+        );
+        request
+            .context
+            .insert("chat_settings", Box::new(settings.clone()));
+
+        let response = next(request).await?;
+
+        if settings.is_changed() {
+            self.repository
+                .save(bot_id, chat_id, settings.get())
+                .await
+                .map_err(|err| {
+                    event!(Level::ERROR, error = %err, chat_id, "Failed to save chat settings");
+
+                    MiddlewareError::new(err)
+                })?;
+        }
+
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::Reqwest,
+        event::{service::ServiceFactory as _, telegram::handler_service, FlowControl},
+        middlewares::inner::wrap_handler_and_middlewares_to_next,
+    };
+
+    use std::{collections::HashMap, convert::Infallible};
+
+    #[derive(Default)]
+    struct Repository {
+        saved: Mutex<HashMap<(i64, i64), u8>>,
+        loads: Mutex<u8>,
+    }
+
+    #[async_trait]
+    impl SettingsRepository<u8> for Arc<Repository> {
+        type Error = Infallible;
+
+        async fn load(&self, bot_id: i64, chat_id: i64) -> Result<Option<u8>, Self::Error> {
+            *self.loads.lock().unwrap() += 1;
+            Ok(self.saved.lock().unwrap().get(&(bot_id, chat_id)).copied())
+        }
+
+        async fn save(&self, bot_id: i64, chat_id: i64, settings: u8) -> Result<(), Self::Error> {
+            self.saved
+                .lock()
+                .unwrap()
+                .insert((bot_id, chat_id), settings);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chat_settings() {
+        let repository = Arc::new(Repository::default());
+        let middleware = ChatSettings::<u8, _>::new(Arc::clone(&repository));
+
+        let request = || {
+            HandlerRequest::new(
+                Arc::new(Bot::<Reqwest>::default()),
+                Arc::new(Update::default()),
+                Arc::new(Context::default()),
+            )
+        };
+
+        let increment = Arc::new(
+            handler_service(|settings: Settings<u8>| async move {
+                settings.update(|value| *value += 1);
+
+                Ok(FlowControl::Continue)
+            })
+            .new_service(())
+            .unwrap(),
+        );
+        let read = Arc::new(
+            handler_service(|settings: Settings<u8>| async move {
+                assert_eq!(settings.get(), 2);
+
+                Ok(FlowControl::Continue)
+            })
+            .new_service(())
+            .unwrap(),
+        );
+
+        for _ in 0..2 {
+            middleware
+                .call(
+                    request(),
+                    wrap_handler_and_middlewares_to_next(Arc::clone(&increment), [].into()),
+                )
+                .await
+                .unwrap();
+        }
+        middleware
+            .call(
+                request(),
+                wrap_handler_and_middlewares_to_next(read, [].into()),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(*repository.loads.lock().unwrap(), 3);
+        assert_eq!(repository.saved.lock().unwrap().get(&(0, 0)), Some(&2));
+    }
+}
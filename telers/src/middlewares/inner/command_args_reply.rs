@@ -0,0 +1,216 @@
+//! This module contains [`CommandArgsReply`] middleware, which replies to the message with the error,
+//! if [`CommandArgs`] extractor can't parse command arguments.
+//!
+//! By default, the reply text contains the command and the parse error,
+//! but you can change it with [`CommandArgsReply::text`] method, for example, to show usage of the command.
+//! If the reply is sent, the update is considered handled, otherwise the extraction error is returned.
+//!
+//! [`CommandArgs`]: crate::extractors::CommandArgs
+
+use super::base::{Middleware, Next};
+
+use crate::{
+    client::Session,
+    errors::EventErrorKind,
+    event::{
+        telegram::{HandlerRequest, HandlerResponse},
+        FlowControl,
+    },
+    extractors::command_args::Error as CommandArgsError,
+    filters::CommandObject,
+    methods::SendMessage,
+    types::{ReplyParameters, UpdateKind},
+};
+
+use async_trait::async_trait;
+use std::{
+    fmt::{self, Debug, Display, Formatter},
+    sync::Arc,
+};
+use tracing::{event, Level};
+
+/// Function, which creates the reply text by the parse error and the command
+pub type TextFactory = Arc<dyn Fn(&CommandArgsError, &CommandObject) -> String + Send + Sync>;
+
+/// Middleware for replying to the message with the error of parsing command arguments
+/// # Notes
+/// Use it as inner middleware, so it handles errors of extractors
+#[derive(Clone)]
+pub struct CommandArgsReply {
+    text: TextFactory,
+}
+
+impl CommandArgsReply {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            text: Arc::new(|err, command| {
+                format!(
+                    "Invalid arguments of the command {prefix}{command}: {err}",
+                    prefix = command.prefix,
+                    command = command.command,
+                )
+            }),
+        }
+    }
+
+    /// Set function, which creates the reply text by the parse error and the command
+    #[must_use]
+    pub fn text<F>(self, val: F) -> Self
+    where
+        F: Fn(&CommandArgsError, &CommandObject) -> String + Send + Sync + 'static,
+    {
+        Self {
+            text: Arc::new(val),
+        }
+    }
+}
+
+impl Default for CommandArgsReply {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for CommandArgsReply {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CommandArgsReply").finish_non_exhaustive()
+    }
+}
+
+impl Display for CommandArgsReply {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "CommandArgsReply")
+    }
+}
+
+#[async_trait]
+impl<Client> Middleware<Client> for CommandArgsReply
+where
+    Client: Session + 'static,
+{
+    async fn call(
+        &self,
+        request: HandlerRequest<Client>,
+        next: Next<Client>,
+    ) -> Result<HandlerResponse<Client>, EventErrorKind> {
+        let bot = Arc::clone(&request.bot);
+        let update = Arc::clone(&request.update);
+        let context = Arc::clone(&request.context);
+
+        let err = match next(request).await {
+            Err(EventErrorKind::Extraction(err)) => err,
+            result => return result,
+        };
+
+        let text = {
+            let (Some(args_err), Some(command)) =
+                (context.get("command_args_error"), context.get("command"))
+            else {
+                return Err(err.into());
+            };
+            let (Some(args_err), Some(command)) = (
+                args_err.downcast_ref::<CommandArgsError>(),
+                command.downcast_ref::<CommandObject>(),
+            ) else {
+                return Err(err.into());
+            };
+
+            (self.text)(args_err, command)
+        };
+
+        let UpdateKind::Message(message) = update.kind() else {
+            return Err(err.into());
+        };
+
+        if let Err(send_err) = bot
+            .send(
+                SendMessage::new(message.chat().id(), text)
+                    .message_thread_id_option(message.thread_id())
+                    .reply_parameters(ReplyParameters::new(message.id())),
+            )
+            .await
+        {
+            event!(Level::ERROR, error = %send_err, "Failed to reply with command arguments error");
+
+            return Err(err.into());
+        }
+
+        Ok(HandlerResponse {
+            request: HandlerRequest::new(bot, update, context),
+            handler_result: Ok(FlowControl::Continue),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{Bot, Reqwest},
+        context::Context,
+        event::{service::ServiceFactory as _, telegram::handler_service},
+        extractors::CommandArgs,
+        middlewares::inner::wrap_handler_and_middlewares_to_next,
+        types::Update,
+    };
+
+    #[test]
+    fn test_text() {
+        let command = CommandObject::extract("/price BTC").unwrap();
+        let err = CommandArgsError::Missing { position: 2 };
+
+        let middleware = CommandArgsReply::default();
+        assert_eq!(
+            (middleware.text)(&err, &command),
+            "Invalid arguments of the command /price: Missing argument at position 2"
+        );
+
+        let middleware =
+            middleware.text(|_, command| format!("Usage: /{} <coin> <amount>", command.command));
+        assert_eq!(
+            (middleware.text)(&err, &command),
+            "Usage: /price <coin> <amount>"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pass_through() {
+        let request = || {
+            HandlerRequest::new(
+                Arc::new(Bot::<Reqwest>::default()),
+                Arc::new(Update::default()),
+                Arc::new(Context::default()),
+            )
+        };
+
+        let service = Arc::new(
+            handler_service(|| async { Ok(FlowControl::Continue) })
+                .new_service(())
+                .unwrap(),
+        );
+        let response = CommandArgsReply::default()
+            .call(
+                request(),
+                wrap_handler_and_middlewares_to_next(service, [].into()),
+            )
+            .await;
+
+        assert!(response.is_ok());
+
+        // Extraction error without parsed command isn't handled
+        let service = Arc::new(
+            handler_service(|_: CommandArgs<(String,)>| async { Ok(FlowControl::Continue) })
+                .new_service(())
+                .unwrap(),
+        );
+        let response = CommandArgsReply::default()
+            .call(
+                request(),
+                wrap_handler_and_middlewares_to_next(service, [].into()),
+            )
+            .await;
+
+        assert!(matches!(response, Err(EventErrorKind::Extraction(_))));
+    }
+}
@@ -0,0 +1,347 @@
+//! This module contains [`ErrorReporter`] middleware, which reports errors and panics of handlers to an error tracking service.
+//!
+//! Reports are sent via [`Reporter`] trait, so you can implement it for any error tracking service you want.
+//! If `sentry` feature is enabled, [`Sentry`] reporter is available, which sends reports to the current [Sentry](https://sentry.io) hub.
+//!
+//! Each report contains update context: update id, update type, chat id and user id (if they are available),
+//! so you can find out what update caused the error.
+//!
+//! Panics are reported and then resumed, so the middleware doesn't change the behaviour of the handler.
+
+use super::base::{Middleware, Next};
+
+use crate::{
+    enums::UpdateType,
+    errors::EventErrorKind,
+    event::telegram::{HandlerRequest, HandlerResponse},
+    types::Update,
+};
+
+use async_trait::async_trait;
+use futures::FutureExt as _;
+use std::{
+    any::Any,
+    fmt::{self, Display, Formatter},
+    panic::{self, AssertUnwindSafe},
+    sync::Arc,
+};
+
+/// What happened while processing the update
+#[derive(Debug)]
+pub enum Failure<'a> {
+    /// Handler, extractor or another middleware returned an error
+    Error(&'a EventErrorKind),
+    /// Handler panicked with the message
+    Panic(&'a str),
+}
+
+impl Display for Failure<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Error(err) => write!(f, "{err}"),
+            Self::Panic(message) => write!(f, "Handler panicked: {message}"),
+        }
+    }
+}
+
+/// Report about the failure with update context
+#[derive(Debug)]
+pub struct Report<'a> {
+    pub failure: Failure<'a>,
+    pub update_id: i64,
+    pub update_type: UpdateType,
+    pub chat_id: Option<i64>,
+    pub user_id: Option<i64>,
+}
+
+impl<'a> Report<'a> {
+    #[must_use]
+    pub fn new(failure: Failure<'a>, update: &Update) -> Self {
+        Self {
+            failure,
+            update_id: update.id,
+            update_type: UpdateType::from(update),
+            chat_id: update.chat_id(),
+            user_id: update.from_id(),
+        }
+    }
+}
+
+/// Implement this trait to send reports to your error tracking service
+pub trait Reporter: Send + Sync {
+    /// Send report to the error tracking service
+    /// # Notes
+    /// This method is called in the async context, so it shouldn't block for a long time.
+    /// If sending of the report is slow, consider to send it in a background task.
+    fn report(&self, report: &Report<'_>);
+}
+
+impl<T: ?Sized> Reporter for Arc<T>
+where
+    T: Reporter,
+{
+    fn report(&self, report: &Report<'_>) {
+        T::report(self, report);
+    }
+}
+
+/// To possible use function-like as reporters
+impl<Func> Reporter for Func
+where
+    Func: Fn(&Report<'_>) + Send + Sync,
+{
+    fn report(&self, report: &Report<'_>) {
+        self(report);
+    }
+}
+
+/// Middleware for reporting errors and panics of handlers to an error tracking service
+/// # Notes
+/// Use it as inner middleware, so it reports errors of handlers and extractors.
+/// Panics are reported and then resumed.
+#[derive(Debug, Default, Clone)]
+pub struct ErrorReporter<R> {
+    reporter: R,
+}
+
+impl<R> ErrorReporter<R> {
+    #[must_use]
+    pub const fn new(reporter: R) -> Self {
+        Self { reporter }
+    }
+}
+
+impl<R> Display for ErrorReporter<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "ErrorReporter")
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "Box<dyn Any>"
+    }
+}
+
+#[async_trait]
+impl<Client, R> Middleware<Client> for ErrorReporter<R>
+where
+    Client: Send + Sync + 'static,
+    R: Reporter,
+{
+    async fn call(
+        &self,
+        request: HandlerRequest<Client>,
+        next: Next<Client>,
+    ) -> Result<HandlerResponse<Client>, EventErrorKind> {
+        let update = Arc::clone(&request.update);
+
+        match AssertUnwindSafe(next(request)).catch_unwind().await {
+            Ok(Ok(response)) => Ok(response),
+            Ok(Err(err)) => {
+                self.reporter
+                    .report(&Report::new(Failure::Error(&err), &update));
+
+                Err(err)
+            }
+            Err(payload) => {
+                self.reporter.report(&Report::new(
+                    Failure::Panic(panic_message(payload.as_ref())),
+                    &update,
+                ));
+
+                panic::resume_unwind(payload)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "sentry")]
+mod sentry {
+    use super::{Failure, Report, Reporter};
+
+    use sentry_core::{protocol::Level, Hub, User};
+    use std::sync::Arc;
+
+    /// Reporter, which sends reports to the [Sentry](https://sentry.io) hub.
+    /// Update context is attached to the event as tags and user.
+    /// # Notes
+    /// Sentry client should be initialized (for example, with `sentry::init`) before reports are sent,
+    /// otherwise reports are ignored.
+    #[derive(Debug, Clone)]
+    pub struct Sentry {
+        hub: Option<Arc<Hub>>,
+    }
+
+    impl Sentry {
+        /// Creates a new reporter, which sends reports to the current hub
+        #[must_use]
+        pub const fn new() -> Self {
+            Self { hub: None }
+        }
+
+        /// Creates a new reporter, which sends reports to the given hub
+        #[must_use]
+        pub const fn with_hub(hub: Arc<Hub>) -> Self {
+            Self { hub: Some(hub) }
+        }
+    }
+
+    impl Default for Sentry {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl Reporter for Sentry {
+        fn report(&self, report: &Report<'_>) {
+            let capture = |hub: &Hub| {
+                hub.with_scope(
+                    |scope| {
+                        scope.set_tag("update_id", report.update_id);
+                        scope.set_tag("update_type", report.update_type);
+
+                        if let Some(chat_id) = report.chat_id {
+                            scope.set_tag("chat_id", chat_id);
+                        }
+                        if let Some(user_id) = report.user_id {
+                            scope.set_user(Some(User {
+                                id: Some(user_id.to_string()),
+                                ..Default::default()
+                            }));
+                        }
+                    },
+                    || match report.failure {
+                        Failure::Error(err) => hub.capture_error(err),
+                        Failure::Panic(_) => {
+                            hub.capture_message(&report.failure.to_string(), Level::Fatal)
+                        }
+                    },
+                );
+            };
+
+            match self.hub {
+                Some(ref hub) => capture(hub),
+                None => Hub::with_active(|hub| capture(hub)),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "sentry")]
+pub use self::sentry::Sentry;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{Bot, Reqwest},
+        context::Context,
+        errors::HandlerError,
+        event::{service::ServiceFactory as _, telegram::handler_service, FlowControl},
+        middlewares::inner::wrap_handler_and_middlewares_to_next,
+        types::{Chat, ChatPrivate, Message, MessageText, UpdateKind, User},
+    };
+
+    use std::sync::Mutex;
+
+    fn request() -> HandlerRequest<Reqwest> {
+        HandlerRequest::new(
+            Arc::new(Bot::<Reqwest>::default()),
+            Arc::new(Update {
+                id: 1,
+                kind: UpdateKind::Message(Message::Text(Box::new(MessageText {
+                    chat: Chat::Private(ChatPrivate {
+                        id: 2,
+                        ..Default::default()
+                    }),
+                    from: Some(User {
+                        id: 3,
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }))),
+            }),
+            Arc::new(Context::default()),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_error_reporter() {
+        let reports = Arc::new(Mutex::new(vec![]));
+        let middleware = ErrorReporter::new({
+            let reports = Arc::clone(&reports);
+
+            move |report: &Report<'_>| {
+                reports.lock().unwrap().push((
+                    report.failure.to_string(),
+                    report.update_id,
+                    report.update_type,
+                    report.chat_id,
+                    report.user_id,
+                ));
+            }
+        });
+
+        let service = Arc::new(
+            handler_service(|| async { Ok(FlowControl::Continue) })
+                .new_service(())
+                .unwrap(),
+        );
+        let response = middleware
+            .call(
+                request(),
+                wrap_handler_and_middlewares_to_next(service, [].into()),
+            )
+            .await;
+
+        assert!(response.is_ok());
+        assert!(reports.lock().unwrap().is_empty());
+
+        let service = Arc::new(
+            handler_service(|| async { Err(HandlerError::from_display("test")) })
+                .new_service(())
+                .unwrap(),
+        );
+        let response = middleware
+            .call(
+                request(),
+                wrap_handler_and_middlewares_to_next(service, [].into()),
+            )
+            .await;
+
+        assert!(response.is_err());
+        assert_eq!(
+            reports.lock().unwrap().as_slice(),
+            [("test".to_owned(), 1, UpdateType::Message, Some(2), Some(3))]
+        );
+
+        let service = Arc::new(
+            handler_service(|| async {
+                panic!("test");
+
+                #[allow(unreachable_code)]
+                Ok(FlowControl::Continue)
+            })
+            .new_service(())
+            .unwrap(),
+        );
+        let result = tokio::spawn(async move {
+            middleware
+                .call(
+                    request(),
+                    wrap_handler_and_middlewares_to_next(service, [].into()),
+                )
+                .await
+        })
+        .await;
+
+        assert!(result.unwrap_err().is_panic());
+        assert_eq!(reports.lock().unwrap().len(), 2);
+        assert_eq!(reports.lock().unwrap()[1].0, "Handler panicked: test");
+    }
+}
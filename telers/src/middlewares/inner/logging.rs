@@ -4,7 +4,7 @@ use crate::{
     errors::EventErrorKind,
     event::{
         telegram::{HandlerRequest, HandlerResponse},
-        EventReturn,
+        FlowControl,
     },
 };
 
@@ -49,22 +49,25 @@ where
         match result {
             // `unwrap` is safe because handler error is wrapped to event error by next function
             Ok(ref response) => match response.handler_result.as_ref().unwrap() {
-                EventReturn::Finish => {
+                FlowControl::Continue => {
                     event!(
                         Level::DEBUG,
                         "Handler finished. Execution time: {elapsed:.2?}",
                     );
                 }
-                EventReturn::Skip => {
+                FlowControl::SkipHandler => {
                     event!(
                         Level::DEBUG,
                         "Handler skipped. Execution time: {elapsed:.2?}",
                     );
                 }
-                EventReturn::Cancel => {
+                flow @ (FlowControl::StopObserver
+                | FlowControl::StopRouter
+                | FlowControl::StopPropagation) => {
                     event!(
                         Level::DEBUG,
-                        "Handler canceled. Execution time: {elapsed:.2?}",
+                        %flow,
+                        "Handler stopped propagation. Execution time: {elapsed:.2?}",
                     );
                 }
             },
@@ -114,7 +117,7 @@ mod tests {
     #[tokio::test]
     async fn test_logging() {
         let handler_service_factory =
-            handler_service(|| async { Ok(EventReturn::Finish) }).new_service(());
+            handler_service(|| async { Ok(FlowControl::Continue) }).new_service(());
         let handler_service = Arc::new(handler_service_factory.unwrap());
 
         let request = HandlerRequest::new(
@@ -16,11 +16,21 @@
 //! [`inner middlewares`]: crate::middlewares::inner
 
 pub mod base;
+#[cfg(feature = "fsm")]
 pub mod fsm_context;
+#[cfg(feature = "fsm")]
+pub mod locale_resolver;
 pub mod manager;
+pub mod member_tracker;
+pub mod poll_tracker;
 pub mod user_context;
 
 pub use base::{Middleware, MiddlewareResponse};
+#[cfg(feature = "fsm")]
 pub use fsm_context::FSMContext;
+#[cfg(feature = "fsm")]
+pub use locale_resolver::{LocaleOverride, LocaleResolver};
 pub use manager::Manager;
+pub use member_tracker::{MemberCache, MemberTracker};
+pub use poll_tracker::{PollResults, PollTracker};
 pub use user_context::UserContext;
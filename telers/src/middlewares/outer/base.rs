@@ -1,12 +1,12 @@
-use crate::{client::Reqwest, errors::EventErrorKind, event::EventReturn, router::Request};
+use crate::{client::DefaultSession, errors::EventErrorKind, event::FlowControl, router::Request};
 
 use async_trait::async_trait;
 use std::{future::Future, sync::Arc};
 
 /// Response from middleware.
-/// First element is/isn't updated [`Request`] and second is [`EventReturn`] for the manipulate processing event,
-/// see [`EventReturn`] for more info.
-pub type MiddlewareResponse<Client = Reqwest> = (Request<Client>, EventReturn);
+/// First element is/isn't updated [`Request`] and second is [`FlowControl`] for the manipulate processing event,
+/// see [`FlowControl`] for more info.
+pub type MiddlewareResponse<Client = DefaultSession> = (Request<Client>, FlowControl);
 
 /// Outer middlewares called before filters, inner middlewares and handlers
 ///
@@ -17,7 +17,7 @@ pub type MiddlewareResponse<Client = Reqwest> = (Request<Client>, EventReturn);
 ///
 /// Implement this trait for your own middlewares
 #[async_trait]
-pub trait Middleware<Client = Reqwest>: Send + Sync {
+pub trait Middleware<Client = DefaultSession>: Send + Sync {
     /// Execute middleware
     /// # Arguments
     /// * `request` - Data for observers, filters, handler and middlewares
@@ -27,6 +27,15 @@ pub trait Middleware<Client = Reqwest>: Send + Sync {
         &self,
         request: Request<Client>,
     ) -> Result<MiddlewareResponse<Client>, EventErrorKind>;
+
+    /// Keys of the context, which the middleware puts to the context, for example, `fsm_context` for [`FSMContext`] middleware.
+    /// It's used for validation of the router configuration (see [`Router::validate`]).
+    ///
+    /// [`FSMContext`]: crate::middlewares::outer::FSMContext
+    /// [`Router::validate`]: crate::router::Router#method.validate
+    fn provided_context_keys(&self) -> Vec<&'static str> {
+        vec![]
+    }
 }
 
 #[async_trait]
@@ -41,6 +50,10 @@ where
     ) -> Result<MiddlewareResponse<Client>, EventErrorKind> {
         T::call(self, request).await
     }
+
+    fn provided_context_keys(&self) -> Vec<&'static str> {
+        T::provided_context_keys(self)
+    }
 }
 
 /// To possible use function-like as middlewares
@@ -73,7 +86,7 @@ mod tests {
     #[tokio::test]
     async fn test_call() {
         let middleware =
-            |request: Request<Reqwest>| async move { Ok((request, EventReturn::default())) };
+            |request: Request<Reqwest>| async move { Ok((request, FlowControl::default())) };
 
         let request = Request::new(
             Arc::new(Bot::<Reqwest>::default()),
@@ -3,25 +3,94 @@ use super::{Middleware, MiddlewareResponse};
 use crate::{
     context::Context as RequestContext,
     errors::{EventErrorKind, MiddlewareError},
-    event::EventReturn,
+    event::FlowControl,
     fsm::{
         storage::base::{StorageKey, DEFAULT_DESTINY},
         strategy::Strategy,
-        Context, Storage,
+        ChatData, Context, Storage, UserData,
     },
     router::Request,
-    types::User,
+    types::{Chat, User},
 };
 
 use async_trait::async_trait;
-use std::fmt::{self, Debug, Formatter};
-use tracing::instrument;
+use std::{
+    collections::HashMap,
+    fmt::{self, Debug, Formatter},
+    sync::{Arc, Mutex as StdMutex, Weak},
+};
+use tokio::sync::{Mutex, OwnedMutexGuard};
+use tracing::{event, instrument, Level};
+
+/// Minimum number of locks, after which unused locks are removed
+const MIN_LOCKS_TO_PRUNE: usize = 64;
+
+#[derive(Debug)]
+struct KeyLocksInner {
+    locks: HashMap<StorageKey, Weak<Mutex<()>>>,
+    /// Number of locks, after which unused locks are removed, so the map doesn't grow infinitely
+    prune_at: usize,
+}
+
+/// Async locks per storage key.
+/// Lock is removed from the map, when it isn't held or waited anymore.
+#[derive(Debug, Clone)]
+struct KeyLocks {
+    inner: Arc<StdMutex<KeyLocksInner>>,
+}
+
+impl Default for KeyLocks {
+    fn default() -> Self {
+        Self {
+            inner: Arc::new(StdMutex::new(KeyLocksInner {
+                locks: HashMap::new(),
+                prune_at: MIN_LOCKS_TO_PRUNE,
+            })),
+        }
+    }
+}
 
-/// Middleware for creating FSM [`Context`]
+impl KeyLocks {
+    async fn lock(&self, key: &StorageKey) -> OwnedMutexGuard<()> {
+        let lock = {
+            let mut inner = self.inner.lock().unwrap();
+
+            if let Some(lock) = inner.locks.get(key).and_then(Weak::upgrade) {
+                lock
+            } else {
+                if inner.locks.len() >= inner.prune_at {
+                    inner.locks.retain(|_, lock| lock.strong_count() > 0);
+                    inner.prune_at = (inner.locks.len() * 2).max(MIN_LOCKS_TO_PRUNE);
+                }
+
+                let lock = Arc::new(Mutex::new(()));
+                inner.locks.insert(key.clone(), Arc::downgrade(&lock));
+                lock
+            }
+        };
+
+        lock.lock_owned().await
+    }
+}
+
+/// Lock of the storage key, which is held in the context until the update is processed
+struct KeyLock {
+    key: StorageKey,
+    _guard: OwnedMutexGuard<()>,
+}
+
+/// Middleware for creating FSM [`Context`].
+/// It also adds [`ChatData`] and [`UserData`] to the context, if the chat and the user are present in the update
+/// # Locking
+/// By default, updates of the same storage key are processed concurrently,
+/// so two rapid messages from the same user can interleave read-modify-write cycles of the state and data.
+/// Use [`FSMContext::with_lock`] to process updates of the same storage key one by one.
 pub struct FSMContext<S> {
     storage: S,
     strategy: Strategy,
     destiny: &'static str,
+    lock: bool,
+    locks: KeyLocks,
 }
 
 impl<S> FSMContext<S> {
@@ -31,6 +100,8 @@ impl<S> FSMContext<S> {
             storage,
             strategy: Strategy::default(),
             destiny: DEFAULT_DESTINY,
+            lock: false,
+            locks: KeyLocks::default(),
         }
     }
 
@@ -49,6 +120,17 @@ impl<S> FSMContext<S> {
             ..self
         }
     }
+
+    /// Set `true` to hold an async lock of the storage key (see [`Strategy`]) while the update is processed,
+    /// so updates of the same key don't interleave and wait for each other. By default, it's `false`.
+    /// # Notes
+    /// Lock is held in the request context and released when the context is dropped after the update is processed,
+    /// so don't move the context to long-running spawned tasks, otherwise next updates of the key wait for them.
+    /// Updates without user aren't locked, because FSM context isn't created for them.
+    #[must_use]
+    pub fn with_lock(self, val: bool) -> Self {
+        Self { lock: val, ..self }
+    }
 }
 
 impl<S> Default for FSMContext<S>
@@ -61,6 +143,8 @@ where
             storage: S::default(),
             strategy: Strategy::default(),
             destiny: DEFAULT_DESTINY,
+            lock: false,
+            locks: KeyLocks::default(),
         }
     }
 }
@@ -70,6 +154,7 @@ impl<S> Debug for FSMContext<S> {
         f.debug_struct("FSMContext")
             .field("strategy", &self.strategy)
             .field("destiny", &self.destiny)
+            .field("lock", &self.lock)
             .finish_non_exhaustive()
     }
 }
@@ -129,7 +214,7 @@ where
                 chat_id,
                 user_id,
                 message_thread_id,
-                destiny: self.destiny,
+                destiny: self.destiny.into(),
             },
         )
     }
@@ -149,6 +234,27 @@ where
         let context = request.context.as_ref();
 
         if let Some(fsm_context) = self.resolve_event_context(request.bot.bot_id, context) {
+            let key = fsm_context.key();
+            // The middleware can be called several times for the same update (for example, in sub routers),
+            // so the key can be already locked by this update
+            let is_locked = context.get("fsm_lock").map_or(false, |lock| {
+                lock.downcast_ref()
+                    .map_or(false, |lock: &KeyLock| lock.key == *key)
+            });
+
+            if self.lock && !is_locked {
+                event!(Level::TRACE, ?key, "Waiting for lock of the storage key");
+
+                let guard = self.locks.lock(key).await;
+                context.insert(
+                    "fsm_lock",
+                    Box::new(KeyLock {
+                        key: key.clone(),
+                        _guard: guard,
+                    }),
+                );
+            }
+
             if let Some(state) = fsm_context
                 .get_state()
                 .await
@@ -160,8 +266,82 @@ where
             context.insert("fsm_context", Box::new(fsm_context));
         }
 
+        let bot_id = request.bot.bot_id;
+        let chat_id = context
+            .get("event_chat")
+            .and_then(|chat| chat.downcast_ref().map(Chat::id));
+        let user_id = context
+            .get("event_user")
+            .and_then(|user| user.downcast_ref().map(|user: &User| user.id));
+
+        if let Some(chat_id) = chat_id {
+            context.insert(
+                "fsm_chat_data",
+                Box::new(ChatData::new(self.storage.clone(), bot_id, chat_id)),
+            );
+        }
+        if let Some(user_id) = user_id {
+            context.insert(
+                "fsm_user_data",
+                Box::new(UserData::new(self.storage.clone(), bot_id, user_id)),
+            );
+        }
+
         context.insert("fsm_storage", Box::new(self.storage.clone()));
 
-        Ok((request, EventReturn::default()))
+        Ok((request, FlowControl::default()))
+    }
+
+    fn provided_context_keys(&self) -> Vec<&'static str> {
+        vec![
+            "fsm_context",
+            "fsm_state",
+            "fsm_chat_data",
+            "fsm_user_data",
+            "fsm_storage",
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::time::Duration;
+    use tokio::time::timeout;
+
+    #[tokio::test]
+    async fn test_key_locks() {
+        let locks = KeyLocks::default();
+        let key = StorageKey::new(0, 1, 1, None);
+        let other_key = StorageKey::new(0, 2, 2, None);
+
+        let guard = locks.lock(&key).await;
+
+        // Same key is locked until the guard is dropped
+        assert!(timeout(Duration::from_millis(10), locks.lock(&key))
+            .await
+            .is_err());
+        // Other keys aren't blocked
+        assert!(timeout(Duration::from_millis(10), locks.lock(&other_key))
+            .await
+            .is_ok());
+
+        drop(guard);
+
+        assert!(timeout(Duration::from_millis(10), locks.lock(&key))
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_key_locks_prune() {
+        let locks = KeyLocks::default();
+
+        for chat_id in 0..(MIN_LOCKS_TO_PRUNE * 2) as i64 {
+            drop(locks.lock(&StorageKey::new(0, chat_id, 0, None)).await);
+        }
+
+        assert!(locks.inner.lock().unwrap().locks.len() <= MIN_LOCKS_TO_PRUNE);
     }
 }
@@ -0,0 +1,235 @@
+use super::{Middleware, MiddlewareResponse};
+
+use crate::{
+    errors::{EventErrorKind, MiddlewareError},
+    event::FlowControl,
+    extractors::Locale,
+    fsm::{data::Data, Storage, UserData},
+    router::Request,
+};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+};
+use tracing::instrument;
+
+/// Locale chosen by the user, which overrides the language code of the user.
+/// It's stored in [`UserData`], so set it in handlers with [`UserData::set`] method.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LocaleOverride(pub Box<str>);
+
+impl LocaleOverride {
+    #[must_use]
+    pub fn new(val: impl Into<Box<str>>) -> Self {
+        Self(val.into())
+    }
+}
+
+impl Data for LocaleOverride {
+    const KEY: &'static str = "locale";
+}
+
+/// Middleware for resolving [`Locale`] of the user and adding it to the context with `locale` key,
+/// so it can be used as extractor and in [`LanguageCode`] filter.
+///
+/// The locale is resolved from [`LocaleOverride`] stored in [`UserData`] or from the language code of the user.
+/// If the locales supported by the bot are specified, the first supported one is used from:
+/// the locale, its fallback, the language of the locale without the region (`en` for `en-US`) and its fallback.
+/// If none of them is supported, the default locale is used.
+///
+/// [`FSMContext`] middleware must be registered before this middleware to use the overrides,
+/// otherwise only the language code of the user is used.
+///
+/// [`LanguageCode`]: crate::filters::LanguageCode
+/// [`FSMContext`]: super::FSMContext
+pub struct LocaleResolver<S> {
+    default: Box<str>,
+    locales: Box<[Box<str>]>,
+    fallbacks: Box<[(Box<str>, Box<str>)]>,
+    _storage: PhantomData<fn() -> S>,
+}
+
+impl<S> LocaleResolver<S> {
+    /// Creates a new [`LocaleResolver`] with the default locale,
+    /// which is used if the locale of the user is unknown or isn't supported
+    #[must_use]
+    pub fn new(default: impl Into<Box<str>>) -> Self {
+        Self {
+            default: default.into(),
+            locales: Box::new([]),
+            fallbacks: Box::new([]),
+            _storage: PhantomData,
+        }
+    }
+
+    /// Adds a locale supported by the bot.
+    /// If no locales are specified, all locales are supported.
+    #[must_use]
+    pub fn locale(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            locales: self
+                .locales
+                .into_vec()
+                .into_iter()
+                .chain(Some(val.into()))
+                .collect(),
+            ..self
+        }
+    }
+
+    /// Adds locales supported by the bot.
+    /// If no locales are specified, all locales are supported.
+    #[must_use]
+    pub fn locales<T, I>(self, val: I) -> Self
+    where
+        T: Into<Box<str>>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            locales: self
+                .locales
+                .into_vec()
+                .into_iter()
+                .chain(val.into_iter().map(Into::into))
+                .collect(),
+            ..self
+        }
+    }
+
+    /// Adds a fallback locale, which is used instead of unsupported `from` locale, for example, `ru` for `be`
+    #[must_use]
+    pub fn fallback(self, from: impl Into<Box<str>>, to: impl Into<Box<str>>) -> Self {
+        Self {
+            fallbacks: self
+                .fallbacks
+                .into_vec()
+                .into_iter()
+                .chain(Some((from.into(), to.into())))
+                .collect(),
+            ..self
+        }
+    }
+
+    fn is_supported(&self, locale: &str) -> bool {
+        self.locales.is_empty()
+            || self
+                .locales
+                .iter()
+                .any(|supported| supported.eq_ignore_ascii_case(locale))
+    }
+
+    fn get_fallback(&self, locale: &str) -> Option<&str> {
+        self.fallbacks
+            .iter()
+            .find(|(from, _)| from.eq_ignore_ascii_case(locale))
+            .map(|(_, to)| to.as_ref())
+    }
+
+    /// Resolves the locale supported by the bot for the locale of the user
+    #[must_use]
+    pub fn resolve(&self, locale: Option<&str>) -> Locale {
+        let Some(locale) = locale else {
+            return Locale::new(self.default.as_ref());
+        };
+        let language = locale.split(['-', '_']).next().unwrap_or(locale);
+
+        [
+            Some(locale),
+            self.get_fallback(locale),
+            Some(language),
+            self.get_fallback(language),
+        ]
+        .into_iter()
+        .flatten()
+        .find(|candidate| self.is_supported(candidate))
+        .map_or_else(|| Locale::new(self.default.as_ref()), Locale::new)
+    }
+}
+
+impl<S> Clone for LocaleResolver<S> {
+    fn clone(&self) -> Self {
+        Self {
+            default: self.default.clone(),
+            locales: self.locales.clone(),
+            fallbacks: self.fallbacks.clone(),
+            _storage: PhantomData,
+        }
+    }
+}
+
+impl<S> Debug for LocaleResolver<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocaleResolver")
+            .field("default", &self.default)
+            .field("locales", &self.locales)
+            .field("fallbacks", &self.fallbacks)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl<Client, S> Middleware<Client> for LocaleResolver<S>
+where
+    Client: Send + Sync + 'static,
+    S: Storage + Clone + Send + Sync + 'static,
+{
+    #[instrument(skip(self, request))]
+    async fn call(
+        &self,
+        request: Request<Client>,
+    ) -> Result<MiddlewareResponse<Client>, EventErrorKind> {
+        let user_data = request
+            .context
+            .get("fsm_user_data")
+            .and_then(|user_data| user_data.downcast_ref::<UserData<S>>().cloned());
+
+        let locale_override = match user_data {
+            Some(user_data) => user_data
+                .get::<LocaleOverride>()
+                .await
+                .map_err(|err| MiddlewareError::new(err.into()))?,
+            None => None,
+        };
+
+        let locale = match locale_override {
+            Some(LocaleOverride(locale)) => self.resolve(Some(&locale)),
+            None => self.resolve(
+                request
+                    .update
+                    .from()
+                    .and_then(|user| user.language_code.as_deref()),
+            ),
+        };
+
+        request.context.insert("locale", Box::new(locale));
+
+        Ok((request, FlowControl::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        let resolver = LocaleResolver::<()>::new("en")
+            .locales(["en", "ru", "uk", "pt-BR"])
+            .fallback("be", "ru");
+
+        assert_eq!(resolver.resolve(None), Locale::new("en"));
+        assert_eq!(resolver.resolve(Some("ru")), Locale::new("ru"));
+        assert_eq!(resolver.resolve(Some("pt-BR")), Locale::new("pt-BR"));
+        assert_eq!(resolver.resolve(Some("uk-UA")), Locale::new("uk"));
+        assert_eq!(resolver.resolve(Some("be")), Locale::new("ru"));
+        assert_eq!(resolver.resolve(Some("be-BY")), Locale::new("ru"));
+        assert_eq!(resolver.resolve(Some("de")), Locale::new("en"));
+
+        let resolver = LocaleResolver::<()>::new("en");
+
+        assert_eq!(resolver.resolve(Some("de-AT")), Locale::new("de-AT"));
+    }
+}
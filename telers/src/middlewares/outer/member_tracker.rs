@@ -0,0 +1,285 @@
+use super::{Middleware, MiddlewareResponse};
+
+use crate::{
+    errors::EventErrorKind,
+    event::FlowControl,
+    extractors::FromContext,
+    router::Request,
+    types::{ChatMember, ChatMemberUpdated, UpdateKind},
+};
+
+use async_trait::async_trait;
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+use tracing::instrument;
+
+/// Default maximum number of cached members per chat
+pub const DEFAULT_MEMBERS_PER_CHAT: usize = 1000;
+
+/// Cached statuses of the bot and the members of the chat
+#[derive(Debug, Default)]
+struct ChatMembers {
+    bot: Option<ChatMember>,
+    /// Members by user ids with the sequence number of the last update, which is used to evict the least recently seen member
+    members: HashMap<i64, (ChatMember, u64)>,
+    seq: u64,
+}
+
+impl ChatMembers {
+    fn insert(&mut self, member: ChatMember, capacity: usize) {
+        self.seq += 1;
+        self.members.insert(member.user().id, (member, self.seq));
+
+        if self.members.len() > capacity {
+            // Linear search is fine, because eviction happens only when the chat has many active members
+            if let Some(user_id) = self
+                .members
+                .iter()
+                .min_by_key(|(_, (_, seq))| *seq)
+                .map(|(user_id, _)| *user_id)
+            {
+                self.members.remove(&user_id);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Members {
+    /// Members of the chats by bot and chat ids
+    chats: RwLock<HashMap<(i64, i64), ChatMembers>>,
+    members_per_chat: usize,
+}
+
+/// Cache of the bot status and recently seen member statuses per chat of the bot, which is maintained by [`MemberTracker`].
+/// Use it in filters and handlers instead of `getChatMember` requests.
+///
+/// Statuses are updated by `my_chat_member` and `chat_member` updates, so they are available only after the first update of the member.
+/// `chat_member` updates are received only if the bot is an administrator of the chat and the update type is allowed explicitly.
+/// Results of own `getChatMember` requests can be cached with [`MemberCache::insert`].
+#[derive(Debug, Clone, FromContext)]
+#[context(
+    key = "member_cache",
+    description = "Cache of the chat members. This context is available only if `MemberTracker` middleware is used."
+)]
+pub struct MemberCache {
+    bot_id: i64,
+    members: Arc<Members>,
+}
+
+impl MemberCache {
+    /// Get the last known status of the bot in the chat
+    #[must_use]
+    pub fn bot_status(&self, chat_id: i64) -> Option<ChatMember> {
+        self.members
+            .chats
+            .read()
+            .unwrap()
+            .get(&(self.bot_id, chat_id))?
+            .bot
+            .clone()
+    }
+
+    /// Get the last known status of the member in the chat
+    #[must_use]
+    pub fn member(&self, chat_id: i64, user_id: i64) -> Option<ChatMember> {
+        self.members
+            .chats
+            .read()
+            .unwrap()
+            .get(&(self.bot_id, chat_id))?
+            .members
+            .get(&user_id)
+            .map(|(member, _)| member.clone())
+    }
+
+    /// Cache the status of the member in the chat, for example, result of `getChatMember` request
+    pub fn insert(&self, chat_id: i64, member: ChatMember) {
+        self.members
+            .chats
+            .write()
+            .unwrap()
+            .entry((self.bot_id, chat_id))
+            .or_default()
+            .insert(member, self.members.members_per_chat);
+    }
+
+    /// Remove the cached status of the member in the chat
+    /// # Returns
+    /// Removed status, if it's cached
+    pub fn remove(&self, chat_id: i64, user_id: i64) -> Option<ChatMember> {
+        self.members
+            .chats
+            .write()
+            .unwrap()
+            .get_mut(&(self.bot_id, chat_id))?
+            .members
+            .remove(&user_id)
+            .map(|(member, _)| member)
+    }
+
+    /// Remove the cached statuses of the bot and the members in the chat
+    pub fn clear_chat(&self, chat_id: i64) {
+        self.members
+            .chats
+            .write()
+            .unwrap()
+            .remove(&(self.bot_id, chat_id));
+    }
+
+    /// Apply `my_chat_member` or `chat_member` update to the cache
+    fn apply(&self, kind: &UpdateKind) {
+        match kind {
+            UpdateKind::MyChatMember(ChatMemberUpdated {
+                chat,
+                new_chat_member,
+                ..
+            }) => {
+                let mut chats = self.members.chats.write().unwrap();
+                let members = chats.entry((self.bot_id, chat.id())).or_default();
+
+                // Members of the chat can't be tracked after the bot leaves it, so cached statuses become stale
+                if matches!(new_chat_member, ChatMember::Left(_) | ChatMember::Banned(_)) {
+                    members.members.clear();
+                }
+                members.bot = Some(new_chat_member.clone());
+            }
+            UpdateKind::ChatMember(ChatMemberUpdated {
+                chat,
+                new_chat_member,
+                ..
+            }) => {
+                self.insert(chat.id(), new_chat_member.clone());
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Middleware for maintaining [`MemberCache`] by `my_chat_member` and `chat_member` updates.
+/// The cache of the bot of the current update is added to context with `member_cache` key, so it can be used as extractor.
+///
+/// Register the middleware for `update` observer to track all updates.
+/// Statuses are stored in memory and only [`DEFAULT_MEMBERS_PER_CHAT`] (by default) recently seen members are stored per chat.
+#[derive(Debug, Clone)]
+pub struct MemberTracker {
+    members: Arc<Members>,
+}
+
+impl MemberTracker {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_members_per_chat(DEFAULT_MEMBERS_PER_CHAT)
+    }
+
+    /// Create the middleware with the maximum number of cached members per chat
+    #[must_use]
+    pub fn with_members_per_chat(val: usize) -> Self {
+        Self {
+            members: Arc::new(Members {
+                chats: RwLock::default(),
+                members_per_chat: val,
+            }),
+        }
+    }
+
+    /// Get the cache of the bot, for example, to use it outside handlers
+    #[must_use]
+    pub fn cache(&self, bot_id: i64) -> MemberCache {
+        MemberCache {
+            bot_id,
+            members: Arc::clone(&self.members),
+        }
+    }
+}
+
+impl Default for MemberTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl<Client> Middleware<Client> for MemberTracker
+where
+    Client: Send + Sync + 'static,
+{
+    #[instrument(skip(self, request))]
+    async fn call(
+        &self,
+        request: Request<Client>,
+    ) -> Result<MiddlewareResponse<Client>, EventErrorKind> {
+        let cache = self.cache(request.bot.bot_id);
+        cache.apply(&request.update.kind);
+
+        request.context.insert("member_cache", Box::new(cache));
+
+        Ok((request, FlowControl::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Chat, ChatMemberLeft, ChatMemberMember, User};
+
+    fn member(user_id: i64) -> ChatMember {
+        ChatMember::Member(ChatMemberMember {
+            user: User {
+                id: user_id,
+                ..Default::default()
+            },
+        })
+    }
+
+    fn updated(bot_member: ChatMember) -> ChatMemberUpdated {
+        ChatMemberUpdated {
+            chat: Chat::default(),
+            from: User::default(),
+            date: 0,
+            old_chat_member: bot_member.clone(),
+            new_chat_member: bot_member,
+            invite_link: None,
+            via_chat_folder_invite_link: None,
+        }
+    }
+
+    #[test]
+    fn test_apply() {
+        let tracker = MemberTracker::with_members_per_chat(2);
+        let cache = tracker.cache(1);
+        let chat_id = Chat::default().id();
+
+        cache.apply(&UpdateKind::MyChatMember(updated(member(1))));
+        assert_eq!(cache.bot_status(chat_id), Some(member(1)));
+        // Caches of other bots are independent
+        assert_eq!(tracker.cache(2).bot_status(chat_id), None);
+
+        cache.apply(&UpdateKind::ChatMember(updated(member(10))));
+        cache.apply(&UpdateKind::ChatMember(updated(member(11))));
+        assert_eq!(cache.member(chat_id, 10), Some(member(10)));
+
+        // The least recently seen member is evicted
+        cache.insert(chat_id, member(12));
+        assert_eq!(cache.member(chat_id, 10), None);
+        assert_eq!(cache.member(chat_id, 11), Some(member(11)));
+        assert_eq!(cache.remove(chat_id, 11), Some(member(11)));
+        assert_eq!(cache.member(chat_id, 11), None);
+
+        // Members are cleared after the bot leaves the chat
+        let left = ChatMember::Left(ChatMemberLeft {
+            user: User {
+                id: 1,
+                ..Default::default()
+            },
+        });
+        cache.apply(&UpdateKind::MyChatMember(updated(left.clone())));
+        assert_eq!(cache.member(chat_id, 12), None);
+        assert_eq!(cache.bot_status(chat_id), Some(left));
+
+        cache.clear_chat(chat_id);
+        assert_eq!(cache.bot_status(chat_id), None);
+    }
+}
@@ -0,0 +1,224 @@
+use super::{Middleware, MiddlewareResponse};
+
+use crate::{
+    errors::EventErrorKind,
+    event::FlowControl,
+    router::Request,
+    types::{Poll, PollAnswer, UpdateKind},
+};
+
+use async_trait::async_trait;
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+use tracing::instrument;
+
+/// Aggregated results of the poll
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PollResults {
+    /// Last received state of the poll, if `poll` update or the sent poll is received
+    pub poll: Option<Poll>,
+    /// 0-based identifiers of answer options, chosen by the voters (users or chats, if the voter is anonymous)
+    pub answers: HashMap<i64, Box<[i64]>>,
+}
+
+impl PollResults {
+    /// Get 0-based identifiers of answer options, chosen by the voter
+    #[must_use]
+    pub fn answer(&self, voter_id: i64) -> Option<&[i64]> {
+        self.answers.get(&voter_id).map(AsRef::as_ref)
+    }
+
+    /// Count votes of each option by the received answers
+    #[must_use]
+    pub fn option_counts(&self) -> Vec<i64> {
+        let len = self
+            .answers
+            .values()
+            .flat_map(|option_ids| option_ids.iter())
+            .max()
+            .map_or(0, |max| usize::try_from(*max).map_or(0, |max| max + 1))
+            .max(self.poll.as_ref().map_or(0, |poll| poll.options().len()));
+
+        let mut counts = vec![0; len];
+        for option_id in self
+            .answers
+            .values()
+            .flat_map(|option_ids| option_ids.iter())
+        {
+            if let Ok(option_id) = usize::try_from(*option_id) {
+                counts[option_id] += 1;
+            }
+        }
+        counts
+    }
+
+    /// Get voters, which chose the correct option of the quiz
+    /// # Returns
+    /// `None` if the poll isn't a quiz or the correct option is unknown
+    #[must_use]
+    pub fn correct_voters(&self) -> Option<Vec<i64>> {
+        let correct_option_id = self.poll.as_ref()?.correct_option_id()?;
+
+        Some(
+            self.answers
+                .iter()
+                .filter(|(_, option_ids)| option_ids.contains(&correct_option_id))
+                .map(|(voter_id, _)| *voter_id)
+                .collect(),
+        )
+    }
+
+    fn apply_answer(&mut self, answer: &PollAnswer) {
+        let voter_id = match (&answer.user, &answer.voter_chat) {
+            (Some(user), _) => user.id,
+            (None, Some(chat)) => chat.id(),
+            (None, None) => return,
+        };
+
+        // Empty option ids mean that the voter retracted the vote
+        if answer.option_ids.is_empty() {
+            self.answers.remove(&voter_id);
+        } else {
+            self.answers.insert(voter_id, answer.option_ids.clone());
+        }
+    }
+}
+
+/// Middleware for aggregating `poll` and `poll_answer` updates per poll id into [`PollResults`],
+/// which can be retrieved with [`PollTracker::results`] at any time.
+/// Results of the poll of the current update are added to context with `poll_results` key.
+///
+/// Register the middleware for `update` observer to track all updates.
+/// Answers of the users are received only for non-anonymous polls, and only if `poll_answer` updates are allowed.
+/// Results are stored in memory until they are removed with [`PollTracker::remove`].
+#[derive(Debug, Default, Clone)]
+pub struct PollTracker {
+    polls: Arc<RwLock<HashMap<Box<str>, PollResults>>>,
+}
+
+impl PollTracker {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start tracking the poll, for example, the poll of the sent message,
+    /// so the results contain the poll before the first `poll` update
+    pub fn track(&self, poll: Poll) {
+        let poll_id = poll.id().into();
+
+        self.polls.write().unwrap().entry(poll_id).or_default().poll = Some(poll);
+    }
+
+    /// Get aggregated results of the poll
+    #[must_use]
+    pub fn results(&self, poll_id: &str) -> Option<PollResults> {
+        self.polls.read().unwrap().get(poll_id).cloned()
+    }
+
+    /// Stop tracking the poll
+    /// # Returns
+    /// Aggregated results of the poll, if it's tracked
+    pub fn remove(&self, poll_id: &str) -> Option<PollResults> {
+        self.polls.write().unwrap().remove(poll_id)
+    }
+
+    /// Apply the update to the results
+    /// # Returns
+    /// Results of the poll of the update, if the update is `poll` or `poll_answer`
+    fn apply(&self, kind: &UpdateKind) -> Option<PollResults> {
+        let mut polls = self.polls.write().unwrap();
+
+        match kind {
+            UpdateKind::Poll(poll) => {
+                let results = polls.entry(poll.id().into()).or_default();
+                results.poll = Some(poll.clone());
+
+                Some(results.clone())
+            }
+            UpdateKind::PollAnswer(answer) => {
+                let results = polls.entry(answer.poll_id.clone()).or_default();
+                results.apply_answer(answer);
+
+                Some(results.clone())
+            }
+            _ => None,
+        }
+    }
+}
+
+#[async_trait]
+impl<Client> Middleware<Client> for PollTracker
+where
+    Client: Send + Sync + 'static,
+{
+    #[instrument(skip(self, request))]
+    async fn call(
+        &self,
+        request: Request<Client>,
+    ) -> Result<MiddlewareResponse<Client>, EventErrorKind> {
+        if let Some(results) = self.apply(&request.update.kind) {
+            request.context.insert("poll_results", Box::new(results));
+        }
+
+        Ok((request, FlowControl::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{PollOption, PollQuiz, User};
+
+    fn answer(user_id: i64, option_ids: &[i64]) -> UpdateKind {
+        UpdateKind::PollAnswer(PollAnswer {
+            poll_id: "poll".into(),
+            user: Some(User {
+                id: user_id,
+                ..Default::default()
+            }),
+            option_ids: option_ids.into(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_apply() {
+        let tracker = PollTracker::new();
+        tracker.track(Poll::Quiz(PollQuiz {
+            id: "poll".into(),
+            options: vec![
+                PollOption {
+                    text: "option".into(),
+                    voter_count: 0,
+                };
+                3
+            ]
+            .into(),
+            correct_option_id: Some(1),
+            ..Default::default()
+        }));
+
+        tracker.apply(&answer(1, &[1]));
+        tracker.apply(&answer(2, &[0]));
+        let results = tracker.apply(&answer(3, &[1])).unwrap();
+
+        assert_eq!(results.option_counts(), [1, 2, 0]);
+        assert_eq!(results.answer(2), Some(&[0][..]));
+
+        let mut correct_voters = results.correct_voters().unwrap();
+        correct_voters.sort_unstable();
+        assert_eq!(correct_voters, [1, 3]);
+
+        // Retracted vote is removed
+        let results = tracker.apply(&answer(3, &[])).unwrap();
+        assert_eq!(results.option_counts(), [1, 1, 0]);
+        assert_eq!(tracker.results("poll"), Some(results));
+
+        assert!(tracker.apply(&UpdateKind::default()).is_none());
+        assert!(tracker.remove("poll").is_some());
+        assert!(tracker.results("poll").is_none());
+    }
+}
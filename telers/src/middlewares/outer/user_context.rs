@@ -1,6 +1,6 @@
 use super::{Middleware, MiddlewareResponse};
 
-use crate::{errors::EventErrorKind, event::EventReturn, router::Request};
+use crate::{errors::EventErrorKind, event::FlowControl, router::Request};
 
 use async_trait::async_trait;
 use tracing::instrument;
@@ -42,7 +42,7 @@ where
             context.insert("event_message_thread_id", Box::new(message_thread_id));
         }
 
-        Ok((request, EventReturn::default()))
+        Ok((request, FlowControl::default()))
     }
 }
 
@@ -92,7 +92,7 @@ mod tests {
                 .downcast_ref::<i64>()
                 .unwrap();
 
-            Ok(EventReturn::default())
+            Ok(FlowControl::default())
         });
 
         let router_service = router.to_service_provider_default().unwrap();
@@ -133,7 +133,7 @@ mod tests {
                 .downcast_ref::<i64>()
                 .unwrap();
 
-            Ok(EventReturn::default())
+            Ok(FlowControl::default())
         });
 
         let router_service = router.to_service_provider_default().unwrap();
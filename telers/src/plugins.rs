@@ -0,0 +1,15 @@
+//! This module contains ready-made plugins, which implement [`Plugin`] trait
+//! and can be installed to the router with [`Router::install`] method:
+//! - [`admin`] module with runtime management commands for bot owners (with `admin-plugin` feature)
+//!
+//! Check [`plugin module`] documentation for more information about plugins.
+//!
+//! [`Plugin`]: crate::router::Plugin
+//! [`Router::install`]: crate::router::Router#method.install
+//! [`plugin module`]: crate::router::plugin
+
+#[cfg(feature = "admin-plugin")]
+pub mod admin;
+
+#[cfg(feature = "admin-plugin")]
+pub use admin::Admin;
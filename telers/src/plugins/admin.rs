@@ -0,0 +1,394 @@
+//! This module contains [`Admin`] plugin, which provides runtime management commands for bot owners:
+//! - `/stats` - uptime, number of fed updates, health status of the polling processes and number of pending outgoing requests
+//! - `/broadcast <text>` - push the message for each recipient to the [`Outbox`]
+//! - `/toggle_handler <name>` - enable or disable the named handler (see [`ServiceHandle`])
+//! - `/export_state <chat_id> [user_id]` - export FSM states and data of the chat and the user in JSON format
+//!
+//! Commands are available only for owners, updates of other users are skipped and propagated to the next routers.
+//! `/stats` and `/toggle_handler` commands use [`Introspection`], which is put to the context by the dispatcher,
+//! so they don't work if updates are propagated to the router manually.
+//!
+//! Broadcast messages are persisted in the outbox and sent by [`Outbox::run`] or [`Outbox::flush`] methods,
+//! so the outbox should be run on startup (see [`outbox module`] for more information).
+//! Recipients of the broadcast are resolved by the function, which is set by [`Admin::recipients`] method,
+//! because the library doesn't track users and chats of the bot.
+//!
+//! ```ignore
+//! let storage = MemoryStorage::new();
+//! let outbox = Outbox::new(storage.clone());
+//!
+//! let mut router = Router::new("main");
+//! router.install(
+//!     Admin::new([OWNER_ID], storage, outbox.clone())
+//!         .recipients(|bot_id| async move { subscribers(bot_id).await }),
+//! );
+//! ```
+//!
+//! [`Outbox`]: crate::client::Outbox
+//! [`Outbox::run`]: crate::client::Outbox#method.run
+//! [`Outbox::flush`]: crate::client::Outbox#method.flush
+//! [`outbox module`]: crate::client::outbox
+//! [`ServiceHandle`]: crate::router::ServiceHandle
+//! [`Introspection`]: crate::dispatcher::Introspection
+
+use crate::{
+    client::{Outbox, Session},
+    context::Context,
+    dispatcher::Introspection,
+    errors::HandlerError,
+    event::{telegram::HandlerResult, FlowControl},
+    filters::{Command, CommandObject, Filter},
+    fsm::{storage::Error as StorageError, Storage, StorageKey},
+    methods::SendMessage,
+    router::{Plugin, PluginConfig, Router, ServiceHandle},
+    types::{Message, Update},
+    Bot,
+};
+
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use serde_json::{json, Value};
+use std::{
+    collections::HashMap,
+    fmt::{self, Debug, Formatter, Write as _},
+    future::Future,
+    sync::Arc,
+};
+
+type Recipients = Arc<dyn Fn(i64) -> BoxFuture<'static, Box<[i64]>> + Send + Sync>;
+
+/// Filter for checking if the user of the update is one of the owners
+#[derive(Debug, Clone)]
+pub struct IsOwner {
+    owner_ids: Arc<[i64]>,
+}
+
+impl IsOwner {
+    #[must_use]
+    pub fn new(owner_ids: impl IntoIterator<Item = i64>) -> Self {
+        Self {
+            owner_ids: owner_ids.into_iter().collect(),
+        }
+    }
+
+    #[must_use]
+    pub fn validate(&self, update: &Update) -> bool {
+        update
+            .from_id()
+            .map_or(false, |user_id| self.owner_ids.contains(&user_id))
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for IsOwner {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        self.validate(update)
+    }
+}
+
+/// Plugin with runtime management commands for bot owners.
+/// Check the [module](self) documentation for more information.
+pub struct Admin<S> {
+    owners: IsOwner,
+    storage: S,
+    outbox: Outbox<S>,
+    recipients: Option<Recipients>,
+}
+
+impl<S> Admin<S> {
+    /// # Arguments
+    /// * `owner_ids` - IDs of the users, who can use the commands
+    /// * `storage` - FSM storage, which states and data are exported by `/export_state` command
+    /// * `outbox` - Outbox, which broadcast messages are pushed to
+    #[must_use]
+    pub fn new(owner_ids: impl IntoIterator<Item = i64>, storage: S, outbox: Outbox<S>) -> Self {
+        Self {
+            owners: IsOwner::new(owner_ids),
+            storage,
+            outbox,
+            recipients: None,
+        }
+    }
+
+    /// Set the function, which resolves chat IDs of the broadcast recipients by the bot ID.
+    /// If it isn't set, `/broadcast` command responds that there are no recipients.
+    #[must_use]
+    pub fn recipients<F, Fut>(self, val: F) -> Self
+    where
+        F: Fn(i64) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Box<[i64]>> + Send + 'static,
+    {
+        Self {
+            recipients: Some(Arc::new(move |bot_id| Box::pin(val(bot_id)))),
+            ..self
+        }
+    }
+}
+
+impl<S> Debug for Admin<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Admin")
+            .field("owners", &self.owners)
+            .field("has_recipients", &self.recipients.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Formats the dispatcher statistics
+fn stats_text(introspection: &Introspection, pending: Option<usize>) -> String {
+    let mut text = format!(
+        "Uptime: {}s\nUpdates: {}\n",
+        introspection.uptime().as_secs(),
+        introspection.updates()
+    );
+
+    for bot in &*introspection.health().bots {
+        let _ = writeln!(
+            text,
+            "Bot {}: polling: {}, queue: {}, dropped: {}, last error: {}",
+            bot.bot_id,
+            bot.is_polling,
+            bot.queue_depth,
+            bot.dropped_updates,
+            bot.last_error.as_deref().unwrap_or("-"),
+        );
+    }
+
+    if let Some(pending) = pending {
+        let _ = write!(text, "Pending outgoing requests: {pending}");
+    }
+
+    text
+}
+
+/// Toggles the named handler and formats the result
+fn toggle_handler_text(handle: &ServiceHandle, name: &str) -> String {
+    match handle.is_handler_enabled(name) {
+        Some(enabled) => {
+            handle.set_handler_enabled(name, !enabled);
+
+            format!(
+                "Handler `{name}` is {}",
+                if enabled { "disabled" } else { "enabled" }
+            )
+        }
+        None => format!(
+            "Handler `{name}` isn't found. Available handlers: {}",
+            handle.handler_names().collect::<Vec<_>>().join(", ")
+        ),
+    }
+}
+
+/// Exports FSM states and data of the key in JSON format
+async fn export_state<S>(storage: &S, key: &StorageKey) -> Result<String, HandlerError>
+where
+    S: Storage,
+{
+    let states = storage
+        .get_states(key)
+        .await
+        .map_err(Into::<StorageError>::into)?;
+    let state = storage
+        .get_state(key)
+        .await
+        .map_err(Into::<StorageError>::into)?;
+    let data: HashMap<Box<str>, Value> = storage
+        .get_data(key)
+        .await
+        .map_err(Into::<StorageError>::into)?;
+
+    serde_json::to_string_pretty(&json!({
+        "state": state,
+        "states": states,
+        "data": data,
+    }))
+    .map_err(HandlerError::new)
+}
+
+async fn reply<Client>(
+    bot: &Bot<Client>,
+    message: &Message,
+    text: impl Into<String>,
+) -> HandlerResult
+where
+    Client: Session,
+{
+    bot.send(SendMessage::new(message.chat().id(), text))
+        .await?;
+
+    Ok(FlowControl::Continue)
+}
+
+impl<Client, S> Plugin<Client> for Admin<S>
+where
+    Client: Session + 'static,
+    S: Storage + Send + Sync + 'static,
+{
+    fn name(&self) -> &'static str {
+        "admin"
+    }
+
+    fn setup(&self, router: &mut Router<Client>, _config: &PluginConfig) {
+        router.filter(self.owners.clone());
+
+        let outbox = self.outbox.clone();
+        router
+            .message
+            .register(
+                move |bot: Arc<Bot<Client>>, message: Message, introspection: Introspection| {
+                    let outbox = outbox.clone();
+
+                    async move {
+                        let pending = outbox.pending(bot.bot_id).await.ok();
+
+                        reply(&bot, &message, stats_text(&introspection, pending)).await
+                    }
+                },
+            )
+            .name("admin_stats")
+            .filter(Command::one("stats"));
+
+        let outbox = self.outbox.clone();
+        let recipients = self.recipients.clone();
+        router
+            .message
+            .register(
+                move |bot: Arc<Bot<Client>>, message: Message, command: CommandObject| {
+                    let outbox = outbox.clone();
+                    let recipients = recipients.clone();
+
+                    async move {
+                        let text = command.args.join(" ");
+                        if text.is_empty() {
+                            return reply(&bot, &message, "Usage: /broadcast <text>").await;
+                        }
+
+                        let chat_ids = match recipients {
+                            Some(recipients) => recipients(bot.bot_id).await,
+                            None => Box::new([]),
+                        };
+                        if chat_ids.is_empty() {
+                            return reply(&bot, &message, "There are no recipients").await;
+                        }
+
+                        for chat_id in &*chat_ids {
+                            outbox
+                                .push(&bot, &SendMessage::new(*chat_id, text.as_str()))
+                                .await
+                                .map_err(HandlerError::new)?;
+                        }
+
+                        reply(
+                            &bot,
+                            &message,
+                            format!("Broadcast is queued for {} chats", chat_ids.len()),
+                        )
+                        .await
+                    }
+                },
+            )
+            .name("admin_broadcast")
+            .filter(Command::one("broadcast"));
+
+        router
+            .message
+            .register(
+                |bot: Arc<Bot<Client>>,
+                 message: Message,
+                 command: CommandObject,
+                 introspection: Introspection| async move {
+                    let text = match command.args.first() {
+                        Some(name) => toggle_handler_text(introspection.handle(), name),
+                        None => "Usage: /toggle_handler <name>".to_owned(),
+                    };
+
+                    reply(&bot, &message, text).await
+                },
+            )
+            .name("admin_toggle_handler")
+            .filter(Command::one("toggle_handler"));
+
+        let storage = self.storage.clone();
+        router
+            .message
+            .register(
+                move |bot: Arc<Bot<Client>>, message: Message, command: CommandObject| {
+                    let storage = storage.clone();
+
+                    async move {
+                        let mut args = command.args.iter().map(|arg| arg.parse::<i64>());
+                        let (Some(Ok(chat_id)), user_id) = (args.next(), args.next()) else {
+                            return reply(
+                                &bot,
+                                &message,
+                                "Usage: /export_state <chat_id> [user_id]",
+                            )
+                            .await;
+                        };
+                        let user_id = match user_id {
+                            Some(Ok(user_id)) => user_id,
+                            Some(Err(_)) => {
+                                return reply(&bot, &message, "User ID must be a number").await
+                            }
+                            None => chat_id,
+                        };
+
+                        let key = StorageKey::new(bot.bot_id, chat_id, user_id, None);
+                        let text = export_state(&storage, &key).await?;
+
+                        reply(&bot, &message, text).await
+                    }
+                },
+            )
+            .name("admin_export_state")
+            .filter(Command::one("export_state"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{MessageText, UpdateKind, User};
+
+    use std::sync::atomic::AtomicBool;
+
+    #[test]
+    fn test_is_owner() {
+        let filter = IsOwner::new([1]);
+
+        let update = |user_id| Update {
+            id: 0,
+            kind: UpdateKind::Message(Message::Text(Box::new(MessageText {
+                from: Some(User {
+                    id: user_id,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }))),
+        };
+
+        assert!(filter.validate(&update(1)));
+        assert!(!filter.validate(&update(2)));
+        assert!(!filter.validate(&Update::default()));
+    }
+
+    #[test]
+    fn test_toggle_handler_text() {
+        let enabled = Arc::new(AtomicBool::new(true));
+        let handle = ServiceHandle::new([("start", Arc::clone(&enabled))]);
+
+        assert_eq!(
+            toggle_handler_text(&handle, "start"),
+            "Handler `start` is disabled"
+        );
+        assert_eq!(handle.is_handler_enabled("start"), Some(false));
+        assert_eq!(
+            toggle_handler_text(&handle, "start"),
+            "Handler `start` is enabled"
+        );
+        assert_eq!(
+            toggle_handler_text(&handle, "help"),
+            "Handler `help` isn't found. Available handlers: start"
+        );
+    }
+}
@@ -28,14 +28,15 @@
 //! * Telegram observer:
 //! [`Telegram observer`] is used to handle telegram events like messages, callback queries, polls and all other event types.
 //! You can register a handler with any arguments that implement [`FromEventAndContext`] trait, see [`extractors module`] for more details.
-//! Return type of the handler is [`Result<EventReturn, HandlerError>`],
-//! where [`EventReturn`] is a special enum that can be used to control the propagation of the event,
-//! see [`EventReturn`] for more details.
-//! When observer is trigger, it calls outer middlewares and checks all handlers in order of registration.
+//! Return type of the handler is [`Result<FlowControl, HandlerError>`],
+//! where [`FlowControl`] is a special enum that can be used to control the propagation of the event,
+//! see [`FlowControl`] for more details.
+//! When observer is trigger, it calls outer middlewares and checks all handlers in order of their priorities
+//! (see [`HandlerObject::priority`]), handlers with the same priority are checked in order of registration.
 //! It calls all filters for each handler and skips the handler if one of them returns `false`.
 //! If the handler is pass the filters, observer calls inner middlewares and the handler itself (in the middleware).
 //! By default, the first handler that pass the filters stop the propagation of the event, so other handlers aren't calls.
-//! (You can change this behaviour by specify another variant of [`EventReturn`]).
+//! (You can change this behaviour by specify another variant of [`FlowControl`]).
 //!
 //! Registration of the handlers looks like this:
 //! ```ignore
@@ -63,55 +64,74 @@
 //! with [`LoggingMiddleware`] to log all incoming updates and [`UserContextMiddleware`] to set up user context.
 //! All config middlewares are registered in the order of registration and before other middlewares.
 //!
+//! In multi-bot setups, routers can be bound to specific bots with [`Router::include_with`] method and [`ForBot`] filter.
+//!
+//! Constant data can be attached to the router with [`Router::context_data`] method,
+//! so it's put to the context for every update passing through the router and its sub routers,
+//! for example, to mark the feature of the subtree for logging or metrics.
+//!
+//! Reusable features (moderation, captcha, stats, etc.) can be distributed as plugins, which implement [`Plugin`] trait
+//! and are installed to the router with [`Router::install`] method, see [`plugin module`] for more details.
+//!
+//! You can get a tree of routers, observers, handlers and their filters with [`Router::describe`] method,
+//! which is useful for debugging, documentation generation and admin commands that list available handlers.
+//! Give handlers names with [`HandlerObject::name`] method to distinguish them in the description.
+//! Named handlers can be enabled and disabled at runtime without rebuilding the service provider with [`ServiceHandle`],
+//! which is returned by [`PropagateEvent::handle`] method.
+//!
 //! You can propagate event with calls [`PropagateEvent::propagate_event`] or [`PropagateEvent::propagate_update_event`],
 //! [`PropagateEvent::emit_startup`], [`PropagateEvent::emit_shutdown`] methods in [`Router`],
 //! but it's better to use [`Dispatcher`] that does it for you.
 //!
 //! Order of event propagation when propagate event is called for router:
+//! 0) Call filters of the router (see [`Router::filter`] and [`Router::include_with`]);
+//! 0.1) If one of the router filters returns `false`, then skip the router with its sub routers and go to the 10 step;
 //! 1) Call outer middlewares of update observer in order of registration;
-//! 1.1) If middleware returns [`EventReturn::Finish`], then update [`Request`] because the middleware could have changed it and go to the 1 step;
-//! 1.2) If middleware returns [`EventReturn::Skip`], then skip this middleware and go to the 1 step;
-//! 1.3) If middleware returns [`EventReturn::Cancel`], then cancel propagation of outer middlewares of update observer and go to the 2 step;
-//! 1.4) If all middlewares by step 1.1 are passed or skipped on 1.2 or propagation cancelled by step 1.3, then go to the 2 step;
-//! 2) Call filters of handlers of update observer in order of registration;
+//! 1.1) If middleware returns [`FlowControl::Continue`], then update [`Request`] because the middleware could have changed it and go to the 1 step;
+//! 1.2) If middleware returns [`FlowControl::SkipHandler`], then skip this middleware and go to the 1 step;
+//! 1.3) If middleware returns [`FlowControl::StopObserver`], then skip update observer and go to the 5 step;
+//! 1.4) If middleware returns [`FlowControl::StopRouter`] or [`FlowControl::StopPropagation`], then go to the 10 step;
+//! 1.5) If all middlewares are passed on 1.1 or skipped on 1.2, then go to the 2 step;
+//! 2) Call filters of handlers of update observer in order of priority;
 //! 2.1) If one of the handler filters returns `false`, then skip the handler and go back to the 2 step;
 //! 2.2) If any handler filter returns `true`, then go to the 3 step;
 //! 2.3) If all handlers are skipped on 2.1, then go to the 4 step;
 //! 3) Call inner middlewares of update observer and the handler itself (handler is called in the middleware);
-//! 3.1) If the handler or middleware returns [`EventReturn::Skip`], then we should skip it and go to the 3 step;
-//! 3.2) If the handler or middleware returns [`EventReturn::Cancel`], then we should stop propagation of innder middlewares of update observer
+//! 3.1) If the handler or middleware returns [`FlowControl::SkipHandler`], then we should skip it and go to the 2 step;
+//! 3.2) If the handler or middleware returns [`FlowControl::StopObserver`], then we should stop propagation of update observer
 //! and go to the 4 step;
-//! 3.3) If the handler or middleware returns [`EventReturn::Finish`], then we should stop propagation and return a response and go to the 4 step;
-//! 3.4) If the handler of middleware returns error, then we should stop propagation and return a response
-//! because the error is the correct result from the point of view of observer and go to the 4 step;
-//! 3.5) If all handlers or middlewares are skipped on 3.1, then go to the 4 step;
+//! 3.3) If the handler or middleware returns [`FlowControl::Continue`] or error, then we should stop propagation and return a response
+//! (the error is the correct result from the point of view of observer) and go to the 10 step;
+//! 3.4) If the handler or middleware returns [`FlowControl::StopRouter`] or [`FlowControl::StopPropagation`], then go to the 10 step;
 //! 4) Stop propagation of update observer;
 //! 5) Check which observer respond to the current [`UpdateType`];
 //! 6) Call outer middlewares of the observer in order of registration;
-//! 6.1) If middleware returns [`EventReturn::Finish`], then update [`Request`] because the middleware could have changed it and go to the 6 step;
-//! 6.2) If middleware returns [`EventReturn::Skip`], then skip this middleware and go to the 6 step;
-//! 6.3) If middleware returns [`EventReturn::Cancel`], then cancel event propagation and go to the 10 step;
-//! 6.4) If all middlewares by step 1.1 are passed or skipped on 1.2, then go to the 7 step;
-//! 7) Call filters of handlers of the observer in order of registration;
+//! 6.1) If middleware returns [`FlowControl::Continue`], then update [`Request`] because the middleware could have changed it and go to the 6 step;
+//! 6.2) If middleware returns [`FlowControl::SkipHandler`], then skip this middleware and go to the 6 step;
+//! 6.3) If middleware returns [`FlowControl::StopObserver`], then skip the observer and go to the 9 step;
+//! 6.4) If middleware returns [`FlowControl::StopRouter`] or [`FlowControl::StopPropagation`], then go to the 10 step;
+//! 6.5) If all middlewares are passed on 6.1 or skipped on 6.2, then go to the 7 step;
+//! 7) Call filters of handlers of the observer in order of priority;
 //! 7.1) If one of the handler filters returns `false`, then skip the handler and go back to the 7 step;
 //! 7.2) If any handler filter returns `true`, then go to the 8 step;
-//! 7.3) If all handlers are skipped on 7.1, then cancel propagation of the observer and go to the 9 step;
+//! 7.3) If all handlers are skipped on 7.1, then go to the 9 step;
 //! 8) Call inner middlewares of the observer and the handler itself (handler is called in the middleware);
-//! 8.1) If the handler or middleware returns [`EventReturn::Skip`], then we should skip it and go to the 8 step;
-//! 8.2) If the handler or middleware returns [`EventReturn::Cancel`], then we should stop propagation of the observer
-//! and go to the 10 step;
-//! 8.3) If the handler or middleware returns [`EventReturn::Finish`], then we should stop propagation and return a response
-//! and go to the 10 step;
-//! 8.4) If the handler of middleware returns error, then we should stop propagation and return a response
-//! because the error is the correct result from the point of view of observer and go to the 10 step;
-//! 8.5) If all handlers or middlewares are skipped on 8.1, then go to the 9 step;
+//! 8.1) If the handler or middleware returns [`FlowControl::SkipHandler`], then we should skip it and go to the 7 step;
+//! 8.2) If the handler or middleware returns [`FlowControl::StopObserver`], then we should stop propagation of the observer
+//! and go to the 9 step;
+//! 8.3) If the handler or middleware returns [`FlowControl::Continue`] or error, then we should stop propagation and return a response
+//! (the error is the correct result from the point of view of observer) and go to the 10 step;
+//! 8.4) If the handler or middleware returns [`FlowControl::StopRouter`] or [`FlowControl::StopPropagation`], then go to the 10 step;
 //! 9) Check to which router from current router's sub routers propagate event next;
 //! 9.1) If there is no sub routers, then go to the 10 step;
-//! 9.2) If there is sub routers, then go to the 1 step for the first sub router;
-//! 9.2.1) If the propagate event [`PropagateEventResult::Unhandled`] by the sub router's observer, then go to the 9 step;
-//! 9.2.2) If the propagate event [`PropagateEventResult::Handled`] by the sub router's observer, then go to the 10 step;
-//! 9.2.3) If the propagate event [`PropagateEventResult::Rejected`], then return an unhandled response and go to the 10 step;
-//! 10) Finish event propagation.
+//! 9.2) If there is sub routers, then go to the 0 step for the first sub router;
+//! 9.2.1) If the propagate event [`PropagateEventResult::Unhandled`] by the sub router, then go to the 9 step for the next sub router;
+//! 9.2.2) If the propagate event [`PropagateEventResult::Handled`] by the sub router, then go to the 10 step;
+//! 9.2.3) If the propagate event [`PropagateEventResult::Rejected`] by the sub router, then go to the 10 step;
+//! 10) Finish event propagation of the router:
+//! the response is [`PropagateEventResult::Unhandled`] if the router is stopped by [`FlowControl::StopRouter`],
+//! so the parent router propagates event to the next sub router,
+//! and [`PropagateEventResult::Rejected`] if it's stopped by [`FlowControl::StopPropagation`], so the parent routers stop propagation.
 //!
 //! [`Simple observer`]: SimpleObserver
 //! [`Telegram observer`]: TelegramObserver
@@ -119,28 +139,56 @@
 //! [`FromEventAndContext`]: crate::extractors::FromEventAndContext
 //! [`extractors module`]: crate::extractors
 //! [`Router::include_router`]: Router#method.include_router
+//! [`Router::filter`]: Router#method.filter
+//! [`Router::include_with`]: Router#method.include_with
+//! [`Router::context_data`]: Router#method.context_data
+//! [`ForBot`]: crate::filters::ForBot
+//! [`Router::describe`]: Router#method.describe
+//! [`Router::install`]: Router#method.install
+//! [`plugin module`]: plugin
+//! [`HandlerObject::name`]: crate::event::telegram::handler::HandlerObject#method.name
+//! [`HandlerObject::priority`]: crate::event::telegram::handler::HandlerObject#method.priority
+
+mod context_data;
+pub mod description;
+pub mod handle;
+pub mod plugin;
+pub mod routing_report;
+pub mod timings;
+pub mod validation;
+
+pub use description::{HandlerDescription, ObserverDescription, RouterDescription};
+pub use handle::ServiceHandle;
+pub use plugin::{Plugin, PluginConfig};
+pub use routing_report::{RoutingReport, RoutingStep};
+pub use timings::{Stage, Timings};
+
+use context_data::ContextData;
 
 use crate::{
-    client::Reqwest,
+    client::DefaultSession,
     enums::{SimpleObserverName, TelegramObserverName, UpdateType},
-    errors::EventErrorKind,
+    errors::{ConfigError, EventErrorKind},
     event::{
-        bases::{EventReturn, PropagateEventResult},
+        bases::{FlowControl, PropagateEventResult},
         service::{ServiceProvider, ToServiceProvider},
         simple::{
             observer::Service as SimpleObserverService, HandlerResult as SimpleHandlerResult,
             Observer as SimpleObserver,
         },
         telegram::{
+            handler::Response as TelegramHandlerResponse,
             observer::{Request as TelegramObserverRequest, Service as TelegramObserverService},
             Observer as TelegramObserver,
         },
     },
+    filters::Filter,
     middlewares::{
         inner::Logging as LoggingMiddleware, outer::UserContext as UserContextMiddleware,
         InnerMiddleware, OuterMiddleware,
     },
-    types::Update,
+    types::{BotCommand, BotCommandScope, Update},
+    utils::commands::BotCommands,
     Bot, Context,
 };
 
@@ -149,11 +197,12 @@ use std::{
     collections::HashSet,
     fmt::{self, Debug, Formatter},
     iter::once,
-    sync::Arc,
+    ops::ControlFlow,
+    sync::{atomic::AtomicBool, Arc},
 };
 use tracing::{event, instrument, Level};
 
-pub struct Request<Client = Reqwest> {
+pub struct Request<Client = DefaultSession> {
     pub bot: Arc<Bot<Client>>,
     pub update: Arc<Update>,
     pub context: Arc<Context>,
@@ -267,6 +316,34 @@ pub trait PropagateEvent<Client>: Send + Sync {
     /// # Errors
     /// If any shutdown observer returns error
     async fn emit_shutdown(&self) -> SimpleHandlerResult;
+
+    /// Gets bot commands, which should be registered in the bot command menu
+    /// # Notes
+    /// By default, returns empty commands
+    fn bot_commands(&self) -> BotCommands {
+        BotCommands::default()
+    }
+
+    /// Gets handle to enable and disable handlers at runtime
+    /// # Notes
+    /// By default, returns handle without handlers
+    fn handle(&self) -> ServiceHandle {
+        ServiceHandle::default()
+    }
+
+    /// Gets update types, which are handled by the service, to check allowed updates of the dispatcher
+    /// # Notes
+    /// By default, returns `None`, so used update types are unknown and allowed updates aren't checked
+    fn used_update_types(&self) -> Option<&HashSet<UpdateType>> {
+        None
+    }
+
+    /// Gets errors of the service configuration, which are found when the service is created
+    /// # Notes
+    /// By default, returns empty errors, so the configuration isn't validated
+    fn config_errors(&self) -> &[ConfigError] {
+        &[]
+    }
 }
 
 #[async_trait]
@@ -302,6 +379,22 @@ where
     async fn emit_shutdown(&self) -> SimpleHandlerResult {
         P::emit_shutdown(self).await
     }
+
+    fn bot_commands(&self) -> BotCommands {
+        P::bot_commands(self)
+    }
+
+    fn handle(&self) -> ServiceHandle {
+        P::handle(self)
+    }
+
+    fn used_update_types(&self) -> Option<&HashSet<UpdateType>> {
+        P::used_update_types(self)
+    }
+
+    fn config_errors(&self) -> &[ConfigError] {
+        P::config_errors(self)
+    }
 }
 
 /// Router combines all event observers.
@@ -337,14 +430,15 @@ where
 /// Telegram observer is used to handle telegram events like messages, callback queries, polls and all other event types. \
 /// You can register a handler with any arguments that implement [`crate::extractors::FromEventAndContext`] trait,
 /// see [`crate::extractors`] for more details. \
-/// Return type of the handler is `Result<EventReturn, HandlerError>`,
-/// where [`EventReturn`] is a special enum that can be used to control the propagation of the event,
-/// see [`EventReturn`] for more details. \
-/// When observer is trigger, it calls outer middlewares and checks all handlers in order of registration.
+/// Return type of the handler is `Result<FlowControl, HandlerError>`,
+/// where [`FlowControl`] is a special enum that can be used to control the propagation of the event,
+/// see [`FlowControl`] for more details. \
+/// When observer is trigger, it calls outer middlewares and checks all handlers in order of their priorities
+/// (see [`crate::event::telegram::HandlerObject::priority`]).
 /// It calls all filters for each handler and skips the handler if one of them returns `false`.
 /// If the handler is pass the filters, observer calls inner middlewares and the handler itself (in the middleware).
 /// By default, the first handler that pass the filters stop the propagation of the event, so other handlers aren't calls.
-/// (You can change this behaviour by specify another variant of [`EventReturn`]).
+/// (You can change this behaviour by specify another variant of [`FlowControl`]).
 ///
 /// Registration of the handlers looks like this:
 /// ```ignore
@@ -363,6 +457,8 @@ where
 pub struct Router<Client> {
     router_name: &'static str,
     sub_routers: Vec<Router<Client>>,
+    filters: Vec<Arc<dyn Filter<Client>>>,
+    context_data: ContextData,
 
     pub message: TelegramObserver<Client>,
     pub edited_message: TelegramObserver<Client>,
@@ -404,6 +500,8 @@ where
         Self {
             router_name,
             sub_routers: vec![],
+            filters: vec![],
+            context_data: ContextData::default(),
             message: TelegramObserver::new(TelegramObserverName::Message),
             edited_message: TelegramObserver::new(TelegramObserverName::EditedMessage),
             channel_post: TelegramObserver::new(TelegramObserverName::ChannelPost),
@@ -448,6 +546,80 @@ where
     pub fn include(&mut self, router: impl Into<Router<Client>>) -> &mut Self {
         self.include_router(router)
     }
+
+    /// Include a router to the current router as sub router, which is gated by the filter,
+    /// so the whole subtree is skipped if the filter doesn't pass.
+    /// # Notes
+    /// Filter is added to the router filters (see [`Router::filter`]).
+    /// Check [`Router::include_router`] for more information.
+    pub fn include_with<T>(&mut self, router: impl Into<Router<Client>>, filter: T) -> &mut Self
+    where
+        T: Filter<Client> + 'static,
+    {
+        let mut router = router.into();
+        router.filter(filter);
+
+        self.include_router(router)
+    }
+
+    /// Install a plugin to the router with default config.
+    /// Check [`Router::install_with`] for more information.
+    pub fn install(&mut self, plugin: impl Plugin<Client>) -> &mut Self {
+        self.install_with(plugin, PluginConfig::default())
+    }
+
+    /// Install a plugin to the router with the config.
+    /// The plugin is set up in its own router named by [`Plugin::name`], which is included to the current router as sub router.
+    /// # Notes
+    /// Check [`Router::include_router`] for more information about sub routers.
+    pub fn install_with(&mut self, plugin: impl Plugin<Client>, config: PluginConfig) -> &mut Self {
+        let mut router = Router::new(plugin.name());
+        plugin.setup(&mut router, &config);
+
+        event!(Level::DEBUG, plugin = plugin.name(), "Plugin installed");
+
+        self.include_router(router)
+    }
+
+    /// Register filter for the router.
+    /// Filters are checked before any observer of the router, so if one of them doesn't pass,
+    /// the router and its sub routers are skipped, and propagation is continued in the next routers.
+    /// # Notes
+    /// Filters aren't checked for startup and shutdown events
+    pub fn filter<T>(&mut self, val: T) -> &mut Self
+    where
+        T: Filter<Client> + 'static,
+    {
+        self.filters.push(Arc::new(val));
+        self
+    }
+
+    /// Register filters for the router.
+    /// Check [`Router::filter`] for more information.
+    pub fn filters<T, I>(&mut self, val: I) -> &mut Self
+    where
+        T: Filter<Client> + 'static,
+        I: IntoIterator<Item = T>,
+    {
+        self.filters
+            .extend(val.into_iter().map(|filter| Arc::new(filter) as _));
+        self
+    }
+
+    /// Attach constant data to the router, which is put to the context by the key
+    /// for every update passing through the router and its sub routers.
+    /// If the key is already set for the router, the value is replaced.
+    /// # Notes
+    /// Data is put to the context before router filters, so filters, middlewares and handlers of the subtree can use it.
+    /// Values of the same keys, which are set by parent routers or middlewares, are replaced in the subtree
+    /// and restored, when the update leaves the subtree.
+    pub fn context_data<T>(&mut self, key: &'static str, value: T) -> &mut Self
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        self.context_data.insert(key, value);
+        self
+    }
 }
 
 impl<Client> Router<Client> {
@@ -509,6 +681,26 @@ impl<Client> Router<Client> {
         observers
     }
 
+    /// Describe the router with its telegram observers, handlers, filters and sub routers.
+    /// Observers without handlers are skipped.
+    /// # Notes
+    /// Check [`RouterDescription`] for more information.
+    /// Its [`Display`](std::fmt::Display) implementation can be used to print the description as a tree.
+    #[must_use]
+    pub fn describe(&self) -> RouterDescription {
+        RouterDescription {
+            name: self.router_name,
+            filters: self.filters.iter().map(|filter| filter.name()).collect(),
+            observers: self
+                .telegram_observers()
+                .into_iter()
+                .filter(|observer| !observer.handlers().is_empty())
+                .map(TelegramObserver::describe)
+                .collect(),
+            sub_routers: self.sub_routers.iter().map(Router::describe).collect(),
+        }
+    }
+
     /// Get all simple event observers
     #[must_use]
     pub const fn event_observers(&self) -> [&SimpleObserver; 2] {
@@ -563,12 +755,63 @@ impl<Client> Router<Client> {
     pub fn resolve_used_update_types(&self) -> HashSet<UpdateType> {
         self.resolve_used_update_types_with_skip([])
     }
+
+    /// Resolve bot commands from the message handlers of the current router and its sub routers.
+    /// Only handlers with description (see [`HandlerObject::description`]) are used.
+    /// This method is useful for registering commands in the bot command menu.
+    ///
+    /// [`HandlerObject::description`]: crate::event::telegram::handler::HandlerObject#method.description
+    #[must_use]
+    pub fn resolve_bot_commands(&self) -> BotCommands {
+        let mut bot_commands = BotCommands::new();
+
+        for handler in self.message.handlers() {
+            let Some(description) = handler.command_description() else {
+                continue;
+            };
+
+            let default_scopes = [BotCommandScope::default()];
+            let scopes = if handler.command_scopes().is_empty() {
+                &default_scopes[..]
+            } else {
+                handler.command_scopes()
+            };
+            let language_codes = if handler.command_language_codes().is_empty() {
+                vec![None]
+            } else {
+                handler
+                    .command_language_codes()
+                    .iter()
+                    .map(|language_code| Some(language_code.as_ref()))
+                    .collect()
+            };
+
+            for command in handler.menu_commands() {
+                for scope in scopes {
+                    for language_code in &language_codes {
+                        bot_commands.add(
+                            BotCommand::new(command.as_ref(), description),
+                            scope.clone(),
+                            *language_code,
+                        );
+                    }
+                }
+            }
+        }
+
+        for router in &self.sub_routers {
+            bot_commands.extend(router.resolve_bot_commands());
+        }
+
+        bot_commands
+    }
 }
 
 impl<Client> Debug for Router<Client> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_struct("Router")
             .field("router_name", &self.router_name)
+            .field("context_data", &self.context_data)
             .field("sub_routers", &self.sub_routers)
             .finish_non_exhaustive()
     }
@@ -685,8 +928,19 @@ where
         // We don't need to register config outer middlewares to sub routers
         config.outer_middlewares = OuterMiddlewaresConfig::new();
 
-        Ok(Service {
+        let bot_commands = self.resolve_bot_commands();
+        let used_update_types = self.resolve_used_update_types();
+        // Middlewares from the config are already registered, so they are validated as well
+        let config_errors = self.validate().into();
+
+        let mut service = Service {
             router_name: self.router_name,
+            bot_commands,
+            used_update_types,
+            config_errors,
+            handle: ServiceHandle::default(),
+            filters: self.filters.into(),
+            context_data: self.context_data,
             sub_routers: self
                 .sub_routers
                 .into_iter()
@@ -713,12 +967,21 @@ where
             update: self.update.to_service_provider_default()?,
             startup: self.startup.to_service_provider_default()?,
             shutdown: self.shutdown.to_service_provider_default()?,
-        })
+        };
+        service.handle = ServiceHandle::new(service.handler_switches());
+
+        Ok(service)
     }
 }
 
 pub struct Service<Client> {
     router_name: &'static str,
+    bot_commands: BotCommands,
+    used_update_types: HashSet<UpdateType>,
+    config_errors: Box<[ConfigError]>,
+    handle: ServiceHandle,
+    filters: Box<[Arc<dyn Filter<Client>>]>,
+    context_data: ContextData,
     sub_routers: Box<[Service<Client>]>,
 
     message: TelegramObserverService<Client>,
@@ -746,6 +1009,80 @@ pub struct Service<Client> {
     shutdown: SimpleObserverService,
 }
 
+/// Call outer middlewares in order of registration.
+/// # Returns
+/// Request, which is updated by the middlewares, and [`FlowControl::Continue`] if all middlewares are passed,
+/// otherwise the flow, which stops the propagation (see [`FlowControl`] for more details)
+async fn call_outer_middlewares<Client>(
+    middlewares: &[Arc<dyn OuterMiddleware<Client>>],
+    request: Request<Client>,
+) -> Result<(Request<Client>, FlowControl), EventErrorKind>
+where
+    Client: Send + Sync + 'static,
+{
+    let mut request = request;
+    for middleware in middlewares {
+        let (updated_request, flow) = Timings::time_in(
+            &request.context,
+            Stage::OuterMiddlewares,
+            middleware.call(request.clone()),
+        )
+        .await?;
+
+        match flow {
+            // If middleware returns continue, then update request because the middleware could have changed it
+            FlowControl::Continue => {
+                event!(Level::TRACE, "Outer middleware returns continue");
+
+                request = updated_request;
+            }
+            // If middleware returns skip handler, then we should skip this middleware and its changes
+            FlowControl::SkipHandler => {
+                event!(Level::TRACE, "Outer middleware returns skip handler");
+            }
+            // If middleware returns stop, then we should stop propagation on the level of the flow
+            FlowControl::StopObserver | FlowControl::StopRouter | FlowControl::StopPropagation => {
+                event!(Level::TRACE, %flow, "Outer middleware stops propagation");
+
+                return Ok((request, flow));
+            }
+        }
+    }
+
+    Ok((request, FlowControl::Continue))
+}
+
+/// Get response of the router, which is stopped by the flow
+fn stopped_response<Client>(request: Request<Client>, flow: FlowControl) -> Response<Client> {
+    Response {
+        request,
+        propagate_result: match flow {
+            // Event is considered as unprocessed by all routers, so the parent routers stop propagation
+            FlowControl::StopPropagation => PropagateEventResult::Rejected,
+            // Event is unhandled by the router, so the parent router propagates it to the next router
+            _ => PropagateEventResult::Unhandled,
+        },
+    }
+}
+
+/// Get response of the router by the flow of the handler, which handled the event
+fn handled_response<Client>(
+    request: Request<Client>,
+    response: TelegramHandlerResponse<Client>,
+) -> Response<Client> {
+    match response.handler_result {
+        Ok(flow @ (FlowControl::StopRouter | FlowControl::StopPropagation)) => {
+            event!(Level::TRACE, %flow, "Handler stops propagation");
+
+            stopped_response(request, flow)
+        }
+        _ => Response {
+            request,
+            propagate_result: PropagateEventResult::Handled(response),
+        },
+    }
+}
+
 impl<Client> ServiceProvider for Service<Client> {}
 
 #[async_trait]
@@ -759,95 +1096,79 @@ impl<Client> PropagateEvent<Client> for Service<Client> {
     where
         Client: Send + Sync + 'static,
     {
-        match self.propagate_update_event(request.clone()).await? {
-            // If update event handled by router, then return a response
-            Response {
-                request,
-                propagate_result: PropagateEventResult::Handled(response),
-            } => {
-                return Ok(Response {
-                    request,
-                    propagate_result: PropagateEventResult::Handled(response),
+        RoutingReport::record_in(&request.context, || RoutingStep::Router {
+            name: self.router_name,
+        });
+
+        let _context_data = self.context_data.inject(&request.context);
+
+        for filter in &*self.filters {
+            if !Timings::time_in(
+                &request.context,
+                Stage::Filters,
+                filter.check(&request.bot, &request.update, &request.context),
+            )
+            .await
+            {
+                event!(Level::TRACE, "Request are not pass router filters");
+
+                RoutingReport::record_in(&request.context, || RoutingStep::RouterFilterRejected {
+                    filter: filter.name(),
                 });
-            }
-            // If update event rejected by router, then return a response
-            Response {
-                request,
-                propagate_result: PropagateEventResult::Rejected,
-            } => {
+
                 return Ok(Response {
                     request,
-                    propagate_result: PropagateEventResult::Rejected,
+                    propagate_result: PropagateEventResult::Unhandled,
                 });
             }
-            // If update event unhandled by router, then continue propagation
-            Response {
-                request: _,
-                propagate_result: PropagateEventResult::Unhandled,
-            } => {}
+        }
+
+        let request = match self.propagate_update_event_flow(request).await? {
+            ControlFlow::Continue(request) => request,
+            ControlFlow::Break(response) => return Ok(response),
         };
 
         event!(Level::TRACE, "Propagate event to router");
 
         let observer = self.telegram_observer_by_update_type(update_type);
 
-        let mut request = request;
-        for middleware in observer.outer_middlewares() {
-            let (updated_request, event_return) = middleware.call(request.clone()).await?;
-
-            match event_return {
-                // If middleware returns finish then update request because the middleware could have changed it
-                EventReturn::Finish => {
-                    event!(Level::TRACE, "Outer middleware returns finish");
+        let (request, flow) = call_outer_middlewares(observer.outer_middlewares(), request).await?;
 
-                    request = updated_request;
-                }
-                // If middleware returns skip, then we should skip this middleware and its changes
-                EventReturn::Skip => {
-                    event!(Level::TRACE, "Outer middleware returns skip");
+        match flow {
+            // If all outer middlewares are passed, then trigger the observer
+            FlowControl::Continue | FlowControl::SkipHandler => {
+                let observer_request = request.clone().into();
+                let observer_response = observer.trigger(observer_request).await?;
 
-                    continue;
-                }
-                // If middleware returns cancel, then we should reject propagation
-                EventReturn::Cancel => {
-                    event!(Level::TRACE, "Outer middleware returns cancel");
-
-                    return Ok(Response {
-                        request,
-                        propagate_result: PropagateEventResult::Rejected,
-                    });
-                }
-            }
-        }
-
-        let observer_request = request.clone().into();
-        let observer_response = observer.trigger(observer_request).await?;
-
-        match observer_response.propagate_result {
-            // If observer unhandled, then propagate event to next observer
-            PropagateEventResult::Unhandled => {
-                event!(Level::TRACE, "Event unhandled by router");
-            }
-            // If observer handled, then return a response
-            PropagateEventResult::Handled(response) => {
-                event!(Level::TRACE, "Event handled by router");
+                match observer_response.propagate_result {
+                    // If observer unhandled, then propagate event to sub routers
+                    PropagateEventResult::Unhandled => {
+                        event!(Level::TRACE, "Event unhandled by router");
+                    }
+                    // If observer handled, then return a response or stop propagation by the flow of the handler
+                    PropagateEventResult::Handled(response) => {
+                        event!(Level::TRACE, "Event handled by router");
 
-                return Ok(Response {
-                    request,
-                    propagate_result: PropagateEventResult::Handled(response),
-                });
+                        return Ok(handled_response(request, response));
+                    }
+                    // If observer rejected, then return a response.
+                    // Router don't know about rejected event by observer, so it returns unhandled response.
+                    PropagateEventResult::Rejected => {
+                        event!(Level::TRACE, "Event rejected by router");
+
+                        return Ok(Response {
+                            request,
+                            propagate_result: PropagateEventResult::Unhandled,
+                        });
+                    }
+                };
             }
-            // If observer rejected, then return a response.
-            // Router don't know about rejected event by observer, so it returns unhandled response.
-            PropagateEventResult::Rejected => {
-                event!(Level::TRACE, "Event rejected by router");
-
-                return Ok(Response {
-                    request,
-                    propagate_result: PropagateEventResult::Unhandled,
-                });
+            // If outer middleware stops the observer, then propagate event to sub routers
+            FlowControl::StopObserver => {}
+            flow @ (FlowControl::StopRouter | FlowControl::StopPropagation) => {
+                return Ok(stopped_response(request, flow));
             }
-        };
+        }
 
         // Propagate event to sub routers
         for router in &*self.sub_routers {
@@ -889,34 +1210,76 @@ impl<Client> PropagateEvent<Client> for Service<Client> {
     where
         Client: Send + Sync + 'static,
     {
-        event!(Level::TRACE, "Propagate update event to router");
+        Ok(match self.propagate_update_event_flow(request).await? {
+            ControlFlow::Continue(request) => Response {
+                request,
+                propagate_result: PropagateEventResult::Unhandled,
+            },
+            ControlFlow::Break(response) => response,
+        })
+    }
+
+    #[instrument(skip(self), fields(router_name = self.router_name))]
+    async fn emit_startup(&self) -> SimpleHandlerResult {
+        for startup in
+            once(&self.startup).chain(self.sub_routers.iter().map(|router| &router.startup))
+        {
+            startup.trigger(()).await?;
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self), fields(router_name = self.router_name))]
+    async fn emit_shutdown(&self) -> SimpleHandlerResult {
+        for shutdown in
+            once(&self.shutdown).chain(self.sub_routers.iter().map(|router| &router.shutdown))
+        {
+            shutdown.trigger(()).await?;
+        }
+        Ok(())
+    }
 
-        let mut request = request;
-        for middleware in self.update.outer_middlewares() {
-            let (updated_request, event_return) = middleware.call(request.clone()).await?;
+    fn bot_commands(&self) -> BotCommands {
+        self.bot_commands.clone()
+    }
 
-            match event_return {
-                // If middleware returns finish, then update request because the middleware could have changed it
-                EventReturn::Finish => {
-                    event!(Level::TRACE, "Update outer middleware returns finish");
+    fn handle(&self) -> ServiceHandle {
+        self.handle.clone()
+    }
 
-                    request = updated_request;
-                }
-                // If middleware returns skip, then we should skip this middleware and its changes
-                EventReturn::Skip => {
-                    event!(Level::TRACE, "Update outer middleware returns skip");
+    fn used_update_types(&self) -> Option<&HashSet<UpdateType>> {
+        Some(&self.used_update_types)
+    }
 
-                    continue;
-                }
-                // If middleware returns cancel, then we should cancel propagation
-                EventReturn::Cancel => {
-                    event!(Level::TRACE, "Update outer middleware returns cancel");
+    fn config_errors(&self) -> &[ConfigError] {
+        &self.config_errors
+    }
+}
 
-                    return Ok(Response {
-                        request,
-                        propagate_result: PropagateEventResult::Rejected,
-                    });
-                }
+impl<Client> Service<Client> {
+    /// Propagate event to update observer of the router.
+    /// # Returns
+    /// [`ControlFlow::Continue`] with the request, which is updated by outer middlewares,
+    /// if the event should be propagated to the observer of the event type,
+    /// otherwise [`ControlFlow::Break`] with the response of the router
+    async fn propagate_update_event_flow(
+        &self,
+        request: Request<Client>,
+    ) -> Result<ControlFlow<Response<Client>, Request<Client>>, EventErrorKind>
+    where
+        Client: Send + Sync + 'static,
+    {
+        event!(Level::TRACE, "Propagate update event to router");
+
+        let (request, flow) =
+            call_outer_middlewares(self.update.outer_middlewares(), request).await?;
+
+        match flow {
+            FlowControl::Continue | FlowControl::SkipHandler => {}
+            // If update outer middleware stops the observer, then skip update observer
+            FlowControl::StopObserver => return Ok(ControlFlow::Continue(request)),
+            flow @ (FlowControl::StopRouter | FlowControl::StopPropagation) => {
+                return Ok(ControlFlow::Break(stopped_response(request, flow)));
             }
         }
 
@@ -924,59 +1287,37 @@ impl<Client> PropagateEvent<Client> for Service<Client> {
         let observer_response = self.update.trigger(observer_request).await?;
 
         match observer_response.propagate_result {
-            // If observer returns unhandled, then propagate event to next observer
-            PropagateEventResult::Unhandled => {
-                event!(Level::TRACE, "Update event unhandled by router");
-
-                Ok(Response {
-                    request,
-                    propagate_result: PropagateEventResult::Unhandled,
-                })
-            }
-            // If observer returns handled, then return a response
+            // If observer returns handled, then return a response or stop propagation by the flow of the handler
             PropagateEventResult::Handled(response) => {
                 event!(Level::TRACE, "Update event handled by router");
 
-                Ok(Response {
-                    request,
-                    propagate_result: PropagateEventResult::Handled(response),
-                })
+                Ok(ControlFlow::Break(handled_response(request, response)))
             }
-            // If observer returns rejected, then return a response.ё
-            // Router don't know about rejected event by observer, so it returns unhandled response.
-            PropagateEventResult::Rejected => {
-                event!(Level::TRACE, "Update event rejected by router");
+            // If observer returns unhandled or rejected, then propagate event to next observer.
+            // Router don't know about rejected event by observer, so it's the same as unhandled.
+            PropagateEventResult::Unhandled | PropagateEventResult::Rejected => {
+                event!(Level::TRACE, "Update event unhandled by router");
 
-                Ok(Response {
-                    request,
-                    propagate_result: PropagateEventResult::Unhandled,
-                })
+                Ok(ControlFlow::Continue(request))
             }
         }
     }
 
-    #[instrument(skip(self), fields(router_name = self.router_name))]
-    async fn emit_startup(&self) -> SimpleHandlerResult {
-        for startup in
-            once(&self.startup).chain(self.sub_routers.iter().map(|router| &router.startup))
-        {
-            startup.trigger(()).await?;
-        }
-        Ok(())
-    }
+    /// Get switches of the named handlers of the router and its sub routers
+    fn handler_switches(&self) -> Vec<(&str, Arc<AtomicBool>)> {
+        let mut switches: Vec<_> = self
+            .telegram_observers()
+            .into_iter()
+            .flat_map(TelegramObserverService::handler_switches)
+            .collect();
 
-    #[instrument(skip(self), fields(router_name = self.router_name))]
-    async fn emit_shutdown(&self) -> SimpleHandlerResult {
-        for shutdown in
-            once(&self.shutdown).chain(self.sub_routers.iter().map(|router| &router.shutdown))
-        {
-            shutdown.trigger(()).await?;
+        for router in &*self.sub_routers {
+            switches.extend(router.handler_switches());
         }
-        Ok(())
+
+        switches
     }
-}
 
-impl<Client> Service<Client> {
     #[must_use]
     pub const fn telegram_observers(&self) -> [&TelegramObserverService<Client>; 19] {
         [
@@ -1039,6 +1380,7 @@ impl<Client> Debug for Service<Client> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_struct("Router")
             .field("router_name", &self.router_name)
+            .field("context_data", &self.context_data)
             .field("sub_routers", &self.sub_routers)
             .finish_non_exhaustive()
     }
@@ -1631,7 +1973,7 @@ mod tests {
     use super::*;
     use crate::{
         client::Reqwest,
-        event::{telegram::HandlerResult as TelegramHandlerResult, EventReturn},
+        event::{telegram::HandlerResult as TelegramHandlerResult, FlowControl},
         middlewares::inner::Next,
     };
 
@@ -1642,7 +1984,7 @@ mod tests {
         let mut router = Router::<Reqwest>::new("main");
 
         let inner_middleware = |request, next: Next<_>| next(request);
-        let outer_middleware = |request| async move { Ok((request, EventReturn::default())) };
+        let outer_middleware = |request| async move { Ok((request, FlowControl::default())) };
 
         router.message.inner_middlewares.register(inner_middleware);
         router.message.outer_middlewares.register(outer_middleware);
@@ -1727,7 +2069,7 @@ mod tests {
     #[test]
     fn test_observer_register() {
         async fn telegram_handler() -> TelegramHandlerResult {
-            Ok(EventReturn::Finish)
+            Ok(FlowControl::Continue)
         }
 
         async fn simple_handler() -> SimpleHandlerResult {
@@ -1777,7 +2119,7 @@ mod tests {
         });
 
         let inner_middleware = |request, next: Next<_>| next(request);
-        let outer_middleware = |request| async move { Ok((request, EventReturn::Finish)) };
+        let outer_middleware = |request| async move { Ok((request, FlowControl::Continue)) };
 
         router.message.inner_middlewares.register(inner_middleware);
         router.message.outer_middlewares.register(outer_middleware);
@@ -1797,7 +2139,7 @@ mod tests {
         let mut router = Router::new("test_handler");
         router
             .message
-            .register(|| async move { Ok(EventReturn::Finish) });
+            .register(|| async move { Ok(FlowControl::Continue) });
 
         let router_service = router.to_service_provider_default().unwrap();
         let response = router_service
@@ -1808,7 +2150,7 @@ mod tests {
         // Handler should be called, because it's registered for this event
         match response.propagate_result {
             PropagateEventResult::Handled(response) => match response.handler_result {
-                Ok(EventReturn::Finish) => {}
+                Ok(FlowControl::Continue) => {}
                 _ => panic!("Unexpected result"),
             },
             _ => panic!("Unexpected result"),
@@ -1832,7 +2174,7 @@ mod tests {
             .register(|request: Request<Reqwest>| async move {
                 request.context.insert("test", Box::new("test"));
 
-                Ok((request, EventReturn::Finish))
+                Ok((request, FlowControl::Continue))
             });
         router.message.register(|context: Arc<Context>| async move {
             // Check that middleware was called and context was modified
@@ -1841,7 +2183,7 @@ mod tests {
                 &"test"
             );
 
-            Ok(EventReturn::Finish)
+            Ok(FlowControl::Continue)
         });
 
         let router_service = router.to_service_provider_default().unwrap();
@@ -1854,7 +2196,7 @@ mod tests {
         // Handler should be called, because it's registered for this event
         match response.propagate_result {
             PropagateEventResult::Handled(response) => match response.handler_result {
-                Ok(EventReturn::Finish) => {}
+                Ok(FlowControl::Continue) => {}
                 _ => panic!("Unexpected result"),
             },
             _ => panic!("Unexpected result"),
@@ -1863,10 +2205,10 @@ mod tests {
         let mut router = Router::new("test_skip_handler");
         router
             .message
-            .register(|| async move { Ok(EventReturn::Skip) });
+            .register(|| async move { Ok(FlowControl::SkipHandler) });
         router
             .message
-            .register(|| async move { Ok(EventReturn::Finish) });
+            .register(|| async move { Ok(FlowControl::Continue) });
 
         let router_service = router.to_service_provider_default().unwrap();
 
@@ -1879,7 +2221,7 @@ mod tests {
         // First handler skipped, so second handler should be called.
         match response.propagate_result {
             PropagateEventResult::Handled(response) => match response.handler_result {
-                Ok(EventReturn::Finish) => {}
+                Ok(FlowControl::Continue) => {}
                 _ => panic!("Unexpected result"),
             },
             _ => panic!("Unexpected result"),
@@ -1888,7 +2230,7 @@ mod tests {
         let mut router = Router::new("test_skip_handler_without_next");
         router
             .message
-            .register(|| async move { Ok(EventReturn::Skip) });
+            .register(|| async move { Ok(FlowControl::SkipHandler) });
 
         let router_service = router.to_service_provider_default().unwrap();
 
@@ -1916,7 +2258,7 @@ mod tests {
         let mut router = Router::new("test_handler_with_filter");
         router
             .message
-            .register(|| async move { Ok(EventReturn::Finish) })
+            .register(|| async move { Ok(FlowControl::Continue) })
             .filter(|_: &Bot<_>, _: &Update, _: &Context| async move { true });
 
         let router_service = router.to_service_provider_default().unwrap();
@@ -1928,7 +2270,7 @@ mod tests {
         // Handler should be called, because filter returns `true`
         match response.propagate_result {
             PropagateEventResult::Handled(response) => match response.handler_result {
-                Ok(EventReturn::Finish) => {}
+                Ok(FlowControl::Continue) => {}
                 _ => panic!("Unexpected result"),
             },
             _ => panic!("Unexpected result"),
@@ -1937,7 +2279,7 @@ mod tests {
         let mut router = Router::new("test_handler_with_fail_filter");
         router
             .message
-            .register(|| async move { Ok(EventReturn::Finish) })
+            .register(|| async move { Ok(FlowControl::Continue) })
             .filter(|_: &Bot<_>, _: &Update, _: &Context| async move { false });
 
         let router_service = router.to_service_provider_default().unwrap();
@@ -1955,7 +2297,7 @@ mod tests {
         let mut router = Router::new("test_handler_with_filters_and_one_fail");
         router
             .message
-            .register(|| async move { Ok(EventReturn::Finish) })
+            .register(|| async move { Ok(FlowControl::Continue) })
             .filter(|_: &Bot<_>, _: &Update, _: &Context| async move { true })
             .filter(|_: &Bot<_>, _: &Update, _: &Context| async move { true })
             .filter(|_: &Bot<_>, _: &Update, _: &Context| async move { false });
@@ -1973,16 +2315,408 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_propagate_event_with_router_filter() {
+        let bot = Bot::<Reqwest>::default();
+        let context = Context::new();
+        let update = Update::default();
+
+        let request = Request::new(Arc::new(bot), Arc::new(update), Arc::new(context));
+
+        let mut router = Router::new("main");
+
+        let mut gated_router = Router::new("gated");
+        gated_router
+            .message
+            .register(|| async move { Ok(FlowControl::Continue) });
+        router.include_with(
+            gated_router,
+            |_: &Bot<_>, _: &Update, _: &Context| async move { false },
+        );
+
+        let router_service = router.to_service_provider_default().unwrap();
+        let response = router_service
+            .propagate_event(UpdateType::Message, request.clone())
+            .await
+            .unwrap();
+
+        // Handler shouldn't be called, because the router filter returns `false`
+        match response.propagate_result {
+            PropagateEventResult::Unhandled => {}
+            _ => panic!("Unexpected result"),
+        }
+
+        let mut router = Router::new("main");
+
+        let mut gated_router = Router::new("gated");
+        gated_router
+            .message
+            .register(|| async move { Ok(FlowControl::Continue) });
+        router.include_with(
+            gated_router,
+            |_: &Bot<_>, _: &Update, _: &Context| async move { true },
+        );
+
+        let router_service = router.to_service_provider_default().unwrap();
+        let response = router_service
+            .propagate_event(UpdateType::Message, request)
+            .await
+            .unwrap();
+
+        // Handler should be called, because the router filter returns `true`
+        match response.propagate_result {
+            PropagateEventResult::Handled(response) => match response.handler_result {
+                Ok(FlowControl::Continue) => {}
+                _ => panic!("Unexpected result"),
+            },
+            _ => panic!("Unexpected result"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_propagate_event_with_context_data() {
+        let bot = Bot::<Reqwest>::default();
+        let context = Context::new();
+        let update = Update::default();
+
+        let request = Request::new(Arc::new(bot), Arc::new(update), Arc::new(context));
+
+        let mut router = Router::new("main");
+        router.context_data("feature", "main");
+
+        let mut payments_router = Router::new("payments");
+        payments_router.context_data("feature", "payments");
+        payments_router
+            .message
+            .register(|context: Arc<Context>| async move {
+                assert_eq!(
+                    context.get("feature").unwrap().downcast_ref::<&str>(),
+                    Some(&"payments")
+                );
+
+                Ok(FlowControl::SkipHandler)
+            });
+
+        let mut other_router = Router::new("other");
+        other_router
+            .message
+            .register(|context: Arc<Context>| async move {
+                // Data of the previous sibling router is restored, when the update leaves it
+                assert_eq!(
+                    context.get("feature").unwrap().downcast_ref::<&str>(),
+                    Some(&"main")
+                );
+
+                Ok(FlowControl::Continue)
+            });
+
+        router.include(payments_router).include(other_router);
+
+        let router_service = router.to_service_provider_default().unwrap();
+        let response = router_service
+            .propagate_event(UpdateType::Message, request.clone())
+            .await
+            .unwrap();
+
+        match response.propagate_result {
+            PropagateEventResult::Handled(response) => match response.handler_result {
+                Ok(FlowControl::Continue) => {}
+                _ => panic!("Unexpected result"),
+            },
+            _ => panic!("Unexpected result"),
+        }
+
+        assert!(request.context.get("feature").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_propagate_event_with_disabled_handler() {
+        let bot = Bot::<Reqwest>::default();
+        let context = Context::new();
+        let update = Update::default();
+
+        let request = Request::new(Arc::new(bot), Arc::new(update), Arc::new(context));
+
+        let mut router = Router::new("main");
+
+        let mut sub_router = Router::new("sub");
+        sub_router
+            .message
+            .register(|| async move { Ok(FlowControl::Continue) })
+            .name("finish");
+        router.include(sub_router);
+
+        let router_service = router.to_service_provider_default().unwrap();
+        let handle = router_service.handle();
+
+        assert!(handle.set_handler_enabled("finish", false));
+        assert!(!handle.set_handler_enabled("unknown", false));
+
+        let response = router_service
+            .propagate_event(UpdateType::Message, request.clone())
+            .await
+            .unwrap();
+
+        // Handler shouldn't be called, because it's disabled
+        match response.propagate_result {
+            PropagateEventResult::Unhandled => {}
+            _ => panic!("Unexpected result"),
+        }
+
+        handle.set_handler_enabled("finish", true);
+
+        let response = router_service
+            .propagate_event(UpdateType::Message, request)
+            .await
+            .unwrap();
+
+        match response.propagate_result {
+            PropagateEventResult::Handled(_) => {}
+            _ => panic!("Unexpected result"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_routing_report() {
+        use crate::filters::Command;
+
+        let report = RoutingReport::new();
+        let context = Context::new();
+        context.insert("routing_report", Box::new(report.clone()));
+
+        let request = Request::new(
+            Arc::new(Bot::<Reqwest>::default()),
+            Arc::new(Update::default()),
+            Arc::new(context),
+        );
+
+        let mut router = Router::new("main");
+        router
+            .message
+            .register(|| async move { Ok(FlowControl::Continue) })
+            .name("start")
+            .filter(Command::one("start"));
+        router
+            .message
+            .register(|| async move { Ok(FlowControl::Continue) })
+            .name("disabled")
+            .enabled(false);
+
+        let mut gated_router = Router::new("gated");
+        gated_router
+            .message
+            .register(|| async move { Ok(FlowControl::Continue) });
+        router.include_with(
+            gated_router,
+            |_: &Bot<_>, _: &Update, _: &Context| async move { false },
+        );
+
+        let router_service = router.to_service_provider_default().unwrap();
+        let response = router_service
+            .propagate_event(UpdateType::Message, request)
+            .await
+            .unwrap();
+
+        match response.propagate_result {
+            PropagateEventResult::Unhandled => {}
+            _ => panic!("Unexpected result"),
+        }
+
+        let steps = report.steps();
+        assert_eq!(steps.len(), 6);
+        assert_eq!(steps[0], RoutingStep::Router { name: "main" });
+        assert_eq!(steps[1], RoutingStep::Observer { name: "message" });
+        assert!(matches!(
+            steps[2],
+            RoutingStep::HandlerFilterRejected { ref handler, filter }
+                if handler.as_deref() == Some("start") && filter.starts_with("telers::filters::command::Command")
+        ));
+        assert_eq!(
+            steps[3],
+            RoutingStep::HandlerDisabled {
+                handler: Some("disabled".into())
+            }
+        );
+        assert_eq!(steps[4], RoutingStep::Router { name: "gated" });
+        assert!(matches!(steps[5], RoutingStep::RouterFilterRejected { .. }));
+        assert!(report
+            .to_string()
+            .starts_with("router main\n  observer message\n"));
+    }
+
+    #[tokio::test]
+    async fn test_flow_control() {
+        use std::sync::Mutex;
+
+        async fn propagate(router: Router<Reqwest>) -> PropagateEventResult<Reqwest> {
+            let request = Request::new(
+                Arc::new(Bot::<Reqwest>::default()),
+                Arc::new(Update::default()),
+                Arc::new(Context::new()),
+            );
+
+            router
+                .to_service_provider_default()
+                .unwrap()
+                .propagate_event(UpdateType::Message, request)
+                .await
+                .unwrap()
+                .propagate_result
+        }
+
+        let calls = Arc::new(Mutex::new(vec![]));
+        let handler = |name: &'static str, flow: FlowControl| {
+            let calls = Arc::clone(&calls);
+            move || {
+                let calls = Arc::clone(&calls);
+                async move {
+                    calls.lock().unwrap().push(name);
+                    Ok(flow)
+                }
+            }
+        };
+
+        // Main router with `first` and `second` sub routers, `first` router has `nested` sub router
+        let router = |first_flow: FlowControl| {
+            let mut nested = Router::new("nested");
+            nested
+                .message
+                .register(handler("nested", FlowControl::Continue));
+
+            let mut first = Router::new("first");
+            first.message.register(handler("first", first_flow));
+            first
+                .message
+                .register(handler("first_2", FlowControl::Continue));
+            first.include(nested);
+
+            let mut second = Router::new("second");
+            second
+                .message
+                .register(handler("second", FlowControl::Continue));
+
+            let mut router = Router::new("main");
+            router.include(first).include(second);
+            router
+        };
+
+        let result = propagate(router(FlowControl::SkipHandler)).await;
+        assert!(matches!(result, PropagateEventResult::Handled(_)));
+        assert_eq!(*calls.lock().unwrap(), ["first", "first_2"]);
+        calls.lock().unwrap().clear();
+
+        let result = propagate(router(FlowControl::StopObserver)).await;
+        assert!(matches!(result, PropagateEventResult::Handled(_)));
+        assert_eq!(*calls.lock().unwrap(), ["first", "nested"]);
+        calls.lock().unwrap().clear();
+
+        let result = propagate(router(FlowControl::StopRouter)).await;
+        assert!(matches!(result, PropagateEventResult::Handled(_)));
+        assert_eq!(*calls.lock().unwrap(), ["first", "second"]);
+        calls.lock().unwrap().clear();
+
+        let result = propagate(router(FlowControl::StopPropagation)).await;
+        assert!(matches!(result, PropagateEventResult::Rejected));
+        assert_eq!(*calls.lock().unwrap(), ["first"]);
+        calls.lock().unwrap().clear();
+
+        // Outer middlewares stop propagation on the same levels as handlers
+        for (flow, expected_calls) in [
+            (FlowControl::SkipHandler, &["first"][..]),
+            (FlowControl::StopObserver, &["nested"][..]),
+            (FlowControl::StopRouter, &["second"][..]),
+            (FlowControl::StopPropagation, &[][..]),
+        ] {
+            let mut router = router(FlowControl::Continue);
+            router.sub_routers[0]
+                .message
+                .outer_middlewares
+                .register(move |request| async move { Ok((request, flow)) });
+
+            let result = propagate(router).await;
+            assert_eq!(
+                matches!(result, PropagateEventResult::Rejected),
+                flow == FlowControl::StopPropagation
+            );
+            assert_eq!(*calls.lock().unwrap(), expected_calls);
+            calls.lock().unwrap().clear();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_timings() {
+        use std::time::Duration;
+        use tokio::time::sleep;
+
+        let timings = Timings::new();
+        let context = Context::new();
+        context.insert("timings", Box::new(timings.clone()));
+
+        let request = Request::new(
+            Arc::new(Bot::<Reqwest>::default()),
+            Arc::new(Update::default()),
+            Arc::new(context),
+        );
+
+        let mut router = Router::new("main");
+        router
+            .message
+            .outer_middlewares
+            .register(|request: Request<Reqwest>| async move {
+                sleep(Duration::from_millis(5)).await;
+
+                Ok((request, FlowControl::Continue))
+            });
+        router
+            .message
+            .inner_middlewares
+            .register(|request, next: Next<_>| async move {
+                sleep(Duration::from_millis(5)).await;
+
+                next(request).await
+            });
+        router
+            .message
+            .register(|| async move {
+                sleep(Duration::from_millis(50)).await;
+
+                Ok(FlowControl::Continue)
+            })
+            .filter(|_: &Bot<_>, _: &Update, _: &Context| async move {
+                sleep(Duration::from_millis(5)).await;
+
+                true
+            });
+
+        let router_service = router.to_service_provider_default().unwrap();
+        let response = router_service
+            .propagate_event(UpdateType::Message, request)
+            .await
+            .unwrap();
+
+        match response.propagate_result {
+            PropagateEventResult::Handled(_) => {}
+            _ => panic!("Unexpected result"),
+        }
+
+        assert!(timings.get(Stage::OuterMiddlewares) >= Duration::from_millis(5));
+        assert!(timings.get(Stage::Filters) >= Duration::from_millis(5));
+        assert!(timings.get(Stage::Handler) >= Duration::from_millis(50));
+        // Inner middlewares are timed without the handler, which they wrap
+        assert!(timings.get(Stage::InnerMiddlewares) >= Duration::from_millis(5));
+        assert!(timings.get(Stage::InnerMiddlewares) < Duration::from_millis(50));
+    }
+
     #[test]
     fn test_resolve_used_update_types() {
         let mut router = Router::<Reqwest>::new("test");
 
         router
             .message
-            .register(|| async { Ok(EventReturn::Finish) });
+            .register(|| async { Ok(FlowControl::Continue) });
         router
             .edited_message
-            .register(|| async { Ok(EventReturn::Finish) });
+            .register(|| async { Ok(FlowControl::Continue) });
 
         let update_types = router.resolve_used_update_types();
 
@@ -1994,10 +2728,10 @@ mod tests {
 
         router2
             .message
-            .register(|| async { Ok(EventReturn::Finish) });
+            .register(|| async { Ok(FlowControl::Continue) });
         router2
             .channel_post
-            .register(|| async { Ok(EventReturn::Finish) });
+            .register(|| async { Ok(FlowControl::Continue) });
 
         assert_eq!(router2.resolve_used_update_types().len(), 2);
 
@@ -2018,4 +2752,153 @@ mod tests {
         assert!(update_types.contains(&UpdateType::EditedMessage));
         assert!(update_types.contains(&UpdateType::ChannelPost));
     }
+
+    #[test]
+    fn test_resolve_bot_commands() {
+        use crate::{filters::Command, types::BotCommandScopeAllPrivateChats};
+
+        let mut router = Router::<Reqwest>::new("test");
+
+        router
+            .message
+            .register(|| async { Ok(FlowControl::Continue) })
+            .filter(Command::many(["start", "begin"]))
+            .description("Start the bot");
+        // Handler without description isn't registered in the menu
+        router
+            .message
+            .register(|| async { Ok(FlowControl::Continue) })
+            .filter(Command::one("hidden"));
+        // Handler with prefix, which isn't allowed in the menu
+        router
+            .message
+            .register(|| async { Ok(FlowControl::Continue) })
+            .filter(Command::one_with_prefix("other", '!'))
+            .description("Other");
+
+        let mut router2 = Router::<Reqwest>::new("test2");
+
+        router2
+            .message
+            .register(|| async { Ok(FlowControl::Continue) })
+            .filter(Command::one("settings"))
+            .description("Settings")
+            .command_scope(BotCommandScopeAllPrivateChats::new())
+            .command_language_code("en")
+            .command_language_code("ru");
+
+        router.include(router2);
+
+        let bot_commands = router.resolve_bot_commands();
+        let groups = bot_commands.groups();
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(
+            groups[0]
+                .commands
+                .iter()
+                .map(|command| command.command.as_str())
+                .collect::<Vec<_>>(),
+            ["start", "begin"]
+        );
+        assert_eq!(groups[1].language_code.as_deref(), Some("en"));
+        assert_eq!(groups[2].language_code.as_deref(), Some("ru"));
+        assert_eq!(groups[2].commands[0].command, "settings");
+    }
+
+    #[tokio::test]
+    async fn test_install_plugin() {
+        struct Greeting;
+
+        impl Plugin<Reqwest> for Greeting {
+            fn name(&self) -> &'static str {
+                "greeting"
+            }
+
+            fn setup(&self, router: &mut Router<Reqwest>, config: &PluginConfig) {
+                let name = config.get_as::<String>("handler").unwrap().unwrap();
+
+                router
+                    .message
+                    .register(|| async { Ok(FlowControl::Continue) })
+                    .name(name);
+                router.startup.register(|| async { Ok(()) }, ());
+            }
+        }
+
+        let mut router = Router::<Reqwest>::new("main");
+        router.install_with(Greeting, PluginConfig::new().value("handler", "greet"));
+
+        let description = router.describe();
+        let plugin = &description.sub_routers[0];
+        assert_eq!(plugin.name, "greeting");
+        assert_eq!(
+            plugin.observers[0].handlers[0].name.as_deref(),
+            Some("greet")
+        );
+
+        let router_service = router.to_service_provider_default().unwrap();
+        router_service.emit_startup().await.unwrap();
+    }
+
+    #[test]
+    fn test_describe() {
+        use crate::filters::{ChatType, Command};
+
+        let mut router = Router::<Reqwest>::new("main");
+        router
+            .message
+            .register(|| async { Ok(FlowControl::Continue) })
+            .name("start_private")
+            .filter(Command::one("start"))
+            .filter(ChatType::one(crate::enums::ChatType::Private));
+        router
+            .callback_query
+            .register(|| async { Ok(FlowControl::Continue) });
+
+        let mut admin = Router::<Reqwest>::new("admin");
+        admin
+            .message
+            .filter(ChatType::one(crate::enums::ChatType::Private))
+            .register(|| async { Ok(FlowControl::Continue) })
+            .name("ban");
+
+        router.include_with(
+            admin,
+            |_: &Bot<_>, _: &Update, _: &Context| async move { true },
+        );
+
+        let description = router.describe();
+
+        assert_eq!(description.name, "main");
+        assert!(description.filters.is_empty());
+        assert_eq!(description.observers.len(), 2);
+        assert_eq!(description.observers[0].name, "message");
+        assert_eq!(description.observers[1].name, "callback_query");
+
+        let handler = &description.observers[0].handlers[0];
+        assert_eq!(handler.name.as_deref(), Some("start_private"));
+        assert_eq!(handler.filters.len(), 2);
+        assert!(handler.filters[1].ends_with("ChatType"));
+        assert!(handler.filters[1].ends_with("ChatType"));
+        assert_eq!(&*handler.commands, [Box::from("start")]);
+        assert_eq!(description.observers[1].handlers[0].name, None);
+
+        let admin = &description.sub_routers[0];
+        assert_eq!(admin.name, "admin");
+        assert_eq!(admin.filters.len(), 1);
+        assert_eq!(admin.observers[0].filters.len(), 1);
+
+        let handlers = description.handlers();
+        assert_eq!(handlers.len(), 3);
+        assert_eq!(handlers[2].0, "message");
+        assert_eq!(handlers[2].1.name.as_deref(), Some("ban"));
+
+        let tree = description.to_string();
+        assert!(tree.starts_with("router main\n  message\n    handler start_private ["));
+        assert!(tree.contains("(commands: start)"));
+        assert!(tree.contains("    handler <unnamed>"));
+        assert!(tree.contains("\n  router admin ["));
+        assert!(tree.contains("\n      handler ban\n"));
+    }
 }
@@ -0,0 +1,121 @@
+//! This module contains [`ContextData`], which is constant data of the router,
+//! which is put to the context for every update passing through the router and its sub routers
+//! (see [`Router::context_data`] for more information).
+//!
+//! [`Router::context_data`]: super::Router#method.context_data
+
+use crate::Context;
+
+use std::{
+    any::Any,
+    fmt::{self, Debug, Formatter},
+    sync::Arc,
+};
+
+/// Function, which creates a new copy of the value for each update
+type Factory = Arc<dyn Fn() -> Box<dyn Any + Send + Sync> + Send + Sync>;
+
+#[derive(Default, Clone)]
+pub(super) struct ContextData {
+    values: Vec<(&'static str, Factory)>,
+}
+
+impl ContextData {
+    /// Set the value by the key. If the key is already set, the value is replaced
+    pub(super) fn insert<T>(&mut self, key: &'static str, value: T)
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        let factory: Factory = Arc::new(move || Box::new(value.clone()));
+
+        match self.values.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, old)) => *old = factory,
+            None => self.values.push((key, factory)),
+        }
+    }
+
+    /// Put the values to the context.
+    /// Replaced values are restored, when the returned guard is dropped,
+    /// so the values are visible only while the update is in the router and its sub routers.
+    #[must_use]
+    pub(super) fn inject(&self, context: &Arc<Context>) -> Option<Guard> {
+        if self.values.is_empty() {
+            return None;
+        }
+
+        let previous = self
+            .values
+            .iter()
+            .map(|(key, factory)| (*key, context.insert(key, factory())))
+            .collect();
+
+        Some(Guard {
+            context: Arc::clone(context),
+            previous,
+        })
+    }
+}
+
+impl Debug for ContextData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.values.iter().map(|(key, _)| key))
+            .finish()
+    }
+}
+
+/// Guard, which restores values of the context, which are replaced by [`ContextData::inject`]
+pub(super) struct Guard {
+    context: Arc<Context>,
+    previous: Vec<(&'static str, Option<Box<dyn Any + Send + Sync>>)>,
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        for (key, value) in self.previous.drain(..).rev() {
+            match value {
+                Some(value) => {
+                    self.context.insert(key, value);
+                }
+                None => {
+                    self.context.remove(key);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inject() {
+        let context = Arc::new(Context::new());
+        context.insert("feature", Box::new("main"));
+
+        let mut data = ContextData::default();
+        data.insert("feature", "old");
+        data.insert("feature", "payments");
+        data.insert("limit", 10_u8);
+
+        let guard = data.inject(&context);
+        assert_eq!(
+            context.get("feature").unwrap().downcast_ref::<&str>(),
+            Some(&"payments")
+        );
+        assert_eq!(
+            context.get("limit").unwrap().downcast_ref::<u8>(),
+            Some(&10)
+        );
+
+        drop(guard);
+        assert_eq!(
+            context.get("feature").unwrap().downcast_ref::<&str>(),
+            Some(&"main")
+        );
+        assert!(context.get("limit").is_none());
+
+        assert!(ContextData::default().inject(&context).is_none());
+    }
+}
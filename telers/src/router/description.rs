@@ -0,0 +1,142 @@
+//! This module contains descriptions of [`Router`], its telegram observers and handlers,
+//! which are returned by [`Router::describe`] method.
+//!
+//! Descriptions are plain data, so they can be used for debugging, documentation generation
+//! or admin commands that list available handlers.
+//! They can be serialized (for example, to JSON) or printed as a tree with [`Display`] implementation.
+//!
+//! Filters are described by their names (see [`Filter::name`]).
+//!
+//! [`Router`]: super::Router
+//! [`Router::describe`]: super::Router#method.describe
+//! [`Filter::name`]: crate::filters::Filter::name
+
+use serde::Serialize;
+use std::fmt::{self, Display, Formatter};
+
+/// Description of a router with its telegram observers and sub routers
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RouterDescription {
+    pub name: &'static str,
+    /// Names of the router filters
+    pub filters: Box<[&'static str]>,
+    /// Descriptions of the telegram observers, which have at least one handler
+    pub observers: Box<[ObserverDescription]>,
+    pub sub_routers: Box<[RouterDescription]>,
+}
+
+/// Description of a telegram observer with its handlers
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ObserverDescription {
+    pub name: &'static str,
+    /// Names of the filters, which are common for all handlers of the observer
+    pub filters: Box<[&'static str]>,
+    pub handlers: Box<[HandlerDescription]>,
+}
+
+/// Description of a handler
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HandlerDescription {
+    /// Name of the handler, if it's set
+    pub name: Option<Box<str>>,
+    /// Priority of the handler in the observer
+    pub priority: i32,
+    /// Names of the handler filters
+    pub filters: Box<[&'static str]>,
+    /// Commands from the handler [`Command`] filters
+    ///
+    /// [`Command`]: crate::filters::Command
+    pub commands: Box<[Box<str>]>,
+}
+
+impl RouterDescription {
+    /// Get descriptions of all handlers of the router and its sub routers with the observer names
+    #[must_use]
+    pub fn handlers(&self) -> Vec<(&'static str, &HandlerDescription)> {
+        let mut handlers = vec![];
+
+        for observer in &*self.observers {
+            handlers.extend(
+                observer
+                    .handlers
+                    .iter()
+                    .map(|handler| (observer.name, handler)),
+            );
+        }
+        for router in &*self.sub_routers {
+            handlers.extend(router.handlers());
+        }
+
+        handlers
+    }
+
+    fn fmt_with_indent(&self, f: &mut Formatter<'_>, indent: usize) -> fmt::Result {
+        writeln!(
+            f,
+            "{:indent$}router {}{}",
+            "",
+            self.name,
+            Filters(&self.filters),
+        )?;
+
+        for observer in &*self.observers {
+            writeln!(
+                f,
+                "{:indent$}  {}{}",
+                "",
+                observer.name,
+                Filters(&observer.filters),
+            )?;
+
+            for handler in &*observer.handlers {
+                writeln!(f, "{:indent$}    {handler}", "")?;
+            }
+        }
+
+        for router in &*self.sub_routers {
+            router.fmt_with_indent(f, indent + 2)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for RouterDescription {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_with_indent(f, 0)
+    }
+}
+
+impl Display for HandlerDescription {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "handler {}{}",
+            self.name.as_deref().unwrap_or("<unnamed>"),
+            Filters(&self.filters),
+        )?;
+
+        if !self.commands.is_empty() {
+            write!(f, " (commands: {})", self.commands.join(", "))?;
+        }
+
+        if self.priority != 0 {
+            write!(f, " (priority: {})", self.priority)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Helper to display filter names in brackets, if there are any
+struct Filters<'a>(&'a [&'static str]);
+
+impl Display for Filters<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return Ok(());
+        }
+
+        write!(f, " [{}]", self.0.join(", "))
+    }
+}
@@ -0,0 +1,117 @@
+//! This module contains [`ServiceHandle`], which is used to control handlers of the router service at runtime
+//! without rebuilding the service provider.
+//!
+//! Handlers are controlled by their names (see [`HandlerObject::name`]), so unnamed handlers can't be controlled.
+//! If several handlers have the same name, they are controlled together.
+//! Disabled handler is skipped as if its filters don't pass.
+//!
+//! It's useful for feature flags and kill-switches, for example, to disable a handler,
+//! which calls an unavailable external service:
+//! ```ignore
+//! let mut router = Router::new("main");
+//! router.message.register(weather).name("weather");
+//!
+//! let dispatcher = Dispatcher::builder().main_router(router).bot(bot).build();
+//! let dispatcher = dispatcher.to_service_provider_default()?;
+//!
+//! let handle = dispatcher.handle();
+//! handle.set_handler_enabled("weather", false);
+//! ```
+//!
+//! [`HandlerObject::name`]: crate::event::telegram::handler::HandlerObject#method.name
+
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+/// Switches of the handlers with the same name
+type Switches = Box<[Arc<AtomicBool>]>;
+
+/// Handle to enable and disable handlers of the router service by their names at runtime.
+/// Check the [module](self) documentation for more information.
+#[derive(Debug, Default, Clone)]
+pub struct ServiceHandle {
+    handlers: Arc<HashMap<Box<str>, Switches>>,
+}
+
+impl ServiceHandle {
+    pub(crate) fn new<'a>(handlers: impl IntoIterator<Item = (&'a str, Arc<AtomicBool>)>) -> Self {
+        let mut map: HashMap<Box<str>, Vec<Arc<AtomicBool>>> = HashMap::new();
+
+        for (name, enabled) in handlers {
+            map.entry(name.into()).or_default().push(enabled);
+        }
+
+        Self {
+            handlers: Arc::new(
+                map.into_iter()
+                    .map(|(name, switches)| (name, switches.into()))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Enable or disable all handlers with the name
+    /// # Returns
+    /// `true` if there is at least one handler with the name, otherwise `false`
+    pub fn set_handler_enabled(&self, name: &str, enabled: bool) -> bool {
+        let Some(switches) = self.handlers.get(name) else {
+            return false;
+        };
+
+        for switch in &**switches {
+            switch.store(enabled, Ordering::Relaxed);
+        }
+
+        true
+    }
+
+    /// Check if handlers with the name are enabled
+    /// # Returns
+    /// `None` if there are no handlers with the name,
+    /// otherwise `Some(true)` if at least one of them is enabled
+    #[must_use]
+    pub fn is_handler_enabled(&self, name: &str) -> Option<bool> {
+        self.handlers
+            .get(name)
+            .map(|switches| switches.iter().any(|switch| switch.load(Ordering::Relaxed)))
+    }
+
+    /// Get names of all handlers, which can be controlled by the handle
+    pub fn handler_names(&self) -> impl Iterator<Item = &str> {
+        self.handlers.keys().map(AsRef::as_ref)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_handler_enabled() {
+        let first = Arc::new(AtomicBool::new(true));
+        let second = Arc::new(AtomicBool::new(false));
+
+        let handle = ServiceHandle::new([
+            ("start", Arc::clone(&first)),
+            ("start", Arc::clone(&second)),
+        ]);
+
+        assert_eq!(handle.handler_names().collect::<Vec<_>>(), ["start"]);
+        assert_eq!(handle.is_handler_enabled("start"), Some(true));
+        assert_eq!(handle.is_handler_enabled("help"), None);
+
+        assert!(handle.set_handler_enabled("start", false));
+        assert!(!first.load(Ordering::Relaxed));
+        assert_eq!(handle.is_handler_enabled("start"), Some(false));
+
+        assert!(handle.clone().set_handler_enabled("start", true));
+        assert!(second.load(Ordering::Relaxed));
+
+        assert!(!handle.set_handler_enabled("help", true));
+    }
+}
@@ -0,0 +1,154 @@
+//! This module contains [`Plugin`] trait, which is used to distribute reusable features (moderation, captcha, stats, etc.)
+//! as bundles of handlers, middlewares and startup/shutdown hooks.
+//!
+//! Plugin is installed to the router with [`Router::install`] or [`Router::install_with`] methods.
+//! Each plugin is set up in its own sub router named by [`Plugin::name`], so it's shown in the [`Router::describe`] tree,
+//! receives inner middlewares of the parent router and can be gated by router filters as any other sub router.
+//! Plugin can be configured by [`PluginConfig`], which contains JSON values by keys, so plugins don't need
+//! to define their own config types to be configurable from files or environment.
+//!
+//! Implementation of the plugin looks like this:
+//! ```ignore
+//! struct Captcha;
+//!
+//! impl<Client> Plugin<Client> for Captcha
+//! where
+//!     Client: Send + Sync + 'static,
+//! {
+//!     fn name(&self) -> &'static str {
+//!         "captcha"
+//!     }
+//!
+//!     fn setup(&self, router: &mut Router<Client>, config: &PluginConfig) {
+//!         let timeout = config.get_as::<u64>("timeout").ok().flatten().unwrap_or(60);
+//!
+//!         router.chat_member.register(move |bot: Bot<Client>, update: ChatMemberUpdated| ask(bot, update, timeout));
+//!         router.callback_query.register(answer);
+//!         router.startup.register(on_startup, ());
+//!     }
+//! }
+//!
+//! let mut router = Router::new("main");
+//! router.install_with(Captcha, PluginConfig::new().value("timeout", 120));
+//! ```
+//!
+//! [`Router::install`]: super::Router#method.install
+//! [`Router::install_with`]: super::Router#method.install_with
+//! [`Router::describe`]: super::Router#method.describe
+
+use super::Router;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Plugin is a reusable bundle of handlers, middlewares and startup/shutdown hooks.
+/// Check the [module](self) documentation for more information.
+pub trait Plugin<Client>: Send + Sync {
+    /// Name of the plugin. It's used as name of the plugin router.
+    /// # Notes
+    /// By default, it's the type name of the plugin
+    fn name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Set up the plugin: register handlers, middlewares, filters and startup/shutdown hooks to the plugin router
+    /// # Arguments
+    /// * `router` - Router of the plugin, which is included to the router the plugin is installed to
+    /// * `config` - Config of the plugin
+    fn setup(&self, router: &mut Router<Client>, config: &PluginConfig);
+}
+
+/// Config of the [`Plugin`], which contains JSON values by keys
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PluginConfig {
+    values: HashMap<Box<str>, Value>,
+}
+
+impl PluginConfig {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the value by the key. If the key already exists, the value is replaced.
+    #[must_use]
+    pub fn value(self, key: impl Into<Box<str>>, val: impl Into<Value>) -> Self {
+        let mut values = self.values;
+        values.insert(key.into(), val.into());
+
+        Self { values }
+    }
+
+    /// Set the values by the keys. If a key already exists, the value is replaced.
+    #[must_use]
+    pub fn values<K, V, I>(self, val: I) -> Self
+    where
+        K: Into<Box<str>>,
+        V: Into<Value>,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let mut values = self.values;
+        values.extend(val.into_iter().map(|(key, val)| (key.into(), val.into())));
+
+        Self { values }
+    }
+
+    /// Get the value by the key
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.values.get(key)
+    }
+
+    /// Get the value by the key and deserialize it to the type
+    /// # Errors
+    /// If the value can't be deserialized to the type
+    pub fn get_as<T>(&self, key: &str) -> Result<Option<T>, serde_json::Error>
+    where
+        T: DeserializeOwned,
+    {
+        self.get(key)
+            .cloned()
+            .map(serde_json::from_value)
+            .transpose()
+    }
+
+    /// Check if the config contains the key
+    #[must_use]
+    pub fn contains(&self, key: &str) -> bool {
+        self.values.contains_key(key)
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for PluginConfig
+where
+    K: Into<Box<str>>,
+    V: Into<Value>,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self::new().values(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plugin_config() {
+        let config = PluginConfig::new()
+            .value("timeout", 60)
+            .value("message", "Hello")
+            .values([("timeout", 120)]);
+
+        assert_eq!(config.get_as::<u64>("timeout").unwrap(), Some(120));
+        assert_eq!(
+            config.get_as::<String>("message").unwrap().as_deref(),
+            Some("Hello")
+        );
+        assert_eq!(config.get_as::<u64>("unknown").unwrap(), None);
+        assert!(config.get_as::<u64>("message").is_err());
+        assert!(config.contains("message"));
+        assert!(!config.contains("unknown"));
+    }
+}
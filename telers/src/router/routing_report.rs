@@ -0,0 +1,137 @@
+//! This module contains [`RoutingReport`], which records how the update was routed:
+//! which routers, observers and handlers were tried and which filter rejected the update.
+//! It's useful to debug "my handler never fires" issues.
+//!
+//! Routing is traced only if the report is in the context by `routing_report` key.
+//! Enable [`Builder::trace_routing`] to put the report to the context for each update by the dispatcher,
+//! or put it to the context by yourself (for example, in tests).
+//! Each recorded step is also emitted as tracing event with `DEBUG` level.
+//!
+//! The report can be retrieved from the context after propagation with [`RoutingReport::from_context`] method
+//! or used as handler argument, and its [`Display`] implementation prints the recorded steps line by line.
+//!
+//! [`Builder::trace_routing`]: crate::dispatcher::Builder#method.trace_routing
+
+use crate::{context::Context, extractors::FromContext};
+
+use std::{
+    fmt::{self, Display, Formatter},
+    sync::{Arc, Mutex},
+};
+use tracing::{event, Level};
+
+/// Step of the update routing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RoutingStep {
+    /// Router is tried
+    Router { name: &'static str },
+    /// Router filter rejected the update, so the router and its sub routers are skipped
+    RouterFilterRejected { filter: &'static str },
+    /// Observer of the router is tried
+    Observer { name: &'static str },
+    /// Observer filter rejected the update, so all handlers of the observer are skipped
+    ObserverFilterRejected { filter: &'static str },
+    /// Handler is disabled (see [`ServiceHandle`](super::ServiceHandle))
+    HandlerDisabled { handler: Option<Box<str>> },
+    /// Handler filter rejected the update
+    HandlerFilterRejected {
+        handler: Option<Box<str>>,
+        filter: &'static str,
+    },
+    /// Handler passed the filters and was called
+    HandlerCalled { handler: Option<Box<str>> },
+}
+
+fn handler_name(handler: Option<&str>) -> &str {
+    handler.unwrap_or("<unnamed>")
+}
+
+impl Display for RoutingStep {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Router { name } => write!(f, "router {name}"),
+            Self::RouterFilterRejected { filter } => {
+                write!(f, "  router filter {filter} rejected the update")
+            }
+            Self::Observer { name } => write!(f, "  observer {name}"),
+            Self::ObserverFilterRejected { filter } => {
+                write!(f, "    observer filter {filter} rejected the update")
+            }
+            Self::HandlerDisabled { handler } => {
+                write!(
+                    f,
+                    "    handler {} is disabled",
+                    handler_name(handler.as_deref())
+                )
+            }
+            Self::HandlerFilterRejected { handler, filter } => write!(
+                f,
+                "    handler {}: filter {filter} rejected the update",
+                handler_name(handler.as_deref()),
+            ),
+            Self::HandlerCalled { handler } => {
+                write!(
+                    f,
+                    "    handler {} is called",
+                    handler_name(handler.as_deref())
+                )
+            }
+        }
+    }
+}
+
+/// Report about the update routing.
+/// Check the [module](self) documentation for more information.
+#[derive(Debug, Default, Clone, FromContext)]
+#[context(
+    key = "routing_report",
+    description = "Report about the update routing. This type is available only if routing tracing is enabled."
+)]
+pub struct RoutingReport {
+    steps: Arc<Mutex<Vec<RoutingStep>>>,
+}
+
+impl RoutingReport {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the report from the context, if routing tracing is enabled
+    #[must_use]
+    pub fn from_context(context: &Context) -> Option<Self> {
+        context
+            .get("routing_report")
+            .and_then(|report| report.downcast_ref::<Self>().cloned())
+    }
+
+    /// Record the step, if the report is in the context
+    pub(crate) fn record_in(context: &Context, step: impl FnOnce() -> RoutingStep) {
+        if let Some(report) = Self::from_context(context) {
+            report.record(step());
+        }
+    }
+
+    /// Record the step and emit it as tracing event
+    pub fn record(&self, step: RoutingStep) {
+        event!(Level::DEBUG, step = %step, "Routing step");
+
+        self.steps.lock().unwrap().push(step);
+    }
+
+    /// Get the recorded steps
+    #[must_use]
+    pub fn steps(&self) -> Vec<RoutingStep> {
+        self.steps.lock().unwrap().clone()
+    }
+}
+
+impl Display for RoutingReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for step in &*self.steps.lock().unwrap() {
+            writeln!(f, "{step}")?;
+        }
+
+        Ok(())
+    }
+}
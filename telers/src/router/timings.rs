@@ -0,0 +1,227 @@
+//! This module contains [`Timings`], which records how long each stage of the update processing takes:
+//! outer middlewares, filters, extraction of the handler arguments, inner middlewares and the handler itself.
+//! It's useful to find where the latency of the update processing goes.
+//!
+//! Stages are timed only if the timings are in the context by `timings` key.
+//! Enable [`Builder::trace_timings`] to put the timings to the context for each update by the dispatcher,
+//! or put them to the context by yourself (for example, in tests).
+//! Each recorded duration is also emitted as tracing event with `TRACE` level,
+//! and the dispatcher logs the breakdown of each update with `DEBUG` level.
+//!
+//! Durations of the same stage are summed up, for example, filters of all routers, observers and handlers,
+//! which are checked for the update, are recorded to [`Stage::Filters`].
+//! The timings can be retrieved from the context after propagation with [`Timings::from_context`] method
+//! or used as handler argument (but the handler itself isn't finished yet, so its duration isn't recorded).
+//!
+//! [`Builder::trace_timings`]: crate::dispatcher::Builder#method.trace_timings
+
+use crate::{context::Context, extractors::FromContext};
+
+use std::{
+    fmt::{self, Display, Formatter},
+    future::Future,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tracing::{event, Level};
+
+/// Stage of the update processing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stage {
+    /// Outer middlewares of the routers
+    OuterMiddlewares,
+    /// Filters of the routers, observers and handlers
+    Filters,
+    /// Extraction of the handler arguments
+    Extraction,
+    /// Handler call
+    Handler,
+    /// Inner middlewares of the observers without the handler and extraction of its arguments
+    InnerMiddlewares,
+}
+
+impl Stage {
+    pub const ALL: [Stage; 5] = [
+        Stage::OuterMiddlewares,
+        Stage::Filters,
+        Stage::Extraction,
+        Stage::Handler,
+        Stage::InnerMiddlewares,
+    ];
+
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Stage::OuterMiddlewares => "outer middlewares",
+            Stage::Filters => "filters",
+            Stage::Extraction => "extraction",
+            Stage::Handler => "handler",
+            Stage::InnerMiddlewares => "inner middlewares",
+        }
+    }
+
+    const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Durations of the update processing stages.
+/// Check the [module](self) documentation for more information.
+#[derive(Debug, Default, Clone, FromContext)]
+#[context(
+    key = "timings",
+    description = "Durations of the update processing stages. This type is available only if timings tracing is enabled."
+)]
+pub struct Timings {
+    durations: Arc<Mutex<[Duration; Stage::ALL.len()]>>,
+}
+
+impl Timings {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the timings from the context, if timings tracing is enabled
+    #[must_use]
+    pub fn from_context(context: &Context) -> Option<Self> {
+        context
+            .get("timings")
+            .and_then(|timings| timings.downcast_ref::<Self>().cloned())
+    }
+
+    /// Time the future as the stage, if the timings are in the context
+    pub(crate) async fn time_in<F: Future>(context: &Context, stage: Stage, fut: F) -> F::Output {
+        let Some(timings) = Self::from_context(context) else {
+            return fut.await;
+        };
+
+        let start = Instant::now();
+        let output = fut.await;
+        timings.record(stage, start.elapsed());
+
+        output
+    }
+
+    /// Time the function as the stage, if the timings are in the context
+    pub(crate) fn time_fn_in<T>(context: &Context, stage: Stage, f: impl FnOnce() -> T) -> T {
+        let Some(timings) = Self::from_context(context) else {
+            return f();
+        };
+
+        let start = Instant::now();
+        let output = f();
+        timings.record(stage, start.elapsed());
+
+        output
+    }
+
+    /// Time the future as the stage without durations of the nested stages, which are recorded while the future is running,
+    /// if the timings are in the context.
+    /// It's used for inner middlewares, which wrap extraction of the handler arguments and the handler itself.
+    pub(crate) async fn time_excluding_in<F: Future>(
+        context: &Context,
+        stage: Stage,
+        nested: &[Stage],
+        fut: F,
+    ) -> F::Output {
+        let Some(timings) = Self::from_context(context) else {
+            return fut.await;
+        };
+
+        let nested_sum = || {
+            nested
+                .iter()
+                .map(|stage| timings.get(*stage))
+                .sum::<Duration>()
+        };
+
+        let nested_before = nested_sum();
+        let start = Instant::now();
+        let output = fut.await;
+        let elapsed = start.elapsed();
+        let nested_elapsed = nested_sum().saturating_sub(nested_before);
+        timings.record(stage, elapsed.saturating_sub(nested_elapsed));
+
+        output
+    }
+
+    /// Add the duration to the stage and emit it as tracing event
+    pub fn record(&self, stage: Stage, duration: Duration) {
+        event!(Level::TRACE, %stage, ?duration, "Stage is timed");
+
+        self.durations.lock().unwrap()[stage.index()] += duration;
+    }
+
+    /// Get the summed up duration of the stage
+    #[must_use]
+    pub fn get(&self, stage: Stage) -> Duration {
+        self.durations.lock().unwrap()[stage.index()]
+    }
+
+    /// Get the summed up duration of all stages
+    #[must_use]
+    pub fn total(&self) -> Duration {
+        self.durations.lock().unwrap().iter().sum()
+    }
+}
+
+impl Display for Timings {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let durations = *self.durations.lock().unwrap();
+
+        for (index, stage) in Stage::ALL.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{stage}: {:?}", durations[stage.index()])?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_record() {
+        let context = Context::default();
+
+        // Timings aren't in the context, so nothing is recorded
+        assert_eq!(Timings::time_fn_in(&context, Stage::Filters, || 1), 1);
+        assert_eq!(
+            Timings::time_in(&context, Stage::Handler, async { 1 }).await,
+            1
+        );
+        assert!(Timings::from_context(&context).is_none());
+
+        let timings = Timings::new();
+        context.insert("timings", Box::new(timings.clone()));
+
+        timings.record(Stage::Filters, Duration::from_millis(1));
+        timings.record(Stage::Filters, Duration::from_millis(2));
+        Timings::time_in(&context, Stage::Handler, async {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        })
+        .await;
+
+        assert_eq!(timings.get(Stage::Filters), Duration::from_millis(3));
+        assert!(timings.get(Stage::Handler) >= Duration::from_millis(1));
+        assert_eq!(timings.get(Stage::Extraction), Duration::ZERO);
+        assert_eq!(
+            timings.total(),
+            timings.get(Stage::Filters) + timings.get(Stage::Handler)
+        );
+        assert!(timings
+            .to_string()
+            .starts_with("outer middlewares: 0ns, filters: 3ms, extraction: 0ns, handler: "));
+    }
+}
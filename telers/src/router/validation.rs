@@ -0,0 +1,308 @@
+//! This module contains validation of the router configuration, which is done by [`Router::validate`] method.
+//!
+//! The dispatcher validates the main router, when it's converted to the service, so misconfiguration is found on startup
+//! instead of silently misbehaving handlers. Check [`ConfigError`] for the list of the checks.
+//!
+//! Handlers in the errors are identified by their names (see [`HandlerObject::name`])
+//! or by their index in order of registration in the observer (for example, `#0`), if the name isn't set.
+//!
+//! [`Router::validate`]: super::Router#method.validate
+//! [`HandlerObject::name`]: crate::event::telegram::handler::HandlerObject#method.name
+
+use super::Router;
+
+use crate::{
+    enums::TelegramObserverName,
+    errors::ConfigError,
+    event::telegram::{handler::HandlerObject, Observer as TelegramObserver},
+};
+
+use std::collections::HashSet;
+
+fn handler_name<Client>(index: usize, handler: &HandlerObject<Client>) -> Box<str> {
+    handler
+        .handler_name()
+        .map_or_else(|| format!("#{index}").into(), Into::into)
+}
+
+/// Keys of the context, which are put by [`FSMContext`] middleware and read by FSM filters and extractors
+///
+/// [`FSMContext`]: crate::middlewares::outer::FSMContext
+#[cfg(feature = "fsm")]
+const FSM_CONTEXT_KEYS: [&str; 2] = ["fsm_context", "fsm_state"];
+
+/// Check if the handler uses FSM: has [`State`] filter or FSM extractors in arguments
+///
+/// [`State`]: crate::filters::State
+#[cfg(feature = "fsm")]
+fn uses_fsm<Client>(handler: &HandlerObject<Client>) -> bool {
+    handler
+        .context_keys()
+        .iter()
+        .any(|key| FSM_CONTEXT_KEYS.contains(key))
+}
+
+/// Check if [`FSMContext`] middleware is registered in the outer middlewares of the observer
+///
+/// [`FSMContext`]: crate::middlewares::outer::FSMContext
+#[cfg(feature = "fsm")]
+fn has_fsm_context<Client>(observer: &TelegramObserver<Client>) -> bool {
+    observer
+        .outer_middlewares
+        .middlewares
+        .iter()
+        .any(|middleware| {
+            let keys = middleware.provided_context_keys();
+            FSM_CONTEXT_KEYS.iter().all(|key| keys.contains(key))
+        })
+}
+
+/// Find handlers, which handle the same command and don't have other filters, so only the first of them is called.
+/// Disabled handlers are skipped, because they can be enabled instead of others at runtime.
+fn overlapping_commands<Client>(
+    router_name: &'static str,
+    observer: &TelegramObserver<Client>,
+    errors: &mut Vec<ConfigError>,
+) {
+    let mut handlers_by_command: Vec<(&str, Vec<Box<str>>)> = vec![];
+
+    for (index, handler) in observer.handlers().iter().enumerate() {
+        if !handler.is_enabled() || !handler.has_only_command_filters() {
+            continue;
+        }
+
+        for command in handler.menu_commands() {
+            let name = handler_name(index, handler);
+
+            match handlers_by_command
+                .iter_mut()
+                .find(|(other_command, _)| *other_command == &**command)
+            {
+                Some((_, handlers)) => handlers.push(name),
+                None => handlers_by_command.push((command, vec![name])),
+            }
+        }
+    }
+
+    errors.extend(
+        handlers_by_command
+            .into_iter()
+            .filter(|(_, handlers)| handlers.len() > 1)
+            .map(|(command, handlers)| ConfigError::OverlappingCommands {
+                router: router_name,
+                observer: observer.event_name.into(),
+                command: command.into(),
+                handlers: handlers.into(),
+            }),
+    );
+}
+
+impl<Client> Router<Client> {
+    /// Validate configuration of the router and its sub routers as the main router.
+    /// Check [`ConfigError`] for the list of the checks.
+    /// # Notes
+    /// This method is called by the dispatcher, when it's converted to the service,
+    /// so you don't need to call it manually, but it's useful for tests of the router configuration.
+    #[must_use]
+    pub fn validate(&self) -> Vec<ConfigError> {
+        let mut errors = vec![];
+
+        if self.describe().handlers().is_empty() {
+            errors.push(ConfigError::NoHandlers);
+        }
+
+        self.validate_with_parents(&HashSet::new(), &mut errors);
+
+        errors
+    }
+
+    /// # Arguments
+    /// * `parent_fsm_context` - Observers of the parent routers, for which [`FSMContext`] middleware is registered
+    ///
+    /// [`FSMContext`]: crate::middlewares::outer::FSMContext
+    fn validate_with_parents(
+        &self,
+        parent_fsm_context: &HashSet<TelegramObserverName>,
+        errors: &mut Vec<ConfigError>,
+    ) {
+        #[cfg(feature = "fsm")]
+        let fsm_context = {
+            let mut fsm_context = parent_fsm_context.clone();
+            for observer in self.telegram_observers() {
+                if has_fsm_context(observer) {
+                    fsm_context.insert(observer.event_name);
+                }
+            }
+            fsm_context
+        };
+        #[cfg(not(feature = "fsm"))]
+        let fsm_context = parent_fsm_context.clone();
+
+        for observer in self.telegram_observers() {
+            overlapping_commands(self.router_name, observer, errors);
+
+            #[cfg(feature = "fsm")]
+            {
+                // Outer middlewares of `update` observer are called for all events
+                let has_fsm_context = [observer.event_name, TelegramObserverName::Update]
+                    .iter()
+                    .any(|name| fsm_context.contains(name));
+
+                if !has_fsm_context {
+                    errors.extend(
+                        observer
+                            .handlers()
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, handler)| uses_fsm(handler))
+                            .map(|(index, handler)| ConfigError::MissingFSMContext {
+                                router: self.router_name,
+                                observer: observer.event_name.into(),
+                                handler: handler_name(index, handler),
+                            }),
+                    );
+                }
+            }
+        }
+
+        for router in &self.sub_routers {
+            router.validate_with_parents(&fsm_context, errors);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::Reqwest,
+        event::FlowControl,
+        filters::{ChatType, Command},
+    };
+
+    async fn handler() -> crate::event::telegram::HandlerResult {
+        Ok(FlowControl::Continue)
+    }
+
+    #[test]
+    fn test_no_handlers() {
+        let router = Router::<Reqwest>::new("main");
+        assert_eq!(router.validate(), [ConfigError::NoHandlers]);
+
+        let mut router = Router::<Reqwest>::new("main");
+        let mut sub_router = Router::new("sub");
+        sub_router.message.register(handler);
+        router.include(sub_router);
+        assert!(router.validate().is_empty());
+    }
+
+    #[test]
+    fn test_overlapping_commands() {
+        let mut router = Router::<Reqwest>::new("main");
+        router
+            .message
+            .register(handler)
+            .name("first")
+            .filter(Command::one("start"));
+        router
+            .message
+            .register(handler)
+            .filter(Command::many(["start", "help"]));
+        // Handlers with other filters aren't overlapping
+        router
+            .message
+            .register(handler)
+            .filter(Command::one("start"))
+            .filter(ChatType::one(crate::enums::ChatType::Private));
+        // Disabled handlers aren't overlapping
+        router
+            .message
+            .register(handler)
+            .filter(Command::one("start"))
+            .enabled(false);
+        router
+            .callback_query
+            .register(handler)
+            .filter(Command::one("start"));
+
+        assert_eq!(
+            router.validate(),
+            [ConfigError::OverlappingCommands {
+                router: "main",
+                observer: "message",
+                command: "start".into(),
+                handlers: ["first".into(), "#1".into()].into(),
+            }]
+        );
+    }
+
+    #[cfg(feature = "memory-storage")]
+    #[test]
+    fn test_missing_fsm_context() {
+        use crate::{
+            filters::State,
+            fsm::{Context as FSMContext, MemoryStorage},
+            middlewares::outer::FSMContext as FSMContextMiddleware,
+        };
+
+        async fn fsm_handler(
+            _: FSMContext<MemoryStorage>,
+        ) -> crate::event::telegram::HandlerResult {
+            Ok(FlowControl::Continue)
+        }
+
+        let router = || {
+            let mut router = Router::<Reqwest>::new("main");
+            router
+                .message
+                .register(handler)
+                .name("state")
+                .filter(State::one("state"));
+            router.callback_query.register(fsm_handler);
+            router
+        };
+
+        assert_eq!(
+            router().validate(),
+            [
+                ConfigError::MissingFSMContext {
+                    router: "main",
+                    observer: "message",
+                    handler: "state".into(),
+                },
+                ConfigError::MissingFSMContext {
+                    router: "main",
+                    observer: "callback_query",
+                    handler: "#0".into(),
+                },
+            ]
+        );
+
+        // Middleware is registered only for messages
+        let mut main_router = router();
+        main_router
+            .message
+            .outer_middlewares
+            .register(FSMContextMiddleware::new(MemoryStorage::new()));
+        assert_eq!(main_router.validate().len(), 1);
+
+        // Middleware is registered for all events in the parent router
+        let mut main_router = Router::<Reqwest>::new("parent");
+        main_router
+            .update
+            .outer_middlewares
+            .register(FSMContextMiddleware::new(MemoryStorage::new()));
+        main_router.include(router());
+        assert!(main_router.validate().is_empty());
+
+        // Middleware is registered after the handlers, in the sub router
+        let mut main_router = router();
+        let mut sub_router = Router::new("sub");
+        sub_router
+            .update
+            .outer_middlewares
+            .register(FSMContextMiddleware::new(MemoryStorage::new()));
+        main_router.include(sub_router);
+        assert_eq!(main_router.validate().len(), 2);
+    }
+}
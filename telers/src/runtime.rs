@@ -0,0 +1,149 @@
+//! This module contains [`Runtime`] trait, which abstracts the async runtime used by the dispatcher for background tasks and delays.
+//!
+//! [`Tokio`] runtime is used by default (with `tokio-runtime` feature, enabled by default).
+//! To use another runtime, for example, on `wasm32-unknown-unknown` target (e.g. Cloudflare Workers)
+//! or with `async-std`, implement [`Runtime`] and set it with [`DispatcherBuilder::runtime`].
+//!
+//! The trait is object safe and works with boxed futures, so the runtime is stored as `Arc<dyn Runtime>`
+//! and doesn't add generic parameters to the dispatcher.
+//! Spawned tasks are awaited and cancelled with [`RemoteHandle`] from `futures` crate,
+//! so the runtime only needs to poll the spawned future to completion.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use futures::future::BoxFuture;
+//! use std::time::Duration;
+//! use telers::runtime::Runtime;
+//!
+//! struct AsyncStd;
+//!
+//! impl Runtime for AsyncStd {
+//!     fn spawn(&self, future: BoxFuture<'static, ()>) {
+//!         // async_std::task::spawn(future);
+//!         # drop(future);
+//!     }
+//!
+//!     fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+//!         // Box::pin(async_std::task::sleep(duration))
+//!         # drop(duration);
+//!         # Box::pin(async {})
+//!     }
+//! }
+//! ```
+//!
+//! [`DispatcherBuilder::runtime`]: crate::dispatcher::Builder::runtime
+//! [`RemoteHandle`]: futures::future::RemoteHandle
+
+use futures::{
+    future::{BoxFuture, RemoteHandle},
+    stream::{self, BoxStream},
+    Future, FutureExt as _,
+};
+use std::{sync::Arc, time::Duration};
+
+/// Async runtime, which runs background tasks and delays.
+/// Check the [module](self) documentation for more information.
+pub trait Runtime: Send + Sync + 'static {
+    /// Spawns the future to run in the background.
+    /// The runtime must poll the future to completion or drop it on shutdown.
+    fn spawn(&self, future: BoxFuture<'static, ()>);
+
+    /// Creates a future, which completes after the duration
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()>;
+
+    /// Creates a stream, which yields every period.
+    /// The first item is yielded after the first period.
+    /// # Notes
+    /// Default implementation sleeps between items, so the period doesn't include the time of processing the item.
+    /// Override it if the runtime has the native interval.
+    fn interval(self: Arc<Self>, period: Duration) -> BoxStream<'static, ()> {
+        Box::pin(stream::unfold(self, move |runtime| async move {
+            runtime.sleep(period).await;
+
+            Some(((), runtime))
+        }))
+    }
+}
+
+impl dyn Runtime {
+    /// Spawns the future to run in the background and returns handle to get its output.
+    /// Dropping the handle cancels the future, use [`RemoteHandle::forget`] to run it without the handle.
+    pub fn spawn_with_handle<F>(&self, future: F) -> RemoteHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let (future, handle) = future.remote_handle();
+
+        self.spawn(Box::pin(future));
+
+        handle
+    }
+}
+
+/// [Tokio](https://tokio.rs) runtime. Tasks are spawned on the current tokio runtime.
+/// # Panics
+/// Spawn and sleep panic if they are called outside the tokio runtime
+#[cfg(feature = "tokio-runtime")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Tokio;
+
+#[cfg(feature = "tokio-runtime")]
+impl Runtime for Tokio {
+    fn spawn(&self, future: BoxFuture<'static, ()>) {
+        tokio::spawn(future);
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+/// Runtime, which is used when no runtime is set and `tokio-runtime` feature is disabled
+#[cfg(not(feature = "tokio-runtime"))]
+#[derive(Debug, Default, Clone, Copy)]
+struct Unset;
+
+#[cfg(not(feature = "tokio-runtime"))]
+impl Runtime for Unset {
+    fn spawn(&self, _future: BoxFuture<'static, ()>) {
+        panic!("Runtime isn't set. Enable `tokio-runtime` feature or set the runtime explicitly");
+    }
+
+    fn sleep(&self, _duration: Duration) -> BoxFuture<'static, ()> {
+        panic!("Runtime isn't set. Enable `tokio-runtime` feature or set the runtime explicitly");
+    }
+}
+
+/// Gets the default runtime: [`Tokio`] with `tokio-runtime` feature
+/// and the runtime, which panics on use, otherwise
+#[must_use]
+pub fn default() -> Arc<dyn Runtime> {
+    #[cfg(feature = "tokio-runtime")]
+    {
+        Arc::new(Tokio)
+    }
+    #[cfg(not(feature = "tokio-runtime"))]
+    {
+        Arc::new(Unset)
+    }
+}
+
+#[cfg(all(test, feature = "tokio-runtime"))]
+mod tests {
+    use super::*;
+
+    use futures::StreamExt as _;
+
+    #[tokio::test]
+    async fn test_tokio() {
+        let runtime = default();
+
+        assert_eq!(runtime.spawn_with_handle(async { 1 }).await, 1);
+
+        let mut interval = runtime.interval(Duration::from_millis(1));
+        interval.next().await;
+        interval.next().await;
+    }
+}
@@ -4,6 +4,11 @@
 //! do it for custom client and implement [`Session`] trait for it.
 //! You can check example of using custom client in bot in `examples/bot_http_client`.
 //!
+//! It also contains the buffer, which is used to deserialize the types, determined by their content (for example, [`Message`]).
+//!
 //! [`Session`]: crate::client::Session
+//! [`Message`]: crate::types::Message
 
+pub(crate) mod content;
+#[cfg(feature = "reqwest")]
 pub(crate) mod reqwest;
@@ -0,0 +1,360 @@
+//! Buffer of the deserialized value, which is used to inspect the value before deserializing it to the target type.
+//!
+//! It's similar to the buffer, which is used by serde for untagged enums, but it's deserialized only once,
+//! so the target type can be chosen by the content (for example, by the keys of the map) instead of trying each type in turn.
+//! Borrowed strings and bytes are kept borrowed, so the keys of the map aren't allocated.
+//! The content is deserialized by reference, so it can be deserialized again, if it's needed.
+
+use serde::{
+    de::{
+        self,
+        value::{
+            BorrowedStrDeserializer, MapAccessDeserializer, MapDeserializer, SeqDeserializer,
+            StrDeserializer,
+        },
+        Deserialize, Deserializer, IntoDeserializer, MapAccess, SeqAccess, Unexpected, Visitor,
+    },
+    forward_to_deserialize_any,
+};
+use std::{
+    fmt::{self, Formatter},
+    marker::PhantomData,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Content<'de> {
+    Bool(bool),
+    U64(u64),
+    I64(i64),
+    F64(f64),
+    String(String),
+    Str(&'de str),
+    ByteBuf(Vec<u8>),
+    Bytes(&'de [u8]),
+    None,
+    Some(Box<Content<'de>>),
+    Unit,
+    Seq(Vec<Content<'de>>),
+    Map(Vec<(Content<'de>, Content<'de>)>),
+}
+
+impl<'de> Content<'de> {
+    #[must_use]
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(val) => Some(val),
+            Self::Str(val) => Some(val),
+            _ => None,
+        }
+    }
+
+    /// Get keys of the map, which are strings
+    pub(crate) fn map_keys(&self) -> impl Iterator<Item = &str> {
+        let entries = match self {
+            Self::Map(entries) => entries.as_slice(),
+            _ => &[],
+        };
+
+        entries.iter().filter_map(|(key, _)| key.as_str())
+    }
+
+    #[must_use]
+    pub(crate) fn unexpected(&self) -> Unexpected<'_> {
+        match self {
+            Self::Bool(val) => Unexpected::Bool(*val),
+            Self::U64(val) => Unexpected::Unsigned(*val),
+            Self::I64(val) => Unexpected::Signed(*val),
+            Self::F64(val) => Unexpected::Float(*val),
+            Self::String(val) => Unexpected::Str(val),
+            Self::Str(val) => Unexpected::Str(val),
+            Self::ByteBuf(val) => Unexpected::Bytes(val),
+            Self::Bytes(val) => Unexpected::Bytes(val),
+            Self::None | Self::Some(_) => Unexpected::Option,
+            Self::Unit => Unexpected::Unit,
+            Self::Seq(_) => Unexpected::Seq,
+            Self::Map(_) => Unexpected::Map,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Content<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ContentVisitor;
+
+        impl<'de> Visitor<'de> for ContentVisitor {
+            type Value = Content<'de>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("any value")
+            }
+
+            fn visit_bool<E>(self, val: bool) -> Result<Self::Value, E> {
+                Ok(Content::Bool(val))
+            }
+
+            fn visit_i64<E>(self, val: i64) -> Result<Self::Value, E> {
+                Ok(Content::I64(val))
+            }
+
+            fn visit_u64<E>(self, val: u64) -> Result<Self::Value, E> {
+                Ok(Content::U64(val))
+            }
+
+            fn visit_f64<E>(self, val: f64) -> Result<Self::Value, E> {
+                Ok(Content::F64(val))
+            }
+
+            fn visit_str<E>(self, val: &str) -> Result<Self::Value, E> {
+                Ok(Content::String(val.to_owned()))
+            }
+
+            fn visit_borrowed_str<E>(self, val: &'de str) -> Result<Self::Value, E> {
+                Ok(Content::Str(val))
+            }
+
+            fn visit_string<E>(self, val: String) -> Result<Self::Value, E> {
+                Ok(Content::String(val))
+            }
+
+            fn visit_bytes<E>(self, val: &[u8]) -> Result<Self::Value, E> {
+                Ok(Content::ByteBuf(val.to_owned()))
+            }
+
+            fn visit_borrowed_bytes<E>(self, val: &'de [u8]) -> Result<Self::Value, E> {
+                Ok(Content::Bytes(val))
+            }
+
+            fn visit_byte_buf<E>(self, val: Vec<u8>) -> Result<Self::Value, E> {
+                Ok(Content::ByteBuf(val))
+            }
+
+            fn visit_none<E>(self) -> Result<Self::Value, E> {
+                Ok(Content::None)
+            }
+
+            fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                Content::deserialize(deserializer).map(|val| Content::Some(Box::new(val)))
+            }
+
+            fn visit_unit<E>(self) -> Result<Self::Value, E> {
+                Ok(Content::Unit)
+            }
+
+            fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                Content::deserialize(deserializer)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+
+                while let Some(value) = seq.next_element()? {
+                    values.push(value);
+                }
+
+                Ok(Content::Seq(values))
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut entries = Vec::with_capacity(map.size_hint().unwrap_or_default());
+
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+
+                Ok(Content::Map(entries))
+            }
+        }
+
+        deserializer.deserialize_any(ContentVisitor)
+    }
+}
+
+/// Deserializer of the buffered [`Content`] by reference, so the content can be deserialized again,
+/// for example, to get the path of the field, which can't be deserialized
+pub(crate) struct ContentRefDeserializer<'a, 'de, E> {
+    content: &'a Content<'de>,
+    marker: PhantomData<E>,
+}
+
+impl<'a, 'de, E> ContentRefDeserializer<'a, 'de, E> {
+    #[must_use]
+    pub(crate) const fn new(content: &'a Content<'de>) -> Self {
+        Self {
+            content,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, 'de, E> IntoDeserializer<'de, E> for &'a Content<'de>
+where
+    E: de::Error,
+{
+    type Deserializer = ContentRefDeserializer<'a, 'de, E>;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        ContentRefDeserializer::new(self)
+    }
+}
+
+impl<'a, 'de, E> Deserializer<'de> for ContentRefDeserializer<'a, 'de, E>
+where
+    E: de::Error,
+{
+    type Error = E;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.content {
+            Content::Bool(val) => visitor.visit_bool(*val),
+            Content::U64(val) => visitor.visit_u64(*val),
+            Content::I64(val) => visitor.visit_i64(*val),
+            Content::F64(val) => visitor.visit_f64(*val),
+            Content::String(val) => visitor.visit_str(val),
+            Content::Str(val) => visitor.visit_borrowed_str(val),
+            Content::ByteBuf(val) => visitor.visit_bytes(val),
+            Content::Bytes(val) => visitor.visit_borrowed_bytes(val),
+            Content::None => visitor.visit_none(),
+            Content::Some(val) => visitor.visit_some(ContentRefDeserializer::new(val)),
+            Content::Unit => visitor.visit_unit(),
+            Content::Seq(values) => {
+                let mut seq = SeqDeserializer::new(values.iter());
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+            Content::Map(entries) => {
+                let mut map = MapDeserializer::new(entries.iter().map(|(key, value)| (key, value)));
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.content {
+            Content::None | Content::Unit => visitor.visit_none(),
+            Content::Some(val) => visitor.visit_some(ContentRefDeserializer::new(val)),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.content {
+            Content::String(val) => visitor.visit_enum(StrDeserializer::new(val)),
+            Content::Str(val) => visitor.visit_enum(BorrowedStrDeserializer::new(val)),
+            Content::Map(entries) if entries.len() == 1 => {
+                visitor.visit_enum(MapAccessDeserializer::new(MapDeserializer::new(
+                    entries.iter().map(|(key, value)| (key, value)),
+                )))
+            }
+            content => Err(de::Error::invalid_type(
+                content.unexpected(),
+                &"string or map with a single key",
+            )),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    enum Kind {
+        Private,
+        Group,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Chat<'a> {
+        id: i64,
+        #[serde(rename = "type")]
+        kind: Kind,
+        title: Option<Box<str>>,
+        username: Option<&'a str>,
+        ids: Vec<u8>,
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let json = r#"{"id": -1, "type": "group", "title": "Escaped \"title\"", "username": "test", "ids": [1, 2], "unknown": {"key": null}}"#;
+        let content: Content = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            content.map_keys().collect::<Vec<_>>(),
+            ["id", "type", "title", "username", "ids", "unknown"]
+        );
+        // Keys and strings without escapes are borrowed
+        assert!(
+            matches!(&content, Content::Map(entries) if matches!(entries[0].0, Content::Str("id")))
+        );
+
+        let chat =
+            Chat::deserialize(ContentRefDeserializer::<serde_json::Error>::new(&content)).unwrap();
+        assert_eq!(
+            chat,
+            Chat {
+                id: -1,
+                kind: Kind::Group,
+                title: Some("Escaped \"title\"".into()),
+                username: Some("test"),
+                ids: vec![1, 2],
+            }
+        );
+
+        let content: Content =
+            serde_json::from_str(r#"{"id": 1, "type": "channel", "ids": []}"#).unwrap();
+        assert!(
+            Chat::deserialize(ContentRefDeserializer::<serde_json::Error>::new(&content)).is_err()
+        );
+    }
+}
@@ -0,0 +1,622 @@
+//! This module contains factories to fabricate realistic [`Update`]s in tests,
+//! so filters, middlewares and handlers can be exercised without hand-writing JSON or nested structures.
+//!
+//! All factories start from [`UpdateFactory`] and fill required fields with deterministic defaults:
+//! update and message ids are `1`, the user is a non-bot user with id `1` and the chat is a private chat with the user.
+//! Override only what matters for the test.
+//!
+//! Use [`request`] to wrap the update into [`Request`] with the default bot and empty context,
+//! which can be propagated through the router with [`PropagateEvent::propagate_event`].
+//!
+//! The module is available only with `test-utils` feature, enable it in `dev-dependencies`.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use telers::{test::UpdateFactory, types::UpdateKind};
+//!
+//! let update = UpdateFactory::message()
+//!     .text("hi")
+//!     .from_user(1)
+//!     .chat_private()
+//!     .build();
+//!
+//! assert!(matches!(update.kind, UpdateKind::Message(_)));
+//! assert_eq!(update.text(), Some("hi"));
+//!
+//! let update = UpdateFactory::message().command("start").chat_group(-100).build();
+//! assert_eq!(update.text(), Some("/start"));
+//! ```
+//!
+//! [`PropagateEvent::propagate_event`]: crate::router::PropagateEvent::propagate_event
+
+use crate::{
+    client::{Bot, Reqwest},
+    context::Context,
+    router::Request,
+    types::{
+        CallbackQuery, Chat, ChatChannel, ChatGroup, ChatPrivate, ChatSupergroup, InlineQuery,
+        MaybeInaccessibleMessage, Message, MessageEntity, MessageText, Update, UpdateKind, User,
+    },
+};
+
+use std::sync::Arc;
+
+/// Default id of the update, message and user
+pub const DEFAULT_ID: i64 = 1;
+
+/// Wrap the update into [`Request`] with the default bot and empty context
+#[must_use]
+pub fn request(update: impl Into<Update>) -> Request<Reqwest> {
+    Request::new(
+        Arc::new(Bot::default()),
+        Arc::new(update.into()),
+        Arc::new(Context::new()),
+    )
+}
+
+/// Entry point to the factories of the updates.
+/// Check the [module](self) documentation for more information.
+pub struct UpdateFactory;
+
+impl UpdateFactory {
+    /// Factory of `message` update
+    #[must_use]
+    pub fn message() -> MessageFactory {
+        MessageFactory::new(MessageUpdateKind::Message)
+    }
+
+    /// Factory of `edited_message` update
+    #[must_use]
+    pub fn edited_message() -> MessageFactory {
+        MessageFactory::new(MessageUpdateKind::EditedMessage)
+    }
+
+    /// Factory of `channel_post` update. The chat is a channel without the sender by default
+    #[must_use]
+    pub fn channel_post() -> MessageFactory {
+        MessageFactory::new(MessageUpdateKind::ChannelPost)
+            .chat_channel(-DEFAULT_ID)
+            .without_user()
+    }
+
+    /// Factory of `callback_query` update
+    #[must_use]
+    pub fn callback_query() -> CallbackQueryFactory {
+        CallbackQueryFactory::default()
+    }
+
+    /// Factory of `inline_query` update
+    #[must_use]
+    pub fn inline_query() -> InlineQueryFactory {
+        InlineQueryFactory::default()
+    }
+}
+
+/// Create the non-bot user with the id
+#[must_use]
+pub fn user(id: i64) -> User {
+    User {
+        id,
+        first_name: "Test".into(),
+        ..Default::default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MessageUpdateKind {
+    Message,
+    EditedMessage,
+    ChannelPost,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ChatKind {
+    /// Private chat with the sender
+    Private,
+    Custom(Chat),
+}
+
+/// Factory of the text message updates.
+/// Check the [module](self) documentation for more information.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageFactory {
+    kind: MessageUpdateKind,
+    update_id: i64,
+    message_id: i64,
+    thread_id: Option<i64>,
+    date: i64,
+    from: Option<User>,
+    chat: ChatKind,
+    text: Box<str>,
+    entities: Vec<MessageEntity>,
+    reply_to_message: Option<Message>,
+}
+
+impl MessageFactory {
+    fn new(kind: MessageUpdateKind) -> Self {
+        Self {
+            kind,
+            update_id: DEFAULT_ID,
+            message_id: DEFAULT_ID,
+            thread_id: None,
+            date: 0,
+            from: Some(user(DEFAULT_ID)),
+            chat: ChatKind::Private,
+            text: "".into(),
+            entities: vec![],
+            reply_to_message: None,
+        }
+    }
+
+    #[must_use]
+    pub fn update_id(self, val: i64) -> Self {
+        Self {
+            update_id: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn message_id(self, val: i64) -> Self {
+        Self {
+            message_id: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn thread_id(self, val: i64) -> Self {
+        Self {
+            thread_id: Some(val),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn date(self, val: i64) -> Self {
+        Self { date: val, ..self }
+    }
+
+    #[must_use]
+    pub fn text(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            text: val.into(),
+            ..self
+        }
+    }
+
+    /// Set text of the message to the command with the `bot_command` entity, as Telegram does
+    /// # Arguments
+    /// * `command` - Command without the prefix, for example, `start` or `start@bot`
+    #[must_use]
+    pub fn command(self, command: &str) -> Self {
+        self.command_with_args(command, "")
+    }
+
+    /// Set text of the message to the command with arguments and the `bot_command` entity, as Telegram does
+    /// # Arguments
+    /// * `command` - Command without the prefix, for example, `start` or `start@bot`
+    /// * `args` - Arguments of the command, separated from the command by a space. If empty, the space isn't added
+    #[must_use]
+    pub fn command_with_args(self, command: &str, args: &str) -> Self {
+        let text = if args.is_empty() {
+            format!("/{command}")
+        } else {
+            format!("/{command} {args}")
+        };
+        let length = (command.encode_utf16().count() + 1)
+            .try_into()
+            .unwrap_or(u16::MAX);
+
+        Self {
+            text: text.into(),
+            ..self
+        }
+        .entity(MessageEntity::new_bot_command(0, length))
+    }
+
+    #[must_use]
+    pub fn entity(self, val: MessageEntity) -> Self {
+        Self {
+            entities: self.entities.into_iter().chain(Some(val)).collect(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn from(self, val: User) -> Self {
+        Self {
+            from: Some(val),
+            ..self
+        }
+    }
+
+    /// Set the sender to the non-bot user with the id
+    #[must_use]
+    pub fn from_user(self, id: i64) -> Self {
+        self.from(user(id))
+    }
+
+    /// Remove the sender, for example, for channel posts
+    #[must_use]
+    pub fn without_user(self) -> Self {
+        Self { from: None, ..self }
+    }
+
+    #[must_use]
+    pub fn chat(self, val: Chat) -> Self {
+        Self {
+            chat: ChatKind::Custom(val),
+            ..self
+        }
+    }
+
+    /// Set the chat to the private chat with the sender
+    #[must_use]
+    pub fn chat_private(self) -> Self {
+        Self {
+            chat: ChatKind::Private,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn chat_group(self, id: i64) -> Self {
+        self.chat(Chat::Group(ChatGroup {
+            id,
+            title: "Test group".into(),
+        }))
+    }
+
+    #[must_use]
+    pub fn chat_supergroup(self, id: i64) -> Self {
+        self.chat(Chat::Supergroup(ChatSupergroup {
+            id,
+            title: "Test supergroup".into(),
+            username: None,
+            is_forum: None,
+        }))
+    }
+
+    /// Set the chat to the forum supergroup and the message to the topic
+    #[must_use]
+    pub fn chat_forum(self, id: i64, thread_id: i64) -> Self {
+        self.chat(Chat::Supergroup(ChatSupergroup {
+            id,
+            title: "Test forum".into(),
+            username: None,
+            is_forum: Some(true),
+        }))
+        .thread_id(thread_id)
+    }
+
+    #[must_use]
+    pub fn chat_channel(self, id: i64) -> Self {
+        self.chat(Chat::Channel(ChatChannel {
+            id,
+            title: "Test channel".into(),
+            username: None,
+        }))
+    }
+
+    #[must_use]
+    pub fn reply_to(self, val: Message) -> Self {
+        Self {
+            reply_to_message: Some(val),
+            ..self
+        }
+    }
+
+    /// Build only the message without the update
+    #[must_use]
+    pub fn build_message(&self) -> Message {
+        let chat = match &self.chat {
+            ChatKind::Private => {
+                let user = self.from.clone().unwrap_or_else(|| user(DEFAULT_ID));
+
+                Chat::Private(ChatPrivate {
+                    id: user.id,
+                    username: user.username,
+                    first_name: Some(user.first_name),
+                    last_name: user.last_name,
+                })
+            }
+            ChatKind::Custom(chat) => chat.clone(),
+        };
+
+        Message::Text(Box::new(MessageText {
+            id: self.message_id,
+            thread_id: self.thread_id,
+            is_topic_message: self.thread_id.map(|_| chat.is_forum()),
+            from: self.from.clone(),
+            date: self.date,
+            edit_date: (self.kind == MessageUpdateKind::EditedMessage).then_some(self.date),
+            chat,
+            text: self.text.clone(),
+            entities: (!self.entities.is_empty()).then(|| self.entities.clone().into()),
+            reply_to_message: self.reply_to_message.clone(),
+            ..Default::default()
+        }))
+    }
+
+    #[must_use]
+    pub fn build(&self) -> Update {
+        let message = self.build_message();
+
+        Update {
+            id: self.update_id,
+            kind: match self.kind {
+                MessageUpdateKind::Message => UpdateKind::Message(message),
+                MessageUpdateKind::EditedMessage => UpdateKind::EditedMessage(message),
+                MessageUpdateKind::ChannelPost => UpdateKind::ChannelPost(message),
+            },
+        }
+    }
+}
+
+impl From<MessageFactory> for Update {
+    fn from(factory: MessageFactory) -> Self {
+        factory.build()
+    }
+}
+
+/// Factory of the callback query updates.
+/// Check the [module](self) documentation for more information.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallbackQueryFactory {
+    update_id: i64,
+    id: Box<str>,
+    from: User,
+    data: Option<Box<str>>,
+    message: Option<Message>,
+}
+
+impl Default for CallbackQueryFactory {
+    fn default() -> Self {
+        Self {
+            update_id: DEFAULT_ID,
+            id: DEFAULT_ID.to_string().into(),
+            from: user(DEFAULT_ID),
+            data: None,
+            message: None,
+        }
+    }
+}
+
+impl CallbackQueryFactory {
+    #[must_use]
+    pub fn update_id(self, val: i64) -> Self {
+        Self {
+            update_id: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn id(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            id: val.into(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn data(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            data: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn from(self, val: User) -> Self {
+        Self { from: val, ..self }
+    }
+
+    /// Set the sender to the non-bot user with the id
+    #[must_use]
+    pub fn from_user(self, id: i64) -> Self {
+        self.from(user(id))
+    }
+
+    /// Set the message with the callback button, for example, built by [`MessageFactory::build_message`]
+    #[must_use]
+    pub fn message(self, val: Message) -> Self {
+        Self {
+            message: Some(val),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn build(&self) -> Update {
+        Update {
+            id: self.update_id,
+            kind: UpdateKind::CallbackQuery(CallbackQuery {
+                id: self.id.clone(),
+                from: self.from.clone(),
+                chat_instance: DEFAULT_ID.to_string().into(),
+                message: self.message.clone().map(MaybeInaccessibleMessage::Message),
+                data: self.data.clone(),
+                ..Default::default()
+            }),
+        }
+    }
+}
+
+impl From<CallbackQueryFactory> for Update {
+    fn from(factory: CallbackQueryFactory) -> Self {
+        factory.build()
+    }
+}
+
+/// Factory of the inline query updates.
+/// Check the [module](self) documentation for more information.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InlineQueryFactory {
+    update_id: i64,
+    id: Box<str>,
+    from: User,
+    query: Box<str>,
+    offset: Box<str>,
+}
+
+impl Default for InlineQueryFactory {
+    fn default() -> Self {
+        Self {
+            update_id: DEFAULT_ID,
+            id: DEFAULT_ID.to_string().into(),
+            from: user(DEFAULT_ID),
+            query: "".into(),
+            offset: "".into(),
+        }
+    }
+}
+
+impl InlineQueryFactory {
+    #[must_use]
+    pub fn update_id(self, val: i64) -> Self {
+        Self {
+            update_id: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn id(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            id: val.into(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn query(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            query: val.into(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn offset(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            offset: val.into(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn from(self, val: User) -> Self {
+        Self { from: val, ..self }
+    }
+
+    /// Set the sender to the non-bot user with the id
+    #[must_use]
+    pub fn from_user(self, id: i64) -> Self {
+        self.from(user(id))
+    }
+
+    #[must_use]
+    pub fn build(&self) -> Update {
+        Update {
+            id: self.update_id,
+            kind: UpdateKind::InlineQuery(InlineQuery {
+                id: self.id.clone(),
+                from: self.from.clone(),
+                query: self.query.clone(),
+                offset: self.offset.clone(),
+                ..Default::default()
+            }),
+        }
+    }
+}
+
+impl From<InlineQueryFactory> for Update {
+    fn from(factory: InlineQueryFactory) -> Self {
+        factory.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        enums::UpdateType,
+        event::{bases::PropagateEventResult, FlowControl, ToServiceProvider as _},
+        filters::Command,
+        router::{PropagateEvent as _, Router},
+    };
+
+    #[test]
+    fn test_message() {
+        let update = UpdateFactory::message().text("hi").from_user(2).build();
+
+        assert_eq!(update.text(), Some("hi"));
+        assert_eq!(update.from_id(), Some(2));
+        assert_eq!(update.chat_id(), Some(2));
+
+        let update = UpdateFactory::message().chat_forum(-100, 5).build();
+        assert_eq!(update.message_thread_id(), Some(5));
+        assert!(update.chat().unwrap().is_forum());
+
+        let update = UpdateFactory::channel_post().text("post").build();
+        assert!(matches!(update.kind, UpdateKind::ChannelPost(_)));
+        assert!(update.from().is_none());
+
+        let update = UpdateFactory::message()
+            .command_with_args("start", "ref")
+            .build();
+        assert_eq!(update.text(), Some("/start ref"));
+    }
+
+    #[test]
+    fn test_callback_query() {
+        let update = UpdateFactory::callback_query()
+            .data("data")
+            .message(UpdateFactory::message().build_message())
+            .build();
+
+        let UpdateKind::CallbackQuery(callback_query) = update.kind else {
+            panic!("Unexpected update kind");
+        };
+        assert_eq!(callback_query.data.as_deref(), Some("data"));
+        assert!(callback_query.message.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_propagate() {
+        let mut router = Router::new("main");
+        router
+            .message
+            .register(|| async { Ok(FlowControl::Continue) })
+            .filter(Command::one("start"));
+
+        let router = router.to_service_provider_default().unwrap();
+
+        let response = router
+            .propagate_event(
+                UpdateType::Message,
+                request(UpdateFactory::message().command("start")),
+            )
+            .await
+            .unwrap();
+        assert!(matches!(
+            response.propagate_result,
+            PropagateEventResult::Handled(_)
+        ));
+
+        let response = router
+            .propagate_event(
+                UpdateType::Message,
+                request(UpdateFactory::message().text("start")),
+            )
+            .await
+            .unwrap();
+        assert!(matches!(
+            response.propagate_result,
+            PropagateEventResult::Unhandled
+        ));
+    }
+}
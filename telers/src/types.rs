@@ -39,6 +39,7 @@
 
 pub mod animation;
 pub mod audio;
+pub mod birthdate;
 pub mod bot_command;
 pub mod bot_command_scope;
 pub mod bot_command_scope_all_chat_administrators;
@@ -51,6 +52,10 @@ pub mod bot_command_scope_default;
 pub mod bot_description;
 pub mod bot_name;
 pub mod bot_short_description;
+pub mod business_intro;
+pub mod business_location;
+pub mod business_opening_hours;
+pub mod business_opening_hours_interval;
 pub mod callback_game;
 pub mod callback_query;
 pub mod chat;
@@ -63,6 +68,7 @@ pub mod chat_boost_source_gift_code;
 pub mod chat_boost_source_giveaway;
 pub mod chat_boost_source_premium;
 pub mod chat_boost_updated;
+pub mod chat_full_info;
 pub mod chat_id_kind;
 pub mod chat_invite_link;
 pub mod chat_join_request;
@@ -137,6 +143,9 @@ pub mod input_media_document;
 pub mod input_media_photo;
 pub mod input_media_video;
 pub mod input_message_content;
+pub mod input_paid_media;
+pub mod input_paid_media_photo;
+pub mod input_paid_media_video;
 pub mod input_sticker;
 pub mod input_text_message_content;
 pub mod input_venue_message_content;
@@ -168,6 +177,11 @@ pub mod message_origin_user;
 pub mod message_reaction_count_updated;
 pub mod message_reaction_updated;
 pub mod order_info;
+pub mod paid_media;
+pub mod paid_media_info;
+pub mod paid_media_photo;
+pub mod paid_media_preview;
+pub mod paid_media_video;
 pub mod passport_data;
 pub mod passport_element_error;
 pub mod passport_element_error_data_field;
@@ -226,6 +240,7 @@ pub mod write_access_allowed;
 
 pub use animation::Animation;
 pub use audio::Audio;
+pub use birthdate::Birthdate;
 pub use bot_command::BotCommand;
 pub use bot_command_scope::BotCommandScope;
 pub use bot_command_scope_all_chat_administrators::BotCommandScopeAllChatAdministrators;
@@ -238,6 +253,10 @@ pub use bot_command_scope_default::BotCommandScopeDefault;
 pub use bot_description::BotDescription;
 pub use bot_name::BotName;
 pub use bot_short_description::BotShortDescription;
+pub use business_intro::BusinessIntro;
+pub use business_location::BusinessLocation;
+pub use business_opening_hours::BusinessOpeningHours;
+pub use business_opening_hours_interval::BusinessOpeningHoursInterval;
 pub use callback_game::CallbackGame;
 pub use callback_query::CallbackQuery;
 pub use chat::{
@@ -253,6 +272,10 @@ pub use chat_boost_source_gift_code::ChatBoostSourceGiftCode;
 pub use chat_boost_source_giveaway::ChatBoostSourceGiveaway;
 pub use chat_boost_source_premium::ChatBoostSourcePremium;
 pub use chat_boost_updated::ChatBoostUpdated;
+pub use chat_full_info::{
+    Channel as ChatFullInfoChannel, ChatFullInfo, Group as ChatFullInfoGroup,
+    Private as ChatFullInfoPrivate, Supergroup as ChatFullInfoSupergroup,
+};
 pub use chat_id_kind::ChatIdKind;
 pub use chat_invite_link::ChatInviteLink;
 pub use chat_join_request::ChatJoinRequest;
@@ -340,10 +363,11 @@ pub use inline_query_result_video::InlineQueryResultVideo;
 pub use inline_query_result_voice::InlineQueryResultVoice;
 pub use inline_query_results_button::InlineQueryResultsButton;
 pub use input_contact_message_content::InputContactMessageContent;
+#[cfg(feature = "client")]
 pub use input_file::{
-    BufferedFile as InputBufferedFile, FSFile as InputFSFile, FileId as InputFileId, InputFile,
-    StreamFile as InputStreamFile, UrlFile as InputUrlFile,
+    BufferedFile as InputBufferedFile, FSFile as InputFSFile, StreamFile as InputStreamFile,
 };
+pub use input_file::{FileId as InputFileId, InputFile, UrlFile as InputUrlFile};
 pub use input_invoice_message_content::InputInvoiceMessageContent;
 pub use input_location_message_content::InputLocationMessageContent;
 pub use input_media::InputMedia;
@@ -353,6 +377,9 @@ pub use input_media_document::InputMediaDocument;
 pub use input_media_photo::InputMediaPhoto;
 pub use input_media_video::InputMediaVideo;
 pub use input_message_content::InputMessageContent;
+pub use input_paid_media::InputPaidMedia;
+pub use input_paid_media_photo::InputPaidMediaPhoto;
+pub use input_paid_media_video::InputPaidMediaVideo;
 pub use input_sticker::InputSticker;
 pub use input_text_message_content::InputTextMessageContent;
 pub use input_venue_message_content::InputVenueMessageContent;
@@ -387,10 +414,11 @@ pub use message::{
     MessageAutoDeleteTimerChanged as MessageMessageAutoDeleteTimerChanged,
     MigrateFromChat as MessageMigrateFromChat, MigrateToChat as MessageMigrateToChat,
     NewChatMembers as MessageNewChatMembers, NewChatPhoto as MessageNewChatPhoto,
-    NewChatTitle as MessageNewChatTitle, PassportData as MessagePassportData,
-    Photo as MessagePhoto, Pinned as MessagePinned, Poll as MessagePoll,
-    ProximityAlertTriggered as MessageProximityAlertTriggered, Sticker as MessageSticker,
-    Story as MessageStory, SuccessfulPayment as MessageSuccessfulPayment,
+    NewChatTitle as MessageNewChatTitle, PaidMedia as MessagePaidMedia,
+    PassportData as MessagePassportData, Photo as MessagePhoto, Pinned as MessagePinned,
+    Poll as MessagePoll, ProximityAlertTriggered as MessageProximityAlertTriggered,
+    Sticker as MessageSticker, Story as MessageStory,
+    SuccessfulPayment as MessageSuccessfulPayment,
     SupergroupChatCreated as MessageSupergroupChatCreated, Text as MessageText,
     UsersShared as MessageUsersShared, Venue as MessageVenue, Video as MessageVideo,
     VideoChatEnded as MessageVideoChatEnded,
@@ -415,6 +443,11 @@ pub use message_origin_user::MessageOriginUser;
 pub use message_reaction_count_updated::MessageReactionCountUpdated;
 pub use message_reaction_updated::MessageReactionUpdated;
 pub use order_info::OrderInfo;
+pub use paid_media::PaidMedia;
+pub use paid_media_info::PaidMediaInfo;
+pub use paid_media_photo::PaidMediaPhoto;
+pub use paid_media_preview::PaidMediaPreview;
+pub use paid_media_video::PaidMediaVideo;
 pub use passport_data::PassportData;
 pub use passport_element_error::PassportElementError;
 pub use passport_element_error_data_field::{
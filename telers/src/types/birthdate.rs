@@ -0,0 +1,14 @@
+use serde::Deserialize;
+
+/// Describes the birthdate of a user.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#birthdate>
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Deserialize)]
+pub struct Birthdate {
+    /// Day of the user's birth; 1-31
+    pub day: i64,
+    /// Month of the user's birth; 1-12
+    pub month: i64,
+    /// Year of the user's birth
+    pub year: Option<i64>,
+}
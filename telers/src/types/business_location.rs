@@ -0,0 +1,14 @@
+use super::Location;
+
+use serde::Deserialize;
+
+/// Contains information about the location of a Telegram Business account.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#businesslocation>
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+pub struct BusinessLocation {
+    /// Address of the business
+    pub address: Box<str>,
+    /// Location of the business
+    pub location: Option<Location>,
+}
@@ -0,0 +1,26 @@
+use super::BusinessOpeningHoursInterval;
+
+use serde::Deserialize;
+
+/// Describes the opening hours of a business.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#businessopeninghours>
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize)]
+pub struct BusinessOpeningHours {
+    /// Unique name of the time zone for which the opening hours are defined
+    pub time_zone_name: Box<str>,
+    /// List of time intervals describing business opening hours
+    pub opening_hours: Box<[BusinessOpeningHoursInterval]>,
+}
+
+impl BusinessOpeningHours {
+    /// Check if the business is open at the given minute of the week in the time zone of the business
+    /// # Arguments
+    /// * `minute` - The minute's sequence number in a week, starting on Monday, marking the time; 0 - 7 * 24 * 60
+    #[must_use]
+    pub fn is_open_at(&self, minute: i64) -> bool {
+        self.opening_hours
+            .iter()
+            .any(|interval| interval.contains(minute))
+    }
+}
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+/// Describes an interval of time during which a business is open.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#businessopeninghoursinterval>
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Deserialize)]
+pub struct BusinessOpeningHoursInterval {
+    /// The minute's sequence number in a week, starting on Monday, marking the start of the time interval during which the business is open; 0 - 7 * 24 * 60
+    pub opening_minute: i64,
+    /// The minute's sequence number in a week, starting on Monday, marking the end of the time interval during which the business is open; 0 - 8 * 24 * 60
+    pub closing_minute: i64,
+}
+
+impl BusinessOpeningHoursInterval {
+    /// Check if the interval contains the given minute of the week
+    #[must_use]
+    pub const fn contains(&self, minute: i64) -> bool {
+        self.opening_minute <= minute && minute < self.closing_minute
+    }
+}
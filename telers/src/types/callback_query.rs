@@ -1,6 +1,6 @@
-use super::{InaccessibleMessage, MaybeInaccessibleMessage, Update, UpdateKind, User};
+use super::{Chat, MaybeInaccessibleMessage, Message, Update, UpdateKind, User};
 
-use crate::{errors::ConvertToTypeError, FromEvent};
+use crate::errors::ConvertToTypeError;
 
 use serde::Deserialize;
 
@@ -8,8 +8,8 @@ use serde::Deserialize;
 /// **NOTE:** After the user presses a callback button, Telegram clients will display a progress bar until you call [`AnswerCallbackQuery`](crate::methods::AnswerCallbackQuery). It is, therefore, necessary to react by calling [`AnswerCallbackQuery`](crate::methods::AnswerCallbackQuery) even if no notification to the user is needed (e.g., without specifying any of the optional parameters).
 /// # Documentation
 /// <https://core.telegram.org/bots/api#callbackquery>
-#[derive(Debug, Default, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct CallbackQuery {
     /// Unique identifier for this query
     pub id: Box<str>,
@@ -28,68 +28,53 @@ pub struct CallbackQuery {
 }
 
 impl CallbackQuery {
+    /// Gets the message with the callback button, if it's present and accessible to the bot
+    #[must_use]
+    pub const fn accessible_message(&self) -> Option<&Message> {
+        match &self.message {
+            Some(message) => message.as_message(),
+            None => None,
+        }
+    }
+
+    /// Gets chat of the message with the callback button, which is available even if the message is inaccessible
+    #[must_use]
+    pub const fn chat(&self) -> Option<&Chat> {
+        match &self.message {
+            Some(message) => Some(message.chat()),
+            None => None,
+        }
+    }
+
     #[must_use]
     pub const fn chat_id(&self) -> Option<i64> {
-        if let Some(message) = &self.message {
-            match message {
-                MaybeInaccessibleMessage::Message(message) => Some(message.chat().id()),
-                MaybeInaccessibleMessage::InaccessibleMessage(InaccessibleMessage {
-                    chat, ..
-                }) => Some(chat.id()),
-            }
-        } else {
-            None
+        match &self.message {
+            Some(message) => Some(message.chat_id()),
+            None => None,
         }
     }
 
     #[must_use]
     pub const fn message_id(&self) -> Option<i64> {
-        if let Some(message) = &self.message {
-            match message {
-                MaybeInaccessibleMessage::Message(message) => Some(message.id()),
-                MaybeInaccessibleMessage::InaccessibleMessage(InaccessibleMessage {
-                    id, ..
-                }) => Some(*id),
-            }
-        } else {
-            None
+        match &self.message {
+            Some(message) => Some(message.id()),
+            None => None,
         }
     }
 
     #[must_use]
     pub fn message_text(&self) -> Option<&str> {
-        if let Some(message) = &self.message {
-            match message {
-                MaybeInaccessibleMessage::Message(message) => message.text(),
-                MaybeInaccessibleMessage::InaccessibleMessage(_) => None,
-            }
-        } else {
-            None
-        }
+        self.accessible_message().and_then(Message::text)
     }
 
     #[must_use]
     pub fn message_caption(&self) -> Option<&str> {
-        if let Some(message) = &self.message {
-            match message {
-                MaybeInaccessibleMessage::Message(message) => message.caption(),
-                MaybeInaccessibleMessage::InaccessibleMessage(_) => None,
-            }
-        } else {
-            None
-        }
+        self.accessible_message().and_then(Message::caption)
     }
 
     #[must_use]
     pub fn message_text_or_caption(&self) -> Option<&str> {
-        if let Some(message) = &self.message {
-            match message {
-                MaybeInaccessibleMessage::Message(message) => message.text_or_caption(),
-                MaybeInaccessibleMessage::InaccessibleMessage(_) => None,
-            }
-        } else {
-            None
-        }
+        self.accessible_message().and_then(Message::text_or_caption)
     }
 }
 
@@ -103,3 +88,14 @@ impl TryFrom<Update> for CallbackQuery {
         }
     }
 }
+
+impl TryFrom<&Update> for CallbackQuery {
+    type Error = ConvertToTypeError;
+
+    fn try_from(update: &Update) -> Result<Self, Self::Error> {
+        match &update.kind {
+            UpdateKind::CallbackQuery(val) => Ok(val.clone()),
+            _ => Err(ConvertToTypeError::new("Update", "CallbackQuery")),
+        }
+    }
+}
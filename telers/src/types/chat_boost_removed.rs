@@ -1,14 +1,14 @@
 use super::{Chat, ChatBoostSource, Update, UpdateKind};
 
-use crate::{errors::ConvertToTypeError, FromEvent};
+use crate::errors::ConvertToTypeError;
 
 use serde::Deserialize;
 
 /// This object represents a boost removed from a chat.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#chatboostremoved>
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct ChatBoostRemoved {
     /// Chat which was boosted
     pub chat: Chat,
@@ -31,3 +31,14 @@ impl TryFrom<Update> for ChatBoostRemoved {
         }
     }
 }
+
+impl TryFrom<&Update> for ChatBoostRemoved {
+    type Error = ConvertToTypeError;
+
+    fn try_from(update: &Update) -> Result<Self, Self::Error> {
+        match &update.kind {
+            UpdateKind::RemovedChatBoost(val) => Ok(val.clone()),
+            _ => Err(ConvertToTypeError::new("Update", "ChatBoostRemoved")),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use super::{ChatBoostSourceGiftCode, ChatBoostSourceGiveaway, ChatBoostSourcePremium};
+use super::{ChatBoostSourceGiftCode, ChatBoostSourceGiveaway, ChatBoostSourcePremium, User};
 
 use serde::Deserialize;
 
@@ -16,6 +16,19 @@ pub enum ChatBoostSource {
     Giveaway(ChatBoostSourceGiveaway),
 }
 
+impl ChatBoostSource {
+    /// Gets user, who boosted the chat.
+    /// For giveaways it's the winner of the giveaway, if any.
+    #[must_use]
+    pub const fn user(&self) -> Option<&User> {
+        match self {
+            Self::Premium(ChatBoostSourcePremium { user })
+            | Self::GiftCode(ChatBoostSourceGiftCode { user }) => Some(user),
+            Self::Giveaway(ChatBoostSourceGiveaway { user, .. }) => user.as_ref(),
+        }
+    }
+}
+
 impl From<ChatBoostSourcePremium> for ChatBoostSource {
     #[must_use]
     fn from(source: ChatBoostSourcePremium) -> Self {
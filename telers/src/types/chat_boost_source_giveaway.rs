@@ -2,7 +2,7 @@ use super::User;
 
 use serde::Deserialize;
 
-/// The boost was obtained by the creation of a Telegram Premium giveaway. This boosts the chat 4 times for the duration of the corresponding Telegram Premium subscription.
+/// The boost was obtained by the creation of a Telegram Premium or a Telegram Star giveaway. This boosts the chat 4 times for the duration of the corresponding Telegram Premium subscription for Telegram Premium giveaways and *prize_star_count* / 500 times for one year for Telegram Star giveaways.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#chatboostsourcegiveaway>
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize)]
@@ -11,6 +11,8 @@ pub struct ChatBoostSourceGiveaway {
     pub giveaway_message_id: i64,
     /// User that won the prize in the giveaway if any
     pub user: Option<User>,
+    /// The number of Telegram Stars to be split between giveaway winners; for Telegram Star giveaways only
+    pub prize_star_count: Option<i64>,
     /// `true`, if the giveaway was completed, but there was no user to win the prize
     pub is_unclaimed: Option<bool>,
 }
@@ -1,14 +1,14 @@
 use super::{Chat, ChatBoostSource, Update, UpdateKind};
 
-use crate::{errors::ConvertToTypeError, FromEvent};
+use crate::errors::ConvertToTypeError;
 
 use serde::Deserialize;
 
 /// This object represents a boost added to a chat or changed.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#chatboostupdated>
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct ChatBoostUpdated {
     /// Chat which was boosted
     pub chat: Chat,
@@ -26,3 +26,14 @@ impl TryFrom<Update> for ChatBoostUpdated {
         }
     }
 }
+
+impl TryFrom<&Update> for ChatBoostUpdated {
+    type Error = ConvertToTypeError;
+
+    fn try_from(update: &Update) -> Result<Self, Self::Error> {
+        match &update.kind {
+            UpdateKind::ChatBoost(val) => Ok(val.clone()),
+            _ => Err(ConvertToTypeError::new("Update", "ChatBoostUpdated")),
+        }
+    }
+}
@@ -0,0 +1,757 @@
+use super::{
+    Birthdate, BusinessIntro, BusinessLocation, BusinessOpeningHours, Chat, ChatChannel, ChatGroup,
+    ChatLocation, ChatPermissions, ChatPhoto, ChatPrivate, ChatSupergroup, Message, ReactionType,
+};
+
+use serde::Deserialize;
+
+/// This object contains full information about a chat.
+/// It's returned by [`GetChat`](crate::methods::GetChat), lightweight information about the chat in updates is represented by [`Chat`].
+/// # Documentation
+/// <https://core.telegram.org/bots/api#chatfullinfo>
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChatFullInfo {
+    Private(Private),
+    Group(Group),
+    Supergroup(Supergroup),
+    Channel(Channel),
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+pub struct Private {
+    /// Unique identifier for this chat. This number may have more than 32 significant bits and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so a signed 64-bit integer or double-precision float type are safe for storing this identifier.
+    pub id: i64,
+    /// Username
+    pub username: Option<Box<str>>,
+    /// First name of the other party
+    pub first_name: Option<Box<str>>,
+    /// Last name of the other party
+    pub last_name: Option<Box<str>>,
+    /// Chat photo
+    pub photo: Option<ChatPhoto>,
+    /// If non-empty, the list of all [active chat usernames](https://telegram.org/blog/topics-in-groups-collectible-usernames/ru?ln=a#collectible-usernames)
+    pub active_usernames: Option<Box<[Box<str>]>>,
+    /// The date of birth of the user
+    pub birthdate: Option<Birthdate>,
+    /// The intro of the business
+    pub business_intro: Option<Box<BusinessIntro>>,
+    /// The location of the business
+    pub business_location: Option<BusinessLocation>,
+    /// The opening hours of the business
+    pub business_opening_hours: Option<BusinessOpeningHours>,
+    /// The personal channel of the user
+    pub personal_chat: Option<Chat>,
+    /// Identifier of the accent color for the chat name and backgrounds of the chat photo, reply header, and link preview. See [accent colors](https://core.telegram.org/bots/api#accent-colors) for more details.
+    pub accent_color_id: i64,
+    /// The maximum number of reactions that can be set on a message in the chat
+    pub max_reaction_count: i64,
+    /// Custom emoji identifier of emoji chosen by the chat for the reply header and link preview background
+    pub background_custom_emoji_id: Option<Box<str>>,
+    /// Identifier of the accent color for the chat's profile background. See [profile accent colors](https://core.telegram.org/bots/api#profile-accent-colors) for more details.
+    pub profile_accent_color_id: Option<i64>,
+    /// Custom emoji identifier of the emoji chosen by the chat for its profile background
+    pub profile_background_custom_emoji_id: Option<Box<str>>,
+    /// Custom emoji identifier of emoji status of the other party
+    pub emoji_status_custom_emoji_id: Option<Box<str>>,
+    /// Expiration date of the emoji status of the other party in Unix time, if any
+    pub emoji_status_expiration_date: Option<i64>,
+    /// Bio of the other party
+    pub bio: Option<Box<str>>,
+    /// `true`, if privacy settings of the other party allows to use `tg://user?id=<user_id>` links only in chats with the user
+    pub has_private_forwards: Option<bool>,
+    /// `true`, if the privacy settings of the other party restrict sending voice and video note messages
+    pub has_restricted_voice_and_video_messages: Option<bool>,
+    /// The most recent pinned message (by sending date)
+    pub pinned_message: Option<Message>,
+    /// The time after which all messages sent to the chat will be automatically deleted; in seconds
+    pub message_auto_delete_time: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Group {
+    /// Unique identifier for this chat. This number may have more than 32 significant bits and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so a signed 64-bit integer or double-precision float type are safe for storing this identifier.
+    pub id: i64,
+    /// Title
+    pub title: Box<str>,
+    /// Chat photo
+    pub photo: Option<ChatPhoto>,
+    /// Identifier of the accent color for the chat name and backgrounds of the chat photo, reply header, and link preview. See [accent colors](https://core.telegram.org/bots/api#accent-colors) for more details.
+    pub accent_color_id: i64,
+    /// The maximum number of reactions that can be set on a message in the chat
+    pub max_reaction_count: i64,
+    /// Custom emoji identifier of emoji chosen by the chat for the reply header and link preview background
+    pub background_custom_emoji_id: Option<Box<str>>,
+    /// Identifier of the accent color for the chat's profile background. See [profile accent colors](https://core.telegram.org/bots/api#profile-accent-colors) for more details.
+    pub profile_accent_color_id: Option<i64>,
+    /// Custom emoji identifier of the emoji chosen by the chat for its profile background
+    pub profile_background_custom_emoji_id: Option<Box<str>>,
+    /// List of available reactions allowed in the chat. If omitted, then all [emoji reactions](https://core.telegram.org/bots/api#reactiontypeemoji) are allowed.
+    pub available_reactions: Option<Box<[ReactionType]>>,
+    /// Description
+    pub description: Option<Box<str>>,
+    /// Primary invite link
+    pub invite_link: Option<Box<str>>,
+    /// The most recent pinned message (by sending date)
+    pub pinned_message: Option<Message>,
+    /// Default chat member permissions
+    pub permissions: Option<ChatPermissions>,
+    /// The time after which all messages sent to the chat will be automatically deleted; in seconds
+    pub message_auto_delete_time: Option<i64>,
+    /// `true`, if non-administrators can only get the list of bots and administrators in the chat
+    pub has_hidden_members: Option<bool>,
+    /// `true`, if messages from the chat can't be forwarded to other chats
+    pub has_protected_content: Option<bool>,
+    /// `true`, if new chat members will have access to old messages; available only to chat administrators
+    pub has_visible_history: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Supergroup {
+    /// Unique identifier for this chat. This number may have more than 32 significant bits and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so a signed 64-bit integer or double-precision float type are safe for storing this identifier.
+    pub id: i64,
+    /// Title
+    pub title: Box<str>,
+    /// Username
+    pub username: Option<Box<str>>,
+    /// `true`, if the chat is a forum (has [`topics`](https://telegram.org/blog/topics-in-groups-collectible-usernames#topics-in-groups) enabled)
+    pub is_forum: Option<bool>,
+    /// Chat photo
+    pub photo: Option<ChatPhoto>,
+    /// If non-empty, the list of all [active chat usernames](https://telegram.org/blog/topics-in-groups-collectible-usernames/ru?ln=a#collectible-usernames)
+    pub active_usernames: Option<Box<[Box<str>]>>,
+    /// Identifier of the accent color for the chat name and backgrounds of the chat photo, reply header, and link preview. See [accent colors](https://core.telegram.org/bots/api#accent-colors) for more details.
+    pub accent_color_id: i64,
+    /// The maximum number of reactions that can be set on a message in the chat
+    pub max_reaction_count: i64,
+    /// Custom emoji identifier of emoji chosen by the chat for the reply header and link preview background
+    pub background_custom_emoji_id: Option<Box<str>>,
+    /// Identifier of the accent color for the chat's profile background. See [profile accent colors](https://core.telegram.org/bots/api#profile-accent-colors) for more details.
+    pub profile_accent_color_id: Option<i64>,
+    /// Custom emoji identifier of the emoji chosen by the chat for its profile background
+    pub profile_background_custom_emoji_id: Option<Box<str>>,
+    /// Custom emoji identifier of the emoji status
+    pub emoji_status_custom_emoji_id: Option<Box<str>>,
+    /// Expiration date of the emoji status in Unix time, if any
+    pub emoji_status_expiration_date: Option<i64>,
+    /// List of available reactions allowed in the chat. If omitted, then all [emoji reactions](https://core.telegram.org/bots/api#reactiontypeemoji) are allowed.
+    pub available_reactions: Option<Box<[ReactionType]>>,
+    /// `true`, if users need to join the supergroup before they can send messages
+    pub join_to_send_messages: Option<bool>,
+    /// `true`, if all users directly joining the supergroup need to be approved by supergroup administrators
+    pub join_by_request: Option<bool>,
+    /// Description
+    pub description: Option<Box<str>>,
+    /// Primary invite link
+    pub invite_link: Option<Box<str>>,
+    /// The most recent pinned message (by sending date)
+    pub pinned_message: Option<Message>,
+    /// Default chat member permissions
+    pub permissions: Option<ChatPermissions>,
+    /// For supergroups, the minimum allowed delay between consecutive messages sent by each unprivileged user; in seconds
+    pub slow_mode_delay: Option<i64>,
+    /// The minimum number of boosts that a non-administrator user needs to add in order to ignore slow mode and chat permissions
+    pub unrestrict_boost_count: Option<i64>,
+    /// The time after which all messages sent to the chat will be automatically deleted; in seconds
+    pub message_auto_delete_time: Option<i64>,
+    /// `true`, if aggressive anti-spam checks are enabled in the supergroup. The field is only available to chat administrators.
+    pub has_aggressive_anti_spam_enabled: Option<bool>,
+    /// `true`, if non-administrators can only get the list of bots and administrators in the chat
+    pub has_hidden_members: Option<bool>,
+    /// `true`, if messages from the chat can't be forwarded to other chats
+    pub has_protected_content: Option<bool>,
+    /// `true`, if new chat members will have access to old messages; available only to chat administrators
+    pub has_visible_history: Option<bool>,
+    /// Name of group sticker set
+    pub sticker_set_name: Option<Box<str>>,
+    /// `true`, if the bot can change the group sticker set
+    pub can_set_sticker_set: Option<bool>,
+    /// The name of the group's custom emoji sticker set. Custom emoji from this set can be used by all users and bots in the group.
+    pub custom_emoji_sticker_set_name: Option<Box<str>>,
+    /// Unique identifier for the linked chat, i.e. the discussion group identifier for a channel and vice versa. This identifier may be greater than 32 bits and some programming languages may have difficulty/silent defects in interpreting it. But it is smaller than 52 bits, so a signed 64 bit integer or double-precision float type are safe for storing this identifier.
+    pub linked_chat_id: Option<i64>,
+    /// The location to which the supergroup is connected
+    pub location: Option<ChatLocation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Channel {
+    /// Unique identifier for this chat. This number may have more than 32 significant bits and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so a signed 64-bit integer or double-precision float type are safe for storing this identifier.
+    pub id: i64,
+    /// Title
+    pub title: Box<str>,
+    /// Username
+    pub username: Option<Box<str>>,
+    /// Chat photo
+    pub photo: Option<ChatPhoto>,
+    /// If non-empty, the list of all [active chat usernames](https://telegram.org/blog/topics-in-groups-collectible-usernames/ru?ln=a#collectible-usernames)
+    pub active_usernames: Option<Box<[Box<str>]>>,
+    /// Identifier of the accent color for the chat name and backgrounds of the chat photo, reply header, and link preview. See [accent colors](https://core.telegram.org/bots/api#accent-colors) for more details.
+    pub accent_color_id: i64,
+    /// The maximum number of reactions that can be set on a message in the chat
+    pub max_reaction_count: i64,
+    /// Custom emoji identifier of emoji chosen by the chat for the reply header and link preview background
+    pub background_custom_emoji_id: Option<Box<str>>,
+    /// Identifier of the accent color for the chat's profile background. See [profile accent colors](https://core.telegram.org/bots/api#profile-accent-colors) for more details.
+    pub profile_accent_color_id: Option<i64>,
+    /// Custom emoji identifier of the emoji chosen by the chat for its profile background
+    pub profile_background_custom_emoji_id: Option<Box<str>>,
+    /// Custom emoji identifier of the emoji status
+    pub emoji_status_custom_emoji_id: Option<Box<str>>,
+    /// Expiration date of the emoji status in Unix time, if any
+    pub emoji_status_expiration_date: Option<i64>,
+    /// List of available reactions allowed in the chat. If omitted, then all [emoji reactions](https://core.telegram.org/bots/api#reactiontypeemoji) are allowed.
+    pub available_reactions: Option<Box<[ReactionType]>>,
+    /// Description
+    pub description: Option<Box<str>>,
+    /// Primary invite link
+    pub invite_link: Option<Box<str>>,
+    /// The most recent pinned message (by sending date)
+    pub pinned_message: Option<Message>,
+    /// The time after which all messages sent to the chat will be automatically deleted; in seconds
+    pub message_auto_delete_time: Option<i64>,
+    /// `true`, if messages from the chat can't be forwarded to other chats
+    pub has_protected_content: Option<bool>,
+    /// Unique identifier for the linked chat, i.e. the discussion group identifier for a channel and vice versa. This identifier may be greater than 32 bits and some programming languages may have difficulty/silent defects in interpreting it. But it is smaller than 52 bits, so a signed 64 bit integer or double-precision float type are safe for storing this identifier.
+    pub linked_chat_id: Option<i64>,
+}
+
+impl ChatFullInfo {
+    #[must_use]
+    pub const fn id(&self) -> i64 {
+        match self {
+            Self::Private(Private { id, .. })
+            | Self::Group(Group { id, .. })
+            | Self::Supergroup(Supergroup { id, .. })
+            | Self::Channel(Channel { id, .. }) => *id,
+        }
+    }
+
+    #[must_use]
+    pub const fn username(&self) -> Option<&str> {
+        match self {
+            Self::Group(_) => None,
+            Self::Private(Private { username, .. })
+            | Self::Supergroup(Supergroup { username, .. })
+            | Self::Channel(Channel { username, .. }) => match username {
+                Some(username) => Some(username),
+                None => None,
+            },
+        }
+    }
+
+    #[must_use]
+    pub const fn title(&self) -> Option<&str> {
+        match self {
+            Self::Private(_) => None,
+            Self::Group(Group { title, .. })
+            | Self::Supergroup(Supergroup { title, .. })
+            | Self::Channel(Channel { title, .. }) => Some(title),
+        }
+    }
+
+    #[must_use]
+    pub const fn photo(&self) -> Option<&ChatPhoto> {
+        match self {
+            Self::Private(Private { photo, .. })
+            | Self::Group(Group { photo, .. })
+            | Self::Supergroup(Supergroup { photo, .. })
+            | Self::Channel(Channel { photo, .. }) => photo.as_ref(),
+        }
+    }
+
+    #[must_use]
+    pub const fn active_usernames(&self) -> Option<&[Box<str>]> {
+        match self {
+            Self::Group(_) => None,
+            Self::Private(Private {
+                active_usernames, ..
+            })
+            | Self::Supergroup(Supergroup {
+                active_usernames, ..
+            })
+            | Self::Channel(Channel {
+                active_usernames, ..
+            }) => match active_usernames {
+                Some(active_usernames) => Some(active_usernames),
+                None => None,
+            },
+        }
+    }
+
+    #[must_use]
+    pub const fn accent_color_id(&self) -> i64 {
+        match self {
+            Self::Private(Private {
+                accent_color_id, ..
+            })
+            | Self::Group(Group {
+                accent_color_id, ..
+            })
+            | Self::Supergroup(Supergroup {
+                accent_color_id, ..
+            })
+            | Self::Channel(Channel {
+                accent_color_id, ..
+            }) => *accent_color_id,
+        }
+    }
+
+    #[must_use]
+    pub const fn max_reaction_count(&self) -> i64 {
+        match self {
+            Self::Private(Private {
+                max_reaction_count, ..
+            })
+            | Self::Group(Group {
+                max_reaction_count, ..
+            })
+            | Self::Supergroup(Supergroup {
+                max_reaction_count, ..
+            })
+            | Self::Channel(Channel {
+                max_reaction_count, ..
+            }) => *max_reaction_count,
+        }
+    }
+
+    #[must_use]
+    pub const fn background_custom_emoji_id(&self) -> Option<&str> {
+        match self {
+            Self::Private(Private {
+                background_custom_emoji_id,
+                ..
+            })
+            | Self::Group(Group {
+                background_custom_emoji_id,
+                ..
+            })
+            | Self::Supergroup(Supergroup {
+                background_custom_emoji_id,
+                ..
+            })
+            | Self::Channel(Channel {
+                background_custom_emoji_id,
+                ..
+            }) => match background_custom_emoji_id {
+                Some(background_custom_emoji_id) => Some(background_custom_emoji_id),
+                None => None,
+            },
+        }
+    }
+
+    #[must_use]
+    pub const fn profile_accent_color_id(&self) -> Option<i64> {
+        match self {
+            Self::Private(Private {
+                profile_accent_color_id,
+                ..
+            })
+            | Self::Group(Group {
+                profile_accent_color_id,
+                ..
+            })
+            | Self::Supergroup(Supergroup {
+                profile_accent_color_id,
+                ..
+            })
+            | Self::Channel(Channel {
+                profile_accent_color_id,
+                ..
+            }) => *profile_accent_color_id,
+        }
+    }
+
+    #[must_use]
+    pub const fn profile_background_custom_emoji_id(&self) -> Option<&str> {
+        match self {
+            Self::Private(Private {
+                profile_background_custom_emoji_id,
+                ..
+            })
+            | Self::Group(Group {
+                profile_background_custom_emoji_id,
+                ..
+            })
+            | Self::Supergroup(Supergroup {
+                profile_background_custom_emoji_id,
+                ..
+            })
+            | Self::Channel(Channel {
+                profile_background_custom_emoji_id,
+                ..
+            }) => match profile_background_custom_emoji_id {
+                Some(profile_background_custom_emoji_id) => {
+                    Some(profile_background_custom_emoji_id)
+                }
+                None => None,
+            },
+        }
+    }
+
+    #[must_use]
+    pub const fn available_reactions(&self) -> Option<&[ReactionType]> {
+        match self {
+            Self::Private(_) => None,
+            Self::Group(Group {
+                available_reactions,
+                ..
+            })
+            | Self::Supergroup(Supergroup {
+                available_reactions,
+                ..
+            })
+            | Self::Channel(Channel {
+                available_reactions,
+                ..
+            }) => match available_reactions {
+                Some(available_reactions) => Some(available_reactions),
+                None => None,
+            },
+        }
+    }
+
+    #[must_use]
+    pub const fn description(&self) -> Option<&str> {
+        match self {
+            Self::Private(_) => None,
+            Self::Group(Group { description, .. })
+            | Self::Supergroup(Supergroup { description, .. })
+            | Self::Channel(Channel { description, .. }) => match description {
+                Some(description) => Some(description),
+                None => None,
+            },
+        }
+    }
+
+    #[must_use]
+    pub const fn invite_link(&self) -> Option<&str> {
+        match self {
+            Self::Private(_) => None,
+            Self::Group(Group { invite_link, .. })
+            | Self::Supergroup(Supergroup { invite_link, .. })
+            | Self::Channel(Channel { invite_link, .. }) => match invite_link {
+                Some(invite_link) => Some(invite_link),
+                None => None,
+            },
+        }
+    }
+
+    #[must_use]
+    pub const fn pinned_message(&self) -> Option<&Message> {
+        match self {
+            Self::Private(Private { pinned_message, .. })
+            | Self::Group(Group { pinned_message, .. })
+            | Self::Supergroup(Supergroup { pinned_message, .. })
+            | Self::Channel(Channel { pinned_message, .. }) => pinned_message.as_ref(),
+        }
+    }
+
+    #[must_use]
+    pub const fn permissions(&self) -> Option<&ChatPermissions> {
+        match self {
+            Self::Private(_) | Self::Channel(_) => None,
+            Self::Group(Group { permissions, .. })
+            | Self::Supergroup(Supergroup { permissions, .. }) => permissions.as_ref(),
+        }
+    }
+
+    #[must_use]
+    pub const fn message_auto_delete_time(&self) -> Option<i64> {
+        match self {
+            Self::Private(Private {
+                message_auto_delete_time,
+                ..
+            })
+            | Self::Group(Group {
+                message_auto_delete_time,
+                ..
+            })
+            | Self::Supergroup(Supergroup {
+                message_auto_delete_time,
+                ..
+            })
+            | Self::Channel(Channel {
+                message_auto_delete_time,
+                ..
+            }) => *message_auto_delete_time,
+        }
+    }
+
+    #[must_use]
+    pub const fn has_protected_content(&self) -> Option<bool> {
+        match self {
+            Self::Private(_) => None,
+            Self::Group(Group {
+                has_protected_content,
+                ..
+            })
+            | Self::Supergroup(Supergroup {
+                has_protected_content,
+                ..
+            })
+            | Self::Channel(Channel {
+                has_protected_content,
+                ..
+            }) => *has_protected_content,
+        }
+    }
+
+    #[must_use]
+    pub const fn has_visible_history(&self) -> Option<bool> {
+        match self {
+            Self::Private(_) | Self::Channel(_) => None,
+            Self::Group(Group {
+                has_visible_history,
+                ..
+            })
+            | Self::Supergroup(Supergroup {
+                has_visible_history,
+                ..
+            }) => *has_visible_history,
+        }
+    }
+
+    #[must_use]
+    pub const fn linked_chat_id(&self) -> Option<i64> {
+        match self {
+            Self::Private(_) | Self::Group(_) => None,
+            Self::Supergroup(Supergroup { linked_chat_id, .. })
+            | Self::Channel(Channel { linked_chat_id, .. }) => *linked_chat_id,
+        }
+    }
+
+    #[must_use]
+    pub const fn bio(&self) -> Option<&str> {
+        match self {
+            Self::Private(Private { bio: Some(bio), .. }) => Some(bio),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn birthdate(&self) -> Option<&Birthdate> {
+        match self {
+            Self::Private(Private { birthdate, .. }) => birthdate.as_ref(),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn business_intro(&self) -> Option<&BusinessIntro> {
+        match self {
+            Self::Private(Private {
+                business_intro: Some(business_intro),
+                ..
+            }) => Some(business_intro),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn business_location(&self) -> Option<&BusinessLocation> {
+        match self {
+            Self::Private(Private {
+                business_location, ..
+            }) => business_location.as_ref(),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn business_opening_hours(&self) -> Option<&BusinessOpeningHours> {
+        match self {
+            Self::Private(Private {
+                business_opening_hours,
+                ..
+            }) => business_opening_hours.as_ref(),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn personal_chat(&self) -> Option<&Chat> {
+        match self {
+            Self::Private(Private { personal_chat, .. }) => personal_chat.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Get lightweight information about the chat, which is used in updates
+    #[must_use]
+    pub fn to_chat(&self) -> Chat {
+        match self {
+            Self::Private(Private {
+                id,
+                username,
+                first_name,
+                last_name,
+                ..
+            }) => Chat::Private(ChatPrivate {
+                id: *id,
+                username: username.clone(),
+                first_name: first_name.clone(),
+                last_name: last_name.clone(),
+            }),
+            Self::Group(Group { id, title, .. }) => Chat::Group(ChatGroup {
+                id: *id,
+                title: title.clone(),
+            }),
+            Self::Supergroup(Supergroup {
+                id,
+                title,
+                username,
+                is_forum,
+                ..
+            }) => Chat::Supergroup(ChatSupergroup {
+                id: *id,
+                title: title.clone(),
+                username: username.clone(),
+                is_forum: *is_forum,
+            }),
+            Self::Channel(Channel {
+                id,
+                title,
+                username,
+                ..
+            }) => Chat::Channel(ChatChannel {
+                id: *id,
+                title: title.clone(),
+                username: username.clone(),
+            }),
+        }
+    }
+}
+
+impl Default for ChatFullInfo {
+    fn default() -> Self {
+        Self::Private(Private::default())
+    }
+}
+
+impl From<ChatFullInfo> for Chat {
+    fn from(chat: ChatFullInfo) -> Self {
+        match chat {
+            ChatFullInfo::Private(Private {
+                id,
+                username,
+                first_name,
+                last_name,
+                ..
+            }) => Self::Private(ChatPrivate {
+                id,
+                username,
+                first_name,
+                last_name,
+            }),
+            ChatFullInfo::Group(Group { id, title, .. }) => Self::Group(ChatGroup { id, title }),
+            ChatFullInfo::Supergroup(Supergroup {
+                id,
+                title,
+                username,
+                is_forum,
+                ..
+            }) => Self::Supergroup(ChatSupergroup {
+                id,
+                title,
+                username,
+                is_forum,
+            }),
+            ChatFullInfo::Channel(Channel {
+                id,
+                title,
+                username,
+                ..
+            }) => Self::Channel(ChatChannel {
+                id,
+                title,
+                username,
+            }),
+        }
+    }
+}
+
+impl<'a> From<&'a ChatFullInfo> for Chat {
+    fn from(chat: &'a ChatFullInfo) -> Self {
+        chat.to_chat()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let chat: ChatFullInfo = serde_json::from_str(
+            r#"{
+                "id": 1,
+                "type": "private",
+                "first_name": "Test",
+                "username": "test",
+                "accent_color_id": 3,
+                "max_reaction_count": 11,
+                "birthdate": {"day": 1, "month": 2},
+                "business_intro": {"title": "Intro"},
+                "business_location": {"address": "Address"},
+                "business_opening_hours": {
+                    "time_zone_name": "Europe/Amsterdam",
+                    "opening_hours": [{"opening_minute": 540, "closing_minute": 1080}]
+                },
+                "personal_chat": {"id": -100, "type": "channel", "title": "Channel"},
+                "bio": "Bio"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(chat.id(), 1);
+        assert_eq!(chat.username(), Some("test"));
+        assert_eq!(chat.accent_color_id(), 3);
+        assert_eq!(chat.max_reaction_count(), 11);
+        assert_eq!(
+            chat.birthdate(),
+            Some(&Birthdate {
+                day: 1,
+                month: 2,
+                year: None
+            })
+        );
+        assert_eq!(
+            chat.business_intro()
+                .and_then(|intro| intro.title.as_deref()),
+            Some("Intro")
+        );
+        assert!(chat
+            .business_opening_hours()
+            .map_or(false, |hours| hours.is_open_at(600)
+                && !hours.is_open_at(1080)));
+        assert_eq!(chat.personal_chat().map(Chat::id), Some(-100));
+        assert_eq!(chat.bio(), Some("Bio"));
+
+        let lightweight = Chat::from(&chat);
+
+        assert_eq!(lightweight.id(), 1);
+        assert_eq!(lightweight.username(), Some("test"));
+        assert_eq!(lightweight.first_name(), Some("Test"));
+        assert_eq!(lightweight, Chat::from(chat));
+
+        let chat: ChatFullInfo = serde_json::from_str(
+            r#"{
+                "id": -1001,
+                "type": "supergroup",
+                "title": "Forum",
+                "is_forum": true,
+                "accent_color_id": 0,
+                "max_reaction_count": 11,
+                "available_reactions": [{"type": "emoji", "emoji": "👍"}],
+                "linked_chat_id": -1002
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(chat.title(), Some("Forum"));
+        assert_eq!(chat.available_reactions().map(<[_]>::len), Some(1));
+        assert_eq!(chat.linked_chat_id(), Some(-1002));
+        assert!(chat.to_chat().is_forum());
+    }
+}
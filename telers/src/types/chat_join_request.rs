@@ -1,14 +1,16 @@
 use super::{Chat, ChatInviteLink, Update, UpdateKind, User};
 
-use crate::{errors::ConvertToTypeError, FromEvent};
+use crate::errors::ConvertToTypeError;
+#[cfg(feature = "client")]
+use crate::methods::{ApproveChatJoinRequest, DeclineChatJoinRequest};
 
 use serde::Deserialize;
 
 /// Represents a join request sent to a chat.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#chatjoinrequest>
-#[derive(Debug, Default, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct ChatJoinRequest {
     /// Chat to which the request was sent
     pub chat: Chat,
@@ -24,6 +26,34 @@ pub struct ChatJoinRequest {
     pub invite_link: Option<ChatInviteLink>,
 }
 
+impl ChatJoinRequest {
+    /// Creates [`ApproveChatJoinRequest`] method to approve this join request
+    /// # Notes
+    /// This method is just a shortcut, you need to send the returned method by the bot
+    #[cfg(feature = "client")]
+    #[must_use]
+    pub fn approve(&self) -> ApproveChatJoinRequest {
+        ApproveChatJoinRequest::new(self.chat.id(), self.from.id)
+    }
+
+    /// Creates [`DeclineChatJoinRequest`] method to decline this join request
+    /// # Notes
+    /// This method is just a shortcut, you need to send the returned method by the bot
+    #[cfg(feature = "client")]
+    #[must_use]
+    pub fn decline(&self) -> DeclineChatJoinRequest {
+        DeclineChatJoinRequest::new(self.chat.id(), self.from.id)
+    }
+
+    /// Gets invite link name, which was used by the user to send the join request
+    #[must_use]
+    pub fn invite_link_name(&self) -> Option<&str> {
+        self.invite_link
+            .as_ref()
+            .and_then(|invite_link| invite_link.name.as_deref())
+    }
+}
+
 impl TryFrom<Update> for ChatJoinRequest {
     type Error = ConvertToTypeError;
 
@@ -34,3 +64,14 @@ impl TryFrom<Update> for ChatJoinRequest {
         }
     }
 }
+
+impl TryFrom<&Update> for ChatJoinRequest {
+    type Error = ConvertToTypeError;
+
+    fn try_from(update: &Update) -> Result<Self, Self::Error> {
+        match &update.kind {
+            UpdateKind::ChatJoinRequest(val) => Ok(val.clone()),
+            _ => Err(ConvertToTypeError::new("Update", "ChatJoinRequest")),
+        }
+    }
+}
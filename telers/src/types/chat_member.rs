@@ -1,6 +1,6 @@
 use super::{
     ChatMemberAdministrator, ChatMemberBanned, ChatMemberLeft, ChatMemberMember, ChatMemberOwner,
-    ChatMemberRestricted,
+    ChatMemberRestricted, User,
 };
 
 use serde::Deserialize;
@@ -28,6 +28,21 @@ pub enum ChatMember {
     Banned(ChatMemberBanned),
 }
 
+impl ChatMember {
+    /// Information about the user
+    #[must_use]
+    pub const fn user(&self) -> &User {
+        match self {
+            Self::Owner(ChatMemberOwner { user, .. })
+            | Self::Administrator(ChatMemberAdministrator { user, .. })
+            | Self::Member(ChatMemberMember { user, .. })
+            | Self::Restricted(ChatMemberRestricted { user, .. })
+            | Self::Left(ChatMemberLeft { user, .. })
+            | Self::Banned(ChatMemberBanned { user, .. }) => user,
+        }
+    }
+}
+
 impl From<ChatMemberOwner> for ChatMember {
     fn from(chat_member: ChatMemberOwner) -> Self {
         Self::Owner(chat_member)
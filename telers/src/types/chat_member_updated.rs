@@ -1,14 +1,14 @@
 use super::{Chat, ChatInviteLink, ChatMember, Update, UpdateKind, User};
 
-use crate::{errors::ConvertToTypeError, FromEvent};
+use crate::errors::ConvertToTypeError;
 
 use serde::Deserialize;
 
 /// This object represents changes in the status of a chat member.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#chatmemberupdated>
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct ChatMemberUpdated {
     /// Chat the user belongs to
     pub chat: Chat,
@@ -36,3 +36,14 @@ impl TryFrom<Update> for ChatMemberUpdated {
         }
     }
 }
+
+impl TryFrom<&Update> for ChatMemberUpdated {
+    type Error = ConvertToTypeError;
+
+    fn try_from(update: &Update) -> Result<Self, Self::Error> {
+        match &update.kind {
+            UpdateKind::MyChatMember(val) | UpdateKind::ChatMember(val) => Ok(val.clone()),
+            _ => Err(ConvertToTypeError::new("Update", "ChatMemberUpdated")),
+        }
+    }
+}
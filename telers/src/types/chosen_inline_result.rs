@@ -1,6 +1,6 @@
 use super::{Location, Update, UpdateKind, User};
 
-use crate::{errors::ConvertToTypeError, FromEvent};
+use crate::errors::ConvertToTypeError;
 
 use serde::Deserialize;
 
@@ -9,8 +9,8 @@ use serde::Deserialize;
 /// It is necessary to enable [`inline feedback`](https://core.telegram.org/bots/inline#collecting-feedback) via [`@BotFather`](https://t.me/botfather) in order to receive these objects in updates.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#choseninlineresult>
-#[derive(Debug, Default, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct ChosenInlineResult {
     /// The unique identifier for the result that was chosen
     pub result_id: Box<str>,
@@ -34,3 +34,14 @@ impl TryFrom<Update> for ChosenInlineResult {
         }
     }
 }
+
+impl TryFrom<&Update> for ChosenInlineResult {
+    type Error = ConvertToTypeError;
+
+    fn try_from(update: &Update) -> Result<Self, Self::Error> {
+        match &update.kind {
+            UpdateKind::ChosenInlineResult(val) => Ok(val.clone()),
+            _ => Err(ConvertToTypeError::new("Update", "ChosenInlineResult")),
+        }
+    }
+}
@@ -23,4 +23,6 @@ pub struct Giveaway {
     pub country_codes: Option<Box<[Box<str>]>>,
     /// The number of months the Telegram Premium subscription won from the giveaway will be active for
     pub premium_subscription_month_count: Option<i64>,
+    /// The number of Telegram Stars to be split between giveaway winners; for Telegram Star giveaways only
+    pub prize_star_count: Option<i64>,
 }
@@ -21,6 +21,8 @@ pub struct GiveawayWinners {
     pub additional_chat_count: Option<i64>,
     /// The number of months the Telegram Premium subscription won from the giveaway will be active for
     pub premium_subscription_month_count: Option<i64>,
+    /// The number of Telegram Stars that were split between giveaway winners; for Telegram Star giveaways only
+    pub prize_star_count: Option<i64>,
     /// Number of undistributed prizes
     pub unclaimed_prize_count: Option<i64>,
     /// `true`, if only users who had joined the chats after the giveaway started were eligible to win
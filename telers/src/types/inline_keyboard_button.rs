@@ -129,6 +129,37 @@ impl InlineKeyboardButton {
     }
 }
 
+impl InlineKeyboardButton {
+    /// Check if the text of the button starts with the marker
+    #[must_use]
+    pub fn has_marker(&self, marker: &str) -> bool {
+        self.text.starts_with(marker)
+    }
+
+    /// Add the marker (for example, [`CHECK_MARK`](crate::utils::keyboard::CHECK_MARK)) to the start of the text of the button if `enabled` is `true`,
+    /// otherwise remove it. The marker isn't duplicated, if it's already added.
+    #[must_use]
+    pub fn marker(self, marker: &str, enabled: bool) -> Self {
+        let has_marker = self.has_marker(marker);
+
+        let text = match (enabled, has_marker) {
+            (true, false) => format!("{marker}{}", self.text),
+            (false, true) => self.text[marker.len()..].to_owned(),
+            _ => self.text,
+        };
+
+        Self { text, ..self }
+    }
+
+    /// Add the marker to the start of the text of the button if it isn't added, otherwise remove it
+    #[must_use]
+    pub fn toggle_marker(self, marker: &str) -> Self {
+        let enabled = !self.has_marker(marker);
+
+        self.marker(marker, enabled)
+    }
+}
+
 impl InlineKeyboardButton {
     #[must_use]
     pub fn url_option(self, val: Option<impl Into<String>>) -> Self {
@@ -47,6 +47,92 @@ impl InlineKeyboardMarkup {
     }
 }
 
+impl InlineKeyboardMarkup {
+    /// Get all buttons of the keyboard row by row
+    pub fn buttons(&self) -> impl Iterator<Item = &InlineKeyboardButton> {
+        self.inline_keyboard.iter().flatten()
+    }
+
+    /// Get the first button with the callback data
+    #[must_use]
+    pub fn button(&self, callback_data: &str) -> Option<&InlineKeyboardButton> {
+        self.buttons()
+            .find(|button| button.callback_data.as_deref() == Some(callback_data))
+    }
+
+    /// Get the first button with the callback data for mutation
+    #[must_use]
+    pub fn button_mut(&mut self, callback_data: &str) -> Option<&mut InlineKeyboardButton> {
+        self.inline_keyboard
+            .iter_mut()
+            .flatten()
+            .find(|button| button.callback_data.as_deref() == Some(callback_data))
+    }
+
+    /// Map each button of the keyboard
+    #[must_use]
+    pub fn map_buttons<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(InlineKeyboardButton) -> InlineKeyboardButton,
+    {
+        Self {
+            inline_keyboard: self
+                .inline_keyboard
+                .into_iter()
+                .map(|row| row.into_iter().map(&mut f).collect())
+                .collect(),
+        }
+    }
+
+    /// Replace buttons with the callback data by the button
+    #[must_use]
+    pub fn replace_button(self, callback_data: &str, button: InlineKeyboardButton) -> Self {
+        self.map_buttons(|val| {
+            if val.callback_data.as_deref() == Some(callback_data) {
+                button.clone()
+            } else {
+                val
+            }
+        })
+    }
+
+    /// Toggle the marker (for example, [`CHECK_MARK`](crate::utils::keyboard::CHECK_MARK)) in the text of buttons with the callback data.
+    /// Check [`InlineKeyboardButton::toggle_marker`] for more information.
+    #[must_use]
+    pub fn toggle_button(self, callback_data: &str, marker: &str) -> Self {
+        self.map_buttons(|button| {
+            if button.callback_data.as_deref() == Some(callback_data) {
+                button.toggle_marker(marker)
+            } else {
+                button
+            }
+        })
+    }
+
+    /// Remove buttons with the callback data. Rows, which become empty, are removed too.
+    #[must_use]
+    pub fn remove_button(self, callback_data: &str) -> Self {
+        Self {
+            inline_keyboard: self
+                .inline_keyboard
+                .into_iter()
+                .filter_map(|row| {
+                    let row = row
+                        .into_iter()
+                        .filter(|button| button.callback_data.as_deref() != Some(callback_data))
+                        .collect::<Vec<_>>();
+
+                    if row.is_empty() {
+                        None
+                    } else {
+                        Some(row)
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
 impl From<Vec<Vec<InlineKeyboardButton>>> for InlineKeyboardMarkup {
     fn from(val: Vec<Vec<InlineKeyboardButton>>) -> Self {
         Self {
@@ -62,3 +148,46 @@ impl From<Vec<InlineKeyboardButton>> for InlineKeyboardMarkup {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn markup() -> InlineKeyboardMarkup {
+        InlineKeyboardMarkup::new([
+            vec![
+                InlineKeyboardButton::new("A").callback_data("a"),
+                InlineKeyboardButton::new("B").callback_data("b"),
+            ],
+            vec![InlineKeyboardButton::new("Back").callback_data("back")],
+        ])
+    }
+
+    #[test]
+    fn test_button() {
+        let mut markup = markup();
+
+        assert_eq!(markup.buttons().count(), 3);
+        assert_eq!(markup.button("b").map(|button| &*button.text), Some("B"));
+        assert!(markup.button("c").is_none());
+
+        markup.button_mut("a").unwrap().text = "AA".to_owned();
+        assert_eq!(markup.button("a").map(|button| &*button.text), Some("AA"));
+    }
+
+    #[test]
+    fn test_mutation() {
+        let markup = markup()
+            .replace_button("a", InlineKeyboardButton::new("C").callback_data("c"))
+            .toggle_button("b", "✅ ");
+
+        assert!(markup.button("a").is_none());
+        assert_eq!(markup.button("c").map(|button| &*button.text), Some("C"));
+        assert_eq!(markup.button("b").map(|button| &*button.text), Some("✅ B"));
+
+        let markup = markup.toggle_button("b", "✅ ").remove_button("back");
+
+        assert_eq!(markup.button("b").map(|button| &*button.text), Some("B"));
+        assert_eq!(markup.inline_keyboard.len(), 1);
+    }
+}
@@ -1,14 +1,14 @@
 use super::{Location, Update, UpdateKind, User};
 
-use crate::{errors::ConvertToTypeError, FromEvent};
+use crate::errors::ConvertToTypeError;
 
 use serde::Deserialize;
 
 /// This object represents an incoming inline query. When the user sends an empty query, your bot could return some default or trending results.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#inlinequery>
-#[derive(Debug, Default, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct InlineQuery {
     /// Unique identifier for this query
     pub id: Box<str>,
@@ -34,3 +34,14 @@ impl TryFrom<Update> for InlineQuery {
         }
     }
 }
+
+impl TryFrom<&Update> for InlineQuery {
+    type Error = ConvertToTypeError;
+
+    fn try_from(update: &Update) -> Result<Self, Self::Error> {
+        match &update.kind {
+            UpdateKind::InlineQuery(val) => Ok(val.clone()),
+            _ => Err(ConvertToTypeError::new("Update", "InlineQuery")),
+        }
+    }
+}
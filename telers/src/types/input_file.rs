@@ -1,21 +1,27 @@
-use bytes::{Bytes, BytesMut};
-use futures::{Stream, TryFutureExt as _, TryStreamExt as _};
 use serde::{Serialize, Serializer};
-use std::{
-    borrow::Cow,
-    ffi::OsStr,
-    fmt::{self, Debug, Formatter},
-    hash::{Hash, Hasher},
-    io,
-    path::{Path, PathBuf},
-    sync::Arc,
+use std::borrow::Cow;
+#[cfg(feature = "client")]
+use {
+    bytes::{Bytes, BytesMut},
+    futures::{stream, Stream, StreamExt as _, TryStreamExt as _},
+    std::{
+        ffi::OsStr,
+        fmt::{self, Debug, Formatter},
+        hash::{Hash, Hasher},
+        io,
+        path::{Path, PathBuf},
+        sync::Arc,
+    },
+    takecell::TakeOwnCell,
+    tokio::io::AsyncRead,
+    tokio_util::codec::{BytesCodec, FramedRead},
+    uuid::Uuid,
 };
-use takecell::TakeOwnCell;
-use tokio_util::codec::{BytesCodec, FramedRead};
-use uuid::Uuid;
 
+#[cfg(feature = "client")]
 const ATTACH_PREFIX: &str = "attach://";
 
+#[cfg(feature = "client")]
 pub const DEFAULT_CAPACITY: usize = 64 * 1024; // 64 KiB
 
 /// This object represents the contents of a file to be uploaded.
@@ -27,6 +33,9 @@ pub const DEFAULT_CAPACITY: usize = 64 * 1024; // 64 KiB
 /// - [`BufferedFile`] (for example `BufferedFile::new(bytes)`)
 /// - [`StreamFile`] (for example `StreamFile::new(stream)`)
 ///
+/// Files to upload ([`FSFile`], [`BufferedFile`] and [`StreamFile`]) are available only with `client` feature,
+/// because they can be sent only by the client.
+///
 /// This struct is useful for fast and easy creation of any of these types,
 /// but if you want to use methods of specific type (for example [`FSFile::stream`] or [`StreamFile::set_stream`]),
 /// you need to use specific type.
@@ -41,8 +50,11 @@ pub const DEFAULT_CAPACITY: usize = 64 * 1024; // 64 KiB
 pub enum InputFile<'a> {
     Id(FileId<'a>),
     Url(UrlFile<'a>),
+    #[cfg(feature = "client")]
     FS(FSFile<'a>),
+    #[cfg(feature = "client")]
     Buffered(BufferedFile<'a>),
+    #[cfg(feature = "client")]
     Stream(StreamFile<'a>),
 }
 
@@ -58,7 +70,10 @@ impl<'a> InputFile<'a> {
     pub fn url(url: impl Into<Cow<'a, str>>) -> Self {
         Self::Url(UrlFile::new(url))
     }
+}
 
+#[cfg(feature = "client")]
+impl<'a> InputFile<'a> {
     /// Creates a new [`InputFile`] with [`FSFile`]
     #[must_use]
     pub fn fs(path: impl AsRef<Path>) -> Self {
@@ -105,6 +120,72 @@ impl<'a> InputFile<'a> {
     ) -> Self {
         Self::Stream(StreamFile::new_with_name(stream, name))
     }
+
+    /// Creates a new [`InputFile`] with [`StreamFile`], which reads bytes from the reader by chunks of [`DEFAULT_CAPACITY`] size
+    /// # Warning
+    /// The reader can be read only once, check [`InputFile::stream`] for more information
+    #[must_use]
+    pub fn read(reader: impl AsyncRead + Send + Sync + 'static) -> Self {
+        Self::Stream(StreamFile::new(read_stream(reader)))
+    }
+
+    /// Creates a new [`InputFile`] with [`StreamFile`], which reads bytes from the reader by chunks of [`DEFAULT_CAPACITY`] size, and specified filename
+    /// # Warning
+    /// The reader can be read only once, check [`InputFile::stream`] for more information
+    #[must_use]
+    pub fn read_with_name(
+        reader: impl AsyncRead + Send + Sync + 'static,
+        name: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self::Stream(StreamFile::new_with_name(read_stream(reader), name))
+    }
+
+    /// Creates a new [`InputFile`] with [`StreamFile`], which stream is produced by the closure.
+    /// The closure is called lazily, only when the file is sent, so the content isn't generated if the request isn't sent.
+    /// # Warning
+    /// The stream can be read only once, check [`InputFile::stream`] for more information
+    #[must_use]
+    pub fn from_fn<F, S>(f: F) -> Self
+    where
+        F: FnOnce() -> S + Send + Sync + 'static,
+        S: Stream<Item = Result<Bytes, io::Error>> + Send + Sync + 'static,
+    {
+        Self::Stream(StreamFile::new(lazy_stream(f)))
+    }
+
+    /// Creates a new [`InputFile`] with [`StreamFile`], which stream is produced by the closure, and specified filename.
+    /// The closure is called lazily, only when the file is sent, so the content isn't generated if the request isn't sent.
+    /// # Warning
+    /// The stream can be read only once, check [`InputFile::stream`] for more information
+    #[must_use]
+    pub fn from_fn_with_name<F, S>(f: F, name: impl Into<Cow<'a, str>>) -> Self
+    where
+        F: FnOnce() -> S + Send + Sync + 'static,
+        S: Stream<Item = Result<Bytes, io::Error>> + Send + Sync + 'static,
+    {
+        Self::Stream(StreamFile::new_with_name(lazy_stream(f), name))
+    }
+}
+
+#[cfg(feature = "client")]
+fn read_stream(
+    reader: impl AsyncRead + Send + Sync + 'static,
+) -> impl Stream<Item = Result<Bytes, io::Error>> + Unpin + Send + Sync + 'static {
+    Box::pin(
+        FramedRead::with_capacity(reader, BytesCodec::new(), DEFAULT_CAPACITY)
+            .map_ok(BytesMut::freeze),
+    )
+}
+
+#[cfg(feature = "client")]
+fn lazy_stream<F, S>(
+    f: F,
+) -> impl Stream<Item = Result<Bytes, io::Error>> + Unpin + Send + Sync + 'static
+where
+    F: FnOnce() -> S + Send + Sync + 'static,
+    S: Stream<Item = Result<Bytes, io::Error>> + Send + Sync + 'static,
+{
+    Box::pin(stream::once(async move { f() }).flatten())
 }
 
 impl<'a> InputFile<'a> {
@@ -121,8 +202,11 @@ impl<'a> InputFile<'a> {
         match self {
             Self::Id(file) => file.str_to_file(),
             Self::Url(file) => file.str_to_file(),
+            #[cfg(feature = "client")]
             Self::FS(file) => file.str_to_file(),
+            #[cfg(feature = "client")]
             Self::Buffered(file) => file.str_to_file(),
+            #[cfg(feature = "client")]
             Self::Stream(file) => file.str_to_file(),
         }
     }
@@ -137,8 +221,11 @@ impl<'a> InputFile<'a> {
         match self {
             Self::Id(file) => file.is_require_multipart(),
             Self::Url(file) => file.is_require_multipart(),
+            #[cfg(feature = "client")]
             Self::FS(file) => file.is_require_multipart(),
+            #[cfg(feature = "client")]
             Self::Buffered(file) => file.is_require_multipart(),
+            #[cfg(feature = "client")]
             Self::Stream(file) => file.is_require_multipart(),
         }
     }
@@ -165,18 +252,21 @@ impl<'a> From<UrlFile<'a>> for InputFile<'a> {
     }
 }
 
+#[cfg(feature = "client")]
 impl<'a> From<FSFile<'a>> for InputFile<'a> {
     fn from(fs_file: FSFile<'a>) -> Self {
         Self::FS(fs_file)
     }
 }
 
+#[cfg(feature = "client")]
 impl<'a> From<BufferedFile<'a>> for InputFile<'a> {
     fn from(buffered_file: BufferedFile<'a>) -> Self {
         Self::Buffered(buffered_file)
     }
 }
 
+#[cfg(feature = "client")]
 impl<'a> From<StreamFile<'a>> for InputFile<'a> {
     fn from(stream_file: StreamFile<'a>) -> Self {
         Self::Stream(stream_file)
@@ -229,6 +319,7 @@ impl<'a> UrlFile<'a> {
     }
 }
 
+#[cfg(feature = "client")]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FSFile<'a> {
     id: Uuid,
@@ -237,6 +328,7 @@ pub struct FSFile<'a> {
     str_to_file: String,
 }
 
+#[cfg(feature = "client")]
 impl<'a> FSFile<'a> {
     #[must_use]
     pub fn new(path: impl AsRef<Path>) -> Self {
@@ -299,6 +391,7 @@ impl<'a> FSFile<'a> {
     }
 }
 
+#[cfg(all(feature = "client", feature = "tokio-runtime"))]
 impl<'a> FSFile<'a> {
     /// Opens a file and returns a stream of its bytes with a specified capacity for the underlying buffer
     /// # Errors
@@ -307,6 +400,8 @@ impl<'a> FSFile<'a> {
         self,
         capacity: usize,
     ) -> impl Stream<Item = Result<Bytes, io::Error>> {
+        use futures::TryFutureExt as _;
+
         tokio::fs::File::open(self.path)
             .map_ok(move |file| {
                 FramedRead::with_capacity(file, BytesCodec::new(), capacity)
@@ -327,12 +422,14 @@ impl<'a> FSFile<'a> {
     }
 }
 
+#[cfg(feature = "client")]
 impl Hash for FSFile<'_> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.id.hash(state);
     }
 }
 
+#[cfg(feature = "client")]
 #[derive(Clone, PartialEq, Eq)]
 pub struct BufferedFile<'a> {
     id: Uuid,
@@ -341,6 +438,7 @@ pub struct BufferedFile<'a> {
     str_to_file: String,
 }
 
+#[cfg(feature = "client")]
 impl<'a> BufferedFile<'a> {
     #[must_use]
     pub fn new(bytes: impl Into<Bytes>) -> Self {
@@ -402,6 +500,7 @@ impl<'a> BufferedFile<'a> {
     }
 }
 
+#[cfg(feature = "client")]
 impl Debug for BufferedFile<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_struct("BufferedFile")
@@ -413,12 +512,14 @@ impl Debug for BufferedFile<'_> {
     }
 }
 
+#[cfg(feature = "client")]
 impl Hash for BufferedFile<'_> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.id.hash(state);
     }
 }
 
+#[cfg(feature = "client")]
 type SharedStream =
     Arc<TakeOwnCell<Box<dyn Stream<Item = Result<Bytes, io::Error>> + Send + Sync + Unpin>>>;
 
@@ -428,6 +529,7 @@ type SharedStream =
 /// This need because the stream can't be restored after it was taken.
 ///
 /// Check [`StreamFile::take_stream`] and [`StreamFile::set_stream`] for more information.
+#[cfg(feature = "client")]
 pub struct StreamFile<'a> {
     id: Uuid,
     file_name: Option<Cow<'a, str>>,
@@ -435,6 +537,7 @@ pub struct StreamFile<'a> {
     str_to_file: String,
 }
 
+#[cfg(feature = "client")]
 impl<'a> StreamFile<'a> {
     #[must_use]
     pub fn new(
@@ -543,6 +646,7 @@ impl<'a> StreamFile<'a> {
     }
 }
 
+#[cfg(feature = "client")]
 impl Debug for StreamFile<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_struct("StreamFile")
@@ -554,6 +658,7 @@ impl Debug for StreamFile<'_> {
     }
 }
 
+#[cfg(feature = "client")]
 impl Clone for StreamFile<'_> {
     fn clone(&self) -> Self {
         let id = Uuid::new_v4();
@@ -567,14 +672,60 @@ impl Clone for StreamFile<'_> {
     }
 }
 
+#[cfg(feature = "client")]
 impl Hash for StreamFile<'_> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.id.hash(state);
     }
 }
 
+#[cfg(feature = "client")]
 impl PartialEq for StreamFile<'_> {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
     }
 }
+
+#[cfg(all(test, feature = "client"))]
+mod tests {
+    use super::*;
+
+    async fn collect(file: InputFile<'_>) -> Vec<u8> {
+        let InputFile::Stream(file) = file else {
+            unreachable!()
+        };
+
+        file.take_stream()
+            .unwrap()
+            .try_fold(vec![], |mut content, bytes| async move {
+                content.extend_from_slice(&bytes);
+                Ok(content)
+            })
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_read() {
+        let content = vec![1; DEFAULT_CAPACITY * 2 + 1];
+
+        assert_eq!(
+            collect(InputFile::read(io::Cursor::new(content.clone()))).await,
+            content
+        );
+    }
+
+    #[tokio::test]
+    async fn test_from_fn() {
+        let file = InputFile::from_fn_with_name(
+            || {
+                stream::iter(
+                    ["Hello", ", ", "world!"].map(|part| Ok(Bytes::from_static(part.as_bytes()))),
+                )
+            },
+            "hello.txt",
+        );
+
+        assert_eq!(collect(file).await, b"Hello, world!");
+    }
+}
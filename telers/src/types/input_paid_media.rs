@@ -0,0 +1,27 @@
+use super::{InputPaidMediaPhoto, InputPaidMediaVideo};
+
+use serde::Serialize;
+
+/// This object describes the paid media to be sent. It can be one of
+/// - [`InputPaidMediaPhoto`]
+/// - [`InputPaidMediaVideo`]
+/// # Documentation
+/// <https://core.telegram.org/bots/api#inputpaidmedia>
+#[derive(Debug, Clone, Hash, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum InputPaidMedia<'a> {
+    Photo(InputPaidMediaPhoto<'a>),
+    Video(InputPaidMediaVideo<'a>),
+}
+
+impl<'a> From<InputPaidMediaPhoto<'a>> for InputPaidMedia<'a> {
+    fn from(input_paid_media_photo: InputPaidMediaPhoto<'a>) -> Self {
+        Self::Photo(input_paid_media_photo)
+    }
+}
+
+impl<'a> From<InputPaidMediaVideo<'a>> for InputPaidMedia<'a> {
+    fn from(input_paid_media_video: InputPaidMediaVideo<'a>) -> Self {
+        Self::Video(input_paid_media_video)
+    }
+}
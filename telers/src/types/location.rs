@@ -18,3 +18,56 @@ pub struct Location {
     /// The maximum distance for proximity alerts about approaching another chat member, in meters. For sent live locations only.
     pub proximity_alert_radius: Option<i64>,
 }
+
+impl Location {
+    /// Mean radius of the Earth in meters, which is used to compute distances
+    pub const EARTH_RADIUS: f64 = 6_371_008.8;
+
+    #[must_use]
+    pub const fn new(latitude: f64, longitude: f64) -> Self {
+        Self {
+            longitude,
+            latitude,
+            horizontal_accuracy: None,
+            live_period: None,
+            heading: None,
+            proximity_alert_radius: None,
+        }
+    }
+
+    /// Computes the great-circle distance to the point in meters with the haversine formula
+    #[must_use]
+    pub fn distance_to(&self, latitude: f64, longitude: f64) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), latitude.to_radians());
+        let delta_lat = lat2 - lat1;
+        let delta_lon = (longitude - self.longitude).to_radians();
+
+        let a = (delta_lat / 2.0).sin().powi(2)
+            + lat1.cos() * lat2.cos() * (delta_lon / 2.0).sin().powi(2);
+
+        2.0 * Self::EARTH_RADIUS * a.sqrt().min(1.0).asin()
+    }
+
+    /// Computes the great-circle distance to the location in meters with the haversine formula
+    #[must_use]
+    pub fn distance(&self, other: &Location) -> f64 {
+        self.distance_to(other.latitude, other.longitude)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distance() {
+        let moscow = Location::new(55.7558, 37.6173);
+        let saint_petersburg = Location::new(59.9343, 30.3351);
+
+        assert!(moscow.distance(&moscow).abs() < f64::EPSILON);
+
+        let distance = moscow.distance(&saint_petersburg);
+        assert!((distance - 634_000.0).abs() < 2_000.0, "{distance}");
+        assert!((distance - saint_petersburg.distance(&moscow)).abs() < 1e-6);
+    }
+}
@@ -1,4 +1,6 @@
-use super::{InaccessibleMessage, Message};
+use super::{Chat, InaccessibleMessage, Message};
+
+use crate::errors::ConvertToTypeError;
 
 use serde::Deserialize;
 
@@ -14,6 +16,54 @@ pub enum MaybeInaccessibleMessage {
     InaccessibleMessage(InaccessibleMessage),
 }
 
+impl MaybeInaccessibleMessage {
+    /// Gets unique message identifier inside the chat, which is available even if the message is inaccessible
+    #[must_use]
+    pub const fn id(&self) -> i64 {
+        match self {
+            Self::Message(message) => message.id(),
+            Self::InaccessibleMessage(message) => message.id,
+        }
+    }
+
+    /// Gets chat the message belongs to, which is available even if the message is inaccessible
+    #[must_use]
+    pub const fn chat(&self) -> &Chat {
+        match self {
+            Self::Message(message) => message.chat(),
+            Self::InaccessibleMessage(message) => &message.chat,
+        }
+    }
+
+    #[must_use]
+    pub const fn chat_id(&self) -> i64 {
+        self.chat().id()
+    }
+
+    #[must_use]
+    pub const fn is_accessible(&self) -> bool {
+        matches!(self, Self::Message(_))
+    }
+
+    /// Gets the message, if it's accessible
+    #[must_use]
+    pub const fn as_message(&self) -> Option<&Message> {
+        match self {
+            Self::Message(message) => Some(message),
+            Self::InaccessibleMessage(_) => None,
+        }
+    }
+
+    /// Converts into the message, if it's accessible
+    #[must_use]
+    pub fn into_message(self) -> Option<Message> {
+        match self {
+            Self::Message(message) => Some(message),
+            Self::InaccessibleMessage(_) => None,
+        }
+    }
+}
+
 impl From<Message> for MaybeInaccessibleMessage {
     fn from(message: Message) -> Self {
         Self::Message(message)
@@ -25,3 +75,48 @@ impl From<InaccessibleMessage> for MaybeInaccessibleMessage {
         Self::InaccessibleMessage(inaccessible_message)
     }
 }
+
+impl TryFrom<MaybeInaccessibleMessage> for Message {
+    type Error = ConvertToTypeError;
+
+    fn try_from(message: MaybeInaccessibleMessage) -> Result<Self, Self::Error> {
+        message
+            .into_message()
+            .ok_or_else(|| ConvertToTypeError::new("MaybeInaccessibleMessage", "Message"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ChatPrivate, MessageText};
+
+    #[test]
+    fn test_accessors() {
+        let chat = Chat::Private(ChatPrivate {
+            id: 1,
+            ..Default::default()
+        });
+
+        let message = MaybeInaccessibleMessage::Message(Message::Text(Box::new(MessageText {
+            id: 2,
+            chat: chat.clone(),
+            ..Default::default()
+        })));
+        assert_eq!(message.id(), 2);
+        assert_eq!(message.chat_id(), 1);
+        assert!(message.as_message().is_some());
+        assert!(Message::try_from(message).is_ok());
+
+        let message = MaybeInaccessibleMessage::InaccessibleMessage(InaccessibleMessage {
+            chat,
+            id: 3,
+            date: 0,
+        });
+        assert_eq!(message.id(), 3);
+        assert_eq!(message.chat_id(), 1);
+        assert!(!message.is_accessible());
+        assert!(message.as_message().is_none());
+        assert!(Message::try_from(message).is_err());
+    }
+}
@@ -1,11 +1,17 @@
 use super::{
     Chat, ExternalReplyInfo, InlineKeyboardMarkup, LinkPreviewOptions, MaybeInaccessibleMessage,
-    MessageEntity, MessageOrigin, PhotoSize, TextQuote, Update, UpdateKind, User,
+    MessageEntity, MessageOrigin, PhotoSize, ReplyParameters, TextQuote, Update, UpdateKind, User,
 };
 
-use crate::{errors::ConvertToTypeError, extractors::FromEvent, types};
+#[cfg(feature = "client")]
+use crate::methods::SendMessage;
+use crate::{
+    errors::ConvertToTypeError,
+    serializers::content::{Content, ContentRefDeserializer},
+    types,
+};
 
-use serde::Deserialize;
+use serde::{de, Deserialize, Deserializer};
 
 /// This object represents a message.
 /// # Documentation
@@ -17,15 +23,15 @@ use serde::Deserialize;
 /// or if you want to get caption from all types where it has,
 /// instead of using [`Animation::caption`], [`Audio::caption`], ..., you can use [`Message::caption`].
 /// Similar methods are implemented for all major message types.
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub enum Message {
     Text(Box<Text>),
     Animation(Box<Animation>),
     Audio(Box<Audio>),
     Document(Box<Document>),
     Photo(Box<Photo>),
+    PaidMedia(Box<PaidMedia>),
     Sticker(Box<Sticker>),
     Story(Box<Story>),
     Video(Box<Video>),
@@ -75,8 +81,140 @@ pub enum Message {
     WebAppData(Box<WebAppData>),
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+macro_rules! impl_deserialize_message {
+    ($($variant:ident => $key:literal),+ $(,)?) => {
+        /// Kind of the message content, which is determined by the content key.
+        /// Kinds are ordered by priority, so if the message has several content keys, the first kind is used.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+        enum ContentKind {
+            $($variant),+
+        }
+
+        impl ContentKind {
+            fn from_key(key: &str) -> Option<Self> {
+                match key {
+                    $($key => Some(Self::$variant),)+
+                    _ => None,
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for Message {
+            /// Message type is determined by the content keys, which are inspected once,
+            /// instead of trying to deserialize each variant in turn.
+            /// For example, animation message with `document` key for backward compatibility is deserialized as [`Animation`],
+            /// because animation has a higher priority.
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let content = Content::deserialize(deserializer)?;
+
+                if !matches!(content, Content::Map(_)) {
+                    return Err(de::Error::invalid_type(content.unexpected(), &"a message map"));
+                }
+
+                let Some(kind) = content.map_keys().filter_map(ContentKind::from_key).min() else {
+                    return Err(de::Error::custom(format_args!(
+                        "Unknown message type, no known content key is found among the message keys: {}",
+                        content.map_keys().collect::<Vec<_>>().join(", "),
+                    )));
+                };
+
+                let deserializer = ContentRefDeserializer::<D::Error>::new(&content);
+
+                match kind {
+                    $(
+                        ContentKind::$variant => $variant::deserialize(deserializer)
+                            .map(|message| Self::$variant(Box::new(message)))
+                            .map_err(|err| {
+                                // Deserialize the content again to find the path to the field, which can't be deserialized.
+                                // It's done only on the error path, so successful deserialization isn't slowed down.
+                                let path = serde_path_to_error::deserialize::<_, $variant>(
+                                    ContentRefDeserializer::<D::Error>::new(&content),
+                                )
+                                .err()
+                                .map(|err| err.path().to_string())
+                                .filter(|path| path != ".");
+
+                                match path {
+                                    Some(path) => de::Error::custom(format_args!(
+                                        "Failed to deserialize message with `{}` content as `{}` at `{path}`: {err}",
+                                        $key,
+                                        stringify!($variant),
+                                    )),
+                                    None => de::Error::custom(format_args!(
+                                        "Failed to deserialize message with `{}` content as `{}`: {err}",
+                                        $key,
+                                        stringify!($variant),
+                                    )),
+                                }
+                            }),
+                    )+
+                }
+            }
+        }
+    };
+}
+
+impl_deserialize_message!(
+    Text => "text",
+    Animation => "animation",
+    Audio => "audio",
+    Document => "document",
+    Photo => "photo",
+    PaidMedia => "paid_media",
+    Sticker => "sticker",
+    Story => "story",
+    Video => "video",
+    VideoNote => "video_note",
+    Voice => "voice",
+    Contact => "contact",
+    Dice => "dice",
+    Game => "game",
+    Poll => "poll",
+    Venue => "venue",
+    Location => "location",
+    NewChatMembers => "new_chat_members",
+    LeftChatMember => "left_chat_member",
+    NewChatTitle => "new_chat_title",
+    NewChatPhoto => "new_chat_photo",
+    DeleteChatPhoto => "delete_chat_photo",
+    GroupChatCreated => "group_chat_created",
+    SupergroupChatCreated => "supergroup_chat_created",
+    ChannelChatCreated => "channel_chat_created",
+    MessageAutoDeleteTimerChanged => "message_auto_delete_timer_changed",
+    MigrateToChat => "migrate_to_chat_id",
+    MigrateFromChat => "migrate_from_chat_id",
+    Pinned => "pinned_message",
+    Invoice => "invoice",
+    SuccessfulPayment => "successful_payment",
+    UsersShared => "users_shared",
+    ChatShared => "chat_shared",
+    ConnectedWebsite => "connected_website",
+    WriteAccessAllowed => "write_access_allowed",
+    PassportData => "passport_data",
+    ProximityAlertTriggered => "proximity_alert_triggered",
+    ChatBoostAdded => "boost_added",
+    ForumTopicCreated => "forum_topic_created",
+    ForumTopicEdited => "forum_topic_edited",
+    ForumTopicClosed => "forum_topic_closed",
+    ForumTopicReopened => "forum_topic_reopened",
+    GeneralForumTopicHidden => "general_forum_topic_hidden",
+    GeneralForumTopicUnhidden => "general_forum_topic_unhidden",
+    GiveawayCreated => "giveaway_created",
+    Giveaway => "giveaway",
+    GiveawayWinners => "giveaway_winners",
+    GiveawayCompleted => "giveaway_completed",
+    VideoChatScheduled => "video_chat_scheduled",
+    VideoChatStarted => "video_chat_started",
+    VideoChatEnded => "video_chat_ended",
+    VideoChatParticipantsInvited => "video_chat_participants_invited",
+    WebAppData => "web_app_data",
+);
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct Animation {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -129,8 +267,8 @@ pub struct Animation {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct Audio {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -183,8 +321,8 @@ pub struct Audio {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct Contact {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -226,8 +364,8 @@ pub struct Contact {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct Dice {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -267,8 +405,8 @@ pub struct Dice {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct Document {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -321,8 +459,8 @@ pub struct Document {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct Game {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -364,8 +502,8 @@ pub struct Game {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct Poll {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -407,8 +545,8 @@ pub struct Poll {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct Venue {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -452,8 +590,8 @@ pub struct Venue {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct Location {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -497,8 +635,8 @@ pub struct Location {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct Photo {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -579,8 +717,60 @@ impl Photo {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
+pub struct PaidMedia {
+    /// Unique message identifier inside this chat
+    #[serde(rename = "message_id")]
+    pub id: i64,
+    /// Unique identifier of a message thread to which the message belongs; for supergroups only
+    #[serde(rename = "message_thread_id")]
+    pub thread_id: Option<i64>,
+    /// Sender of the message; empty for messages sent to channels. For backward compatibility, the field contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub from: Option<User>,
+    /// Sender of the message, sent on behalf of a chat. For example, the channel itself for channel posts, the supergroup itself for messages from anonymous group administrators, the linked channel for messages automatically forwarded to the discussion group. For backward compatibility, the field *from* contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub sender_chat: Option<Chat>,
+    /// If the sender of the message boosted the chat, the number of boosts added by the user
+    pub sender_boost_count: Option<i64>,
+    /// Date the message was sent in Unix time
+    pub date: i64,
+    /// Conversation the message belongs to
+    pub chat: Chat,
+    /// Information about the original message for forwarded messages
+    pub forward_origin: Option<MessageOrigin>,
+    /// `true`, if the message is sent to a forum topic
+    pub is_topic_message: Option<bool>,
+    /// `true`, if the message is a channel post that was automatically forwarded to the connected discussion group
+    pub is_automatic_forward: Option<bool>,
+    /// For replies, the original message. Note that the [Message object](https://core.telegram.org/bots/api#message) in this field will not contain further *reply_to_message* fields even if it itself is a reply.
+    pub reply_to_message: Option<Message>,
+    /// For replies to a story, the original story
+    pub reply_to_story: Option<Story>,
+    /// Information about the message that is being replied to, which may come from another chat or forum topic
+    pub external_reply: Option<ExternalReplyInfo>,
+    /// For replies that quote part of the original message, the quoted part of the message
+    pub quote: Option<TextQuote>,
+    /// Bot through which the message was sent
+    pub via_bot: Option<User>,
+    /// Date the message was last edited in Unix time
+    pub edit_date: Option<i64>,
+    /// `true`, if the message can't be forwarded
+    pub has_protected_content: Option<bool>,
+    /// Signature of the post author for messages in channels, or the custom title of an anonymous group administrator
+    pub author_signature: Option<Box<str>>,
+    /// Message contains paid media; information about the paid media
+    pub paid_media: types::PaidMediaInfo,
+    /// Caption
+    pub caption: Option<Box<str>>,
+    /// Special entities like usernames, URLs, bot commands, etc. that appear in the caption
+    #[serde(rename = "caption_entities")]
+    pub entities: Option<Box<[MessageEntity]>>,
+    /// Inline keyboard attached to the message. `login_url` buttons are represented as ordinary `url` buttons.
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct Story {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -612,8 +802,8 @@ pub struct Story {
     pub story: types::Story,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct Sticker {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -653,8 +843,8 @@ pub struct Sticker {
     pub sticker: types::Sticker,
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct Text {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -704,8 +894,8 @@ pub struct Text {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct Video {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -760,8 +950,8 @@ pub struct Video {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct VideoNote {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -801,8 +991,8 @@ pub struct VideoNote {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct Voice {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -851,8 +1041,8 @@ pub struct Voice {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct MigrateToChat {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -868,8 +1058,8 @@ pub struct MigrateToChat {
     pub to_chat_id: i64,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct MigrateFromChat {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -885,8 +1075,8 @@ pub struct MigrateFromChat {
     pub from_chat_id: i64,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct NewChatMembers {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -904,8 +1094,8 @@ pub struct NewChatMembers {
     pub members: Box<[User]>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct LeftChatMember {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -923,8 +1113,8 @@ pub struct LeftChatMember {
     pub member: User,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct NewChatTitle {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -942,8 +1132,8 @@ pub struct NewChatTitle {
     pub title: Box<str>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct NewChatPhoto {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -961,8 +1151,8 @@ pub struct NewChatPhoto {
     pub photo: Box<[PhotoSize]>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct DeleteChatPhoto {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -980,8 +1170,8 @@ pub struct DeleteChatPhoto {
     pub photo: bool,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct GroupChatCreated {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -999,8 +1189,8 @@ pub struct GroupChatCreated {
     pub created: bool,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct SupergroupChatCreated {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1018,8 +1208,8 @@ pub struct SupergroupChatCreated {
     pub created: bool,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct ChannelChatCreated {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1038,8 +1228,8 @@ pub struct ChannelChatCreated {
 }
 
 #[allow(clippy::module_name_repetitions)]
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct MessageAutoDeleteTimerChanged {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1057,8 +1247,8 @@ pub struct MessageAutoDeleteTimerChanged {
     pub timer: types::MessageAutoDeleteTimerChanged,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct Pinned {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1085,8 +1275,8 @@ pub struct Pinned {
     pub message: Box<MaybeInaccessibleMessage>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct Invoice {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1126,8 +1316,8 @@ pub struct Invoice {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct SuccessfulPayment {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1145,8 +1335,8 @@ pub struct SuccessfulPayment {
     pub payment: types::SuccessfulPayment,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct UsersShared {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1162,8 +1352,8 @@ pub struct UsersShared {
     pub shared: types::UsersShared,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct ChatShared {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1179,8 +1369,8 @@ pub struct ChatShared {
     pub shared: types::ChatShared,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct ConnectedWebsite {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1194,8 +1384,8 @@ pub struct ConnectedWebsite {
     pub website: Box<str>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct WriteAccessAllowed {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1209,8 +1399,8 @@ pub struct WriteAccessAllowed {
     pub allowed: types::WriteAccessAllowed,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct PassportData {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1237,8 +1427,8 @@ pub struct PassportData {
     pub data: types::PassportData,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct ProximityAlertTriggered {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1252,8 +1442,8 @@ pub struct ProximityAlertTriggered {
     pub triggered: types::ProximityAlertTriggered,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct ChatBoostAdded {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1267,8 +1457,8 @@ pub struct ChatBoostAdded {
     pub added: types::ChatBoostAdded,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct ForumTopicCreated {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1295,8 +1485,8 @@ pub struct ForumTopicCreated {
     pub created: types::ForumTopicCreated,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct ForumTopicEdited {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1323,8 +1513,8 @@ pub struct ForumTopicEdited {
     pub edited: types::ForumTopicEdited,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct ForumTopicClosed {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1351,8 +1541,8 @@ pub struct ForumTopicClosed {
     pub closed: types::ForumTopicClosed,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct ForumTopicReopened {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1379,8 +1569,8 @@ pub struct ForumTopicReopened {
     pub reopened: types::ForumTopicReopened,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct GeneralForumTopicHidden {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1407,8 +1597,8 @@ pub struct GeneralForumTopicHidden {
     pub hidden: types::GeneralForumTopicHidden,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct GeneralForumTopicUnhidden {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1435,8 +1625,8 @@ pub struct GeneralForumTopicUnhidden {
     pub unhidden: types::GeneralForumTopicUnhidden,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct GiveawayCreated {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1457,8 +1647,8 @@ pub struct GiveawayCreated {
     pub created: types::GiveawayCreated,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct Giveaway {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1480,8 +1670,8 @@ pub struct Giveaway {
     pub giveaway: types::Giveaway,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct GiveawayWinners {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1504,8 +1694,8 @@ pub struct GiveawayWinners {
     pub winners: types::GiveawayWinners,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct GiveawayCompleted {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1526,8 +1716,8 @@ pub struct GiveawayCompleted {
     pub completed: types::GiveawayCompleted,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct VideoChatScheduled {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1545,8 +1735,8 @@ pub struct VideoChatScheduled {
     pub scheduled: types::VideoChatScheduled,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct VideoChatStarted {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1564,8 +1754,8 @@ pub struct VideoChatStarted {
     pub started: types::VideoChatStarted,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct VideoChatEnded {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1583,8 +1773,8 @@ pub struct VideoChatEnded {
     pub ended: types::VideoChatEnded,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct VideoChatParticipantsInvited {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1602,8 +1792,8 @@ pub struct VideoChatParticipantsInvited {
     pub invited: types::VideoChatParticipantsInvited,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct WebAppData {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
@@ -1626,6 +1816,7 @@ impl Message {
             Message::Audio(message) => message.id,
             Message::Document(message) => message.id,
             Message::Photo(message) => message.id,
+            Message::PaidMedia(message) => message.id,
             Message::Sticker(message) => message.id,
             Message::Story(message) => message.id,
             Message::Video(message) => message.id,
@@ -1684,6 +1875,7 @@ impl Message {
             Message::Audio(message) => message.thread_id,
             Message::Document(message) => message.thread_id,
             Message::Photo(message) => message.thread_id,
+            Message::PaidMedia(message) => message.thread_id,
             Message::Sticker(message) => message.thread_id,
             Message::Story(message) => message.thread_id,
             Message::Video(message) => message.thread_id,
@@ -1720,6 +1912,7 @@ impl Message {
             Message::Audio(message) => message.date,
             Message::Document(message) => message.date,
             Message::Photo(message) => message.date,
+            Message::PaidMedia(message) => message.date,
             Message::Sticker(message) => message.date,
             Message::Story(message) => message.date,
             Message::Video(message) => message.date,
@@ -1770,6 +1963,61 @@ impl Message {
         }
     }
 
+    /// Create [`SendMessage`] to answer the message in the same chat.
+    /// If the message is sent to the forum topic, the answer is sent to the same topic.
+    /// Use [`SendMessage::message_thread_id_option`] with `None` to send the answer to the general topic.
+    #[cfg(feature = "client")]
+    #[must_use]
+    pub fn answer(&self, text: impl Into<String>) -> SendMessage {
+        let chat = self.chat();
+        let thread_id = if chat.is_forum() {
+            self.thread_id()
+        } else {
+            None
+        };
+
+        SendMessage::new(chat.id(), text).message_thread_id_option(thread_id)
+    }
+
+    /// Create [`ReplyParameters`] to reply to the message.
+    /// Chat id is set, so the reply can be sent to another chat or to the same chat by methods without a chat of the message.
+    #[must_use]
+    pub fn reply_parameters(&self) -> ReplyParameters {
+        ReplyParameters::new(self.id()).chat_id(self.chat().id())
+    }
+
+    /// Create [`ReplyParameters`] to reply to the message with the quote of its part.
+    /// Position of the quote and its entities are taken from the text or caption of the message.
+    /// # Returns
+    /// `None` if the message doesn't have text or caption or the quote isn't its part
+    #[must_use]
+    pub fn reply_parameters_with_quote(&self, quote: &str) -> Option<ReplyParameters> {
+        let text = self.text_or_caption()?;
+        let start = text.find(quote)?;
+
+        let position = u16::try_from(text[..start].encode_utf16().count()).ok()?;
+        let length = u16::try_from(quote.encode_utf16().count()).ok()?;
+        let end = position.checked_add(length)?;
+
+        let entities = self
+            .entities()
+            .unwrap_or_default()
+            .iter()
+            .filter(|entity| entity.offset >= position && entity.offset + entity.length <= end)
+            .map(|entity| MessageEntity {
+                offset: entity.offset - position,
+                ..entity.clone()
+            })
+            .collect::<Vec<_>>();
+
+        Some(
+            self.reply_parameters()
+                .quote(quote)
+                .quote_position(position)
+                .quote_entities_option((!entities.is_empty()).then_some(entities)),
+        )
+    }
+
     #[must_use]
     pub const fn chat(&self) -> &Chat {
         match self {
@@ -1778,6 +2026,7 @@ impl Message {
             Message::Audio(message) => &message.chat,
             Message::Document(message) => &message.chat,
             Message::Photo(message) => &message.chat,
+            Message::PaidMedia(message) => &message.chat,
             Message::Sticker(message) => &message.chat,
             Message::Story(message) => &message.chat,
             Message::Video(message) => &message.chat,
@@ -1836,6 +2085,7 @@ impl Message {
             Message::Audio(message) => message.via_bot.as_ref(),
             Message::Document(message) => message.via_bot.as_ref(),
             Message::Photo(message) => message.via_bot.as_ref(),
+            Message::PaidMedia(message) => message.via_bot.as_ref(),
             Message::Sticker(message) => message.via_bot.as_ref(),
             Message::Video(message) => message.via_bot.as_ref(),
             Message::Voice(message) => message.via_bot.as_ref(),
@@ -1884,6 +2134,10 @@ impl Message {
                 Some(ref caption) => Some(caption),
                 None => None,
             },
+            Message::PaidMedia(message) => match message.caption {
+                Some(ref caption) => Some(caption),
+                None => None,
+            },
             _ => None,
         }
     }
@@ -1897,6 +2151,22 @@ impl Message {
         }
     }
 
+    /// Special entities like usernames, URLs, bot commands, etc. that appear in the text or caption
+    #[must_use]
+    pub fn entities(&self) -> Option<&[MessageEntity]> {
+        match self {
+            Message::Text(message) => message.entities.as_deref(),
+            Message::Animation(message) => message.entities.as_deref(),
+            Message::Audio(message) => message.entities.as_deref(),
+            Message::Document(message) => message.entities.as_deref(),
+            Message::Video(message) => message.entities.as_deref(),
+            Message::Voice(message) => message.entities.as_deref(),
+            Message::Photo(message) => message.entities.as_deref(),
+            Message::PaidMedia(message) => message.entities.as_deref(),
+            _ => None,
+        }
+    }
+
     #[must_use]
     pub const fn from(&self) -> Option<&User> {
         match self {
@@ -1905,6 +2175,7 @@ impl Message {
             Message::Audio(message) => message.from.as_ref(),
             Message::Document(message) => message.from.as_ref(),
             Message::Photo(message) => message.from.as_ref(),
+            Message::PaidMedia(message) => message.from.as_ref(),
             Message::Sticker(message) => message.from.as_ref(),
             Message::Story(message) => message.from.as_ref(),
             Message::Video(message) => message.from.as_ref(),
@@ -1965,6 +2236,7 @@ impl Message {
             Message::Audio(message) => message.sender_boost_count,
             Message::Document(message) => message.sender_boost_count,
             Message::Photo(message) => message.sender_boost_count,
+            Message::PaidMedia(message) => message.sender_boost_count,
             Message::Sticker(message) => message.sender_boost_count,
             Message::Story(message) => message.sender_boost_count,
             Message::Video(message) => message.sender_boost_count,
@@ -1989,6 +2261,7 @@ impl Message {
             Message::Audio(message) => message.sender_chat.as_ref(),
             Message::Document(message) => message.sender_chat.as_ref(),
             Message::Photo(message) => message.sender_chat.as_ref(),
+            Message::PaidMedia(message) => message.sender_chat.as_ref(),
             Message::Sticker(message) => message.sender_chat.as_ref(),
             Message::Story(message) => message.sender_chat.as_ref(),
             Message::Video(message) => message.sender_chat.as_ref(),
@@ -2065,6 +2338,10 @@ impl Message {
                 Some(ref author_signature) => Some(author_signature),
                 None => None,
             },
+            Message::PaidMedia(message) => match message.author_signature {
+                Some(ref author_signature) => Some(author_signature),
+                None => None,
+            },
             Message::Sticker(message) => match message.author_signature {
                 Some(ref author_signature) => Some(author_signature),
                 None => None,
@@ -2129,6 +2406,7 @@ impl Message {
             Message::Audio(message) => message.reply_to_message.as_ref(),
             Message::Document(message) => message.reply_to_message.as_ref(),
             Message::Photo(message) => message.reply_to_message.as_ref(),
+            Message::PaidMedia(message) => message.reply_to_message.as_ref(),
             Message::Sticker(message) => message.reply_to_message.as_ref(),
             Message::Video(message) => message.reply_to_message.as_ref(),
             Message::VideoNote(message) => message.reply_to_message.as_ref(),
@@ -2162,6 +2440,7 @@ impl Message {
             Message::Audio(message) => message.reply_to_story.as_ref(),
             Message::Document(message) => message.reply_to_story.as_ref(),
             Message::Photo(message) => message.reply_to_story.as_ref(),
+            Message::PaidMedia(message) => message.reply_to_story.as_ref(),
             Message::Sticker(message) => message.reply_to_story.as_ref(),
             Message::Video(message) => message.reply_to_story.as_ref(),
             Message::VideoNote(message) => message.reply_to_story.as_ref(),
@@ -2192,6 +2471,7 @@ impl Message {
             Message::Audio(message) => message.external_reply.as_ref(),
             Message::Document(message) => message.external_reply.as_ref(),
             Message::Photo(message) => message.external_reply.as_ref(),
+            Message::PaidMedia(message) => message.external_reply.as_ref(),
             Message::Sticker(message) => message.external_reply.as_ref(),
             Message::Story(message) => message.external_reply.as_ref(),
             Message::Video(message) => message.external_reply.as_ref(),
@@ -2220,6 +2500,7 @@ impl Message {
             Message::Video(message) => message.quote.as_ref(),
             Message::Voice(message) => message.quote.as_ref(),
             Message::Photo(message) => message.quote.as_ref(),
+            Message::PaidMedia(message) => message.quote.as_ref(),
             _ => None,
         }
     }
@@ -2232,6 +2513,7 @@ impl Message {
             Message::Audio(message) => message.edit_date,
             Message::Document(message) => message.edit_date,
             Message::Photo(message) => message.edit_date,
+            Message::PaidMedia(message) => message.edit_date,
             Message::Video(message) => message.edit_date,
             Message::Game(message) => message.edit_date,
             Message::Poll(message) => message.edit_date,
@@ -2249,6 +2531,7 @@ impl Message {
             Message::Audio(message) => message.reply_markup.as_ref(),
             Message::Document(message) => message.reply_markup.as_ref(),
             Message::Photo(message) => message.reply_markup.as_ref(),
+            Message::PaidMedia(message) => message.reply_markup.as_ref(),
             Message::Video(message) => message.reply_markup.as_ref(),
             Message::VideoNote(message) => message.reply_markup.as_ref(),
             Message::Voice(message) => message.reply_markup.as_ref(),
@@ -2273,6 +2556,40 @@ impl Message {
             Message::Video(message) => message.is_automatic_forward,
             Message::Voice(message) => message.is_automatic_forward,
             Message::Photo(message) => message.is_automatic_forward,
+            Message::PaidMedia(message) => message.is_automatic_forward,
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn is_topic_message(&self) -> Option<bool> {
+        match self {
+            Message::Text(message) => message.is_topic_message,
+            Message::Animation(message) => message.is_topic_message,
+            Message::Audio(message) => message.is_topic_message,
+            Message::Contact(message) => message.is_topic_message,
+            Message::Dice(message) => message.is_topic_message,
+            Message::Document(message) => message.is_topic_message,
+            Message::Game(message) => message.is_topic_message,
+            Message::Poll(message) => message.is_topic_message,
+            Message::Venue(message) => message.is_topic_message,
+            Message::Location(message) => message.is_topic_message,
+            Message::Photo(message) => message.is_topic_message,
+            Message::PaidMedia(message) => message.is_topic_message,
+            Message::Story(message) => message.is_topic_message,
+            Message::Sticker(message) => message.is_topic_message,
+            Message::Video(message) => message.is_topic_message,
+            Message::VideoNote(message) => message.is_topic_message,
+            Message::Voice(message) => message.is_topic_message,
+            Message::Pinned(message) => message.is_topic_message,
+            Message::Invoice(message) => message.is_topic_message,
+            Message::PassportData(message) => message.is_topic_message,
+            Message::ForumTopicCreated(message) => message.is_topic_message,
+            Message::ForumTopicEdited(message) => message.is_topic_message,
+            Message::ForumTopicClosed(message) => message.is_topic_message,
+            Message::ForumTopicReopened(message) => message.is_topic_message,
+            Message::GeneralForumTopicHidden(message) => message.is_topic_message,
+            Message::GeneralForumTopicUnhidden(message) => message.is_topic_message,
             _ => None,
         }
     }
@@ -2287,6 +2604,7 @@ impl Message {
             Message::Video(message) => message.has_protected_content,
             Message::Voice(message) => message.has_protected_content,
             Message::Photo(message) => message.has_protected_content,
+            Message::PaidMedia(message) => message.has_protected_content,
             _ => None,
         }
     }
@@ -2299,6 +2617,7 @@ impl Message {
             Message::Audio(message) => message.forward_origin.as_ref(),
             Message::Document(message) => message.forward_origin.as_ref(),
             Message::Photo(message) => message.forward_origin.as_ref(),
+            Message::PaidMedia(message) => message.forward_origin.as_ref(),
             Message::Sticker(message) => message.forward_origin.as_ref(),
             Message::Story(message) => message.forward_origin.as_ref(),
             Message::Video(message) => message.forward_origin.as_ref(),
@@ -2707,6 +3026,35 @@ impl Message {
         }
     }
 
+    #[must_use]
+    pub const fn paid_media(&self) -> Option<&types::PaidMediaInfo> {
+        match self {
+            Message::PaidMedia(message) => Some(&message.paid_media),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of Telegram Stars that must be paid to buy access to the paid media of the message
+    #[must_use]
+    pub const fn paid_media_star_count(&self) -> Option<i64> {
+        match self {
+            Message::PaidMedia(message) => Some(message.paid_media.star_count),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of Telegram Stars to be split (or that were split) between giveaway winners
+    /// for the giveaway, the giveaway creation and the giveaway winners messages of Telegram Star giveaways
+    #[must_use]
+    pub const fn giveaway_prize_star_count(&self) -> Option<i64> {
+        match self {
+            Message::Giveaway(message) => message.giveaway.prize_star_count,
+            Message::GiveawayCreated(message) => message.created.prize_star_count,
+            Message::GiveawayWinners(message) => message.winners.prize_star_count,
+            _ => None,
+        }
+    }
+
     #[must_use]
     pub const fn story(&self) -> Option<&types::Story> {
         match self {
@@ -2784,6 +3132,18 @@ macro_rules! impl_try_from_message {
                 }
             }
         }
+
+        impl TryFrom<&Message> for $ty {
+            type Error = ConvertToTypeError;
+
+            fn try_from(value: &Message) -> Result<Self, Self::Error> {
+                if let Message::$variant(val) = value {
+                    Ok((**val).clone())
+                } else {
+                    Err(Self::Error::new("Message", stringify!($ty)))
+                }
+            }
+        }
     };
 }
 
@@ -2830,6 +3190,7 @@ impl_try_from_message!(WebAppData, WebAppData);
 impl_try_from_message!(Poll, Poll);
 impl_try_from_message!(Venue, Venue);
 impl_try_from_message!(Photo, Photo);
+impl_try_from_message!(PaidMedia, PaidMedia);
 impl_try_from_message!(Story, Story);
 impl_try_from_message!(Sticker, Sticker);
 impl_try_from_message!(Video, Video);
@@ -2854,6 +3215,28 @@ impl TryFrom<Update> for Message {
     }
 }
 
+impl<'a> TryFrom<&'a Update> for &'a Message {
+    type Error = ConvertToTypeError;
+
+    fn try_from(update: &'a Update) -> Result<Self, Self::Error> {
+        match &update.kind {
+            UpdateKind::Message(val)
+            | UpdateKind::EditedMessage(val)
+            | UpdateKind::ChannelPost(val)
+            | UpdateKind::EditedChannelPost(val) => Ok(val),
+            _ => Err(ConvertToTypeError::new("Update", "Message")),
+        }
+    }
+}
+
+impl TryFrom<&Update> for Message {
+    type Error = ConvertToTypeError;
+
+    fn try_from(update: &Update) -> Result<Self, Self::Error> {
+        <&Message>::try_from(update).cloned()
+    }
+}
+
 macro_rules! impl_try_from_update {
     ($ty:ty) => {
         impl TryFrom<Update> for $ty {
@@ -2863,6 +3246,14 @@ macro_rules! impl_try_from_update {
                 Message::try_from(update)?.try_into()
             }
         }
+
+        impl TryFrom<&Update> for $ty {
+            type Error = ConvertToTypeError;
+
+            fn try_from(update: &Update) -> Result<Self, Self::Error> {
+                <&Message>::try_from(update)?.try_into()
+            }
+        }
     };
 }
 
@@ -2909,6 +3300,7 @@ impl_try_from_update!(WebAppData);
 impl_try_from_update!(Poll);
 impl_try_from_update!(Venue);
 impl_try_from_update!(Photo);
+impl_try_from_update!(PaidMedia);
 impl_try_from_update!(Story);
 impl_try_from_update!(Sticker);
 impl_try_from_update!(Video);
@@ -2978,6 +3370,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn deserialize_by_content_key() {
+        // Content key with the higher priority is used regardless of the keys order
+        let message: Message = serde_json::from_str(
+            r#"{
+                "document": {"file_id": "test", "file_unique_id": "test"},
+                "message_id": 1,
+                "date": 0,
+                "chat": {"id": -1, "title": "test", "type": "channel"},
+                "animation": {"file_id": "test", "file_unique_id": "test", "width": 1, "height": 1, "duration": 1}
+            }"#,
+        )
+        .unwrap();
+        assert!(matches!(message, Message::Animation(_)));
+
+        let err =
+            serde_json::from_str::<Message>(r#"{"message_id": 1, "date": 0, "text": "test"}"#)
+                .unwrap_err();
+        assert!(err.to_string().starts_with(
+            "Failed to deserialize message with `text` content as `Text`: missing field `chat`"
+        ));
+
+        let err = serde_json::from_str::<Message>(
+            r#"{"message_id": 1, "date": 0, "chat": {"id": -1, "type": "channel"}, "new_content": {}}"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().starts_with(
+            "Unknown message type, no known content key is found among the message keys: message_id, date, chat, new_content"
+        ));
+    }
+
     #[test]
     fn deserialize_text_with_forward() {
         let jsons = [
@@ -3192,6 +3615,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn deserialize_paid_media() {
+        let jsons = [serde_json::json!({
+            "message_id": 1,
+            "date": 0,
+            "chat": {
+                "id": -1,
+                "title": "test",
+                "type": "channel",
+            },
+            "paid_media": {
+                "star_count": 10,
+                "paid_media": [
+                    {
+                        "type": "preview",
+                        "width": 1,
+                        "height": 1,
+                    },
+                    {
+                        "type": "photo",
+                        "photo": [{
+                            "file_id": "test",
+                            "file_unique_id": "test",
+                            "width": 1,
+                            "height": 1,
+                        }],
+                    },
+                ],
+            },
+            "caption": "test",
+        })];
+
+        for json in jsons {
+            let message_kind = serde_json::from_value(json.clone()).unwrap();
+            let message: Message = serde_json::from_value(json).unwrap();
+
+            assert_eq!(message.paid_media_star_count(), Some(10));
+            assert_eq!(message.caption(), Some("test"));
+
+            match message {
+                Message::PaidMedia(message) => {
+                    assert!(message.paid_media.paid_media[0].is_preview());
+                    assert_eq!(*message, message_kind);
+                }
+                _ => panic!("Unexpected message type: {message:?}"),
+            }
+        }
+    }
+
     #[test]
     fn deserialize_sticker() {
         let jsons = [serde_json::json!({
@@ -4344,29 +4816,53 @@ mod tests {
 
     #[test]
     fn deserialize_giveaway() {
-        let jsons = [serde_json::json!({
-            "message_id": 1,
-            "date": 0,
-            "chat": {
-                "id": -1,
-                "title": "test",
-                "type": "channel",
-            },
-            "giveaway": {
-                "chats": [{
+        let jsons = [
+            serde_json::json!({
+                "message_id": 1,
+                "date": 0,
+                "chat": {
                     "id": -1,
                     "title": "test",
                     "type": "channel",
-                }],
-                "winners_selection_date": 0,
-                "winner_count": 1,
-            },
-        })];
+                },
+                "giveaway": {
+                    "chats": [{
+                        "id": -1,
+                        "title": "test",
+                        "type": "channel",
+                    }],
+                    "winners_selection_date": 0,
+                    "winner_count": 1,
+                },
+            }),
+            serde_json::json!({
+                "message_id": 1,
+                "date": 0,
+                "chat": {
+                    "id": -1,
+                    "title": "test",
+                    "type": "channel",
+                },
+                "giveaway": {
+                    "chats": [{
+                        "id": -1,
+                        "title": "test",
+                        "type": "channel",
+                    }],
+                    "winners_selection_date": 0,
+                    "winner_count": 1,
+                    "prize_star_count": 500,
+                },
+            }),
+        ];
 
         for json in jsons {
+            let star_count = json["giveaway"]["prize_star_count"].as_i64();
             let message_kind = serde_json::from_value(json.clone()).unwrap();
             let message: Message = serde_json::from_value(json).unwrap();
 
+            assert_eq!(message.giveaway_prize_star_count(), star_count);
+
             match message {
                 Message::Giveaway(message) => assert_eq!(*message, message_kind),
                 _ => panic!("Unexpected message type: {message:?}"),
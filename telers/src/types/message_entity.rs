@@ -230,6 +230,42 @@ impl MessageEntity {
     pub fn kind(&self) -> &Kind {
         &self.kind
     }
+
+    /// Extracts the part of the text, which the entity belongs to.
+    /// Offset and length of the entity are measured in UTF-16 code units, so they are converted to byte indexes of the text.
+    /// # Returns
+    /// Part of the text, if the entity is inside the text
+    #[must_use]
+    pub fn extract_from<'a>(&self, text: &'a str) -> Option<&'a str> {
+        let start = usize::from(self.offset);
+        let end = start + usize::from(self.length);
+
+        let mut start_index = None;
+        let mut position = 0;
+
+        for (index, ch) in text.char_indices() {
+            if position == start {
+                start_index = Some(index);
+            }
+            if position == end {
+                return text.get(start_index?..index);
+            }
+            if position > end {
+                return None;
+            }
+
+            position += ch.len_utf16();
+        }
+
+        if position == start {
+            start_index = Some(text.len());
+        }
+        if position == end {
+            text.get(start_index?..)
+        } else {
+            None
+        }
+    }
 }
 
 impl From<Pre> for Kind {
@@ -255,3 +291,28 @@ impl From<CustomEmoji> for Kind {
         Self::CustomEmoji(custom_emoji)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_from() {
+        let text = "Hi 👋 @telers #news";
+
+        assert_eq!(
+            MessageEntity::new_mention(6, 7).extract_from(text),
+            Some("@telers")
+        );
+        assert_eq!(
+            MessageEntity::new_hashtag(14, 5).extract_from(text),
+            Some("#news")
+        );
+        assert_eq!(MessageEntity::new_bold(3, 2).extract_from(text), Some("👋"));
+        assert_eq!(MessageEntity::new_bold(0, 0).extract_from(text), Some(""));
+        // Offset in the middle of the surrogate pair
+        assert_eq!(MessageEntity::new_bold(4, 2).extract_from(text), None);
+        // Out of the text
+        assert_eq!(MessageEntity::new_bold(14, 6).extract_from(text), None);
+    }
+}
@@ -1,14 +1,14 @@
 use super::{Chat, ReactionCount, Update, UpdateKind};
 
-use crate::{errors::ConvertToTypeError, extractors::FromEvent};
+use crate::errors::ConvertToTypeError;
 
 use serde::Deserialize;
 
 /// This object represents reaction changes on a message with anonymous reactions.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#messagereactioncountupdated>
-#[derive(Debug, Default, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct MessageReactionCountUpdated {
     /// The chat containing the message
     pub chat: Chat,
@@ -31,3 +31,14 @@ impl TryFrom<Update> for MessageReactionCountUpdated {
         }
     }
 }
+
+impl TryFrom<&Update> for MessageReactionCountUpdated {
+    type Error = ConvertToTypeError;
+
+    fn try_from(update: &Update) -> Result<Self, Self::Error> {
+        match &update.kind {
+            UpdateKind::MessageReactionCount(val) => Ok(val.clone()),
+            _ => Err(ConvertToTypeError::new("Update", "MessageReactionCount")),
+        }
+    }
+}
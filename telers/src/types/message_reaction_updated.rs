@@ -1,14 +1,14 @@
 use super::{Chat, ReactionType, Update, UpdateKind, User};
 
-use crate::{errors::ConvertToTypeError, extractors::FromEvent};
+use crate::errors::ConvertToTypeError;
 
 use serde::Deserialize;
 
 /// This object represents a change of a reaction on a message performed by a user.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#messagereactionupdated>
-#[derive(Debug, Default, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct MessageReactionUpdated {
     /// The chat containing the message the user reacted to
     pub chat: Chat,
@@ -38,6 +38,17 @@ impl TryFrom<Update> for MessageReactionUpdated {
     }
 }
 
+impl TryFrom<&Update> for MessageReactionUpdated {
+    type Error = ConvertToTypeError;
+
+    fn try_from(update: &Update) -> Result<Self, Self::Error> {
+        match &update.kind {
+            UpdateKind::MessageReaction(val) => Ok(val.clone()),
+            _ => Err(ConvertToTypeError::new("Update", "MessageReaction")),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
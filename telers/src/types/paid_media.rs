@@ -0,0 +1,43 @@
+use super::{PaidMediaPhoto, PaidMediaPreview, PaidMediaVideo};
+
+use serde::Deserialize;
+
+/// This object describes paid media. It can be one of
+/// - [`PaidMediaPreview`]
+/// - [`PaidMediaPhoto`]
+/// - [`PaidMediaVideo`]
+/// # Documentation
+/// <https://core.telegram.org/bots/api#paidmedia>
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PaidMedia {
+    Preview(PaidMediaPreview),
+    Photo(PaidMediaPhoto),
+    Video(PaidMediaVideo),
+}
+
+impl PaidMedia {
+    /// Check if the media isn't available before the payment
+    #[must_use]
+    pub const fn is_preview(&self) -> bool {
+        matches!(self, Self::Preview(_))
+    }
+}
+
+impl From<PaidMediaPreview> for PaidMedia {
+    fn from(media: PaidMediaPreview) -> Self {
+        Self::Preview(media)
+    }
+}
+
+impl From<PaidMediaPhoto> for PaidMedia {
+    fn from(media: PaidMediaPhoto) -> Self {
+        Self::Photo(media)
+    }
+}
+
+impl From<PaidMediaVideo> for PaidMedia {
+    fn from(media: PaidMediaVideo) -> Self {
+        Self::Video(media)
+    }
+}
@@ -0,0 +1,12 @@
+use super::PhotoSize;
+
+use serde::Deserialize;
+
+/// The paid media is a photo.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#paidmediaphoto>
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize)]
+pub struct PaidMediaPhoto {
+    /// The photo
+    pub photo: Box<[PhotoSize]>,
+}
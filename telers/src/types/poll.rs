@@ -1,22 +1,22 @@
 use super::{MessageEntity, PollOption, Update, UpdateKind};
 
-use crate::{errors::ConvertToTypeError, extractors::FromEvent};
+use crate::errors::ConvertToTypeError;
 
 use serde::Deserialize;
 
 /// This object contains information about a poll.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#poll>
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum Poll {
     Regular(Regular),
     Quiz(Quiz),
 }
 
-#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct Regular {
     /// Unique poll identifier
     pub id: Box<str>,
@@ -38,8 +38,8 @@ pub struct Regular {
     pub close_date: Option<i64>,
 }
 
-#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct Quiz {
     /// Unique poll identifier
     pub id: Box<str>,
@@ -67,6 +67,57 @@ pub struct Quiz {
     pub close_date: Option<i64>,
 }
 
+impl Poll {
+    #[must_use]
+    pub const fn id(&self) -> &str {
+        match self {
+            Poll::Regular(poll) => &poll.id,
+            Poll::Quiz(poll) => &poll.id,
+        }
+    }
+
+    #[must_use]
+    pub const fn question(&self) -> &str {
+        match self {
+            Poll::Regular(poll) => &poll.question,
+            Poll::Quiz(poll) => &poll.question,
+        }
+    }
+
+    #[must_use]
+    pub const fn options(&self) -> &[PollOption] {
+        match self {
+            Poll::Regular(poll) => &poll.options,
+            Poll::Quiz(poll) => &poll.options,
+        }
+    }
+
+    #[must_use]
+    pub const fn total_voter_count(&self) -> i64 {
+        match self {
+            Poll::Regular(poll) => poll.total_voter_count,
+            Poll::Quiz(poll) => poll.total_voter_count,
+        }
+    }
+
+    #[must_use]
+    pub const fn is_closed(&self) -> bool {
+        match self {
+            Poll::Regular(poll) => poll.is_closed,
+            Poll::Quiz(poll) => poll.is_closed,
+        }
+    }
+
+    /// Returns 0-based identifier of the correct answer option, if the poll is a quiz and the identifier is available
+    #[must_use]
+    pub const fn correct_option_id(&self) -> Option<i64> {
+        match self {
+            Poll::Regular(_) => None,
+            Poll::Quiz(poll) => poll.correct_option_id,
+        }
+    }
+}
+
 impl Default for Poll {
     fn default() -> Self {
         Self::Regular(Regular::default())
@@ -108,6 +159,17 @@ impl TryFrom<Update> for Poll {
     }
 }
 
+impl TryFrom<&Update> for Poll {
+    type Error = ConvertToTypeError;
+
+    fn try_from(update: &Update) -> Result<Self, Self::Error> {
+        match &update.kind {
+            UpdateKind::Poll(val) => Ok(val.clone()),
+            _ => Err(ConvertToTypeError::new("Update", "Poll")),
+        }
+    }
+}
+
 impl TryFrom<Update> for Regular {
     type Error = ConvertToTypeError;
 
@@ -116,6 +178,18 @@ impl TryFrom<Update> for Regular {
     }
 }
 
+impl TryFrom<&Update> for Regular {
+    type Error = ConvertToTypeError;
+
+    fn try_from(update: &Update) -> Result<Self, Self::Error> {
+        match &update.kind {
+            UpdateKind::Poll(Poll::Regular(val)) => Ok(val.clone()),
+            UpdateKind::Poll(_) => Err(ConvertToTypeError::new("Poll", "Regular")),
+            _ => Err(ConvertToTypeError::new("Update", "Poll")),
+        }
+    }
+}
+
 impl TryFrom<Update> for Quiz {
     type Error = ConvertToTypeError;
 
@@ -123,3 +197,15 @@ impl TryFrom<Update> for Quiz {
         Poll::try_from(update)?.try_into()
     }
 }
+
+impl TryFrom<&Update> for Quiz {
+    type Error = ConvertToTypeError;
+
+    fn try_from(update: &Update) -> Result<Self, Self::Error> {
+        match &update.kind {
+            UpdateKind::Poll(Poll::Quiz(val)) => Ok(val.clone()),
+            UpdateKind::Poll(_) => Err(ConvertToTypeError::new("Poll", "Quiz")),
+            _ => Err(ConvertToTypeError::new("Update", "Poll")),
+        }
+    }
+}
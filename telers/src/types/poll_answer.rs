@@ -1,14 +1,14 @@
 use super::{Chat, Update, UpdateKind, User};
 
-use crate::{errors::ConvertToTypeError, FromEvent};
+use crate::errors::ConvertToTypeError;
 
 use serde::Deserialize;
 
 /// This object represents an answer of a user in a non-anonymous poll.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#pollanswer>
-#[derive(Debug, Default, Clone, PartialEq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct PollAnswer {
     /// Unique poll identifier
     pub poll_id: Box<str>,
@@ -30,3 +30,14 @@ impl TryFrom<Update> for PollAnswer {
         }
     }
 }
+
+impl TryFrom<&Update> for PollAnswer {
+    type Error = ConvertToTypeError;
+
+    fn try_from(update: &Update) -> Result<Self, Self::Error> {
+        match &update.kind {
+            UpdateKind::PollAnswer(val) => Ok(val.clone()),
+            _ => Err(ConvertToTypeError::new("Update", "PollAnswer")),
+        }
+    }
+}
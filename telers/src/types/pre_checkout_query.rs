@@ -1,14 +1,14 @@
 use super::{OrderInfo, Update, UpdateKind, User};
 
-use crate::{errors::ConvertToTypeError, FromEvent};
+use crate::errors::ConvertToTypeError;
 
 use serde::Deserialize;
 
 /// This object contains information about an incoming pre-checkout query.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#precheckoutquery>
-#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct PreCheckoutQuery {
     /// Unique query identifier
     pub id: Box<str>,
@@ -36,3 +36,14 @@ impl TryFrom<Update> for PreCheckoutQuery {
         }
     }
 }
+
+impl TryFrom<&Update> for PreCheckoutQuery {
+    type Error = ConvertToTypeError;
+
+    fn try_from(update: &Update) -> Result<Self, Self::Error> {
+        match &update.kind {
+            UpdateKind::PreCheckoutQuery(val) => Ok(val.clone()),
+            _ => Err(ConvertToTypeError::new("Update", "PreCheckoutQuery")),
+        }
+    }
+}
@@ -1,14 +1,14 @@
 use super::{ShippingAddress, Update, UpdateKind, User};
 
-use crate::{errors::ConvertToTypeError, FromEvent};
+use crate::errors::ConvertToTypeError;
 
 use serde::Deserialize;
 
 /// This object contains information about an incoming shipping query.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#shippingquery>
-#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize, FromEvent)]
-#[event(try_from = Update)]
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(try_from = &Update))]
 pub struct ShippingQuery {
     /// Unique query identifier
     pub id: Box<str>,
@@ -30,3 +30,14 @@ impl TryFrom<Update> for ShippingQuery {
         }
     }
 }
+
+impl TryFrom<&Update> for ShippingQuery {
+    type Error = ConvertToTypeError;
+
+    fn try_from(update: &Update) -> Result<Self, Self::Error> {
+        match &update.kind {
+            UpdateKind::ShippingQuery(val) => Ok(val.clone()),
+            _ => Err(ConvertToTypeError::new("Update", "ShippingQuery")),
+        }
+    }
+}
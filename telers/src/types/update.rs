@@ -1,13 +1,15 @@
 use super::{
-    CallbackQuery, Chat, ChatBoostRemoved, ChatBoostSource, ChatBoostSourcePremium,
-    ChatBoostUpdated, ChatJoinRequest, ChatMemberUpdated, ChosenInlineResult, InaccessibleMessage,
-    InlineQuery, MaybeInaccessibleMessage, Message, MessageReactionCountUpdated,
-    MessageReactionUpdated, Poll, PollAnswer, PreCheckoutQuery, ShippingQuery, User,
+    CallbackQuery, Chat, ChatBoostRemoved, ChatBoostUpdated, ChatJoinRequest, ChatMemberUpdated,
+    ChosenInlineResult, InlineQuery, Message, MessageReactionCountUpdated, MessageReactionUpdated,
+    Poll, PollAnswer, PreCheckoutQuery, ShippingQuery, SuccessfulPayment, User,
 };
 
-use crate::{enums::UpdateType, extractors::FromEvent};
+use crate::enums::UpdateType;
 
-use serde::{de::MapAccess, Deserialize, Deserializer};
+use serde::{
+    de::{IgnoredAny, MapAccess},
+    Deserialize, Deserializer,
+};
 use std::{
     fmt::{self, Formatter},
     str::FromStr as _,
@@ -17,17 +19,14 @@ use std::{
 /// At most **one** of the optional parameters can be present in any given update.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#update>
-#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct Update {
-    #[serde(rename = "update_id")]
     pub id: i64,
-
-    #[serde(flatten)]
     pub kind: Kind,
 }
 
-#[derive(Debug, Clone, PartialEq, FromEvent)]
-#[event(from = Update)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "dispatcher", derive(crate::FromEvent), event(from = Update))]
 pub enum Kind {
     /// New incoming message of any kind — text, photo, sticker, etc.
     Message(Message),
@@ -106,16 +105,10 @@ impl Kind {
             | Kind::EditedMessage(message)
             | Kind::ChannelPost(message)
             | Kind::EditedChannelPost(message) => message.caption(),
-            Kind::CallbackQuery(CallbackQuery { message, .. }) => {
-                let Some(message) = message else {
-                    return None;
-                };
-
-                match message {
-                    MaybeInaccessibleMessage::Message(message) => message.caption(),
-                    MaybeInaccessibleMessage::InaccessibleMessage(_) => None,
-                }
-            }
+            Kind::CallbackQuery(callback_query) => match callback_query.accessible_message() {
+                Some(message) => message.caption(),
+                None => None,
+            },
             Kind::InlineQuery(_)
             | Kind::ChosenInlineResult(_)
             | Kind::ShippingQuery(_)
@@ -140,6 +133,9 @@ impl Kind {
         }
     }
 
+    /// Gets user, who caused the event.
+    /// For reactions it's the user, who changed the reaction, if it isn't anonymous,
+    /// and for boosts it's the user, who boosted the chat, if it's known
     #[must_use]
     pub const fn from(&self) -> Option<&User> {
         match self {
@@ -157,11 +153,9 @@ impl Kind {
             | Kind::ChatJoinRequest(ChatJoinRequest { from, .. }) => Some(from),
             Kind::PollAnswer(PollAnswer { user, .. })
             | Kind::MessageReaction(MessageReactionUpdated { user, .. }) => user.as_ref(),
-            Kind::ChatBoost(ChatBoostUpdated { boost, .. }) => match boost {
-                ChatBoostSource::Premium(ChatBoostSourcePremium { user }) => Some(user),
-                ChatBoostSource::GiftCode(_) | ChatBoostSource::Giveaway(_) => None,
-            },
-            Kind::Poll(_) | Kind::MessageReactionCount(_) | Kind::RemovedChatBoost(_) => None,
+            Kind::ChatBoost(ChatBoostUpdated { boost: source, .. })
+            | Kind::RemovedChatBoost(ChatBoostRemoved { source, .. }) => source.user(),
+            Kind::Poll(_) | Kind::MessageReactionCount(_) => None,
         }
     }
 
@@ -173,6 +167,9 @@ impl Kind {
         }
     }
 
+    /// Gets chat, where the event occurred.
+    /// For reactions it's the chat of the message, not the chat on behalf of which the reaction was changed
+    /// (see [`Kind::sender_chat`])
     #[must_use]
     pub const fn chat(&self) -> Option<&Chat> {
         match self {
@@ -180,26 +177,14 @@ impl Kind {
             | Kind::EditedMessage(message)
             | Kind::ChannelPost(message)
             | Kind::EditedChannelPost(message) => Some(message.chat()),
-            Kind::CallbackQuery(CallbackQuery { message, .. }) => {
-                let Some(message) = message else {
-                    return None;
-                };
-
-                match message {
-                    MaybeInaccessibleMessage::Message(message) => Some(message.chat()),
-                    MaybeInaccessibleMessage::InaccessibleMessage(InaccessibleMessage {
-                        chat,
-                        ..
-                    }) => Some(chat),
-                }
-            }
+            Kind::CallbackQuery(callback_query) => callback_query.chat(),
             Kind::MyChatMember(ChatMemberUpdated { chat, .. })
             | Kind::ChatMember(ChatMemberUpdated { chat, .. })
             | Kind::ChatJoinRequest(ChatJoinRequest { chat, .. })
+            | Kind::MessageReaction(MessageReactionUpdated { chat, .. })
             | Kind::MessageReactionCount(MessageReactionCountUpdated { chat, .. })
             | Kind::ChatBoost(ChatBoostUpdated { chat, .. })
             | Kind::RemovedChatBoost(ChatBoostRemoved { chat, .. }) => Some(chat),
-            Kind::MessageReaction(MessageReactionUpdated { actor_chat, .. }) => actor_chat.as_ref(),
             Kind::InlineQuery(_)
             | Kind::ChosenInlineResult(_)
             | Kind::ShippingQuery(_)
@@ -218,6 +203,8 @@ impl Kind {
         }
     }
 
+    /// Gets chat, on behalf of which the event was caused:
+    /// sender chat of the message, chat of the anonymous poll vote or reaction
     #[must_use]
     pub const fn sender_chat(&self) -> Option<&Chat> {
         match self {
@@ -225,26 +212,20 @@ impl Kind {
             | Kind::EditedMessage(message)
             | Kind::ChannelPost(message)
             | Kind::EditedChannelPost(message) => message.sender_chat(),
-            Kind::CallbackQuery(CallbackQuery { message, .. }) => {
-                let Some(message) = message else {
-                    return None;
-                };
-
-                match message {
-                    MaybeInaccessibleMessage::Message(message) => message.sender_chat(),
-                    MaybeInaccessibleMessage::InaccessibleMessage(_) => None,
-                }
-            }
+            Kind::CallbackQuery(callback_query) => match callback_query.accessible_message() {
+                Some(message) => message.sender_chat(),
+                None => None,
+            },
+            Kind::PollAnswer(PollAnswer { voter_chat, .. }) => voter_chat.as_ref(),
+            Kind::MessageReaction(MessageReactionUpdated { actor_chat, .. }) => actor_chat.as_ref(),
             Kind::InlineQuery(_)
             | Kind::ChosenInlineResult(_)
             | Kind::ShippingQuery(_)
             | Kind::PreCheckoutQuery(_)
-            | Kind::PollAnswer(_)
             | Kind::MyChatMember(_)
             | Kind::ChatMember(_)
             | Kind::ChatJoinRequest(_)
             | Kind::Poll(_)
-            | Kind::MessageReaction(_)
             | Kind::MessageReactionCount(_)
             | Kind::ChatBoost(_)
             | Kind::RemovedChatBoost(_) => None,
@@ -260,6 +241,32 @@ impl Kind {
         }
     }
 
+    /// Gets identifier of the message, which the update is related to.
+    /// For callback queries, it's identifier of the message with the callback button, even if the message is inaccessible.
+    #[must_use]
+    pub const fn message_id(&self) -> Option<i64> {
+        match self {
+            Kind::Message(message)
+            | Kind::EditedMessage(message)
+            | Kind::ChannelPost(message)
+            | Kind::EditedChannelPost(message) => Some(message.id()),
+            Kind::CallbackQuery(callback_query) => callback_query.message_id(),
+            Kind::MessageReaction(message_reaction) => Some(message_reaction.id),
+            Kind::MessageReactionCount(message_reaction_count) => Some(message_reaction_count.id),
+            Kind::InlineQuery(_)
+            | Kind::ChosenInlineResult(_)
+            | Kind::ShippingQuery(_)
+            | Kind::PreCheckoutQuery(_)
+            | Kind::PollAnswer(_)
+            | Kind::MyChatMember(_)
+            | Kind::ChatMember(_)
+            | Kind::ChatJoinRequest(_)
+            | Kind::Poll(_)
+            | Kind::ChatBoost(_)
+            | Kind::RemovedChatBoost(_) => None,
+        }
+    }
+
     #[must_use]
     pub const fn message_thread_id(&self) -> Option<i64> {
         match self {
@@ -267,16 +274,10 @@ impl Kind {
             | Kind::EditedMessage(message)
             | Kind::ChannelPost(message)
             | Kind::EditedChannelPost(message) => message.thread_id(),
-            Kind::CallbackQuery(CallbackQuery { message, .. }) => {
-                let Some(message) = message else {
-                    return None;
-                };
-
-                match message {
-                    MaybeInaccessibleMessage::Message(message) => message.thread_id(),
-                    MaybeInaccessibleMessage::InaccessibleMessage(_) => None,
-                }
-            }
+            Kind::CallbackQuery(callback_query) => match callback_query.accessible_message() {
+                Some(message) => message.thread_id(),
+                None => None,
+            },
             Kind::InlineQuery(_)
             | Kind::ChosenInlineResult(_)
             | Kind::ShippingQuery(_)
@@ -292,6 +293,71 @@ impl Kind {
             | Kind::RemovedChatBoost(_) => None,
         }
     }
+
+    /// Gets invoice payload of the successful payment message, shipping query or pre-checkout query
+    #[must_use]
+    pub const fn invoice_payload(&self) -> Option<&str> {
+        match self {
+            Kind::Message(message)
+            | Kind::EditedMessage(message)
+            | Kind::ChannelPost(message)
+            | Kind::EditedChannelPost(message) => match message.successful_payment() {
+                Some(SuccessfulPayment {
+                    invoice_payload, ..
+                }) => Some(invoice_payload),
+                None => None,
+            },
+            Kind::ShippingQuery(ShippingQuery {
+                invoice_payload, ..
+            })
+            | Kind::PreCheckoutQuery(PreCheckoutQuery {
+                invoice_payload, ..
+            }) => Some(invoice_payload),
+            Kind::InlineQuery(_)
+            | Kind::ChosenInlineResult(_)
+            | Kind::CallbackQuery(_)
+            | Kind::PollAnswer(_)
+            | Kind::MyChatMember(_)
+            | Kind::ChatMember(_)
+            | Kind::ChatJoinRequest(_)
+            | Kind::Poll(_)
+            | Kind::MessageReaction(_)
+            | Kind::MessageReactionCount(_)
+            | Kind::ChatBoost(_)
+            | Kind::RemovedChatBoost(_) => None,
+        }
+    }
+
+    /// Gets date of the event in Unix time.
+    /// For edited messages it's the date of the last edit.
+    #[must_use]
+    pub const fn date(&self) -> Option<i64> {
+        match self {
+            Kind::Message(message) | Kind::ChannelPost(message) => Some(message.date()),
+            Kind::EditedMessage(message) | Kind::EditedChannelPost(message) => {
+                match message.edit_date() {
+                    Some(edit_date) => Some(edit_date),
+                    None => Some(message.date()),
+                }
+            }
+            Kind::MessageReaction(MessageReactionUpdated { date, .. })
+            | Kind::MessageReactionCount(MessageReactionCountUpdated { date, .. })
+            | Kind::MyChatMember(ChatMemberUpdated { date, .. })
+            | Kind::ChatMember(ChatMemberUpdated { date, .. })
+            | Kind::ChatJoinRequest(ChatJoinRequest { date, .. })
+            | Kind::RemovedChatBoost(ChatBoostRemoved {
+                remove_date: date, ..
+            }) => Some(*date),
+            Kind::InlineQuery(_)
+            | Kind::ChosenInlineResult(_)
+            | Kind::CallbackQuery(_)
+            | Kind::ShippingQuery(_)
+            | Kind::PreCheckoutQuery(_)
+            | Kind::PollAnswer(_)
+            | Kind::Poll(_)
+            | Kind::ChatBoost(_) => None,
+        }
+    }
 }
 
 impl Default for Kind {
@@ -308,6 +374,109 @@ impl From<Update> for Kind {
     }
 }
 
+impl<'de> Deserialize<'de> for Update {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = Update;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("a map")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut id = None;
+                let mut kind = None;
+                let mut unknown_key = None;
+
+                // The update kind is deserialized directly from the map without buffering of the whole update,
+                // so the keys can be in any order and unknown keys are skipped
+                while let Some(field) = map.next_key::<Field>()? {
+                    match field {
+                        Field::Id => {
+                            if id.is_some() {
+                                return Err(serde::de::Error::duplicate_field("update_id"));
+                            }
+                            id = Some(map.next_value()?);
+                        }
+                        Field::Kind(update_type) if kind.is_none() => {
+                            kind = Some(deserialize_kind(&mut map, update_type)?);
+                        }
+                        Field::Kind(_) => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                        Field::Unknown(key) => {
+                            map.next_value::<IgnoredAny>()?;
+                            unknown_key.get_or_insert(key);
+                        }
+                    }
+                }
+
+                let Some(id) = id else {
+                    return Err(serde::de::Error::missing_field("update_id"));
+                };
+                let Some(kind) = kind else {
+                    return Err(match unknown_key {
+                        Some(key) => {
+                            serde::de::Error::custom(format!("Unknown update type: {key}"))
+                        }
+                        None => serde::de::Error::custom("No update type key found"),
+                    });
+                };
+
+                Ok(Update { id, kind })
+            }
+        }
+
+        deserializer.deserialize_map(Visitor)
+    }
+}
+
+/// Key of the update map
+enum Field {
+    Id,
+    Kind(UpdateType),
+    Unknown(Box<str>),
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = Field;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("an update field")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                if value == "update_id" {
+                    return Ok(Field::Id);
+                }
+
+                Ok(UpdateType::from_str(value)
+                    .map_or_else(|_| Field::Unknown(value.into()), Field::Kind))
+            }
+        }
+
+        deserializer.deserialize_identifier(Visitor)
+    }
+}
+
 impl<'de> Deserialize<'de> for Kind {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -353,61 +522,7 @@ impl<'de> Deserialize<'de> for Kind {
                     }
                 };
 
-                let update_kind = match update_type {
-                    UpdateType::Message => map.next_value::<Message>().map(Kind::Message),
-                    UpdateType::InlineQuery => {
-                        map.next_value::<InlineQuery>().map(Kind::InlineQuery)
-                    }
-                    UpdateType::ChosenInlineResult => map
-                        .next_value::<ChosenInlineResult>()
-                        .map(Kind::ChosenInlineResult),
-                    UpdateType::CallbackQuery => {
-                        map.next_value::<CallbackQuery>().map(Kind::CallbackQuery)
-                    }
-                    UpdateType::ChannelPost => map.next_value::<Message>().map(Kind::ChannelPost),
-                    UpdateType::EditedMessage => {
-                        map.next_value::<Message>().map(Kind::EditedMessage)
-                    }
-                    UpdateType::EditedChannelPost => {
-                        map.next_value::<Message>().map(Kind::EditedChannelPost)
-                    }
-                    UpdateType::ShippingQuery => {
-                        map.next_value::<ShippingQuery>().map(Kind::ShippingQuery)
-                    }
-                    UpdateType::PreCheckoutQuery => map
-                        .next_value::<PreCheckoutQuery>()
-                        .map(Kind::PreCheckoutQuery),
-                    UpdateType::Poll => map.next_value::<Poll>().map(Kind::Poll),
-                    UpdateType::PollAnswer => map.next_value::<PollAnswer>().map(Kind::PollAnswer),
-                    UpdateType::MyChatMember => map
-                        .next_value::<ChatMemberUpdated>()
-                        .map(Kind::MyChatMember),
-                    UpdateType::ChatMember => {
-                        map.next_value::<ChatMemberUpdated>().map(Kind::ChatMember)
-                    }
-                    UpdateType::ChatJoinRequest => map
-                        .next_value::<ChatJoinRequest>()
-                        .map(Kind::ChatJoinRequest),
-                    UpdateType::MessageReaction => map
-                        .next_value::<MessageReactionUpdated>()
-                        .map(Kind::MessageReaction),
-                    UpdateType::MessageReactionCount => map
-                        .next_value::<MessageReactionCountUpdated>()
-                        .map(Kind::MessageReactionCount),
-                    UpdateType::ChatBoost => {
-                        map.next_value::<ChatBoostUpdated>().map(Kind::ChatBoost)
-                    }
-                    UpdateType::RemovedChatBoost => map
-                        .next_value::<ChatBoostRemoved>()
-                        .map(Kind::RemovedChatBoost),
-                };
-
-                match update_kind {
-                    Ok(update_kind) => Ok(update_kind),
-                    Err(err) => Err(serde::de::Error::custom(format!(
-                        "Error deserializing update kind: {err}"
-                    ))),
-                }
+                deserialize_kind(&mut map, update_type)
             }
         }
 
@@ -415,6 +530,54 @@ impl<'de> Deserialize<'de> for Kind {
     }
 }
 
+/// Deserializes the update kind by the update type from the next value of the map
+fn deserialize_kind<'de, A>(map: &mut A, update_type: UpdateType) -> Result<Kind, A::Error>
+where
+    A: MapAccess<'de>,
+{
+    let update_kind = match update_type {
+        UpdateType::Message => map.next_value::<Message>().map(Kind::Message),
+        UpdateType::InlineQuery => map.next_value::<InlineQuery>().map(Kind::InlineQuery),
+        UpdateType::ChosenInlineResult => map
+            .next_value::<ChosenInlineResult>()
+            .map(Kind::ChosenInlineResult),
+        UpdateType::CallbackQuery => map.next_value::<CallbackQuery>().map(Kind::CallbackQuery),
+        UpdateType::ChannelPost => map.next_value::<Message>().map(Kind::ChannelPost),
+        UpdateType::EditedMessage => map.next_value::<Message>().map(Kind::EditedMessage),
+        UpdateType::EditedChannelPost => map.next_value::<Message>().map(Kind::EditedChannelPost),
+        UpdateType::ShippingQuery => map.next_value::<ShippingQuery>().map(Kind::ShippingQuery),
+        UpdateType::PreCheckoutQuery => map
+            .next_value::<PreCheckoutQuery>()
+            .map(Kind::PreCheckoutQuery),
+        UpdateType::Poll => map.next_value::<Poll>().map(Kind::Poll),
+        UpdateType::PollAnswer => map.next_value::<PollAnswer>().map(Kind::PollAnswer),
+        UpdateType::MyChatMember => map
+            .next_value::<ChatMemberUpdated>()
+            .map(Kind::MyChatMember),
+        UpdateType::ChatMember => map.next_value::<ChatMemberUpdated>().map(Kind::ChatMember),
+        UpdateType::ChatJoinRequest => map
+            .next_value::<ChatJoinRequest>()
+            .map(Kind::ChatJoinRequest),
+        UpdateType::MessageReaction => map
+            .next_value::<MessageReactionUpdated>()
+            .map(Kind::MessageReaction),
+        UpdateType::MessageReactionCount => map
+            .next_value::<MessageReactionCountUpdated>()
+            .map(Kind::MessageReactionCount),
+        UpdateType::ChatBoost => map.next_value::<ChatBoostUpdated>().map(Kind::ChatBoost),
+        UpdateType::RemovedChatBoost => map
+            .next_value::<ChatBoostRemoved>()
+            .map(Kind::RemovedChatBoost),
+    };
+
+    match update_kind {
+        Ok(update_kind) => Ok(update_kind),
+        Err(err) => Err(serde::de::Error::custom(format!(
+            "Error deserializing update kind: {err}"
+        ))),
+    }
+}
+
 impl Update {
     #[must_use]
     pub const fn text(&self) -> Option<&str> {
@@ -466,8 +629,118 @@ impl Update {
         self.kind().sender_chat_id()
     }
 
+    #[must_use]
+    pub const fn message_id(&self) -> Option<i64> {
+        self.kind().message_id()
+    }
+
     #[must_use]
     pub const fn message_thread_id(&self) -> Option<i64> {
         self.kind().message_thread_id()
     }
+
+    #[must_use]
+    pub const fn date(&self) -> Option<i64> {
+        self.kind().date()
+    }
+
+    #[must_use]
+    pub const fn invoice_payload(&self) -> Option<&str> {
+        self.kind().invoice_payload()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accessors() {
+        let update: Update = serde_json::from_str(
+            r#"{"update_id": 1, "message_reaction": {"chat": {"id": -100, "type": "supergroup", "title": "test"}, "message_id": 1, "actor_chat": {"id": -200, "type": "channel", "title": "test"}, "date": 10, "old_reaction": [], "new_reaction": []}}"#,
+        )
+        .unwrap();
+        assert_eq!(update.chat_id(), Some(-100));
+        assert_eq!(update.sender_chat_id(), Some(-200));
+        assert_eq!(update.from_id(), None);
+        assert_eq!(update.message_id(), Some(1));
+        assert_eq!(update.date(), Some(10));
+
+        let update: Update = serde_json::from_str(
+            r#"{"update_id": 2, "removed_chat_boost": {"chat": {"id": -100, "type": "channel", "title": "test"}, "boost_id": 1, "remove_date": 20, "source": {"source": "gift_code", "user": {"id": 1, "is_bot": false, "first_name": "test"}}}}"#,
+        )
+        .unwrap();
+        assert_eq!(update.chat_id(), Some(-100));
+        assert_eq!(update.from_id(), Some(1));
+        assert_eq!(update.message_id(), None);
+        assert_eq!(update.date(), Some(20));
+
+        let update: Update = serde_json::from_str(
+            r#"{"update_id": 3, "poll_answer": {"poll_id": "1", "voter_chat": {"id": -100, "type": "channel", "title": "test"}, "option_ids": [0]}}"#,
+        )
+        .unwrap();
+        assert_eq!(update.chat_id(), None);
+        assert_eq!(update.sender_chat_id(), Some(-100));
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let update: Update = serde_json::from_str(
+            r#"{"update_id": 1, "inline_query": {"id": "1", "from": {"id": 1, "is_bot": false, "first_name": "test"}, "query": "", "offset": ""}}"#,
+        )
+        .unwrap();
+        assert_eq!(update.id, 1);
+        assert!(matches!(update.kind(), Kind::InlineQuery(_)));
+
+        // Keys in any order and unknown keys
+        let update: Update = serde_json::from_str(
+            r#"{"unknown": {"key": [1, 2]}, "poll_answer": {"poll_id": "1", "option_ids": [0]}, "update_id": 2}"#,
+        )
+        .unwrap();
+        assert_eq!(update.id, 2);
+        assert!(matches!(update.kind(), Kind::PollAnswer(_)));
+
+        let err = serde_json::from_str::<Update>(r#"{"update_id": 3, "new_update_type": {}}"#)
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Unknown update type: new_update_type"));
+
+        assert!(serde_json::from_str::<Update>(r#"{"update_id": 4}"#).is_err());
+        assert!(serde_json::from_str::<Update>(
+            r#"{"poll_answer": {"poll_id": "1", "option_ids": [0]}}"#
+        )
+        .is_err());
+    }
+
+    /// Seeds of the fuzz targets must stay valid when the types change, otherwise fuzzing starts from the rejected inputs.
+    /// Seeds with `invalid_` prefix are expected to be rejected.
+    fn check_fuzz_seeds<T: serde::de::DeserializeOwned>(target: &str) {
+        let seeds = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("fuzz/seeds")
+            .join(target);
+
+        for entry in std::fs::read_dir(seeds).unwrap() {
+            let path = entry.unwrap().path();
+            let data = std::fs::read_to_string(&path).unwrap();
+            let is_invalid = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .map_or(false, |name| name.starts_with("invalid_"));
+
+            assert_eq!(
+                serde_json::from_str::<T>(&data).is_err(),
+                is_invalid,
+                "Unexpected result of the seed {}",
+                path.display(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_deserialize_fuzz_seeds() {
+        check_fuzz_seeds::<Update>("update");
+        check_fuzz_seeds::<Message>("message");
+        check_fuzz_seeds::<Chat>("chat");
+    }
 }
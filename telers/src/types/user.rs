@@ -1,5 +1,3 @@
-use crate::extractors::FromContext;
-
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use std::borrow::Cow;
@@ -8,11 +6,15 @@ use std::borrow::Cow;
 /// # Documentation
 /// <https://core.telegram.org/bots/api#user>
 #[skip_serializing_none]
-#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize, Serialize, FromContext)]
-#[context(
-    key = "event_user",
-    description = "This object represents a Telegram user or bot. \
-    This context is available only if `UserContext` middleware is used (default middleware) and user in `Update` is not empty."
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "dispatcher",
+    derive(crate::FromContext),
+    context(
+        key = "event_user",
+        description = "This object represents a Telegram user or bot. \
+        This context is available only if `UserContext` middleware is used (default middleware) and user in `Update` is not empty."
+    )
 )]
 pub struct User {
     /// Unique identifier for this user or bot. This number may have more than 32 significant bits and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so a 64-bit integer or double-precision float type are safe for storing this identifier.
@@ -20,13 +22,13 @@ pub struct User {
     /// `true`, if this user is a bot
     pub is_bot: bool,
     /// User's or bot's first name
-    pub first_name: String,
+    pub first_name: Box<str>,
     /// User's or bot's last name
-    pub last_name: Option<String>,
+    pub last_name: Option<Box<str>>,
     /// User's or bot's username
-    pub username: Option<String>,
+    pub username: Option<Box<str>>,
     /// [`IETF language tag`](https://en.wikipedia.org/wiki/IETF_language_tag) of the user's language
-    pub language_code: Option<String>,
+    pub language_code: Option<Box<str>>,
     /// `true`, if this user is a Telegram Premium user
     pub is_premium: Option<bool>,
     /// `true`, if this user added the bot to the attachment menu
@@ -51,7 +53,7 @@ impl User {
 
 impl User {
     #[must_use]
-    pub fn new(id: i64, is_bot: bool, first_name: impl Into<String>) -> Self {
+    pub fn new(id: i64, is_bot: bool, first_name: impl Into<Box<str>>) -> Self {
         Self {
             id,
             is_bot,
@@ -81,7 +83,7 @@ impl User {
     }
 
     #[must_use]
-    pub fn first_name(self, val: impl Into<String>) -> Self {
+    pub fn first_name(self, val: impl Into<Box<str>>) -> Self {
         Self {
             first_name: val.into(),
             ..self
@@ -89,7 +91,7 @@ impl User {
     }
 
     #[must_use]
-    pub fn last_name(self, val: impl Into<String>) -> Self {
+    pub fn last_name(self, val: impl Into<Box<str>>) -> Self {
         Self {
             last_name: Some(val.into()),
             ..self
@@ -97,7 +99,7 @@ impl User {
     }
 
     #[must_use]
-    pub fn username(self, val: impl Into<String>) -> Self {
+    pub fn username(self, val: impl Into<Box<str>>) -> Self {
         Self {
             username: Some(val.into()),
             ..self
@@ -105,7 +107,7 @@ impl User {
     }
 
     #[must_use]
-    pub fn language_code(self, val: impl Into<String>) -> Self {
+    pub fn language_code(self, val: impl Into<Box<str>>) -> Self {
         Self {
             language_code: Some(val.into()),
             ..self
@@ -155,7 +157,7 @@ impl User {
 
 impl User {
     #[must_use]
-    pub fn last_name_option(self, val: Option<impl Into<String>>) -> Self {
+    pub fn last_name_option(self, val: Option<impl Into<Box<str>>>) -> Self {
         Self {
             last_name: val.map(Into::into),
             ..self
@@ -163,7 +165,7 @@ impl User {
     }
 
     #[must_use]
-    pub fn username_option(self, val: Option<impl Into<String>>) -> Self {
+    pub fn username_option(self, val: Option<impl Into<Box<str>>>) -> Self {
         Self {
             username: val.map(Into::into),
             ..self
@@ -171,7 +173,7 @@ impl User {
     }
 
     #[must_use]
-    pub fn language_code_option(self, val: Option<impl Into<String>>) -> Self {
+    pub fn language_code_option(self, val: Option<impl Into<Box<str>>>) -> Self {
         Self {
             language_code: val.map(Into::into),
             ..self
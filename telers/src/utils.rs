@@ -1,2 +1,33 @@
+#[cfg(feature = "utils")]
+pub mod callback_data;
+#[cfg(feature = "utils")]
+pub mod captcha;
+#[cfg(feature = "dispatcher")]
+pub mod commands;
+#[cfg(feature = "utils")]
+pub mod forum_topic;
+#[cfg(feature = "utils")]
+pub mod inline_cache;
+#[cfg(feature = "utils")]
+pub mod invite_links;
+#[cfg(feature = "utils")]
+pub mod keyboard;
+#[cfg(feature = "utils")]
+pub mod link;
+#[cfg(feature = "utils")]
+pub mod live_message;
+#[cfg(feature = "media")]
+pub mod media;
+#[cfg(feature = "utils")]
+pub mod media_group;
+#[cfg(feature = "utils")]
+pub mod menu;
+#[cfg(feature = "utils")]
+pub mod quiz;
+#[cfg(feature = "utils")]
+pub mod respond;
+#[cfg(feature = "signing")]
+pub mod signing;
+#[cfg(feature = "client")]
 pub mod text;
 pub mod token;
@@ -0,0 +1,288 @@
+//! This module contains [`Compressor`], which makes callback data longer than Telegram's limit ([`MAX_CALLBACK_DATA_LEN`] bytes) possible.
+//!
+//! Long payloads are stored in [`PayloadStorage`] by a short random token,
+//! and the token with the prefix ([`DEFAULT_PREFIX`] by default) is used as the callback data of the button instead of the payload.
+//! Payloads, which fit into the limit, are used as is, so the storage is used only if it's needed.
+//!
+//! [`Compressor`] is also an outer middleware, which replaces the token in the callback data of the received callback query
+//! with the stored payload, so filters and handlers work with the full payload transparently.
+//! If the payload isn't found (for example, it's expired), the callback data is left as is.
+//!
+//! [`MemoryPayloadStorage`] keeps payloads in memory for [`DEFAULT_TTL`], so buttons don't work after the bot restart.
+//! Implement [`PayloadStorage`] for your database to keep them longer.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! let compressor = Compressor::new(MemoryPayloadStorage::default());
+//!
+//! router.callback_query.outer_middlewares.register(compressor.clone());
+//! router
+//!     .callback_query
+//!     .register(select_items)
+//!     .filter(Text::starts_with("select:"));
+//!
+//! let data = compressor.pack(&format!("select:{}", item_ids.join(","))).await?;
+//! let button = InlineKeyboardButton::new("Select").callback_data(data);
+//! ```
+
+use super::menu::MAX_CALLBACK_DATA_LEN;
+
+use crate::{
+    errors::{EventErrorKind, MiddlewareError},
+    event::FlowControl,
+    filters::CachedCheck,
+    middlewares::outer::{Middleware, MiddlewareResponse},
+    router::Request,
+    types::{CallbackQuery, Update, UpdateKind},
+};
+
+use async_trait::async_trait;
+use std::{
+    convert::Infallible,
+    fmt::{self, Debug, Formatter},
+    sync::Arc,
+    time::Duration,
+};
+use tracing::{event, Level};
+use uuid::Uuid;
+
+/// Default prefix of the callback data with the token
+pub const DEFAULT_PREFIX: &str = "~";
+/// Default time to keep payloads in [`MemoryPayloadStorage`]
+pub const DEFAULT_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+/// Default max number of payloads in [`MemoryPayloadStorage`]
+pub const DEFAULT_MAX_SIZE: usize = 100_000;
+
+/// Storage of the payloads by their tokens, which is implemented for your database
+#[async_trait]
+pub trait PayloadStorage: Send + Sync {
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Saves the payload by the token
+    /// # Errors
+    /// If the payload can't be saved
+    async fn save(&self, token: &str, payload: &str) -> Result<(), Self::Error>;
+
+    /// Loads the payload by the token
+    /// # Returns
+    /// `None` if the payload isn't found, for example, it's expired
+    /// # Errors
+    /// If the payload can't be loaded
+    async fn load(&self, token: &str) -> Result<Option<Box<str>>, Self::Error>;
+}
+
+/// Storage, which keeps payloads in memory with TTL and max size
+#[derive(Debug, Clone)]
+pub struct MemoryPayloadStorage {
+    payloads: CachedCheck<Box<str>, Box<str>>,
+}
+
+impl MemoryPayloadStorage {
+    /// Creates a new storage, which keeps payloads for `ttl` and stores at most `max_size` payloads
+    #[must_use]
+    pub fn new(ttl: Duration, max_size: usize) -> Self {
+        Self {
+            payloads: CachedCheck::new(ttl, max_size),
+        }
+    }
+}
+
+impl Default for MemoryPayloadStorage {
+    fn default() -> Self {
+        Self::new(DEFAULT_TTL, DEFAULT_MAX_SIZE)
+    }
+}
+
+#[async_trait]
+impl PayloadStorage for MemoryPayloadStorage {
+    type Error = Infallible;
+
+    async fn save(&self, token: &str, payload: &str) -> Result<(), Self::Error> {
+        self.payloads.insert(token.into(), payload.into());
+        Ok(())
+    }
+
+    async fn load(&self, token: &str) -> Result<Option<Box<str>>, Self::Error> {
+        Ok(self.payloads.get(&token.into()))
+    }
+}
+
+/// Packer of long callback data and middleware to unpack it.
+/// Check the [module](self) documentation for more information.
+pub struct Compressor<S> {
+    storage: Arc<S>,
+    prefix: Box<str>,
+}
+
+impl<S> Compressor<S> {
+    #[must_use]
+    pub fn new(storage: S) -> Self {
+        Self {
+            storage: Arc::new(storage),
+            prefix: DEFAULT_PREFIX.into(),
+        }
+    }
+
+    /// Set prefix of the callback data with the token.
+    /// Prefix must not be a prefix of the callback data of your other buttons, because such data is considered as packed.
+    #[must_use]
+    pub fn prefix(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            prefix: val.into(),
+            ..self
+        }
+    }
+
+    /// Gets the token from the callback data
+    /// # Returns
+    /// `None` if the callback data isn't packed
+    #[must_use]
+    pub fn token<'a>(&self, data: &'a str) -> Option<&'a str> {
+        data.strip_prefix(&*self.prefix)
+    }
+}
+
+impl<S> Compressor<S>
+where
+    S: PayloadStorage,
+{
+    /// Packs the payload to the callback data.
+    /// Payload is stored by the token, if it's longer than [`MAX_CALLBACK_DATA_LEN`] bytes or starts with the prefix,
+    /// otherwise it's returned as is.
+    /// # Errors
+    /// If the payload can't be saved to the storage
+    pub async fn pack(&self, payload: &str) -> Result<String, S::Error> {
+        if payload.len() <= MAX_CALLBACK_DATA_LEN && self.token(payload).is_none() {
+            return Ok(payload.to_owned());
+        }
+
+        let token = Uuid::new_v4().simple().to_string();
+        self.storage.save(&token, payload).await?;
+
+        Ok(format!("{}{token}", self.prefix))
+    }
+
+    /// Unpacks the callback data to the payload
+    /// # Returns
+    /// The callback data as is, if it isn't packed, and `None` if the payload isn't found in the storage
+    /// # Errors
+    /// If the payload can't be loaded from the storage
+    pub async fn unpack(&self, data: &str) -> Result<Option<Box<str>>, S::Error> {
+        match self.token(data) {
+            Some(token) => self.storage.load(token).await,
+            None => Ok(Some(data.into())),
+        }
+    }
+}
+
+impl<S> Clone for Compressor<S> {
+    fn clone(&self) -> Self {
+        Self {
+            storage: Arc::clone(&self.storage),
+            prefix: self.prefix.clone(),
+        }
+    }
+}
+
+impl<S> Debug for Compressor<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Compressor")
+            .field("prefix", &self.prefix)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl<Client, S> Middleware<Client> for Compressor<S>
+where
+    Client: Send + Sync + 'static,
+    S: PayloadStorage + 'static,
+{
+    async fn call(
+        &self,
+        mut request: Request<Client>,
+    ) -> Result<MiddlewareResponse<Client>, EventErrorKind> {
+        let UpdateKind::CallbackQuery(query) = &request.update.kind else {
+            return Ok((request, FlowControl::default()));
+        };
+        let Some(token) = query.data.as_deref().and_then(|data| self.token(data)) else {
+            return Ok((request, FlowControl::default()));
+        };
+
+        match self
+            .storage
+            .load(token)
+            .await
+            .map_err(MiddlewareError::new)?
+        {
+            Some(payload) => {
+                request.update = Arc::new(Update {
+                    id: request.update.id,
+                    kind: UpdateKind::CallbackQuery(CallbackQuery {
+                        data: Some(payload),
+                        ..query.clone()
+                    }),
+                });
+            }
+            None => {
+                event!(
+                    Level::WARN,
+                    token,
+                    "Payload of the callback data isn't found"
+                );
+            }
+        }
+
+        Ok((request, FlowControl::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{Bot, Reqwest};
+
+    #[tokio::test]
+    async fn test_pack_unpack() {
+        let compressor = Compressor::new(MemoryPayloadStorage::default());
+
+        assert_eq!(compressor.pack("short").await.unwrap(), "short");
+
+        let payload = "select:".to_owned() + &"1,".repeat(50);
+        let data = compressor.pack(&payload).await.unwrap();
+        assert!(data.len() <= MAX_CALLBACK_DATA_LEN);
+        assert!(data.starts_with(DEFAULT_PREFIX));
+        assert_eq!(
+            compressor.unpack(&data).await.unwrap().as_deref(),
+            Some(payload.as_str())
+        );
+
+        // Payloads with the prefix are packed, so they aren't confused with tokens
+        let data = compressor.pack("~data").await.unwrap();
+        assert_ne!(data, "~data");
+        assert_eq!(
+            compressor.unpack(&data).await.unwrap().as_deref(),
+            Some("~data")
+        );
+        assert_eq!(compressor.unpack("~unknown").await.unwrap(), None);
+
+        let request = Request::new(
+            Arc::new(Bot::<Reqwest>::default()),
+            Arc::new(Update {
+                id: 1,
+                kind: UpdateKind::CallbackQuery(CallbackQuery {
+                    data: Some(compressor.pack(&payload).await.unwrap().into()),
+                    ..Default::default()
+                }),
+            }),
+            Arc::default(),
+        );
+        let (request, _) = compressor.call(request).await.unwrap();
+
+        let UpdateKind::CallbackQuery(query) = &request.update.kind else {
+            panic!("Expected callback query");
+        };
+        assert_eq!(query.data.as_deref(), Some(payload.as_str()));
+    }
+}
@@ -0,0 +1,613 @@
+//! This module contains [`Captcha`], which is a join-gate for new members of the group:
+//! the new member must solve the challenge before they can send messages.
+//!
+//! [`Captcha::router`] creates the router with the handlers, which:
+//! - restrict the new member and send the challenge to the group, when `new_chat_members` message is received,
+//! - send the challenge to the private chat of the user, when the join request is received (the bot can message the user for 5 minutes after it),
+//! - verify the answer, when the button of the challenge is pressed, and only the challenged user can press it.
+//!
+//! If the answer is correct, the member permissions are restored (or the join request is approved).
+//! If the answer is wrong or the user doesn't answer in the timeout, the member is kicked (or the join request is declined).
+//! In both cases, the message with the challenge is deleted.
+//!
+//! Available challenges (see [`Challenge`]):
+//! - [`Challenge::Button`]: press the single button;
+//! - [`Challenge::Emoji`]: press the button with the requested emoji;
+//! - [`Challenge::Math`]: press the button with the result of the simple expression.
+//!
+//! Pending challenges are stored in memory and timers are spawned with the [`Runtime`],
+//! so the challenges are lost on restart and the users stay restricted.
+//! The bot must be an administrator of the group with `can_restrict_members`, `can_delete_messages` and `can_invite_users` rights.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use telers::{
+//!     client::Reqwest,
+//!     utils::captcha::{Captcha, Challenge},
+//!     Router,
+//! };
+//!
+//! let captcha = Captcha::new(Challenge::Math).timeout(Duration::from_secs(120));
+//!
+//! let mut router = Router::<Reqwest>::new("main");
+//! router.include(captcha.router("captcha"));
+//! ```
+//!
+//! [`Runtime`]: crate::runtime::Runtime
+
+use crate::{
+    client::{Bot, Session},
+    enums::ContentType as ContentTypeEnum,
+    errors::SessionErrorKind,
+    event::{telegram::HandlerResult, FlowControl},
+    filters::{ContentType, Text},
+    methods::{
+        AnswerCallbackQuery, ApproveChatJoinRequest, BanChatMember, DeclineChatJoinRequest,
+        DeleteMessage, RestrictChatMember, SendMessage, UnbanChatMember,
+    },
+    runtime::{self, Runtime},
+    types::{
+        CallbackQuery, ChatJoinRequest, ChatPermissions, InlineKeyboardButton,
+        InlineKeyboardMarkup, Message, User,
+    },
+    Router,
+};
+
+use std::{
+    collections::HashMap,
+    fmt::{self, Debug, Formatter},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use tracing::{event, Level};
+use uuid::Uuid;
+
+/// Default prefix of the callback data of the challenge buttons
+pub const DEFAULT_PREFIX: &str = "captcha";
+/// Default time to solve the challenge
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+/// Emojis of [`Challenge::Emoji`]
+pub const EMOJIS: [&str; 12] = [
+    "🍎", "🍌", "🍇", "🍉", "🍒", "🍋", "🐶", "🐱", "🐭", "🦊", "🐻", "🐼",
+];
+
+/// Number of buttons of [`Challenge::Emoji`] and [`Challenge::Math`]
+const OPTION_COUNT: usize = 6;
+
+/// Kind of the challenge
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Challenge {
+    /// Press the single button
+    #[default]
+    Button,
+    /// Press the button with the requested emoji among the other emojis
+    Emoji,
+    /// Press the button with the result of the addition of two numbers among the other numbers
+    Math,
+}
+
+/// Generated task of the challenge
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Task {
+    text: String,
+    options: Vec<String>,
+    answer: usize,
+}
+
+impl Challenge {
+    fn generate(self) -> Task {
+        match self {
+            Self::Button => Task {
+                text: "press the button to join the chat".to_owned(),
+                options: vec!["I'm not a robot".to_owned()],
+                answer: 0,
+            },
+            Self::Emoji => {
+                let mut emojis = EMOJIS.to_vec();
+                shuffle(&mut emojis);
+                emojis.truncate(OPTION_COUNT);
+
+                let answer = random(OPTION_COUNT);
+
+                Task {
+                    text: format!("press {} to join the chat", emojis[answer]),
+                    options: emojis.into_iter().map(ToOwned::to_owned).collect(),
+                    answer,
+                }
+            }
+            Self::Math => {
+                let (a, b) = (random(10) + 1, random(10) + 1);
+                let result = a + b;
+
+                // Wrong options are near the result, so they can't be guessed by the range
+                let mut options = (result.saturating_sub(OPTION_COUNT)..=result + OPTION_COUNT)
+                    .filter(|option| *option != result)
+                    .collect::<Vec<_>>();
+                shuffle(&mut options);
+                options.truncate(OPTION_COUNT - 1);
+                options.push(result);
+                shuffle(&mut options);
+
+                Task {
+                    text: format!("solve {a} + {b} to join the chat"),
+                    // `unwrap` is safe, because the result is always in the options
+                    answer: options.iter().position(|option| *option == result).unwrap(),
+                    options: options
+                        .into_iter()
+                        .map(|option| option.to_string())
+                        .collect(),
+                }
+            }
+        }
+    }
+}
+
+/// Source of the challenged user
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Source {
+    /// The user joined the group and is restricted
+    Member,
+    /// The user sent the join request
+    JoinRequest,
+}
+
+/// Pending challenge of the user
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Pending {
+    /// Identifier of the challenge, so the timer of the previous challenge doesn't affect the new one
+    id: u64,
+    source: Source,
+    answer: usize,
+    /// Chat and message identifiers of the challenge message
+    message: (i64, i64),
+}
+
+/// Join-gate for new members of the group.
+/// Check the [module](self) documentation for more information.
+#[derive(Clone)]
+pub struct Captcha {
+    challenge: Challenge,
+    timeout: Duration,
+    prefix: Box<str>,
+    permissions: ChatPermissions,
+    runtime: Arc<dyn Runtime>,
+    /// Pending challenges by chat and user identifiers
+    pending: Arc<Mutex<HashMap<(i64, i64), Pending>>>,
+    next_id: Arc<AtomicU64>,
+}
+
+impl Debug for Captcha {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Captcha")
+            .field("challenge", &self.challenge)
+            .field("timeout", &self.timeout)
+            .field("prefix", &self.prefix)
+            .field("permissions", &self.permissions)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Captcha {
+    #[must_use]
+    pub fn new(challenge: Challenge) -> Self {
+        Self {
+            challenge,
+            timeout: DEFAULT_TIMEOUT,
+            prefix: DEFAULT_PREFIX.into(),
+            permissions: ChatPermissions::new()
+                .can_send_messages(true)
+                .can_send_audios(true)
+                .can_send_documents(true)
+                .can_send_photos(true)
+                .can_send_videos(true)
+                .can_send_video_notes(true)
+                .can_send_voice_notes(true)
+                .can_send_polls(true)
+                .can_send_other_messages(true)
+                .can_add_web_page_previews(true),
+            runtime: runtime::default(),
+            pending: Arc::default(),
+            next_id: Arc::default(),
+        }
+    }
+
+    /// Time to solve the challenge
+    /// # Default
+    /// [`DEFAULT_TIMEOUT`]
+    #[must_use]
+    pub fn timeout(self, val: Duration) -> Self {
+        Self {
+            timeout: val,
+            ..self
+        }
+    }
+
+    /// Prefix of the callback data. Use different prefixes for different captchas in the same bot.
+    /// # Default
+    /// [`DEFAULT_PREFIX`]
+    #[must_use]
+    pub fn prefix(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            prefix: val.into(),
+            ..self
+        }
+    }
+
+    /// Permissions of the member after the challenge is solved
+    /// # Default
+    /// Permissions to send all kinds of messages
+    #[must_use]
+    pub fn permissions(self, val: ChatPermissions) -> Self {
+        Self {
+            permissions: val,
+            ..self
+        }
+    }
+
+    /// Runtime to spawn the timers of the challenges
+    /// # Default
+    /// [`runtime::default`]
+    #[must_use]
+    pub fn runtime(self, val: Arc<dyn Runtime>) -> Self {
+        Self {
+            runtime: val,
+            ..self
+        }
+    }
+
+    /// Check if the user has the pending challenge in the chat
+    #[must_use]
+    pub fn is_pending(&self, chat_id: i64, user_id: i64) -> bool {
+        self.pending
+            .lock()
+            .unwrap()
+            .contains_key(&(chat_id, user_id))
+    }
+
+    fn callback_data(&self, chat_id: i64, user_id: i64, option: usize) -> String {
+        format!("{}:{chat_id}:{user_id}:{option}", self.prefix)
+    }
+
+    /// Parse the callback data
+    /// # Returns
+    /// Chat and user identifiers and the chosen option
+    fn parse_callback_data(&self, data: &str) -> Option<(i64, i64, usize)> {
+        let mut parts = data
+            .strip_prefix(&*self.prefix)?
+            .strip_prefix(':')?
+            .splitn(3, ':');
+
+        Some((
+            parts.next()?.parse().ok()?,
+            parts.next()?.parse().ok()?,
+            parts.next()?.parse().ok()?,
+        ))
+    }
+
+    fn message(&self, chat_id: i64, target_chat_id: i64, user: &User, task: &Task) -> SendMessage {
+        let buttons = task
+            .options
+            .iter()
+            .enumerate()
+            .map(|(option, text)| {
+                InlineKeyboardButton::new(text)
+                    .callback_data(self.callback_data(chat_id, user.id, option))
+            })
+            .collect::<Vec<_>>();
+
+        SendMessage::new(
+            target_chat_id,
+            format!(
+                "{}, {}. You have {} seconds.",
+                user.first_name,
+                task.text,
+                self.timeout.as_secs()
+            ),
+        )
+        .reply_markup(InlineKeyboardMarkup::new([buttons]))
+    }
+}
+
+impl Captcha {
+    /// Create the router with the handlers of new members, join requests and answers
+    #[must_use]
+    pub fn router<Client>(&self, router_name: &'static str) -> Router<Client>
+    where
+        Client: Session + 'static,
+    {
+        let mut router = Router::new(router_name);
+
+        let captcha = self.clone();
+        router
+            .message
+            .register(move |bot: Arc<Bot<Client>>, message: Message| {
+                let captcha = captcha.clone();
+
+                async move { captcha.handle_new_members(bot, message).await }
+            })
+            .filter(ContentType::one(ContentTypeEnum::NewChatMembers));
+
+        let captcha = self.clone();
+        router.chat_join_request.register(
+            move |bot: Arc<Bot<Client>>, request: ChatJoinRequest| {
+                let captcha = captcha.clone();
+
+                async move { captcha.handle_join_request(bot, request).await }
+            },
+        );
+
+        let captcha = self.clone();
+        router
+            .callback_query
+            .register(move |bot: Arc<Bot<Client>>, query: CallbackQuery| {
+                let captcha = captcha.clone();
+
+                async move { captcha.handle_answer(bot, query).await }
+            })
+            .filter(Text::starts_with_single(format!("{}:", self.prefix)));
+
+        router
+    }
+
+    async fn handle_new_members<Client: Session + 'static>(
+        &self,
+        bot: Arc<Bot<Client>>,
+        message: Message,
+    ) -> HandlerResult {
+        let Some(users) = message.new_chat_members() else {
+            return Ok(FlowControl::SkipHandler);
+        };
+        let chat_id = message.chat().id();
+
+        for user in users.iter().filter(|user| !user.is_bot) {
+            bot.send(RestrictChatMember::new(
+                chat_id,
+                user.id,
+                ChatPermissions::new().can_send_messages(false),
+            ))
+            .await?;
+
+            self.challenge(&bot, chat_id, chat_id, user, Source::Member)
+                .await?;
+        }
+
+        Ok(FlowControl::Continue)
+    }
+
+    async fn handle_join_request<Client: Session + 'static>(
+        &self,
+        bot: Arc<Bot<Client>>,
+        request: ChatJoinRequest,
+    ) -> HandlerResult {
+        self.challenge(
+            &bot,
+            request.chat.id(),
+            request.user_chat_id,
+            &request.from,
+            Source::JoinRequest,
+        )
+        .await?;
+
+        Ok(FlowControl::Continue)
+    }
+
+    async fn handle_answer<Client: Session + 'static>(
+        &self,
+        bot: Arc<Bot<Client>>,
+        query: CallbackQuery,
+    ) -> HandlerResult {
+        let Some((chat_id, user_id, option)) = query
+            .data
+            .as_deref()
+            .and_then(|data| self.parse_callback_data(data))
+        else {
+            return Ok(FlowControl::SkipHandler);
+        };
+
+        if query.from.id != user_id {
+            bot.send(AnswerCallbackQuery::new(&*query.id).text("This challenge isn't for you"))
+                .await?;
+
+            return Ok(FlowControl::Continue);
+        }
+
+        let pending = self.pending.lock().unwrap().remove(&(chat_id, user_id));
+
+        match pending {
+            Some(pending) => {
+                bot.send(AnswerCallbackQuery::new(&*query.id)).await?;
+
+                if option == pending.answer {
+                    self.pass(&bot, chat_id, user_id, pending).await?;
+                } else {
+                    self.fail(&bot, chat_id, user_id, pending).await?;
+                }
+            }
+            None => {
+                bot.send(AnswerCallbackQuery::new(&*query.id).text("The challenge is expired"))
+                    .await?;
+            }
+        }
+
+        Ok(FlowControl::Continue)
+    }
+
+    /// Send the challenge and start the timer
+    async fn challenge<Client: Session + 'static>(
+        &self,
+        bot: &Arc<Bot<Client>>,
+        chat_id: i64,
+        target_chat_id: i64,
+        user: &User,
+        source: Source,
+    ) -> Result<(), SessionErrorKind> {
+        let task = self.challenge.generate();
+        let message = bot
+            .send(self.message(chat_id, target_chat_id, user, &task))
+            .await?;
+
+        let pending = Pending {
+            id: self.next_id.fetch_add(1, Ordering::Relaxed),
+            source,
+            answer: task.answer,
+            message: (target_chat_id, message.id()),
+        };
+        self.pending
+            .lock()
+            .unwrap()
+            .insert((chat_id, user.id), pending);
+
+        let captcha = self.clone();
+        let bot = Arc::clone(bot);
+        let user_id = user.id;
+        let sleep = self.runtime.sleep(self.timeout);
+
+        self.runtime.spawn(Box::pin(async move {
+            sleep.await;
+
+            let expired = {
+                let mut pendings = captcha.pending.lock().unwrap();
+
+                match pendings.get(&(chat_id, user_id)) {
+                    // The user may join again, then the new challenge isn't expired
+                    Some(current) if current.id == pending.id => pendings.remove(&(chat_id, user_id)),
+                    _ => None,
+                }
+            };
+
+            if let Some(expired) = expired {
+                if let Err(err) = captcha.fail(&bot, chat_id, user_id, expired).await {
+                    event!(Level::ERROR, error = %err, chat_id, user_id, "Failed to handle expired captcha");
+                }
+            }
+        }));
+
+        Ok(())
+    }
+
+    async fn pass<Client: Session + 'static>(
+        &self,
+        bot: &Bot<Client>,
+        chat_id: i64,
+        user_id: i64,
+        pending: Pending,
+    ) -> Result<(), SessionErrorKind> {
+        event!(Level::DEBUG, chat_id, user_id, "Captcha is passed");
+
+        match pending.source {
+            Source::Member => {
+                bot.send(RestrictChatMember::new(
+                    chat_id,
+                    user_id,
+                    self.permissions.clone(),
+                ))
+                .await?;
+            }
+            Source::JoinRequest => {
+                bot.send(ApproveChatJoinRequest::new(chat_id, user_id))
+                    .await?;
+            }
+        }
+
+        delete_message(bot, pending).await;
+
+        Ok(())
+    }
+
+    async fn fail<Client: Session + 'static>(
+        &self,
+        bot: &Bot<Client>,
+        chat_id: i64,
+        user_id: i64,
+        pending: Pending,
+    ) -> Result<(), SessionErrorKind> {
+        event!(Level::DEBUG, chat_id, user_id, "Captcha is failed");
+
+        match pending.source {
+            Source::Member => {
+                // Ban and unban to kick the user, so they can join again
+                bot.send(BanChatMember::new(chat_id, user_id)).await?;
+                bot.send(UnbanChatMember::new(chat_id, user_id).only_if_banned(true))
+                    .await?;
+            }
+            Source::JoinRequest => {
+                bot.send(DeclineChatJoinRequest::new(chat_id, user_id))
+                    .await?;
+            }
+        }
+
+        delete_message(bot, pending).await;
+
+        Ok(())
+    }
+}
+
+async fn delete_message<Client: Session>(bot: &Bot<Client>, pending: Pending) {
+    let (chat_id, message_id) = pending.message;
+
+    if let Err(err) = bot.send(DeleteMessage::new(chat_id, message_id)).await {
+        event!(Level::DEBUG, error = %err, chat_id, message_id, "Failed to delete captcha message");
+    }
+}
+
+/// Get random number in `0..bound`.
+/// UUID v4 is used as the source of randomness to avoid extra dependency, it's enough for the challenges.
+fn random(bound: usize) -> usize {
+    usize::try_from(Uuid::new_v4().as_u128() % bound as u128).unwrap_or_default()
+}
+
+fn shuffle<T>(items: &mut [T]) {
+    for i in (1..items.len()).rev() {
+        items.swap(i, random(i + 1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for challenge in [Challenge::Button, Challenge::Emoji, Challenge::Math] {
+            for _ in 0..100 {
+                let task = challenge.generate();
+
+                assert!(task.answer < task.options.len());
+                if challenge != Challenge::Button {
+                    assert_eq!(task.options.len(), OPTION_COUNT);
+                }
+            }
+        }
+
+        let task = Challenge::Math.generate();
+        let (a, b) = task
+            .text
+            .strip_prefix("solve ")
+            .and_then(|text| text.strip_suffix(" to join the chat"))
+            .and_then(|text| text.split_once(" + "))
+            .unwrap();
+        let result = a.parse::<usize>().unwrap() + b.parse::<usize>().unwrap();
+        assert_eq!(task.options[task.answer], result.to_string());
+    }
+
+    #[test]
+    fn test_callback_data() {
+        let captcha = Captcha::new(Challenge::Emoji).prefix("gate");
+
+        let data = captcha.callback_data(-100_123, 456, 3);
+        assert_eq!(data, "gate:-100123:456:3");
+        assert_eq!(captcha.parse_callback_data(&data), Some((-100_123, 456, 3)));
+        assert_eq!(captcha.parse_callback_data("gate:1:2"), None);
+        assert_eq!(captcha.parse_callback_data("other:1:2:3"), None);
+
+        let task = Challenge::Emoji.generate();
+        let method = captcha.message(-100_123, -100_123, &User::default(), &task);
+        assert!(method.text.ends_with("You have 60 seconds."));
+        assert!(!captcha.is_pending(-100_123, 456));
+    }
+}
@@ -0,0 +1,491 @@
+//! This module contains [`BotCommands`] structure, which aggregates bot commands by scope and language
+//! to register them in the bot command menu with [`SetMyCommands`] method.
+//!
+//! Usually you don't need to create it manually, because it's collected from handlers of the router,
+//! which have description (see [`HandlerObject::description`]),
+//! and registered by the dispatcher automatically if [`DispatcherBuilder::register_commands`] is enabled.
+//!
+//! # Examples
+//! ```rust
+//! use telers::{client::Reqwest, filters::Command, event::FlowControl, Router};
+//!
+//! let mut router = Router::<Reqwest>::new("main");
+//! router
+//!     .message
+//!     .register(|| async { Ok(FlowControl::Continue) })
+//!     .filter(Command::one("start"))
+//!     .description("Start the bot");
+//!
+//! let commands = router.resolve_bot_commands();
+//! let requests = commands.set_my_commands();
+//!
+//! assert_eq!(requests.len(), 1);
+//! assert_eq!(requests[0].commands[0].command, "start");
+//! ```
+//!
+//! If you manage commands for many scopes and languages (for example, different commands for private chats,
+//! chat administrators and specific chats), use [`CommandScopeManager`].
+//! It compares the desired commands with the current commands of the bot
+//! and computes minimal [`SetMyCommands`] and [`DeleteMyCommands`] calls to sync them,
+//! so you don't need to wire scope combinatorics manually.
+//!
+//! ```rust
+//! use telers::{
+//!     types::{BotCommand, BotCommandScope},
+//!     utils::commands::CommandScopeManager,
+//!     Bot,
+//! };
+//!
+//! async fn sync_commands(bot: Bot) {
+//!     let manager = CommandScopeManager::new()
+//!         .command(BotCommand::new("start", "Start the bot"), BotCommandScope::default(), None)
+//!         .command(BotCommand::new("start", "Запустить бота"), BotCommandScope::default(), Some("ru"))
+//!         .command(BotCommand::new("ban", "Ban the user"), BotCommandScope::all_chat_administrators(), None)
+//!         // Commands for this scope will be deleted, because there are no desired commands for it
+//!         .manage(BotCommandScope::all_group_chats(), None);
+//!
+//!     let _ = manager.sync(&bot).await;
+//! }
+//! ```
+//!
+//! [`HandlerObject::description`]: crate::event::telegram::handler::HandlerObject#method.description
+//! [`DispatcherBuilder::register_commands`]: crate::dispatcher::Builder#method.register_commands
+
+use crate::{
+    client::{Bot, Session},
+    errors::SessionErrorKind,
+    methods::{DeleteMyCommands, GetMyCommands, SetMyCommands},
+    types::{BotCommand, BotCommandScope},
+};
+
+use tracing::{event, instrument, Level};
+
+/// Commands with the same scope and language
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+    pub scope: BotCommandScope,
+    pub language_code: Option<Box<str>>,
+    pub commands: Vec<BotCommand>,
+}
+
+/// Bot commands aggregated by scope and language.
+/// # Notes
+/// Commands are unique by name in each scope and language, so if a command is added twice,
+/// the first description is used.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BotCommands {
+    groups: Vec<Group>,
+}
+
+impl BotCommands {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds command to the group with the same scope and language
+    /// # Arguments
+    /// * `command` - Command to add
+    /// * `scope` - Scope of users for which the command is relevant
+    /// * `language_code` -
+    /// A two-letter ISO 639-1 language code. If `None`, command will be applied to all users from the given scope,
+    /// for whose language there are no dedicated commands.
+    pub fn add(
+        &mut self,
+        command: BotCommand,
+        scope: BotCommandScope,
+        language_code: Option<&str>,
+    ) -> &mut Self {
+        let group = match self.groups.iter_mut().position(|group| {
+            group.scope == scope && group.language_code.as_deref() == language_code
+        }) {
+            Some(index) => &mut self.groups[index],
+            None => {
+                self.groups.push(Group {
+                    scope,
+                    language_code: language_code.map(Into::into),
+                    commands: vec![],
+                });
+                // `unwrap` is safe, because we just added element to the vector
+                self.groups.last_mut().unwrap()
+            }
+        };
+
+        if !group
+            .commands
+            .iter()
+            .any(|added_command| added_command.command == command.command)
+        {
+            group.commands.push(command);
+        }
+
+        self
+    }
+
+    /// Extends commands by other commands
+    pub fn extend(&mut self, other: Self) -> &mut Self {
+        for group in other.groups {
+            for command in group.commands {
+                self.add(command, group.scope.clone(), group.language_code.as_deref());
+            }
+        }
+
+        self
+    }
+
+    #[must_use]
+    pub fn groups(&self) -> &[Group] {
+        &self.groups
+    }
+
+    /// Gets commands for the scope and language
+    #[must_use]
+    pub fn get(
+        &self,
+        scope: &BotCommandScope,
+        language_code: Option<&str>,
+    ) -> Option<&[BotCommand]> {
+        self.groups
+            .iter()
+            .find(|group| &group.scope == scope && group.language_code.as_deref() == language_code)
+            .map(|group| group.commands.as_slice())
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Creates [`SetMyCommands`] methods for each group of commands
+    #[must_use]
+    pub fn set_my_commands(&self) -> Vec<SetMyCommands> {
+        self.groups
+            .iter()
+            .map(|group| {
+                SetMyCommands::new(group.commands.clone())
+                    .scope(group.scope.clone())
+                    .language_code_option(group.language_code.as_deref())
+            })
+            .collect()
+    }
+}
+
+/// Change of the bot commands, which is computed by [`CommandScopeManager::diff`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandsChange {
+    Set(SetMyCommands),
+    Delete(DeleteMyCommands),
+}
+
+/// Manager, which computes minimal [`SetMyCommands`] and [`DeleteMyCommands`] calls
+/// to sync the desired commands with the current commands of the bot across scopes and languages.
+/// # Notes
+/// Manager checks scopes and languages of the desired commands and the scopes and languages added by [`CommandScopeManager::manage`].
+/// Commands for other scopes and languages aren't changed.
+///
+/// Check [module docs](crate::utils::commands) for examples.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CommandScopeManager {
+    desired: BotCommands,
+    managed: Vec<(BotCommandScope, Option<Box<str>>)>,
+}
+
+impl CommandScopeManager {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new manager with the desired commands
+    #[must_use]
+    pub fn with_commands(desired: BotCommands) -> Self {
+        Self {
+            desired,
+            managed: vec![],
+        }
+    }
+
+    /// Adds desired command for the scope and language
+    #[must_use]
+    pub fn command(
+        self,
+        command: BotCommand,
+        scope: BotCommandScope,
+        language_code: Option<&str>,
+    ) -> Self {
+        let mut desired = self.desired;
+        desired.add(command, scope, language_code);
+
+        Self { desired, ..self }
+    }
+
+    /// Adds desired commands for the scope and language
+    #[must_use]
+    pub fn commands(
+        self,
+        commands: impl IntoIterator<Item = BotCommand>,
+        scope: BotCommandScope,
+        language_code: Option<&str>,
+    ) -> Self {
+        let mut desired = self.desired;
+        for command in commands {
+            desired.add(command, scope.clone(), language_code);
+        }
+
+        Self { desired, ..self }
+    }
+
+    /// Adds scope and language, which is managed by the manager.
+    /// If there are no desired commands for it, its commands will be deleted.
+    #[must_use]
+    pub fn manage(self, scope: BotCommandScope, language_code: Option<&str>) -> Self {
+        Self {
+            managed: self
+                .managed
+                .into_iter()
+                .chain(Some((scope, language_code.map(Into::into))))
+                .collect(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub const fn desired(&self) -> &BotCommands {
+        &self.desired
+    }
+
+    /// Gets all scopes and languages, which are checked by the manager without duplicates
+    #[must_use]
+    pub fn keys(&self) -> Vec<(&BotCommandScope, Option<&str>)> {
+        let mut keys: Vec<(&BotCommandScope, Option<&str>)> = vec![];
+
+        let desired_keys = self
+            .desired
+            .groups()
+            .iter()
+            .map(|group| (&group.scope, group.language_code.as_deref()));
+        let managed_keys = self
+            .managed
+            .iter()
+            .map(|(scope, language_code)| (scope, language_code.as_deref()));
+
+        for key in desired_keys.chain(managed_keys) {
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+
+        keys
+    }
+
+    /// Computes minimal changes to sync the desired commands with the current commands
+    /// # Arguments
+    /// * `current` - Current commands of the bot, for example, received by [`CommandScopeManager::fetch_current`]
+    #[must_use]
+    pub fn diff(&self, current: &BotCommands) -> Vec<CommandsChange> {
+        let mut changes = vec![];
+
+        for (scope, language_code) in self.keys() {
+            let desired = self.desired.get(scope, language_code).unwrap_or_default();
+            let current = current.get(scope, language_code).unwrap_or_default();
+
+            if desired == current {
+                continue;
+            }
+
+            if desired.is_empty() {
+                changes.push(CommandsChange::Delete(
+                    DeleteMyCommands::new()
+                        .scope(scope.clone())
+                        .language_code_option(language_code),
+                ));
+            } else {
+                changes.push(CommandsChange::Set(
+                    SetMyCommands::new(desired.iter().cloned())
+                        .scope(scope.clone())
+                        .language_code_option(language_code),
+                ));
+            }
+        }
+
+        changes
+    }
+
+    /// Gets current commands of the bot for all scopes and languages, which are checked by the manager
+    /// # Errors
+    /// If any request to the Telegram API is unsuccessful
+    pub async fn fetch_current<Client: Session>(
+        &self,
+        bot: &Bot<Client>,
+    ) -> Result<BotCommands, SessionErrorKind> {
+        let mut current = BotCommands::new();
+
+        for (scope, language_code) in self.keys() {
+            let commands = bot
+                .send(
+                    GetMyCommands::new()
+                        .scope(scope.clone())
+                        .language_code_option(language_code),
+                )
+                .await?;
+
+            for command in commands {
+                current.add(command, scope.clone(), language_code);
+            }
+        }
+
+        Ok(current)
+    }
+
+    /// Syncs the desired commands with the current commands of the bot
+    /// # Returns
+    /// Applied changes
+    /// # Errors
+    /// If any request to the Telegram API is unsuccessful
+    #[instrument(skip(self, bot))]
+    pub async fn sync<Client: Session>(
+        &self,
+        bot: &Bot<Client>,
+    ) -> Result<Vec<CommandsChange>, SessionErrorKind> {
+        let changes = self.diff(&self.fetch_current(bot).await?);
+
+        for change in &changes {
+            match change {
+                CommandsChange::Set(method) => bot.send(method).await?,
+                CommandsChange::Delete(method) => bot.send(method).await?,
+            };
+        }
+
+        event!(
+            Level::DEBUG,
+            changes = changes.len(),
+            "Bot commands are synced"
+        );
+
+        Ok(changes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::BotCommandScopeAllPrivateChats;
+
+    #[test]
+    fn test_add() {
+        let mut commands = BotCommands::new();
+
+        commands
+            .add(
+                BotCommand::new("start", "Start the bot"),
+                BotCommandScope::default(),
+                None,
+            )
+            .add(
+                BotCommand::new("start", "Duplicate"),
+                BotCommandScope::default(),
+                None,
+            )
+            .add(
+                BotCommand::new("help", "Show help"),
+                BotCommandScope::default(),
+                None,
+            )
+            .add(
+                BotCommand::new("start", "Запустить бота"),
+                BotCommandScope::default(),
+                Some("ru"),
+            )
+            .add(
+                BotCommand::new("settings", "Settings"),
+                BotCommandScopeAllPrivateChats::new().into(),
+                None,
+            );
+
+        let groups = commands.groups();
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[0].commands.len(), 2);
+        assert_eq!(groups[0].commands[0].description, "Start the bot");
+        assert_eq!(groups[1].language_code.as_deref(), Some("ru"));
+        assert_eq!(
+            groups[2].scope,
+            BotCommandScope::AllPrivateChats(BotCommandScopeAllPrivateChats::new())
+        );
+
+        let requests = commands.set_my_commands();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[1].language_code.as_deref(), Some("ru"));
+    }
+
+    #[test]
+    fn test_diff() {
+        let manager = CommandScopeManager::new()
+            .command(
+                BotCommand::new("start", "Start the bot"),
+                BotCommandScope::default(),
+                None,
+            )
+            .commands(
+                [
+                    BotCommand::new("ban", "Ban the user"),
+                    BotCommand::new("unban", "Unban the user"),
+                ],
+                BotCommandScope::all_chat_administrators(),
+                None,
+            )
+            .manage(BotCommandScope::all_group_chats(), None)
+            .manage(BotCommandScope::all_private_chats(), Some("ru"));
+
+        assert_eq!(manager.keys().len(), 4);
+
+        let mut current = BotCommands::new();
+        current
+            // The same as desired, so it isn't changed
+            .add(
+                BotCommand::new("start", "Start the bot"),
+                BotCommandScope::default(),
+                None,
+            )
+            // Differs from desired, so it's set
+            .add(
+                BotCommand::new("ban", "Ban the user"),
+                BotCommandScope::all_chat_administrators(),
+                None,
+            )
+            // Isn't desired, so it's deleted
+            .add(
+                BotCommand::new("help", "Show help"),
+                BotCommandScope::all_group_chats(),
+                None,
+            )
+            // Isn't managed, so it isn't changed
+            .add(
+                BotCommand::new("help", "Show help"),
+                BotCommandScope::chat(1),
+                None,
+            );
+
+        let changes = manager.diff(&current);
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(
+            changes[0],
+            CommandsChange::Set(
+                SetMyCommands::new([
+                    BotCommand::new("ban", "Ban the user"),
+                    BotCommand::new("unban", "Unban the user"),
+                ])
+                .scope(BotCommandScope::all_chat_administrators())
+            )
+        );
+        assert_eq!(
+            changes[1],
+            CommandsChange::Delete(
+                DeleteMyCommands::new().scope(BotCommandScope::all_group_chats())
+            )
+        );
+
+        // Nothing to change, if current commands are the same as desired
+        assert!(manager.diff(manager.desired()).is_empty());
+    }
+}
@@ -0,0 +1,290 @@
+//! This module contains [`ForumTopic`], which wraps a topic of the forum supergroup
+//! and provides shortcuts for its management (edit, close, reopen, delete, etc.) and for sending messages to it,
+//! so you don't need to pass the chat id and the thread id to each method.
+//!
+//! Messages, which are sent by [`ForumTopic::message`], are sent to the topic.
+//! For replies to the messages in the topics use [`Message::answer`], which sets the thread id of the message automatically,
+//! and [`ThreadId`] extractor to get the thread id of the message in the handler.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! let topic = ForumTopic::create(bot, chat_id, "Support").await?;
+//!
+//! bot.send(topic.message("How can we help you?")).await?;
+//!
+//! topic.rename("Support (closed)").await?;
+//! topic.close().await?;
+//! ```
+//!
+//! [`Message::answer`]: crate::types::Message#method.answer
+//! [`ThreadId`]: crate::extractors::ThreadId
+
+use crate::{
+    client::{Bot, DefaultSession, Session},
+    errors::SessionErrorKind,
+    methods::{
+        CloseForumTopic, CreateForumTopic, DeleteForumTopic, EditForumTopic, ReopenForumTopic,
+        SendMessage, UnpinAllForumTopicMessages,
+    },
+    types::{ChatIdKind, ForumTopic as ForumTopicInfo, Message},
+};
+
+use std::sync::Arc;
+
+/// Topic of the forum supergroup.
+/// Check the [module](self) documentation for more information.
+pub struct ForumTopic<Client = DefaultSession> {
+    bot: Arc<Bot<Client>>,
+    chat_id: ChatIdKind,
+    thread_id: i64,
+}
+
+impl<Client> ForumTopic<Client> {
+    /// Wrap the topic, which is already created
+    #[must_use]
+    pub fn new(bot: Arc<Bot<Client>>, chat_id: impl Into<ChatIdKind>, thread_id: i64) -> Self {
+        Self {
+            bot,
+            chat_id: chat_id.into(),
+            thread_id,
+        }
+    }
+
+    /// Wrap the topic of the message
+    /// # Returns
+    /// `None` if the message isn't sent to the forum topic
+    #[must_use]
+    pub fn from_message(bot: Arc<Bot<Client>>, message: &Message) -> Option<Self> {
+        if !message.chat().is_forum() {
+            return None;
+        }
+
+        Some(Self::new(bot, message.chat().id(), message.thread_id()?))
+    }
+
+    #[must_use]
+    pub const fn chat_id(&self) -> &ChatIdKind {
+        &self.chat_id
+    }
+
+    #[must_use]
+    pub const fn thread_id(&self) -> i64 {
+        self.thread_id
+    }
+
+    /// Create [`SendMessage`] to send the message to the topic
+    #[must_use]
+    pub fn message(&self, text: impl Into<String>) -> SendMessage {
+        SendMessage::new(self.chat_id.clone(), text).message_thread_id(self.thread_id)
+    }
+}
+
+impl<Client> ForumTopic<Client>
+where
+    Client: Session,
+{
+    /// Create the topic in the forum supergroup chat and wrap it
+    /// # Errors
+    /// If the topic can't be created
+    pub async fn create(
+        bot: Arc<Bot<Client>>,
+        chat_id: impl Into<ChatIdKind>,
+        name: impl Into<String>,
+    ) -> Result<Self, SessionErrorKind> {
+        let chat_id = chat_id.into();
+
+        Self::create_with(bot, CreateForumTopic::new(chat_id, name))
+            .await
+            .map(|(topic, _)| topic)
+    }
+
+    /// Create the topic with the method, which allows to set the icon of the topic, and wrap it
+    /// # Returns
+    /// Wrapped topic and information about the created topic
+    /// # Errors
+    /// If the topic can't be created
+    pub async fn create_with(
+        bot: Arc<Bot<Client>>,
+        method: CreateForumTopic,
+    ) -> Result<(Self, ForumTopicInfo), SessionErrorKind> {
+        let info = bot.send(&method).await?;
+
+        Ok((Self::new(bot, method.chat_id, info.message_thread_id), info))
+    }
+
+    /// Edit name and icon of the topic
+    /// # Arguments
+    /// * `name` - New topic name, if `None`, then the current name is kept
+    /// * `icon_custom_emoji_id` - New unique identifier of the custom emoji shown as the topic icon.
+    ///   Pass an empty string to remove the icon. If `None`, then the current icon is kept.
+    /// # Errors
+    /// If the topic can't be edited
+    pub async fn edit(
+        &self,
+        name: Option<impl Into<String>>,
+        icon_custom_emoji_id: Option<impl Into<String>>,
+    ) -> Result<bool, SessionErrorKind> {
+        self.bot
+            .send(
+                EditForumTopic::new(self.chat_id.clone(), self.thread_id)
+                    .name_option(name)
+                    .icon_custom_emoji_id_option(icon_custom_emoji_id),
+            )
+            .await
+    }
+
+    /// Rename the topic
+    /// # Errors
+    /// If the topic can't be edited
+    pub async fn rename(&self, name: impl Into<String>) -> Result<bool, SessionErrorKind> {
+        self.edit(Some(name), None::<String>).await
+    }
+
+    /// Close the topic, so only administrators can send messages to it
+    /// # Errors
+    /// If the topic can't be closed
+    pub async fn close(&self) -> Result<bool, SessionErrorKind> {
+        self.bot
+            .send(CloseForumTopic::new(self.chat_id.clone(), self.thread_id))
+            .await
+    }
+
+    /// Reopen the closed topic
+    /// # Errors
+    /// If the topic can't be reopened
+    pub async fn reopen(&self) -> Result<bool, SessionErrorKind> {
+        self.bot
+            .send(ReopenForumTopic::new(self.chat_id.clone(), self.thread_id))
+            .await
+    }
+
+    /// Delete the topic along with all its messages
+    /// # Errors
+    /// If the topic can't be deleted
+    pub async fn delete(self) -> Result<bool, SessionErrorKind> {
+        self.bot
+            .send(DeleteForumTopic::new(self.chat_id, self.thread_id))
+            .await
+    }
+
+    /// Unpin all messages in the topic
+    /// # Errors
+    /// If the messages can't be unpinned
+    pub async fn unpin_all_messages(&self) -> Result<bool, SessionErrorKind> {
+        self.bot
+            .send(UnpinAllForumTopicMessages::new(
+                self.chat_id.clone(),
+                self.thread_id,
+            ))
+            .await
+    }
+
+    /// Send the text message to the topic
+    /// # Errors
+    /// If the message can't be sent
+    pub async fn send_message(&self, text: impl Into<String>) -> Result<Message, SessionErrorKind> {
+        self.bot.send(self.message(text)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::Reqwest,
+        types::{Chat, ChatPrivate, ChatSupergroup, MessageText},
+    };
+
+    fn message(chat: Chat) -> Message {
+        Message::Text(Box::new(MessageText {
+            id: 1,
+            thread_id: Some(10),
+            chat,
+            ..Default::default()
+        }))
+    }
+
+    #[test]
+    fn test_from_message() {
+        let bot = Arc::new(Bot::<Reqwest>::default());
+
+        let topic = ForumTopic::from_message(
+            Arc::clone(&bot),
+            &message(Chat::Supergroup(ChatSupergroup {
+                id: -100,
+                title: "Forum".into(),
+                username: None,
+                is_forum: Some(true),
+            })),
+        )
+        .unwrap();
+        assert_eq!(topic.thread_id(), 10);
+        assert_eq!(topic.message("text").message_thread_id, Some(10));
+
+        assert!(ForumTopic::from_message(
+            Arc::clone(&bot),
+            &message(Chat::Private(ChatPrivate {
+                id: 1,
+                ..Default::default()
+            }))
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_answer() {
+        let forum_message = message(Chat::Supergroup(ChatSupergroup {
+            id: -100,
+            title: "Forum".into(),
+            username: None,
+            is_forum: Some(true),
+        }));
+        assert_eq!(forum_message.answer("text").message_thread_id, Some(10));
+
+        // Thread id of the reply thread isn't used outside of the forum
+        let group_message = message(Chat::Supergroup(ChatSupergroup {
+            id: -100,
+            title: "Forum".into(),
+            username: None,
+            is_forum: None,
+        }));
+        assert_eq!(group_message.answer("text").message_thread_id, None);
+    }
+
+    #[test]
+    fn test_thread_id_extractor() {
+        use crate::{
+            context::Context,
+            extractors::{FromEventAndContext as _, ThreadId},
+            types::{Update, UpdateKind},
+        };
+
+        let bot = Arc::new(Bot::<Reqwest>::default());
+        let update = Update {
+            kind: UpdateKind::Message(message(Chat::Supergroup(ChatSupergroup {
+                id: -100,
+                title: "Forum".into(),
+                username: None,
+                is_forum: Some(true),
+            }))),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            ThreadId::extract(
+                Arc::clone(&bot),
+                Arc::new(update),
+                Arc::new(Context::default())
+            )
+            .unwrap(),
+            ThreadId(10)
+        );
+        assert!(ThreadId::extract(
+            bot,
+            Arc::new(Update::default()),
+            Arc::new(Context::default())
+        )
+        .is_err());
+    }
+}
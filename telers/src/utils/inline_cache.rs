@@ -0,0 +1,274 @@
+//! This module contains [`InlineCache`], which caches rendered results of inline queries per normalized query text and offset,
+//! so popular queries are rendered once per TTL instead of on each keystroke of each user.
+//!
+//! Query text is normalized by [`normalize`] (trimmed, lowercased and with collapsed whitespaces),
+//! so `"Cats "` and `"cats"` share the same results.
+//! Concurrent renders of the same query are deduplicated: the first one renders the results and others wait for it and use its results.
+//!
+//! [`InlineCache::answer`] fills `cache_time` of [`AnswerInlineQuery`] with the TTL of the cache,
+//! so Telegram caches the results on its side for the same time, and `is_personal` with [`InlineCache::personal`] option.
+//! Personal results are cached per user.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! async fn handler(bot: Bot, query: InlineQuery, cache: Arc<InlineCache>) -> HandlerResult {
+//!     let answer = cache
+//!         .answer(&query, || async {
+//!             let articles = search(&normalize(&query.query), &query.offset).await?;
+//!
+//!             Ok::<_, HandlerError>(InlineAnswer::new(articles.into_iter().map(render_article)))
+//!         })
+//!         .await?;
+//!
+//!     bot.send(answer).await?;
+//!
+//!     Ok(FlowControl::Continue)
+//! }
+//! ```
+
+use crate::{
+    filters::CachedCheck,
+    methods::AnswerInlineQuery,
+    types::{InlineQuery, InlineQueryResult},
+};
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// Default time to keep the results in the cache
+pub const DEFAULT_TTL: Duration = Duration::from_secs(300);
+/// Default max number of cached pages of results
+pub const DEFAULT_MAX_SIZE: usize = 10_000;
+
+/// Key of the cached results: user ID for personal results, normalized query text and offset
+pub type QueryKey = (Option<i64>, Box<str>, Box<str>);
+
+/// Rendered page of results of the inline query
+#[derive(Debug, Clone, PartialEq)]
+pub struct InlineAnswer {
+    pub results: Vec<InlineQueryResult>,
+    /// Offset, which a client should send in the next query with the same text to receive more results
+    pub next_offset: Option<String>,
+}
+
+impl InlineAnswer {
+    #[must_use]
+    pub fn new<T, I>(results: I) -> Self
+    where
+        T: Into<InlineQueryResult>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            results: results.into_iter().map(Into::into).collect(),
+            next_offset: None,
+        }
+    }
+
+    #[must_use]
+    pub fn next_offset(self, val: impl Into<String>) -> Self {
+        Self {
+            next_offset: Some(val.into()),
+            ..self
+        }
+    }
+}
+
+/// Normalizes the query text: trims it, lowercases it and collapses whitespaces
+#[must_use]
+pub fn normalize(query: &str) -> String {
+    query
+        .split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Cache of rendered results of inline queries.
+/// Check the [module](self) documentation for more information.
+#[derive(Debug, Clone)]
+pub struct InlineCache {
+    cache: CachedCheck<QueryKey, InlineAnswer>,
+    /// Locks of the queries, which are being rendered now
+    rendering: Arc<Mutex<HashMap<QueryKey, Arc<tokio::sync::Mutex<()>>>>>,
+    ttl: Duration,
+    personal: bool,
+}
+
+impl InlineCache {
+    /// Creates a new cache, which keeps results for `ttl` and stores at most `max_size` pages of results
+    #[must_use]
+    pub fn new(ttl: Duration, max_size: usize) -> Self {
+        Self {
+            cache: CachedCheck::new(ttl, max_size),
+            rendering: Arc::default(),
+            ttl,
+            personal: false,
+        }
+    }
+
+    /// Set that results depend on the user, so they are cached per user, and Telegram caches them only for the user
+    #[must_use]
+    pub fn personal(self, val: bool) -> Self {
+        Self {
+            personal: val,
+            ..self
+        }
+    }
+
+    /// Gets key of the cached results of the query
+    #[must_use]
+    pub fn key(&self, query: &InlineQuery) -> QueryKey {
+        (
+            self.personal.then_some(query.from.id),
+            normalize(&query.query).into(),
+            query.offset.clone(),
+        )
+    }
+
+    /// Removes the cached results of the query, for example, when the results are known to be changed
+    pub fn invalidate(&self, query: &InlineQuery) {
+        self.cache.invalidate(&self.key(query));
+    }
+
+    /// Removes all cached results
+    pub fn clear(&self) {
+        self.cache.clear();
+    }
+
+    /// Gets the cached results of the query or renders them and caches them.
+    /// Errors of the render aren't cached, so the results are rendered again next time.
+    /// # Returns
+    /// [`AnswerInlineQuery`] with the results, `cache_time` and `is_personal`
+    /// # Errors
+    /// If the render returns an error
+    pub async fn answer<F, Fut, E>(
+        &self,
+        query: &InlineQuery,
+        render: F,
+    ) -> Result<AnswerInlineQuery, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<InlineAnswer, E>>,
+    {
+        let key = self.key(query);
+
+        let answer = match self.cache.get(&key) {
+            Some(answer) => answer,
+            None => self.render(key, render).await?,
+        };
+
+        Ok(AnswerInlineQuery::new(&*query.id, answer.results)
+            .next_offset_option(answer.next_offset)
+            .cache_time(i64::try_from(self.ttl.as_secs()).unwrap_or(i64::MAX))
+            .is_personal(self.personal))
+    }
+
+    async fn render<F, Fut, E>(&self, key: QueryKey, render: F) -> Result<InlineAnswer, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<InlineAnswer, E>>,
+    {
+        let lock = Arc::clone(
+            self.rendering
+                .lock()
+                .unwrap()
+                .entry(key.clone())
+                .or_default(),
+        );
+
+        let result = {
+            let _guard = lock.lock().await;
+
+            // Results can be rendered by another query, while we were waiting for the lock
+            match self.cache.get(&key) {
+                Some(answer) => Ok(answer),
+                None => self.cache.get_or_try_insert_with(key.clone(), render).await,
+            }
+        };
+
+        let mut rendering = self.rendering.lock().unwrap();
+        // Lock is removed by the last query, which uses it: one reference is in the map and another one is ours
+        if Arc::strong_count(&lock) == 2 {
+            rendering.remove(&key);
+        }
+
+        result
+    }
+}
+
+impl Default for InlineCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_TTL, DEFAULT_MAX_SIZE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{InlineQueryResultArticle, InputTextMessageContent, User};
+
+    use std::{
+        convert::Infallible,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    fn query(user_id: i64, text: &str) -> InlineQuery {
+        InlineQuery {
+            id: "1".into(),
+            from: User {
+                id: user_id,
+                ..Default::default()
+            },
+            query: text.into(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_answer() {
+        let cache = InlineCache::default();
+        let renders = AtomicUsize::new(0);
+
+        let render = || async {
+            renders.fetch_add(1, Ordering::SeqCst);
+            tokio::task::yield_now().await;
+
+            Ok::<_, Infallible>(
+                InlineAnswer::new([InlineQueryResultArticle::new(
+                    "1",
+                    "title",
+                    InputTextMessageContent::new("text"),
+                )])
+                .next_offset("10"),
+            )
+        };
+
+        // Concurrent renders of the same query are deduplicated
+        let (first, second) = (query(1, "Cats "), query(2, "cats"));
+        let (first, second) =
+            tokio::join!(cache.answer(&first, render), cache.answer(&second, render),);
+        let (first, second) = (first.unwrap(), second.unwrap());
+
+        assert_eq!(renders.load(Ordering::SeqCst), 1);
+        assert_eq!(first.results, second.results);
+        assert_eq!(first.next_offset.as_deref(), Some("10"));
+        assert_eq!(first.cache_time, Some(300));
+        assert_eq!(first.is_personal, Some(false));
+        assert!(cache.rendering.lock().unwrap().is_empty());
+
+        // Personal results are cached per user
+        let cache = cache.personal(true);
+        cache.answer(&query(1, "dogs"), render).await.unwrap();
+        cache.answer(&query(2, "dogs"), render).await.unwrap();
+        assert_eq!(renders.load(Ordering::SeqCst), 3);
+
+        cache.invalidate(&query(1, "dogs"));
+        cache.answer(&query(1, "Dogs"), render).await.unwrap();
+        assert_eq!(renders.load(Ordering::SeqCst), 4);
+    }
+}
@@ -0,0 +1,444 @@
+//! This module contains [`InviteLinks`], which creates, edits and revokes invite links of the chat
+//! with typed parameters ([`InviteLinkParams`]) and tracks them by named campaigns,
+//! so join requests and new members can be correlated back to the campaign, which brought them.
+//!
+//! Telegram doesn't allow to set both member limit and join request approval for the same link,
+//! so [`Access`] is used instead of separate fields, and parameters are validated before the request is sent.
+//!
+//! Links are tracked by [`Campaigns`], which is a cheap to clone in-memory registry,
+//! so it can be shared between the manager and handlers (for example, with a closure or a context middleware).
+//! Use [`Campaigns::by_join_request`] to get the campaign of the join request.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! let links = InviteLinks::new(bot, chat_id, Campaigns::new());
+//!
+//! let link = links
+//!     .create("summer_sale", InviteLinkParams::new().join_request().expire_in(Duration::from_secs(86400)))
+//!     .await?;
+//!
+//! // In the chat join request handler
+//! if let Some(campaign) = campaigns.by_join_request(&join_request) {
+//!     println!("User came from the campaign {campaign}");
+//! }
+//! ```
+
+use crate::{
+    client::{Bot, DefaultSession, Session},
+    errors::SessionErrorKind,
+    methods::{CreateChatInviteLink, EditChatInviteLink, RevokeChatInviteLink},
+    types::{ChatIdKind, ChatInviteLink, ChatJoinRequest},
+};
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use thiserror;
+
+/// Max length of the invite link name
+pub const MAX_NAME_LEN: usize = 32;
+/// Max number of users that can be members of the chat simultaneously after joining the chat via the invite link
+pub const MAX_MEMBER_LIMIT: u32 = 99_999;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Invite link name must be at most {MAX_NAME_LEN} characters, got {0}")]
+    NameTooLong(usize),
+    #[error("Member limit must be in range 1-{MAX_MEMBER_LIMIT}, got {0}")]
+    MemberLimit(u32),
+    #[error("Campaign `{0}` isn't tracked")]
+    UnknownCampaign(Box<str>),
+    #[error(transparent)]
+    Session(#[from] SessionErrorKind),
+}
+
+/// How users join the chat via the invite link
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Access {
+    /// Users join the chat without restrictions
+    #[default]
+    Open,
+    /// Users join the chat without approval, but the number of members joined via the link is limited
+    MemberLimit(u32),
+    /// Users send join requests, which need to be approved by chat administrators
+    JoinRequest,
+}
+
+/// Typed parameters of the invite link.
+/// Check the [module](self) documentation for more information.
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq)]
+pub struct InviteLinkParams {
+    /// Invite link name; 0-32 characters. If not set, the campaign name is used
+    pub name: Option<Box<str>>,
+    /// Point in time (Unix timestamp) when the link will expire
+    pub expire_date: Option<i64>,
+    pub access: Access,
+}
+
+impl InviteLinkParams {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn name(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            name: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn expire_date(self, val: i64) -> Self {
+        Self {
+            expire_date: Some(val),
+            ..self
+        }
+    }
+
+    /// Set expire date of the link to the current time plus the duration
+    #[must_use]
+    pub fn expire_in(self, val: Duration) -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+
+        self.expire_date((now + val).as_secs().try_into().unwrap_or(i64::MAX))
+    }
+
+    #[must_use]
+    pub fn access(self, val: Access) -> Self {
+        Self {
+            access: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn member_limit(self, val: u32) -> Self {
+        self.access(Access::MemberLimit(val))
+    }
+
+    #[must_use]
+    pub fn join_request(self) -> Self {
+        self.access(Access::JoinRequest)
+    }
+
+    /// Check the parameters against Telegram constraints
+    /// # Errors
+    /// - If the name is longer than [`MAX_NAME_LEN`] characters
+    /// - If the member limit isn't in range 1-[`MAX_MEMBER_LIMIT`]
+    pub fn validate(&self) -> Result<(), Error> {
+        if let Some(name) = &self.name {
+            let len = name.chars().count();
+            if len > MAX_NAME_LEN {
+                return Err(Error::NameTooLong(len));
+            }
+        }
+        if let Access::MemberLimit(limit) = self.access {
+            if limit == 0 || limit > MAX_MEMBER_LIMIT {
+                return Err(Error::MemberLimit(limit));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Build [`CreateChatInviteLink`] method with the parameters
+    /// # Errors
+    /// If the parameters are invalid, see [`InviteLinkParams::validate`]
+    pub fn create_method(
+        &self,
+        chat_id: impl Into<ChatIdKind>,
+    ) -> Result<CreateChatInviteLink, Error> {
+        self.validate()?;
+
+        let (member_limit, creates_join_request) = self.access_fields();
+
+        Ok(CreateChatInviteLink::new(chat_id)
+            .name_option(self.name.as_deref())
+            .expire_date_option(self.expire_date)
+            .member_limit_option(member_limit)
+            .creates_join_request_option(creates_join_request))
+    }
+
+    /// Build [`EditChatInviteLink`] method with the parameters
+    /// # Errors
+    /// If the parameters are invalid, see [`InviteLinkParams::validate`]
+    pub fn edit_method(
+        &self,
+        chat_id: impl Into<ChatIdKind>,
+        invite_link: impl Into<String>,
+    ) -> Result<EditChatInviteLink, Error> {
+        self.validate()?;
+
+        let (member_limit, creates_join_request) = self.access_fields();
+
+        Ok(EditChatInviteLink::new(chat_id, invite_link)
+            .name_option(self.name.as_deref())
+            .expire_date_option(self.expire_date)
+            .member_limit_option(member_limit)
+            .creates_join_request_option(creates_join_request))
+    }
+
+    /// Get `member_limit` and `creates_join_request` fields of the methods
+    fn access_fields(&self) -> (Option<i64>, Option<bool>) {
+        match self.access {
+            Access::Open => (None, None),
+            Access::MemberLimit(limit) => (Some(limit.into()), None),
+            Access::JoinRequest => (None, Some(true)),
+        }
+    }
+}
+
+/// In-memory registry of the invite links by campaign names.
+/// Check the [module](self) documentation for more information.
+#[derive(Debug, Default, Clone)]
+pub struct Campaigns {
+    links: Arc<RwLock<HashMap<Box<str>, Box<str>>>>,
+}
+
+impl Campaigns {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Track the invite link as a link of the campaign
+    pub fn insert(&self, campaign: impl Into<Box<str>>, invite_link: impl Into<Box<str>>) {
+        self.links
+            .write()
+            .unwrap()
+            .insert(invite_link.into(), campaign.into());
+    }
+
+    /// Stop tracking the invite link
+    /// # Returns
+    /// Campaign of the link, if it's tracked
+    pub fn remove(&self, invite_link: &str) -> Option<Box<str>> {
+        self.links.write().unwrap().remove(invite_link)
+    }
+
+    /// Get campaign of the invite link
+    #[must_use]
+    pub fn by_link(&self, invite_link: &str) -> Option<Box<str>> {
+        self.links.read().unwrap().get(invite_link).cloned()
+    }
+
+    /// Get campaign of the invite link, which was used by the user to send the join request
+    #[must_use]
+    pub fn by_join_request(&self, join_request: &ChatJoinRequest) -> Option<Box<str>> {
+        self.by_link(&join_request.invite_link.as_ref()?.invite_link)
+    }
+
+    /// Get invite links of the campaign
+    #[must_use]
+    pub fn links(&self, campaign: &str) -> Vec<Box<str>> {
+        self.links
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(_, link_campaign)| &***link_campaign == campaign)
+            .map(|(link, _)| link.clone())
+            .collect()
+    }
+}
+
+/// Manager of the invite links of the chat.
+/// Check the [module](self) documentation for more information.
+pub struct InviteLinks<Client = DefaultSession> {
+    bot: Arc<Bot<Client>>,
+    chat_id: ChatIdKind,
+    campaigns: Campaigns,
+}
+
+impl<Client> InviteLinks<Client> {
+    #[must_use]
+    pub fn new(
+        bot: Arc<Bot<Client>>,
+        chat_id: impl Into<ChatIdKind>,
+        campaigns: Campaigns,
+    ) -> Self {
+        Self {
+            bot,
+            chat_id: chat_id.into(),
+            campaigns,
+        }
+    }
+
+    #[must_use]
+    pub const fn chat_id(&self) -> &ChatIdKind {
+        &self.chat_id
+    }
+
+    #[must_use]
+    pub const fn campaigns(&self) -> &Campaigns {
+        &self.campaigns
+    }
+}
+
+impl<Client> InviteLinks<Client>
+where
+    Client: Session,
+{
+    /// Create the invite link for the campaign and track it.
+    /// If the name of the link isn't set in the parameters, the campaign name is used.
+    /// # Errors
+    /// - If the parameters are invalid
+    /// - If the link can't be created
+    pub async fn create(
+        &self,
+        campaign: impl Into<Box<str>>,
+        params: InviteLinkParams,
+    ) -> Result<ChatInviteLink, Error> {
+        let campaign = campaign.into();
+
+        let params = if params.name.is_some() {
+            params
+        } else {
+            params.name(campaign.chars().take(MAX_NAME_LEN).collect::<String>())
+        };
+
+        let link = self
+            .bot
+            .send(params.create_method(self.chat_id.clone())?)
+            .await?;
+
+        self.campaigns.insert(campaign, &*link.invite_link);
+
+        Ok(link)
+    }
+
+    /// Edit the invite link. Campaign of the link isn't changed.
+    /// # Errors
+    /// - If the parameters are invalid
+    /// - If the link can't be edited
+    pub async fn edit(
+        &self,
+        invite_link: &str,
+        params: &InviteLinkParams,
+    ) -> Result<ChatInviteLink, Error> {
+        self.bot
+            .send(params.edit_method(self.chat_id.clone(), invite_link)?)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Revoke the invite link and stop tracking it
+    /// # Errors
+    /// If the link can't be revoked
+    pub async fn revoke(&self, invite_link: &str) -> Result<ChatInviteLink, Error> {
+        let link = self
+            .bot
+            .send(RevokeChatInviteLink::new(self.chat_id.clone(), invite_link))
+            .await?;
+
+        self.campaigns.remove(invite_link);
+
+        Ok(link)
+    }
+
+    /// Revoke all invite links of the campaign
+    /// # Returns
+    /// Revoked links
+    /// # Errors
+    /// - If the campaign isn't tracked
+    /// - If some link can't be revoked, links, which are revoked before the error, aren't tracked anymore
+    pub async fn revoke_campaign(&self, campaign: &str) -> Result<Vec<ChatInviteLink>, Error> {
+        let links = self.campaigns.links(campaign);
+        if links.is_empty() {
+            return Err(Error::UnknownCampaign(campaign.into()));
+        }
+
+        let mut revoked = Vec::with_capacity(links.len());
+        for link in links {
+            revoked.push(self.revoke(&link).await?);
+        }
+
+        Ok(revoked)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ChatInviteLink, User};
+
+    #[test]
+    fn test_params() {
+        let method = InviteLinkParams::new()
+            .name("campaign")
+            .member_limit(10)
+            .create_method(1)
+            .unwrap();
+        assert_eq!(method.name.as_deref(), Some("campaign"));
+        assert_eq!(method.member_limit, Some(10));
+        assert_eq!(method.creates_join_request, None);
+
+        // Join request replaces the member limit, because they can't be used together
+        let method = InviteLinkParams::new()
+            .member_limit(10)
+            .join_request()
+            .edit_method(1, "https://t.me/+link")
+            .unwrap();
+        assert_eq!(method.member_limit, None);
+        assert_eq!(method.creates_join_request, Some(true));
+
+        assert!(matches!(
+            InviteLinkParams::new().member_limit(0).validate(),
+            Err(Error::MemberLimit(0))
+        ));
+        assert!(matches!(
+            InviteLinkParams::new().name("a".repeat(33)).validate(),
+            Err(Error::NameTooLong(33))
+        ));
+        assert!(InviteLinkParams::new()
+            .expire_in(Duration::from_secs(60))
+            .expire_date
+            .is_some());
+    }
+
+    #[test]
+    fn test_campaigns() {
+        let campaigns = Campaigns::new();
+        campaigns.insert("summer", "https://t.me/+a");
+        campaigns.insert("summer", "https://t.me/+b");
+        campaigns.insert("winter", "https://t.me/+c");
+
+        let mut links = campaigns.links("summer");
+        links.sort();
+        assert_eq!(links, ["https://t.me/+a".into(), "https://t.me/+b".into()]);
+
+        let join_request = ChatJoinRequest {
+            invite_link: Some(ChatInviteLink {
+                invite_link: "https://t.me/+c".into(),
+                creator: User::default(),
+                creates_join_request: true,
+                is_primary: false,
+                is_revoked: false,
+                name: None,
+                expire_date: None,
+                member_limit: None,
+                pending_join_request_count: None,
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            campaigns.by_join_request(&join_request).as_deref(),
+            Some("winter")
+        );
+        assert!(campaigns
+            .by_join_request(&ChatJoinRequest::default())
+            .is_none());
+
+        assert_eq!(
+            campaigns.remove("https://t.me/+c").as_deref(),
+            Some("winter")
+        );
+        assert!(campaigns.by_link("https://t.me/+c").is_none());
+    }
+}
@@ -0,0 +1,155 @@
+//! This module contains helpers for editing inline keyboards of sent messages,
+//! which menu-style bots do constantly: toggle a setting, mark the selected option or replace a button.
+//!
+//! Buttons are found by their callback data, check [`InlineKeyboardMarkup::button`], [`InlineKeyboardMarkup::replace_button`],
+//! [`InlineKeyboardMarkup::toggle_button`] and [`InlineKeyboardMarkup::remove_button`] for mutation of the keyboard
+//! and [`InlineKeyboardButton::marker`] for markers in the button text.
+//!
+//! # Examples
+//!
+//! Toggle [`CHECK_MARK`] on the pressed button of the callback query message:
+//!
+//! ```rust,ignore
+//! async fn handler(bot: Bot, query: CallbackQuery) -> HandlerResult {
+//!     if let Some(method) = keyboard::toggle_pressed_button(&query, CHECK_MARK) {
+//!         bot.send(method).await?;
+//!     }
+//!
+//!     Ok(FlowControl::Continue)
+//! }
+//! ```
+//!
+//! [`InlineKeyboardButton::marker`]: crate::types::InlineKeyboardButton#method.marker
+
+use crate::{
+    methods::EditMessageReplyMarkup,
+    types::{CallbackQuery, InlineKeyboardMarkup, Message},
+};
+
+/// Marker of the selected button, which is added to the start of the button text
+pub const CHECK_MARK: &str = "✅ ";
+
+/// Create [`EditMessageReplyMarkup`] for the message with the keyboard
+#[must_use]
+pub fn edit_message_reply_markup(
+    message: &Message,
+    markup: impl Into<InlineKeyboardMarkup>,
+) -> EditMessageReplyMarkup {
+    EditMessageReplyMarkup::new()
+        .chat_id(message.chat().id())
+        .message_id(message.id())
+        .reply_markup(markup)
+}
+
+/// Create [`EditMessageReplyMarkup`] for the message, which originated the callback query, with the keyboard.
+/// Messages sent via the bot in inline mode are edited by `inline_message_id`.
+/// # Returns
+/// `None` if the callback query doesn't have a message or `inline_message_id`
+#[must_use]
+pub fn edit_reply_markup(
+    query: &CallbackQuery,
+    markup: impl Into<InlineKeyboardMarkup>,
+) -> Option<EditMessageReplyMarkup> {
+    let method = EditMessageReplyMarkup::new().reply_markup(markup);
+
+    if let Some(inline_message_id) = query.inline_message_id.as_deref() {
+        return Some(method.inline_message_id(inline_message_id));
+    }
+
+    Some(
+        method
+            .chat_id(query.chat_id()?)
+            .message_id(query.message_id()?),
+    )
+}
+
+/// Get the keyboard of the message, which originated the callback query, to edit it
+/// # Returns
+/// `None` if the message is inaccessible or doesn't have the keyboard
+#[must_use]
+pub fn reply_markup(query: &CallbackQuery) -> Option<InlineKeyboardMarkup> {
+    query.accessible_message()?.reply_markup().cloned()
+}
+
+/// Toggle the marker in the text of the pressed button (found by callback data of the query)
+/// and create [`EditMessageReplyMarkup`] with the changed keyboard
+/// # Returns
+/// `None` if the callback query doesn't have data, the message is inaccessible or doesn't have the keyboard
+#[must_use]
+pub fn toggle_pressed_button(
+    query: &CallbackQuery,
+    marker: &str,
+) -> Option<EditMessageReplyMarkup> {
+    let data = query.data.as_deref()?;
+    let markup = reply_markup(query)?.toggle_button(data, marker);
+
+    edit_reply_markup(query, markup)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{
+        Chat, ChatPrivate, InlineKeyboardButton, MaybeInaccessibleMessage, MessageText,
+    };
+
+    fn query() -> CallbackQuery {
+        CallbackQuery {
+            data: Some("notifications".into()),
+            message: Some(MaybeInaccessibleMessage::Message(Message::Text(Box::new(
+                MessageText {
+                    id: 2,
+                    chat: Chat::Private(ChatPrivate {
+                        id: 1,
+                        ..Default::default()
+                    }),
+                    reply_markup: Some(InlineKeyboardMarkup::new([[
+                        InlineKeyboardButton::new("Notifications").callback_data("notifications"),
+                        InlineKeyboardButton::new("Sounds").callback_data("sounds"),
+                    ]])),
+                    ..Default::default()
+                },
+            )))),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_toggle_pressed_button() {
+        let method = toggle_pressed_button(&query(), CHECK_MARK).unwrap();
+        let markup = method.reply_markup.unwrap();
+
+        assert_eq!(method.message_id, Some(2));
+        assert_eq!(
+            markup.button("notifications").map(|button| &*button.text),
+            Some("✅ Notifications")
+        );
+        assert_eq!(
+            markup.button("sounds").map(|button| &*button.text),
+            Some("Sounds")
+        );
+
+        let query = CallbackQuery {
+            message: None,
+            ..query()
+        };
+        assert!(toggle_pressed_button(&query, CHECK_MARK).is_none());
+    }
+
+    #[test]
+    fn test_edit_reply_markup() {
+        let query = CallbackQuery {
+            inline_message_id: Some("inline".into()),
+            message: None,
+            ..Default::default()
+        };
+        let method = edit_reply_markup(&query, InlineKeyboardMarkup::empty()).unwrap();
+
+        assert_eq!(method.inline_message_id.as_deref(), Some("inline"));
+        assert!(method.chat_id.is_none());
+
+        assert!(
+            edit_reply_markup(&CallbackQuery::default(), InlineKeyboardMarkup::empty()).is_none()
+        );
+    }
+}
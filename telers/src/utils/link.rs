@@ -0,0 +1,197 @@
+//! This module contains [`message_link`] function, which builds a link to the message, and [`parse_message_link`] function,
+//! which extracts the chat and the message from the link, for example, for moderation and cross-posting bots.
+//!
+//! Messages of public supergroups and channels have links with the username of the chat (`https://t.me/<username>/<message_id>`),
+//! messages of private supergroups and channels have links with the internal id of the chat (`https://t.me/c/<internal_id>/<message_id>`),
+//! which are available only for members of the chat.
+//! Links to messages in forum topics also contain the thread id (`https://t.me/<username>/<thread_id>/<message_id>`).
+//! Messages of private chats and basic groups don't have links.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use telers::{
+//!     types::ChatIdKind,
+//!     utils::link::{parse_message_link, MessageLink},
+//! };
+//!
+//! assert_eq!(
+//!     parse_message_link("https://t.me/c/1234567890/10"),
+//!     Some(MessageLink {
+//!         chat_id: ChatIdKind::Id(-1_001_234_567_890),
+//!         thread_id: None,
+//!         message_id: 10,
+//!     }),
+//! );
+//! ```
+
+use crate::types::{Chat, ChatIdKind, Message};
+
+/// Prefix of the ids of supergroups and channels
+const CHANNEL_ID_PREFIX: i64 = -1_000_000_000_000;
+
+/// Chat and message, which are extracted from the link by [`parse_message_link`]
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct MessageLink {
+    /// Id of the chat for private links and username of the chat with `@` for public links,
+    /// so it can be passed to methods as is
+    pub chat_id: ChatIdKind,
+    /// Id of the forum topic, if the message is sent to the topic
+    pub thread_id: Option<i64>,
+    pub message_id: i64,
+}
+
+/// Gets the internal id of the supergroup or channel, which is used in private links
+/// # Returns
+/// `None` if the chat isn't a supergroup or channel
+#[must_use]
+pub const fn internal_chat_id(chat_id: i64) -> Option<i64> {
+    if chat_id < CHANNEL_ID_PREFIX {
+        Some(CHANNEL_ID_PREFIX - chat_id)
+    } else {
+        None
+    }
+}
+
+/// Builds a link to the message in the chat.
+/// Link with the username is used if the chat is public, otherwise the private link with the internal id of the chat is used.
+/// `thread_id` is added to the link, so the message is opened in the forum topic.
+/// # Returns
+/// `None` if the chat is a private chat or a basic group, because their messages don't have links
+#[must_use]
+pub fn message_link(chat: &Chat, message_id: i64, thread_id: Option<i64>) -> Option<String> {
+    let chat = match chat {
+        Chat::Private(_) | Chat::Group(_) => return None,
+        Chat::Supergroup(_) | Chat::Channel(_) => match chat.username() {
+            Some(username) => username.to_owned(),
+            None => format!("c/{}", internal_chat_id(chat.id())?),
+        },
+    };
+
+    Some(match thread_id {
+        Some(thread_id) => format!("https://t.me/{chat}/{thread_id}/{message_id}"),
+        None => format!("https://t.me/{chat}/{message_id}"),
+    })
+}
+
+/// Builds a link to the message with [`message_link`].
+/// Thread id is added only for messages in forum topics.
+#[must_use]
+pub fn link_to(message: &Message) -> Option<String> {
+    let chat = message.chat();
+    let thread_id = if chat.is_forum() {
+        message.thread_id()
+    } else {
+        None
+    };
+
+    message_link(chat, message.id(), thread_id)
+}
+
+/// Extracts the chat and the message from the link, which is built by Telegram or [`message_link`].
+/// Links with and without scheme, `telegram.me` and `telegram.dog` domains and query parameters (for example, `?single`) are supported.
+/// # Returns
+/// `None` if the link isn't a link to the message
+#[must_use]
+pub fn parse_message_link(link: &str) -> Option<MessageLink> {
+    let link = link.trim();
+    let link = link
+        .strip_prefix("https://")
+        .or_else(|| link.strip_prefix("http://"))
+        .unwrap_or(link);
+    let path = ["t.me/", "telegram.me/", "telegram.dog/"]
+        .into_iter()
+        .find_map(|domain| link.strip_prefix(domain))?;
+    let path = path.split(['?', '#']).next()?.trim_end_matches('/');
+
+    let mut parts = path.split('/');
+    let chat_id = match parts.next()? {
+        "c" => ChatIdKind::Id(CHANNEL_ID_PREFIX - parts.next()?.parse::<i64>().ok()?),
+        username
+            if !username.is_empty()
+                && username
+                    .chars()
+                    .all(|ch| ch.is_ascii_alphanumeric() || ch == '_') =>
+        {
+            ChatIdKind::Username(format!("@{username}").into())
+        }
+        _ => return None,
+    };
+
+    let ids = parts
+        .map(|part| part.parse::<i64>().ok().filter(|id| *id > 0))
+        .collect::<Option<Vec<_>>>()?;
+    let (thread_id, message_id) = match ids[..] {
+        [message_id] => (None, message_id),
+        [thread_id, message_id] => (Some(thread_id), message_id),
+        _ => return None,
+    };
+
+    Some(MessageLink {
+        chat_id,
+        thread_id,
+        message_id,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ChatChannel, ChatPrivate, ChatSupergroup};
+
+    #[test]
+    fn test_message_link() {
+        let public = Chat::Channel(ChatChannel {
+            id: -1_001_234_567_890,
+            title: "test".into(),
+            username: Some("test".into()),
+        });
+        let private = Chat::Supergroup(ChatSupergroup {
+            id: -1_001_234_567_890,
+            title: "test".into(),
+            username: None,
+            is_forum: Some(true),
+        });
+
+        assert_eq!(
+            message_link(&public, 10, None).as_deref(),
+            Some("https://t.me/test/10")
+        );
+        assert_eq!(
+            message_link(&private, 10, Some(5)).as_deref(),
+            Some("https://t.me/c/1234567890/5/10")
+        );
+        assert_eq!(
+            message_link(&Chat::Private(ChatPrivate::default()), 10, None),
+            None
+        );
+
+        for chat in [public, private] {
+            let link = message_link(&chat, 10, Some(5)).unwrap();
+            let parsed = parse_message_link(&link).unwrap();
+
+            assert_eq!(parsed.thread_id, Some(5));
+            assert_eq!(parsed.message_id, 10);
+            match parsed.chat_id {
+                ChatIdKind::Id(id) => assert_eq!(id, chat.id()),
+                ChatIdKind::Username(username) => assert_eq!(&*username, "@test"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_message_link() {
+        assert_eq!(
+            parse_message_link("t.me/test/10?single"),
+            Some(MessageLink {
+                chat_id: ChatIdKind::username("@test"),
+                thread_id: None,
+                message_id: 10,
+            })
+        );
+        assert_eq!(parse_message_link("https://t.me/test"), None);
+        assert_eq!(parse_message_link("https://t.me/+invite"), None);
+        assert_eq!(parse_message_link("https://t.me/c/test/10"), None);
+        assert_eq!(parse_message_link("https://example.com/test/10"), None);
+    }
+}
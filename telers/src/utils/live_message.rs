@@ -0,0 +1,307 @@
+//! This module contains [`LiveMessage`], which wraps a sent message and edits it with rate limiting,
+//! so progress bars and live dashboards don't hit `MESSAGE_NOT_MODIFIED` and flood limits (`429 Too Many Requests`).
+//!
+//! [`LiveMessage::update`] doesn't edit the message more often than once per [`LiveMessage::min_interval`]:
+//! if the interval isn't passed, the text is saved as pending and the method returns immediately,
+//! so intermediate states of the progress are skipped.
+//! Identical content isn't sent and `MESSAGE_NOT_MODIFIED` errors are ignored.
+//! If Telegram returns `RetryAfter` error, next edits are postponed for the requested time.
+//!
+//! Call [`LiveMessage::flush`] or [`LiveMessage::finish`] at the end to make sure that the last text is sent,
+//! they wait for the rate limit instead of skipping the edit.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! let live = LiveMessage::send(bot, chat_id, "Progress: 0%").await?;
+//!
+//! for progress in 1..=100 {
+//!     do_work().await;
+//!     live.update(format!("Progress: {progress}%")).await?;
+//! }
+//!
+//! live.finish("Done!").await?;
+//! ```
+
+use crate::{
+    client::{Bot, DefaultSession, Session},
+    errors::{SessionErrorKind, TelegramErrorKind},
+    methods::{EditMessageText, SendMessage},
+    runtime::{self, Runtime},
+    types::ChatIdKind,
+};
+
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::sync::Mutex;
+use tracing::{event, Level};
+
+/// Default min interval between edits of the message.
+/// Telegram allows about one message per second in the same chat.
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Decision of the rate limiter about the new text
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Decision {
+    /// Text is the same as the sent one, so the edit is skipped
+    NotModified,
+    /// Edit isn't allowed until the instant, so the text is saved as pending
+    Wait(Instant),
+    /// Edit is allowed
+    Edit,
+}
+
+#[derive(Debug)]
+struct State {
+    /// Last sent text
+    sent: Option<Box<str>>,
+    /// Text, which isn't sent, because of the rate limit
+    pending: Option<Box<str>>,
+    /// Instant, when the next edit is allowed
+    next_edit_at: Instant,
+}
+
+impl State {
+    fn new(sent: Option<Box<str>>, now: Instant) -> Self {
+        Self {
+            sent,
+            pending: None,
+            next_edit_at: now,
+        }
+    }
+
+    fn decide(&mut self, text: Box<str>, now: Instant) -> Decision {
+        if self.sent.as_deref() == Some(&*text) {
+            self.pending = None;
+
+            return Decision::NotModified;
+        }
+
+        self.pending = Some(text);
+
+        if now < self.next_edit_at {
+            Decision::Wait(self.next_edit_at)
+        } else {
+            Decision::Edit
+        }
+    }
+
+    fn edited(&mut self, now: Instant, min_interval: Duration) {
+        self.sent = self.pending.take();
+        self.next_edit_at = now + min_interval;
+    }
+
+    fn retry_after(&mut self, now: Instant, retry_after: Duration) {
+        self.next_edit_at = now + retry_after;
+    }
+}
+
+/// Message, which is edited with rate limiting.
+/// Check the [module](self) documentation for more information.
+pub struct LiveMessage<Client = DefaultSession> {
+    bot: Arc<Bot<Client>>,
+    chat_id: ChatIdKind,
+    message_id: i64,
+    min_interval: Duration,
+    state: Mutex<State>,
+    runtime: Arc<dyn Runtime>,
+}
+
+impl<Client> LiveMessage<Client> {
+    /// Wrap the message, which is already sent
+    /// # Arguments
+    /// * `text` - Current text of the message, if it's known, to skip edits with the same text
+    #[must_use]
+    pub fn new(
+        bot: Arc<Bot<Client>>,
+        chat_id: impl Into<ChatIdKind>,
+        message_id: i64,
+        text: Option<impl Into<Box<str>>>,
+    ) -> Self {
+        Self {
+            bot,
+            chat_id: chat_id.into(),
+            message_id,
+            min_interval: DEFAULT_MIN_INTERVAL,
+            state: Mutex::new(State::new(text.map(Into::into), Instant::now())),
+            runtime: runtime::default(),
+        }
+    }
+
+    /// Set min interval between edits of the message. By default, it's [`DEFAULT_MIN_INTERVAL`].
+    /// Use bigger interval for groups, because Telegram allows only about 20 messages per minute in the same group.
+    #[must_use]
+    pub fn min_interval(self, val: Duration) -> Self {
+        Self {
+            min_interval: val,
+            ..self
+        }
+    }
+
+    /// Set runtime, which is used to wait for the rate limit.
+    /// Check [`runtime`] module documentation for more information.
+    /// By default, it's [`runtime::Tokio`] with `tokio-runtime` feature.
+    #[must_use]
+    pub fn runtime(self, val: impl Runtime) -> Self {
+        Self {
+            runtime: Arc::new(val),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub const fn message_id(&self) -> i64 {
+        self.message_id
+    }
+}
+
+impl<Client> LiveMessage<Client>
+where
+    Client: Session,
+{
+    /// Send the message with the text and wrap it
+    /// # Errors
+    /// If the message can't be sent
+    pub async fn send(
+        bot: Arc<Bot<Client>>,
+        chat_id: impl Into<ChatIdKind>,
+        text: impl Into<Box<str>>,
+    ) -> Result<Self, SessionErrorKind> {
+        let chat_id = chat_id.into();
+        let text = text.into();
+
+        let message = bot.send(SendMessage::new(chat_id.clone(), &*text)).await?;
+
+        Ok(Self::new(bot, chat_id, message.id(), Some(text)))
+    }
+
+    /// Edit the message with the text, if the rate limit allows it, otherwise save the text as pending
+    /// # Returns
+    /// `true` if the message is edited, `false` if the edit is skipped
+    /// # Errors
+    /// If the message can't be edited (except `MESSAGE_NOT_MODIFIED` and `RetryAfter` errors)
+    pub async fn update(&self, text: impl Into<Box<str>>) -> Result<bool, SessionErrorKind> {
+        let mut state = self.state.lock().await;
+
+        match state.decide(text.into(), Instant::now()) {
+            Decision::NotModified | Decision::Wait(_) => Ok(false),
+            Decision::Edit => self.edit(&mut state).await,
+        }
+    }
+
+    /// Edit the message with the pending text, waiting for the rate limit if it's needed
+    /// # Returns
+    /// `true` if the message is edited, `false` if there is no pending text
+    /// # Errors
+    /// If the message can't be edited (except `MESSAGE_NOT_MODIFIED` errors)
+    pub async fn flush(&self) -> Result<bool, SessionErrorKind> {
+        let mut state = self.state.lock().await;
+
+        while state.pending.is_some() {
+            self.runtime
+                .sleep(state.next_edit_at.saturating_duration_since(Instant::now()))
+                .await;
+
+            if self.edit(&mut state).await? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Edit the message with the final text, waiting for the rate limit if it's needed
+    /// # Returns
+    /// `true` if the message is edited, `false` if the text is the same as the current one
+    /// # Errors
+    /// If the message can't be edited (except `MESSAGE_NOT_MODIFIED` errors)
+    pub async fn finish(&self, text: impl Into<Box<str>>) -> Result<bool, SessionErrorKind> {
+        if self.state.lock().await.decide(text.into(), Instant::now()) == Decision::NotModified {
+            return Ok(false);
+        }
+
+        self.flush().await
+    }
+
+    /// Edit the message with the pending text
+    /// # Returns
+    /// `true` if the message is edited, `false` if the edit is postponed because of `RetryAfter` error
+    async fn edit(&self, state: &mut State) -> Result<bool, SessionErrorKind> {
+        let Some(text) = state.pending.as_deref() else {
+            return Ok(false);
+        };
+
+        let method = EditMessageText::new(text)
+            .chat_id(self.chat_id.clone())
+            .message_id(self.message_id);
+
+        match self.bot.send(method).await {
+            Ok(_) => {}
+            Err(err) if err.is_message_not_modified() => {}
+            Err(SessionErrorKind::Telegram(TelegramErrorKind::RetryAfter {
+                retry_after, ..
+            })) => {
+                event!(
+                    Level::WARN,
+                    retry_after,
+                    "Flood limit is exceeded, edit of the live message is postponed",
+                );
+
+                state.retry_after(
+                    Instant::now(),
+                    Duration::from_secs(retry_after.try_into().unwrap_or_default()),
+                );
+
+                return Ok(false);
+            }
+            Err(err) => return Err(err),
+        }
+
+        state.edited(Instant::now(), self.min_interval);
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decide() {
+        let now = Instant::now();
+        let interval = Duration::from_secs(1);
+        let mut state = State::new(Some("0%".into()), now);
+
+        assert_eq!(state.decide("0%".into(), now), Decision::NotModified);
+        assert_eq!(state.decide("10%".into(), now), Decision::Edit);
+
+        state.edited(now, interval);
+        assert_eq!(state.sent.as_deref(), Some("10%"));
+        assert!(state.pending.is_none());
+
+        // Intermediate states are saved as pending until the interval is passed
+        assert_eq!(
+            state.decide("20%".into(), now),
+            Decision::Wait(now + interval)
+        );
+        assert_eq!(
+            state.decide("30%".into(), now),
+            Decision::Wait(now + interval)
+        );
+        assert_eq!(state.pending.as_deref(), Some("30%"));
+        assert_eq!(state.decide("30%".into(), now + interval), Decision::Edit);
+
+        // Text, which is the same as the sent one, drops the pending text
+        assert_eq!(state.decide("10%".into(), now), Decision::NotModified);
+        assert!(state.pending.is_none());
+
+        state.retry_after(now, Duration::from_secs(5));
+        assert_eq!(
+            state.decide("40%".into(), now + interval),
+            Decision::Wait(now + Duration::from_secs(5))
+        );
+    }
+}
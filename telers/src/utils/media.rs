@@ -0,0 +1,184 @@
+//! This module contains helpers for preparing media before sending it.
+//!
+//! [`thumbnail`] function generates a JPEG thumbnail, which satisfies Telegram requirements
+//! (width and height don't exceed [`THUMBNAIL_MAX_SIDE`], size is less than [`THUMBNAIL_MAX_BYTES`]),
+//! from an image in any supported format (JPEG, PNG, GIF, WebP, BMP).
+//! [`thumbnail_for`] function generates a thumbnail for local image files ([`InputFile::FS`] and [`InputFile::Buffered`]),
+//! for example, for images, which are sent as documents.
+//!
+//! Generated thumbnail is a new file, so it's attached to the request as a separate part of `multipart/form-data`
+//! and can be passed to `thumbnail` builder method of [`SendDocument`], [`SendVideo`], [`SendAnimation`] and other methods.
+//!
+//! # Notes
+//! Only still images are decoded, frames of videos and animations aren't extracted.
+//! To generate a thumbnail for a video or an animation, extract its frame (for example, with `ffmpeg`)
+//! and pass the frame bytes to [`thumbnail`].
+//!
+//! Image decoding and encoding is CPU-bound, so consider to generate thumbnails for big images in a blocking task.
+//!
+//! This module is available only with `media` feature.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use telers::{
+//!     methods::SendDocument,
+//!     types::InputFile,
+//!     utils::media::thumbnail_for,
+//!     Bot,
+//! };
+//!
+//! async fn send_document(bot: Bot) {
+//!     let document = InputFile::fs("cat.png");
+//!     let thumbnail = thumbnail_for(&document).await.unwrap().unwrap();
+//!
+//!     let _ = bot
+//!         .send(SendDocument::new(1, document).thumbnail(thumbnail))
+//!         .await;
+//! }
+//! ```
+//!
+//! [`SendVideo`]: crate::methods::SendVideo
+//! [`SendDocument`]: crate::methods::SendDocument
+//! [`SendAnimation`]: crate::methods::SendAnimation
+
+use crate::types::InputFile;
+
+use image::{codecs::jpeg::JpegEncoder, ImageError};
+use std::io;
+use thiserror;
+use tokio::io::AsyncReadExt as _;
+
+/// Max width and height of the thumbnail
+pub const THUMBNAIL_MAX_SIDE: u32 = 320;
+/// Max size of the thumbnail in bytes
+pub const THUMBNAIL_MAX_BYTES: usize = 200 * 1024;
+/// Filename of generated thumbnails
+pub const THUMBNAIL_FILE_NAME: &str = "thumbnail.jpg";
+
+/// Number of the first bytes of the file, which are enough to guess the image format
+const SIGNATURE_LEN: u64 = 16;
+
+/// JPEG qualities, which are tried in turn until the thumbnail fits in [`THUMBNAIL_MAX_BYTES`]
+const QUALITIES: [u8; 5] = [90, 75, 60, 45, 30];
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error(transparent)]
+    Image(#[from] ImageError),
+    #[error("Thumbnail is too large even with the lowest quality: {0} bytes")]
+    TooLarge(usize),
+}
+
+/// Generates a JPEG thumbnail from the image bytes.
+/// The image is resized with preserving the aspect ratio, so its width and height don't exceed [`THUMBNAIL_MAX_SIDE`].
+/// # Errors
+/// - If the image format isn't supported or the image is corrupted
+/// - If the thumbnail is larger than [`THUMBNAIL_MAX_BYTES`] even with the lowest quality
+pub fn thumbnail(bytes: &[u8]) -> Result<InputFile<'static>, Error> {
+    let image = image::load_from_memory(bytes)?
+        .thumbnail(THUMBNAIL_MAX_SIDE, THUMBNAIL_MAX_SIDE)
+        .into_rgb8();
+
+    let mut content = vec![];
+    for quality in QUALITIES {
+        content.clear();
+
+        JpegEncoder::new_with_quality(&mut content, quality).encode_image(&image)?;
+
+        if content.len() <= THUMBNAIL_MAX_BYTES {
+            return Ok(InputFile::buffered_with_name(content, THUMBNAIL_FILE_NAME));
+        }
+    }
+
+    Err(Error::TooLarge(content.len()))
+}
+
+/// Generates a JPEG thumbnail for the local image file.
+/// Check [`thumbnail`] for more information.
+/// # Returns
+/// `None` if the file isn't local ([`InputFile::Id`], [`InputFile::Url`] and [`InputFile::Stream`], which can be read only once)
+/// or isn't an image in a supported format (for example, a video), so only the first bytes of the file are read
+/// # Errors
+/// - If the file cannot be read
+/// - If the image is corrupted
+/// - If the thumbnail is larger than [`THUMBNAIL_MAX_BYTES`] even with the lowest quality
+pub async fn thumbnail_for(file: &InputFile<'_>) -> Result<Option<InputFile<'static>>, Error> {
+    match file {
+        InputFile::FS(file) => {
+            let mut signature = vec![];
+            tokio::fs::File::open(file.path())
+                .await?
+                .take(SIGNATURE_LEN)
+                .read_to_end(&mut signature)
+                .await?;
+
+            if image::guess_format(&signature).is_err() {
+                return Ok(None);
+            }
+
+            let bytes = tokio::fs::read(file.path()).await?;
+
+            thumbnail(&bytes).map(Some)
+        }
+        InputFile::Buffered(file) => {
+            if image::guess_format(file.bytes()).is_err() {
+                return Ok(None);
+            }
+
+            thumbnail(file.bytes()).map(Some)
+        }
+        InputFile::Id(_) | InputFile::Url(_) | InputFile::Stream(_) => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use image::{ImageFormat, ImageOutputFormat, RgbImage};
+    use std::io::Cursor;
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut content = vec![];
+
+        RgbImage::from_fn(width, height, |x, y| {
+            image::Rgb([(x % 256) as u8, (y % 256) as u8, ((x + y) % 256) as u8])
+        })
+        .write_to(&mut Cursor::new(&mut content), ImageOutputFormat::Png)
+        .unwrap();
+
+        content
+    }
+
+    #[tokio::test]
+    async fn test_thumbnail() {
+        let file = InputFile::buffered(png(1000, 500));
+
+        let Some(InputFile::Buffered(file)) = thumbnail_for(&file).await.unwrap() else {
+            panic!("Thumbnail should be generated as buffered file");
+        };
+
+        assert_eq!(file.file_name(), Some(THUMBNAIL_FILE_NAME));
+        assert!(file.bytes().len() <= THUMBNAIL_MAX_BYTES);
+
+        let image = image::load_from_memory_with_format(file.bytes(), ImageFormat::Jpeg).unwrap();
+
+        assert_eq!((image.width(), image.height()), (320, 160));
+
+        assert!(thumbnail_for(&InputFile::id("id")).await.unwrap().is_none());
+        // Videos aren't decoded
+        assert!(
+            thumbnail_for(&InputFile::buffered(b"\0\0\0\x18ftypmp42".to_vec()))
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(matches!(
+            super::thumbnail(b"not an image"),
+            Err(Error::Image(_))
+        ));
+    }
+}
@@ -0,0 +1,260 @@
+//! This module contains [`MediaGroupBuilder`], which accumulates media and builds [`SendMediaGroup`] method
+//! with validation of Telegram constraints:
+//! - Media group must include 2-10 items
+//! - Photos and videos can be grouped together in any order
+//! - Documents can be grouped only with documents and audios only with audios
+//! - Animations can't be sent in media groups
+//!
+//! Files, which are uploaded with `multipart/form-data` (see [`InputFile`]), are attached automatically with unique `attach://` names,
+//! so you don't need to manage attachments manually.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use telers::{types::InputFile, utils::media_group::MediaGroupBuilder};
+//!
+//! let method = MediaGroupBuilder::new()
+//!     .photo(InputFile::url("https://http.cat/images/200.jpg"))
+//!     .video(InputFile::fs("cat.mp4"))
+//!     .caption("Cats")
+//!     .build(1)
+//!     .unwrap();
+//!
+//! assert_eq!(method.media.len(), 2);
+//! ```
+
+use crate::{
+    methods::SendMediaGroup,
+    types::{
+        ChatIdKind, InputFile, InputMedia, InputMediaAudio, InputMediaDocument, InputMediaPhoto,
+        InputMediaVideo,
+    },
+};
+
+use thiserror;
+
+/// Min number of items in the media group
+pub const MIN_MEDIA_COUNT: usize = 2;
+/// Max number of items in the media group
+pub const MAX_MEDIA_COUNT: usize = 10;
+
+/// Kind of media in terms of grouping rules
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupKind {
+    /// Photos and videos
+    Visual,
+    Document,
+    Audio,
+    /// Animations, which can't be grouped
+    Animation,
+}
+
+impl From<&InputMedia<'_>> for GroupKind {
+    fn from(media: &InputMedia<'_>) -> Self {
+        match media {
+            InputMedia::Photo(_) | InputMedia::Video(_) => Self::Visual,
+            InputMedia::Document(_) => Self::Document,
+            InputMedia::Audio(_) => Self::Audio,
+            InputMedia::Animation(_) => Self::Animation,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    #[error("Media group must include at least {MIN_MEDIA_COUNT} items, got {0}")]
+    TooFew(usize),
+    #[error("Media group must include at most {MAX_MEDIA_COUNT} items, got {0}")]
+    TooMany(usize),
+    #[error("Animations can't be sent in media groups (item with index {index})")]
+    Animation { index: usize },
+    #[error("Media of kind {found:?} can't be grouped with media of kind {expected:?} (item with index {index})")]
+    MixedKinds {
+        index: usize,
+        expected: GroupKind,
+        found: GroupKind,
+    },
+}
+
+/// Builder of [`SendMediaGroup`] method with validation of Telegram constraints.
+/// Check [module docs](crate::utils::media_group) for more information.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MediaGroupBuilder<'a> {
+    media: Vec<InputMedia<'a>>,
+    caption: Option<String>,
+}
+
+impl<'a> MediaGroupBuilder<'a> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add any media to the group
+    #[must_use]
+    pub fn media(self, val: impl Into<InputMedia<'a>>) -> Self {
+        Self {
+            media: self.media.into_iter().chain(Some(val.into())).collect(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn photo(self, val: impl Into<InputFile<'a>>) -> Self {
+        self.media(InputMediaPhoto::new(val))
+    }
+
+    #[must_use]
+    pub fn video(self, val: impl Into<InputFile<'a>>) -> Self {
+        self.media(InputMediaVideo::new(val))
+    }
+
+    #[must_use]
+    pub fn document(self, val: impl Into<InputFile<'a>>) -> Self {
+        self.media(InputMediaDocument::new(val))
+    }
+
+    #[must_use]
+    pub fn audio(self, val: impl Into<InputFile<'a>>) -> Self {
+        self.media(InputMediaAudio::new(val))
+    }
+
+    /// Caption of the media group.
+    /// Telegram shows the caption of the first item as the caption of the album, so it's set to the first item.
+    #[must_use]
+    pub fn caption(self, val: impl Into<String>) -> Self {
+        Self {
+            caption: Some(val.into()),
+            ..self
+        }
+    }
+
+    /// Number of the accumulated media
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.media.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.media.is_empty()
+    }
+
+    /// Checks Telegram constraints of the media group
+    /// # Errors
+    /// - If the number of items isn't in range 2-10
+    /// - If the group contains animations
+    /// - If documents or audios are grouped with media of another kind
+    pub fn validate(&self) -> Result<(), Error> {
+        let mut expected = None;
+
+        for (index, media) in self.media.iter().enumerate() {
+            let found = GroupKind::from(media);
+
+            if found == GroupKind::Animation {
+                return Err(Error::Animation { index });
+            }
+
+            match expected {
+                None => expected = Some(found),
+                Some(expected) if expected != found => {
+                    return Err(Error::MixedKinds {
+                        index,
+                        expected,
+                        found,
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+
+        match self.media.len() {
+            len if len < MIN_MEDIA_COUNT => Err(Error::TooFew(len)),
+            len if len > MAX_MEDIA_COUNT => Err(Error::TooMany(len)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Validates the media group and builds [`SendMediaGroup`] method
+    /// # Errors
+    /// If the media group doesn't satisfy Telegram constraints, check [`MediaGroupBuilder::validate`]
+    pub fn build(self, chat_id: impl Into<ChatIdKind>) -> Result<SendMediaGroup<'a>, Error> {
+        self.validate()?;
+
+        let mut media = self.media;
+        if let Some(caption) = self.caption {
+            // `remove` doesn't panic, because we validated that the group isn't empty
+            let first = media.remove(0);
+
+            media.insert(
+                0,
+                match first {
+                    InputMedia::Animation(media) => media.caption(caption).into(),
+                    InputMedia::Document(media) => media.caption(caption).into(),
+                    InputMedia::Audio(media) => media.caption(caption).into(),
+                    InputMedia::Photo(media) => media.caption(caption).into(),
+                    InputMedia::Video(media) => media.caption(caption).into(),
+                },
+            );
+        }
+
+        Ok(SendMediaGroup::new(chat_id, media))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::InputMediaAnimation;
+
+    #[test]
+    fn test_validate() {
+        let builder = MediaGroupBuilder::new().photo(InputFile::id("1"));
+        assert_eq!(builder.validate(), Err(Error::TooFew(1)));
+
+        let builder = builder.video(InputFile::id("2"));
+        assert_eq!(builder.validate(), Ok(()));
+
+        let builder = builder.document(InputFile::id("3"));
+        assert_eq!(
+            builder.validate(),
+            Err(Error::MixedKinds {
+                index: 2,
+                expected: GroupKind::Visual,
+                found: GroupKind::Document,
+            })
+        );
+
+        let builder = MediaGroupBuilder::new()
+            .audio(InputFile::id("1"))
+            .media(InputMediaAnimation::new(InputFile::id("2")));
+        assert_eq!(builder.validate(), Err(Error::Animation { index: 1 }));
+
+        let builder = (0..11).fold(MediaGroupBuilder::new(), |builder, index| {
+            builder.document(InputFile::id(index.to_string()))
+        });
+        assert_eq!(builder.validate(), Err(Error::TooMany(11)));
+    }
+
+    #[test]
+    fn test_build() {
+        let method = MediaGroupBuilder::new()
+            .photo(InputFile::buffered(vec![1]))
+            .photo(InputFile::buffered(vec![2]))
+            .caption("caption")
+            .build(1)
+            .unwrap();
+
+        let InputMedia::Photo(ref first) = method.media[0] else {
+            panic!("First media should be a photo");
+        };
+        let InputMedia::Photo(ref second) = method.media[1] else {
+            panic!("Second media should be a photo");
+        };
+
+        assert_eq!(first.caption.as_deref(), Some("caption"));
+        assert_eq!(second.caption, None);
+        assert_ne!(first.media.str_to_file(), second.media.str_to_file());
+        assert!(first.media.str_to_file().starts_with("attach://"));
+    }
+}
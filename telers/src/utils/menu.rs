@@ -0,0 +1,534 @@
+//! This module contains [`Menu`], which allows to declare a tree of inline keyboard menus
+//! (buttons, submenus and actions) for settings-style UIs, instead of writing callback data, handlers and back buttons manually.
+//!
+//! Callback data of the buttons is generated from the identifiers of the submenus and actions,
+//! so the menu is stateless: the path to the current menu is stored in the callback data.
+//! Callback data has the format `{prefix}:n:{path}` for navigation and `{prefix}:a:{path}/{action}` for actions,
+//! where path consists of the submenu identifiers separated by `/`.
+//!
+//! [`Menu::router`] creates the router with the callback query handler, which:
+//! - renders the submenu by editing the message, when the submenu button is pressed,
+//! - renders the parent menu, when the back button is pressed (it's added to each submenu automatically),
+//! - calls the action and handles [`ActionReply`], when the action button is pressed,
+//! - answers the callback query.
+//!
+//! Send the root menu with [`Menu::message`] method.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use telers::{
+//!     client::Reqwest,
+//!     utils::menu::{ActionReply, Menu},
+//!     Router,
+//! };
+//!
+//! let menu = Menu::<Reqwest>::new("Settings")
+//!     .submenu(
+//!         "Notifications",
+//!         Menu::with_id("notifications", "Notifications settings")
+//!             .action("on", "Enable", |_bot, _query| async { Ok(ActionReply::Render) })
+//!             .action("off", "Disable", |_bot, _query| async { Ok(ActionReply::Render) }),
+//!     )
+//!     .row()
+//!     .url("Help", "https://example.com/help");
+//!
+//! let mut router = Router::<Reqwest>::new("main");
+//! router.include(menu.router("settings_menu").unwrap());
+//!
+//! let method = menu.message(1);
+//! assert_eq!(method.text, "Settings");
+//! ```
+
+use crate::{
+    client::{Bot, DefaultSession, Session},
+    errors::HandlerError,
+    event::{service::BoxFuture, telegram::HandlerResult, FlowControl},
+    filters::Text,
+    methods::{AnswerCallbackQuery, EditMessageText, SendMessage},
+    types::{CallbackQuery, ChatIdKind, InlineKeyboardButton, InlineKeyboardMarkup},
+    Router,
+};
+
+use std::{collections::HashSet, future::Future, sync::Arc};
+use thiserror;
+
+/// Default prefix of the callback data of the menu buttons
+pub const DEFAULT_PREFIX: &str = "menu";
+/// Default text of the back button
+pub const DEFAULT_BACK_TEXT: &str = "« Back";
+/// Max length of the callback data in bytes
+pub const MAX_CALLBACK_DATA_LEN: usize = 64;
+
+/// What to do after the action is called
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum ActionReply {
+    /// Render the menu, which contains the action, again
+    #[default]
+    Render,
+    /// Render the menu by the path (submenu identifiers separated by `/`, empty path is the root menu)
+    Navigate(Box<str>),
+    /// Don't change the message
+    Stay,
+}
+
+/// Error while validating the menu tree
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    #[error("Menu identifier `{0}` is empty or contains reserved `:` or `/` characters")]
+    InvalidId(Box<str>),
+    #[error("Menu identifier `{0}` is used more than once in the same menu")]
+    DuplicateId(Box<str>),
+    #[error("Callback data `{0}` is longer than {MAX_CALLBACK_DATA_LEN} bytes")]
+    CallbackDataTooLong(Box<str>),
+}
+
+type Action<Client> = Arc<
+    dyn Fn(Arc<Bot<Client>>, CallbackQuery) -> BoxFuture<Result<ActionReply, HandlerError>>
+        + Send
+        + Sync,
+>;
+
+enum ButtonKind<Client> {
+    Submenu(Menu<Client>),
+    Action {
+        id: Box<str>,
+        action: Action<Client>,
+    },
+    Url(Box<str>),
+}
+
+impl<Client> Clone for ButtonKind<Client> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Submenu(menu) => Self::Submenu(menu.clone()),
+            Self::Action { id, action } => Self::Action {
+                id: id.clone(),
+                action: Arc::clone(action),
+            },
+            Self::Url(url) => Self::Url(url.clone()),
+        }
+    }
+}
+
+struct Button<Client> {
+    text: Box<str>,
+    kind: ButtonKind<Client>,
+}
+
+impl<Client> Clone for Button<Client> {
+    fn clone(&self) -> Self {
+        Self {
+            text: self.text.clone(),
+            kind: self.kind.clone(),
+        }
+    }
+}
+
+/// Menu with text and buttons, which can be submenus, actions or URLs.
+/// Check the [module](self) documentation for more information.
+pub struct Menu<Client = DefaultSession> {
+    id: Box<str>,
+    text: Box<str>,
+    rows: Vec<Vec<Button<Client>>>,
+    prefix: Box<str>,
+    back_text: Box<str>,
+}
+
+impl<Client> Clone for Menu<Client> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id.clone(),
+            text: self.text.clone(),
+            rows: self.rows.clone(),
+            prefix: self.prefix.clone(),
+            back_text: self.back_text.clone(),
+        }
+    }
+}
+
+impl<Client> Menu<Client> {
+    /// Create the root menu
+    #[must_use]
+    pub fn new(text: impl Into<Box<str>>) -> Self {
+        Self::with_id("", text)
+    }
+
+    /// Create the submenu with the identifier, which is used in the callback data
+    #[must_use]
+    pub fn with_id(id: impl Into<Box<str>>, text: impl Into<Box<str>>) -> Self {
+        Self {
+            id: id.into(),
+            text: text.into(),
+            rows: vec![vec![]],
+            prefix: DEFAULT_PREFIX.into(),
+            back_text: DEFAULT_BACK_TEXT.into(),
+        }
+    }
+
+    /// Set prefix of the callback data. It's used only for the root menu.
+    /// Use different prefixes for different menus in the same bot.
+    #[must_use]
+    pub fn prefix(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            prefix: val.into(),
+            ..self
+        }
+    }
+
+    /// Set text of the back button. It's used only for the root menu.
+    #[must_use]
+    pub fn back_text(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            back_text: val.into(),
+            ..self
+        }
+    }
+
+    fn button(mut self, button: Button<Client>) -> Self {
+        // `unwrap` is safe, because the menu always has at least one row
+        self.rows.last_mut().unwrap().push(button);
+        self
+    }
+
+    /// Start a new row of buttons
+    #[must_use]
+    pub fn row(mut self) -> Self {
+        if self.rows.last().map_or(false, |row| !row.is_empty()) {
+            self.rows.push(vec![]);
+        }
+        self
+    }
+
+    /// Add the button, which opens the submenu, to the current row
+    #[must_use]
+    pub fn submenu(self, text: impl Into<Box<str>>, menu: Menu<Client>) -> Self {
+        self.button(Button {
+            text: text.into(),
+            kind: ButtonKind::Submenu(menu),
+        })
+    }
+
+    /// Add the button, which calls the action, to the current row
+    #[must_use]
+    pub fn action<F, Fut>(
+        self,
+        id: impl Into<Box<str>>,
+        text: impl Into<Box<str>>,
+        action: F,
+    ) -> Self
+    where
+        F: Fn(Arc<Bot<Client>>, CallbackQuery) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<ActionReply, HandlerError>> + Send + 'static,
+    {
+        self.button(Button {
+            text: text.into(),
+            kind: ButtonKind::Action {
+                id: id.into(),
+                action: Arc::new(move |bot, query| Box::pin(action(bot, query))),
+            },
+        })
+    }
+
+    /// Add the button, which opens the URL, to the current row
+    #[must_use]
+    pub fn url(self, text: impl Into<Box<str>>, url: impl Into<Box<str>>) -> Self {
+        self.button(Button {
+            text: text.into(),
+            kind: ButtonKind::Url(url.into()),
+        })
+    }
+
+    fn navigate_data(&self, path: &str) -> String {
+        format!("{}:n:{path}", self.prefix)
+    }
+
+    fn action_data(&self, path: &str, id: &str) -> String {
+        format!("{}:a:{}", self.prefix, join(path, id))
+    }
+
+    /// Find the submenu by the path
+    fn find(&self, path: &str) -> Option<&Self> {
+        if path.is_empty() {
+            return Some(self);
+        }
+
+        path.split('/').try_fold(self, |menu, id| {
+            menu.rows
+                .iter()
+                .flatten()
+                .find_map(|button| match &button.kind {
+                    ButtonKind::Submenu(submenu) if &*submenu.id == id => Some(submenu),
+                    _ => None,
+                })
+        })
+    }
+
+    /// Render text and keyboard of the submenu by the path (submenu identifiers separated by `/`, empty path is the root menu)
+    /// # Returns
+    /// `None` if the submenu isn't found
+    #[must_use]
+    pub fn render(&self, path: &str) -> Option<(&str, InlineKeyboardMarkup)> {
+        let menu = self.find(path)?;
+
+        let mut keyboard = menu
+            .rows
+            .iter()
+            .filter(|row| !row.is_empty())
+            .map(|row| {
+                row.iter()
+                    .map(|button| {
+                        let inline_button = InlineKeyboardButton::new(&*button.text);
+
+                        match &button.kind {
+                            ButtonKind::Submenu(submenu) => inline_button
+                                .callback_data(self.navigate_data(&join(path, &submenu.id))),
+                            ButtonKind::Action { id, .. } => {
+                                inline_button.callback_data(self.action_data(path, id))
+                            }
+                            ButtonKind::Url(url) => inline_button.url(&**url),
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        if !path.is_empty() {
+            keyboard.push(vec![InlineKeyboardButton::new(&*self.back_text)
+                .callback_data(self.navigate_data(parent(path)))]);
+        }
+
+        Some((&menu.text, keyboard.into()))
+    }
+
+    /// Create [`SendMessage`] with the root menu
+    #[must_use]
+    pub fn message(&self, chat_id: impl Into<ChatIdKind>) -> SendMessage {
+        // `unwrap` is safe, because the root menu always exists
+        let (text, keyboard) = self.render("").unwrap();
+
+        SendMessage::new(chat_id, text).reply_markup(keyboard)
+    }
+
+    /// Validate identifiers and callback data of the menu tree
+    /// # Errors
+    /// - If identifier of the submenu or action is empty or contains reserved characters
+    /// - If identifier is used more than once in the same menu
+    /// - If callback data is longer than [`MAX_CALLBACK_DATA_LEN`] bytes
+    pub fn validate(&self) -> Result<(), Error> {
+        self.validate_menu(self, "")
+    }
+
+    fn validate_menu(&self, menu: &Self, path: &str) -> Result<(), Error> {
+        let mut ids = HashSet::new();
+
+        for button in menu.rows.iter().flatten() {
+            let (id, data) = match &button.kind {
+                ButtonKind::Submenu(submenu) => {
+                    (&submenu.id, self.navigate_data(&join(path, &submenu.id)))
+                }
+                ButtonKind::Action { id, .. } => (id, self.action_data(path, id)),
+                ButtonKind::Url(_) => continue,
+            };
+
+            if id.is_empty() || id.contains([':', '/']) {
+                return Err(Error::InvalidId(id.clone()));
+            }
+            if !ids.insert(id) {
+                return Err(Error::DuplicateId(id.clone()));
+            }
+            if data.len() > MAX_CALLBACK_DATA_LEN {
+                return Err(Error::CallbackDataTooLong(data.into()));
+            }
+
+            if let ButtonKind::Submenu(submenu) = &button.kind {
+                self.validate_menu(submenu, &join(path, &submenu.id))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<Client> Menu<Client>
+where
+    Client: Session + 'static,
+{
+    /// Create the router with the callback query handler of the menu
+    /// # Errors
+    /// If the menu tree isn't valid, check [`Menu::validate`] for more information
+    pub fn router(&self, router_name: &'static str) -> Result<Router<Client>, Error> {
+        self.validate()?;
+
+        let prefix = format!("{}:", self.prefix);
+        let menu = Arc::new(self.clone());
+
+        let mut router = Router::new(router_name);
+        router
+            .callback_query
+            .register(move |bot: Arc<Bot<Client>>, query: CallbackQuery| {
+                let menu = Arc::clone(&menu);
+
+                async move { menu.handle(bot, query).await }
+            })
+            .filter(Text::starts_with_single(prefix));
+
+        Ok(router)
+    }
+
+    async fn handle(&self, bot: Arc<Bot<Client>>, query: CallbackQuery) -> HandlerResult {
+        let Some((kind, path)) = query
+            .data
+            .as_deref()
+            .and_then(|data| data.strip_prefix(&*self.prefix))
+            .and_then(|data| data.strip_prefix(':'))
+            .and_then(|data| data.split_once(':'))
+        else {
+            return Ok(FlowControl::SkipHandler);
+        };
+
+        let path = match kind {
+            "n" => Some(path.to_owned()),
+            "a" => {
+                let (menu_path, id) = path.rsplit_once('/').unwrap_or(("", path));
+
+                let Some(action) = self.find(menu_path).and_then(|menu| {
+                    menu.rows
+                        .iter()
+                        .flatten()
+                        .find_map(|button| match &button.kind {
+                            ButtonKind::Action {
+                                id: action_id,
+                                action,
+                            } if &**action_id == id => Some(Arc::clone(action)),
+                            _ => None,
+                        })
+                }) else {
+                    return Ok(FlowControl::SkipHandler);
+                };
+
+                match action(Arc::clone(&bot), query.clone()).await? {
+                    ActionReply::Render => Some(menu_path.to_owned()),
+                    ActionReply::Navigate(path) => Some(path.into()),
+                    ActionReply::Stay => None,
+                }
+            }
+            _ => return Ok(FlowControl::SkipHandler),
+        };
+
+        if let Some((text, keyboard)) = path.as_deref().and_then(|path| self.render(path)) {
+            let method = EditMessageText::new(text).reply_markup(keyboard);
+            let method = match (
+                query.inline_message_id.as_deref(),
+                query.chat_id(),
+                query.message_id(),
+            ) {
+                (Some(inline_message_id), _, _) => method.inline_message_id(inline_message_id),
+                (None, Some(chat_id), Some(message_id)) => {
+                    method.chat_id(chat_id).message_id(message_id)
+                }
+                _ => return Ok(FlowControl::SkipHandler),
+            };
+
+            match bot.send(method).await {
+                Err(err) if !err.is_message_not_modified() => return Err(err.into()),
+                _ => {}
+            }
+        }
+
+        bot.send(AnswerCallbackQuery::new(&*query.id)).await?;
+
+        Ok(FlowControl::Continue)
+    }
+}
+
+fn join(path: &str, id: &str) -> String {
+    if path.is_empty() {
+        id.to_owned()
+    } else {
+        format!("{path}/{id}")
+    }
+}
+
+fn parent(path: &str) -> &str {
+    path.rsplit_once('/').map_or("", |(parent, _)| parent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Reqwest;
+
+    fn menu() -> Menu {
+        Menu::new("Settings")
+            .submenu(
+                "Notifications",
+                Menu::with_id("notifications", "Notifications settings")
+                    .action("on", "Enable", |_, _| async { Ok(ActionReply::Render) })
+                    .submenu("Sounds", Menu::with_id("sounds", "Sounds settings")),
+            )
+            .row()
+            .url("Help", "https://example.com")
+    }
+
+    #[test]
+    fn test_render() {
+        let menu = menu();
+
+        let (text, keyboard) = menu.render("").unwrap();
+        assert_eq!(text, "Settings");
+        assert_eq!(keyboard.inline_keyboard.len(), 2);
+        assert_eq!(
+            keyboard.inline_keyboard[0][0].callback_data.as_deref(),
+            Some("menu:n:notifications")
+        );
+        assert_eq!(
+            keyboard.inline_keyboard[1][0].url.as_deref(),
+            Some("https://example.com")
+        );
+
+        let (text, keyboard) = menu.render("notifications").unwrap();
+        assert_eq!(text, "Notifications settings");
+        assert_eq!(
+            keyboard
+                .buttons()
+                .map(|button| button.callback_data.as_deref().unwrap())
+                .collect::<Vec<_>>(),
+            [
+                "menu:a:notifications/on",
+                "menu:n:notifications/sounds",
+                "menu:n:"
+            ]
+        );
+
+        let (_, keyboard) = menu.render("notifications/sounds").unwrap();
+        assert_eq!(
+            keyboard
+                .button("menu:n:notifications")
+                .map(|button| &*button.text),
+            Some(DEFAULT_BACK_TEXT)
+        );
+
+        assert!(menu.render("unknown").is_none());
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(menu().validate(), Ok(()));
+
+        let menu = Menu::<Reqwest>::new("Settings")
+            .submenu("A", Menu::with_id("a", "A"))
+            .submenu("A", Menu::with_id("a", "A"));
+        assert_eq!(menu.validate(), Err(Error::DuplicateId("a".into())));
+
+        let menu = Menu::<Reqwest>::new("Settings").submenu("A", Menu::with_id("a:b", "A"));
+        assert_eq!(menu.validate(), Err(Error::InvalidId("a:b".into())));
+
+        let menu =
+            Menu::<Reqwest>::new("Settings").submenu("A", Menu::with_id("a".repeat(60), "A"));
+        assert!(matches!(
+            menu.validate(),
+            Err(Error::CallbackDataTooLong(_))
+        ));
+    }
+}
@@ -0,0 +1,297 @@
+//! This module contains [`QuizBuilder`], which accumulates options of the quiz and builds [`SendPoll`] method
+//! with validation of Telegram constraints:
+//! - Quiz must include 2-10 options and exactly one of them must be correct
+//! - Question must be 1-300 characters and each option must be 1-100 characters
+//! - Explanation must be 0-200 characters
+//!
+//! The correct option is marked when it's added with [`QuizBuilder::correct_option`],
+//! so you don't need to calculate its index manually.
+//!
+//! To aggregate answers of the sent quizzes use [`PollTracker`] middleware.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use telers::utils::quiz::QuizBuilder;
+//!
+//! let method = QuizBuilder::new("2 + 2 = ?")
+//!     .option("3")
+//!     .correct_option("4")
+//!     .option("5")
+//!     .explanation("Basic arithmetic")
+//!     .build(1)
+//!     .unwrap();
+//!
+//! assert_eq!(method.correct_option_id, Some(1));
+//! ```
+//!
+//! [`PollTracker`]: crate::middlewares::outer::PollTracker
+
+use crate::{
+    enums::PollType,
+    methods::SendPoll,
+    types::{ChatIdKind, MessageEntity},
+};
+
+use thiserror;
+
+/// Min number of options in the poll
+pub const MIN_OPTION_COUNT: usize = 2;
+/// Max number of options in the poll
+pub const MAX_OPTION_COUNT: usize = 10;
+/// Max length of the poll question
+pub const MAX_QUESTION_LEN: usize = 300;
+/// Max length of the poll option
+pub const MAX_OPTION_LEN: usize = 100;
+/// Max length of the quiz explanation
+pub const MAX_EXPLANATION_LEN: usize = 200;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    #[error("Quiz must include at least {MIN_OPTION_COUNT} options, got {0}")]
+    TooFew(usize),
+    #[error("Quiz must include at most {MAX_OPTION_COUNT} options, got {0}")]
+    TooMany(usize),
+    #[error("Question must be 1-{MAX_QUESTION_LEN} characters, got {0}")]
+    QuestionLength(usize),
+    #[error("Option must be 1-{MAX_OPTION_LEN} characters, got {len} (option with index {index})")]
+    OptionLength { index: usize, len: usize },
+    #[error("Explanation must be at most {MAX_EXPLANATION_LEN} characters, got {0}")]
+    ExplanationLength(usize),
+    #[error("Quiz must have the correct option")]
+    NoCorrectOption,
+    #[error("Quiz must have exactly one correct option (options with indexes {first} and {second} are correct)")]
+    SeveralCorrectOptions { first: usize, second: usize },
+}
+
+/// Builder of the quiz.
+/// Check the [module](self) documentation for more information.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct QuizBuilder {
+    question: String,
+    options: Vec<String>,
+    correct_option_ids: Vec<usize>,
+    explanation: Option<String>,
+    explanation_parse_mode: Option<String>,
+    explanation_entities: Option<Vec<MessageEntity>>,
+    is_anonymous: Option<bool>,
+    open_period: Option<i64>,
+}
+
+impl QuizBuilder {
+    #[must_use]
+    pub fn new(question: impl Into<String>) -> Self {
+        Self {
+            question: question.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Add incorrect option
+    #[must_use]
+    pub fn option(self, val: impl Into<String>) -> Self {
+        Self {
+            options: self.options.into_iter().chain(Some(val.into())).collect(),
+            ..self
+        }
+    }
+
+    /// Add incorrect options
+    #[must_use]
+    pub fn options<T, I>(self, val: I) -> Self
+    where
+        T: Into<String>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            options: self
+                .options
+                .into_iter()
+                .chain(val.into_iter().map(Into::into))
+                .collect(),
+            ..self
+        }
+    }
+
+    /// Add correct option
+    #[must_use]
+    pub fn correct_option(self, val: impl Into<String>) -> Self {
+        let index = self.options.len();
+
+        let mut builder = self.option(val);
+        builder.correct_option_ids.push(index);
+        builder
+    }
+
+    /// Text that is shown when a user chooses an incorrect answer or taps on the lamp icon, 0-200 characters
+    #[must_use]
+    pub fn explanation(self, val: impl Into<String>) -> Self {
+        Self {
+            explanation: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn explanation_parse_mode(self, val: impl Into<String>) -> Self {
+        Self {
+            explanation_parse_mode: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn explanation_entities(self, val: impl IntoIterator<Item = MessageEntity>) -> Self {
+        Self {
+            explanation_entities: Some(
+                self.explanation_entities
+                    .unwrap_or_default()
+                    .into_iter()
+                    .chain(val)
+                    .collect(),
+            ),
+            ..self
+        }
+    }
+
+    /// Pass `false` to receive answers of the users with `poll_answer` updates
+    #[must_use]
+    pub fn is_anonymous(self, val: bool) -> Self {
+        Self {
+            is_anonymous: Some(val),
+            ..self
+        }
+    }
+
+    /// Amount of time in seconds the quiz will be active after creation, 5-600
+    #[must_use]
+    pub fn open_period(self, val: i64) -> Self {
+        Self {
+            open_period: Some(val),
+            ..self
+        }
+    }
+
+    /// Check the quiz against Telegram constraints
+    /// # Returns
+    /// Index of the correct option
+    /// # Errors
+    /// If the quiz doesn't satisfy the constraints, see [module](self) documentation
+    pub fn validate(&self) -> Result<usize, Error> {
+        let question_len = self.question.chars().count();
+        if question_len == 0 || question_len > MAX_QUESTION_LEN {
+            return Err(Error::QuestionLength(question_len));
+        }
+
+        let count = self.options.len();
+        if count < MIN_OPTION_COUNT {
+            return Err(Error::TooFew(count));
+        }
+        if count > MAX_OPTION_COUNT {
+            return Err(Error::TooMany(count));
+        }
+
+        for (index, option) in self.options.iter().enumerate() {
+            let len = option.chars().count();
+            if len == 0 || len > MAX_OPTION_LEN {
+                return Err(Error::OptionLength { index, len });
+            }
+        }
+
+        if let Some(explanation) = &self.explanation {
+            let len = explanation.chars().count();
+            if len > MAX_EXPLANATION_LEN {
+                return Err(Error::ExplanationLength(len));
+            }
+        }
+
+        match self.correct_option_ids[..] {
+            [] => Err(Error::NoCorrectOption),
+            [index] => Ok(index),
+            [first, second, ..] => Err(Error::SeveralCorrectOptions { first, second }),
+        }
+    }
+
+    /// Build [`SendPoll`] method with the quiz
+    /// # Errors
+    /// If the quiz doesn't satisfy the constraints, see [module](self) documentation
+    pub fn build(self, chat_id: impl Into<ChatIdKind>) -> Result<SendPoll, Error> {
+        let correct_option_id = self.validate()?;
+
+        Ok(SendPoll::new(chat_id, self.question, self.options)
+            .poll_type(PollType::Quiz)
+            .correct_option_id(correct_option_id.try_into().unwrap())
+            .explanation_option(self.explanation)
+            .explanation_parse_mode_option(self.explanation_parse_mode)
+            .explanation_entities_option(self.explanation_entities)
+            .is_anonymous_option(self.is_anonymous)
+            .open_period_option(self.open_period))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        let method = QuizBuilder::new("question")
+            .options(["a", "b"])
+            .correct_option("c")
+            .explanation("explanation")
+            .is_anonymous(false)
+            .build(1)
+            .unwrap();
+
+        assert_eq!(method.poll_type.as_deref(), Some("quiz"));
+        assert_eq!(method.options, ["a", "b", "c"]);
+        assert_eq!(method.correct_option_id, Some(2));
+        assert_eq!(method.explanation.as_deref(), Some("explanation"));
+        assert_eq!(method.is_anonymous, Some(false));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(
+            QuizBuilder::new("question").correct_option("a").validate(),
+            Err(Error::TooFew(1))
+        );
+        assert_eq!(
+            QuizBuilder::new("question").options(["a", "b"]).validate(),
+            Err(Error::NoCorrectOption)
+        );
+        assert_eq!(
+            QuizBuilder::new("question")
+                .correct_option("a")
+                .option("b")
+                .correct_option("c")
+                .validate(),
+            Err(Error::SeveralCorrectOptions {
+                first: 0,
+                second: 2
+            })
+        );
+        assert_eq!(
+            QuizBuilder::new("")
+                .option("a")
+                .correct_option("b")
+                .validate(),
+            Err(Error::QuestionLength(0))
+        );
+        assert_eq!(
+            QuizBuilder::new("question")
+                .option("")
+                .correct_option("b")
+                .validate(),
+            Err(Error::OptionLength { index: 0, len: 0 })
+        );
+        assert_eq!(
+            QuizBuilder::new("question")
+                .option("a")
+                .correct_option("b")
+                .explanation("a".repeat(201))
+                .validate(),
+            Err(Error::ExplanationLength(201))
+        );
+    }
+}
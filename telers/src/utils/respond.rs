@@ -0,0 +1,314 @@
+//! This module contains [`respond_in_kind`] function, which creates [`Respond`] method to echo the message back
+//! with the same content kind (text, photo, video, sticker, etc.) as a reply to it.
+//!
+//! Unlike [`CopyMessage`], the method is built from the `Send*` method of the content kind,
+//! so it can be changed before sending, for example, to add or replace the caption or to add the keyboard.
+//! Media are sent by their file ids, so the files aren't downloaded and uploaded again.
+//!
+//! The response is sent to the same chat and the same forum topic (if the chat is a forum)
+//! and replies to the message with [`Message::reply_parameters`].
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! async fn handler(bot: Bot, message: Message) -> HandlerResult {
+//!     if let Some(respond) = respond_in_kind(&message) {
+//!         bot.send(respond.caption("Your file is saved")).await?;
+//!     }
+//!
+//!     Ok(FlowControl::Continue)
+//! }
+//! ```
+//!
+//! [`CopyMessage`]: crate::methods::CopyMessage
+
+use crate::{
+    client::{Bot, Defaults},
+    methods::{
+        Request, SendAnimation, SendAudio, SendDocument, SendMessage, SendPhoto, SendSticker,
+        SendVideo, SendVideoNote, SendVoice, TelegramMethod,
+    },
+    types::{InputFile, Message, ReplyMarkup, ReplyParameters},
+};
+
+use serde::Serialize;
+
+/// Calls the expression for the inner method of each variant
+macro_rules! each_method {
+    ($respond:expr, $method:ident => $expr:expr) => {
+        match $respond {
+            Respond::Message($method) => $expr,
+            Respond::Animation($method) => $expr,
+            Respond::Audio($method) => $expr,
+            Respond::Document($method) => $expr,
+            Respond::Photo($method) => $expr,
+            Respond::Sticker($method) => $expr,
+            Respond::Video($method) => $expr,
+            Respond::VideoNote($method) => $expr,
+            Respond::Voice($method) => $expr,
+        }
+    };
+}
+
+/// Method to send the content of the same kind as the message.
+/// Check the [module](self) documentation for more information.
+/// # Returns
+/// On success, the sent [`Message`] is returned
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Respond<'a> {
+    Message(SendMessage),
+    Animation(SendAnimation<'a>),
+    Audio(SendAudio<'a>),
+    Document(SendDocument<'a>),
+    Photo(SendPhoto<'a>),
+    Sticker(SendSticker<'a>),
+    Video(SendVideo<'a>),
+    VideoNote(SendVideoNote<'a>),
+    Voice(SendVoice<'a>),
+}
+
+impl<'a> Respond<'a> {
+    /// Replace the caption of the media and remove its entities.
+    /// For text messages the text is replaced, stickers and video notes don't have captions, so they are left as is.
+    #[must_use]
+    pub fn caption(self, val: impl Into<String>) -> Self {
+        let val = val.into();
+
+        match self {
+            Self::Message(method) => {
+                Self::Message(method.text(val).entities_option(None::<[_; 0]>))
+            }
+            Self::Animation(method) => {
+                Self::Animation(method.caption(val).caption_entities_option(None::<[_; 0]>))
+            }
+            Self::Audio(method) => {
+                Self::Audio(method.caption(val).caption_entities_option(None::<[_; 0]>))
+            }
+            Self::Document(method) => {
+                Self::Document(method.caption(val).caption_entities_option(None::<[_; 0]>))
+            }
+            Self::Photo(method) => {
+                Self::Photo(method.caption(val).caption_entities_option(None::<[_; 0]>))
+            }
+            Self::Video(method) => {
+                Self::Video(method.caption(val).caption_entities_option(None::<[_; 0]>))
+            }
+            Self::Voice(method) => {
+                Self::Voice(method.caption(val).caption_entities_option(None::<[_; 0]>))
+            }
+            Self::Sticker(_) | Self::VideoNote(_) => self,
+        }
+    }
+
+    #[must_use]
+    pub fn reply_parameters_option(self, val: Option<ReplyParameters>) -> Self {
+        match self {
+            Self::Message(method) => Self::Message(method.reply_parameters_option(val)),
+            Self::Animation(method) => Self::Animation(method.reply_parameters_option(val)),
+            Self::Audio(method) => Self::Audio(method.reply_parameters_option(val)),
+            Self::Document(method) => Self::Document(method.reply_parameters_option(val)),
+            Self::Photo(method) => Self::Photo(method.reply_parameters_option(val)),
+            Self::Sticker(method) => Self::Sticker(method.reply_parameters_option(val)),
+            Self::Video(method) => Self::Video(method.reply_parameters_option(val)),
+            Self::VideoNote(method) => Self::VideoNote(method.reply_parameters_option(val)),
+            Self::Voice(method) => Self::Voice(method.reply_parameters_option(val)),
+        }
+    }
+
+    #[must_use]
+    pub fn reply_markup(self, val: impl Into<ReplyMarkup>) -> Self {
+        let val = val.into();
+
+        match self {
+            Self::Message(method) => Self::Message(method.reply_markup(val)),
+            Self::Animation(method) => Self::Animation(method.reply_markup(val)),
+            Self::Audio(method) => Self::Audio(method.reply_markup(val)),
+            Self::Document(method) => Self::Document(method.reply_markup(val)),
+            Self::Photo(method) => Self::Photo(method.reply_markup(val)),
+            Self::Sticker(method) => Self::Sticker(method.reply_markup(val)),
+            Self::Video(method) => Self::Video(method.reply_markup(val)),
+            Self::VideoNote(method) => Self::VideoNote(method.reply_markup(val)),
+            Self::Voice(method) => Self::Voice(method.reply_markup(val)),
+        }
+    }
+}
+
+impl<'a> TelegramMethod for Respond<'a> {
+    type Method = Self;
+    type Return = Message;
+
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<'_, Self::Method> {
+        let (method_name, files) = each_method!(self, method => {
+            let request = method.build_request(bot);
+            (request.method_name, request.files)
+        });
+
+        Request::new(method_name, self, files)
+    }
+
+    fn with_defaults(&self, defaults: &Defaults) -> Option<Self> {
+        match self {
+            Self::Message(method) => method.with_defaults(defaults).map(Self::Message),
+            Self::Animation(method) => method.with_defaults(defaults).map(Self::Animation),
+            Self::Audio(method) => method.with_defaults(defaults).map(Self::Audio),
+            Self::Document(method) => method.with_defaults(defaults).map(Self::Document),
+            Self::Photo(method) => method.with_defaults(defaults).map(Self::Photo),
+            Self::Sticker(method) => method.with_defaults(defaults).map(Self::Sticker),
+            Self::Video(method) => method.with_defaults(defaults).map(Self::Video),
+            Self::VideoNote(method) => method.with_defaults(defaults).map(Self::VideoNote),
+            Self::Voice(method) => method.with_defaults(defaults).map(Self::Voice),
+        }
+    }
+}
+
+impl<'a> AsRef<Respond<'a>> for Respond<'a> {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+/// Creates [`Respond`] method to send the content of the same kind as the message to the same chat and topic as a reply to it.
+/// Caption, caption entities and spoiler of the media are copied, use [`Respond::caption`] to replace the caption.
+/// # Returns
+/// `None` if the message content can't be sent by `Send*` methods by its file id, for example, polls and service messages
+#[must_use]
+pub fn respond_in_kind(message: &Message) -> Option<Respond<'static>> {
+    let chat_id = message.chat().id();
+    let caption = message.caption().map(ToOwned::to_owned);
+    let entities = message.entities().map(<[_]>::to_vec);
+
+    let respond = match message {
+        Message::Text(text) => Respond::Message(
+            SendMessage::new(chat_id, text.text.as_ref()).entities_option(entities),
+        ),
+        Message::Animation(animation) => Respond::Animation(
+            SendAnimation::new(chat_id, file(&animation.animation.file_id))
+                .caption_option(caption)
+                .caption_entities_option(entities)
+                .has_spoiler_option(animation.has_media_spoiler),
+        ),
+        Message::Audio(audio) => Respond::Audio(
+            SendAudio::new(chat_id, file(&audio.audio.file_id))
+                .caption_option(caption)
+                .caption_entities_option(entities),
+        ),
+        Message::Document(document) => Respond::Document(
+            SendDocument::new(chat_id, file(&document.document.file_id))
+                .caption_option(caption)
+                .caption_entities_option(entities),
+        ),
+        Message::Photo(photo) => Respond::Photo(
+            SendPhoto::new(chat_id, file(&message.largest_photo()?.file_id))
+                .caption_option(caption)
+                .caption_entities_option(entities)
+                .has_spoiler_option(photo.has_media_spoiler),
+        ),
+        Message::Sticker(sticker) => {
+            Respond::Sticker(SendSticker::new(chat_id, file(&sticker.sticker.file_id)))
+        }
+        Message::Video(video) => Respond::Video(
+            SendVideo::new(chat_id, file(&video.video.file_id))
+                .caption_option(caption)
+                .caption_entities_option(entities)
+                .has_spoiler_option(video.has_media_spoiler),
+        ),
+        Message::VideoNote(video_note) => Respond::VideoNote(SendVideoNote::new(
+            chat_id,
+            file(&video_note.video_note.file_id),
+        )),
+        Message::Voice(voice) => Respond::Voice(
+            SendVoice::new(chat_id, file(&voice.voice.file_id))
+                .caption_option(caption)
+                .caption_entities_option(entities),
+        ),
+        _ => return None,
+    };
+
+    let thread_id = if message.chat().is_forum() {
+        message.thread_id()
+    } else {
+        None
+    };
+
+    Some(
+        each_method!(respond, method => Respond::from(method.message_thread_id_option(thread_id)))
+            .reply_parameters_option(Some(message.reply_parameters())),
+    )
+}
+
+fn file(file_id: &str) -> InputFile<'static> {
+    InputFile::id(file_id.to_owned())
+}
+
+macro_rules! impl_from_method {
+    ($variant:ident, $method:ty) => {
+        impl<'a> From<$method> for Respond<'a> {
+            fn from(method: $method) -> Self {
+                Self::$variant(method)
+            }
+        }
+    };
+}
+
+impl_from_method!(Message, SendMessage);
+impl_from_method!(Animation, SendAnimation<'a>);
+impl_from_method!(Audio, SendAudio<'a>);
+impl_from_method!(Document, SendDocument<'a>);
+impl_from_method!(Photo, SendPhoto<'a>);
+impl_from_method!(Sticker, SendSticker<'a>);
+impl_from_method!(Video, SendVideo<'a>);
+impl_from_method!(VideoNote, SendVideoNote<'a>);
+impl_from_method!(Voice, SendVoice<'a>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_respond_in_kind() {
+        let message: Message = serde_json::from_value(serde_json::json!({
+            "message_id": 2,
+            "message_thread_id": 3,
+            "date": 0,
+            "chat": {"id": -100, "type": "supergroup", "title": "test", "is_forum": true},
+            "photo": [
+                {"file_id": "small", "file_unique_id": "small", "width": 1, "height": 1},
+                {"file_id": "large", "file_unique_id": "large", "width": 10, "height": 10}
+            ],
+            "caption": "cat"
+        }))
+        .unwrap();
+
+        let Some(Respond::Photo(method)) =
+            respond_in_kind(&message).map(|respond| respond.caption("dog"))
+        else {
+            panic!("Expected photo");
+        };
+        assert_eq!(method.chat_id, (-100).into());
+        assert_eq!(method.message_thread_id, Some(3));
+        assert_eq!(method.photo, InputFile::id("large"));
+        assert_eq!(method.caption.as_deref(), Some("dog"));
+        assert_eq!(method.reply_parameters, Some(message.reply_parameters()));
+
+        let message: Message = serde_json::from_value(serde_json::json!({
+            "message_id": 2,
+            "date": 0,
+            "chat": {"id": 1, "type": "private", "first_name": "test"},
+            "text": "hello world",
+            "entities": [{"type": "bold", "offset": 6, "length": 5}]
+        }))
+        .unwrap();
+
+        let Some(Respond::Message(method)) = respond_in_kind(&message) else {
+            panic!("Expected text");
+        };
+        assert_eq!(method.message_thread_id, None);
+        assert_eq!(method.text, "hello world");
+
+        let reply_parameters = message.reply_parameters_with_quote("world").unwrap();
+        assert_eq!(reply_parameters.quote_position, Some(6));
+        assert_eq!(reply_parameters.quote_entities.unwrap()[0].offset, 0);
+        assert!(message.reply_parameters_with_quote("other").is_none());
+    }
+}
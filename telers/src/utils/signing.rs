@@ -0,0 +1,225 @@
+//! This module contains [`Signer`], which signs payloads of callback data and deep links (start parameters) with HMAC-SHA256
+//! and verifies them, so users can't tamper with ids embedded in buttons and links.
+//!
+//! Signature is truncated to [`DEFAULT_SIGNATURE_LEN`] bytes (can be changed by [`Signer::signature_len`]),
+//! encoded with URL-safe base64 without padding and appended to the payload without a separator,
+//! so signed payloads contain only characters allowed in start parameters (`A-Z`, `a-z`, `0-9`, `_` and `-`),
+//! if the payload itself contains only them.
+//! Keep in mind that callback data and start parameters are limited to 64 bytes, including the signature.
+//!
+//! The key is derived from the bot token by [`Signer::from_token`] or passed as is by [`Signer::new`].
+//! Payload can be also bound to the user with [`Signer::sign_for`], so the signed payload is valid only for this user,
+//! for example, to prevent using buttons, which are forwarded from another chat.
+//!
+//! The payload isn't encrypted, so don't put secrets in it.
+//!
+//! This module is available only with `signing` feature.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use telers::utils::signing::{Error, Signer};
+//!
+//! let signer = Signer::from_token("123:token");
+//!
+//! let data = signer.sign("delete:42");
+//! assert_eq!(signer.verify(&data).unwrap(), "delete:42");
+//!
+//! let tampered = data.replacen("42", "43", 1);
+//! assert!(matches!(signer.verify(&tampered), Err(Error::InvalidSignature)));
+//! ```
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use ring::hmac;
+use std::fmt::{self, Debug, Formatter};
+use subtle::ConstantTimeEq as _;
+
+/// Default length of the signature in bytes, which is 11 characters after encoding
+pub const DEFAULT_SIGNATURE_LEN: usize = 8;
+/// Max length of the signature in bytes, which is length of HMAC-SHA256 tag
+pub const MAX_SIGNATURE_LEN: usize = 32;
+
+/// Key to derive the signing key from the bot token, so the key isn't the same as the token
+const TOKEN_CONTEXT: &[u8] = b"telers-signing";
+/// Tag of the signature, which isn't bound to the user
+const UNSCOPED_TAG: u8 = 0;
+/// Tag of the signature, which is bound to the user, it's followed by the user id
+const SCOPED_TAG: u8 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    #[error("Signed payload is too short to contain the signature")]
+    TooShort,
+    #[error("Signature of the payload is invalid")]
+    InvalidSignature,
+}
+
+/// Signer of payloads of callback data and deep links.
+/// Check the [module](self) documentation for more information.
+#[derive(Clone)]
+pub struct Signer {
+    key: hmac::Key,
+    signature_len: usize,
+}
+
+impl Signer {
+    /// Creates a new signer with the key
+    #[must_use]
+    pub fn new(key: impl AsRef<[u8]>) -> Self {
+        Self {
+            key: hmac::Key::new(hmac::HMAC_SHA256, key.as_ref()),
+            signature_len: DEFAULT_SIGNATURE_LEN,
+        }
+    }
+
+    /// Creates a new signer with the key, which is derived from the bot token.
+    /// Payloads signed by the bot can't be verified by bots with other tokens.
+    #[must_use]
+    pub fn from_token(token: &str) -> Self {
+        let context = hmac::Key::new(hmac::HMAC_SHA256, TOKEN_CONTEXT);
+
+        Self::new(hmac::sign(&context, token.as_bytes()))
+    }
+
+    /// Set length of the signature in bytes.
+    /// Shorter signature leaves more space for the payload, but is easier to guess.
+    /// Length is clamped to `1..=`[`MAX_SIGNATURE_LEN`].
+    #[must_use]
+    pub fn signature_len(self, val: usize) -> Self {
+        Self {
+            signature_len: val.clamp(1, MAX_SIGNATURE_LEN),
+            ..self
+        }
+    }
+
+    /// Gets length of the encoded signature, which is appended to the payload
+    #[must_use]
+    pub fn encoded_signature_len(&self) -> usize {
+        (self.signature_len * 4 + 2) / 3
+    }
+
+    fn signature(&self, user_id: Option<i64>, payload: &str) -> String {
+        let mut context = hmac::Context::with_key(&self.key);
+        // Modes are separated by the tag, so the unscoped signature can't be used as the signature for the user
+        match user_id {
+            Some(user_id) => {
+                context.update(&[SCOPED_TAG]);
+                context.update(&user_id.to_be_bytes());
+            }
+            None => context.update(&[UNSCOPED_TAG]),
+        }
+        context.update(payload.as_bytes());
+
+        URL_SAFE_NO_PAD.encode(&context.sign().as_ref()[..self.signature_len])
+    }
+
+    fn verify_inner<'a>(&self, user_id: Option<i64>, signed: &'a str) -> Result<&'a str, Error> {
+        let index = signed
+            .len()
+            .checked_sub(self.encoded_signature_len())
+            .filter(|index| signed.is_char_boundary(*index))
+            .ok_or(Error::TooShort)?;
+        let (payload, signature) = signed.split_at(index);
+
+        let expected = self.signature(user_id, payload);
+
+        // Signatures are compared in constant time, so the signature can't be guessed by timing
+        if expected.as_bytes().ct_eq(signature.as_bytes()).into() {
+            Ok(payload)
+        } else {
+            Err(Error::InvalidSignature)
+        }
+    }
+
+    /// Signs the payload
+    /// # Returns
+    /// Payload with the appended signature
+    #[must_use]
+    pub fn sign(&self, payload: &str) -> String {
+        format!("{payload}{}", self.signature(None, payload))
+    }
+
+    /// Signs the payload for the user, so the signed payload is valid only for this user
+    /// # Returns
+    /// Payload with the appended signature
+    #[must_use]
+    pub fn sign_for(&self, user_id: i64, payload: &str) -> String {
+        format!("{payload}{}", self.signature(Some(user_id), payload))
+    }
+
+    /// Verifies the signed payload, which is signed by [`Signer::sign`]
+    /// # Returns
+    /// Payload without the signature
+    /// # Errors
+    /// - If the signed payload is shorter than the signature
+    /// - If the signature is invalid
+    pub fn verify<'a>(&self, signed: &'a str) -> Result<&'a str, Error> {
+        self.verify_inner(None, signed)
+    }
+
+    /// Verifies the signed payload, which is signed by [`Signer::sign_for`] for the user
+    /// # Returns
+    /// Payload without the signature
+    /// # Errors
+    /// - If the signed payload is shorter than the signature
+    /// - If the signature is invalid or the payload is signed for another user
+    pub fn verify_for<'a>(&self, user_id: i64, signed: &'a str) -> Result<&'a str, Error> {
+        self.verify_inner(Some(user_id), signed)
+    }
+}
+
+impl Debug for Signer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Signer")
+            .field("signature_len", &self.signature_len)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_verify() {
+        let signer = Signer::from_token("123:token");
+
+        let signed = signer.sign("ban_42");
+        assert_eq!(
+            signed.len(),
+            "ban_42".len() + signer.encoded_signature_len()
+        );
+        assert!(signed
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '-'));
+        assert_eq!(signer.verify(&signed), Ok("ban_42"));
+
+        assert_eq!(
+            signer.verify(&signed.replacen("42", "43", 1)),
+            Err(Error::InvalidSignature)
+        );
+        assert_eq!(
+            Signer::from_token("456:token").verify(&signed),
+            Err(Error::InvalidSignature)
+        );
+        assert_eq!(signer.verify("short"), Err(Error::TooShort));
+
+        let signed = signer.sign_for(1, "ban_42");
+        assert_eq!(signer.verify_for(1, &signed), Ok("ban_42"));
+        assert_eq!(signer.verify_for(2, &signed), Err(Error::InvalidSignature));
+        assert_eq!(signer.verify(&signed), Err(Error::InvalidSignature));
+
+        // Unscoped signature of the payload, which starts with bytes of the user id, isn't valid for the user
+        let user_id = i64::from_be_bytes(*b"12345678");
+        let signed = signer.sign("12345678ban_42");
+        let signature = &signed["12345678ban_42".len()..];
+        assert_eq!(
+            signer.verify_for(user_id, &format!("ban_42{signature}")),
+            Err(Error::InvalidSignature)
+        );
+
+        let signer = Signer::new("key").signature_len(100);
+        assert_eq!(signer.encoded_signature_len(), 43);
+        assert_eq!(signer.verify(&signer.sign("")), Ok(""));
+    }
+}
@@ -346,6 +346,14 @@ where
             "Failed to add mention for the user without username. Report this issue to the developers",)
     }
 
+    /// Add mention of the user by the username or by the full name, if the user doesn't have username.
+    /// Check [`Formatter::mention_user`] for details.
+    #[must_use]
+    pub fn mention_user(mut self, user: &User) -> Self {
+        self.text.push_str(&self.formatter.mention_user(user));
+        self
+    }
+
     /// Add custom emoji to the text instead of unicode emoji.
     /// # Arguments
     /// * `emoji` - Emoji that will be added to the text and will be replaced with custom emoji.
@@ -432,6 +440,8 @@ mod tests {
             .text(" ")
             .text_mention("text_mention", User::default())
             .text(" ")
+            .mention_user(&User::new(1, false, "Name"))
+            .text(" ")
             .custom_emoji("custom_emoji", "emoji_id");
 
         assert_eq!(
@@ -444,6 +454,7 @@ mod tests {
             <pre><code class=\"language-python\">pre_language</code></pre> \
             <a href=\"https://example.com\">text_link</a> \
             <a href=\"tg://user?id=0\">text_mention</a> \
+            <a href=\"tg://user?id=1\">Name</a> \
             <tg-emoji data-emoji-id=\"emoji_id\">custom_emoji</tg-emoji>\
             "
         );
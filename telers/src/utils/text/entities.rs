@@ -0,0 +1,444 @@
+//! This module contains converters between plain text with [`MessageEntity`] objects and formatted text,
+//! so bots can edit or transform formatted messages (for example, add a footer or translate them)
+//! while preserving the formatting instead of losing the entities.
+//!
+//! - [`unparse_entities`] converts text with entities to formatted text with any [`Formatter`],
+//!   [`unparse_html`] and [`unparse_markdown`] are shortcuts for HTML and MarkdownV2 formatters.
+//! - [`parse_html`] converts text with HTML markup back to plain text with entities.
+//!
+//! Offsets and lengths of the entities are in UTF-16 code units, like in the Telegram Bot API.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use telers::utils::text::{parse_html, unparse_html};
+//!
+//! let (text, entities) = parse_html("Hello, <b>world</b>!").unwrap();
+//!
+//! assert_eq!(text, "Hello, world!");
+//! assert_eq!(unparse_html(&text, &entities), "Hello, <b>world</b>!");
+//! ```
+
+use super::{html_formatter, markdown_formatter, Formatter};
+
+use crate::types::{
+    CustomEmojiMessageEntity, MessageEntity, MessageEntityKind, PreMessageEntity,
+    TextLinkMessageEntity, TextMentionMessageEntity, User,
+};
+
+use std::ops::Range;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseError {
+    #[error("Unsupported tag `{0}`")]
+    UnsupportedTag(Box<str>),
+    #[error("Tag `{0}` isn't closed")]
+    UnclosedTag(Box<str>),
+    #[error("Closing tag `{0}` doesn't match the open tag")]
+    UnexpectedClosingTag(Box<str>),
+    #[error("Tag `{tag}` requires `{attribute}` attribute")]
+    MissingAttribute {
+        tag: Box<str>,
+        attribute: &'static str,
+    },
+    #[error("Unsupported character reference `{0}`")]
+    UnsupportedReference(Box<str>),
+    #[error("Text is too long, max length is {} UTF-16 code units", u16::MAX)]
+    TooLong,
+}
+
+/// Byte offsets of the text by UTF-16 offsets.
+/// Offsets in the middle of the surrogate pair are rounded up to the end of the character.
+fn byte_offsets(text: &str) -> Vec<usize> {
+    let mut offsets = Vec::with_capacity(text.len() + 1);
+
+    for (index, ch) in text.char_indices() {
+        offsets.push(index);
+        if ch.len_utf16() == 2 {
+            offsets.push(index + ch.len_utf8());
+        }
+    }
+    offsets.push(text.len());
+
+    offsets
+}
+
+fn unparse_range<F>(
+    formatter: &F,
+    text: &str,
+    offsets: &[usize],
+    range: Range<usize>,
+    entities: &[&MessageEntity],
+) -> String
+where
+    F: Formatter,
+{
+    let byte = |offset: usize| offsets[offset.min(offsets.len() - 1)];
+
+    let mut result = String::new();
+    let mut position = range.start;
+    let mut index = 0;
+
+    while index < entities.len() {
+        let entity = entities[index];
+        let start = usize::from(entity.offset).max(position);
+        let end = (usize::from(entity.offset) + usize::from(entity.length)).min(range.end);
+
+        // Entities, which are inside the current one, are its children
+        let children_len = entities[index + 1..]
+            .iter()
+            .take_while(|child| usize::from(child.offset) < end)
+            .count();
+        let children = &entities[index + 1..=index + children_len];
+        index += children_len + 1;
+
+        if start >= end {
+            continue;
+        }
+
+        result.push_str(&formatter.quote(&text[byte(position)..byte(start)]));
+
+        let inner = unparse_range(formatter, text, offsets, start..end, children);
+        let formatted = match &entity.kind {
+            MessageEntityKind::Bold => formatter.bold(inner),
+            MessageEntityKind::Italic => formatter.italic(inner),
+            MessageEntityKind::Underline => formatter.underline(inner),
+            MessageEntityKind::Strikethrough => formatter.strikethrough(inner),
+            MessageEntityKind::Spoiler => formatter.spoiler(inner),
+            MessageEntityKind::Blockquote => formatter.blockquote(inner),
+            MessageEntityKind::Code => formatter.code(inner),
+            MessageEntityKind::Pre(PreMessageEntity { language }) => match language {
+                Some(language) => formatter.pre_language(inner, language),
+                None => formatter.pre(inner),
+            },
+            MessageEntityKind::TextLink(TextLinkMessageEntity { url }) => {
+                formatter.text_link(inner, formatter.quote(url))
+            }
+            MessageEntityKind::TextMention(TextMentionMessageEntity {
+                user: User { id: user_id, .. },
+            }) => formatter.text_mention(inner, *user_id),
+            MessageEntityKind::CustomEmoji(CustomEmojiMessageEntity { custom_emoji_id }) => {
+                formatter.custom_emoji(inner, custom_emoji_id)
+            }
+            // These entities are detected by Telegram automatically, so their text is kept as is
+            MessageEntityKind::Mention
+            | MessageEntityKind::Hashtag
+            | MessageEntityKind::Cashtag
+            | MessageEntityKind::BotCommand
+            | MessageEntityKind::Url
+            | MessageEntityKind::Email
+            | MessageEntityKind::PhoneNumber => inner,
+        };
+
+        result.push_str(&formatted);
+        position = end;
+    }
+
+    result.push_str(&formatter.quote(&text[byte(position)..byte(range.end)]));
+    result
+}
+
+/// Converts the text with entities to the formatted text with the formatter.
+/// The text is escaped by [`Formatter::quote`], so it can be sent with the parse mode of the formatter.
+/// # Notes
+/// Entities must satisfy the Telegram restrictions: if two entities have common characters, one of them is fully contained inside another.
+/// Entities, which cross the boundary of the parent entity, are cut at the boundary.
+#[must_use]
+pub fn unparse_entities<F>(formatter: &F, text: &str, entities: &[MessageEntity]) -> String
+where
+    F: Formatter,
+{
+    let offsets = byte_offsets(text);
+
+    // Parent entities go before their children
+    let mut entities = entities.iter().collect::<Vec<_>>();
+    entities.sort_by_key(|entity| (entity.offset, u16::MAX - entity.length));
+
+    unparse_range(formatter, text, &offsets, 0..offsets.len() - 1, &entities)
+}
+
+/// Converts the text with entities to the text with HTML markup
+#[must_use]
+pub fn unparse_html(text: &str, entities: &[MessageEntity]) -> String {
+    unparse_entities(&*html_formatter::FORMATTER, text, entities)
+}
+
+/// Converts the text with entities to the text with MarkdownV2 markup
+#[must_use]
+pub fn unparse_markdown(text: &str, entities: &[MessageEntity]) -> String {
+    unparse_entities(&*markdown_formatter::FORMATTER, text, entities)
+}
+
+/// Value of the attribute of the tag, for example, `href` of `<a href="https://example.com">`
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = tag;
+
+    while let Some(index) = rest.find(name) {
+        let before = rest[..index].chars().next_back();
+        let after = rest[index + name.len()..].trim_start();
+        rest = &rest[index + name.len()..];
+
+        if !before.map_or(false, char::is_whitespace) {
+            continue;
+        }
+        let Some(after) = after.strip_prefix('=') else {
+            continue;
+        };
+        let after = after.trim_start();
+
+        return match after.chars().next() {
+            Some(quote @ ('"' | '\'')) => after[1..].split(quote).next(),
+            _ => after.split(|ch: char| ch.is_whitespace()).next(),
+        };
+    }
+
+    None
+}
+
+fn unescape(reference: &str) -> Result<char, ParseError> {
+    let ch = match reference {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        _ => reference.strip_prefix('#').and_then(|code| {
+            match code.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                None => code.parse().ok(),
+            }
+            .and_then(char::from_u32)
+        }),
+    };
+
+    ch.ok_or_else(|| ParseError::UnsupportedReference(reference.into()))
+}
+
+/// Unescapes character references in the value of the attribute
+fn unescape_attribute(value: &str) -> Result<String, ParseError> {
+    let mut result = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(index) = rest.find('&') {
+        result.push_str(&rest[..index]);
+        rest = &rest[index..];
+
+        let end = rest
+            .find(';')
+            .ok_or_else(|| ParseError::UnsupportedReference(rest.into()))?;
+        result.push(unescape(&rest[1..end])?);
+        rest = &rest[end + 1..];
+    }
+    result.push_str(rest);
+
+    Ok(result)
+}
+
+fn tag_kind(name: &str, tag: &str) -> Result<MessageEntityKind, ParseError> {
+    let missing = |attribute| ParseError::MissingAttribute {
+        tag: name.into(),
+        attribute,
+    };
+
+    let kind = match name {
+        "b" | "strong" => MessageEntityKind::Bold,
+        "i" | "em" => MessageEntityKind::Italic,
+        "u" | "ins" => MessageEntityKind::Underline,
+        "s" | "strike" | "del" => MessageEntityKind::Strikethrough,
+        "tg-spoiler" => MessageEntityKind::Spoiler,
+        "span" if attribute(tag, "class") == Some("tg-spoiler") => MessageEntityKind::Spoiler,
+        "blockquote" => MessageEntityKind::Blockquote,
+        "code" => MessageEntityKind::Code,
+        "pre" => MessageEntityKind::Pre(PreMessageEntity::new()),
+        "a" => MessageEntityKind::TextLink(TextLinkMessageEntity::new(unescape_attribute(
+            attribute(tag, "href").ok_or_else(|| missing("href"))?,
+        )?)),
+        "tg-emoji" => MessageEntityKind::CustomEmoji(CustomEmojiMessageEntity::new(
+            attribute(tag, "emoji-id")
+                .or_else(|| attribute(tag, "data-emoji-id"))
+                .ok_or_else(|| missing("emoji-id"))?,
+        )),
+        _ => return Err(ParseError::UnsupportedTag(name.into())),
+    };
+
+    Ok(kind)
+}
+
+/// Converts the text with HTML markup to the plain text with entities.
+/// Supported tags and character references are the same as in the Telegram `HTML` parse mode.
+/// `<code class="language-...">` inside `<pre>` sets the language of the `pre` entity.
+/// Links are converted to `text_link` entities, including `tg://user?id=...` links, because the user isn't known.
+/// # Errors
+/// - If the tag isn't supported or doesn't have the required attribute
+/// - If the tags aren't closed or are closed in the wrong order
+/// - If the character reference isn't supported
+/// - If the text is longer than [`u16::MAX`] UTF-16 code units
+pub fn parse_html(html: &str) -> Result<(String, Vec<MessageEntity>), ParseError> {
+    let mut text = String::with_capacity(html.len());
+    let mut len: usize = 0;
+    let mut entities: Vec<MessageEntity> = vec![];
+    // Open tags with indexes of their entities. `None` is for `code` inside `pre`, which sets the language instead of a new entity
+    let mut open: Vec<(&str, Option<usize>)> = vec![];
+
+    let offset = |len: usize| u16::try_from(len).map_err(|_| ParseError::TooLong);
+
+    let mut rest = html;
+    while let Some(ch) = rest.chars().next() {
+        match ch {
+            '<' => {
+                let end = rest
+                    .find('>')
+                    .ok_or_else(|| ParseError::UnclosedTag(rest.into()))?;
+                let tag = &rest[1..end];
+                rest = &rest[end + 1..];
+
+                if let Some(name) = tag.strip_prefix('/') {
+                    let name = name.trim();
+
+                    match open.pop() {
+                        Some((open_name, index)) if open_name == name => {
+                            if let Some(index) = index {
+                                let entity = &mut entities[index];
+                                entity.length = offset(len)? - entity.offset;
+                            }
+                        }
+                        _ => return Err(ParseError::UnexpectedClosingTag(name.into())),
+                    }
+                    continue;
+                }
+
+                let name = tag.split_whitespace().next().unwrap_or_default();
+
+                // Language of the code block is set by the nested `code` tag
+                if name == "code" {
+                    if let Some((_, Some(index))) = open.last().filter(|(name, _)| *name == "pre") {
+                        let entity = &mut entities[*index];
+                        if entity.offset == offset(len)? {
+                            if let Some(language) = attribute(tag, "class")
+                                .and_then(|class| class.strip_prefix("language-"))
+                            {
+                                entity.kind = MessageEntityKind::Pre(
+                                    PreMessageEntity::new_language(language),
+                                );
+                                open.push((name, None));
+                                continue;
+                            }
+                        }
+                    }
+                }
+
+                let kind = tag_kind(name, tag)?;
+                open.push((name, Some(entities.len())));
+                entities.push(MessageEntity::new(offset(len)?, 0, kind));
+            }
+            '&' => {
+                let end = rest
+                    .find(';')
+                    .ok_or_else(|| ParseError::UnsupportedReference(rest.into()))?;
+                let ch = unescape(&rest[1..end])?;
+                rest = &rest[end + 1..];
+
+                text.push(ch);
+                len += ch.len_utf16();
+            }
+            _ => {
+                text.push(ch);
+                len += ch.len_utf16();
+                rest = &rest[ch.len_utf8()..];
+            }
+        }
+    }
+
+    if let Some((name, _)) = open.pop() {
+        return Err(ParseError::UnclosedTag(name.into()));
+    }
+    offset(len)?;
+
+    // Empty entities are ignored by Telegram
+    entities.retain(|entity| entity.length > 0);
+
+    Ok((text, entities))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unparse_entities() {
+        let text = "Hello, 🌍 world & <you>!";
+        let entities = [
+            MessageEntity::new(0, 5, MessageEntityKind::Bold),
+            MessageEntity::new(7, 8, MessageEntityKind::Italic),
+            MessageEntity::new(10, 5, MessageEntityKind::Underline),
+            MessageEntity::new(
+                18,
+                5,
+                TextLinkMessageEntity::new("https://example.com?a=1&b=2"),
+            ),
+        ];
+
+        assert_eq!(
+            unparse_html(text, &entities),
+            "<b>Hello</b>, <i>🌍 <u>world</u></i> &amp; <a href=\"https://example.com?a=1&amp;b=2\">&lt;you&gt;</a>!"
+        );
+        assert_eq!(
+            unparse_markdown("a.b", &[MessageEntity::new(1, 2, MessageEntityKind::Bold)]),
+            "a*\\.b*"
+        );
+        assert_eq!(unparse_html("text", &[]), "text");
+    }
+
+    #[test]
+    fn test_parse_html() {
+        let (text, entities) = parse_html(
+            "<b>Hello</b>, <i>🌍 <u>world</u></i> &amp; &#60;you&gt;!\n<pre><code class=\"language-rust\">fn main() {}</code></pre>",
+        )
+        .unwrap();
+
+        assert_eq!(text, "Hello, 🌍 world & <you>!\nfn main() {}");
+        assert_eq!(
+            entities,
+            [
+                MessageEntity::new(0, 5, MessageEntityKind::Bold),
+                MessageEntity::new(7, 8, MessageEntityKind::Italic),
+                MessageEntity::new(10, 5, MessageEntityKind::Underline),
+                MessageEntity::new(25, 12, PreMessageEntity::new_language("rust")),
+            ]
+        );
+        assert_eq!(
+            unparse_html(&text, &entities),
+            "<b>Hello</b>, <i>🌍 <u>world</u></i> &amp; &lt;you&gt;!\n<pre><code class=\"language-rust\">fn main() {}</code></pre>"
+        );
+
+        let (_, entities) =
+            parse_html("<a href='tg://user?id=1'>user</a> <tg-emoji emoji-id=\"5\">👍</tg-emoji>")
+                .unwrap();
+        assert_eq!(
+            entities,
+            [
+                MessageEntity::new(0, 4, TextLinkMessageEntity::new("tg://user?id=1")),
+                MessageEntity::new(5, 2, CustomEmojiMessageEntity::new("5")),
+            ]
+        );
+
+        assert_eq!(
+            parse_html("<b>text</i>"),
+            Err(ParseError::UnexpectedClosingTag("i".into()))
+        );
+        assert_eq!(
+            parse_html("<b>text"),
+            Err(ParseError::UnclosedTag("b".into()))
+        );
+        assert_eq!(
+            parse_html("<div>text</div>"),
+            Err(ParseError::UnsupportedTag("div".into()))
+        );
+        assert_eq!(
+            parse_html("<a>text</a>"),
+            Err(ParseError::MissingAttribute {
+                tag: "a".into(),
+                attribute: "href"
+            })
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::types::{MessageEntity, MessageEntityKind};
+use crate::types::{MessageEntity, MessageEntityKind, User};
 
 #[derive(Debug, thiserror::Error)]
 pub enum ErrorKind {
@@ -89,6 +89,18 @@ pub trait Formatter {
     where
         T: AsRef<str>;
 
+    /// Mention the user by the username, if the user has it, otherwise by the full name with `tg://user?id=<user_id>` link.
+    /// Username and full name are quoted, so they can contain any characters.
+    /// # Notes
+    /// Mentions by the link work only if the user has contacted the bot or is a member of the chat, check [`Formatter`] for details.
+    #[must_use]
+    fn mention_user(&self, user: &User) -> String {
+        match user.username {
+            Some(ref username) => self.quote(format!("@{username}")),
+            None => self.text_mention(self.quote(user.full_name()), user.id),
+        }
+    }
+
     /// Apply the [`MessageEntity`] to the given text with offset and length.
     /// # Notes
     /// Differences between [`Formatter::apply_entity`] and [`Formatter::apply_entity_kind`]:
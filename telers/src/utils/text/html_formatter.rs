@@ -309,6 +309,10 @@ pub fn quote(text: impl AsRef<str>) -> String {
     FORMATTER.quote(text)
 }
 
+pub fn mention(user: &User) -> String {
+    FORMATTER.mention_user(user)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -407,4 +411,15 @@ mod tests {
         assert_eq!(formatter.quote("<text>"), "&lt;text&gt;");
         assert_eq!(formatter.quote("&text"), "&amp;text");
     }
+
+    #[test]
+    fn test_mention() {
+        let user = User::new(1, false, "<Name>");
+
+        assert_eq!(
+            mention(&user),
+            "<a href=\"tg://user?id=1\">&lt;Name&gt;</a>"
+        );
+        assert_eq!(mention(&user.username("name")), "@name");
+    }
 }
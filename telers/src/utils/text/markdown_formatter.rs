@@ -254,6 +254,10 @@ pub fn quote(text: impl AsRef<str>) -> String {
     FORMATTER.quote(text)
 }
 
+pub fn mention(user: &User) -> String {
+    FORMATTER.mention_user(user)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -361,4 +365,12 @@ mod tests {
             r"test \` \* \_ \~ \| \> \# \+ \- \= \. \! \[ \] \( \) \{ \} test"
         );
     }
+
+    #[test]
+    fn test_mention() {
+        let user = User::new(1, false, "First").last_name("Last");
+
+        assert_eq!(mention(&user), "[First Last](tg://user?id=1)");
+        assert_eq!(mention(&user.username("user_name")), r"@user\_name");
+    }
 }
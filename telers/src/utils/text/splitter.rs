@@ -0,0 +1,458 @@
+//! This module contains functions to split long texts into chunks, which fit into the Telegram message limit
+//! ([`MAX_TEXT_LEN`] characters after entities parsing), so the text can be sent as several messages.
+//!
+//! Texts are split at safe boundaries: at line breaks if it's possible, otherwise at whitespaces,
+//! and only as a last resort in the middle of a word. Formatting is preserved:
+//! - [`split_text`] splits [`MessageEntity`] objects, which cross the boundary, and shifts their offsets,
+//! - [`split_html`] closes open tags at the end of the chunk and reopens them at the start of the next chunk,
+//! - [`split_markdown`] doesn't split code blocks, if it's possible.
+//!
+//! Usually you don't need to use these functions directly, check [`SendMessage::chunked`] and [`Bot::send_long_text`].
+//!
+//! [`SendMessage::chunked`]: crate::methods::SendMessage#method.chunked
+//! [`Bot::send_long_text`]: crate::client::Bot#method.send_long_text
+
+use crate::types::MessageEntity;
+
+/// Max length of the message text in UTF-16 code units after entities parsing
+pub const MAX_TEXT_LEN: usize = 4096;
+
+/// Priority of the break after the atom. The boundary with the highest priority is chosen in the chunk.
+const BREAK_NONE: u8 = 0;
+const BREAK_WHITESPACE: u8 = 1;
+const BREAK_NEWLINE: u8 = 2;
+/// Breaks outside of the entities and code blocks are preferred
+const BREAK_OUTSIDE_OFFSET: u8 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tag<'a> {
+    Open { name: &'a str },
+    Close { name: &'a str },
+}
+
+/// The smallest part of the text, which can't be split
+#[derive(Debug, Clone, Copy)]
+struct Atom<'a> {
+    text: &'a str,
+    /// Length of the atom after entities parsing in UTF-16 code units
+    len: usize,
+    /// Priority of the break after the atom
+    priority: u8,
+    tag: Option<Tag<'a>>,
+}
+
+fn char_priority(ch: char) -> u8 {
+    if ch == '\n' {
+        BREAK_NEWLINE
+    } else if ch.is_whitespace() {
+        BREAK_WHITESPACE
+    } else {
+        BREAK_NONE
+    }
+}
+
+fn char_atoms(text: &str) -> impl Iterator<Item = Atom<'_>> {
+    text.char_indices().map(|(index, ch)| Atom {
+        text: &text[index..index + ch.len_utf8()],
+        len: ch.len_utf16(),
+        priority: char_priority(ch),
+        tag: None,
+    })
+}
+
+/// Split atoms into chunks with max length
+/// # Returns
+/// Ranges of the atoms of each chunk with open tags at the start of the chunk
+fn split_atoms<'a>(atoms: &[Atom<'a>], max_len: usize) -> Vec<(usize, usize, Vec<Atom<'a>>)> {
+    let mut chunks = vec![];
+    let mut start = 0;
+    let mut open_tags: Vec<Atom<'a>> = vec![];
+
+    loop {
+        let mut len = 0;
+        let mut tags = open_tags.clone();
+        // Priority, end and open tags at the end of the best boundary
+        let mut best: Option<(u8, usize, Vec<Atom<'a>>)> = None;
+        let mut end = start;
+
+        while end < atoms.len() {
+            let atom = atoms[end];
+
+            if len + atom.len > max_len && end > start {
+                break;
+            }
+
+            len += atom.len;
+            end += 1;
+
+            match atom.tag {
+                Some(Tag::Open { .. }) => tags.push(atom),
+                Some(Tag::Close { name }) => {
+                    if let Some(index) = tags.iter().rposition(|tag| {
+                        matches!(tag.tag, Some(Tag::Open { name: open_name }) if open_name == name)
+                    }) {
+                        tags.remove(index);
+                    }
+                }
+                None => {}
+            }
+
+            if best
+                .as_ref()
+                .map_or(true, |(priority, _, _)| atom.priority >= *priority)
+            {
+                best = Some((atom.priority, end, tags.clone()));
+            }
+        }
+
+        if end == atoms.len() {
+            chunks.push((start, end, open_tags));
+            break;
+        }
+
+        // `unwrap` is safe, because at least one atom is added to the chunk
+        let (_, best_end, best_tags) = best.unwrap();
+
+        chunks.push((start, best_end, open_tags));
+        start = best_end;
+        open_tags = best_tags;
+    }
+
+    chunks
+}
+
+/// Chunk of the text with entities
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chunk {
+    pub text: String,
+    pub entities: Option<Vec<MessageEntity>>,
+}
+
+/// Split plain text with entities into chunks with max length in UTF-16 code units.
+/// Entities, which cross the boundary, are split, and offsets of the entities are shifted.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn split_text(text: &str, entities: Option<&[MessageEntity]>, max_len: usize) -> Vec<Chunk> {
+    let mut offset = 0;
+    let atoms = char_atoms(text)
+        .map(|mut atom| {
+            offset += atom.len;
+
+            let inside_entity = entities.map_or(false, |entities| {
+                entities.iter().any(|entity| {
+                    let start = usize::from(entity.offset);
+                    let end = start + usize::from(entity.length);
+
+                    start < offset && offset < end
+                })
+            });
+            if !inside_entity && atom.priority > BREAK_NONE {
+                atom.priority += BREAK_OUTSIDE_OFFSET;
+            }
+
+            atom
+        })
+        .collect::<Vec<_>>();
+
+    let mut chunk_offset = 0;
+
+    split_atoms(&atoms, max_len)
+        .into_iter()
+        .map(|(start, end, _)| {
+            let atoms = &atoms[start..end];
+            let chunk_len = atoms.iter().map(|atom| atom.len).sum::<usize>();
+            let chunk_end = chunk_offset + chunk_len;
+
+            let entities = entities.map(|entities| {
+                entities
+                    .iter()
+                    .filter_map(|entity| {
+                        let start = usize::from(entity.offset).max(chunk_offset);
+                        let end = (usize::from(entity.offset) + usize::from(entity.length))
+                            .min(chunk_end);
+
+                        (start < end).then(|| MessageEntity {
+                            offset: (start - chunk_offset) as u16,
+                            length: (end - start) as u16,
+                            kind: entity.kind.clone(),
+                        })
+                    })
+                    .collect()
+            });
+
+            let chunk = Chunk {
+                text: atoms.iter().map(|atom| atom.text).collect(),
+                entities,
+            };
+
+            chunk_offset = chunk_end;
+            chunk
+        })
+        .collect()
+}
+
+fn html_atoms(text: &str) -> Vec<Atom<'_>> {
+    let mut atoms = vec![];
+    let mut rest = text;
+
+    while let Some(ch) = rest.chars().next() {
+        let atom = match ch {
+            '<' => {
+                let len = rest.find('>').map_or(rest.len(), |index| index + 1);
+                let tag_text = &rest[..len];
+                let inner = tag_text.trim_start_matches('<').trim_end_matches('>');
+
+                let tag = if let Some(name) = inner.strip_prefix('/') {
+                    Tag::Close { name: name.trim() }
+                } else {
+                    Tag::Open {
+                        name: inner.split_whitespace().next().unwrap_or_default(),
+                    }
+                };
+
+                Atom {
+                    text: tag_text,
+                    len: 0,
+                    priority: BREAK_NONE,
+                    tag: Some(tag),
+                }
+            }
+            '&' => {
+                let len = rest
+                    .find(';')
+                    .filter(|index| {
+                        rest[1..*index]
+                            .chars()
+                            .all(|ch| ch.is_ascii_alphanumeric() || ch == '#')
+                    })
+                    .map_or(1, |index| index + 1);
+
+                Atom {
+                    text: &rest[..len],
+                    len: 1,
+                    priority: BREAK_NONE,
+                    tag: None,
+                }
+            }
+            _ => Atom {
+                text: &rest[..ch.len_utf8()],
+                len: ch.len_utf16(),
+                priority: char_priority(ch) + BREAK_OUTSIDE_OFFSET,
+                tag: None,
+            },
+        };
+
+        rest = &rest[atom.text.len()..];
+        atoms.push(atom);
+    }
+
+    atoms
+}
+
+/// Split text with HTML markup into chunks with max length in UTF-16 code units after entities parsing.
+/// Tags, which are open at the end of the chunk, are closed and reopened at the start of the next chunk.
+#[must_use]
+pub fn split_html(text: &str, max_len: usize) -> Vec<String> {
+    let atoms = html_atoms(text);
+    let chunks = split_atoms(&atoms, max_len);
+
+    chunks
+        .iter()
+        .enumerate()
+        .map(|(index, (start, end, open_tags))| {
+            let mut chunk = open_tags.iter().map(|tag| tag.text).collect::<String>();
+            chunk.extend(atoms[*start..*end].iter().map(|atom| atom.text));
+
+            // Close tags, which are reopened in the next chunk
+            if let Some((_, _, next_open_tags)) = chunks.get(index + 1) {
+                for tag in next_open_tags.iter().rev() {
+                    if let Some(Tag::Open { name }) = tag.tag {
+                        chunk.push_str("</");
+                        chunk.push_str(name);
+                        chunk.push('>');
+                    }
+                }
+            }
+
+            chunk
+        })
+        .collect()
+}
+
+/// Split text with Markdown markup into chunks with max length in UTF-16 code units.
+/// Code blocks aren't split, if it's possible.
+/// # Notes
+/// Inline formatting, which crosses the boundary, isn't closed and reopened,
+/// so prefer [`split_html`] or [`split_text`] with entities for texts with much formatting.
+#[must_use]
+pub fn split_markdown(text: &str, max_len: usize) -> Vec<String> {
+    let mut in_code_block = false;
+    let mut backticks = 0;
+
+    let atoms = char_atoms(text)
+        .map(|mut atom| {
+            if atom.text == "`" {
+                backticks += 1;
+                if backticks == 3 {
+                    in_code_block = !in_code_block;
+                    backticks = 0;
+                }
+            } else {
+                backticks = 0;
+            }
+
+            if !in_code_block && atom.priority > BREAK_NONE {
+                atom.priority += BREAK_OUTSIDE_OFFSET;
+            }
+
+            atom
+        })
+        .collect::<Vec<_>>();
+
+    split_atoms(&atoms, max_len)
+        .into_iter()
+        .map(|(start, end, _)| atoms[start..end].iter().map(|atom| atom.text).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::message_entity::Kind;
+
+    #[test]
+    fn test_split_text() {
+        assert_eq!(
+            split_text("short", None, 10),
+            [Chunk {
+                text: "short".to_owned(),
+                entities: None
+            }]
+        );
+
+        let chunks = split_text("first line\nsecond line", None, 15);
+        assert_eq!(
+            chunks.iter().map(|chunk| &*chunk.text).collect::<Vec<_>>(),
+            ["first line\n", "second line"]
+        );
+
+        // The word is split only as a last resort
+        let chunks = split_text("abcdefghij", None, 4);
+        assert_eq!(
+            chunks.iter().map(|chunk| &*chunk.text).collect::<Vec<_>>(),
+            ["abcd", "efgh", "ij"]
+        );
+
+        // Surrogate pairs aren't split
+        let chunks = split_text("😀😀😀", None, 3);
+        assert_eq!(
+            chunks.iter().map(|chunk| &*chunk.text).collect::<Vec<_>>(),
+            ["😀", "😀", "😀"]
+        );
+    }
+
+    #[test]
+    fn test_split_text_entities() {
+        let entities = [
+            MessageEntity {
+                offset: 0,
+                length: 3,
+                kind: Kind::Bold,
+            },
+            MessageEntity {
+                offset: 4,
+                length: 11,
+                kind: Kind::Italic,
+            },
+        ];
+
+        // Break outside the italic entity is preferred
+        let chunks = split_text("bbb iiii iiiiii", Some(&entities), 12);
+
+        assert_eq!(chunks[0].text, "bbb ");
+        assert_eq!(chunks[1].text, "iiii iiiiii");
+        assert_eq!(
+            chunks[1].entities.as_deref(),
+            Some(
+                &[MessageEntity {
+                    offset: 0,
+                    length: 11,
+                    kind: Kind::Italic
+                }][..]
+            )
+        );
+
+        // Entity, which is longer than the chunk, is split
+        let chunks = split_text("bbb iiii iiiiii", Some(&entities), 8);
+
+        assert_eq!(
+            chunks.iter().map(|chunk| &*chunk.text).collect::<Vec<_>>(),
+            ["bbb ", "iiii ", "iiiiii"]
+        );
+        assert_eq!(
+            chunks[2].entities.as_deref(),
+            Some(
+                &[MessageEntity {
+                    offset: 0,
+                    length: 6,
+                    kind: Kind::Italic
+                }][..]
+            )
+        );
+    }
+
+    #[test]
+    fn test_split_html() {
+        assert_eq!(
+            split_html("<b>bold &amp; text</b>\nplain text", 12),
+            ["<b>bold &amp; text</b>\n", "plain text"]
+        );
+        assert_eq!(
+            split_html(
+                r#"<a href="https://example.com">link <b>bold text</b></a>"#,
+                10
+            ),
+            [
+                r#"<a href="https://example.com">link <b>bold </b></a>"#,
+                r#"<a href="https://example.com"><b>text</b></a>"#
+            ]
+        );
+    }
+
+    #[test]
+    fn test_send_message_chunked() {
+        use crate::{enums::ParseMode, methods::SendMessage, types::ReplyParameters};
+
+        let text = "word ".repeat(1000);
+
+        let methods = SendMessage::new_chunked(1, &text);
+        assert_eq!(methods.len(), 2);
+        assert_eq!(
+            methods
+                .iter()
+                .map(|method| &*method.text)
+                .collect::<String>(),
+            text
+        );
+
+        let methods = SendMessage::new(1, format!("<b>{text}</b>"))
+            .parse_mode(ParseMode::HTML)
+            .reply_parameters(ReplyParameters::new(2))
+            .chunked();
+        assert_eq!(methods.len(), 2);
+        assert!(methods[0].text.ends_with("</b>"));
+        assert!(methods[1].text.starts_with("<b>"));
+        assert!(methods[0].reply_parameters.is_some());
+        assert!(methods[1].reply_parameters.is_none());
+
+        assert_eq!(SendMessage::new_chunked(1, "short").len(), 1);
+    }
+
+    #[test]
+    fn test_split_markdown() {
+        assert_eq!(
+            split_markdown("text\n```\ncode code\n```\nend", 20),
+            ["text\n", "```\ncode code\n```\n", "end"]
+        );
+    }
+}
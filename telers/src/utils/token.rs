@@ -1,6 +1,7 @@
 /// Hide telegram token for privacy. \
 /// For example,
 /// `1234567890:ABC-DEF1234ghIkl-zyx57W2v1u123ew11` will be hidden as `12********11`
+#[cfg(feature = "client")]
 pub(crate) fn hide(token: &str) -> String {
     let token_len = token.len();
 